@@ -0,0 +1,301 @@
+#include "example/baseline.h"
+
+#include <stdio.h>
+#include <stdlib.h>
+#include <string.h>
+
+#define CHECK(condition)                                                                \
+    do {                                                                                \
+        if (!(condition)) {                                                             \
+            fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__, __LINE__, #condition); \
+            exit(EXIT_FAILURE);                                                         \
+        }                                                                               \
+    } while (0)
+
+#define CHECK_OK(call) CHECK((call) == EXAMPLE_SBE_OK)
+
+static size_t original(uint8_t *buffer, size_t capacity) {
+    FILE *file = fopen("tests/static/example_schema_car.sbe", "rb");
+    CHECK(file != NULL);
+
+    const size_t size = fread(buffer, 1, capacity, file);
+    fclose(file);
+    return size;
+}
+
+static void encode_text(example_sbe_error_t (*encode)(example_sbe_encoder_t *, const uint8_t *, size_t),
+                        example_sbe_encoder_t *encoder, const char *text) {
+    CHECK_OK(encode(encoder, (const uint8_t *)text, strlen(text)));
+}
+
+static void check_text(example_sbe_error_t (*decode)(example_sbe_decoder_t *, const uint8_t **, size_t *),
+                       example_sbe_decoder_t *decoder, const char *expected) {
+    const uint8_t *value;
+    size_t length;
+
+    CHECK_OK(decode(decoder, &value, &length));
+    CHECK(length == strlen(expected));
+    CHECK(memcmp(value, expected, length) == 0);
+}
+
+static void test_encode(void) {
+    uint8_t expected[1024];
+    const size_t expected_size = original(expected, sizeof(expected));
+
+    uint8_t buffer[1024] = {0};
+    example_sbe_encoder_t encoder;
+    uint8_t *car;
+    size_t car_len;
+    CHECK_OK(baseline_car_encode(buffer, sizeof(buffer), &encoder, &car, &car_len));
+
+    const uint32_t some_numbers[] = {1, 2, 3, 4};
+    baseline_optional_extras_t extras = 0;
+    extras = baseline_optional_extras_set_sun_roof(extras, false);
+    extras = baseline_optional_extras_set_sports_pack(extras, true);
+    extras = baseline_optional_extras_set_cruise_control(extras, true);
+
+    CHECK_OK(baseline_car_set_serial_number(car, car_len, 1234));
+    CHECK_OK(baseline_car_set_model_year(car, car_len, 2013));
+    CHECK_OK(baseline_car_set_available(car, car_len, BASELINE_BOOLEAN_TYPE_T));
+    CHECK_OK(baseline_car_set_code(car, car_len, BASELINE_MODEL_A));
+    CHECK_OK(baseline_car_set_some_numbers(car, car_len, some_numbers, 4));
+    CHECK_OK(baseline_car_set_vehicle_code(car, car_len, "abcdef", 6));
+    CHECK_OK(baseline_car_set_extras(car, car_len, extras));
+
+    uint8_t *engine;
+    uint8_t *booster;
+    CHECK_OK(baseline_car_set_engine(car, car_len, &engine));
+    CHECK_OK(baseline_engine_set_capacity(engine, BASELINE_ENGINE_ENCODED_LENGTH, 2000));
+    CHECK_OK(baseline_engine_set_num_cylinders(engine, BASELINE_ENGINE_ENCODED_LENGTH, 4));
+    CHECK_OK(baseline_engine_set_manufacturer_code(engine, BASELINE_ENGINE_ENCODED_LENGTH, "123", 3));
+    CHECK_OK(baseline_engine_set_efficiency(engine, BASELINE_ENGINE_ENCODED_LENGTH, 35));
+    CHECK_OK(baseline_engine_set_booster_enabled(engine, BASELINE_ENGINE_ENCODED_LENGTH, BASELINE_BOOLEAN_TYPE_T));
+    CHECK_OK(baseline_engine_set_booster(engine, BASELINE_ENGINE_ENCODED_LENGTH, &booster));
+    CHECK_OK(baseline_booster_set_boost_type(booster, BASELINE_BOOSTER_ENCODED_LENGTH, BASELINE_BOOST_TYPE_NITROUS));
+    CHECK_OK(baseline_booster_set_horse_power(booster, BASELINE_BOOSTER_ENCODED_LENGTH, 200));
+
+    const uint16_t speeds[] = {30, 55, 75};
+    const float mpgs[] = {35.9f, 49.0f, 40.0f};
+    const char *usage_descriptions[] = {"Urban Cycle", "Combined Cycle", "Highway Cycle"};
+    example_sbe_group_encoder_t fuel_figures;
+    CHECK_OK(baseline_car_fuel_figures_encode(&encoder, &fuel_figures));
+
+    for (size_t idx = 0; idx < 3; ++idx) {
+        uint8_t *entry;
+        size_t entry_len;
+        CHECK_OK(baseline_car_fuel_figures_encode_next(&fuel_figures, &entry, &entry_len));
+        CHECK_OK(baseline_car_fuel_figures_set_speed(entry, entry_len, speeds[idx]));
+        CHECK_OK(baseline_car_fuel_figures_set_mpg(entry, entry_len, mpgs[idx]));
+        encode_text(baseline_car_fuel_figures_usage_description_encode, &encoder, usage_descriptions[idx]);
+    }
+
+    const uint8_t octane_ratings[] = {95, 99};
+    const float seconds[][3] = {{4.0f, 7.5f, 12.2f}, {3.8f, 7.1f, 11.8f}};
+    const uint16_t mphs[] = {30, 60, 100};
+    example_sbe_group_encoder_t performance_figures;
+    CHECK_OK(baseline_car_performance_figures_encode(&encoder, &performance_figures));
+
+    for (size_t idx = 0; idx < 2; ++idx) {
+        uint8_t *entry;
+        size_t entry_len;
+        CHECK_OK(baseline_car_performance_figures_encode_next(&performance_figures, &entry, &entry_len));
+        CHECK_OK(baseline_car_performance_figures_set_octane_rating(entry, entry_len, octane_ratings[idx]));
+
+        example_sbe_group_encoder_t acceleration;
+        CHECK_OK(baseline_car_performance_figures_acceleration_encode(&encoder, &acceleration));
+
+        for (size_t jdx = 0; jdx < 3; ++jdx) {
+            uint8_t *acceleration_entry;
+            size_t acceleration_len;
+            CHECK_OK(baseline_car_performance_figures_acceleration_encode_next(&acceleration, &acceleration_entry, &acceleration_len));
+            CHECK_OK(baseline_car_performance_figures_acceleration_set_mph(acceleration_entry, acceleration_len, mphs[jdx]));
+            CHECK_OK(baseline_car_performance_figures_acceleration_set_seconds(acceleration_entry, acceleration_len, seconds[idx][jdx]));
+        }
+    }
+
+    encode_text(baseline_car_manufacturer_encode, &encoder, "Honda");
+    encode_text(baseline_car_model_encode, &encoder, "Civic VTi");
+    encode_text(baseline_car_activation_code_encode, &encoder, "abcdef");
+
+    CHECK(encoder.position == expected_size);
+    CHECK(memcmp(buffer, expected, expected_size) == 0);
+}
+
+static void test_decode(void) {
+    uint8_t buffer[1024];
+    const size_t size = original(buffer, sizeof(buffer));
+
+    example_sbe_decoder_t decoder;
+    const uint8_t *car;
+    size_t car_len;
+    CHECK_OK(baseline_car_decode(buffer, size, &decoder, &car, &car_len));
+
+    uint64_t serial_number;
+    uint16_t model_year;
+    baseline_boolean_type_t available;
+    baseline_model_t code;
+    uint32_t some_numbers[BASELINE_CAR_SOME_NUMBERS_LENGTH];
+    char vehicle_code[BASELINE_CAR_VEHICLE_CODE_LENGTH];
+    baseline_optional_extras_t extras;
+
+    CHECK_OK(baseline_car_get_serial_number(car, car_len, &serial_number));
+    CHECK(serial_number == 1234);
+    CHECK_OK(baseline_car_get_model_year(car, car_len, &model_year));
+    CHECK(model_year == 2013);
+    CHECK_OK(baseline_car_get_available(car, car_len, &available));
+    CHECK(available == BASELINE_BOOLEAN_TYPE_T);
+    CHECK_OK(baseline_car_get_code(car, car_len, &code));
+    CHECK(code == BASELINE_MODEL_A);
+    CHECK_OK(baseline_car_get_some_numbers(car, car_len, some_numbers));
+    CHECK(some_numbers[0] == 1 && some_numbers[1] == 2 && some_numbers[2] == 3 && some_numbers[3] == 4);
+    CHECK_OK(baseline_car_get_vehicle_code(car, car_len, vehicle_code));
+    CHECK(memcmp(vehicle_code, "abcdef", 6) == 0);
+    CHECK_OK(baseline_car_get_extras(car, car_len, &extras));
+    CHECK(!baseline_optional_extras_get_sun_roof(extras));
+    CHECK(baseline_optional_extras_get_sports_pack(extras));
+    CHECK(baseline_optional_extras_get_cruise_control(extras));
+
+    const uint8_t *engine;
+    const uint8_t *booster;
+    uint16_t capacity;
+    uint8_t num_cylinders;
+    char manufacturer_code[BASELINE_ENGINE_MANUFACTURER_CODE_LENGTH];
+    int8_t efficiency;
+    baseline_boolean_type_t booster_enabled;
+    baseline_boost_type_t boost_type;
+    uint8_t horse_power;
+
+    CHECK_OK(baseline_car_get_engine(car, car_len, &engine));
+    CHECK_OK(baseline_engine_get_capacity(engine, BASELINE_ENGINE_ENCODED_LENGTH, &capacity));
+    CHECK(capacity == 2000);
+    CHECK_OK(baseline_engine_get_num_cylinders(engine, BASELINE_ENGINE_ENCODED_LENGTH, &num_cylinders));
+    CHECK(num_cylinders == 4);
+    CHECK_OK(baseline_engine_get_manufacturer_code(engine, BASELINE_ENGINE_ENCODED_LENGTH, manufacturer_code));
+    CHECK(memcmp(manufacturer_code, "123", 3) == 0);
+    CHECK_OK(baseline_engine_get_efficiency(engine, BASELINE_ENGINE_ENCODED_LENGTH, &efficiency));
+    CHECK(efficiency == 35);
+    CHECK_OK(baseline_engine_get_booster_enabled(engine, BASELINE_ENGINE_ENCODED_LENGTH, &booster_enabled));
+    CHECK(booster_enabled == BASELINE_BOOLEAN_TYPE_T);
+    CHECK_OK(baseline_engine_get_booster(engine, BASELINE_ENGINE_ENCODED_LENGTH, &booster));
+    CHECK_OK(baseline_booster_get_boost_type(booster, BASELINE_BOOSTER_ENCODED_LENGTH, &boost_type));
+    CHECK(boost_type == BASELINE_BOOST_TYPE_NITROUS);
+    CHECK_OK(baseline_booster_get_horse_power(booster, BASELINE_BOOSTER_ENCODED_LENGTH, &horse_power));
+    CHECK(horse_power == 200);
+
+    const uint16_t speeds[] = {30, 55, 75};
+    const float mpgs[] = {35.9f, 49.0f, 40.0f};
+    const char *usage_descriptions[] = {"Urban Cycle", "Combined Cycle", "Highway Cycle"};
+    example_sbe_group_decoder_t fuel_figures;
+    CHECK_OK(baseline_car_fuel_figures_decode(&decoder, &fuel_figures));
+    CHECK(fuel_figures.num_in_group == 3);
+
+    for (size_t idx = 0; idx < 3; ++idx) {
+        const uint8_t *entry;
+        size_t entry_len;
+        uint16_t speed;
+        float mpg;
+        CHECK_OK(baseline_car_fuel_figures_decode_next(&fuel_figures, &entry, &entry_len));
+        CHECK_OK(baseline_car_fuel_figures_get_speed(entry, entry_len, &speed));
+        CHECK(speed == speeds[idx]);
+        CHECK_OK(baseline_car_fuel_figures_get_mpg(entry, entry_len, &mpg));
+        CHECK(mpg == mpgs[idx]);
+        check_text(baseline_car_fuel_figures_usage_description_decode, &decoder, usage_descriptions[idx]);
+    }
+
+    const uint8_t octane_ratings[] = {95, 99};
+    const float seconds[][3] = {{4.0f, 7.5f, 12.2f}, {3.8f, 7.1f, 11.8f}};
+    const uint16_t mphs[] = {30, 60, 100};
+    example_sbe_group_decoder_t performance_figures;
+    CHECK_OK(baseline_car_performance_figures_decode(&decoder, &performance_figures));
+    CHECK(performance_figures.num_in_group == 2);
+
+    for (size_t idx = 0; idx < 2; ++idx) {
+        const uint8_t *entry;
+        size_t entry_len;
+        uint8_t octane_rating;
+        CHECK_OK(baseline_car_performance_figures_decode_next(&performance_figures, &entry, &entry_len));
+        CHECK_OK(baseline_car_performance_figures_get_octane_rating(entry, entry_len, &octane_rating));
+        CHECK(octane_rating == octane_ratings[idx]);
+
+        example_sbe_group_decoder_t acceleration;
+        CHECK_OK(baseline_car_performance_figures_acceleration_decode(&decoder, &acceleration));
+        CHECK(acceleration.num_in_group == 3);
+
+        for (size_t jdx = 0; jdx < 3; ++jdx) {
+            const uint8_t *acceleration_entry;
+            size_t acceleration_len;
+            uint16_t mph;
+            float acceleration_seconds;
+            CHECK_OK(baseline_car_performance_figures_acceleration_decode_next(&acceleration, &acceleration_entry, &acceleration_len));
+            CHECK_OK(baseline_car_performance_figures_acceleration_get_mph(acceleration_entry, acceleration_len, &mph));
+            CHECK(mph == mphs[jdx]);
+            CHECK_OK(baseline_car_performance_figures_acceleration_get_seconds(acceleration_entry, acceleration_len, &acceleration_seconds));
+            CHECK(acceleration_seconds == seconds[idx][jdx]);
+        }
+
+        const uint8_t *unused;
+        size_t unused_len;
+        CHECK(baseline_car_performance_figures_acceleration_decode_next(&acceleration, &unused, &unused_len) == EXAMPLE_SBE_GROUP_OUT_OF_BOUNDS);
+    }
+
+    check_text(baseline_car_manufacturer_decode, &decoder, "Honda");
+    check_text(baseline_car_model_decode, &decoder, "Civic VTi");
+    check_text(baseline_car_activation_code_decode, &decoder, "abcdef");
+    CHECK(decoder.position == size);
+}
+
+static void test_any_message_decode(void) {
+    uint8_t buffer[1024];
+    const size_t size = original(buffer, sizeof(buffer));
+
+    uint16_t template_id;
+    CHECK_OK(baseline_template_id(buffer, size, &template_id));
+    CHECK(template_id == BASELINE_CAR_TEMPLATE_ID);
+
+    buffer[2] = 999 & 0xff;
+    buffer[3] = 999 >> 8;
+    CHECK(baseline_template_id(buffer, size, &template_id) == EXAMPLE_SBE_UNKNOWN_MESSAGE_TYPE);
+
+    example_sbe_decoder_t decoder;
+    const uint8_t *car;
+    size_t car_len;
+    CHECK(baseline_car_decode(buffer, size, &decoder, &car, &car_len) == EXAMPLE_SBE_WRONG_MESSAGE_TYPE);
+    CHECK(strcmp(example_sbe_error_message(EXAMPLE_SBE_WRONG_MESSAGE_TYPE), "received message had wrong type") == 0);
+
+    buffer[2] = BASELINE_CAR_TEMPLATE_ID;
+    buffer[3] = 0;
+    buffer[4] = 2;
+    CHECK(baseline_template_id(buffer, size, &template_id) == EXAMPLE_SBE_WRONG_SCHEMA);
+    CHECK(baseline_car_decode(buffer, size, &decoder, &car, &car_len) == EXAMPLE_SBE_WRONG_SCHEMA);
+}
+
+static void test_errors(void) {
+    uint8_t buffer[1024] = {0};
+    example_sbe_encoder_t encoder;
+    uint8_t *car;
+    size_t car_len;
+    CHECK_OK(baseline_car_encode(buffer, sizeof(buffer), &encoder, &car, &car_len));
+
+    const uint32_t some_numbers[] = {1, 2, 3};
+    CHECK(baseline_car_set_some_numbers(car, car_len, some_numbers, 3) == EXAMPLE_SBE_WRONG_SLICE_SIZE);
+    CHECK(baseline_car_set_vehicle_code(car, car_len, "abcdefg", 7) == EXAMPLE_SBE_VALUE_OUT_OF_BOUNDS);
+    CHECK(baseline_car_set_code(car, car_len, 'Z') == EXAMPLE_SBE_INVALID_ENUM_VALUE);
+    CHECK(baseline_car_set_serial_number(car, 4, 1234) == EXAMPLE_SBE_CODEC_OUT_OF_BOUNDS);
+
+    baseline_model_t code;
+    car[11] = 'Z';
+    CHECK(baseline_car_get_code(car, car_len, &code) == EXAMPLE_SBE_INVALID_ENUM_VALUE);
+
+    example_sbe_decoder_t decoder;
+    const uint8_t *block;
+    size_t block_len;
+    CHECK(baseline_car_decode(buffer, 8, &decoder, &block, &block_len) == EXAMPLE_SBE_CODEC_OUT_OF_BOUNDS);
+}
+
+int main(void) {
+    test_encode();
+    test_decode();
+    test_any_message_decode();
+    test_errors();
+    return EXIT_SUCCESS;
+}
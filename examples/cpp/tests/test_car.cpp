@@ -0,0 +1,239 @@
+#include "example/baseline.hpp"
+
+#include <gtest/gtest.h>
+
+#include <array>
+#include <cstdint>
+#include <fstream>
+#include <iterator>
+#include <string>
+#include <vector>
+
+using namespace example;
+using namespace example::baseline;
+
+namespace {
+
+std::vector<std::uint8_t> original() {
+    std::ifstream stream("tests/static/example_schema_car.sbe", std::ios::binary);
+    return std::vector<std::uint8_t>(std::istreambuf_iterator<char>(stream), std::istreambuf_iterator<char>());
+}
+
+std::string text(const CarDecoder::FuelFiguresDecoder::UsageDescriptionDecoder &decoder) {
+    return std::string(decoder.get_slice_at(0, decoder.length()));
+}
+
+class SerialNumberVisitor : public MessageVisitor {
+public:
+    void on_car(CarDecoder &decoder) override {
+        serial_numbers.push_back(decoder.serial_number());
+    }
+
+    std::vector<std::uint64_t> serial_numbers;
+};
+
+} // namespace
+
+TEST(CarTest, Encode) {
+    std::vector<std::uint8_t> buffer(1024);
+    CarEncoder car(buffer.data(), buffer.size());
+
+    car.serial_number(1234);
+    car.model_year(2013);
+    car.available(BooleanType::T);
+    car.code(Model::A);
+    car.some_numbers({1, 2, 3, 4});
+    car.vehicle_code("abcdef");
+    car.extras(OptionalExtras().set_sun_roof(false).set_sports_pack(true).set_cruise_control(true));
+
+    car.engine_encoder([](auto &engine) {
+        engine.capacity(2000);
+        engine.num_cylinders(4);
+        engine.manufacturer_code("123");
+        engine.efficiency(35);
+        engine.booster_enabled(BooleanType::T);
+        engine.booster_encoder([](auto &booster) {
+            booster.boost_type(BoostType::Nitrous);
+            booster.horse_power(200);
+        });
+    });
+
+    car.fuel_figures_encoder([](auto &fuel_figures) {
+        fuel_figures.speed(30);
+        fuel_figures.mpg(35.9f);
+        fuel_figures.usage_description_encoder([](auto &usage_description) { usage_description.put_slice_at(0, "Urban Cycle"); });
+        fuel_figures.advance();
+
+        fuel_figures.speed(55);
+        fuel_figures.mpg(49.0f);
+        fuel_figures.usage_description_encoder([](auto &usage_description) { usage_description.put_slice_at(0, "Combined Cycle"); });
+        fuel_figures.advance();
+
+        fuel_figures.speed(75);
+        fuel_figures.mpg(40.0f);
+        fuel_figures.usage_description_encoder([](auto &usage_description) { usage_description.put_slice_at(0, "Highway Cycle"); });
+        fuel_figures.advance();
+    });
+
+    car.performance_figures_encoder([](auto &performance_figures) {
+        performance_figures.octane_rating(95);
+        performance_figures.acceleration_encoder([](auto &acceleration) {
+            acceleration.mph(30);
+            acceleration.seconds(4.0f);
+            acceleration.advance();
+
+            acceleration.mph(60);
+            acceleration.seconds(7.5f);
+            acceleration.advance();
+
+            acceleration.mph(100);
+            acceleration.seconds(12.2f);
+            acceleration.advance();
+        });
+        performance_figures.advance();
+
+        performance_figures.octane_rating(99);
+        performance_figures.acceleration_encoder([](auto &acceleration) {
+            acceleration.mph(30);
+            acceleration.seconds(3.8f);
+            acceleration.advance();
+
+            acceleration.mph(60);
+            acceleration.seconds(7.1f);
+            acceleration.advance();
+
+            acceleration.mph(100);
+            acceleration.seconds(11.8f);
+            acceleration.advance();
+        });
+        performance_figures.advance();
+    });
+
+    car.manufacturer_encoder([](auto &manufacturer) { manufacturer.put_slice_at(0, "Honda"); });
+    car.model_encoder([](auto &model) { model.put_slice_at(0, "Civic VTi"); });
+    car.activation_code_encoder([](auto &activation_code) { activation_code.put_slice_at(0, "abcdef"); });
+
+    ASSERT_TRUE(car.size().has_value());
+    buffer.resize(*car.size());
+    EXPECT_EQ(original(), buffer);
+}
+
+TEST(CarTest, Decode) {
+    const std::vector<std::uint8_t> buffer = original();
+    CarDecoder car(buffer.data(), buffer.size());
+
+    EXPECT_EQ(1234u, car.serial_number());
+    EXPECT_EQ(2013u, car.model_year());
+    EXPECT_EQ(BooleanType::T, car.available());
+    EXPECT_EQ(Model::A, car.code());
+    EXPECT_EQ((std::array<std::uint32_t, 4>{1, 2, 3, 4}), car.some_numbers());
+    EXPECT_EQ("abcdef", car.vehicle_code());
+
+    const OptionalExtras extras = car.extras();
+    EXPECT_FALSE(extras.get_sun_roof());
+    EXPECT_TRUE(extras.get_sports_pack());
+    EXPECT_TRUE(extras.get_cruise_control());
+
+    car.engine_decoder([](const auto &engine) {
+        EXPECT_EQ(2000u, engine.capacity());
+        EXPECT_EQ(4u, engine.num_cylinders());
+        EXPECT_EQ("123", engine.manufacturer_code());
+        EXPECT_EQ(35, engine.efficiency());
+        EXPECT_EQ(BooleanType::T, engine.booster_enabled());
+
+        engine.booster_decoder([](const auto &booster) {
+            EXPECT_EQ(BoostType::Nitrous, booster.boost_type());
+            EXPECT_EQ(200u, booster.horse_power());
+        });
+    });
+
+    car.fuel_figures_decoder([](auto &fuel_figures) {
+        EXPECT_EQ(30u, fuel_figures.speed());
+        EXPECT_EQ(35.9f, fuel_figures.mpg());
+        EXPECT_EQ("Urban Cycle", fuel_figures.usage_description_decoder(text));
+        fuel_figures.advance();
+
+        EXPECT_EQ(55u, fuel_figures.speed());
+        EXPECT_EQ(49.0f, fuel_figures.mpg());
+        EXPECT_EQ("Combined Cycle", fuel_figures.usage_description_decoder(text));
+        fuel_figures.advance();
+
+        EXPECT_EQ(75u, fuel_figures.speed());
+        EXPECT_EQ(40.0f, fuel_figures.mpg());
+        EXPECT_EQ("Highway Cycle", fuel_figures.usage_description_decoder(text));
+        fuel_figures.advance();
+    });
+
+    car.performance_figures_decoder([](auto &performance_figures) {
+        EXPECT_EQ(95u, performance_figures.octane_rating());
+        performance_figures.acceleration_decoder([](auto &acceleration) {
+            EXPECT_EQ(30u, acceleration.mph());
+            EXPECT_EQ(4.0f, acceleration.seconds());
+            acceleration.advance();
+
+            EXPECT_EQ(60u, acceleration.mph());
+            EXPECT_EQ(7.5f, acceleration.seconds());
+            acceleration.advance();
+
+            EXPECT_EQ(100u, acceleration.mph());
+            EXPECT_EQ(12.2f, acceleration.seconds());
+            acceleration.advance();
+        });
+        performance_figures.advance();
+
+        EXPECT_EQ(99u, performance_figures.octane_rating());
+        performance_figures.acceleration_decoder([](auto &acceleration) {
+            EXPECT_EQ(30u, acceleration.mph());
+            EXPECT_EQ(3.8f, acceleration.seconds());
+            acceleration.advance();
+
+            EXPECT_EQ(60u, acceleration.mph());
+            EXPECT_EQ(7.1f, acceleration.seconds());
+            acceleration.advance();
+
+            EXPECT_EQ(100u, acceleration.mph());
+            EXPECT_EQ(11.8f, acceleration.seconds());
+            acceleration.advance();
+        });
+        performance_figures.advance();
+    });
+}
+
+TEST(CarTest, AnyMessageDecode) {
+    const std::vector<std::uint8_t> buffer = original();
+    SerialNumberVisitor visitor;
+
+    AnyMessageDecoder message(buffer.data(), buffer.size());
+    EXPECT_EQ(CarDecoder::id(), message.template_id());
+
+    message.accept(visitor);
+    EXPECT_EQ(std::vector<std::uint64_t>{1234}, visitor.serial_numbers);
+
+    std::vector<std::uint8_t> unknown = original();
+    unknown[2] = 999 & 0xff;
+    unknown[3] = 999 >> 8;
+
+    try {
+        AnyMessageDecoder(unknown.data(), unknown.size());
+        FAIL() << "expected an unknown message type error";
+    } catch (const SbeError &error) {
+        EXPECT_EQ(SbeError::Kind::UnknownMessageType, error.kind());
+    }
+
+    std::vector<std::uint8_t> foreign = original();
+    foreign[4] = 2;
+
+    try {
+        AnyMessageDecoder(foreign.data(), foreign.size());
+        FAIL() << "expected a wrong schema error";
+    } catch (const SbeError &error) {
+        EXPECT_EQ(SbeError::Kind::WrongSchema, error.kind());
+    }
+
+    try {
+        CarDecoder(foreign.data(), foreign.size());
+        FAIL() << "expected a wrong schema error";
+    } catch (const SbeError &error) {
+        EXPECT_EQ(SbeError::Kind::WrongSchema, error.kind());
+    }
+}
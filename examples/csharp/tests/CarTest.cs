@@ -0,0 +1,248 @@
+using System;
+using System.Buffers.Binary;
+using System.Collections.Generic;
+using System.IO;
+using System.Text;
+using Example.Baseline;
+using Xunit;
+
+namespace Example.Tests;
+
+public class CarTest
+{
+    private static byte[] Original()
+    {
+        return File.ReadAllBytes(Path.Combine(AppContext.BaseDirectory, "tests", "example_schema_car.sbe"));
+    }
+
+    private static string Text(ReadOnlySpan<byte> value)
+    {
+        return Encoding.ASCII.GetString(value);
+    }
+
+    private static byte[] Bytes(string value)
+    {
+        return Encoding.ASCII.GetBytes(value);
+    }
+
+    [Fact]
+    public void EncodesACar()
+    {
+        byte[] buffer = new byte[1024];
+        CarEncoder car = new CarEncoder(buffer);
+
+        car.SerialNumber(1234);
+        car.ModelYear(2013);
+        car.Available(BooleanType.T);
+        car.Code(Model.A);
+        car.SomeNumbers(new uint[] { 1, 2, 3, 4 });
+        car.VehicleCode("abcdef");
+        car.Extras(OptionalExtras.SportsPack | OptionalExtras.CruiseControl);
+
+        EngineEncoder engine = car.Engine();
+        engine.Capacity(2000);
+        engine.NumCylinders(4);
+        engine.ManufacturerCode("123");
+        engine.Efficiency(35);
+        engine.BoosterEnabled(BooleanType.T);
+
+        BoosterEncoder booster = engine.Booster();
+        booster.BoostType(BoostType.Nitrous);
+        booster.HorsePower(200);
+
+        (ushort Speed, float Mpg, string UsageDescription)[] fuelFigureValues =
+        {
+            (30, 35.9f, "Urban Cycle"),
+            (55, 49.0f, "Combined Cycle"),
+            (75, 40.0f, "Highway Cycle"),
+        };
+
+        CarEncoder.FuelFiguresEncoder fuelFigures = car.FuelFigures();
+        foreach (var value in fuelFigureValues)
+        {
+            fuelFigures.Speed(value.Speed);
+            fuelFigures.Mpg(value.Mpg);
+            fuelFigures.UsageDescription(Bytes(value.UsageDescription));
+            fuelFigures.Advance();
+        }
+
+        (byte OctaneRating, (ushort Mph, float Seconds)[] Accelerations)[] performanceFigureValues =
+        {
+            (95, new (ushort, float)[] { (30, 4.0f), (60, 7.5f), (100, 12.2f) }),
+            (99, new (ushort, float)[] { (30, 3.8f), (60, 7.1f), (100, 11.8f) }),
+        };
+
+        CarEncoder.PerformanceFiguresEncoder performanceFigures = car.PerformanceFigures();
+        foreach (var value in performanceFigureValues)
+        {
+            performanceFigures.OctaneRating(value.OctaneRating);
+
+            CarEncoder.PerformanceFiguresEncoder.AccelerationEncoder acceleration = performanceFigures.Acceleration();
+            foreach (var (mph, seconds) in value.Accelerations)
+            {
+                acceleration.Mph(mph);
+                acceleration.Seconds(seconds);
+                acceleration.Advance();
+            }
+
+            performanceFigures.Advance();
+        }
+
+        car.Manufacturer(Bytes("Honda"));
+        car.Model(Bytes("Civic VTi"));
+        car.ActivationCode(Bytes("abcdef"));
+
+        Assert.Equal(Original(), buffer.AsSpan(0, car.Size()).ToArray());
+    }
+
+    [Fact]
+    public void DecodesACar()
+    {
+        byte[] original = Original();
+        CarDecoder car = new CarDecoder(original);
+
+        Assert.Equal(0, car.ActingVersion());
+        Assert.Equal(CarEncoder.BlockLength, car.BlockLength());
+        Assert.Equal(1234UL, car.SerialNumber());
+        Assert.Equal(2013, car.ModelYear());
+        Assert.Equal(BooleanType.T, car.Available());
+        Assert.Equal(Model.A, car.Code());
+        Assert.Equal(new uint[] { 1, 2, 3, 4 }, car.SomeNumbers());
+        Assert.Equal("abcdef", car.VehicleCode());
+        Assert.Equal(Model.C, car.DiscountedModel());
+        Assert.Equal(OptionalExtras.SportsPack | OptionalExtras.CruiseControl, car.Extras());
+
+        EngineDecoder engine = car.Engine();
+        Assert.Equal(2000, engine.Capacity());
+        Assert.Equal(4, engine.NumCylinders());
+        Assert.Equal(9000, engine.MaxRpm());
+        Assert.Equal("123", engine.ManufacturerCode());
+        Assert.Equal("Petrol", engine.Fuel());
+        Assert.Equal(35, engine.Efficiency());
+        Assert.Equal(BooleanType.T, engine.BoosterEnabled());
+
+        BoosterDecoder booster = engine.Booster();
+        Assert.Equal(BoostType.Nitrous, booster.BoostType());
+        Assert.Equal(200, booster.HorsePower());
+
+        var fuelFigures = new List<(ushort, float, string)>();
+        foreach (CarDecoder.FuelFiguresDecoder entry in car.FuelFigures())
+        {
+            fuelFigures.Add((entry.Speed(), entry.Mpg(), Text(entry.UsageDescription())));
+        }
+        Assert.Equal(
+            new List<(ushort, float, string)>
+            {
+                (30, 35.9f, "Urban Cycle"),
+                (55, 49.0f, "Combined Cycle"),
+                (75, 40.0f, "Highway Cycle"),
+            },
+            fuelFigures);
+
+        var performanceFigures = new List<(byte, List<(ushort, float)>)>();
+        foreach (CarDecoder.PerformanceFiguresDecoder entry in car.PerformanceFigures())
+        {
+            var accelerations = new List<(ushort, float)>();
+            foreach (CarDecoder.PerformanceFiguresDecoder.AccelerationDecoder acceleration in entry.Acceleration())
+            {
+                accelerations.Add((acceleration.Mph(), acceleration.Seconds()));
+            }
+
+            performanceFigures.Add((entry.OctaneRating(), accelerations));
+        }
+        Assert.Equal(2, performanceFigures.Count);
+        Assert.Equal((byte)95, performanceFigures[0].Item1);
+        Assert.Equal(new List<(ushort, float)> { (30, 4.0f), (60, 7.5f), (100, 12.2f) }, performanceFigures[0].Item2);
+        Assert.Equal((byte)99, performanceFigures[1].Item1);
+        Assert.Equal(new List<(ushort, float)> { (30, 3.8f), (60, 7.1f), (100, 11.8f) }, performanceFigures[1].Item2);
+
+        Assert.Equal("Honda", Text(car.Manufacturer()));
+        Assert.Equal("Civic VTi", Text(car.Model()));
+        Assert.Equal("abcdef", Text(car.ActivationCode()));
+        Assert.Equal(original.Length - 8, car.Size());
+    }
+
+    [Fact]
+    public void DecodesFieldsInAnyOrder()
+    {
+        CarDecoder car = new CarDecoder(Original());
+
+        Assert.Equal("abcdef", Text(car.ActivationCode()));
+        Assert.Equal(2, car.PerformanceFigures().NumInGroup());
+        Assert.Equal("Honda", Text(car.Manufacturer()));
+        Assert.Equal(3, car.FuelFigures().NumInGroup());
+    }
+
+    [Fact]
+    public void RejectsValuesOutOfBounds()
+    {
+        CarEncoder car = new CarEncoder(new byte[1024]);
+        CarEncoder.PerformanceFiguresEncoder performanceFigures = car.PerformanceFigures();
+        SbeException? error = null;
+
+        try
+        {
+            performanceFigures.OctaneRating(80);
+        }
+        catch (SbeException e)
+        {
+            error = e;
+        }
+
+        Assert.Equal(SbeErrorKind.ValueOutOfBounds, error?.Kind);
+    }
+
+    private sealed class SerialNumberVisitor : IMessageVisitor
+    {
+        public ulong? SerialNumber { get; private set; }
+
+        public void OnCar(CarDecoder decoder)
+        {
+            SerialNumber = decoder.SerialNumber();
+        }
+    }
+
+    [Fact]
+    public void DispatchesMessagesByTemplateId()
+    {
+        AnyMessageDecoder message = new AnyMessageDecoder(Original());
+        Assert.Equal(CarDecoder.TemplateId, message.TemplateId());
+
+        SerialNumberVisitor visitor = new SerialNumberVisitor();
+        message.Accept(visitor);
+        Assert.Equal(1234UL, visitor.SerialNumber);
+
+        byte[] unknown = Original();
+        BinaryPrimitives.WriteUInt16LittleEndian(unknown.AsSpan(2), 999);
+
+        SbeException unknownError = Assert.Throws<SbeException>(() => { new AnyMessageDecoder(unknown); });
+        Assert.Equal(SbeErrorKind.UnknownMessageType, unknownError.Kind);
+
+        SbeException wrongError = Assert.Throws<SbeException>(() => { new CarDecoder(unknown); });
+        Assert.Equal(SbeErrorKind.WrongMessageType, wrongError.Kind);
+
+        byte[] foreign = Original();
+        BinaryPrimitives.WriteUInt16LittleEndian(foreign.AsSpan(4), 2);
+
+        SbeException foreignError = Assert.Throws<SbeException>(() => { new AnyMessageDecoder(foreign); });
+        Assert.Equal(SbeErrorKind.WrongSchema, foreignError.Kind);
+
+        foreignError = Assert.Throws<SbeException>(() => { new CarDecoder(foreign); });
+        Assert.Equal(SbeErrorKind.WrongSchema, foreignError.Kind);
+    }
+
+    [Fact]
+    public void RejectsTruncatedMessages()
+    {
+        byte[] truncated = Original().AsSpan(0, 60).ToArray();
+
+        SbeException error = Assert.Throws<SbeException>(() =>
+        {
+            foreach (CarDecoder.FuelFiguresDecoder entry in new CarDecoder(truncated).FuelFigures())
+            {
+                entry.UsageDescription();
+            }
+        });
+        Assert.Equal(SbeErrorKind.CodecOutOfBounds, error.Kind);
+    }
+}
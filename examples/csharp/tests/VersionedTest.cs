@@ -0,0 +1,134 @@
+using System;
+using System.Buffers.Binary;
+using System.Collections.Generic;
+using System.Text;
+using Example.Versioned;
+using Xunit;
+
+namespace Example.Tests;
+
+public class VersionedTest
+{
+    /// <summary>Order encoded with the version 0 of the schema, which only has the order id,
+    /// the leg ids and the venue.</summary>
+    private static byte[] OrderV0()
+    {
+        byte[] frame = new byte[36];
+        Span<byte> buffer = frame;
+
+        // Header: block length, template id, schema id and version, then the order id
+        BinaryPrimitives.WriteUInt16LittleEndian(buffer.Slice(0), 8);
+        BinaryPrimitives.WriteUInt16LittleEndian(buffer.Slice(2), 1);
+        BinaryPrimitives.WriteUInt16LittleEndian(buffer.Slice(4), 2);
+        BinaryPrimitives.WriteUInt16LittleEndian(buffer.Slice(6), 0);
+        BinaryPrimitives.WriteUInt64LittleEndian(buffer.Slice(8), 42);
+
+        // Legs: block length and number of entries, then the leg ids
+        BinaryPrimitives.WriteUInt16LittleEndian(buffer.Slice(16), 4);
+        BinaryPrimitives.WriteUInt16LittleEndian(buffer.Slice(18), 2);
+        BinaryPrimitives.WriteUInt32LittleEndian(buffer.Slice(20), 7);
+        BinaryPrimitives.WriteUInt32LittleEndian(buffer.Slice(24), 8);
+
+        BinaryPrimitives.WriteUInt32LittleEndian(buffer.Slice(28), 4);
+        Encoding.ASCII.GetBytes("XNAS").CopyTo(buffer.Slice(32));
+
+        return frame;
+    }
+
+    private static string Text(ReadOnlySpan<byte> value)
+    {
+        return Encoding.ASCII.GetString(value);
+    }
+
+    private static byte[] Bytes(string value)
+    {
+        return Encoding.ASCII.GetBytes(value);
+    }
+
+    [Fact]
+    public void DecodesAnOrderOfVersion0()
+    {
+        byte[] frame = OrderV0();
+        OrderDecoder order = new OrderDecoder(frame);
+
+        Assert.Equal(0, order.ActingVersion());
+        Assert.Equal(8, order.BlockLength());
+        Assert.Equal(42UL, order.OrderId());
+        Assert.Equal(uint.MaxValue, order.Quantity());
+        Assert.Equal(Side.NullVal, order.Side());
+        Assert.Equal((OrderFlags)0, order.Flags());
+
+        var legs = new List<(uint, ushort, string)>();
+        foreach (OrderDecoder.LegsDecoder entry in order.Legs())
+        {
+            legs.Add((entry.LegId(), entry.Ratio(), Text(entry.LegNote())));
+        }
+        Assert.Equal(new List<(uint, ushort, string)> { (7, ushort.MaxValue, ""), (8, ushort.MaxValue, "") }, legs);
+
+        Assert.Equal(0, order.Fills().NumInGroup());
+        Assert.Equal("XNAS", Text(order.Venue()));
+        Assert.Equal("", Text(order.Note()));
+        Assert.Equal(frame.Length - 8, order.Size());
+    }
+
+    [Fact]
+    public void RoundTripsAnOrder()
+    {
+        byte[] buffer = new byte[1024];
+        OrderEncoder encoder = new OrderEncoder(buffer);
+
+        encoder.OrderId(42);
+        encoder.Quantity(100);
+        encoder.Side(Side.Sell);
+        encoder.Flags(OrderFlags.PostOnly);
+
+        PriceEncoder price = encoder.Price();
+        price.Mantissa(12345);
+        price.Exponent(-2);
+
+        OrderEncoder.LegsEncoder legs = encoder.Legs();
+        foreach (var (legId, ratio, legNote) in new (uint, ushort, string)[] { (7, 1, "first"), (8, 2, "second") })
+        {
+            legs.LegId(legId);
+            legs.Ratio(ratio);
+            legs.LegNote(Bytes(legNote));
+            legs.Advance();
+        }
+
+        OrderEncoder.FillsEncoder fills = encoder.Fills();
+        fills.FillQuantity(60);
+        fills.Advance();
+
+        encoder.Venue(Bytes("XNAS"));
+        encoder.Note(Bytes("urgent"));
+
+        OrderDecoder order = new OrderDecoder(buffer.AsSpan(0, encoder.Size()));
+
+        Assert.Equal(Schema.Version, order.ActingVersion());
+        Assert.Equal(OrderEncoder.BlockLength, order.BlockLength());
+        Assert.Equal(42UL, order.OrderId());
+        Assert.Equal(100U, order.Quantity());
+        Assert.Equal(Side.Sell, order.Side());
+        Assert.Equal(OrderFlags.PostOnly, order.Flags());
+        Assert.Equal(12345L, order.Price().Mantissa());
+        Assert.Equal((sbyte)-2, order.Price().Exponent());
+
+        var decodedLegs = new List<(uint, ushort, string)>();
+        foreach (OrderDecoder.LegsDecoder entry in order.Legs())
+        {
+            decodedLegs.Add((entry.LegId(), entry.Ratio(), Text(entry.LegNote())));
+        }
+        Assert.Equal(new List<(uint, ushort, string)> { (7, 1, "first"), (8, 2, "second") }, decodedLegs);
+
+        var decodedFills = new List<uint>();
+        foreach (OrderDecoder.FillsDecoder entry in order.Fills())
+        {
+            decodedFills.Add(entry.FillQuantity());
+        }
+        Assert.Equal(new List<uint> { 60 }, decodedFills);
+
+        Assert.Equal("XNAS", Text(order.Venue()));
+        Assert.Equal("urgent", Text(order.Note()));
+        Assert.Equal(encoder.Size() - 8, order.Size());
+    }
+}
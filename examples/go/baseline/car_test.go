@@ -0,0 +1,158 @@
+package baseline
+
+import (
+	"bytes"
+	"errors"
+	"os"
+	"reflect"
+	"testing"
+
+	"example"
+)
+
+func original(t *testing.T) []byte {
+	data, err := os.ReadFile("testdata/example_schema_car.sbe")
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	return data
+}
+
+func car() Car {
+	return Car{
+		SerialNumber: 1234,
+		ModelYear:    2013,
+		Available:    BooleanTypeT,
+		Code:         ModelA,
+		SomeNumbers:  [4]uint32{1, 2, 3, 4},
+		VehicleCode:  "abcdef",
+		Extras:       OptionalExtras(0).SetSunRoof(false).SetSportsPack(true).SetCruiseControl(true),
+		Engine: Engine{
+			Capacity:         2000,
+			NumCylinders:     4,
+			ManufacturerCode: "123",
+			Efficiency:       35,
+			BoosterEnabled:   BooleanTypeT,
+			Booster: Booster{
+				BoostType:  BoostTypeNitrous,
+				HorsePower: 200,
+			},
+		},
+		FuelFigures: []CarFuelFigures{
+			{Speed: 30, Mpg: 35.9, UsageDescription: "Urban Cycle"},
+			{Speed: 55, Mpg: 49.0, UsageDescription: "Combined Cycle"},
+			{Speed: 75, Mpg: 40.0, UsageDescription: "Highway Cycle"},
+		},
+		PerformanceFigures: []CarPerformanceFigures{
+			{
+				OctaneRating: 95,
+				Acceleration: []CarPerformanceFiguresAcceleration{
+					{Mph: 30, Seconds: 4.0},
+					{Mph: 60, Seconds: 7.5},
+					{Mph: 100, Seconds: 12.2},
+				},
+			},
+			{
+				OctaneRating: 99,
+				Acceleration: []CarPerformanceFiguresAcceleration{
+					{Mph: 30, Seconds: 3.8},
+					{Mph: 60, Seconds: 7.1},
+					{Mph: 100, Seconds: 11.8},
+				},
+			},
+		},
+		Manufacturer:   "Honda",
+		Model:          "Civic VTi",
+		ActivationCode: "abcdef",
+	}
+}
+
+func TestEncode(t *testing.T) {
+	var buffer bytes.Buffer
+	message := car()
+
+	if err := message.Encode(&buffer); err != nil {
+		t.Fatal(err)
+	}
+
+	if !bytes.Equal(original(t), buffer.Bytes()) {
+		t.Errorf("encoded %x, expected %x", buffer.Bytes(), original(t))
+	}
+}
+
+func TestDecode(t *testing.T) {
+	var message Car
+
+	if err := message.Decode(bytes.NewReader(original(t))); err != nil {
+		t.Fatal(err)
+	}
+
+	if expected := car(); !reflect.DeepEqual(expected, message) {
+		t.Errorf("decoded %+v, expected %+v", message, expected)
+	}
+
+	if message.DiscountedModel() != ModelC {
+		t.Errorf("discounted model %v, expected %v", message.DiscountedModel(), ModelC)
+	}
+
+	if !message.Extras.SportsPack() || message.Extras.SunRoof() {
+		t.Errorf("unexpected extras %08b", message.Extras)
+	}
+}
+
+func TestEncodeOutOfBounds(t *testing.T) {
+	message := car()
+	message.PerformanceFigures[0].OctaneRating = 80
+
+	err := message.Encode(&bytes.Buffer{})
+	if !errors.Is(err, example.ErrValueOutOfBounds) {
+		t.Errorf("expected a value out of bounds error, got %v", err)
+	}
+}
+
+func TestDecodeMessage(t *testing.T) {
+	message, err := DecodeMessage(bytes.NewReader(original(t)))
+	if err != nil {
+		t.Fatal(err)
+	}
+
+	decoded, ok := message.(*Car)
+	if !ok || decoded.SerialNumber != 1234 {
+		t.Errorf("unexpected message %+v", message)
+	}
+
+	unknown := original(t)
+	unknown[2] = 999 & 0xff
+	unknown[3] = 999 >> 8
+
+	if _, err := DecodeMessage(bytes.NewReader(unknown)); !errors.Is(err, example.ErrUnknownMessageType) {
+		t.Errorf("expected an unknown message type error, got %v", err)
+	}
+
+	var wrong Car
+	if err := wrong.Decode(bytes.NewReader(unknown)); !errors.Is(err, example.ErrWrongMessageType) {
+		t.Errorf("expected a wrong message type error, got %v", err)
+	}
+
+	foreign := original(t)
+	foreign[4] = 2
+
+	if _, err := DecodeMessage(bytes.NewReader(foreign)); !errors.Is(err, example.ErrWrongSchema) {
+		t.Errorf("expected a wrong schema error, got %v", err)
+	}
+
+	if err := wrong.Decode(bytes.NewReader(foreign)); !errors.Is(err, example.ErrWrongSchema) {
+		t.Errorf("expected a wrong schema error, got %v", err)
+	}
+}
+
+func TestDecodeTruncated(t *testing.T) {
+	truncated := original(t)
+	truncated = truncated[:len(truncated)-1]
+
+	var message Car
+	if err := message.Decode(bytes.NewReader(truncated)); !errors.Is(err, example.ErrCodecOutOfBounds) {
+		t.Errorf("expected a codec out of bounds error, got %v", err)
+	}
+}
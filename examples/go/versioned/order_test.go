@@ -0,0 +1,87 @@
+package versioned
+
+import (
+	"bytes"
+	"encoding/binary"
+	"math"
+	"reflect"
+	"testing"
+)
+
+// orderV0 is an Order encoded with the version 0 of the schema, which only has the order id,
+// the leg ids and the venue.
+func orderV0() []byte {
+	var frame bytes.Buffer
+
+	// Header: block length, template id, schema id and version, then the order id
+	binary.Write(&frame, binary.LittleEndian, []uint16{8, 1, 2, 0})
+	binary.Write(&frame, binary.LittleEndian, uint64(42))
+
+	// Legs: block length and number of entries, then the leg ids
+	binary.Write(&frame, binary.LittleEndian, []uint16{4, 2})
+	binary.Write(&frame, binary.LittleEndian, []uint32{7, 8})
+
+	binary.Write(&frame, binary.LittleEndian, uint32(4))
+	frame.WriteString("XNAS")
+
+	return frame.Bytes()
+}
+
+func order(price *Price) Order {
+	return Order{
+		OrderId:  42,
+		Quantity: 100,
+		Side:     SideSell,
+		Flags:    OrderFlags(0).SetPostOnly(true),
+		Price:    price,
+		Legs: []OrderLegs{
+			{LegId: 7, Ratio: 1, LegNote: "first"},
+			{LegId: 8, Ratio: 2, LegNote: "second"},
+		},
+		Fills: []OrderFills{{FillQuantity: 60}},
+		Venue: "XNAS",
+		Note:  "urgent",
+	}
+}
+
+func TestDecodeV0(t *testing.T) {
+	var message Order
+
+	if err := message.Decode(bytes.NewReader(orderV0())); err != nil {
+		t.Fatal(err)
+	}
+
+	expected := Order{
+		OrderId:  42,
+		Quantity: math.MaxUint32,
+		Side:     SideNullVal,
+		Legs: []OrderLegs{
+			{LegId: 7, Ratio: math.MaxUint16},
+			{LegId: 8, Ratio: math.MaxUint16},
+		},
+		Venue: "XNAS",
+	}
+	if !reflect.DeepEqual(expected, message) {
+		t.Errorf("decoded %+v, expected %+v", message, expected)
+	}
+}
+
+func TestRoundTrip(t *testing.T) {
+	for _, price := range []*Price{nil, {Mantissa: 12345, Exponent: -2}} {
+		var buffer bytes.Buffer
+		message := order(price)
+
+		if err := message.Encode(&buffer); err != nil {
+			t.Fatal(err)
+		}
+
+		var decoded Order
+		if err := decoded.Decode(&buffer); err != nil {
+			t.Fatal(err)
+		}
+
+		if !reflect.DeepEqual(message, decoded) {
+			t.Errorf("decoded %+v, expected %+v", decoded, message)
+		}
+	}
+}
@@ -0,0 +1,253 @@
+package example.baseline;
+
+import static org.junit.jupiter.api.Assertions.assertArrayEquals;
+import static org.junit.jupiter.api.Assertions.assertEquals;
+import static org.junit.jupiter.api.Assertions.assertFalse;
+import static org.junit.jupiter.api.Assertions.assertThrows;
+import static org.junit.jupiter.api.Assertions.assertTrue;
+
+import example.SbeException;
+import java.io.IOException;
+import java.io.InputStream;
+import java.nio.ByteBuffer;
+import java.nio.ByteOrder;
+import java.nio.charset.StandardCharsets;
+import java.util.ArrayList;
+import java.util.Arrays;
+import java.util.List;
+import org.junit.jupiter.api.Test;
+
+class CarTest {
+    private static byte[] original() throws IOException {
+        try (InputStream stream = CarTest.class.getResourceAsStream("/example_schema_car.sbe")) {
+            return stream.readAllBytes();
+        }
+    }
+
+    private static String text(CarDecoder.FuelFiguresDecoder.UsageDescriptionDecoder decoder) {
+        return new String(decoder.getSliceAt(0, decoder.length()), StandardCharsets.UTF_8);
+    }
+
+    @Test
+    void testCarEncode() throws IOException {
+        byte[] buffer = new byte[1024];
+        CarEncoder car = new CarEncoder(ByteBuffer.wrap(buffer));
+
+        car.serialNumber(1234);
+        car.modelYear(2013);
+        car.available(BooleanType.T);
+        car.code(Model.A);
+        car.someNumbers(new long[] {1, 2, 3, 4});
+        car.vehicleCode("abcdef");
+        car.extras(new OptionalExtras().setSunRoof(false).setSportsPack(true).setCruiseControl(true));
+
+        car.engineEncoder(engine -> {
+            engine.capacity(2000);
+            engine.numCylinders((short) 4);
+            engine.manufacturerCode("123");
+            engine.efficiency((byte) 35);
+            engine.boosterEnabled(BooleanType.T);
+            return engine.boosterEncoder(booster -> {
+                booster.boostType(BoostType.NITROUS);
+                booster.horsePower((short) 200);
+                return null;
+            });
+        });
+
+        car.fuelFiguresEncoder(fuelFigures -> {
+            fuelFigures.speed(30);
+            fuelFigures.mpg(35.9f);
+            fuelFigures.usageDescriptionEncoder(usageDescription -> {
+                usageDescription.putSliceAt(0, "Urban Cycle".getBytes(StandardCharsets.UTF_8));
+                return null;
+            });
+            fuelFigures.advance();
+
+            fuelFigures.speed(55);
+            fuelFigures.mpg(49.0f);
+            fuelFigures.usageDescriptionEncoder(usageDescription -> {
+                usageDescription.putSliceAt(0, "Combined Cycle".getBytes(StandardCharsets.UTF_8));
+                return null;
+            });
+            fuelFigures.advance();
+
+            fuelFigures.speed(75);
+            fuelFigures.mpg(40.0f);
+            fuelFigures.usageDescriptionEncoder(usageDescription -> {
+                usageDescription.putSliceAt(0, "Highway Cycle".getBytes(StandardCharsets.UTF_8));
+                return null;
+            });
+            fuelFigures.advance();
+            return null;
+        });
+
+        car.performanceFiguresEncoder(performanceFigures -> {
+            performanceFigures.octaneRating((short) 95);
+            performanceFigures.accelerationEncoder(acceleration -> {
+                acceleration.mph(30);
+                acceleration.seconds(4.0f);
+                acceleration.advance();
+
+                acceleration.mph(60);
+                acceleration.seconds(7.5f);
+                acceleration.advance();
+
+                acceleration.mph(100);
+                acceleration.seconds(12.2f);
+                acceleration.advance();
+                return null;
+            });
+            performanceFigures.advance();
+
+            performanceFigures.octaneRating((short) 99);
+            performanceFigures.accelerationEncoder(acceleration -> {
+                acceleration.mph(30);
+                acceleration.seconds(3.8f);
+                acceleration.advance();
+
+                acceleration.mph(60);
+                acceleration.seconds(7.1f);
+                acceleration.advance();
+
+                acceleration.mph(100);
+                acceleration.seconds(11.8f);
+                acceleration.advance();
+                return null;
+            });
+            performanceFigures.advance();
+            return null;
+        });
+
+        car.manufacturerEncoder(manufacturer -> {
+            manufacturer.putSliceAt(0, "Honda".getBytes(StandardCharsets.UTF_8));
+            return null;
+        });
+        car.modelEncoder(model -> {
+            model.putSliceAt(0, "Civic VTi".getBytes(StandardCharsets.UTF_8));
+            return null;
+        });
+        car.activationCodeEncoder(activationCode -> {
+            activationCode.putSliceAt(0, "abcdef".getBytes(StandardCharsets.UTF_8));
+            return null;
+        });
+
+        assertArrayEquals(original(), Arrays.copyOf(buffer, car.size()));
+    }
+
+    @Test
+    void testCarDecode() throws IOException {
+        CarDecoder car = new CarDecoder(ByteBuffer.wrap(original()));
+
+        assertEquals(1234, car.serialNumber());
+        assertEquals(2013, car.modelYear());
+        assertEquals(BooleanType.T, car.available());
+        assertEquals(Model.A, car.code());
+        assertArrayEquals(new long[] {1, 2, 3, 4}, car.someNumbers());
+        assertEquals("abcdef", car.vehicleCode());
+
+        OptionalExtras extras = car.extras();
+        assertFalse(extras.getSunRoof());
+        assertTrue(extras.getSportsPack());
+        assertTrue(extras.getCruiseControl());
+
+        car.engineDecoder(engine -> {
+            assertEquals(2000, engine.capacity());
+            assertEquals(4, engine.numCylinders());
+            assertEquals("123", engine.manufacturerCode());
+            assertEquals(35, engine.efficiency());
+            assertEquals(BooleanType.T, engine.boosterEnabled());
+
+            return engine.boosterDecoder(booster -> {
+                assertEquals(BoostType.NITROUS, booster.boostType());
+                assertEquals(200, booster.horsePower());
+                return null;
+            });
+        });
+
+        car.fuelFiguresDecoder(fuelFigures -> {
+            assertEquals(30, fuelFigures.speed());
+            assertEquals(35.9f, fuelFigures.mpg());
+            assertEquals("Urban Cycle", fuelFigures.usageDescriptionDecoder(CarTest::text));
+            fuelFigures.advance();
+
+            assertEquals(55, fuelFigures.speed());
+            assertEquals(49.0f, fuelFigures.mpg());
+            assertEquals("Combined Cycle", fuelFigures.usageDescriptionDecoder(CarTest::text));
+            fuelFigures.advance();
+
+            assertEquals(75, fuelFigures.speed());
+            assertEquals(40.0f, fuelFigures.mpg());
+            assertEquals("Highway Cycle", fuelFigures.usageDescriptionDecoder(CarTest::text));
+            fuelFigures.advance();
+            return null;
+        });
+
+        car.performanceFiguresDecoder(performanceFigures -> {
+            assertEquals(95, performanceFigures.octaneRating());
+            performanceFigures.accelerationDecoder(acceleration -> {
+                assertEquals(30, acceleration.mph());
+                assertEquals(4.0f, acceleration.seconds());
+                acceleration.advance();
+
+                assertEquals(60, acceleration.mph());
+                assertEquals(7.5f, acceleration.seconds());
+                acceleration.advance();
+
+                assertEquals(100, acceleration.mph());
+                assertEquals(12.2f, acceleration.seconds());
+                acceleration.advance();
+                return null;
+            });
+            performanceFigures.advance();
+
+            assertEquals(99, performanceFigures.octaneRating());
+            performanceFigures.accelerationDecoder(acceleration -> {
+                assertEquals(30, acceleration.mph());
+                assertEquals(3.8f, acceleration.seconds());
+                acceleration.advance();
+
+                assertEquals(60, acceleration.mph());
+                assertEquals(7.1f, acceleration.seconds());
+                acceleration.advance();
+
+                assertEquals(100, acceleration.mph());
+                assertEquals(11.8f, acceleration.seconds());
+                acceleration.advance();
+                return null;
+            });
+            performanceFigures.advance();
+            return null;
+        });
+    }
+
+    @Test
+    void testAnyMessageDecode() throws IOException {
+        List<Long> serialNumbers = new ArrayList<>();
+        MessageVisitor visitor = new MessageVisitor() {
+            @Override
+            public void onCar(CarDecoder decoder) {
+                serialNumbers.add(decoder.serialNumber());
+            }
+        };
+
+        AnyMessageDecoder message = new AnyMessageDecoder(ByteBuffer.wrap(original()));
+        assertEquals(CarDecoder.id(), message.templateId());
+
+        message.accept(visitor);
+        assertEquals(List.of(1234L), serialNumbers);
+
+        byte[] unknown = original();
+        ByteBuffer.wrap(unknown).order(ByteOrder.LITTLE_ENDIAN).putShort(2, (short) 999);
+
+        SbeException error = assertThrows(SbeException.class, () -> new AnyMessageDecoder(ByteBuffer.wrap(unknown)));
+        assertEquals(SbeException.Kind.UNKNOWN_MESSAGE_TYPE, error.kind());
+
+        byte[] foreign = original();
+        ByteBuffer.wrap(foreign).order(ByteOrder.LITTLE_ENDIAN).putShort(4, (short) 2);
+
+        error = assertThrows(SbeException.class, () -> new AnyMessageDecoder(ByteBuffer.wrap(foreign)));
+        assertEquals(SbeException.Kind.WRONG_SCHEMA, error.kind());
+        error = assertThrows(SbeException.class, () -> new CarDecoder(ByteBuffer.wrap(foreign)));
+        assertEquals(SbeException.Kind.WRONG_SCHEMA, error.kind());
+    }
+}
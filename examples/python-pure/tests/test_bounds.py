@@ -0,0 +1,22 @@
+import pytest
+from example.bounds import Rating
+
+# Offset of the score after the header, levels, discount and targets
+SCORE_OFFSET = 8 + 3 + 1 + 4
+
+
+def make_rating(score: int | None) -> Rating:
+    return Rating(levels=[1, 1, 1], discount=None, targets=None, score=score)
+
+
+def test_rating_encode_custom_null_value():
+    # The score declares 0 as its null value instead of 255
+    assert make_rating(None).to_bytes()[SCORE_OFFSET] == 0
+
+
+@pytest.mark.parametrize("encoded, score", [(0, None), (255, 255), (7, 7)])
+def test_rating_decode_custom_null_value(encoded: int, score: int | None):
+    buffer = bytearray(make_rating(None).to_bytes())
+    buffer[SCORE_OFFSET] = encoded
+
+    assert Rating.from_bytes(bytes(buffer)).score == score
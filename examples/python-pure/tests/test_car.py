@@ -0,0 +1,96 @@
+import pytest
+from conftest import load_file, make_car
+from example.error import SbeError
+from example.baseline import (
+    Car,
+    Model,
+    BoostType,
+    BooleanType,
+    OptionalExtras,
+    Engine,
+    Booster
+)
+
+def test_subclass():
+    class SubBooster(Booster):
+        pass
+
+    _instance = SubBooster(boost_type=BoostType.NITROUS, horse_power=200)
+
+
+def test_car_encode():
+    car = make_car()
+    car_bytes = car.to_bytes()
+    car_bytes_ref = load_file("example_schema_car.sbe")
+
+    assert car_bytes == car_bytes_ref
+
+
+def test_car_encode_buffer():
+    car = make_car()
+    buffer = bytearray(512)
+    encoded_size = car.write_to_buffer(buffer)
+
+    car_bytes_ref = load_file("example_schema_car.sbe")
+
+    assert buffer[:encoded_size] == car_bytes_ref
+
+
+def test_car_decode():
+    car_bytes_ref = load_file("example_schema_car.sbe")
+
+    car = Car.from_bytes(car_bytes_ref)
+
+    extras = OptionalExtras()
+    extras.sun_roof = False
+    extras.sports_pack = True
+    extras.cruise_control = True
+
+    assert car == Car(
+        serial_number=1234,
+        model_year=2013,
+        available=BooleanType.T,
+        code=Model.A,
+        some_numbers=[1, 2, 3, 4],
+        vehicle_code="abcdef",
+        extras=extras,
+        engine=Engine(
+            capacity=2000,
+            num_cylinders=4,
+            manufacturer_code="123",
+            efficiency=35,
+            booster_enabled=BooleanType.T,
+            booster=Booster(
+                boost_type=BoostType.NITROUS,
+                horse_power=200
+            )
+        ),
+        fuel_figures=[
+            Car.FuelFigures(speed=30, mpg=35.9, usage_description="Urban Cycle"),
+            Car.FuelFigures(speed=55, mpg=49.0, usage_description="Combined Cycle"),
+            Car.FuelFigures(speed=75, mpg=40.0, usage_description="Highway Cycle"),
+        ],
+        performance_figures=[
+            Car.PerformanceFigures(octane_rating=95, acceleration=[
+                Car.PerformanceFigures.Acceleration(mph=30, seconds=4.0),
+                Car.PerformanceFigures.Acceleration(mph=60, seconds=7.5),
+                Car.PerformanceFigures.Acceleration(mph=100, seconds=12.2),
+            ]),
+            Car.PerformanceFigures(octane_rating=99, acceleration=[
+                Car.PerformanceFigures.Acceleration(mph=30, seconds=3.8),
+                Car.PerformanceFigures.Acceleration(mph=60, seconds=7.1),
+                Car.PerformanceFigures.Acceleration(mph=100, seconds=11.8),
+            ])
+        ],
+        manufacturer="Honda",
+        model="Civic VTi",
+        activation_code="abcdef"
+    )
+
+
+def test_car_decode_wrong_schema():
+    car_bytes = bytearray(load_file("example_schema_car.sbe"))
+    car_bytes[4] = 2
+
+    with pytest.raises(SbeError, match="wrong schema: 2, expected 1"):
+        Car.from_bytes(car_bytes)
@@ -0,0 +1,45 @@
+import pytest
+from example.layout import Quote
+
+
+def make_quote() -> Quote:
+    return Quote(
+        bid=-5,
+        ask=7,
+        volume=300,
+        levels=[
+            Quote.Levels(price=-1, quantity=10),
+            Quote.Levels(price=2, quantity=20),
+        ],
+        venue="XNAS",
+    )
+
+
+def quote_frame(padding: int) -> bytes:
+    """Quote as encoded on the wire, with the gaps between the fields and the padding of the
+    blocks filled with `padding`."""
+    p = padding
+
+    return bytes([
+        # Header: block length 24, template id 1, schema id 4 and version 0
+        24, 0, 1, 0, 4, 0, 0, 0,
+        # Block: bid at 0, ask at 8 and volume at 16, padded to 24 bytes
+        0xfb, 0xff, 0xff, 0xff, p, p, p, p,
+        7, 0, 0, 0, p, p, p, p,
+        44, 1, p, p, p, p, p, p,
+        # Levels: block length 8 and 2 entries, with price at 0 and quantity at 4
+        8, 0, 2, 0,
+        0xff, 0xff, p, p, 10, 0, p, p,
+        2, 0, p, p, 20, 0, p, p,
+        # Venue
+        4, 0, 0, 0, *b"XNAS",
+    ])
+
+
+def test_quote_encode_layout():
+    assert make_quote().to_bytes() == quote_frame(0)
+
+
+@pytest.mark.parametrize("padding", [0, 0xaa])
+def test_quote_decode_layout(padding: int):
+    assert Quote.from_bytes(quote_frame(padding)) == make_quote()
@@ -1,6 +1,6 @@
 from pathlib import Path
 
-from example.baseline import FuelFigures, PerformanceFigures, Acceleration, BooleanType, Booster, BoostType, Engine, Model, Car, \
+from example.baseline import BooleanType, Booster, BoostType, Engine, Model, Car, \
     OptionalExtras
 
 STATIC_PATH = Path(__file__).parent / "static"
@@ -36,20 +36,20 @@ def make_car() -> Car:
             ),
         ),
         fuel_figures=[
-            FuelFigures(speed=30, mpg=35.9, usage_description="Urban Cycle"),
-            FuelFigures(speed=55, mpg=49.0, usage_description="Combined Cycle"),
-            FuelFigures(speed=75, mpg=40.0, usage_description="Highway Cycle"),
+            Car.FuelFigures(speed=30, mpg=35.9, usage_description="Urban Cycle"),
+            Car.FuelFigures(speed=55, mpg=49.0, usage_description="Combined Cycle"),
+            Car.FuelFigures(speed=75, mpg=40.0, usage_description="Highway Cycle"),
         ],
         performance_figures=[
-            PerformanceFigures(octane_rating=95, acceleration=[
-                Acceleration(mph=30, seconds=4.0),
-                Acceleration(mph=60, seconds=7.5),
-                Acceleration(mph=100, seconds=12.2),
+            Car.PerformanceFigures(octane_rating=95, acceleration=[
+                Car.PerformanceFigures.Acceleration(mph=30, seconds=4.0),
+                Car.PerformanceFigures.Acceleration(mph=60, seconds=7.5),
+                Car.PerformanceFigures.Acceleration(mph=100, seconds=12.2),
             ]),
-            PerformanceFigures(octane_rating=99, acceleration=[
-                Acceleration(mph=30, seconds=3.8),
-                Acceleration(mph=60, seconds=7.1),
-                Acceleration(mph=100, seconds=11.8),
+            Car.PerformanceFigures(octane_rating=99, acceleration=[
+                Car.PerformanceFigures.Acceleration(mph=30, seconds=3.8),
+                Car.PerformanceFigures.Acceleration(mph=60, seconds=7.1),
+                Car.PerformanceFigures.Acceleration(mph=100, seconds=11.8),
             ])
         ],
         manufacturer="Honda",
@@ -3,9 +3,6 @@ from example.baseline import (
     Car,
     Model,
     BoostType,
-    FuelFigures,
-    PerformanceFigures,
-    Acceleration,
     BooleanType,
     OptionalExtras,
     Engine,
@@ -22,7 +19,7 @@ def test_subclass():
 
 def test_car_encode():
     car = make_car()
-    car_bytes = car.to_bytes(512)
+    car_bytes = car.to_bytes()
     car_bytes_ref = load_file("example_schema_car.sbe")
 
     assert car_bytes == car_bytes_ref
@@ -68,20 +65,20 @@ def test_car_decode():
             )
         ),
         fuel_figures=[
-            FuelFigures(speed=30, mpg=35.9, usage_description="Urban Cycle"),
-            FuelFigures(speed=55, mpg=49.0, usage_description="Combined Cycle"),
-            FuelFigures(speed=75, mpg=40.0, usage_description="Highway Cycle"),
+            Car.FuelFigures(speed=30, mpg=35.9, usage_description="Urban Cycle"),
+            Car.FuelFigures(speed=55, mpg=49.0, usage_description="Combined Cycle"),
+            Car.FuelFigures(speed=75, mpg=40.0, usage_description="Highway Cycle"),
         ],
         performance_figures=[
-            PerformanceFigures(octane_rating=95, acceleration=[
-                Acceleration(mph=30, seconds=4.0),
-                Acceleration(mph=60, seconds=7.5),
-                Acceleration(mph=100, seconds=12.2),
+            Car.PerformanceFigures(octane_rating=95, acceleration=[
+                Car.PerformanceFigures.Acceleration(mph=30, seconds=4.0),
+                Car.PerformanceFigures.Acceleration(mph=60, seconds=7.5),
+                Car.PerformanceFigures.Acceleration(mph=100, seconds=12.2),
             ]),
-            PerformanceFigures(octane_rating=99, acceleration=[
-                Acceleration(mph=30, seconds=3.8),
-                Acceleration(mph=60, seconds=7.1),
-                Acceleration(mph=100, seconds=11.8),
+            Car.PerformanceFigures(octane_rating=99, acceleration=[
+                Car.PerformanceFigures.Acceleration(mph=30, seconds=3.8),
+                Car.PerformanceFigures.Acceleration(mph=60, seconds=7.1),
+                Car.PerformanceFigures.Acceleration(mph=100, seconds=11.8),
             ])
         ],
         manufacturer="Honda",
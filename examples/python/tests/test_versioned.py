@@ -0,0 +1,49 @@
+import struct
+
+from example.versioned import Order, Price, Side
+
+
+def order_v0() -> bytes:
+    """Order encoded with the version 0 of the schema, which only has the order id, the leg ids
+    and the venue."""
+    header = struct.pack("<4H", 8, 1, 2, 0)
+    legs = struct.pack("<2H2I", 4, 2, 7, 8)
+    venue = struct.pack("<I", 4) + b"XNAS"
+
+    return header + struct.pack("<Q", 42) + legs + venue
+
+
+def assert_order_v0(order: Order):
+    assert order.order_id == 42
+    assert order.side is None
+    assert order.price is None
+    assert [leg.leg_id for leg in order.legs] == [7, 8]
+    assert all(leg.leg_note == "" for leg in order.legs)
+    assert order.fills == []
+    assert order.venue == "XNAS"
+    assert order.note == ""
+
+
+def test_order_decode_v0():
+    assert_order_v0(Order.from_bytes(order_v0()))
+
+
+def test_order_reencode_v0():
+    order = Order.from_bytes(order_v0())
+    decoded = Order.from_bytes(order.to_bytes())
+
+    assert_order_v0(decoded)
+
+
+def test_order_side_round_trip():
+    order = Order.from_bytes(order_v0())
+    order.side = Side.SELL
+
+    assert Order.from_bytes(order.to_bytes()).side == Side.SELL
+
+
+def test_order_price_round_trip():
+    order = Order.from_bytes(order_v0())
+    order.price = Price(mantissa=12345, exponent=-2)
+
+    assert Order.from_bytes(order.to_bytes()).price == Price(mantissa=12345, exponent=-2)
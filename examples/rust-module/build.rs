@@ -0,0 +1,13 @@
+use sbe_codegen::{Builder, Language};
+
+fn main() {
+    Builder::new()
+        .schema("../example-schema.xml")
+        .schema("../versioned-schema.xml")
+        .language(Language::Rust)
+        .module("crate::example")
+        .serde(true)
+        .out_dir(std::env::var("OUT_DIR").unwrap())
+        .generate()
+        .unwrap();
+}
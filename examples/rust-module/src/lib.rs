@@ -0,0 +1,15 @@
+//! Crate mounting the codecs generated by its build script, which must not warn.
+#![deny(warnings)]
+
+mod example {
+    include!(concat!(env!("OUT_DIR"), "/example.rs"));
+}
+
+pub use example::versioned::messages::Order;
+
+/// Decodes the order, which is only one of the messages of the mounted schemas.
+pub fn decode_order(data: &[u8]) -> example::error::Result<Order> {
+    let read_buf = example::versioned::decoder::ReadBuf::new(data);
+    let mut decoder = example::versioned::messages::OrderDecoder::try_from(read_buf)?;
+    Order::decode_from(&mut decoder)
+}
@@ -0,0 +1,19 @@
+use example_module::decode_order;
+
+#[test]
+fn test_decode_order() {
+    let mut frame = vec![];
+    for value in [8u16, 1, 2, 0] {
+        frame.extend_from_slice(&value.to_le_bytes());
+    }
+    frame.extend_from_slice(&42u64.to_le_bytes());
+    for value in [4u16, 0] {
+        frame.extend_from_slice(&value.to_le_bytes());
+    }
+    frame.extend_from_slice(&0u32.to_le_bytes());
+
+    let order = decode_order(&frame).unwrap();
+
+    assert_eq!(order.order_id, 42);
+    assert!(order.legs.is_empty());
+}
@@ -0,0 +1,106 @@
+use example::bounds::decoder::ReadBuf;
+use example::bounds::encoder::WriteBuf;
+use example::bounds::messages::{Rating, RatingDecoder, RatingEncoder};
+use example::error::{Result, SbeError};
+use rstest::rstest;
+
+/// Offset of the score after the header, levels, discount and targets.
+const SCORE_OFFSET: usize = 8 + 3 + 1 + 4;
+
+fn encode(rating: &Rating) -> Result<Vec<u8>> {
+    let mut buffer = vec![];
+    let write_buf = WriteBuf::growable(&mut buffer);
+    let mut encoder = RatingEncoder::try_from(write_buf)?;
+    rating.encode_into(&mut encoder)?;
+
+    Ok(buffer)
+}
+
+#[rstest]
+#[case::bounds([1, 5, 3], Some(0), Some([-100, 100]), Some(1))]
+#[case::nulls([2, 2, 2], None, None, None)]
+fn test_rating_round_trip(
+    #[case] levels: [u8; 3],
+    #[case] discount: Option<i8>,
+    #[case] targets: Option<[i16; 2]>,
+    #[case] score: Option<u8>,
+) -> Result<()> {
+    let rating = Rating {
+        levels,
+        discount,
+        targets,
+        score,
+    };
+    let buffer = encode(&rating)?;
+
+    let read_buf = ReadBuf::new(&buffer);
+    let mut decoder = RatingDecoder::try_from(read_buf)?;
+    assert_eq!(Rating::decode_from(&mut decoder)?, rating);
+
+    Ok(())
+}
+
+#[rstest]
+#[case::array_below_min([1, 0, 3], None, None, "levels")]
+#[case::array_above_max([1, 6, 3], None, None, "levels")]
+#[case::optional_below_min([1, 1, 1], Some(-1), None, "discount")]
+#[case::optional_above_max([1, 1, 1], Some(51), None, "discount")]
+#[case::optional_array_below_min([1, 1, 1], None, Some([-101, 0]), "targets")]
+#[case::optional_array_above_max([1, 1, 1], None, Some([0, 101]), "targets")]
+fn test_rating_encode_out_of_bounds(
+    #[case] levels: [u8; 3],
+    #[case] discount: Option<i8>,
+    #[case] targets: Option<[i16; 2]>,
+    #[case] field: &str,
+) {
+    let rating = Rating {
+        levels,
+        discount,
+        targets,
+        score: None,
+    };
+
+    match encode(&rating) {
+        Err(SbeError::ValueOutOfBounds { field_name, .. }) => assert_eq!(field_name, field),
+        result => panic!("expected {} out of bounds, got {:?}", field, result),
+    }
+}
+
+#[rstest]
+fn test_rating_encode_custom_null_value() -> Result<()> {
+    let rating = Rating {
+        levels: [1, 1, 1],
+        discount: None,
+        targets: None,
+        score: None,
+    };
+    let buffer = encode(&rating)?;
+
+    // The score declares 0 as its null value instead of u8::MAX
+    assert_eq!(buffer[SCORE_OFFSET], 0);
+
+    Ok(())
+}
+
+#[rstest]
+#[case::custom_null(0, None)]
+#[case::default_null(u8::MAX, Some(u8::MAX))]
+#[case::value(7, Some(7))]
+fn test_rating_decode_custom_null_value(
+    #[case] encoded: u8,
+    #[case] score: Option<u8>,
+) -> Result<()> {
+    let mut buffer = encode(&Rating {
+        levels: [1, 1, 1],
+        discount: None,
+        targets: None,
+        score: None,
+    })?;
+    buffer[SCORE_OFFSET] = encoded;
+
+    let read_buf = ReadBuf::new(&buffer);
+    let mut decoder = RatingDecoder::try_from(read_buf)?;
+    assert_eq!(Rating::decode_from(&mut decoder)?.score, score);
+
+    Ok(())
+}
@@ -0,0 +1,81 @@
+use example::custom_header::decoder::ReadBuf;
+use example::custom_header::encoder::WriteBuf;
+use example::custom_header::groups::order::Legs;
+use example::custom_header::messages::{Order, OrderDecoder, OrderEncoder};
+use example::custom_header::SCHEMA_ID;
+use example::error::{Result, SbeError};
+use rstest::rstest;
+
+fn order() -> Order {
+    Order {
+        price: -1250,
+        legs: vec![Legs { quantity: 3 }, Legs { quantity: 4 }],
+        note: b"urgent".to_vec(),
+    }
+}
+
+fn encode(order: &Order) -> Result<Vec<u8>> {
+    let mut buffer = vec![];
+    let write_buf = WriteBuf::growable(&mut buffer);
+    let mut encoder = OrderEncoder::try_from(write_buf)?;
+    order.encode_into(&mut encoder)?;
+
+    Ok(buffer)
+}
+
+#[rstest]
+fn test_order_encode_header() -> Result<()> {
+    let buffer = encode(&order())?;
+
+    // Header: uint16 block length, uint8 template id, uint32 schema id, uint16 version,
+    // uint16 number of groups and uint16 number of var data fields
+    assert_eq!(buffer[..2], 8u16.to_le_bytes());
+    assert_eq!(buffer[2], 1);
+    assert_eq!(buffer[3..7], 7u32.to_le_bytes());
+    assert_eq!(buffer[7..9], 0u16.to_le_bytes());
+    assert_eq!(buffer[9..11], 1u16.to_le_bytes());
+    assert_eq!(buffer[11..13], 1u16.to_le_bytes());
+    assert_eq!(buffer[13..21], (-1250i64).to_le_bytes());
+
+    Ok(())
+}
+
+#[rstest]
+fn test_order_round_trip() -> Result<()> {
+    let order = order();
+    let buffer = encode(&order)?;
+
+    let read_buf = ReadBuf::new(&buffer);
+    let mut decoder = OrderDecoder::try_from(read_buf)?;
+    assert_eq!(
+        decoder.message_header_decoder(|header| Ok((
+            header.num_groups()?,
+            header.num_var_data_fields()?
+        )))?,
+        (1, 1)
+    );
+    assert_eq!(decoder.size()?, buffer.len());
+
+    let decoded = Order::decode_from(&mut decoder)?;
+    assert_eq!(decoded, order);
+
+    Ok(())
+}
+
+#[rstest]
+#[case::other_schema(8)]
+#[case::wider_than_u16(0x10007)]
+fn test_order_decode_wrong_schema(#[case] schema_id: u32) -> Result<()> {
+    let mut buffer = encode(&order())?;
+    buffer[3..7].copy_from_slice(&schema_id.to_le_bytes());
+
+    let read_buf = ReadBuf::new(&buffer);
+    let error = OrderDecoder::try_from(read_buf).err();
+
+    assert_eq!(
+        error,
+        Some(SbeError::WrongSchema(schema_id.into(), SCHEMA_ID.into()))
+    );
+
+    Ok(())
+}
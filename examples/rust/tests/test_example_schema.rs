@@ -1,8 +1,9 @@
 use example::baseline::decoder::ReadBuf;
 use example::baseline::encoder::WriteBuf;
 use example::baseline::enums::{BooleanType, BoostType, Model};
-use example::baseline::messages::{CarDecoder, CarEncoder};
+use example::baseline::messages::{AnyMessageDecoder, Car, CarDecoder, CarEncoder, MessageVisitor};
 use example::baseline::sets::OptionalExtras;
+use example::error::{Result, SbeError};
 use rstest::rstest;
 
 #[rstest]
@@ -236,3 +237,186 @@ fn test_car_decode() {
     })
     .unwrap();
 }
+
+#[rstest]
+fn test_car_decode_iterators() -> Result<()> {
+    let original = include_bytes!("static/example_schema_car.sbe");
+
+    let read_buf = ReadBuf::new(original);
+    let mut car = CarDecoder::try_from(read_buf)?;
+
+    let mut speeds = vec![];
+    for fuel_figures in car.fuel_figures()? {
+        speeds.push(fuel_figures?.speed()?);
+    }
+    assert_eq!(speeds, [30, 55, 75]);
+
+    let mut seconds = vec![];
+    for performance_figures in car.performance_figures()? {
+        for acceleration in performance_figures?.acceleration()? {
+            seconds.push(acceleration?.seconds()?);
+        }
+    }
+    assert_eq!(seconds, [4.0, 7.5, 12.2, 3.8, 7.1, 11.8]);
+
+    let manufacturer = car.manufacturer_decoder(|manufacturer| {
+        Ok(manufacturer
+            .get_slice_at(0, manufacturer.length())?
+            .to_vec())
+    })?;
+    assert_eq!(manufacturer, b"Honda");
+
+    Ok(())
+}
+
+#[rstest]
+fn test_car_decode_any_order() -> Result<()> {
+    let original = include_bytes!("static/example_schema_car.sbe");
+
+    let read_buf = ReadBuf::new(original);
+    let mut car = CarDecoder::try_from(read_buf)?;
+
+    let activation_code = car.activation_code_decoder(|activation_code| {
+        Ok(activation_code
+            .get_slice_at(0, activation_code.length())?
+            .to_vec())
+    })?;
+    assert_eq!(activation_code, b"abcdef");
+
+    let octane_ratings = car
+        .performance_figures()?
+        .map(|performance_figures| performance_figures?.octane_rating())
+        .collect::<Result<Vec<_>>>()?;
+    assert_eq!(octane_ratings, [95, 99]);
+
+    assert_eq!(car.size()?, original.len());
+
+    Ok(())
+}
+
+#[rstest]
+fn test_car_owned_round_trip() {
+    let original = include_bytes!("static/example_schema_car.sbe");
+
+    let read_buf = ReadBuf::new(original);
+    let mut decoder = CarDecoder::try_from(read_buf).unwrap();
+    let car = Car::decode_from(&mut decoder).unwrap();
+
+    assert_eq!(car.serial_number, 1234);
+    assert_eq!(car.vehicle_code, "abcdef");
+    assert_eq!(car.engine.booster.boost_type, BoostType::Nitrous);
+    assert_eq!(car.fuel_figures.len(), 3);
+    assert_eq!(car.fuel_figures[1].usage_description, "Combined Cycle");
+    assert_eq!(car.performance_figures[1].acceleration[2].seconds, 11.8);
+    assert_eq!(car.activation_code, "abcdef");
+
+    let mut buffer = [0u8; 1024];
+    let write_buf = WriteBuf::new(&mut buffer);
+    let mut encoder = CarEncoder::try_from(write_buf).unwrap();
+    car.encode_into(&mut encoder).unwrap();
+
+    let encoded_size = encoder.size().unwrap();
+
+    assert_eq!(&buffer[..encoded_size], original)
+}
+
+#[rstest]
+fn test_car_encode_growable() -> Result<()> {
+    let original = include_bytes!("static/example_schema_car.sbe");
+
+    let read_buf = ReadBuf::new(original);
+    let mut decoder = CarDecoder::try_from(read_buf)?;
+    let car = Car::decode_from(&mut decoder)?;
+
+    let mut buffer = vec![];
+    for _ in 0..2 {
+        let write_buf = WriteBuf::growable(&mut buffer);
+        let mut encoder = CarEncoder::try_from(write_buf)?;
+        car.encode_into(&mut encoder)?;
+
+        assert_eq!(encoder.size(), Some(original.len()));
+    }
+
+    assert_eq!(buffer, [&original[..], &original[..]].concat());
+
+    Ok(())
+}
+
+#[rstest]
+fn test_car_bytes_round_trip() -> Result<()> {
+    let original = bytes::Bytes::from_static(include_bytes!("static/example_schema_car.sbe"));
+
+    let read_buf = ReadBuf::new(&original);
+    let mut decoder = CarDecoder::try_from(read_buf)?;
+    let car = Car::decode_from(&mut decoder)?;
+
+    let mut buffer = bytes::BytesMut::new();
+    let write_buf = WriteBuf::growable(&mut buffer);
+    let mut encoder = CarEncoder::try_from(write_buf)?;
+    car.encode_into(&mut encoder)?;
+
+    assert_eq!(buffer.freeze(), original);
+
+    Ok(())
+}
+
+#[cfg(feature = "serde")]
+#[rstest]
+fn test_car_json() {
+    let original = include_bytes!("static/example_schema_car.sbe");
+    let expected: serde_json::Value =
+        serde_json::from_str(include_str!("static/example_schema_car.json")).unwrap();
+
+    let read_buf = ReadBuf::new(original);
+    let mut decoder = CarDecoder::try_from(read_buf).unwrap();
+    let car = Car::decode_from(&mut decoder).unwrap();
+
+    // Floats are compared in their shortest textual representation
+    let json = serde_json::to_string(&car).unwrap();
+    assert_eq!(
+        serde_json::from_str::<serde_json::Value>(&json).unwrap(),
+        expected
+    );
+
+    let deserialized: Car = serde_json::from_value(expected).unwrap();
+    assert_eq!(deserialized, car);
+}
+
+#[rstest]
+fn test_any_message_decode() {
+    struct SerialNumbers(Vec<u64>);
+
+    impl MessageVisitor for SerialNumbers {
+        fn on_car(&mut self, decoder: &mut CarDecoder<'_>) -> Result<()> {
+            self.0.push(decoder.serial_number()?);
+            Ok(())
+        }
+    }
+
+    let original = include_bytes!("static/example_schema_car.sbe");
+
+    let mut message = AnyMessageDecoder::try_from(ReadBuf::new(original)).unwrap();
+    assert_eq!(message.template_id(), CarDecoder::id());
+
+    let mut visitor = SerialNumbers(Vec::new());
+    message.accept(&mut visitor).unwrap();
+    assert_eq!(visitor.0, vec![1234]);
+
+    let mut unknown = original.to_vec();
+    unknown[2..4].copy_from_slice(&999_u16.to_le_bytes());
+    assert_eq!(
+        AnyMessageDecoder::try_from(ReadBuf::new(&unknown)).unwrap_err(),
+        SbeError::UnknownMessageType(999)
+    );
+
+    let mut foreign = original.to_vec();
+    foreign[4..6].copy_from_slice(&2_u16.to_le_bytes());
+    assert_eq!(
+        AnyMessageDecoder::try_from(ReadBuf::new(&foreign)).unwrap_err(),
+        SbeError::WrongSchema(2, 1)
+    );
+    assert_eq!(
+        CarDecoder::try_from(ReadBuf::new(&foreign)).unwrap_err(),
+        SbeError::WrongSchema(2, 1)
+    );
+}
@@ -0,0 +1,73 @@
+use example::error::Result;
+use example::layout::decoder::ReadBuf;
+use example::layout::encoder::WriteBuf;
+use example::layout::groups::quote::Levels;
+use example::layout::messages::{Quote, QuoteDecoder, QuoteEncoder};
+use rstest::rstest;
+
+fn quote() -> Quote {
+    Quote {
+        bid: -5,
+        ask: 7,
+        volume: 300,
+        levels: vec![
+            Levels {
+                price: -1,
+                quantity: 10,
+            },
+            Levels {
+                price: 2,
+                quantity: 20,
+            },
+        ],
+        venue: "XNAS".to_owned(),
+    }
+}
+
+/// Quote as encoded on the wire, with the gaps between the fields and the padding of the
+/// blocks filled with `padding`.
+#[rustfmt::skip]
+fn quote_frame(padding: u8) -> Vec<u8> {
+    let p = padding;
+
+    vec![
+        // Header: block length 24, template id 1, schema id 4 and version 0
+        24, 0, 1, 0, 4, 0, 0, 0,
+        // Block: bid at 0, ask at 8 and volume at 16, padded to 24 bytes
+        0xfb, 0xff, 0xff, 0xff, p, p, p, p,
+        7, 0, 0, 0, p, p, p, p,
+        44, 1, p, p, p, p, p, p,
+        // Levels: block length 8 and 2 entries, with price at 0 and quantity at 4
+        8, 0, 2, 0,
+        0xff, 0xff, p, p, 10, 0, p, p,
+        2, 0, p, p, 20, 0, p, p,
+        // Venue
+        4, 0, 0, 0, b'X', b'N', b'A', b'S',
+    ]
+}
+
+#[rstest]
+fn test_quote_encode_layout() -> Result<()> {
+    let mut buffer = vec![];
+    let write_buf = WriteBuf::growable(&mut buffer);
+    let mut encoder = QuoteEncoder::try_from(write_buf)?;
+    quote().encode_into(&mut encoder)?;
+
+    assert_eq!(buffer, quote_frame(0));
+
+    Ok(())
+}
+
+#[rstest]
+#[case::zero_padding(0)]
+#[case::garbage_padding(0xaa)]
+fn test_quote_decode_layout(#[case] padding: u8) -> Result<()> {
+    let frame = quote_frame(padding);
+
+    let read_buf = ReadBuf::new(&frame);
+    let mut decoder = QuoteDecoder::try_from(read_buf)?;
+    assert_eq!(decoder.size()?, frame.len());
+    assert_eq!(Quote::decode_from(&mut decoder)?, quote());
+
+    Ok(())
+}
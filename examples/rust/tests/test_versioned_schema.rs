@@ -0,0 +1,240 @@
+use example::error::Result;
+use example::versioned::composites::Price;
+use example::versioned::decoder::ReadBuf;
+use example::versioned::encoder::WriteBuf;
+use example::versioned::enums::Side;
+use example::versioned::groups::order::{Fills, Legs};
+use example::versioned::messages::{Order, OrderDecoder, OrderEncoder};
+use example::versioned::sets::OrderFlags;
+use example::versioned::SCHEMA_VERSION;
+use rstest::rstest;
+
+/// Order encoded with the version 0 of the schema, which only has the order id, the leg ids
+/// and the venue.
+fn order_v0() -> Vec<u8> {
+    let mut frame = vec![];
+
+    // Header: block length, template id, schema id and version
+    for value in [8u16, 1, 2, 0] {
+        frame.extend_from_slice(&value.to_le_bytes());
+    }
+    frame.extend_from_slice(&42u64.to_le_bytes());
+
+    // Legs: block length and number of entries, then the leg ids
+    for value in [4u16, 2] {
+        frame.extend_from_slice(&value.to_le_bytes());
+    }
+    for leg_id in [7u32, 8] {
+        frame.extend_from_slice(&leg_id.to_le_bytes());
+    }
+
+    frame.extend_from_slice(&4u32.to_le_bytes());
+    frame.extend_from_slice(b"XNAS");
+
+    frame
+}
+
+/// Order encoded by a producer of a newer version 3 of the schema, which added 4 bytes to the
+/// blocks of the message and of both of its groups.
+fn order_v3() -> Vec<u8> {
+    let extension = [0xee; 4];
+    let mut frame = vec![];
+
+    // Header: block length, template id, schema id and version
+    for value in [27u16, 1, 2, 3] {
+        frame.extend_from_slice(&value.to_le_bytes());
+    }
+    frame.extend_from_slice(&42u64.to_le_bytes());
+    frame.extend_from_slice(&100u32.to_le_bytes());
+    frame.extend_from_slice(&[Side::Sell as u8, 0b10]);
+    frame.extend_from_slice(&12345i64.to_le_bytes());
+    frame.extend_from_slice(&(-2i8).to_le_bytes());
+    frame.extend_from_slice(&extension);
+
+    // Legs: block length and number of entries, then the leg ids, the ratios and the notes
+    for value in [10u16, 2] {
+        frame.extend_from_slice(&value.to_le_bytes());
+    }
+    for (leg_id, ratio, leg_note) in [(7u32, 1u16, "first"), (8, 2, "second")] {
+        frame.extend_from_slice(&leg_id.to_le_bytes());
+        frame.extend_from_slice(&ratio.to_le_bytes());
+        frame.extend_from_slice(&extension);
+        frame.extend_from_slice(&(leg_note.len() as u32).to_le_bytes());
+        frame.extend_from_slice(leg_note.as_bytes());
+    }
+
+    // Fills: block length and number of entries, then the fill quantity
+    for value in [8u16, 1] {
+        frame.extend_from_slice(&value.to_le_bytes());
+    }
+    frame.extend_from_slice(&60u32.to_le_bytes());
+    frame.extend_from_slice(&extension);
+
+    for var_data in ["XNAS", "urgent"] {
+        frame.extend_from_slice(&(var_data.len() as u32).to_le_bytes());
+        frame.extend_from_slice(var_data.as_bytes());
+    }
+
+    frame
+}
+
+#[rstest]
+fn test_order_decode_v0() -> Result<()> {
+    let frame = order_v0();
+
+    let read_buf = ReadBuf::new(&frame);
+    let mut order = OrderDecoder::try_from(read_buf)?;
+
+    assert_eq!(order.acting_version(), 0);
+    assert_eq!(order.block_length(), 8);
+    assert_eq!(order.order_id()?, 42);
+    assert_eq!(order.quantity()?, u32::MAX);
+    assert_eq!(order.side()?, Side::NullVal);
+    assert_eq!(order.flags()?, OrderFlags::default());
+    assert_eq!(order.price_decoder(|price| price.mantissa())?, None);
+
+    order.legs_decoder(|legs| {
+        assert_eq!(legs.num_in_group(), 2);
+        for leg_id in [7, 8] {
+            assert_eq!(legs.leg_id()?, leg_id);
+            assert_eq!(legs.ratio()?, u16::MAX);
+            assert_eq!(legs.leg_note_decoder(|leg_note| Ok(leg_note.length()))?, 0);
+            legs.advance()?;
+        }
+        Ok(())
+    })?;
+
+    assert_eq!(order.fills_decoder(|fills| Ok(fills.num_in_group()))?, 0);
+
+    let venue = order.venue_decoder(|venue| Ok(venue.get_slice_at(0, venue.length())?.to_vec()))?;
+    assert_eq!(venue, b"XNAS");
+
+    let note = order.note_decoder(|note| Ok(note.get_slice_at(0, note.length())?.to_vec()))?;
+    assert!(note.is_empty());
+
+    Ok(())
+}
+
+#[rstest]
+fn test_order_size_v0() -> Result<()> {
+    let frame = order_v0();
+
+    let read_buf = ReadBuf::new(&frame);
+    let mut order = OrderDecoder::try_from(read_buf)?;
+
+    // The sizes of the legs and their notes, which are absent, are found without decoding them
+    assert_eq!(order.size()?, frame.len());
+
+    let mut order = OrderDecoder::try_from(read_buf)?;
+    let leg_ids = order
+        .legs()?
+        .map(|leg| leg.and_then(|leg| leg.leg_id()))
+        .collect::<Result<Vec<_>>>()?;
+    assert_eq!(leg_ids, [7, 8]);
+
+    let venue = order.venue_decoder(|venue| Ok(venue.get_slice_at(0, venue.length())?.to_vec()))?;
+    assert_eq!(venue, b"XNAS");
+
+    Ok(())
+}
+
+#[rstest]
+fn test_order_owned_decode_v0() -> Result<()> {
+    let frame = order_v0();
+
+    let read_buf = ReadBuf::new(&frame);
+    let mut decoder = OrderDecoder::try_from(read_buf)?;
+    let order = Order::decode_from(&mut decoder)?;
+
+    assert_eq!(order.order_id, 42);
+    assert_eq!(order.price, None);
+    assert_eq!(
+        order.legs.iter().map(|leg| leg.leg_id).collect::<Vec<_>>(),
+        [7, 8]
+    );
+    assert!(order.legs.iter().all(|leg| leg.leg_note.is_empty()));
+    assert!(order.fills.is_empty());
+    assert_eq!(order.venue, "XNAS");
+    assert!(order.note.is_empty());
+
+    Ok(())
+}
+
+#[rstest]
+#[case::without_price(None)]
+#[case::with_price(Some(Price { mantissa: 12345, exponent: -2 }))]
+fn test_order_round_trip(#[case] price: Option<Price>) -> Result<()> {
+    let order = Order {
+        order_id: 42,
+        quantity: 100,
+        side: Side::Buy,
+        flags: OrderFlags::default(),
+        price,
+        legs: vec![Legs {
+            leg_id: 7,
+            ratio: 1,
+            leg_note: "first".to_owned(),
+        }],
+        fills: vec![Fills { fill_quantity: 60 }],
+        venue: "XNAS".to_owned(),
+        note: "urgent".to_owned(),
+    };
+
+    let mut buffer = vec![];
+    let write_buf = WriteBuf::growable(&mut buffer);
+    let mut encoder = OrderEncoder::try_from(write_buf)?;
+    order.encode_into(&mut encoder)?;
+
+    let read_buf = ReadBuf::new(&buffer);
+    let mut decoder = OrderDecoder::try_from(read_buf)?;
+    assert_eq!(decoder.acting_version(), SCHEMA_VERSION);
+
+    assert_eq!(Order::decode_from(&mut decoder)?, order);
+
+    Ok(())
+}
+
+#[rstest]
+fn test_order_decode_newer_version() -> Result<()> {
+    let frame = order_v3();
+
+    let read_buf = ReadBuf::new(&frame);
+    let mut decoder = OrderDecoder::try_from(read_buf)?;
+    assert_eq!(decoder.acting_version(), 3);
+    assert_eq!(decoder.block_length(), 27);
+
+    // The extensions of the blocks are skipped without being known
+    assert_eq!(decoder.size()?, frame.len());
+
+    let mut decoder = OrderDecoder::try_from(read_buf)?;
+    assert_eq!(
+        Order::decode_from(&mut decoder)?,
+        Order {
+            order_id: 42,
+            quantity: 100,
+            side: Side::Sell,
+            flags: *OrderFlags::default().set_post_only(true),
+            price: Some(Price {
+                mantissa: 12345,
+                exponent: -2,
+            }),
+            legs: vec![
+                Legs {
+                    leg_id: 7,
+                    ratio: 1,
+                    leg_note: "first".to_owned(),
+                },
+                Legs {
+                    leg_id: 8,
+                    ratio: 2,
+                    leg_note: "second".to_owned(),
+                },
+            ],
+            fills: vec![Fills { fill_quantity: 60 }],
+            venue: "XNAS".to_owned(),
+            note: "urgent".to_owned(),
+        }
+    );
+
+    Ok(())
+}
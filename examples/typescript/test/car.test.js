@@ -0,0 +1,236 @@
+import assert from "node:assert/strict";
+import { readFileSync } from "node:fs";
+import { test } from "node:test";
+
+import { SbeError, SbeErrorKind } from "../src/index.js";
+import {
+    AnyMessageDecoder,
+    BooleanType,
+    BoostType,
+    CarDecoder,
+    CarEncoder,
+    Model,
+    OptionalExtras,
+} from "../src/baseline/index.js";
+
+function original() {
+    return new Uint8Array(readFileSync(new URL("./example_schema_car.sbe", import.meta.url)));
+}
+
+function text(decoder) {
+    return new TextDecoder().decode(decoder.getSliceAt());
+}
+
+function bytes(value) {
+    return new TextEncoder().encode(value);
+}
+
+test("encodes a car", () => {
+    const buffer = new Uint8Array(1024);
+    const car = new CarEncoder(buffer);
+
+    car.serialNumber(1234n);
+    car.modelYear(2013);
+    car.available(BooleanType.T);
+    car.code(Model.A);
+    car.someNumbers([1, 2, 3, 4]);
+    car.vehicleCode("abcdef");
+
+    const extras = new OptionalExtras();
+    extras.sunRoof = false;
+    extras.sportsPack = true;
+    extras.cruiseControl = true;
+    car.extras(extras);
+
+    car.engineEncoder((engine) => {
+        engine.capacity(2000);
+        engine.numCylinders(4);
+        engine.manufacturerCode("123");
+        engine.efficiency(35);
+        engine.boosterEnabled(BooleanType.T);
+        engine.boosterEncoder((booster) => {
+            booster.boostType(BoostType.NITROUS);
+            booster.horsePower(200);
+        });
+    });
+
+    car.fuelFiguresEncoder((fuelFigures) => {
+        for (const [speed, mpg, usageDescription] of [
+            [30, 35.9, "Urban Cycle"],
+            [55, 49.0, "Combined Cycle"],
+            [75, 40.0, "Highway Cycle"],
+        ]) {
+            fuelFigures.speed(speed);
+            fuelFigures.mpg(mpg);
+            fuelFigures.usageDescriptionEncoder((encoder) => encoder.putSliceAt(0, bytes(usageDescription)));
+            fuelFigures.advance();
+        }
+    });
+
+    car.performanceFiguresEncoder((performanceFigures) => {
+        for (const [octaneRating, accelerations] of [
+            [95, [[30, 4.0], [60, 7.5], [100, 12.2]]],
+            [99, [[30, 3.8], [60, 7.1], [100, 11.8]]],
+        ]) {
+            performanceFigures.octaneRating(octaneRating);
+            performanceFigures.accelerationEncoder((acceleration) => {
+                for (const [mph, seconds] of accelerations) {
+                    acceleration.mph(mph);
+                    acceleration.seconds(seconds);
+                    acceleration.advance();
+                }
+            });
+            performanceFigures.advance();
+        }
+    });
+
+    car.manufacturerEncoder((encoder) => encoder.putSliceAt(0, bytes("Honda")));
+    car.modelEncoder((encoder) => encoder.putSliceAt(0, bytes("Civic VTi")));
+    car.activationCodeEncoder((encoder) => encoder.putSliceAt(0, bytes("abcdef")));
+
+    assert.deepEqual(buffer.subarray(0, car.size()), original());
+});
+
+test("decodes a car", () => {
+    const car = new CarDecoder(original());
+
+    assert.equal(car.actingVersion(), 0);
+    assert.equal(car.blockLength(), CarEncoder.BLOCK_LENGTH);
+    assert.equal(car.serialNumber(), 1234n);
+    assert.equal(car.modelYear(), 2013);
+    assert.equal(car.available(), BooleanType.T);
+    assert.equal(car.code(), Model.A);
+    assert.deepEqual(car.someNumbers(), [1, 2, 3, 4]);
+    assert.equal(car.vehicleCode(), "abcdef");
+    assert.equal(car.discountedModel(), Model.C);
+
+    const extras = car.extras();
+    assert.equal(extras.sunRoof, false);
+    assert.equal(extras.sportsPack, true);
+    assert.equal(extras.cruiseControl, true);
+
+    car.engineDecoder((engine) => {
+        assert.equal(engine.capacity(), 2000);
+        assert.equal(engine.numCylinders(), 4);
+        assert.equal(engine.maxRpm(), 9000);
+        assert.equal(engine.manufacturerCode(), "123");
+        assert.equal(engine.fuel(), "Petrol");
+        assert.equal(engine.efficiency(), 35);
+        assert.equal(engine.boosterEnabled(), BooleanType.T);
+        engine.boosterDecoder((booster) => {
+            assert.equal(booster.boostType(), BoostType.NITROUS);
+            assert.equal(booster.horsePower(), 200);
+        });
+    });
+
+    const fuelFigures = car.fuelFiguresDecoder((decoder) => {
+        const values = [];
+
+        for (let index = 0; index < decoder.numInGroup(); index++) {
+            values.push([decoder.speed(), decoder.mpg(), decoder.usageDescriptionDecoder(text)]);
+            decoder.advance();
+        }
+
+        return values;
+    });
+    assert.deepEqual(fuelFigures, [
+        [30, Math.fround(35.9), "Urban Cycle"],
+        [55, 49.0, "Combined Cycle"],
+        [75, 40.0, "Highway Cycle"],
+    ]);
+
+    const performanceFigures = car.performanceFiguresDecoder((decoder) => {
+        const values = [];
+
+        for (let index = 0; index < decoder.numInGroup(); index++) {
+            const accelerations = decoder.accelerationDecoder((acceleration) => {
+                const values = [];
+
+                for (let index = 0; index < acceleration.numInGroup(); index++) {
+                    values.push([acceleration.mph(), acceleration.seconds()]);
+                    acceleration.advance();
+                }
+
+                return values;
+            });
+            values.push([decoder.octaneRating(), accelerations]);
+            decoder.advance();
+        }
+
+        return values;
+    });
+    assert.deepEqual(performanceFigures, [
+        [95, [[30, 4.0], [60, 7.5], [100, Math.fround(12.2)]]],
+        [99, [[30, Math.fround(3.8)], [60, Math.fround(7.1)], [100, Math.fround(11.8)]]],
+    ]);
+
+    assert.equal(car.manufacturerDecoder(text), "Honda");
+    assert.equal(car.modelDecoder(text), "Civic VTi");
+    assert.equal(car.activationCodeDecoder(text), "abcdef");
+    assert.equal(car.size(), original().length);
+});
+
+test("sizes a car without decoding it", () => {
+    const car = new CarDecoder(original());
+
+    assert.equal(car.size(), original().length);
+    assert.equal(car.manufacturerDecoder(text), "Honda");
+});
+
+test("rejects values out of bounds", () => {
+    const car = new CarEncoder(new Uint8Array(1024));
+
+    car.fuelFiguresEncoder(() => {});
+    car.performanceFiguresEncoder((performanceFigures) => {
+        assert.throws(
+            () => performanceFigures.octaneRating(80),
+            (error) => error instanceof SbeError && error.kind === SbeErrorKind.VALUE_OUT_OF_BOUNDS,
+        );
+    });
+});
+
+test("dispatches messages by template id", () => {
+    let serialNumber = null;
+
+    const message = new AnyMessageDecoder(original());
+    assert.equal(message.templateId(), CarDecoder.TEMPLATE_ID);
+    message.accept({
+        onCar(decoder) {
+            serialNumber = decoder.serialNumber();
+        },
+    });
+    assert.equal(serialNumber, 1234n);
+
+    const unknown = original();
+    new DataView(unknown.buffer).setUint16(2, 999, true);
+
+    assert.throws(
+        () => new AnyMessageDecoder(unknown),
+        (error) => error instanceof SbeError && error.kind === SbeErrorKind.UNKNOWN_MESSAGE_TYPE,
+    );
+    assert.throws(
+        () => new CarDecoder(unknown),
+        (error) => error instanceof SbeError && error.kind === SbeErrorKind.WRONG_MESSAGE_TYPE,
+    );
+
+    const foreign = original();
+    new DataView(foreign.buffer).setUint16(4, 2, true);
+
+    assert.throws(
+        () => new AnyMessageDecoder(foreign),
+        (error) => error instanceof SbeError && error.kind === SbeErrorKind.WRONG_SCHEMA,
+    );
+    assert.throws(
+        () => new CarDecoder(foreign),
+        (error) => error instanceof SbeError && error.kind === SbeErrorKind.WRONG_SCHEMA,
+    );
+});
+
+test("rejects truncated messages", () => {
+    const truncated = original().subarray(0, 60);
+
+    assert.throws(
+        () => new CarDecoder(truncated).fuelFiguresDecoder((decoder) => decoder.usageDescriptionDecoder(text)),
+        (error) => error instanceof SbeError && error.kind === SbeErrorKind.CODEC_OUT_OF_BOUNDS,
+    );
+});
@@ -0,0 +1,476 @@
+use crate::generator::c::CGenerator;
+use crate::generator::cpp::CppGenerator;
+use crate::generator::csharp::CsharpGenerator;
+use crate::generator::go::GoGenerator;
+use crate::generator::java::JavaGenerator;
+use crate::generator::python::pure::PurePythonGenerator;
+use crate::generator::python::PythonGenerator;
+use crate::generator::rust::{RustGenerator, RustLayout};
+use crate::generator::typescript::TypeScriptGenerator;
+use crate::generator::CodeGenerator;
+use crate::models::schema::{MessageSchema, ValidatedMessageSchema};
+use anyhow::{anyhow, Context, Result};
+use convert_case::{Case, Casing};
+use hard_xml::xmlparser::{Token, Tokenizer};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use xml_include::resolve_xml_includes;
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum Language {
+    Rust,
+    Python,
+    #[cfg_attr(feature = "cli", value(name = "python-pure"))]
+    PythonPure,
+    Java,
+    Cpp,
+    C,
+    Go,
+    #[cfg_attr(feature = "cli", value(name = "typescript"))]
+    TypeScript,
+    #[cfg_attr(feature = "cli", value(name = "csharp"))]
+    Csharp,
+}
+
+/// Generates the codecs of one or more schemas, either from the command line or from the
+/// `build.rs` of a downstream crate:
+///
+/// ```no_run
+/// use sbe_codegen::{Builder, Language};
+///
+/// Builder::new()
+///     .schema("schemas/example-schema.xml")
+///     .language(Language::Rust)
+///     .out_dir(std::env::var("OUT_DIR").unwrap())
+///     .generate()
+///     .unwrap();
+/// ```
+///
+/// The project name and version default to the package and semantic version of the first
+/// schema, and the output directory to `OUT_DIR`. Cargo is asked to rerun the build script
+/// whenever a schema or one of the files it includes changes.
+///
+/// A Cargo project cannot be built from `OUT_DIR`, so the Rust codecs generated there are
+/// a single module mounted at `crate::<project name>` by default, see [`Builder::module`].
+#[derive(Debug, Clone)]
+pub struct Builder {
+    schema_paths: Vec<PathBuf>,
+    language: Option<Language>,
+    project_name: Option<String>,
+    version: Option<String>,
+    out_dir: Option<PathBuf>,
+    test_dependencies: bool,
+    format: bool,
+    bounds_checks: bool,
+    serde: bool,
+    module_path: Option<String>,
+    rerun_if_changed: bool,
+}
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self {
+            schema_paths: Vec::new(),
+            language: None,
+            project_name: None,
+            version: None,
+            out_dir: None,
+            test_dependencies: false,
+            format: false,
+            bounds_checks: true,
+            serde: false,
+            module_path: None,
+            rerun_if_changed: true,
+        }
+    }
+}
+
+impl Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a schema, whose `xi:include` elements are resolved relative to its directory.
+    pub fn schema(mut self, path: impl AsRef<Path>) -> Self {
+        self.schema_paths.push(path.as_ref().to_owned());
+        self
+    }
+
+    pub fn language(mut self, language: Language) -> Self {
+        self.language = Some(language);
+        self
+    }
+
+    pub fn project_name(mut self, project_name: &str) -> Self {
+        self.project_name = Some(project_name.to_owned());
+        self
+    }
+
+    pub fn project_version(mut self, version: &str) -> Self {
+        self.version = Some(version.to_owned());
+        self
+    }
+
+    pub fn out_dir(mut self, path: impl AsRef<Path>) -> Self {
+        self.out_dir = Some(path.as_ref().to_owned());
+        self
+    }
+
+    pub fn with_test_dependencies(mut self, test_dependencies: bool) -> Self {
+        self.test_dependencies = test_dependencies;
+        self
+    }
+
+    /// Formats the generated project with the tools of the language, if they are installed.
+    pub fn format(mut self, format: bool) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Checks the `minValue`/`maxValue` ranges in encoders, which is enabled by default.
+    pub fn bounds_checks(mut self, bounds_checks: bool) -> Self {
+        self.bounds_checks = bounds_checks;
+        self
+    }
+
+    /// Derives serde traits behind a `serde` feature of the generated Rust crate, or of the
+    /// crate mounting the generated module.
+    pub fn serde(mut self, serde: bool) -> Self {
+        self.serde = serde;
+        self
+    }
+
+    /// Generates the Rust codecs as a single file with their module tree instead of a Cargo
+    /// project, which an existing crate mounts at `path`, e.g. `crate::example` for the
+    /// `example.rs` written to the output directory. This is the default in `OUT_DIR`, with
+    /// the project name as the module.
+    pub fn module(mut self, path: &str) -> Self {
+        self.module_path = Some(path.to_owned());
+        self
+    }
+
+    /// Prints the `cargo:rerun-if-changed` instructions of the schemas and their includes,
+    /// which is enabled by default and only meaningful in a build script.
+    pub fn rerun_if_changed(mut self, rerun_if_changed: bool) -> Self {
+        self.rerun_if_changed = rerun_if_changed;
+        self
+    }
+
+    /// Loads and validates the schemas without generating any code.
+    pub fn load(&self) -> Result<Vec<ValidatedMessageSchema>> {
+        if self.schema_paths.is_empty() {
+            return Err(anyhow!("No schemas found"));
+        }
+
+        let mut schemas = Vec::new();
+
+        for schema_path in &self.schema_paths {
+            if self.rerun_if_changed {
+                for file in schema_files(schema_path)? {
+                    println!("cargo:rerun-if-changed={}", file.display());
+                }
+            }
+
+            schemas.push(load_schema(schema_path)?);
+        }
+
+        Ok(schemas)
+    }
+
+    /// Generates the codecs, returning the validated schemas they were generated from.
+    pub fn generate(&self) -> Result<Vec<ValidatedMessageSchema>> {
+        let language = self.language.ok_or(anyhow!("No language specified"))?;
+        let out_dir = match &self.out_dir {
+            Some(out_dir) => out_dir.to_owned(),
+            None => env::var_os("OUT_DIR").map(PathBuf::from).ok_or(anyhow!(
+                "No output directory specified and OUT_DIR is not set"
+            ))?,
+        };
+
+        let schemas = self.load()?;
+
+        // TODO: A better way to determine project version
+        // It does not matter now, because the release pipeline will set the version
+        // regardless, but this is not pretty.
+        let project_name = self
+            .project_name
+            .clone()
+            .unwrap_or(schemas[0].package.clone());
+        let version = self
+            .version
+            .clone()
+            .unwrap_or(schemas[0].semantic_version.clone());
+
+        // A Cargo project with a workspace of its own in `OUT_DIR` would not be built
+        // by the build script's crate, which can only mount the codecs as a module
+        let module_path = match &self.module_path {
+            None if language == Language::Rust && is_out_dir(&out_dir) => {
+                Some(format!("crate::{}", project_name.to_case(Case::Snake)))
+            }
+            module_path => module_path.clone(),
+        };
+
+        let generator = self.generator(
+            language,
+            schemas.clone(),
+            &project_name,
+            &out_dir,
+            &version,
+            module_path.as_deref(),
+        )?;
+        generator.generate_project(self.test_dependencies, self.format)?;
+
+        Ok(schemas)
+    }
+}
+
+impl Builder {
+    fn generator(
+        &self,
+        language: Language,
+        schemas: Vec<ValidatedMessageSchema>,
+        project_name: &str,
+        project_path: &Path,
+        version: &str,
+        module_path: Option<&str>,
+    ) -> Result<Box<dyn CodeGenerator>> {
+        let bounds_checks = self.bounds_checks;
+
+        if module_path.is_some() && language != Language::Rust {
+            return Err(anyhow!("Only the Rust codecs can be generated as a module"));
+        }
+
+        Ok(match language {
+            Language::Rust => Box::new(RustGenerator::new(
+                project_path,
+                project_name,
+                version,
+                schemas,
+                match module_path {
+                    Some(path) => RustLayout::Module {
+                        path: path.to_owned(),
+                    },
+                    None => RustLayout::Crate {
+                        workspace_root: true,
+                    },
+                },
+                bounds_checks,
+                self.serde,
+            )),
+            Language::Python => Box::new(PythonGenerator::new(
+                project_path,
+                project_name,
+                version,
+                schemas,
+                bounds_checks,
+            )),
+            Language::PythonPure => Box::new(PurePythonGenerator::new(
+                project_path,
+                project_name,
+                version,
+                schemas,
+                bounds_checks,
+            )),
+            Language::Java => Box::new(JavaGenerator::new(
+                project_path,
+                project_name,
+                version,
+                schemas,
+                bounds_checks,
+            )),
+            Language::Cpp => Box::new(CppGenerator::new(
+                project_path,
+                project_name,
+                version,
+                schemas,
+                bounds_checks,
+            )),
+            Language::C => Box::new(CGenerator::new(
+                project_path,
+                project_name,
+                version,
+                schemas,
+                bounds_checks,
+            )),
+            Language::Go => Box::new(GoGenerator::new(
+                project_path,
+                project_name,
+                schemas,
+                bounds_checks,
+            )),
+            Language::TypeScript => Box::new(TypeScriptGenerator::new(
+                project_path,
+                project_name,
+                version,
+                schemas,
+                bounds_checks,
+            )),
+            Language::Csharp => Box::new(CsharpGenerator::new(
+                project_path,
+                project_name,
+                version,
+                schemas,
+                bounds_checks,
+            )),
+        })
+    }
+}
+
+/// Whether the output directory is the `OUT_DIR` of a build script.
+fn is_out_dir(out_dir: &Path) -> bool {
+    env::var_os("OUT_DIR").is_some_and(|env_out_dir| Path::new(&env_out_dir) == out_dir)
+}
+
+fn load_schema(schema_path: &Path) -> Result<ValidatedMessageSchema> {
+    let merged_content =
+        resolve_xml_includes(schema_path).context("failed to resolve XML includes")?;
+
+    let schema =
+        MessageSchema::load_from_string(&merged_content).context("failed to load XML schema")?;
+
+    schema
+        .validate()
+        .with_context(|| format!("failed to validate schema {}", schema_path.display()))
+}
+
+/// Returns the schema followed by the files it includes, recursively. Like `xml_include`,
+/// relative includes are always resolved against the directory of the schema.
+fn schema_files(schema_path: &Path) -> Result<Vec<PathBuf>> {
+    let schema_dir = schema_path.parent().unwrap_or(Path::new(""));
+    let mut files = vec![schema_path.to_owned()];
+    let mut pending = vec![schema_path.to_owned()];
+
+    while let Some(path) = pending.pop() {
+        let content = fs::read_to_string(&path)
+            .with_context(|| format!("failed to read {}", path.display()))?;
+        let mut in_include = false;
+
+        for token in Tokenizer::from(content.as_str()) {
+            match token? {
+                Token::ElementStart { prefix, local, .. } => {
+                    in_include = prefix.as_str() == "xi" && local.as_str() == "include";
+                }
+                Token::Attribute { local, value, .. } if in_include && local.as_str() == "href" => {
+                    let include = schema_dir.join(value.as_str());
+
+                    if !files.contains(&include) {
+                        files.push(include.clone());
+                        pending.push(include);
+                    }
+                }
+                Token::ElementEnd { .. } => in_include = false,
+                _ => {}
+            }
+        }
+    }
+
+    Ok(files)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_schema_files_include_common_types() {
+        let files = schema_files(Path::new("./examples/example-schema.xml")).unwrap();
+
+        assert_eq!(
+            files,
+            vec![
+                PathBuf::from("./examples/example-schema.xml"),
+                PathBuf::from("./examples/common-types.xml"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_generate_returns_loaded_schemas() {
+        let out_dir = env::temp_dir().join(format!("sbe-codegen-builder-{}", std::process::id()));
+
+        let schemas = Builder::new()
+            .schema("./examples/example-schema.xml")
+            .language(Language::Go)
+            .out_dir(&out_dir)
+            .rerun_if_changed(false)
+            .generate()
+            .unwrap();
+        let generated = out_dir.join("baseline").exists();
+        fs::remove_dir_all(&out_dir).unwrap();
+
+        assert_eq!(schemas.len(), 1);
+        assert_eq!(schemas[0].package, "baseline");
+        assert!(generated);
+    }
+
+    #[test]
+    fn test_generate_custom_header() {
+        let out_dir = env::temp_dir().join(format!("sbe-codegen-header-{}", std::process::id()));
+        let schema_path = Path::new("./examples/custom-header-schema.xml");
+
+        let languages = [
+            Language::Rust,
+            Language::Python,
+            Language::PythonPure,
+            Language::Java,
+            Language::Cpp,
+            Language::C,
+            Language::Go,
+            Language::TypeScript,
+            Language::Csharp,
+        ];
+
+        let results = languages
+            .iter()
+            .map(|language| {
+                Builder::new()
+                    .schema(schema_path)
+                    .language(*language)
+                    .out_dir(out_dir.join(format!("{:?}", language)))
+                    .rerun_if_changed(false)
+                    .generate()
+                    .map(|_| ())
+            })
+            .collect::<Vec<_>>();
+        fs::remove_dir_all(&out_dir).unwrap();
+
+        for (language, result) in languages.iter().zip(results) {
+            assert!(result.is_ok(), "{:?}: {:?}", language, result);
+        }
+    }
+
+    #[test]
+    fn test_generate_rust_module_in_out_dir() {
+        let out_dir = env::temp_dir().join(format!("sbe-codegen-out-dir-{}", std::process::id()));
+        env::set_var("OUT_DIR", &out_dir);
+
+        let result = Builder::new()
+            .schema("./examples/example-schema.xml")
+            .language(Language::Rust)
+            .rerun_if_changed(false)
+            .generate();
+        env::remove_var("OUT_DIR");
+        let generated = fs::read_dir(&out_dir).map(|entries| {
+            entries
+                .map(|entry| entry.unwrap().file_name())
+                .collect::<Vec<_>>()
+        });
+        fs::remove_dir_all(&out_dir).unwrap();
+
+        assert!(result.is_ok(), "{:?}", result);
+        assert_eq!(generated.unwrap(), vec!["baseline.rs"]);
+    }
+
+    #[test]
+    fn test_generate_requires_language() {
+        let result = Builder::new()
+            .schema("./examples/example-schema.xml")
+            .out_dir(env::temp_dir())
+            .rerun_if_changed(false)
+            .generate();
+
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,107 @@
+use crate::generator::c::codecs::{buffer_suffix, c_type};
+use crate::generator::c::module::ModuleGenerator;
+use crate::models::types::primitive_type::NativeType;
+use crate::models::ByteOrder;
+use genco::prelude::*;
+
+/// Unsigned types, which are read and written byte by byte.
+const UNSIGNED_TYPES: [(NativeType, usize); 4] = [
+    (NativeType::UInt8, 1),
+    (NativeType::UInt16, 2),
+    (NativeType::UInt32, 4),
+    (NativeType::UInt64, 8),
+];
+
+/// Other types, which are read and written as the bits of the unsigned type of the same size.
+const BIT_CAST_TYPES: [(NativeType, NativeType); 7] = [
+    (NativeType::Char, NativeType::UInt8),
+    (NativeType::Int8, NativeType::UInt8),
+    (NativeType::Int16, NativeType::UInt16),
+    (NativeType::Int32, NativeType::UInt32),
+    (NativeType::Int64, NativeType::UInt64),
+    (NativeType::Float, NativeType::UInt32),
+    (NativeType::Double, NativeType::UInt64),
+];
+
+impl ModuleGenerator<'_> {
+    /// Generates the functions reading and writing all primitive types in the byte order of
+    /// the schema, regardless of the one of the host. The callers check the bounds of the
+    /// buffer themselves.
+    pub(crate) fn generate_buffer_functions(&self) -> Tokens<C> {
+        let mut functions: Tokens<C> = quote!();
+
+        for (native_type, size) in &UNSIGNED_TYPES {
+            let lang_type = c_type(native_type);
+            let shift = match self.schema.byte_order {
+                ByteOrder::LittleEndian => "8 * idx".to_owned(),
+                ByteOrder::BigEndian => format!("8 * ({} - idx)", size - 1),
+            };
+
+            functions.line();
+            functions.append(if *size == 1 {
+                quote! {
+                    static inline $lang_type $(self.get_function(native_type))(const uint8_t *buf) {
+                        return buf[0];
+                    }
+
+                    static inline void $(self.put_function(native_type))(uint8_t *buf, $lang_type value) {
+                        buf[0] = value;
+                    }
+                }
+            } else {
+                quote! {
+                    static inline $lang_type $(self.get_function(native_type))(const uint8_t *buf) {
+                        $lang_type value = 0;
+
+                        for (size_t idx = 0; idx < $(*size); ++idx) {
+                            value |= ($lang_type)(($lang_type)buf[idx] << ($(&shift)));
+                        }
+
+                        return value;
+                    }
+
+                    static inline void $(self.put_function(native_type))(uint8_t *buf, $lang_type value) {
+                        for (size_t idx = 0; idx < $(*size); ++idx) {
+                            buf[idx] = (uint8_t)(value >> ($(&shift)));
+                        }
+                    }
+                }
+            });
+        }
+
+        for (native_type, bits_type) in &BIT_CAST_TYPES {
+            let lang_type = c_type(native_type);
+            let bits_lang_type = c_type(bits_type);
+
+            functions.line();
+            functions.append(quote! {
+                static inline $lang_type $(self.get_function(native_type))(const uint8_t *buf) {
+                    const $bits_lang_type bits = $(self.get_function(bits_type))(buf);
+                    $lang_type value;
+
+                    memcpy(&value, &bits, sizeof(value));
+                    return value;
+                }
+
+                static inline void $(self.put_function(native_type))(uint8_t *buf, $lang_type value) {
+                    $bits_lang_type bits;
+
+                    memcpy(&bits, &value, sizeof(bits));
+                    $(self.put_function(bits_type))(buf, bits);
+                }
+            });
+        }
+
+        functions
+    }
+
+    /// Function reading a primitive type from the buffer, e.g. `baseline_get_uint16`.
+    pub(crate) fn get_function(&self, native_type: &NativeType) -> String {
+        format!("{}_get_{}", self.prefix, buffer_suffix(native_type))
+    }
+
+    /// Function writing a primitive type to the buffer, e.g. `baseline_put_uint16`.
+    pub(crate) fn put_function(&self, native_type: &NativeType) -> String {
+        format!("{}_put_{}", self.prefix, buffer_suffix(native_type))
+    }
+}
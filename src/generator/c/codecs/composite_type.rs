@@ -0,0 +1,496 @@
+use crate::generator::c::codecs::{
+    bounds_checks, field_metadata, literal, null_value, null_value_condition,
+};
+use crate::generator::c::module::ModuleGenerator;
+use crate::generator::common::FieldMetadata;
+use crate::models::layout::BlockLayout;
+use crate::models::types::composite_type::CompositeType;
+use crate::models::types::encoded_data_type::EncodedDataType;
+use crate::models::types::enum_type::EnumType;
+use crate::models::types::primitive_type::{NativeType, ResolvableType};
+use crate::models::types::set_type::SetType;
+use crate::models::types::{CharacterEncoding, Presence, SizedEncoded, Type};
+use anyhow::{anyhow, Result};
+use convert_case::{Case, Casing};
+use genco::prelude::*;
+
+impl ModuleGenerator<'_> {
+    fn generate_composite_codec(&self, composite_type: &CompositeType) -> Result<Tokens<C>> {
+        let ident = self.type_ident(&composite_type.name);
+        let layout = BlockLayout::from_composite(composite_type, &self.schema.types)?;
+
+        let mut accessors: Tokens<C> = quote!();
+
+        for (field, offset) in composite_type.fields.iter().zip(layout.offsets) {
+            accessors.line();
+            accessors.append(self.generate_field_accessors(
+                &ident,
+                field.name(),
+                field,
+                offset,
+                0,
+            )?);
+        }
+
+        Ok(quote! {
+            $(format!("#define {}_ENCODED_LENGTH {}", ident.to_uppercase(), layout.block_length))
+            $accessors
+        })
+    }
+
+    pub(crate) fn generate_composite_codecs(&self) -> Result<Tokens<C>> {
+        let mut composite_tokens: Tokens<C> = quote!();
+
+        for composite_type in self.schema.types.composite_types.values() {
+            composite_tokens.line();
+            composite_tokens.append(self.generate_composite_codec(composite_type)?);
+        }
+
+        Ok(composite_tokens)
+    }
+
+    /// Generates the accessors of a field at the given offset of a block, the getter used by
+    /// decoders and the setter used by encoders, e.g. `baseline_car_get_serial_number`.
+    ///
+    /// The accessors take the block as a `(buf, len)` pair, where `len` is the block length of
+    /// the decoded data. A non-zero `since_version` means the field was added in that version
+    /// of the schema, so it lies past the end of the blocks encoded with older versions, for
+    /// which the getter returns the null value of the field instead of an error.
+    pub(crate) fn generate_field_accessors(
+        &self,
+        scope: &str,
+        field_name: &str,
+        field: &Type,
+        offset: usize,
+        since_version: usize,
+    ) -> Result<Tokens<C>> {
+        Ok(match self.resolved(field)? {
+            Type::EncodedData(encoded_type) => match encoded_type.presence {
+                Presence::Constant => {
+                    self.generate_encoded_constant_field(scope, field_name, &encoded_type)?
+                }
+                _ => self.generate_encoded_field_accessors(
+                    scope,
+                    field_name,
+                    &encoded_type,
+                    offset,
+                    since_version,
+                )?,
+            },
+            Type::Enum(enum_type) => self.generate_enum_field_accessors(
+                scope,
+                field_name,
+                &enum_type,
+                offset,
+                since_version,
+            )?,
+            Type::Set(set_type) => self.generate_set_field_accessors(
+                scope,
+                field_name,
+                &set_type,
+                offset,
+                since_version,
+            )?,
+            Type::Composite(composite_type) => self.generate_composite_field_accessors(
+                scope,
+                field_name,
+                &composite_type,
+                offset,
+                since_version,
+            )?,
+            Type::Reference(reference_type) => {
+                return Err(anyhow!(
+                    "Unresolved reference to {} in field '{}'",
+                    reference_type.type_name,
+                    field_name
+                ))
+            }
+        })
+    }
+
+    fn generate_encoded_constant_field(
+        &self,
+        scope: &str,
+        field_name: &str,
+        encoded_type: &EncodedDataType,
+    ) -> Result<Tokens<C>> {
+        let metadata = field_metadata(field_name, encoded_type, &self.schema.types)?;
+        let macro_name = format!("{}_{}", scope, metadata.field_name).to_uppercase();
+
+        let value = encoded_type.default_value.as_ref().ok_or(anyhow!(
+            "Constant field {} has no default value",
+            metadata.field_name
+        ))?;
+
+        Ok(match metadata.field_primitive_type {
+            NativeType::Char => quote!($(format!("#define {} ", macro_name))$(quoted(value))),
+            _ => quote!($(format!(
+                "#define {} {}",
+                macro_name,
+                literal(value, &metadata.field_primitive_type)
+            ))),
+        })
+    }
+
+    fn generate_encoded_field_accessors(
+        &self,
+        scope: &str,
+        field_name: &str,
+        encoded_type: &EncodedDataType,
+        offset: usize,
+        since_version: usize,
+    ) -> Result<Tokens<C>> {
+        let metadata = field_metadata(field_name, encoded_type, &self.schema.types)?;
+        let name = &metadata.field_name;
+        let lang_type = &metadata.lang_type;
+        let optional = encoded_type.presence == Presence::Optional;
+        let null_value = null_value(encoded_type, &metadata);
+        let get = self.get_function(&metadata.field_primitive_type);
+        let put = self.put_function(&metadata.field_primitive_type);
+        let end = offset + metadata.field_length * metadata.type_size;
+        let error_type = self.error_type();
+        let ok = self.error("Ok");
+
+        let null_check = |variable: &str, checks: Tokens<C>| -> Tokens<C> {
+            if checks.is_empty() || !optional {
+                checks
+            } else {
+                quote! {
+                    if (!$(scope)_$(name)_is_null($variable)) {
+                        $checks
+                    }
+                }
+            }
+        };
+        let value_checks = |variable: &str, enabled: bool| -> Tokens<C> {
+            if enabled {
+                null_check(
+                    variable,
+                    bounds_checks(
+                        variable,
+                        encoded_type,
+                        &metadata.field_primitive_type,
+                        &self.error("ValueOutOfBounds"),
+                    ),
+                )
+            } else {
+                quote!()
+            }
+        };
+
+        Ok(
+            match (&metadata.field_primitive_type, metadata.field_length) {
+                (NativeType::Char, 2..) => {
+                    let ascii = matches!(metadata.encoding, Some(CharacterEncoding::Ascii));
+                    let length_macro = format!("{}_{}_LENGTH", scope, name).to_uppercase();
+
+                    quote! {
+                        $(format!("#define {} {}", length_macro, metadata.field_length))
+
+                        $("/// Copies the characters of the field to the value, which has to hold all of them.")
+                        $("/// Strings shorter than the field are padded with null characters.")
+                        $(version_doc(since_version))
+                        static inline $(&error_type) $(scope)_get_$(name)(const uint8_t *buf, size_t len, char *value) {
+                            $(self.length_check(end, since_version, quote! {
+                                for (size_t idx = 0; idx < $(&length_macro); ++idx) {
+                                    value[idx] = $(&null_value);
+                                }
+                            }))
+
+                            memcpy(value, $(offset_expr(offset)), $(&length_macro));
+                            $(if ascii {
+                                $['\n']
+                                for (size_t idx = 0; idx < $(&length_macro); ++idx) {
+                                    if ((uint8_t)value[idx] > 127) {
+                                        return $(self.error("InvalidStringValue"));
+                                    }
+                                }
+                            })
+
+                            return $(&ok);
+                        }
+
+                        $("/// Writes the characters of the value padded with null characters.")
+                        $(if optional {
+                            $("/// A null value writes the null value of the field instead.")
+                        })
+                        static inline $(&error_type) $(scope)_set_$(name)(uint8_t *buf, size_t len, const char *value, size_t value_len) {
+                            if (len < $end) {
+                                return $(self.error("CodecOutOfBounds"));
+                            }
+                            $(if optional {
+                                $['\n']
+                                if (value == NULL) {
+                                    for (size_t idx = 0; idx < $(&length_macro); ++idx) {
+                                        $(&put)($(offset_expr(offset)) + idx, $(&null_value));
+                                    }
+
+                                    return $(&ok);
+                                }
+                            })
+                            $(if ascii {
+                                $['\n']
+                                for (size_t idx = 0; idx < value_len; ++idx) {
+                                    if ((uint8_t)value[idx] > 127) {
+                                        return $(self.error("InvalidStringValue"));
+                                    }
+                                }
+                            })
+
+                            if (value_len > $(&length_macro)) {
+                                return $(self.error("ValueOutOfBounds"));
+                            }
+
+                            memcpy($(offset_expr(offset)), value, value_len);
+                            memset($(offset_expr(offset)) + value_len, 0, $(&length_macro) - value_len);
+                            return $(&ok);
+                        }
+                    }
+                }
+                (_, 2..) => {
+                    let length_macro = format!("{}_{}_LENGTH", scope, name).to_uppercase();
+                    let element = quote!($(offset_expr(offset)) + idx * $(metadata.type_size));
+
+                    quote! {
+                        $(format!("#define {} {}", length_macro, metadata.field_length))
+                        $(self.null_value_accessors(scope, encoded_type, &metadata))
+
+                        $("/// Copies the elements of the field to the value, which has to hold all of them.")
+                        $(version_doc(since_version))
+                        static inline $(&error_type) $(scope)_get_$(name)(const uint8_t *buf, size_t len, $lang_type *value) {
+                            $(self.length_check(end, since_version, quote! {
+                                for (size_t idx = 0; idx < $(&length_macro); ++idx) {
+                                    value[idx] = $(&null_value);
+                                }
+                            }))
+
+                            for (size_t idx = 0; idx < $(&length_macro); ++idx) {
+                                value[idx] = $(&get)($(&element));
+                            }
+
+                            return $(&ok);
+                        }
+
+                        static inline $(&error_type) $(scope)_set_$(name)(uint8_t *buf, size_t len, const $lang_type *value, size_t value_len) {
+                            if (value_len != $(&length_macro)) {
+                                return $(self.error("WrongSliceSize"));
+                            }
+
+                            if (len < $end) {
+                                return $(self.error("CodecOutOfBounds"));
+                            }
+
+                            for (size_t idx = 0; idx < $(&length_macro); ++idx) {
+                                $(value_checks("value[idx]", self.bounds_checks))
+                                $(&put)($(&element), value[idx]);
+                            }
+
+                            return $(&ok);
+                        }
+                    }
+                }
+                (_, _) => quote! {
+                    $(self.null_value_accessors(scope, encoded_type, &metadata))
+                    $(version_doc(since_version))
+                    static inline $(&error_type) $(scope)_get_$(name)(const uint8_t *buf, size_t len, $lang_type *value) {
+                        $(self.length_check(end, since_version, quote!(*value = $(&null_value);)))
+
+                        *value = $(&get)($(offset_expr(offset)));
+                        $(value_checks("*value", true))
+                        return $(&ok);
+                    }
+
+                    static inline $(&error_type) $(scope)_set_$(name)(uint8_t *buf, size_t len, $lang_type value) {
+                        $(value_checks("value", self.bounds_checks))
+                        if (len < $end) {
+                            return $(self.error("CodecOutOfBounds"));
+                        }
+
+                        $(&put)($(offset_expr(offset)), value);
+                        return $(&ok);
+                    }
+                },
+            },
+        )
+    }
+
+    /// Macro of the null value of an optional field and the function checking it, which
+    /// handles the `NAN` null values of floating point fields.
+    fn null_value_accessors(
+        &self,
+        scope: &str,
+        encoded_type: &EncodedDataType,
+        metadata: &FieldMetadata<C>,
+    ) -> Tokens<C> {
+        if encoded_type.presence != Presence::Optional {
+            return quote!();
+        }
+
+        let name = &metadata.field_name;
+
+        quote! {
+            $(format!("#define {}_{}_NULL_VALUE {}", scope.to_uppercase(), name.to_uppercase(), null_value(encoded_type, metadata)))
+
+            static inline bool $(scope)_$(name)_is_null($(&metadata.lang_type) value) {
+                return $(null_value_condition("value", encoded_type, metadata));
+            }
+            $['\n']
+        }
+    }
+
+    fn generate_enum_field_accessors(
+        &self,
+        scope: &str,
+        field_name: &str,
+        enum_type: &EnumType,
+        offset: usize,
+        since_version: usize,
+    ) -> Result<Tokens<C>> {
+        let name = field_name.to_case(Case::Snake);
+        let enum_ident = self.type_ident(&enum_type.name);
+        let encoding_type = enum_type
+            .encoding_type
+            .resolved(&self.schema.types.encoded_types)?;
+        let end = offset + enum_type.size(&self.schema.types)?;
+        let error_type = self.error_type();
+
+        Ok(quote! {
+            $(version_doc(since_version))
+            static inline $(&error_type) $(scope)_get_$(&name)(const uint8_t *buf, size_t len, $(&enum_ident)_t *value) {
+                $(self.length_check(end, since_version, quote!(*value = $(enum_ident.to_uppercase())_NULL_VALUE;)))
+
+                *value = $(self.get_function(&encoding_type))($(offset_expr(offset)));
+                if (!$(&enum_ident)_is_valid(*value)) {
+                    return $(self.error("InvalidEnumValue"));
+                }
+
+                return $(self.error("Ok"));
+            }
+
+            static inline $(&error_type) $(scope)_set_$(&name)(uint8_t *buf, size_t len, $(&enum_ident)_t value) {
+                if (!$(&enum_ident)_is_valid(value)) {
+                    return $(self.error("InvalidEnumValue"));
+                }
+
+                if (len < $end) {
+                    return $(self.error("CodecOutOfBounds"));
+                }
+
+                $(self.put_function(&encoding_type))($(offset_expr(offset)), value);
+                return $(self.error("Ok"));
+            }
+        })
+    }
+
+    fn generate_set_field_accessors(
+        &self,
+        scope: &str,
+        field_name: &str,
+        set_type: &SetType,
+        offset: usize,
+        since_version: usize,
+    ) -> Result<Tokens<C>> {
+        let name = field_name.to_case(Case::Snake);
+        let set_ident = self.type_ident(&set_type.name);
+        let encoding_type = set_type
+            .encoding_type
+            .resolved(&self.schema.types.encoded_types)?;
+        let end = offset + set_type.size(&self.schema.types)?;
+        let error_type = self.error_type();
+
+        Ok(quote! {
+            $(version_doc(since_version))
+            static inline $(&error_type) $(scope)_get_$(&name)(const uint8_t *buf, size_t len, $(&set_ident)_t *value) {
+                $(self.length_check(end, since_version, quote!(*value = 0;)))
+
+                *value = $(self.get_function(&encoding_type))($(offset_expr(offset)));
+                return $(self.error("Ok"));
+            }
+
+            static inline $(&error_type) $(scope)_set_$(&name)(uint8_t *buf, size_t len, $(&set_ident)_t value) {
+                if (len < $end) {
+                    return $(self.error("CodecOutOfBounds"));
+                }
+
+                $(self.put_function(&encoding_type))($(offset_expr(offset)), value);
+                return $(self.error("Ok"));
+            }
+        })
+    }
+
+    fn generate_composite_field_accessors(
+        &self,
+        scope: &str,
+        field_name: &str,
+        composite_type: &CompositeType,
+        offset: usize,
+        since_version: usize,
+    ) -> Result<Tokens<C>> {
+        let name = field_name.to_case(Case::Snake);
+        let length_macro = format!(
+            "{}_ENCODED_LENGTH",
+            self.type_ident(&composite_type.name).to_uppercase()
+        );
+        let end = offset + composite_type.size(&self.schema.types)?;
+        let error_type = self.error_type();
+
+        Ok(quote! {
+            $(format!("/// Returns the encoded composite, which is {} bytes long.", length_macro))
+            $(if since_version > 0 {
+                $(format!("/// Added in version {} of the schema, a null pointer is returned for the blocks", since_version))
+                $("/// encoded with an older one.")
+            })
+            static inline $(&error_type) $(scope)_get_$(&name)(const uint8_t *buf, size_t len, const uint8_t **value) {
+                $(self.length_check(end, since_version, quote!(*value = NULL;)))
+
+                *value = $(offset_expr(offset));
+                return $(self.error("Ok"));
+            }
+
+            $(format!("/// Returns the part of the block the composite is encoded to, which is {} bytes long.", length_macro))
+            static inline $(&error_type) $(scope)_set_$(&name)(uint8_t *buf, size_t len, uint8_t **value) {
+                if (len < $end) {
+                    return $(self.error("CodecOutOfBounds"));
+                }
+
+                *value = $(offset_expr(offset));
+                return $(self.error("Ok"));
+            }
+        })
+    }
+
+    /// Returns an error when the field ends past the end of the block, unless the field was
+    /// added in a newer version of the schema than the block, then it is set to null instead.
+    fn length_check(&self, end: usize, since_version: usize, absent: Tokens<C>) -> Tokens<C> {
+        quote! {
+            if (len < $end) {
+                $(if since_version > 0 {
+                    $absent
+                    return $(self.error("Ok"));
+                } else {
+                    return $(self.error("CodecOutOfBounds"));
+                })
+            }
+        }
+    }
+}
+
+/// Pointer to the field at the offset of the block.
+fn offset_expr(offset: usize) -> String {
+    match offset {
+        0 => "buf".to_owned(),
+        offset => format!("buf + {}", offset),
+    }
+}
+
+/// Documents the null value returned for a field missing in older versions of the schema.
+fn version_doc(since_version: usize) -> Tokens<C> {
+    if since_version == 0 {
+        return quote!();
+    }
+
+    quote! {
+        $(format!("/// Added in version {} of the schema, the null value is returned for the blocks", since_version))
+        $("/// encoded with an older one.")
+    }
+}
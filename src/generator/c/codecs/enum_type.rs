@@ -0,0 +1,74 @@
+use crate::generator::c::codecs::{c_type, literal, null_literal};
+use crate::generator::c::module::ModuleGenerator;
+use crate::models::types::enum_type::EnumType;
+use crate::models::types::primitive_type::{NativeType, ResolvableType};
+use anyhow::Result;
+use convert_case::{Case, Casing};
+use genco::prelude::*;
+
+impl ModuleGenerator<'_> {
+    /// Generates the typedef of an enum as its encoding type, with a macro for every value
+    /// of the schema, e.g. `BASELINE_MODEL_A`.
+    fn generate_enum_codec(&self, enum_type: &EnumType) -> Result<Tokens<C>> {
+        let ident = self.type_ident(&enum_type.name);
+        let macro_name = ident.to_uppercase();
+        let encoding_type = enum_type
+            .encoding_type
+            .resolved(&self.schema.types.encoded_types)?;
+        let char_encoding = encoding_type == NativeType::Char;
+
+        let values = enum_type
+            .values
+            .iter()
+            .map(|value| {
+                let encoded_value = value.encoded_value(char_encoding)?;
+
+                Ok((
+                    format!("{}_{}", macro_name, value.name.to_case(Case::UpperSnake)),
+                    match char::from_u32(encoded_value as u32) {
+                        Some(value) if char_encoding && value.is_ascii_alphanumeric() => {
+                            format!("'{}'", value)
+                        }
+                        _ => literal(&encoded_value.to_string(), &encoding_type),
+                    },
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let null_value_name = format!("{}_NULL_VALUE", macro_name);
+
+        Ok(quote! {
+            typedef $(c_type(&encoding_type)) $(&ident)_t;
+
+            $(for (value_name, value) in &values {
+                $['\r']
+                $(format!("#define {} {}", value_name, value))
+            })
+            $(format!("#define {} {}", null_value_name, null_literal(&encoding_type)))
+
+            $("/// Returns whether the encoded value is one of the schema, or the null value.")
+            static inline bool $(&ident)_is_valid($(&ident)_t value) {
+                switch (value) {
+                $(for (value_name, _) in &values {
+                    $['\r']
+                    case $value_name:
+                })
+                case $(&null_value_name):
+                    return true;
+                default:
+                    return false;
+                }
+            }
+        })
+    }
+
+    pub(crate) fn generate_enum_codecs(&self) -> Result<Tokens<C>> {
+        let mut enum_tokens: Tokens<C> = quote!();
+
+        for enum_type in self.schema.types.enum_types.values() {
+            enum_tokens.line();
+            enum_tokens.append(self.generate_enum_codec(enum_type)?);
+        }
+
+        Ok(enum_tokens)
+    }
+}
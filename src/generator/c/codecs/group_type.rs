@@ -0,0 +1,230 @@
+use crate::generator::c::codecs::{c_type, max_literal, Codec};
+use crate::generator::c::module::ModuleGenerator;
+use crate::generator::common::{dimension_type, field_groups};
+use crate::models::layout::BlockLayout;
+use crate::models::types::field_type::FieldType;
+use crate::models::types::group_type::GroupType;
+use crate::models::types::primitive_type::ResolvableType;
+use crate::models::types::{MessageField, Presence, SizedEncoded, Type};
+use anyhow::{anyhow, Result};
+use convert_case::{Case, Casing};
+use genco::prelude::*;
+
+impl ModuleGenerator<'_> {
+    /// Generates the codec of the block, groups and var data of a message or an entry of a
+    /// group, whose identifiers all start with the scope, e.g. `baseline_car_fuel_figures`.
+    pub(crate) fn generate_block_codec(
+        &self,
+        scope: &str,
+        block_fields: &[MessageField],
+        block_length: Option<usize>,
+    ) -> Result<Codec> {
+        let (fields, groups, var_data) = field_groups(block_fields);
+        let layout = BlockLayout::from_fields(scope, &fields, block_length, &self.schema.types)?;
+
+        let mut codec = Codec::default();
+
+        codec.inline(quote! {
+            $(format!("#define {}_BLOCK_LENGTH {}", scope.to_uppercase(), layout.block_length))
+        });
+
+        for (field, offset) in fields.iter().zip(layout.offsets) {
+            let field_type = field.to_type(&self.schema.types)?;
+
+            codec.inline(if field.presence == Presence::Constant {
+                self.generate_constant_enum(scope, field, field_type)?
+            } else {
+                self.generate_field_accessors(
+                    scope,
+                    &field.name,
+                    &field_type,
+                    offset,
+                    field.since_version.unwrap_or(0),
+                )?
+            });
+        }
+
+        for group in groups {
+            codec.extend(self.generate_group_codec(scope, group)?);
+        }
+
+        for var_data_type in var_data {
+            codec.extend(self.generate_var_data_codec(scope, var_data_type)?);
+        }
+
+        Ok(codec)
+    }
+
+    fn generate_constant_enum(
+        &self,
+        scope: &str,
+        field: &FieldType,
+        field_type: Type,
+    ) -> Result<Tokens<C>> {
+        let enum_type = match field_type {
+            Type::Enum(enum_type) => enum_type,
+            _ => return Err(anyhow!("Constant field {} is not an enum", field.name)),
+        };
+
+        let value_ref = field
+            .value_ref
+            .as_ref()
+            .ok_or(anyhow!("Constant field {} has no ref value", field.name))?;
+        let value_name = value_ref.split('.').nth(1).unwrap_or(value_ref);
+        let value = enum_type
+            .values
+            .iter()
+            .find(|value| value.name == value_name)
+            .ok_or(anyhow!(
+                "Constant field {} has no value {}",
+                field.name,
+                value_name
+            ))?;
+
+        Ok(quote! {
+            $(format!(
+                "#define {}_{} {}_{}",
+                scope.to_uppercase(),
+                field.name.to_case(Case::UpperSnake),
+                self.type_ident(&enum_type.name).to_uppercase(),
+                value.name.to_case(Case::UpperSnake)
+            ))
+        })
+    }
+
+    /// Generates the functions reading the dimension of a group and the blocks of its entries
+    /// from a decoder, and the ones writing them to an encoder, followed by the codecs of the
+    /// entries.
+    fn generate_group_codec(&self, parent_scope: &str, group: &GroupType) -> Result<Codec> {
+        let scope = format!("{}_{}", parent_scope, group.name.to_case(Case::Snake));
+        let types = &self.schema.types;
+
+        let dimension_type = dimension_type(group, &types.composite_types)?;
+        let dimension_size = dimension_type.size(types)?;
+        let (block_length_type, block_length_offset) = self.encoded_field(dimension_type, 0)?;
+        let (num_in_group_type, num_in_group_offset) = self.encoded_field(dimension_type, 1)?;
+        let block_length_native = block_length_type
+            .primitive_type
+            .resolved(&types.encoded_types)?;
+        let num_in_group_native = num_in_group_type
+            .primitive_type
+            .resolved(&types.encoded_types)?;
+
+        let error_type = self.error_type();
+        let decoder_type = self.cursor_type("decoder");
+        let group_decoder_type = self.cursor_type("group_decoder");
+        let encoder_type = self.cursor_type("encoder");
+        let group_encoder_type = self.cursor_type("group_encoder");
+        let block_length = format!("{}_BLOCK_LENGTH", scope.to_uppercase());
+        let ok = self.error("Ok");
+        let out_of_bounds = self.error("CodecOutOfBounds");
+
+        let mut codec = Codec::default();
+        let entries = self.generate_block_codec(&scope, &group.fields, group.block_length)?;
+
+        codec.function(
+            quote! {
+                $(format!("/// Reads the dimension of the group, whose entries are then read by `{}_decode_next`.", scope))
+                $(if let Some(since_version) = group.since_version.filter(|version| *version > 0) {
+                    $(format!("/// Added in version {} of the schema, the group is empty in the messages encoded", since_version))
+                    $("/// with an older one.")
+                })
+            },
+            quote!($(&error_type) $(&scope)_decode($(&decoder_type) *decoder, $(&group_decoder_type) *group)),
+            quote! {
+                group->decoder = decoder;
+                group->index = 0;
+                $(if let Some(since_version) = group.since_version.filter(|version| *version > 0) {
+                    $['\n']
+                    if (decoder->acting_version < $since_version) {
+                        group->block_length = 0;
+                        group->num_in_group = 0;
+                        return $(&ok);
+                    }
+                })
+
+                if (decoder->len - decoder->position < $dimension_size) {
+                    return $(&out_of_bounds);
+                }
+
+                group->block_length = (size_t)$(self.get_function(&block_length_native))(decoder->buf + decoder->position + $block_length_offset);
+                group->num_in_group = (size_t)$(self.get_function(&num_in_group_native))(decoder->buf + decoder->position + $num_in_group_offset);
+                decoder->position += $dimension_size;
+                return $(&ok);
+            },
+        );
+        codec.function(
+            quote! {
+                $("/// Returns the block of the next entry of the group, which is followed by the groups and")
+                $("/// var data of the entry.")
+            },
+            quote!($(&error_type) $(&scope)_decode_next($(&group_decoder_type) *group, const uint8_t **block, size_t *block_len)),
+            quote! {
+                $(&decoder_type) *decoder = group->decoder;
+
+                if (group->index >= group->num_in_group) {
+                    return $(self.error("GroupOutOfBounds"));
+                }
+
+                if (decoder->len - decoder->position < group->block_length) {
+                    return $(&out_of_bounds);
+                }
+
+                *block = decoder->buf + decoder->position;
+                *block_len = group->block_length;
+                decoder->position += group->block_length;
+                ++group->index;
+                return $(&ok);
+            },
+        );
+        codec.function(
+            quote! {
+                $(format!("/// Writes the dimension of the group, whose entries are then written by `{}_encode_next`.", scope))
+            },
+            quote!($(&error_type) $(&scope)_encode($(&encoder_type) *encoder, $(&group_encoder_type) *group)),
+            quote! {
+                if (encoder->len - encoder->position < $dimension_size) {
+                    return $(&out_of_bounds);
+                }
+
+                group->encoder = encoder;
+                group->dimension_offset = encoder->position;
+                group->num_in_group = 0;
+
+                $(self.put_function(&block_length_native))(encoder->buf + encoder->position + $block_length_offset, ($(c_type(&block_length_native)))$(&block_length));
+                $(self.put_function(&num_in_group_native))(encoder->buf + encoder->position + $num_in_group_offset, 0);
+                encoder->position += $dimension_size;
+                return $(&ok);
+            },
+        );
+        codec.function(
+            quote! {
+                $("/// Returns the block of the next entry of the group, which has to be followed by the groups")
+                $("/// and var data of the entry, and counts the entry in the dimension of the group.")
+            },
+            quote!($(&error_type) $(&scope)_encode_next($(&group_encoder_type) *group, uint8_t **block, size_t *block_len)),
+            quote! {
+                $(&encoder_type) *encoder = group->encoder;
+
+                if (group->num_in_group >= $(max_literal(&num_in_group_native))) {
+                    return $(self.error("GroupOutOfBounds"));
+                }
+
+                if (encoder->len - encoder->position < $(&block_length)) {
+                    return $(&out_of_bounds);
+                }
+
+                ++group->num_in_group;
+                $(self.put_function(&num_in_group_native))(encoder->buf + group->dimension_offset + $num_in_group_offset, ($(c_type(&num_in_group_native)))group->num_in_group);
+
+                *block = encoder->buf + encoder->position;
+                *block_len = $(&block_length);
+                encoder->position += $(&block_length);
+                return $(&ok);
+            },
+        );
+        codec.extend(entries);
+
+        Ok(codec)
+    }
+}
@@ -0,0 +1,191 @@
+use crate::generator::c::codecs::{c_type, Codec};
+use crate::generator::c::module::ModuleGenerator;
+use crate::generator::common::{field_groups, header_counts};
+use crate::models::message::MessageType;
+use crate::models::types::primitive_type::ResolvableType;
+use crate::models::types::SizedEncoded;
+use anyhow::Result;
+use genco::prelude::*;
+
+impl ModuleGenerator<'_> {
+    /// Generates the functions decoding and encoding the header of a message, which return the
+    /// root block of the message and a cursor over its groups and var data, followed by the
+    /// codec of the message block.
+    fn generate_message_codec(&self, message: &MessageType) -> Result<Codec> {
+        let scope = self.type_ident(&message.name);
+        let types = &self.schema.types;
+        let header_size = types.header_type.size(types)?;
+        let template_id = format!("{}_TEMPLATE_ID", scope.to_uppercase());
+        let block_length = format!("{}_BLOCK_LENGTH", scope.to_uppercase());
+
+        let header_field = |field_name: &str| -> Result<(String, String, usize)> {
+            let (encoded_type, offset) = self.header_field(field_name)?;
+            let native_type = encoded_type.primitive_type.resolved(&types.encoded_types)?;
+
+            Ok((
+                self.get_function(&native_type),
+                self.put_function(&native_type),
+                offset,
+            ))
+        };
+        let header_value = |field_name: &str, value: &str| -> Result<Tokens<C>> {
+            let (encoded_type, _) = self.header_field(field_name)?;
+            let native_type = encoded_type.primitive_type.resolved(&types.encoded_types)?;
+            let (_, put, offset) = header_field(field_name)?;
+
+            Ok(quote!($put(buf + $offset, ($(c_type(&native_type)))$value);))
+        };
+        let (get_block_length, _, block_length_offset) = header_field("blockLength")?;
+        let (get_template_id, _, template_id_offset) = header_field("templateId")?;
+        let (get_schema_id, _, schema_id_offset) = header_field("schemaId")?;
+        let (get_version, _, version_offset) = header_field("version")?;
+        let schema_id = format!("{}_SCHEMA_ID", self.macro_prefix());
+        let (_, groups, var_data) = field_groups(&message.fields);
+
+        let error_type = self.error_type();
+        let ok = self.error("Ok");
+        let out_of_bounds = self.error("CodecOutOfBounds");
+
+        let mut codec = Codec::default();
+
+        codec.inline(quote! {
+            $(format!("#define {} {}", template_id, message.id))
+        });
+        codec.function(
+            quote! {
+                $("/// Decodes the header of the message, returning its root block and the decoder of the")
+                $("/// groups and var data following it.")
+            },
+            quote!($(&error_type) $(&scope)_decode(const uint8_t *buf, size_t len, $(self.cursor_type("decoder")) *decoder, const uint8_t **block, size_t *block_len)),
+            quote! {
+                if (len < $header_size) {
+                    return $(&out_of_bounds);
+                }
+
+                if ($get_schema_id(buf + $schema_id_offset) != $(&schema_id)) {
+                    return $(self.error("WrongSchema"));
+                }
+
+                if ((uint16_t)$get_template_id(buf + $template_id_offset) != $(&template_id)) {
+                    return $(self.error("WrongMessageType"));
+                }
+
+                const size_t block_length = (size_t)$get_block_length(buf + $block_length_offset);
+
+                if (len - $header_size < block_length) {
+                    return $(&out_of_bounds);
+                }
+
+                decoder->buf = buf;
+                decoder->len = len;
+                decoder->position = $header_size + block_length;
+                decoder->acting_version = (uint16_t)$get_version(buf + $version_offset);
+
+                *block = buf + $header_size;
+                *block_len = block_length;
+                return $(&ok);
+            },
+        );
+        codec.function(
+            quote! {
+                $("/// Encodes the header of the message, returning its root block and the encoder of the")
+                $("/// groups and var data following it.")
+            },
+            quote!($(&error_type) $(&scope)_encode(uint8_t *buf, size_t len, $(self.cursor_type("encoder")) *encoder, uint8_t **block, size_t *block_len)),
+            quote! {
+                if (len < $header_size + $(&block_length)) {
+                    return $(&out_of_bounds);
+                }
+
+                $(header_value("blockLength", &block_length)?)
+                $(header_value("templateId", &template_id)?)
+                $(header_value("schemaId", &schema_id)?)
+                $(header_value("version", &format!("{}_SCHEMA_VERSION", self.macro_prefix()))?)
+                $(for (field_name, count) in header_counts(types, groups.len(), var_data.len()) {
+                    $(header_value(field_name, &count.to_string())?)
+                    $['\r']
+                })
+
+                encoder->buf = buf;
+                encoder->len = len;
+                encoder->position = $header_size + $(&block_length);
+
+                *block = buf + $header_size;
+                *block_len = $(&block_length);
+                return $(&ok);
+            },
+        );
+        codec.extend(self.generate_block_codec(&scope, &message.fields, message.block_length)?);
+
+        Ok(codec)
+    }
+
+    /// Generates the function reading the template id from the header of any message of the
+    /// schema, which fails for the ones of other schemas.
+    fn generate_message_dispatcher(&self, message_types: &[&MessageType]) -> Result<Codec> {
+        let types = &self.schema.types;
+        let (template_id_type, template_id_offset) = self.header_field("templateId")?;
+        let template_id_native = template_id_type
+            .primitive_type
+            .resolved(&types.encoded_types)?;
+        let (schema_id_type, schema_id_offset) = self.header_field("schemaId")?;
+        let schema_id_native = schema_id_type
+            .primitive_type
+            .resolved(&types.encoded_types)?;
+
+        let mut codec = Codec::default();
+
+        codec.function(
+            quote! {
+                $("/// Reads the template id of a message, which picks the function decoding it.")
+            },
+            quote!($(self.error_type()) $(&self.prefix)_template_id(const uint8_t *buf, size_t len, uint16_t *template_id)),
+            quote! {
+                if (len < $(types.header_type.size(types)?)) {
+                    return $(self.error("CodecOutOfBounds"));
+                }
+
+                if ($(self.get_function(&schema_id_native))(buf + $schema_id_offset) != $(format!("{}_SCHEMA_ID", self.macro_prefix()))) {
+                    return $(self.error("WrongSchema"));
+                }
+
+                *template_id = (uint16_t)$(self.get_function(&template_id_native))(buf + $template_id_offset);
+
+                switch (*template_id) {
+                $(for message_type in message_types {
+                    $['\r']
+                    $(format!("case {}_TEMPLATE_ID:", self.type_ident(&message_type.name).to_uppercase()))
+                })
+                    return $(self.error("Ok"));
+                default:
+                    return $(self.error("UnknownMessageType"));
+                }
+            },
+        );
+
+        Ok(codec)
+    }
+
+    /// Returns the declarations of the codecs of all messages for the header of the schema,
+    /// along with their definitions for the source.
+    pub(crate) fn generate_message_codecs(&self) -> Result<(Tokens<C>, Tokens<C>)> {
+        let message_types = self
+            .schema
+            .message_types
+            .message_types
+            .values()
+            .collect::<Vec<_>>();
+
+        let mut codec = Codec::default();
+
+        for message_type in &message_types {
+            codec.extend(self.generate_message_codec(message_type)?);
+        }
+
+        if !message_types.is_empty() {
+            codec.extend(self.generate_message_dispatcher(&message_types)?);
+        }
+
+        Ok((codec.header, codec.source))
+    }
+}
@@ -0,0 +1,198 @@
+mod composite_type;
+mod enum_type;
+mod group_type;
+mod message_type;
+mod set_type;
+mod var_data_type;
+
+use crate::generator::common::FieldMetadata;
+use crate::models::types::encoded_data_type::EncodedDataType;
+use crate::models::types::primitive_type::{LanguagePrimitive, NativeType, ResolvableType};
+use crate::models::types::SizedEncoded;
+use crate::models::TypeMap;
+use anyhow::Result;
+use convert_case::{Case, Casing};
+use genco::prelude::*;
+
+/// Metadata of an encoded field, with the field name in snake case and the C type.
+pub(crate) fn field_metadata(
+    field_name: &str,
+    encoded_type: &EncodedDataType,
+    types: &TypeMap,
+) -> Result<FieldMetadata<C>> {
+    let field_primitive_type = encoded_type.primitive_type.resolved(&types.encoded_types)?;
+    let field_size = encoded_type.size(types)?;
+    let type_size = encoded_type.primitive_type.size(types)?;
+
+    Ok(FieldMetadata {
+        field_name: field_name.to_case(Case::Snake),
+        lang_type: LanguagePrimitive::new(c_type(&field_primitive_type)),
+        field_primitive_type,
+        type_size,
+        field_length: field_size / type_size,
+        encoding: encoded_type.character_encoding,
+    })
+}
+
+/// C type of a resolved primitive type.
+pub(crate) fn c_type(native_type: &NativeType) -> &'static str {
+    match native_type {
+        NativeType::Char => "char",
+        NativeType::UInt8 => "uint8_t",
+        NativeType::UInt16 => "uint16_t",
+        NativeType::UInt32 => "uint32_t",
+        NativeType::UInt64 => "uint64_t",
+        NativeType::Int8 => "int8_t",
+        NativeType::Int16 => "int16_t",
+        NativeType::Int32 => "int32_t",
+        NativeType::Int64 => "int64_t",
+        NativeType::Float => "float",
+        NativeType::Double => "double",
+        NativeType::Reference(type_name) => unreachable!("Resolved reference: {}", type_name),
+    }
+}
+
+/// Suffix of the functions reading and writing a resolved primitive type in the byte order
+/// of the schema, e.g. `baseline_get_uint16`.
+pub(crate) fn buffer_suffix(native_type: &NativeType) -> &'static str {
+    let c_type = c_type(native_type);
+
+    c_type.strip_suffix("_t").unwrap_or(c_type)
+}
+
+/// C literal of a numeric value from the schema, typed as the primitive type.
+pub(crate) fn literal(value: &str, native_type: &NativeType) -> String {
+    match native_type {
+        NativeType::Char => format!("(char){}", value),
+        NativeType::UInt32 => format!("{}U", value),
+        NativeType::UInt64 => format!("{}ULL", value),
+        NativeType::Int64 => format!("{}LL", value),
+        NativeType::Float => format!("{}f", floating_literal(value)),
+        NativeType::Double => floating_literal(value),
+        _ => value.to_owned(),
+    }
+}
+
+/// Floating point literal, which needs a decimal point or an exponent.
+fn floating_literal(value: &str) -> String {
+    if value.contains(['.', 'e', 'E']) || value.chars().any(char::is_alphabetic) {
+        value.to_owned()
+    } else {
+        format!("{}.0", value)
+    }
+}
+
+/// Default null value of a primitive type, see `NativeType::null`.
+pub(crate) fn null_literal(native_type: &NativeType) -> String {
+    match native_type {
+        NativeType::Char => "(char)0".to_owned(),
+        NativeType::UInt8 | NativeType::UInt16 | NativeType::UInt32 | NativeType::UInt64 => {
+            max_literal(native_type)
+        }
+        NativeType::Int8 | NativeType::Int16 | NativeType::Int32 | NativeType::Int64 => {
+            format!("{}_MIN", buffer_suffix(native_type).to_uppercase())
+        }
+        _ => "NAN".to_owned(),
+    }
+}
+
+/// Largest value of an unsigned primitive type, used for the counters of groups and var data.
+pub(crate) fn max_literal(native_type: &NativeType) -> String {
+    format!("{}_MAX", buffer_suffix(native_type).to_uppercase())
+}
+
+/// Null value of an optional field, either declared by the `nullValue` of the encoded type,
+/// or the default one for its primitive type.
+pub(crate) fn null_value(encoded_type: &EncodedDataType, metadata: &FieldMetadata<C>) -> String {
+    match &encoded_type.null_value {
+        Some(null_value) => literal(null_value, &metadata.field_primitive_type),
+        None => null_literal(&metadata.field_primitive_type),
+    }
+}
+
+pub(crate) fn null_value_condition(
+    variable: &str,
+    encoded_type: &EncodedDataType,
+    metadata: &FieldMetadata<C>,
+) -> Tokens<C> {
+    match (&metadata.field_primitive_type, &encoded_type.null_value) {
+        (NativeType::Float | NativeType::Double, None) => quote!(isnan($variable)),
+        _ => quote!($variable == $(null_value(encoded_type, metadata))),
+    }
+}
+
+/// Checks of a `variable` against the `minValue` and `maxValue` of the encoded type, returning
+/// the error code when it is out of bounds.
+pub(crate) fn bounds_checks(
+    variable: &str,
+    encoded_type: &EncodedDataType,
+    native_type: &NativeType,
+    error_code: &str,
+) -> Tokens<C> {
+    let mut checks = quote!();
+    let unsigned = matches!(
+        native_type,
+        NativeType::UInt8 | NativeType::UInt16 | NativeType::UInt32 | NativeType::UInt64
+    );
+
+    if let Some(min) = &encoded_type.min_value {
+        // Unsigned values can never be below zero
+        if !(unsigned && min.parse::<u64>() == Ok(0)) {
+            checks.append(quote! {
+                if ($variable < $(literal(min, native_type))) {
+                    return $error_code;
+                }
+            });
+            checks.line();
+        }
+    }
+
+    if let Some(max) = &encoded_type.max_value {
+        checks.append(quote! {
+            if ($variable > $(literal(max, native_type))) {
+                return $error_code;
+            }
+        });
+        checks.line();
+    }
+
+    checks
+}
+
+/// Declarations of a codec for the header of the schema, along with the definitions of its
+/// functions for the source.
+#[derive(Default)]
+pub(crate) struct Codec {
+    pub(crate) header: Tokens<C>,
+    pub(crate) source: Tokens<C>,
+}
+
+impl Codec {
+    /// Appends the inline accessors or macros to the header.
+    pub(crate) fn inline(&mut self, tokens: Tokens<C>) {
+        if !tokens.is_empty() {
+            self.header.line();
+            self.header.append(tokens);
+        }
+    }
+
+    /// Appends the declaration of a function to the header and its definition to the source.
+    pub(crate) fn function(&mut self, doc: Tokens<C>, signature: Tokens<C>, body: Tokens<C>) {
+        self.header.line();
+        self.header.append(quote! {
+            $doc
+            $(&signature);
+        });
+        self.source.line();
+        self.source.append(quote! {
+            $signature {
+                $body
+            }
+        });
+    }
+
+    pub(crate) fn extend(&mut self, codec: Codec) {
+        self.header.append(codec.header);
+        self.source.append(codec.source);
+    }
+}
@@ -0,0 +1,55 @@
+use crate::generator::c::codecs::c_type;
+use crate::generator::c::module::ModuleGenerator;
+use crate::models::types::primitive_type::ResolvableType;
+use crate::models::types::set_type::{Choice, SetType};
+use anyhow::Result;
+use convert_case::{Case, Casing};
+use genco::prelude::*;
+
+impl ModuleGenerator<'_> {
+    fn generate_choice_functions(ident: &str, choice: &Choice) -> Tokens<C> {
+        let choice_name = choice.name.to_case(Case::Snake);
+        let bit = format!("{}_{}", ident.to_uppercase(), choice_name.to_uppercase());
+
+        quote! {
+            $['\n']
+            static inline bool $(ident)_get_$(&choice_name)($(ident)_t set) {
+                return 0 != (set & ((uint64_t)1 << $(&bit)));
+            }
+
+            static inline $(ident)_t $(ident)_set_$(&choice_name)($(ident)_t set, bool value) {
+                return ($(ident)_t)(value ? set | ((uint64_t)1 << $(&bit)) : set & ~((uint64_t)1 << $(&bit)));
+            }
+        }
+    }
+
+    /// Generates the typedef of a set as its encoding type, with a macro for the bit of every
+    /// choice and the functions getting and setting it.
+    fn generate_set_codec(&self, set_type: &SetType) -> Result<Tokens<C>> {
+        let ident = self.type_ident(&set_type.name);
+        let encoding_type = set_type
+            .encoding_type
+            .resolved(&self.schema.types.encoded_types)?;
+
+        Ok(quote! {
+            typedef $(c_type(&encoding_type)) $(&ident)_t;
+
+            $(for choice in &set_type.choices {
+                $['\r']
+                $(format!("#define {}_{} {}", ident.to_uppercase(), choice.name.to_case(Case::UpperSnake), choice.value))
+            })
+            $(for choice in &set_type.choices => $(Self::generate_choice_functions(&ident, choice)))
+        })
+    }
+
+    pub(crate) fn generate_set_codecs(&self) -> Result<Tokens<C>> {
+        let mut set_tokens: Tokens<C> = quote!();
+
+        for set_type in self.schema.types.set_types.values() {
+            set_tokens.line();
+            set_tokens.append(self.generate_set_codec(set_type)?);
+        }
+
+        Ok(set_tokens)
+    }
+}
@@ -0,0 +1,146 @@
+use crate::generator::c::codecs::{c_type, field_metadata, max_literal, Codec};
+use crate::generator::c::module::ModuleGenerator;
+use crate::models::types::primitive_type::NativeType;
+use crate::models::types::variable_data_type::VariableDataType;
+use crate::models::types::CharacterEncoding;
+use anyhow::Result;
+use convert_case::{Case, Casing};
+use genco::prelude::*;
+
+impl ModuleGenerator<'_> {
+    /// Generates the functions reading var data from a decoder and writing it to an encoder.
+    ///
+    /// The decoded values point into the buffer. Var data of single byte values is returned as
+    /// characters or bytes, any other one as the bytes of its elements in the byte order of the
+    /// schema, which are read by the functions of their primitive type, e.g. `baseline_get_uint16`.
+    pub(crate) fn generate_var_data_codec(
+        &self,
+        parent_scope: &str,
+        var_data: &VariableDataType,
+    ) -> Result<Codec> {
+        let scope = format!("{}_{}", parent_scope, var_data.name.to_case(Case::Snake));
+        let types = &self.schema.types;
+        let repr_type = var_data.repr_type(&types.composite_types)?;
+
+        let (length_type, length_offset) = self.encoded_field(repr_type, 0)?;
+        let (value_type, value_offset) = self.encoded_field(repr_type, 1)?;
+        let length_metadata = field_metadata("length", &length_type, types)?;
+        let value_metadata = field_metadata("value", &value_type, types)?;
+
+        let length_native = &length_metadata.field_primitive_type;
+        let value_native = &value_metadata.field_primitive_type;
+        let value_size = value_metadata.type_size;
+        let max_length = match &length_type.max_value {
+            Some(max_value) => max_value.to_owned(),
+            None => max_literal(length_native),
+        };
+        let ascii = matches!(value_metadata.encoding, Some(CharacterEncoding::Ascii));
+
+        let decoded_type = match value_native {
+            NativeType::Char => "char",
+            _ => "uint8_t",
+        };
+        // Number of bytes of a number of elements, and whether they fit into the available bytes
+        let payload_length = |length: &str| match value_size {
+            1 => length.to_owned(),
+            _ => format!("{} * {}", length, value_size),
+        };
+        let exceeds = |available: &str, length: &str| match value_size {
+            1 => format!("{} < {}", available, length),
+            _ => format!("({}) / {} < {}", available, value_size, length),
+        };
+
+        let error_type = self.error_type();
+        let ok = self.error("Ok");
+        let out_of_bounds = self.error("CodecOutOfBounds");
+        let since_version = var_data.since_version.filter(|version| *version > 0);
+
+        let mut codec = Codec::default();
+
+        codec.function(
+            quote! {
+                $(format!("/// Reads the var data, returning the number of its {} elements.", c_type(value_native)))
+                $(if let Some(since_version) = since_version {
+                    $(format!("/// Added in version {} of the schema, the var data is empty in the messages encoded", since_version))
+                    $("/// with an older one.")
+                })
+            },
+            quote!($(&error_type) $(&scope)_decode($(self.cursor_type("decoder")) *decoder, const $decoded_type **value, size_t *length)),
+            quote! {
+                $(if let Some(since_version) = since_version {
+                    if (decoder->acting_version < $since_version) {
+                        *value = (const $decoded_type *)(decoder->buf + decoder->position);
+                        *length = 0;
+                        return $(&ok);
+                    }
+                    $['\n']
+                })
+                if (decoder->len - decoder->position < $value_offset) {
+                    return $(&out_of_bounds);
+                }
+
+                const uint8_t *data = decoder->buf + decoder->position;
+                const size_t data_length = (size_t)$(self.get_function(length_native))(data + $length_offset);
+
+                if ($(exceeds(&format!("decoder->len - decoder->position - {}", value_offset), "data_length"))) {
+                    return $(&out_of_bounds);
+                }
+                $(if ascii {
+                    $['\n']
+                    for (size_t idx = 0; idx < data_length; ++idx) {
+                        if (data[$value_offset + idx] > 127) {
+                            return $(self.error("InvalidStringValue"));
+                        }
+                    }
+                })
+
+                *value = (const $decoded_type *)(data + $value_offset);
+                *length = data_length;
+                decoder->position += $value_offset + $(payload_length("data_length"));
+                return $(&ok);
+            },
+        );
+        codec.function(
+            quote!($(format!("/// Writes the var data of the given number of {} elements.", c_type(value_native)))),
+            quote!($(&error_type) $(&scope)_encode($(self.cursor_type("encoder")) *encoder, const $(c_type(value_native)) *value, size_t length)),
+            quote! {
+                if (length > $(&max_length)) {
+                    return $(self.error("VarDataOutOfBounds"));
+                }
+                $(if ascii {
+                    $['\n']
+                    for (size_t idx = 0; idx < length; ++idx) {
+                        if ((uint8_t)value[idx] > 127) {
+                            return $(self.error("InvalidStringValue"));
+                        }
+                    }
+                })
+
+                if (encoder->len - encoder->position < $value_offset || $(exceeds(&format!("encoder->len - encoder->position - {}", value_offset), "length"))) {
+                    return $(&out_of_bounds);
+                }
+
+                uint8_t *data = encoder->buf + encoder->position;
+
+                $(self.put_function(length_native))(data + $length_offset, ($(c_type(length_native)))length);
+                $(match value_size {
+                    1 => {
+                        if (length > 0) {
+                            memcpy(data + $value_offset, value, length);
+                        }
+                    },
+                    _ => {
+                        for (size_t idx = 0; idx < length; ++idx) {
+                            $(self.put_function(value_native))(data + $value_offset + idx * $value_size, value[idx]);
+                        }
+                    },
+                })
+
+                encoder->position += $value_offset + $(payload_length("length"));
+                return $(&ok);
+            },
+        );
+
+        Ok(codec)
+    }
+}
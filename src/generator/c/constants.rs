@@ -0,0 +1,4 @@
+pub const INCLUDE_DIR: &str = "include";
+pub const SOURCE_DIR: &str = "src";
+pub const SBE_HEADER_NAME: &str = "sbe.h";
+pub const SBE_SOURCE_NAME: &str = "sbe.c";
@@ -0,0 +1,204 @@
+mod buffer;
+mod codecs;
+mod constants;
+mod module;
+mod sbe;
+
+use crate::generator::c::constants::{INCLUDE_DIR, SBE_SOURCE_NAME, SOURCE_DIR};
+use crate::generator::{write_file, CodeGenerator};
+use crate::models::schema::ValidatedMessageSchema;
+use anyhow::Result;
+use convert_case::{Case, Casing};
+use genco::prelude::*;
+use indoc::formatdoc;
+use std::fs::{create_dir_all, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Generates a C99 library with a header and a source file per schema, for consumers which
+/// cannot use the C++ codecs, such as firmware.
+pub struct CGenerator {
+    path: PathBuf,
+    project_name: String,
+    project_version: String,
+    bounds_checks: bool,
+    schemas: Vec<ValidatedMessageSchema>,
+}
+
+impl CGenerator {
+    pub fn new(
+        path: &Path,
+        project_name: &str,
+        project_version: &str,
+        schemas: Vec<ValidatedMessageSchema>,
+        bounds_checks: bool,
+    ) -> Self {
+        Self {
+            path: path.to_owned(),
+            project_name: project_name.to_owned(),
+            project_version: project_version.to_owned(),
+            bounds_checks,
+            schemas,
+        }
+    }
+
+    /// Prefix of the identifiers shared by all schemas of the project, which is also the
+    /// directory of the headers in the include path.
+    fn library_name(&self) -> String {
+        self.project_name.to_case(Case::Snake)
+    }
+
+    fn include_path(&self) -> PathBuf {
+        self.path.join(INCLUDE_DIR).join(self.library_name())
+    }
+
+    fn source_path(&self) -> PathBuf {
+        self.path.join(SOURCE_DIR)
+    }
+
+    fn write_project_files(&self, with_test_dependencies: bool) -> Result<()> {
+        create_dir_all(self.include_path())?;
+        create_dir_all(self.source_path())?;
+
+        self.write_cmake_lists(with_test_dependencies)?;
+        self.write_sbe_header()?;
+        self.write_sbe_source()
+    }
+
+    fn write_cmake_lists(&self, with_test_dependencies: bool) -> Result<()> {
+        let library = self.library_name();
+        let sources = std::iter::once(SBE_SOURCE_NAME.to_owned())
+            .chain(
+                self.schemas
+                    .iter()
+                    .map(|schema| format!("{}.c", schema.package)),
+            )
+            .map(|source| format!("    {}/{}", SOURCE_DIR, source))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let test_dependencies = formatdoc! {"
+
+            enable_testing()
+
+            file(GLOB TEST_SOURCES CONFIGURE_DEPENDS tests/*.c)
+
+            foreach(TEST_SOURCE ${{TEST_SOURCES}})
+                get_filename_component(TEST_NAME ${{TEST_SOURCE}} NAME_WE)
+                add_executable(${{TEST_NAME}} ${{TEST_SOURCE}})
+                target_link_libraries(${{TEST_NAME}} PRIVATE {library})
+                add_test(NAME ${{TEST_NAME}} COMMAND ${{TEST_NAME}} WORKING_DIRECTORY ${{CMAKE_CURRENT_SOURCE_DIR}})
+            endforeach()
+            ",
+            library = library,
+        };
+
+        let cmake_lists_content = formatdoc! {"
+                cmake_minimum_required(VERSION 3.14)
+
+                project({name} VERSION {version} LANGUAGES C)
+
+                add_library({library}
+                {sources}
+                )
+                add_library({library}::{library} ALIAS {library})
+                target_include_directories({library} PUBLIC $<BUILD_INTERFACE:${{CMAKE_CURRENT_SOURCE_DIR}}/{include_dir}>)
+                set_target_properties({library} PROPERTIES C_STANDARD 99 C_STANDARD_REQUIRED ON C_EXTENSIONS OFF)
+                {test_deps}",
+            name = self.project_name,
+            version = self.project_version,
+            library = library,
+            sources = sources,
+            include_dir = INCLUDE_DIR,
+            test_deps = if with_test_dependencies {
+                test_dependencies
+            } else {
+                String::new()
+            },
+        };
+
+        let cmake_lists_path = self.path.join("CMakeLists.txt");
+        let mut cmake_lists_file = File::create(cmake_lists_path)?;
+        cmake_lists_file.write_all(cmake_lists_content.as_bytes())?;
+
+        Ok(())
+    }
+}
+
+impl CodeGenerator for CGenerator {
+    fn generate_project(&self, with_test_dependencies: bool, _format_project: bool) -> Result<()> {
+        // Project files (CMakeLists.txt, ...)
+        self.write_project_files(with_test_dependencies)?;
+
+        // Generate a header and a source file for every schema. clang-format is not installed
+        // everywhere, so the project is formatted only by the generator itself.
+        let library_name = self.library_name();
+
+        for schema in &self.schemas {
+            let module_generator = module::ModuleGenerator::new(
+                &self.include_path(),
+                &self.source_path(),
+                &library_name,
+                schema,
+                self.bounds_checks,
+            );
+
+            module_generator.generate_module()?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Writes a header with an include guard and the declarations wrapped in `extern "C"`, so the
+/// header can be included from C++ as well.
+fn write_header(path: &Path, guard: &str, includes: &[String], content: Tokens<C>) -> Result<()> {
+    let header_content: Tokens<C> = quote! {
+        $(format!("#ifndef {}", guard))
+        $(format!("#define {}", guard))
+
+        $(includes_tokens(includes))
+
+        #ifdef __cplusplus
+        extern "C" {
+        #endif
+
+        $content
+
+        #ifdef __cplusplus
+        }
+        #endif
+
+        $(format!("#endif // {}", guard))
+    };
+
+    write_file(path, &c::Config::default(), header_content)
+}
+
+/// Writes a source file with the includes followed by the content.
+fn write_source(path: &Path, includes: &[String], content: Tokens<C>) -> Result<()> {
+    let source_content: Tokens<C> = quote! {
+        $(includes_tokens(includes))
+
+        $content
+    };
+
+    write_file(path, &c::Config::default(), source_content)
+}
+
+/// Includes of the project headers followed by the ones of the standard library.
+fn includes_tokens(includes: &[String]) -> Tokens<C> {
+    let (local_includes, system_includes): (Vec<_>, Vec<_>) = includes
+        .iter()
+        .partition(|include| include.starts_with('"'));
+    let groups = [local_includes, system_includes];
+
+    quote! {
+        $(for group in groups.iter().filter(|group| !group.is_empty()) join ($['\n']) {
+            $(for include in group {
+                $['\r']
+                $(format!("#include {}", include))
+            })
+        })
+    }
+}
@@ -0,0 +1,158 @@
+use crate::generator::c::constants::SBE_HEADER_NAME;
+use crate::generator::c::sbe::{error_code, include_guard};
+use crate::generator::c::{write_header, write_source};
+use crate::models::layout::BlockLayout;
+use crate::models::schema::ValidatedMessageSchema;
+use crate::models::types::composite_type::CompositeType;
+use crate::models::types::encoded_data_type::EncodedDataType;
+use crate::models::types::Type;
+use anyhow::{anyhow, Result};
+use convert_case::{Case, Casing};
+use genco::prelude::*;
+use std::path::{Path, PathBuf};
+
+pub struct ModuleGenerator<'a> {
+    pub(crate) include_path: PathBuf,
+    pub(crate) source_path: PathBuf,
+    pub(crate) library_name: &'a str,
+    /// Prefix of all identifiers of the schema, e.g. `baseline`.
+    pub(crate) prefix: String,
+    pub(crate) schema: &'a ValidatedMessageSchema,
+    pub(crate) bounds_checks: bool,
+}
+
+impl<'a> ModuleGenerator<'a> {
+    pub fn new(
+        include_path: &Path,
+        source_path: &Path,
+        library_name: &'a str,
+        schema: &'a ValidatedMessageSchema,
+        bounds_checks: bool,
+    ) -> Self {
+        Self {
+            include_path: include_path.to_owned(),
+            source_path: source_path.to_owned(),
+            library_name,
+            prefix: schema.package.to_case(Case::Snake),
+            schema,
+            bounds_checks,
+        }
+    }
+
+    /// Writes the header with the inline accessors of the types and blocks, and the source
+    /// with the functions reading and writing the messages, their groups and var data.
+    pub fn generate_module(&self) -> Result<()> {
+        let (message_declarations, message_definitions) = self.generate_message_codecs()?;
+        let header_name = format!("{}.h", self.schema.package);
+
+        let header_content: Tokens<C> = quote! {
+            $(format!("#define {}_SCHEMA_ID {}", self.macro_prefix(), self.schema.id))
+            $(format!("#define {}_SCHEMA_VERSION {}", self.macro_prefix(), self.schema.version))
+            $(self.generate_buffer_functions())
+            $(self.generate_enum_codecs()?)
+            $(self.generate_set_codecs()?)
+            $(self.generate_composite_codecs()?)
+            $message_declarations
+        };
+
+        write_header(
+            &self.include_path.join(&header_name),
+            &include_guard(&[self.library_name, &header_name]),
+            &[
+                format!("\"{}/{}\"", self.library_name, SBE_HEADER_NAME),
+                "<math.h>".to_owned(),
+                "<stdbool.h>".to_owned(),
+                "<stddef.h>".to_owned(),
+                "<stdint.h>".to_owned(),
+                "<string.h>".to_owned(),
+            ],
+            header_content,
+        )?;
+
+        write_source(
+            &self.source_path.join(format!("{}.c", self.schema.package)),
+            &[format!("\"{}/{}\"", self.library_name, header_name)],
+            message_definitions,
+        )
+    }
+
+    /// Prefix of all macros of the schema, e.g. `BASELINE`.
+    pub(crate) fn macro_prefix(&self) -> String {
+        self.prefix.to_uppercase()
+    }
+
+    /// Identifier of a type of the schema, e.g. `baseline_boolean_type`.
+    pub(crate) fn type_ident(&self, type_name: &str) -> String {
+        format!("{}_{}", self.prefix, type_name.to_case(Case::Snake))
+    }
+
+    /// Error code of an `SbeError` variant, e.g. `EXAMPLE_SBE_CODEC_OUT_OF_BOUNDS`.
+    pub(crate) fn error(&self, variant: &str) -> String {
+        error_code(self.library_name, variant)
+    }
+
+    /// Type of the error codes, shared by all schemas of the project.
+    pub(crate) fn error_type(&self) -> String {
+        format!("{}_sbe_error_t", self.library_name)
+    }
+
+    /// Type of a cursor shared by all schemas of the project, e.g. `example_sbe_decoder_t`.
+    pub(crate) fn cursor_type(&self, cursor: &str) -> String {
+        format!("{}_sbe_{}_t", self.library_name, cursor)
+    }
+
+    /// Resolves a reference to the type it refers to.
+    pub(crate) fn resolved(&self, field_type: &Type) -> Result<Type> {
+        match field_type {
+            Type::Reference(reference_type) => self
+                .schema
+                .types
+                .find_type(&reference_type.type_name)
+                .ok_or(anyhow!(
+                    "Referenced type {} not found",
+                    reference_type.type_name
+                )),
+            field_type => Ok(field_type.clone()),
+        }
+    }
+
+    /// Encoded field of a composite at the index, e.g. the number of entries of a group
+    /// dimension, along with its offset.
+    pub(crate) fn encoded_field(
+        &self,
+        composite_type: &CompositeType,
+        index: usize,
+    ) -> Result<(EncodedDataType, usize)> {
+        let layout = BlockLayout::from_composite(composite_type, &self.schema.types)?;
+        let field = composite_type.fields.get(index).ok_or(anyhow!(
+            "Missing field {} in composite '{}'",
+            index,
+            composite_type.name
+        ))?;
+
+        match self.resolved(field)? {
+            Type::EncodedData(encoded_type) => Ok((encoded_type, layout.offsets[index])),
+            _ => Err(anyhow!(
+                "Only encoded data type expected for the field '{}' in composite '{}'",
+                field.name(),
+                composite_type.name
+            )),
+        }
+    }
+
+    /// Encoded field of the message header by its name, along with its offset.
+    pub(crate) fn header_field(&self, field_name: &str) -> Result<(EncodedDataType, usize)> {
+        let header_type = &self.schema.types.header_type;
+        let index = header_type
+            .fields
+            .iter()
+            .position(|field| field.name() == field_name)
+            .ok_or(anyhow!(
+                "Missing field '{}' in message header '{}'",
+                field_name,
+                header_type.name
+            ))?;
+
+        self.encoded_field(header_type, index)
+    }
+}
@@ -0,0 +1,129 @@
+use crate::generator::c::constants::{SBE_HEADER_NAME, SBE_SOURCE_NAME};
+use crate::generator::c::{write_header, write_source, CGenerator};
+use anyhow::Result;
+use convert_case::{Case, Casing};
+use genco::prelude::*;
+
+/// Error codes of the codecs with the messages of the corresponding `SbeError` variants.
+const ERRORS: [(&str, &str); 12] = [
+    ("InvalidStringValue", "invalid ascii string"),
+    ("InvalidEnumValue", "invalid enum value"),
+    ("ValueOutOfBounds", "value out of bounds"),
+    ("WrongSliceSize", "wrong slice size"),
+    ("MissingGroupSize", "missing group size"),
+    ("MissingVarDataSize", "missing var data size"),
+    ("GroupOutOfBounds", "group out of bounds"),
+    ("VarDataOutOfBounds", "var data out of bounds"),
+    ("WrongSchema", "received message had wrong schema"),
+    ("WrongMessageType", "received message had wrong type"),
+    ("UnknownMessageType", "received message had unknown type"),
+    ("CodecOutOfBounds", "codec out of bounds"),
+];
+
+/// Name of the error code of an `SbeError` variant, e.g. `EXAMPLE_SBE_CODEC_OUT_OF_BOUNDS`.
+pub(crate) fn error_code(library_name: &str, variant: &str) -> String {
+    format!(
+        "{}_SBE_{}",
+        library_name.to_case(Case::UpperSnake),
+        variant.to_case(Case::UpperSnake)
+    )
+}
+
+impl CGenerator {
+    /// Writes the header shared by all schemas of the project, with the error codes returned
+    /// by the codecs and the cursors over the encoded messages.
+    pub(crate) fn write_sbe_header(&self) -> Result<()> {
+        let library = self.library_name();
+
+        let sbe_header_content: Tokens<C> = quote! {
+            $("/// Error codes returned by the codecs, one for every variant of `SbeError`. The cursors")
+            $("/// read and write the groups and var data in the order of the schema, so the missing")
+            $("/// group and var data sizes are never returned and only kept to match the other languages.")
+            typedef enum $(&library)_sbe_error {
+                $(error_code(&library, "Ok")) = 0,
+                $(for (variant, _) in ERRORS {
+                    $['\r']
+                    $(error_code(&library, variant)),
+                })
+            } $(&library)_sbe_error_t;
+
+            $("/// Returns the message of an error code.")
+            const char *$(&library)_sbe_error_message($(&library)_sbe_error_t error);
+
+            $("/// Cursor reading the groups and var data of a decoded message in the order of the schema.")
+            typedef struct $(&library)_sbe_decoder {
+                const uint8_t *buf;
+                size_t len;
+                $("/// Offset of the next group or var data, and the size of the message once all were read.")
+                size_t position;
+                $("/// Version of the schema the decoded message was encoded with.")
+                uint16_t acting_version;
+            } $(&library)_sbe_decoder_t;
+
+            $("/// Cursor writing the groups and var data of an encoded message in the order of the schema.")
+            typedef struct $(&library)_sbe_encoder {
+                uint8_t *buf;
+                size_t len;
+                $("/// Offset of the next group or var data, and the size of the message once all were written.")
+                size_t position;
+            } $(&library)_sbe_encoder_t;
+
+            $("/// Entries of a decoded group, read one by one along with their own groups and var data.")
+            typedef struct $(&library)_sbe_group_decoder {
+                $(&library)_sbe_decoder_t *decoder;
+                size_t block_length;
+                size_t num_in_group;
+                size_t index;
+            } $(&library)_sbe_group_decoder_t;
+
+            $("/// Entries of an encoded group, written one by one along with their own groups and var data.")
+            typedef struct $(&library)_sbe_group_encoder {
+                $(&library)_sbe_encoder_t *encoder;
+                size_t dimension_offset;
+                size_t num_in_group;
+            } $(&library)_sbe_group_encoder_t;
+        };
+
+        write_header(
+            &self.include_path().join(SBE_HEADER_NAME),
+            &include_guard(&[&library, SBE_HEADER_NAME]),
+            &["<stddef.h>".to_owned(), "<stdint.h>".to_owned()],
+            sbe_header_content,
+        )
+    }
+
+    pub(crate) fn write_sbe_source(&self) -> Result<()> {
+        let library = self.library_name();
+
+        let sbe_source_content: Tokens<C> = quote! {
+            const char *$(&library)_sbe_error_message($(&library)_sbe_error_t error) {
+                switch (error) {
+                case $(error_code(&library, "Ok")):
+                    return "ok";
+                $(for (variant, message) in ERRORS {
+                    $['\r']
+                    case $(error_code(&library, variant)):
+                        return $(quoted(message));
+                })
+                }
+
+                return "unknown error";
+            }
+        };
+
+        write_source(
+            &self.source_path().join(SBE_SOURCE_NAME),
+            &[format!("\"{}/{}\"", library, SBE_HEADER_NAME)],
+            sbe_source_content,
+        )
+    }
+}
+
+/// Include guard of a header from the segments of its path, e.g. `EXAMPLE_SBE_H`.
+pub(crate) fn include_guard(segments: &[&str]) -> String {
+    segments
+        .iter()
+        .map(|segment| segment.replace('.', "_").to_case(Case::UpperSnake))
+        .collect::<Vec<_>>()
+        .join("_")
+}
@@ -1,3 +1,4 @@
+use crate::models::types::composite_type::CompositeType;
 use crate::models::types::encoded_data_type::EncodedDataType;
 use crate::models::types::field_type::FieldType;
 use crate::models::types::group_type::GroupType;
@@ -6,10 +7,12 @@ use crate::models::types::primitive_type::{
 };
 use crate::models::types::variable_data_type::VariableDataType;
 use crate::models::types::{CharacterEncoding, MessageField, SizedEncoded};
-use crate::models::TypeMap;
-use anyhow::Result;
+use crate::models::{FieldPath, TypeMap};
+use anyhow::{anyhow, Result};
 use convert_case::{Case, Casing};
 use genco::lang::{Lang, Rust};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
 
 #[derive(Debug)]
 pub struct FieldMetadata<L: Lang> {
@@ -82,3 +85,61 @@ pub fn field_groups(
         .collect::<Vec<&VariableDataType>>();
     (fields, groups, var_data)
 }
+
+/// Returns the directory of the module of a group or var data codec, or of the module
+/// scoping the codecs nested in a message or group.
+pub fn scoped_module_dir(root: &Path, path: &FieldPath) -> PathBuf {
+    path.segments()
+        .iter()
+        .fold(root.to_owned(), |dir, segment| {
+            dir.join(segment.to_case(Case::Snake))
+        })
+}
+
+/// Returns the Rust module path of a group or var data codec relative to its root module.
+pub fn scoped_module_path(path: &FieldPath) -> String {
+    path.segments()
+        .iter()
+        .map(|segment| segment.to_case(Case::Snake))
+        .collect::<Vec<_>>()
+        .join("::")
+}
+
+/// Composite holding the block length and the number of entries of a group.
+pub fn dimension_type<'a>(
+    group: &GroupType,
+    composite_types: &'a BTreeMap<String, CompositeType>,
+) -> Result<&'a CompositeType> {
+    let dimension_type_name = group
+        .dimension_type
+        .clone()
+        .unwrap_or("groupSizeEncoding".to_owned());
+
+    composite_types.get(&dimension_type_name).ok_or(anyhow!(
+        "Missing dimension type '{}' for group '{}'",
+        dimension_type_name,
+        group.name
+    ))
+}
+
+/// Optional fields of the message header counting the groups and var data of the message,
+/// as introduced by SBE 2.0, paired with the counts to encode for a message.
+pub fn header_counts(
+    types: &TypeMap,
+    num_groups: usize,
+    num_var_data_fields: usize,
+) -> Vec<(&'static str, usize)> {
+    [
+        ("numGroups", num_groups),
+        ("numVarDataFields", num_var_data_fields),
+    ]
+    .into_iter()
+    .filter(|(name, _)| {
+        types
+            .header_type
+            .fields
+            .iter()
+            .any(|field| field.name() == *name)
+    })
+    .collect()
+}
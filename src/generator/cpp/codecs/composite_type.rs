@@ -0,0 +1,627 @@
+use crate::generator::common::FieldMetadata;
+use crate::generator::cpp::codecs::{
+    bounds_checks, field_metadata, literal, null_value, null_value_condition, CODEC_SYSTEM_INCLUDES,
+};
+use crate::generator::cpp::constants::{
+    COMPOSITE_DIR_NAME, READ_BUF_HEADER_NAME, WRITE_BUF_HEADER_NAME,
+};
+use crate::generator::cpp::module::ModuleGenerator;
+use crate::models::layout::BlockLayout;
+use crate::models::types::composite_type::CompositeType;
+use crate::models::types::encoded_data_type::EncodedDataType;
+use crate::models::types::enum_type::EnumType;
+use crate::models::types::primitive_type::{NativeType, PrimitiveConvertible};
+use crate::models::types::set_type::SetType;
+use crate::models::types::{CharacterEncoding, Presence, SizedEncoded, Type};
+use anyhow::{anyhow, Result};
+use convert_case::{Case, Casing};
+use genco::prelude::*;
+
+impl ModuleGenerator<'_> {
+    fn write_composite_codec(&self, composite_type: &CompositeType) -> Result<()> {
+        let name = composite_type.name.to_case(Case::UpperCamel);
+        let decoder_name = format!("{}Decoder", name);
+        let encoder_name = format!("{}Encoder", name);
+
+        let layout = BlockLayout::from_composite(composite_type, &self.schema.types)?;
+        let encoded_length = composite_type.size(&self.schema.types)?;
+
+        let mut decoder_fields: Tokens<C> = quote!();
+        let mut encoder_fields: Tokens<C> = quote!();
+
+        for (field, offset) in composite_type.fields.iter().zip(layout.offsets) {
+            decoder_fields.line();
+            decoder_fields.append(self.generate_decoder_field(field.name(), field, offset, 0)?);
+            encoder_fields.line();
+            encoder_fields.append(self.generate_encoder_field(
+                field.name(),
+                field,
+                quote!($offset),
+            )?);
+        }
+
+        let codec_tokens: Tokens<C> = quote! {
+            class $(&decoder_name) {
+            public:
+                static constexpr std::size_t ENCODED_LENGTH = $encoded_length;
+
+                explicit $(&decoder_name)(ReadBuf buffer) noexcept : buffer_(buffer) {
+                }
+                $decoder_fields
+
+            private:
+                ReadBuf buffer_;
+            };
+
+            class $(&encoder_name) {
+            public:
+                static constexpr std::size_t ENCODED_LENGTH = $encoded_length;
+
+                explicit $(&encoder_name)(WriteBuf buffer) noexcept : buffer_(buffer) {
+                }
+                $encoder_fields
+
+            private:
+                WriteBuf buffer_;
+            };
+        };
+
+        let mut includes = vec![
+            self.include(READ_BUF_HEADER_NAME),
+            self.include(WRITE_BUF_HEADER_NAME),
+        ];
+        includes.extend(self.type_includes(&composite_type.fields)?);
+        includes.extend(CODEC_SYSTEM_INCLUDES.map(str::to_owned));
+
+        self.write_header(
+            &format!(
+                "{}/{}.hpp",
+                COMPOSITE_DIR_NAME,
+                composite_type.name.to_case(Case::Snake)
+            ),
+            &includes,
+            codec_tokens,
+        )
+    }
+
+    pub fn write_composite_codecs(&self) -> Result<()> {
+        for composite_type in self.schema.types.composite_types.values() {
+            self.write_composite_codec(composite_type)?;
+        }
+
+        Ok(())
+    }
+
+    /// Generates the accessor for a field at the given offset.
+    ///
+    /// A non-zero `since_version` means the field was added in that version of the schema.
+    /// The accessor then checks the `acting_version_` of the decoder (only message and group
+    /// decoders have one) and returns the null value of the field, if the field is not present
+    /// in the decoded data.
+    pub(crate) fn generate_decoder_field(
+        &self,
+        field_name: &str,
+        field: &Type,
+        offset: usize,
+        since_version: usize,
+    ) -> Result<Tokens<C>> {
+        Ok(match field {
+            Type::EncodedData(encoded_type) => self.generate_encoded_field_decoder(
+                field_name,
+                encoded_type,
+                offset,
+                since_version,
+            )?,
+            Type::Enum(enum_type) => {
+                self.generate_enum_field_decoder(field_name, enum_type, offset, since_version)?
+            }
+            Type::Set(set_type) => {
+                self.generate_set_field_decoder(field_name, set_type, offset, since_version)?
+            }
+            Type::Composite(composite_type) => self.generate_composite_field_decoder(
+                field_name,
+                composite_type,
+                offset,
+                since_version,
+            ),
+            Type::Reference(reference_type) => {
+                let referenced_type = self
+                    .schema
+                    .types
+                    .find_type(&reference_type.type_name)
+                    .ok_or(anyhow!(
+                        "Referenced type {} not found",
+                        reference_type.type_name
+                    ))?;
+                self.generate_decoder_field(field_name, &referenced_type, offset, since_version)?
+            }
+        })
+    }
+
+    fn generate_enum_field_decoder(
+        &self,
+        field_name: &str,
+        enum_type: &EnumType,
+        offset: usize,
+        since_version: usize,
+    ) -> Result<Tokens<C>> {
+        let enum_type_name = enum_type.name.to_case(Case::UpperCamel);
+        let field_name = field_name.to_case(Case::Snake);
+        let encoding_type = enum_type
+            .encoding_type
+            .lang_primitive(&self.schema.types.encoded_types)?;
+
+        Ok(quote! {
+            $(&enum_type_name) $(field_name)() const {
+                $(version_check(since_version, quote!($(&enum_type_name)::NullVal)))
+                return $(enum_type.name.to_case(Case::Snake))_from_value(buffer_.get_at<$(&encoding_type)>($offset));
+            }
+        })
+    }
+
+    fn generate_set_field_decoder(
+        &self,
+        field_name: &str,
+        set_type: &SetType,
+        offset: usize,
+        since_version: usize,
+    ) -> Result<Tokens<C>> {
+        let set_type_name = set_type.name.to_case(Case::UpperCamel);
+        let field_name = field_name.to_case(Case::Snake);
+        let encoding_type = set_type
+            .encoding_type
+            .lang_primitive(&self.schema.types.encoded_types)?;
+
+        Ok(quote! {
+            $(&set_type_name) $(field_name)() const {
+                $(version_check(since_version, quote!($(&set_type_name)())))
+                return $(&set_type_name)(buffer_.get_at<$(&encoding_type)>($offset));
+            }
+        })
+    }
+
+    fn generate_composite_field_decoder(
+        &self,
+        field_name: &str,
+        composite_type: &CompositeType,
+        offset: usize,
+        since_version: usize,
+    ) -> Tokens<C> {
+        let decoder_name = format!("{}Decoder", composite_type.name.to_case(Case::UpperCamel));
+        let field_name = field_name.to_case(Case::Snake);
+
+        // Composites have no null value, so the result is optional for the ones
+        // which might not be present in the decoded data.
+        if since_version > 0 {
+            quote! {
+                template <typename F>
+                auto $(field_name)_decoder(F &&action) const -> std::optional<std::invoke_result_t<F, $(&decoder_name) &>> {
+                    $(version_check(since_version, quote!(std::nullopt)))
+                    $(&decoder_name) decoder(buffer_.split_at($offset));
+
+                    return std::forward<F>(action)(decoder);
+                }
+            }
+        } else {
+            quote! {
+                template <typename F>
+                auto $(field_name)_decoder(F &&action) const {
+                    $(&decoder_name) decoder(buffer_.split_at($offset));
+
+                    return std::forward<F>(action)(decoder);
+                }
+            }
+        }
+    }
+
+    fn generate_encoded_field_decoder(
+        &self,
+        field_name: &str,
+        encoded_type: &EncodedDataType,
+        offset: usize,
+        since_version: usize,
+    ) -> Result<Tokens<C>> {
+        Ok(match encoded_type.presence {
+            Presence::Constant => self.generate_encoded_constant_field(field_name, encoded_type)?,
+            Presence::Required => self.generate_encoded_required_field_decoder(
+                field_name,
+                encoded_type,
+                offset,
+                since_version,
+            )?,
+            Presence::Optional => self.generate_encoded_optional_field_decoder(
+                field_name,
+                encoded_type,
+                offset,
+                since_version,
+            )?,
+        })
+    }
+
+    fn generate_encoded_constant_field(
+        &self,
+        field_name: &str,
+        encoded_type: &EncodedDataType,
+    ) -> Result<Tokens<C>> {
+        let metadata = field_metadata(field_name, encoded_type, &self.schema.types)?;
+
+        let value = encoded_type.default_value.as_ref().ok_or(anyhow!(
+            "Constant field {} has no default value",
+            metadata.field_name
+        ))?;
+
+        Ok(match metadata.field_primitive_type {
+            NativeType::Char => quote! {
+                std::string_view $(&metadata.field_name)() const noexcept {
+                    return $(quoted(value));
+                }
+            },
+            _ => quote! {
+                $(&metadata.lang_type) $(&metadata.field_name)() const noexcept {
+                    return $(literal(value, &metadata.field_primitive_type));
+                }
+            },
+        })
+    }
+
+    fn generate_encoded_required_field_decoder(
+        &self,
+        field_name: &str,
+        encoded_type: &EncodedDataType,
+        offset: usize,
+        since_version: usize,
+    ) -> Result<Tokens<C>> {
+        let metadata = field_metadata(field_name, encoded_type, &self.schema.types)?;
+        let lang_type = &metadata.lang_type;
+        let null_value = null_value(encoded_type, &metadata);
+
+        Ok(
+            match (&metadata.field_primitive_type, metadata.field_length) {
+                (NativeType::Char, 2..) => quote! {
+                    std::string $(&metadata.field_name)() const {
+                        $(version_check(since_version, quote!(std::string())))
+                        return buffer_.get_string_at($offset, $(metadata.field_length));
+                    }
+                },
+                (_, 2..) => quote! {
+                    $(array_type(&metadata)) $(&metadata.field_name)() const {
+                        $(version_check(since_version, null_array(&metadata, &null_value)))
+                        $(array_type(&metadata)) value{};
+
+                        for (std::size_t idx = 0; idx < value.size(); ++idx) {
+                            value[idx] = buffer_.get_at<$lang_type>($offset + idx * $(metadata.type_size));
+                        }
+
+                        return value;
+                    }
+                },
+                (_, _) => {
+                    let bounds_checks = bounds_checks(
+                        &metadata.field_name,
+                        "value",
+                        encoded_type,
+                        &metadata.field_primitive_type,
+                    );
+
+                    if bounds_checks.is_empty() {
+                        quote! {
+                            $lang_type $(&metadata.field_name)() const {
+                                $(version_check(since_version, &null_value))
+                                return buffer_.get_at<$lang_type>($offset);
+                            }
+                        }
+                    } else {
+                        quote! {
+                            $lang_type $(&metadata.field_name)() const {
+                                $(version_check(since_version, &null_value))
+                                const $lang_type value = buffer_.get_at<$lang_type>($offset);
+
+                                $bounds_checks
+                                return value;
+                            }
+                        }
+                    }
+                }
+            },
+        )
+    }
+
+    fn generate_encoded_optional_field_decoder(
+        &self,
+        field_name: &str,
+        encoded_type: &EncodedDataType,
+        offset: usize,
+        since_version: usize,
+    ) -> Result<Tokens<C>> {
+        let metadata = field_metadata(field_name, encoded_type, &self.schema.types)?;
+        let lang_type = &metadata.lang_type;
+
+        Ok(
+            match (&metadata.field_primitive_type, metadata.field_length) {
+                (NativeType::Char, 2..) => quote! {
+                    std::optional<std::string> $(&metadata.field_name)() const {
+                        $(version_check(since_version, quote!(std::nullopt)))
+                        bool is_null = true;
+
+                        for (const char part : buffer_.get_slice_at($offset, $(metadata.field_length))) {
+                            is_null = is_null && $(null_value_condition("part", encoded_type, &metadata));
+                        }
+
+                        if (is_null) {
+                            return std::nullopt;
+                        }
+
+                        return buffer_.get_string_at($offset, $(metadata.field_length));
+                    }
+                },
+                (_, 2..) => quote! {
+                    std::optional<$(array_type(&metadata))> $(&metadata.field_name)() const {
+                        $(version_check(since_version, quote!(std::nullopt)))
+                        $(array_type(&metadata)) value{};
+                        bool is_null = true;
+
+                        for (std::size_t idx = 0; idx < value.size(); ++idx) {
+                            const $lang_type part = buffer_.get_at<$lang_type>($offset + idx * $(metadata.type_size));
+                            value[idx] = part;
+                            is_null = is_null && $(null_value_condition("part", encoded_type, &metadata));
+                        }
+
+                        if (is_null) {
+                            return std::nullopt;
+                        }
+
+                        return value;
+                    }
+                },
+                (_, _) => quote! {
+                    std::optional<$lang_type> $(&metadata.field_name)() const {
+                        $(version_check(since_version, quote!(std::nullopt)))
+                        const $lang_type value = buffer_.get_at<$lang_type>($offset);
+
+                        if ($(null_value_condition("value", encoded_type, &metadata))) {
+                            return std::nullopt;
+                        }
+
+                        $(bounds_checks(&metadata.field_name, "value", encoded_type, &metadata.field_primitive_type))
+                        return value;
+                    }
+                },
+            },
+        )
+    }
+
+    pub(crate) fn generate_encoder_field(
+        &self,
+        field_name: &str,
+        field: &Type,
+        offset: Tokens<C>,
+    ) -> Result<Tokens<C>> {
+        Ok(match field {
+            Type::EncodedData(encoded_type) => match encoded_type.presence {
+                Presence::Constant => quote!(),
+                Presence::Required => {
+                    self.generate_encoded_required_field_encoder(field_name, encoded_type, offset)?
+                }
+                Presence::Optional => {
+                    self.generate_encoded_optional_field_encoder(field_name, encoded_type, offset)?
+                }
+            },
+            Type::Enum(enum_type) => {
+                let encoding_type = enum_type
+                    .encoding_type
+                    .lang_primitive(&self.schema.types.encoded_types)?;
+
+                quote! {
+                    void $(field_name.to_case(Case::Snake))($(enum_type.name.to_case(Case::UpperCamel)) value) {
+                        buffer_.put_at<$(&encoding_type)>($offset, static_cast<$(&encoding_type)>(value));
+                    }
+                }
+            }
+            Type::Set(set_type) => {
+                let encoding_type = set_type
+                    .encoding_type
+                    .lang_primitive(&self.schema.types.encoded_types)?;
+
+                quote! {
+                    void $(field_name.to_case(Case::Snake))($(set_type.name.to_case(Case::UpperCamel)) value) {
+                        buffer_.put_at<$(&encoding_type)>($offset, value.value());
+                    }
+                }
+            }
+            Type::Composite(composite_type) => {
+                let encoder_name =
+                    format!("{}Encoder", composite_type.name.to_case(Case::UpperCamel));
+
+                quote! {
+                    template <typename F>
+                    auto $(field_name.to_case(Case::Snake))_encoder(F &&action) {
+                        $(&encoder_name) encoder(buffer_.split_at($offset));
+
+                        return std::forward<F>(action)(encoder);
+                    }
+                }
+            }
+            Type::Reference(reference_type) => {
+                let referenced_type = self
+                    .schema
+                    .types
+                    .find_type(&reference_type.type_name)
+                    .ok_or(anyhow!(
+                        "Referenced type {} not found",
+                        reference_type.type_name
+                    ))?;
+                self.generate_encoder_field(field_name, &referenced_type, offset)?
+            }
+        })
+    }
+
+    fn generate_encoded_required_field_encoder(
+        &self,
+        field_name: &str,
+        encoded_type: &EncodedDataType,
+        offset: Tokens<C>,
+    ) -> Result<Tokens<C>> {
+        let metadata = field_metadata(field_name, encoded_type, &self.schema.types)?;
+        let lang_type = &metadata.lang_type;
+
+        Ok(
+            match (&metadata.field_primitive_type, metadata.field_length) {
+                (NativeType::Char, 2..) => quote! {
+                    void $(&metadata.field_name)(std::string_view value) {
+                        $(self.string_encoder(&metadata, "value"))
+                        buffer_.put_bytes_at($offset, value);
+                    }
+                },
+                (_, 2..) => quote! {
+                    void $(&metadata.field_name)(const $(array_type(&metadata)) &value) {
+                        const std::size_t offset = $offset;
+
+                        for (std::size_t idx = 0; idx < value.size(); ++idx) {
+                            $(self.bounds_checks(&metadata, "value[idx]", encoded_type))
+                            buffer_.put_at<$lang_type>(offset + idx * $(metadata.type_size), value[idx]);
+                        }
+                    }
+                },
+                (_, _) => quote! {
+                    void $(&metadata.field_name)($lang_type value) {
+                        $(self.bounds_checks(&metadata, "value", encoded_type))
+                        buffer_.put_at<$lang_type>($offset, value);
+                    }
+                },
+            },
+        )
+    }
+
+    fn generate_encoded_optional_field_encoder(
+        &self,
+        field_name: &str,
+        encoded_type: &EncodedDataType,
+        offset: Tokens<C>,
+    ) -> Result<Tokens<C>> {
+        let metadata = field_metadata(field_name, encoded_type, &self.schema.types)?;
+        let lang_type = &metadata.lang_type;
+        let null_value = null_value(encoded_type, &metadata);
+
+        Ok(
+            match (&metadata.field_primitive_type, metadata.field_length) {
+                (NativeType::Char, 2..) => quote! {
+                    void $(&metadata.field_name)(std::optional<std::string_view> value) {
+                        const std::size_t offset = $offset;
+
+                        if (!value) {
+                            for (std::size_t idx = 0; idx < $(metadata.field_length); ++idx) {
+                                buffer_.put_at<char>(offset + idx, $(&null_value));
+                            }
+
+                            return;
+                        }
+
+                        const std::string_view text = *value;
+
+                        $(self.string_encoder(&metadata, "text"))
+                        buffer_.put_bytes_at(offset, text);
+                    }
+                },
+                (_, 2..) => quote! {
+                    void $(&metadata.field_name)(const std::optional<$(array_type(&metadata))> &value) {
+                        const std::size_t offset = $offset;
+
+                        for (std::size_t idx = 0; idx < $(metadata.field_length); ++idx) {
+                            if (!value) {
+                                buffer_.put_at<$lang_type>(offset + idx * $(metadata.type_size), $(&null_value));
+                                continue;
+                            }
+
+                            $(self.bounds_checks(&metadata, "(*value)[idx]", encoded_type))
+                            buffer_.put_at<$lang_type>(offset + idx * $(metadata.type_size), (*value)[idx]);
+                        }
+                    }
+                },
+                (_, _) => {
+                    let bounds_checks = self.bounds_checks(&metadata, "*value", encoded_type);
+
+                    if bounds_checks.is_empty() {
+                        quote! {
+                            void $(&metadata.field_name)(std::optional<$lang_type> value) {
+                                buffer_.put_at<$lang_type>($offset, value.value_or($(&null_value)));
+                            }
+                        }
+                    } else {
+                        quote! {
+                            void $(&metadata.field_name)(std::optional<$lang_type> value) {
+                                if (value) {
+                                    $bounds_checks
+                                }
+
+                                buffer_.put_at<$lang_type>($offset, value.value_or($(&null_value)));
+                            }
+                        }
+                    }
+                }
+            },
+        )
+    }
+
+    /// Checks of the `minValue` and `maxValue` of the field, unless they are disabled.
+    fn bounds_checks(
+        &self,
+        metadata: &FieldMetadata<C>,
+        variable: &str,
+        encoded_type: &EncodedDataType,
+    ) -> Tokens<C> {
+        if self.bounds_checks {
+            bounds_checks(
+                &metadata.field_name,
+                variable,
+                encoded_type,
+                &metadata.field_primitive_type,
+            )
+        } else {
+            quote!()
+        }
+    }
+
+    /// Checks of a string, which has to fit into the field and to be ASCII for ASCII fields.
+    fn string_encoder(&self, metadata: &FieldMetadata<C>, variable: &str) -> Tokens<C> {
+        let max_length = metadata.field_length;
+
+        quote! {
+            $(if let Some(CharacterEncoding::Ascii) = metadata.encoding {
+                for (const char part : $variable) {
+                    if (static_cast<std::uint8_t>(part) > 127) {
+                        throw SbeError::invalid_string_value(std::string($variable));
+                    }
+                }
+                $['\n']
+            })
+            if ($variable.size() > $max_length) {
+                throw SbeError::value_out_of_bounds($(quoted(&metadata.field_name)), "string '" + std::string($variable) + "' length " + std::to_string($variable.size()) + $(quoted(format!(" > {} (max)", max_length))));
+            }
+            $['\n']
+        }
+    }
+}
+
+/// Returns the null value early from an accessor of a field, which was added in a newer
+/// version of the schema than the one used to encode the decoded data.
+fn version_check(since_version: usize, null_value: impl FormatInto<C>) -> Tokens<C> {
+    quote! {
+        $(if since_version > 0 {
+            if (acting_version_ < $since_version) {
+                return $null_value;
+            }
+            $['\n']
+        })
+    }
+}
+
+fn array_type(metadata: &FieldMetadata<C>) -> Tokens<C> {
+    quote!(std::array<$(&metadata.lang_type), $(metadata.field_length)>)
+}
+
+/// Array of the field length filled with the null value.
+fn null_array(metadata: &FieldMetadata<C>, null_value: &str) -> Tokens<C> {
+    let values = vec![null_value; metadata.field_length].join(", ");
+
+    quote!($(array_type(metadata)){$values})
+}
@@ -0,0 +1,83 @@
+use anyhow::Result;
+use convert_case::{Case, Casing};
+
+use crate::generator::cpp::codecs::{literal, null_literal};
+use crate::generator::cpp::constants::ENUM_DIR_NAME;
+use crate::generator::cpp::module::ModuleGenerator;
+use crate::models::types::enum_type::EnumType;
+use crate::models::types::primitive_type::{NativeType, PrimitiveConvertible, ResolvableType};
+use genco::prelude::*;
+
+impl ModuleGenerator<'_> {
+    fn write_enum_codec(&self, enum_type: &EnumType) -> Result<()> {
+        let name = enum_type.name.to_case(Case::UpperCamel);
+        let encoding_type = enum_type
+            .encoding_type
+            .resolved(&self.schema.types.encoded_types)?;
+        let cpp_type = encoding_type.lang_primitive(&self.schema.types.encoded_types)?;
+        let char_encoding = encoding_type == NativeType::Char;
+
+        let values = enum_type
+            .values
+            .iter()
+            .map(|value| {
+                Ok((
+                    value.name.to_case(Case::UpperCamel),
+                    literal(
+                        &value.encoded_value(char_encoding)?.to_string(),
+                        &encoding_type,
+                    ),
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let null_value = null_literal(&encoding_type);
+
+        let enum_tokens: Tokens<C> = quote! {
+            enum class $(&name) : $(&cpp_type) {
+                $(for (value_name, value) in &values {
+                    $['\r']
+                    $value_name = $value,
+                })
+                NullVal = $(&null_value),
+            };
+
+            $("/// Converts an encoded value to the enum, failing for the values missing in the schema.")
+            inline $(&name) $(enum_type.name.to_case(Case::Snake))_from_value($(&cpp_type) value) {
+                $(for (value_name, value) in &values {
+                    $['\r']
+                    if (value == $value) {
+                        return $(&name)::$value_name;
+                    }
+                })
+                if (value == $(&null_value)) {
+                    return $(&name)::NullVal;
+                }
+
+                throw SbeError::invalid_enum_value($(quoted(&name)), std::to_string(value));
+            }
+        };
+
+        self.write_header(
+            &format!(
+                "{}/{}.hpp",
+                ENUM_DIR_NAME,
+                enum_type.name.to_case(Case::Snake)
+            ),
+            &[
+                self.error_include(),
+                "<cstdint>".to_owned(),
+                "<limits>".to_owned(),
+                "<string>".to_owned(),
+            ],
+            enum_tokens,
+        )
+    }
+
+    pub fn write_enum_codecs(&self) -> Result<()> {
+        for enum_type in self.schema.types.enum_types.values() {
+            self.write_enum_codec(enum_type)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,447 @@
+use crate::generator::common::{dimension_type, field_groups};
+use crate::generator::cpp::codecs::max_size;
+use crate::generator::cpp::module::ModuleGenerator;
+use crate::models::layout::BlockLayout;
+use crate::models::types::field_type::FieldType;
+use crate::models::types::group_type::GroupType;
+use crate::models::types::primitive_type::{PrimitiveConvertible, ResolvableType};
+use crate::models::types::variable_data_type::VariableDataType;
+use crate::models::types::{Presence, SizedEncoded, Type};
+use anyhow::{anyhow, Result};
+use convert_case::{Case, Casing};
+use genco::prelude::*;
+
+impl ModuleGenerator<'_> {
+    /// Generates the decoder of a group as a class nested in the decoder of its message or
+    /// enclosing group, along with the decoders of its own groups and var data.
+    pub(crate) fn generate_group_decoder(&self, group: &GroupType) -> Result<Tokens<C>> {
+        let name = group.name.as_str();
+        let decoder_name = format!("{}Decoder", name.to_case(Case::UpperCamel));
+        let (fields, groups, var_data) = field_groups(&group.fields);
+
+        let layout =
+            BlockLayout::from_fields(name, &fields, group.block_length, &self.schema.types)?;
+
+        let dimension_type = dimension_type(group, &self.schema.types.composite_types)?;
+        let dimension_type_size = dimension_type.size(&self.schema.types)?;
+        let dimension_type_decoder_name =
+            format!("{}Decoder", dimension_type.name.to_case(Case::UpperCamel));
+
+        Ok(quote! {
+            class $(&decoder_name) {
+            public:
+                $(&decoder_name)(ReadBuf buffer, std::uint16_t acting_version) : buffer_(buffer.split_at($dimension_type_size)), acting_version_(acting_version), size_($dimension_type_size) {
+                    const $(&dimension_type_decoder_name) dimension(buffer);
+
+                    block_length_ = static_cast<std::size_t>(dimension.block_length());
+                    num_in_group_ = static_cast<std::size_t>(dimension.num_in_group());
+                }
+
+                $("/// Creates a decoder of a group, which is not present in the decoded data, because it")
+                $("/// was added in a newer version of the schema.")
+                static $(&decoder_name) empty(std::uint16_t acting_version) noexcept {
+                    return $(&decoder_name)(acting_version);
+                }
+
+                std::uint16_t acting_version() const noexcept {
+                    return acting_version_;
+                }
+
+                std::size_t block_length() const noexcept {
+                    return block_length_;
+                }
+
+                std::size_t num_in_group() const noexcept {
+                    return num_in_group_;
+                }
+
+                std::optional<std::size_t> size() const noexcept {
+                    if (index_ != num_in_group_) {
+                        return std::nullopt;
+                    }
+
+                    return size_;
+                }
+
+                void advance() {
+                    if (index_ >= num_in_group_) {
+                        throw SbeError::group_out_of_bounds($(quoted(name.to_case(Case::Snake))));
+                    }
+
+                    const std::size_t fields_size = block_length_;
+                    $(taken_sizes(&groups, &var_data))
+                    const std::size_t advancement = fields_size + groups_size + var_data_size;
+
+                    ++index_;
+                    size_ += advancement;
+                    buffer_ = buffer_.split_at(advancement);
+                }
+                $(self.generate_field_decoders(&fields, layout.offsets)?)
+                $(self.generate_group_decoders(&groups, &quote!(block_length_))?)
+                $(self.generate_var_data_decoders(&var_data, &groups, &quote!(block_length_))?)
+                $(for group in &groups {
+                    $['\n']
+                    $(self.generate_group_decoder(group)?)
+                })
+                $(for var_data_field in &var_data {
+                    $['\n']
+                    $(self.generate_var_data_decoder(var_data_field)?)
+                })
+
+            private:
+                explicit $(&decoder_name)(std::uint16_t acting_version) noexcept : acting_version_(acting_version) {
+                }
+
+                ReadBuf buffer_;
+                std::uint16_t acting_version_;
+                std::size_t block_length_ = 0;
+                std::size_t num_in_group_ = 0;
+                std::size_t index_ = 0;
+                std::size_t size_ = 0;
+                $(size_fields(&groups, &var_data))
+            };
+        })
+    }
+
+    /// Generates the encoder of a group as a class nested in the encoder of its message or
+    /// enclosing group, along with the encoders of its own groups and var data.
+    pub(crate) fn generate_group_encoder(&self, group: &GroupType) -> Result<Tokens<C>> {
+        let name = group.name.as_str();
+        let encoder_name = format!("{}Encoder", name.to_case(Case::UpperCamel));
+        let (fields, groups, var_data) = field_groups(&group.fields);
+        let types = &self.schema.types;
+
+        let dimension_type = dimension_type(group, &types.composite_types)?;
+        let dimension_type_size = dimension_type.size(types)?;
+
+        let block_length_type = match &dimension_type.fields.first() {
+            Some(Type::EncodedData(block_length_type)) => block_length_type,
+            _ => {
+                return Err(anyhow!(
+                    "Only encoded data type expected for the block length type in group '{}'",
+                    group.name
+                ))
+            }
+        };
+        let num_in_group_type = match &dimension_type.fields.last() {
+            Some(Type::EncodedData(num_in_group_type)) => num_in_group_type,
+            _ => {
+                return Err(anyhow!(
+                    "Only encoded data type expected for the num in group type in group '{}'",
+                    group.name
+                ))
+            }
+        };
+
+        let block_length_primitive = block_length_type
+            .primitive_type
+            .lang_primitive(&types.encoded_types)?;
+        let block_length_primitive_size = block_length_type.primitive_type.size(types)?;
+        let num_in_group_native = num_in_group_type
+            .primitive_type
+            .resolved(&types.encoded_types)?;
+        let num_in_group_primitive = num_in_group_native.lang_primitive(&types.encoded_types)?;
+
+        let layout = BlockLayout::from_fields(name, &fields, group.block_length, types)?;
+        let offset_tokens = quote!(size_ + BLOCK_LENGTH);
+
+        Ok(quote! {
+            class $(&encoder_name) {
+            public:
+                static constexpr std::size_t BLOCK_LENGTH = $(layout.block_length);
+
+                explicit $(&encoder_name)(WriteBuf buffer) noexcept : buffer_(buffer) {
+                }
+
+                std::size_t size() const noexcept {
+                    return size_;
+                }
+
+                void advance() {
+                    const std::size_t fields_size = BLOCK_LENGTH;
+                    $(taken_sizes(&groups, &var_data))
+
+                    if (num_in_group_ >= $(max_size(&num_in_group_native))) {
+                        throw SbeError::group_out_of_bounds("num_in_group");
+                    }
+
+                    ++num_in_group_;
+                    size_ += fields_size + groups_size + var_data_size;
+                }
+
+                $("/// Writes the dimension of the group, after all its entries were encoded.")
+                void finish() {
+                    buffer_.put_at<$(&block_length_primitive)>(0, static_cast<$(&block_length_primitive)>(BLOCK_LENGTH));
+                    buffer_.put_at<$(&num_in_group_primitive)>($block_length_primitive_size, static_cast<$(&num_in_group_primitive)>(num_in_group_));
+                }
+                $(self.generate_field_encoders(&fields, &quote!(size_ + ), layout.offsets)?)
+                $(self.generate_group_encoders(&groups, &offset_tokens)?)
+                $(self.generate_var_data_encoders(&var_data, &groups, &offset_tokens)?)
+                $(for group in &groups {
+                    $['\n']
+                    $(self.generate_group_encoder(group)?)
+                })
+                $(for var_data_field in &var_data {
+                    $['\n']
+                    $(self.generate_var_data_encoder(var_data_field)?)
+                })
+
+            private:
+                WriteBuf buffer_;
+                std::size_t num_in_group_ = 0;
+                std::size_t size_ = $dimension_type_size;
+                $(size_fields(&groups, &var_data))
+            };
+        })
+    }
+
+    pub(crate) fn generate_field_decoders(
+        &self,
+        fields: &[&FieldType],
+        offsets: impl IntoIterator<Item = usize>,
+    ) -> Result<Tokens<C>> {
+        let mut decoder_fields: Tokens<C> = quote!();
+
+        for (field, offset) in fields.iter().zip(offsets) {
+            let repr_type = field.to_type(&self.schema.types)?;
+
+            decoder_fields.line();
+            decoder_fields.append(if matches!(field.presence, Presence::Constant) {
+                self.generate_constant_enum(field, repr_type)?
+            } else {
+                let since_version = field.since_version.unwrap_or(0);
+                self.generate_decoder_field(&field.name, &repr_type, offset, since_version)?
+            });
+        }
+
+        Ok(decoder_fields)
+    }
+
+    fn generate_constant_enum(&self, field: &FieldType, repr_type: Type) -> Result<Tokens<C>> {
+        let field_name = field.name.to_case(Case::Snake);
+        let enum_type = match repr_type {
+            Type::Enum(enum_type) => enum_type,
+            _ => return Err(anyhow!("Constant field {} is not an enum", field.name)),
+        };
+        let enum_type_name = enum_type.name.to_case(Case::UpperCamel);
+
+        let default_value = field
+            .value_ref
+            .as_ref()
+            .ok_or(anyhow!("Constant field {} has no ref value", field.name))?;
+
+        let default_value = default_value.split('.').take(2).collect::<Vec<_>>()[1];
+        let default_value = enum_type
+            .values
+            .iter()
+            .find(|value| value.name == default_value)
+            .ok_or(anyhow!(
+                "Constant field {} has no value {}",
+                field.name,
+                default_value
+            ))?;
+
+        Ok(quote! {
+            $(&enum_type_name) $(&field_name)() const noexcept {
+                return $(&enum_type_name)::$(default_value.name.to_case(Case::UpperCamel));
+            }
+        })
+    }
+
+    pub(crate) fn generate_field_encoders(
+        &self,
+        fields: &[&FieldType],
+        offset_prefix: &Tokens<C>,
+        offsets: impl IntoIterator<Item = usize>,
+    ) -> Result<Tokens<C>> {
+        let mut encoder_fields: Tokens<C> = quote!();
+
+        for (field, offset) in fields.iter().zip(offsets) {
+            if matches!(field.presence, Presence::Constant) {
+                continue;
+            }
+
+            let repr_type = field.to_type(&self.schema.types)?;
+
+            encoder_fields.line();
+            encoder_fields.append(self.generate_encoder_field(
+                &field.name,
+                &repr_type,
+                quote!($offset_prefix$offset),
+            )?);
+        }
+
+        Ok(encoder_fields)
+    }
+
+    pub(crate) fn generate_group_decoders(
+        &self,
+        groups: &[&GroupType],
+        offset: &Tokens<C>,
+    ) -> Result<Tokens<C>> {
+        let mut decoder_fields: Tokens<C> = quote!();
+
+        for (group_idx, group) in groups.iter().enumerate() {
+            let field_name = group.name.to_case(Case::Snake);
+            let decoder_name = format!("{}Decoder", group.name.to_case(Case::UpperCamel));
+
+            decoder_fields.line();
+            decoder_fields.append(quote! {
+                template <typename F>
+                auto $(&field_name)_decoder(F &&action) {
+                    $(if let Some(since_version) = group.since_version.filter(|version| *version > 0) {
+                        if (acting_version_ < $since_version) {
+                            $(&decoder_name) decoder = $(&decoder_name)::empty(acting_version_);
+
+                            return detail::invoke_then(decoder, std::forward<F>(action), [this](const $(&decoder_name) &) { $(&field_name)_size_ = 0; });
+                        }
+                        $['\n']
+                    })
+                    const std::size_t offset = $offset$(previous_group_sizes(&groups[..group_idx]));
+                    $(&decoder_name) decoder(buffer_.split_at(offset), acting_version_);
+
+                    return detail::invoke_then(decoder, std::forward<F>(action), [this](const $(&decoder_name) &codec) { $(&field_name)_size_ = codec.size(); });
+                }
+            });
+        }
+
+        Ok(decoder_fields)
+    }
+
+    pub(crate) fn generate_var_data_decoders(
+        &self,
+        var_data_fields: &[&VariableDataType],
+        groups: &[&GroupType],
+        offset: &Tokens<C>,
+    ) -> Result<Tokens<C>> {
+        let mut decoder_fields: Tokens<C> = quote!();
+
+        for (var_data_idx, var_data_field) in var_data_fields.iter().enumerate() {
+            let field_name = var_data_field.name.to_case(Case::Snake);
+            let decoder_name = format!("{}Decoder", var_data_field.name.to_case(Case::UpperCamel));
+
+            decoder_fields.line();
+            decoder_fields.append(quote! {
+                template <typename F>
+                auto $(&field_name)_decoder(F &&action) {
+                    $(if let Some(since_version) = var_data_field.since_version.filter(|version| *version > 0) {
+                        if (acting_version_ < $since_version) {
+                            $(&decoder_name) decoder{ReadBuf()};
+
+                            return detail::invoke_then(decoder, std::forward<F>(action), [this](const $(&decoder_name) &) { $(&field_name)_size_ = 0; });
+                        }
+                        $['\n']
+                    })
+                    const std::size_t offset = $offset$(previous_group_sizes(groups))$(previous_var_data_sizes(&var_data_fields[..var_data_idx]));
+                    $(&decoder_name) decoder(buffer_.split_at(offset));
+
+                    return detail::invoke_then(decoder, std::forward<F>(action), [this](const $(&decoder_name) &codec) { $(&field_name)_size_ = codec.size(); });
+                }
+            });
+        }
+
+        Ok(decoder_fields)
+    }
+
+    pub(crate) fn generate_group_encoders(
+        &self,
+        groups: &[&GroupType],
+        offset: &Tokens<C>,
+    ) -> Result<Tokens<C>> {
+        let mut encoder_fields: Tokens<C> = quote!();
+
+        for (group_idx, group) in groups.iter().enumerate() {
+            let field_name = group.name.to_case(Case::Snake);
+            let encoder_name = format!("{}Encoder", group.name.to_case(Case::UpperCamel));
+
+            encoder_fields.line();
+            encoder_fields.append(quote! {
+                template <typename F>
+                auto $(&field_name)_encoder(F &&action) {
+                    const std::size_t offset = $offset$(previous_group_sizes(&groups[..group_idx]));
+                    $(&encoder_name) encoder(buffer_.split_at(offset));
+
+                    return detail::invoke_then(encoder, std::forward<F>(action), [this]($(&encoder_name) &codec) {
+                        $(&field_name)_size_ = codec.size();
+                        codec.finish();
+                    });
+                }
+            });
+        }
+
+        Ok(encoder_fields)
+    }
+
+    pub(crate) fn generate_var_data_encoders(
+        &self,
+        var_data_fields: &[&VariableDataType],
+        groups: &[&GroupType],
+        offset: &Tokens<C>,
+    ) -> Result<Tokens<C>> {
+        let mut encoder_fields: Tokens<C> = quote!();
+
+        for (var_data_idx, var_data_field) in var_data_fields.iter().enumerate() {
+            let field_name = var_data_field.name.to_case(Case::Snake);
+            let encoder_name = format!("{}Encoder", var_data_field.name.to_case(Case::UpperCamel));
+
+            encoder_fields.line();
+            encoder_fields.append(quote! {
+                template <typename F>
+                auto $(&field_name)_encoder(F &&action) {
+                    const std::size_t offset = $offset$(previous_group_sizes(groups))$(previous_var_data_sizes(&var_data_fields[..var_data_idx]));
+                    $(&encoder_name) encoder(buffer_.split_at(offset));
+
+                    return detail::invoke_then(encoder, std::forward<F>(action), [this]($(&encoder_name) &codec) {
+                        $(&field_name)_size_ = codec.size();
+                        codec.finish();
+                    });
+                }
+            });
+        }
+
+        Ok(encoder_fields)
+    }
+}
+
+/// Sums the recorded sizes of the groups and var data of the current entry, which are
+/// reset for the next one.
+fn taken_sizes(groups: &[&GroupType], var_data: &[&VariableDataType]) -> Tokens<C> {
+    quote! {
+        const std::size_t groups_size = 0$(previous_group_sizes(groups));
+        const std::size_t var_data_size = 0$(previous_var_data_sizes(var_data));
+        $(for group in groups {
+            $['\r']
+            $(group.name.to_case(Case::Snake))_size_.reset();
+        })
+        $(for var in var_data {
+            $['\r']
+            $(var.name.to_case(Case::Snake))_size_.reset();
+        })
+    }
+}
+
+fn previous_group_sizes(groups: &[&GroupType]) -> Tokens<C> {
+    quote! {
+        $(for group in groups => $[' ']+ SbeError::require_group_size($(group.name.to_case(Case::Snake))_size_, $(quoted(group.name.to_case(Case::Snake)))))
+    }
+}
+
+fn previous_var_data_sizes(var_data_fields: &[&VariableDataType]) -> Tokens<C> {
+    quote! {
+        $(for var in var_data_fields => $[' ']+ SbeError::require_var_data_size($(var.name.to_case(Case::Snake))_size_, $(quoted(var.name.to_case(Case::Snake)))))
+    }
+}
+
+/// Recorded sizes of the groups and var data, empty until they were decoded or encoded.
+pub(crate) fn size_fields(groups: &[&GroupType], var_data: &[&VariableDataType]) -> Tokens<C> {
+    quote! {
+        $(for group in groups {
+            $['\r']
+            std::optional<std::size_t> $(group.name.to_case(Case::Snake))_size_;
+        })
+        $(for var in var_data {
+            $['\r']
+            std::optional<std::size_t> $(var.name.to_case(Case::Snake))_size_;
+        })
+    }
+}
@@ -0,0 +1,433 @@
+use crate::generator::common::{dimension_type, field_groups, header_counts};
+use crate::generator::cpp::codecs::group_type::size_fields;
+use crate::generator::cpp::codecs::{field_metadata, CODEC_SYSTEM_INCLUDES};
+use crate::generator::cpp::constants::{
+    COMPOSITE_DIR_NAME, DISPATCHER_HEADER_NAME, MESSAGE_DIR_NAME, READ_BUF_HEADER_NAME,
+    WRITE_BUF_HEADER_NAME,
+};
+use crate::generator::cpp::module::ModuleGenerator;
+use crate::models::layout::BlockLayout;
+use crate::models::message::MessageType;
+use crate::models::types::group_type::GroupType;
+use crate::models::types::variable_data_type::VariableDataType;
+use crate::models::types::{MessageField, SizedEncoded, Type};
+use anyhow::{anyhow, Result};
+use convert_case::{Case, Casing};
+use genco::prelude::*;
+use std::collections::BTreeSet;
+
+impl ModuleGenerator<'_> {
+    fn generate_message_decoder(&self, message: &MessageType) -> Result<Tokens<C>> {
+        let name = message.name.as_str();
+        let decoder_name = format!("{}Decoder", name.to_case(Case::UpperCamel));
+        let (fields, groups, var_data) = field_groups(&message.fields);
+
+        let layout =
+            BlockLayout::from_fields(name, &fields, message.block_length, &self.schema.types)?;
+
+        let header_type = Type::Composite(self.schema.types.header_type.clone());
+        let header_size = header_type.size(&self.schema.types)?;
+        let header_decoder_name = format!(
+            "{}Decoder",
+            self.schema.types.header_type.name.to_case(Case::UpperCamel)
+        );
+        let header_offset = quote!($header_size + block_length_);
+
+        Ok(quote! {
+            class $(&decoder_name) {
+            public:
+                static constexpr std::uint16_t TEMPLATE_ID = $(message.id);
+
+                explicit $(&decoder_name)(ReadBuf buffer) : buffer_(buffer) {
+                    const $(&header_decoder_name) header(buffer_);
+                    const auto schema_id = header.schema_id();
+
+                    if (schema_id != static_cast<$(self.header_field_type("schemaId")?)>(SCHEMA_ID)) {
+                        throw SbeError::wrong_schema(static_cast<std::uint64_t>(schema_id), SCHEMA_ID);
+                    }
+
+                    const auto template_id = static_cast<std::uint16_t>(header.template_id());
+
+                    if (template_id != TEMPLATE_ID) {
+                        throw SbeError::wrong_message_type(template_id, TEMPLATE_ID);
+                    }
+
+                    acting_version_ = static_cast<std::uint16_t>(header.version());
+                    block_length_ = static_cast<std::size_t>(header.block_length());
+                }
+
+                $(&decoder_name)(const void *data, std::size_t length) : $(&decoder_name)(ReadBuf(data, length)) {
+                }
+
+                static constexpr std::uint16_t id() noexcept {
+                    return TEMPLATE_ID;
+                }
+
+                $("/// Version of the schema the decoded message was encoded with.")
+                std::uint16_t acting_version() const noexcept {
+                    return acting_version_;
+                }
+
+                $("/// Length of the root block of the decoded message, which can differ from the one in")
+                $("/// the schema, when the message was encoded with another version.")
+                std::size_t block_length() const noexcept {
+                    return block_length_;
+                }
+
+                $("/// Size of the decoded message without its header, or empty until all its groups and")
+                $("/// var data were decoded.")
+                std::optional<std::size_t> size() const noexcept {
+                    $(missing_sizes_check(&groups, &var_data))
+                    return block_length_$(for size in sizes(&groups, &var_data) => $[' ']+ *$size);
+                }
+
+                $(self.generate_decoder_field("message_header", &header_type, 0, 0)?)
+                $(self.generate_field_decoders(&fields, layout.offsets.iter().map(|field_offset| header_size + field_offset))?)
+                $(self.generate_group_decoders(&groups, &header_offset)?)
+                $(self.generate_var_data_decoders(&var_data, &groups, &header_offset)?)
+                $(for group in &groups {
+                    $['\n']
+                    $(self.generate_group_decoder(group)?)
+                })
+                $(for var_data_field in &var_data {
+                    $['\n']
+                    $(self.generate_var_data_decoder(var_data_field)?)
+                })
+
+            private:
+                ReadBuf buffer_;
+                std::uint16_t acting_version_ = SCHEMA_VERSION;
+                std::size_t block_length_ = $(layout.block_length);
+                $(size_fields(&groups, &var_data))
+            };
+        })
+    }
+
+    fn generate_message_encoder(&self, message: &MessageType) -> Result<Tokens<C>> {
+        let name = message.name.as_str();
+        let encoder_name = format!("{}Encoder", name.to_case(Case::UpperCamel));
+        let (fields, groups, var_data) = field_groups(&message.fields);
+
+        let layout =
+            BlockLayout::from_fields(name, &fields, message.block_length, &self.schema.types)?;
+
+        let header_type = Type::Composite(self.schema.types.header_type.clone());
+        let header_size = header_type.size(&self.schema.types)?;
+        let header_encoder_name = format!(
+            "{}Encoder",
+            self.schema.types.header_type.name.to_case(Case::UpperCamel)
+        );
+        let header_offset = quote!($(header_size + layout.block_length));
+
+        Ok(quote! {
+            class $(&encoder_name) {
+            public:
+                static constexpr std::uint16_t TEMPLATE_ID = $(message.id);
+                static constexpr std::size_t BLOCK_LENGTH = $(layout.block_length);
+
+                explicit $(&encoder_name)(WriteBuf buffer) : buffer_(buffer) {
+                    $(&header_encoder_name) header(buffer_);
+
+                    header.block_length(static_cast<$(self.header_field_type("blockLength")?)>(BLOCK_LENGTH));
+                    header.template_id(static_cast<$(self.header_field_type("templateId")?)>(TEMPLATE_ID));
+                    header.schema_id(static_cast<$(self.header_field_type("schemaId")?)>(SCHEMA_ID));
+                    header.version(static_cast<$(self.header_field_type("version")?)>(SCHEMA_VERSION));
+                    $(for (field_name, count) in header_counts(&self.schema.types, groups.len(), var_data.len()) {
+                        $['\r']
+                        header.$(field_name.to_case(Case::Snake))(static_cast<$(self.header_field_type(field_name)?)>($count));
+                    })
+                }
+
+                $(&encoder_name)(void *data, std::size_t length) : $(&encoder_name)(WriteBuf(data, length)) {
+                }
+
+                static constexpr std::uint16_t id() noexcept {
+                    return TEMPLATE_ID;
+                }
+
+                $("/// Size of the encoded message including its header, or empty until all its groups")
+                $("/// and var data were encoded.")
+                std::optional<std::size_t> size() const noexcept {
+                    $(missing_sizes_check(&groups, &var_data))
+                    return $header_size + BLOCK_LENGTH$(for size in sizes(&groups, &var_data) => $[' ']+ *$size);
+                }
+
+                $(self.generate_encoder_field("message_header", &header_type, quote!(0))?)
+                $(self.generate_field_encoders(&fields, &quote!(), layout.offsets.iter().map(|field_offset| header_size + field_offset))?)
+                $(self.generate_group_encoders(&groups, &header_offset)?)
+                $(self.generate_var_data_encoders(&var_data, &groups, &header_offset)?)
+                $(for group in &groups {
+                    $['\n']
+                    $(self.generate_group_encoder(group)?)
+                })
+                $(for var_data_field in &var_data {
+                    $['\n']
+                    $(self.generate_var_data_encoder(var_data_field)?)
+                })
+
+            private:
+                WriteBuf buffer_;
+                $(size_fields(&groups, &var_data))
+            };
+        })
+    }
+
+    fn write_message_codec(&self, message: &MessageType) -> Result<()> {
+        let codec_tokens: Tokens<C> = quote! {
+            $(self.generate_message_decoder(message)?)
+
+            $(self.generate_message_encoder(message)?)
+        };
+
+        let mut includes = vec![
+            self.include(READ_BUF_HEADER_NAME),
+            self.include(WRITE_BUF_HEADER_NAME),
+        ];
+        includes.extend(self.message_type_includes(message)?);
+        includes.extend(CODEC_SYSTEM_INCLUDES.map(str::to_owned));
+
+        self.write_header(
+            &format!(
+                "{}/{}.hpp",
+                MESSAGE_DIR_NAME,
+                message.name.to_case(Case::Snake)
+            ),
+            &includes,
+            codec_tokens,
+        )
+    }
+
+    /// Includes of the types used by the message header, the fields of the message and of its
+    /// groups, and the dimensions of the groups.
+    fn message_type_includes(&self, message: &MessageType) -> Result<BTreeSet<String>> {
+        let mut field_types = vec![Type::Composite(self.schema.types.header_type.clone())];
+        let mut pending_fields = message.fields.iter().collect::<Vec<_>>();
+
+        while let Some(field) = pending_fields.pop() {
+            match field {
+                MessageField::Field(field) => {
+                    field_types.push(field.to_type(&self.schema.types)?);
+                }
+                MessageField::Group(group) => {
+                    let dimension_type = dimension_type(group, &self.schema.types.composite_types)?;
+                    field_types.push(Type::Composite(dimension_type.clone()));
+                    pending_fields.extend(group.fields.iter());
+                }
+                MessageField::VariableData(_) => {}
+            }
+        }
+
+        self.type_includes(&field_types)
+    }
+
+    /// C++ type of a field of the message header, which the values written by the message
+    /// encoders are cast to.
+    fn header_field_type(&self, field_name: &str) -> Result<String> {
+        let header_type = &self.schema.types.header_type;
+        let field = header_type
+            .fields
+            .iter()
+            .find(|field| field.name() == field_name)
+            .ok_or(anyhow!(
+                "Missing field '{}' in message header '{}'",
+                field_name,
+                header_type.name
+            ))?;
+
+        let encoded_type = match field {
+            Type::EncodedData(encoded_type) => encoded_type.clone(),
+            Type::Reference(reference_type) => {
+                match self.schema.types.find_type(&reference_type.type_name) {
+                    Some(Type::EncodedData(encoded_type)) => encoded_type,
+                    _ => {
+                        return Err(anyhow!(
+                            "Only encoded data type expected for the field '{}' in message header",
+                            field_name
+                        ))
+                    }
+                }
+            }
+            _ => {
+                return Err(anyhow!(
+                    "Only encoded data type expected for the field '{}' in message header",
+                    field_name
+                ))
+            }
+        };
+
+        let metadata = field_metadata(field_name, &encoded_type, &self.schema.types)?;
+
+        Ok(metadata.lang_type.name.to_owned())
+    }
+
+    /// Writes the decoder of any message of the schema, which checks the schema id and picks
+    /// the message decoder by the template id in the message header, and the visitor of the
+    /// decoded messages.
+    fn write_message_dispatcher(&self, message_types: &[&MessageType]) -> Result<()> {
+        let header_decoder_name = format!(
+            "{}Decoder",
+            self.schema.types.header_type.name.to_case(Case::UpperCamel)
+        );
+
+        let variants = message_types
+            .iter()
+            .map(|message_type| {
+                (
+                    message_type.name.to_case(Case::UpperCamel),
+                    message_type.name.to_case(Case::Snake),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let dispatcher_tokens: Tokens<C> = quote! {
+            $("/// Visitor of the decoded messages. Messages without an overridden method are ignored.")
+            class MessageVisitor {
+            public:
+                virtual ~MessageVisitor() = default;
+                $(for (name, snake_name) in &variants {
+                    $['\r']
+                    virtual void on_$(snake_name)($(name)Decoder &) {
+                    }
+                })
+            };
+
+            $("/// Decoder of any message of the schema.")
+            class AnyMessageDecoder {
+            public:
+                using Decoder = std::variant<$(for (name, _) in &variants join (, ) => $(name)Decoder)>;
+
+                explicit AnyMessageDecoder(ReadBuf buffer) : template_id_(read_template_id(buffer)), decoder_(decode(template_id_, buffer)) {
+                }
+
+                AnyMessageDecoder(const void *data, std::size_t length) : AnyMessageDecoder(ReadBuf(data, length)) {
+                }
+
+                std::uint16_t template_id() const noexcept {
+                    return template_id_;
+                }
+
+                $("/// Version of the schema the decoded message was encoded with.")
+                std::uint16_t acting_version() const noexcept {
+                    return std::visit([](const auto &decoder) { return decoder.acting_version(); }, decoder_);
+                }
+
+                $("/// Decoder of the message, holding the decoder type with the template id of the message.")
+                Decoder &decoder() noexcept {
+                    return decoder_;
+                }
+
+                $("/// Calls the method of the visitor for the type of the decoded message.")
+                void accept(MessageVisitor &visitor) {
+                    switch (template_id_) {
+                    $(for (name, snake_name) in &variants {
+                        $['\r']
+                        case $(name)Decoder::TEMPLATE_ID:
+                            visitor.on_$(snake_name)(std::get<$(name)Decoder>(decoder_));
+                            break;
+                    })
+                    default:
+                        throw SbeError::unknown_message_type(template_id_);
+                    }
+                }
+
+            private:
+                static std::uint16_t read_template_id(ReadBuf buffer) {
+                    const $(&header_decoder_name) header(buffer);
+
+                    if (header.schema_id() != static_cast<$(self.header_field_type("schemaId")?)>(SCHEMA_ID)) {
+                        throw SbeError::wrong_schema(static_cast<std::uint64_t>(header.schema_id()), SCHEMA_ID);
+                    }
+
+                    return static_cast<std::uint16_t>(header.template_id());
+                }
+
+                static Decoder decode(std::uint16_t template_id, ReadBuf buffer) {
+                    switch (template_id) {
+                    $(for (name, _) in &variants {
+                        $['\r']
+                        case $(name)Decoder::TEMPLATE_ID:
+                            return $(name)Decoder(buffer);
+                    })
+                    default:
+                        throw SbeError::unknown_message_type(template_id);
+                    }
+                }
+
+                std::uint16_t template_id_;
+                Decoder decoder_;
+            };
+        };
+
+        let mut includes = vec![
+            self.include(READ_BUF_HEADER_NAME),
+            self.include(&format!(
+                "{}/{}.hpp",
+                COMPOSITE_DIR_NAME,
+                self.schema.types.header_type.name.to_case(Case::Snake)
+            )),
+        ];
+        includes.extend(message_types.iter().map(|message_type| {
+            self.include(&format!(
+                "{}/{}.hpp",
+                MESSAGE_DIR_NAME,
+                message_type.name.to_case(Case::Snake)
+            ))
+        }));
+        includes.extend(["<cstddef>", "<cstdint>", "<variant>"].map(str::to_owned));
+
+        self.write_header(
+            &format!("{}/{}", MESSAGE_DIR_NAME, DISPATCHER_HEADER_NAME),
+            &includes,
+            dispatcher_tokens,
+        )
+    }
+
+    pub fn write_message_codecs(&self) -> Result<()> {
+        let message_types = self
+            .schema
+            .message_types
+            .message_types
+            .values()
+            .collect::<Vec<_>>();
+
+        for message_type in &message_types {
+            self.write_message_codec(message_type)?;
+        }
+
+        if !message_types.is_empty() {
+            self.write_message_dispatcher(&message_types)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Names of the recorded sizes of the groups and var data.
+fn sizes(groups: &[&GroupType], var_data: &[&VariableDataType]) -> Vec<String> {
+    groups
+        .iter()
+        .map(|group| group.name.as_str())
+        .chain(var_data.iter().map(|var| var.name.as_str()))
+        .map(|name| format!("{}_size_", name.to_case(Case::Snake)))
+        .collect()
+}
+
+/// Returns early from the size of a message, until the sizes of all its groups and var data
+/// were recorded.
+fn missing_sizes_check(groups: &[&GroupType], var_data: &[&VariableDataType]) -> Tokens<C> {
+    let conditions = sizes(groups, var_data)
+        .iter()
+        .map(|size| format!("!{}", size))
+        .collect::<Vec<_>>();
+
+    if conditions.is_empty() {
+        return quote!();
+    }
+
+    quote! {
+        if ($(conditions.join(" || "))) {
+            return std::nullopt;
+        }
+        $['\n']
+    }
+}
@@ -0,0 +1,193 @@
+mod composite_type;
+mod enum_type;
+mod group_type;
+mod message_type;
+mod set_type;
+mod var_data_type;
+
+use crate::generator::common::FieldMetadata;
+use crate::models::types::encoded_data_type::EncodedDataType;
+use crate::models::types::primitive_type::{NativeType, PrimitiveConvertible, ResolvableType};
+use crate::models::types::SizedEncoded;
+use crate::models::TypeMap;
+use anyhow::Result;
+use convert_case::{Case, Casing};
+use genco::prelude::*;
+
+/// Standard library headers used by the codecs of the enums, composites and messages.
+pub(crate) const CODEC_SYSTEM_INCLUDES: [&str; 10] = [
+    "<array>",
+    "<cmath>",
+    "<cstddef>",
+    "<cstdint>",
+    "<limits>",
+    "<optional>",
+    "<string>",
+    "<string_view>",
+    "<type_traits>",
+    "<utility>",
+];
+
+/// Metadata of an encoded field, with the field name in snake case.
+pub(crate) fn field_metadata(
+    field_name: &str,
+    encoded_type: &EncodedDataType,
+    types: &TypeMap,
+) -> Result<FieldMetadata<C>> {
+    let field_primitive_type = encoded_type.primitive_type.resolved(&types.encoded_types)?;
+    let field_size = encoded_type.size(types)?;
+    let type_size = encoded_type.primitive_type.size(types)?;
+
+    Ok(FieldMetadata {
+        field_name: field_name.to_case(Case::Snake),
+        field_primitive_type: field_primitive_type.clone(),
+        type_size,
+        field_length: field_size / type_size,
+        lang_type: field_primitive_type.lang_primitive(&types.encoded_types)?,
+        encoding: encoded_type.character_encoding,
+    })
+}
+
+/// C++ literal of a numeric value from the schema, typed as the primitive type.
+pub(crate) fn literal(value: &str, native_type: &NativeType) -> String {
+    match native_type {
+        NativeType::Char => format!("static_cast<char>({})", value),
+        NativeType::UInt32 => format!("{}U", value),
+        NativeType::UInt64 => format!("{}ULL", value),
+        NativeType::Int64 => format!("{}LL", value),
+        NativeType::Float => format!("{}f", floating_literal(value)),
+        NativeType::Double => floating_literal(value),
+        _ => value.to_owned(),
+    }
+}
+
+/// Floating point literal, which needs a decimal point or an exponent.
+fn floating_literal(value: &str) -> String {
+    if value.contains(['.', 'e', 'E']) || value.chars().any(char::is_alphabetic) {
+        value.to_owned()
+    } else {
+        format!("{}.0", value)
+    }
+}
+
+/// Default null value of a primitive type, see `NativeType::null`.
+pub(crate) fn null_literal(native_type: &NativeType) -> String {
+    match native_type {
+        NativeType::Char => "static_cast<char>(0)".to_owned(),
+        NativeType::UInt8 | NativeType::UInt16 | NativeType::UInt32 | NativeType::UInt64 => {
+            format!("std::numeric_limits<{}>::max()", cpp_type(native_type))
+        }
+        NativeType::Int8 | NativeType::Int16 | NativeType::Int32 | NativeType::Int64 => {
+            format!("std::numeric_limits<{}>::min()", cpp_type(native_type))
+        }
+        _ => format!(
+            "std::numeric_limits<{}>::quiet_NaN()",
+            cpp_type(native_type)
+        ),
+    }
+}
+
+/// Largest value of an integer primitive type as a size, used for the counters of groups and
+/// var data.
+pub(crate) fn max_size(native_type: &NativeType) -> String {
+    format!(
+        "static_cast<std::size_t>(std::numeric_limits<{}>::max())",
+        cpp_type(native_type)
+    )
+}
+
+/// C++ type of a resolved primitive type.
+pub(crate) fn cpp_type(native_type: &NativeType) -> &'static str {
+    match native_type {
+        NativeType::Char => "char",
+        NativeType::UInt8 => "std::uint8_t",
+        NativeType::UInt16 => "std::uint16_t",
+        NativeType::UInt32 => "std::uint32_t",
+        NativeType::UInt64 => "std::uint64_t",
+        NativeType::Int8 => "std::int8_t",
+        NativeType::Int16 => "std::int16_t",
+        NativeType::Int32 => "std::int32_t",
+        NativeType::Int64 => "std::int64_t",
+        NativeType::Float => "float",
+        NativeType::Double => "double",
+        NativeType::Reference(type_name) => unreachable!("Resolved reference: {}", type_name),
+    }
+}
+
+/// Null value of an optional field, either declared by the `nullValue` of the encoded type,
+/// or the default one for its primitive type.
+pub(crate) fn null_value(encoded_type: &EncodedDataType, metadata: &FieldMetadata<C>) -> String {
+    match &encoded_type.null_value {
+        Some(null_value) => literal(null_value, &metadata.field_primitive_type),
+        None => null_literal(&metadata.field_primitive_type),
+    }
+}
+
+pub(crate) fn null_value_condition(
+    variable: &str,
+    encoded_type: &EncodedDataType,
+    metadata: &FieldMetadata<C>,
+) -> Tokens<C> {
+    match (&metadata.field_primitive_type, &encoded_type.null_value) {
+        (NativeType::Float | NativeType::Double, None) => quote!(std::isnan($variable)),
+        _ => quote!($variable == $(null_value(encoded_type, metadata))),
+    }
+}
+
+/// Checks of a `variable` against the `minValue` and `maxValue` of the encoded type.
+pub(crate) fn bounds_checks(
+    field_name: &str,
+    variable: &str,
+    encoded_type: &EncodedDataType,
+    native_type: &NativeType,
+) -> Tokens<C> {
+    let mut checks = quote!();
+    let unsigned = matches!(
+        native_type,
+        NativeType::UInt8 | NativeType::UInt16 | NativeType::UInt32 | NativeType::UInt64
+    );
+
+    if let Some(min) = &encoded_type.min_value {
+        // Unsigned values can never be below zero
+        if !(unsigned && min.parse::<u64>() == Ok(0)) {
+            checks.append(bounds_check(
+                field_name,
+                variable,
+                "<",
+                min,
+                native_type,
+                "min",
+            ));
+            checks.line();
+        }
+    }
+
+    if let Some(max) = &encoded_type.max_value {
+        checks.append(bounds_check(
+            field_name,
+            variable,
+            ">",
+            max,
+            native_type,
+            "max",
+        ));
+        checks.line();
+    }
+
+    checks
+}
+
+fn bounds_check(
+    field_name: &str,
+    variable: &str,
+    operator: &str,
+    limit: &str,
+    native_type: &NativeType,
+    limit_name: &str,
+) -> Tokens<C> {
+    quote! {
+        if ($variable $operator $(literal(limit, native_type))) {
+            throw SbeError::value_out_of_bounds($(quoted(field_name)), std::to_string($variable) + $(quoted(format!(" {} {} ({})", operator, limit, limit_name))));
+        }
+    }
+}
@@ -0,0 +1,82 @@
+use anyhow::Result;
+use convert_case::{Case, Casing};
+
+use crate::generator::cpp::constants::SET_DIR_NAME;
+use crate::generator::cpp::module::ModuleGenerator;
+use crate::models::types::primitive_type::PrimitiveConvertible;
+use crate::models::types::set_type::{Choice, SetType};
+use genco::prelude::*;
+
+impl ModuleGenerator<'_> {
+    fn generate_choice_token(name: &str, cpp_type: &str, choice: &Choice) -> Tokens<C> {
+        let choice_name = choice.name.to_case(Case::Snake);
+
+        quote! {
+            $['\n']
+            bool get_$(&choice_name)() const noexcept {
+                return 0 != (value_ & (std::uint64_t{1} << $(choice.value)));
+            }
+
+            $name &set_$(&choice_name)(bool value) noexcept {
+                value_ = static_cast<$cpp_type>(value ? value_ | (std::uint64_t{1} << $(choice.value)) : value_ & ~(std::uint64_t{1} << $(choice.value)));
+                return *this;
+            }
+        }
+    }
+
+    fn write_set_codec(&self, set_type: &SetType) -> Result<()> {
+        let name = set_type.name.to_case(Case::UpperCamel);
+        let cpp_type = set_type
+            .encoding_type
+            .lang_primitive(&self.schema.types.encoded_types)?;
+
+        let set_tokens: Tokens<C> = quote! {
+            class $(&name) {
+            public:
+                $(&name)() noexcept = default;
+
+                explicit $(&name)($(&cpp_type) value) noexcept : value_(value) {
+                }
+
+                $(&cpp_type) value() const noexcept {
+                    return value_;
+                }
+
+                $(&name) &clear() noexcept {
+                    value_ = 0;
+                    return *this;
+                }
+                $(for choice in &set_type.choices => $(Self::generate_choice_token(&name, cpp_type.name, choice)))
+
+                bool operator==(const $(&name) &other) const noexcept {
+                    return value_ == other.value_;
+                }
+
+                bool operator!=(const $(&name) &other) const noexcept {
+                    return value_ != other.value_;
+                }
+
+            private:
+                $(&cpp_type) value_ = 0;
+            };
+        };
+
+        self.write_header(
+            &format!(
+                "{}/{}.hpp",
+                SET_DIR_NAME,
+                set_type.name.to_case(Case::Snake)
+            ),
+            &["<cstdint>".to_owned()],
+            set_tokens,
+        )
+    }
+
+    pub fn write_set_codecs(&self) -> Result<()> {
+        for set_type in self.schema.types.set_types.values() {
+            self.write_set_codec(set_type)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,193 @@
+use crate::generator::common::FieldMetadata;
+use crate::generator::cpp::codecs::{field_metadata, max_size};
+use crate::generator::cpp::module::ModuleGenerator;
+use crate::models::types::composite_type::CompositeType;
+use crate::models::types::primitive_type::NativeType;
+use crate::models::types::variable_data_type::VariableDataType;
+use crate::models::types::{CharacterEncoding, Type};
+use crate::models::TypeMap;
+use anyhow::{anyhow, Result};
+use convert_case::{Case, Casing};
+use genco::prelude::*;
+
+impl ModuleGenerator<'_> {
+    /// Generates the decoder of var data as a class nested in the decoder of its message
+    /// or group.
+    pub(crate) fn generate_var_data_decoder(
+        &self,
+        var_data: &VariableDataType,
+    ) -> Result<Tokens<C>> {
+        let name = var_data.name.as_str();
+        let decoder_name = format!("{}Decoder", name.to_case(Case::UpperCamel));
+        let repr_type = var_data.repr_type(&self.schema.types.composite_types)?;
+
+        let (length_type_metadata, value_type_metadata) =
+            repr_type_metadata(name, repr_type, &self.schema.types)?;
+
+        let length_type = &length_type_metadata.lang_type;
+        let length_type_size = length_type_metadata.type_size;
+
+        let value_type_native = &value_type_metadata.field_primitive_type;
+        let value_type = &value_type_metadata.lang_type;
+        let value_type_size = value_type_metadata.type_size;
+
+        Ok(quote! {
+            class $(&decoder_name) {
+            public:
+                explicit $(&decoder_name)(ReadBuf buffer) noexcept : buffer_(buffer) {
+                }
+
+                std::size_t length() const {
+                    $("// Var data missing in older versions of the schema has no buffer at all")
+                    if (buffer_.length() < $length_type_size) {
+                        return 0;
+                    }
+
+                    return static_cast<std::size_t>(buffer_.get_at<$length_type>(0));
+                }
+
+                std::size_t size() const {
+                    return length() * $value_type_size + $length_type_size;
+                }
+
+                $value_type get_at(std::size_t index) const {
+                    return buffer_.get_at<$value_type>(index * $value_type_size + $length_type_size);
+                }
+                $(if is_bytes(value_type_native) {
+                    $['\n']
+                    std::string_view get_slice_at(std::size_t index, std::size_t length) const {
+                        if (length == 0) {
+                            return std::string_view();
+                        }
+
+                        return buffer_.get_slice_at(index * $value_type_size + $length_type_size, length);
+                    }
+                })
+
+            private:
+                ReadBuf buffer_;
+            };
+        })
+    }
+
+    /// Generates the encoder of var data as a class nested in the encoder of its message
+    /// or group.
+    pub(crate) fn generate_var_data_encoder(
+        &self,
+        var_data: &VariableDataType,
+    ) -> Result<Tokens<C>> {
+        let name = var_data.name.as_str();
+        let encoder_name = format!("{}Encoder", name.to_case(Case::UpperCamel));
+        let repr_type = var_data.repr_type(&self.schema.types.composite_types)?;
+
+        let (length_type_metadata, value_type_metadata) =
+            repr_type_metadata(name, repr_type, &self.schema.types)?;
+
+        let length_type = &length_type_metadata.lang_type;
+        let length_type_size = length_type_metadata.type_size;
+        let max_length = max_size(&length_type_metadata.field_primitive_type);
+
+        let value_type_native = &value_type_metadata.field_primitive_type;
+        let value_type = &value_type_metadata.lang_type;
+        let value_type_size = value_type_metadata.type_size;
+        let ascii = matches!(value_type_metadata.encoding, Some(CharacterEncoding::Ascii));
+
+        Ok(quote! {
+            class $(&encoder_name) {
+            public:
+                explicit $(&encoder_name)(WriteBuf buffer) noexcept : buffer_(buffer) {
+                }
+
+                std::size_t size() const noexcept {
+                    return length_ * $value_type_size + $length_type_size;
+                }
+
+                void put_at(std::size_t index, $value_type value) {
+                    $(if ascii {
+                        if (static_cast<std::uint8_t>(value) > 127) {
+                            throw SbeError::invalid_string_value(std::string(1, value));
+                        }
+                        $['\n']
+                    })
+                    if (index >= length_) {
+                        if (index >= $(&max_length)) {
+                            throw SbeError::var_data_out_of_bounds($(quoted(name.to_case(Case::Snake))));
+                        }
+
+                        length_ = index + 1;
+                    }
+
+                    buffer_.put_at<$value_type>(index * $value_type_size + $length_type_size, value);
+                }
+                $(if is_bytes(value_type_native) {
+                    $['\n']
+                    void put_slice_at(std::size_t index, std::string_view value) {
+                        $(if ascii {
+                            for (const char part : value) {
+                                if (static_cast<std::uint8_t>(part) > 127) {
+                                    throw SbeError::invalid_string_value(std::string(value));
+                                }
+                            }
+                            $['\n']
+                        })
+                        const std::size_t data_end = index + value.size();
+
+                        if (data_end >= length_) {
+                            if (data_end > $(&max_length)) {
+                                throw SbeError::var_data_out_of_bounds($(quoted(name.to_case(Case::Snake))));
+                            }
+
+                            length_ = data_end;
+                        }
+
+                        buffer_.put_bytes_at(index * $value_type_size + $length_type_size, value);
+                    }
+                })
+
+                $("/// Writes the length of the var data, after all its values were encoded.")
+                void finish() {
+                    buffer_.put_at<$length_type>(0, static_cast<$length_type>(length_));
+                }
+
+            private:
+                WriteBuf buffer_;
+                std::size_t length_ = 0;
+            };
+        })
+    }
+}
+
+/// Single byte values can be read and written as slices.
+fn is_bytes(native_type: &NativeType) -> bool {
+    matches!(native_type, NativeType::Char | NativeType::UInt8)
+}
+
+fn repr_type_metadata(
+    var_data_name: &str,
+    repr_type: &CompositeType,
+    types: &TypeMap,
+) -> Result<(FieldMetadata<C>, FieldMetadata<C>)> {
+    let length_type = match &repr_type.fields[0] {
+        Type::EncodedData(length_type) => length_type,
+        _ => {
+            return Err(anyhow!(
+            "Only encoded data type expected for the length type in variable data encoding '{}'",
+            var_data_name
+        ))
+        }
+    };
+    let length_type_metadata = field_metadata("", length_type, types)?;
+
+    let value_type = match &repr_type.fields[1] {
+        Type::EncodedData(value_type) => value_type,
+        _ => {
+            return Err(anyhow!(
+                "Only encoded data type expected for the value type in variable data encoding '{}'",
+                var_data_name
+            ))
+        }
+    };
+    let value_type_metadata = field_metadata("", value_type, types)?;
+
+    Ok((length_type_metadata, value_type_metadata))
+}
@@ -0,0 +1,10 @@
+pub const INCLUDE_DIR: &str = "include";
+pub const ERROR_HEADER_NAME: &str = "sbe_error.hpp";
+pub const SCHEMA_HEADER_NAME: &str = "schema.hpp";
+pub const READ_BUF_HEADER_NAME: &str = "read_buf.hpp";
+pub const WRITE_BUF_HEADER_NAME: &str = "write_buf.hpp";
+pub const DISPATCHER_HEADER_NAME: &str = "dispatcher.hpp";
+pub const ENUM_DIR_NAME: &str = "enums";
+pub const SET_DIR_NAME: &str = "sets";
+pub const COMPOSITE_DIR_NAME: &str = "composites";
+pub const MESSAGE_DIR_NAME: &str = "messages";
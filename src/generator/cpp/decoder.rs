@@ -0,0 +1,102 @@
+use anyhow::Result;
+
+use crate::generator::cpp::constants::{READ_BUF_HEADER_NAME, SCHEMA_HEADER_NAME};
+use crate::generator::cpp::module::ModuleGenerator;
+use genco::prelude::*;
+
+impl ModuleGenerator<'_> {
+    pub fn write_read_buf_header(&self) -> Result<()> {
+        let read_buf_content: Tokens<C> = quote! {
+            $("/// Read-only view of the bytes of an encoded message, or a part of it. The bytes are")
+            $("/// not owned by the view, so they have to outlive it.")
+            class ReadBuf {
+            public:
+                ReadBuf() noexcept = default;
+
+                ReadBuf(const void *data, std::size_t length) noexcept : data_(static_cast<const std::uint8_t *>(data)), length_(length) {
+                }
+
+                const std::uint8_t *data() const noexcept {
+                    return data_;
+                }
+
+                std::size_t length() const noexcept {
+                    return length_;
+                }
+
+                $("/// Returns the bytes from the index to the end of the buffer.")
+                ReadBuf split_at(std::size_t index) const {
+                    check_bounds(index, 0);
+
+                    return ReadBuf(data_ + index, length_ - index);
+                }
+
+                template <typename T>
+                T get_at(std::size_t index) const {
+                    check_bounds(index, sizeof(T));
+
+                    T value;
+                    std::memcpy(&value, data_ + index, sizeof(T));
+
+                    return detail::to_schema_order(value);
+                }
+
+                std::string_view get_slice_at(std::size_t index, std::size_t length) const {
+                    check_bounds(index, length);
+
+                    return std::string_view(reinterpret_cast<const char *>(data_ + index), length);
+                }
+
+                $("/// Decodes a string, which can be either UTF-8 or ASCII, as any valid ASCII string is also")
+                $("/// a valid UTF-8 string.")
+                std::string get_string_at(std::size_t index, std::size_t length) const {
+                    const std::string_view value = get_slice_at(index, length);
+                    std::size_t position = 0;
+
+                    while (position < value.size()) {
+                        const auto lead = static_cast<std::uint8_t>(value[position]);
+                        const std::size_t count = lead < 0x80 ? 1 : (lead >> 5) == 0x6 ? 2 : (lead >> 4) == 0xE ? 3 : (lead >> 3) == 0x1E ? 4 : 0;
+
+                        if (count == 0 || count > value.size() - position) {
+                            throw SbeError::invalid_string_value("invalid utf-8 sequence at byte " + std::to_string(position));
+                        }
+
+                        for (std::size_t idx = 1; idx < count; ++idx) {
+                            if ((static_cast<std::uint8_t>(value[position + idx]) >> 6) != 0x2) {
+                                throw SbeError::invalid_string_value("invalid utf-8 sequence at byte " + std::to_string(position));
+                            }
+                        }
+
+                        position += count;
+                    }
+
+                    return std::string(value);
+                }
+
+            private:
+                void check_bounds(std::size_t index, std::size_t count) const {
+                    if (index > length_ || count > length_ - index) {
+                        throw SbeError::codec_out_of_bounds(index + count, length_);
+                    }
+                }
+
+                const std::uint8_t *data_ = nullptr;
+                std::size_t length_ = 0;
+            };
+        };
+
+        self.write_header(
+            READ_BUF_HEADER_NAME,
+            &[
+                self.error_include(),
+                self.include(SCHEMA_HEADER_NAME),
+                "<cstddef>".to_owned(),
+                "<cstdint>".to_owned(),
+                "<cstring>".to_owned(),
+                "<string>".to_owned(),
+                "<string_view>".to_owned(),
+            ],
+            read_buf_content,
+        )
+    }
+}
@@ -0,0 +1,71 @@
+use anyhow::Result;
+
+use crate::generator::cpp::constants::{SCHEMA_HEADER_NAME, WRITE_BUF_HEADER_NAME};
+use crate::generator::cpp::module::ModuleGenerator;
+use genco::prelude::*;
+
+impl ModuleGenerator<'_> {
+    pub fn write_write_buf_header(&self) -> Result<()> {
+        let write_buf_content: Tokens<C> = quote! {
+            $("/// Writable view of the bytes of an encoded message, or a part of it. The bytes are")
+            $("/// not owned by the view, so they have to outlive it.")
+            class WriteBuf {
+            public:
+                WriteBuf(void *data, std::size_t length) noexcept : data_(static_cast<std::uint8_t *>(data)), length_(length) {
+                }
+
+                std::uint8_t *data() const noexcept {
+                    return data_;
+                }
+
+                std::size_t length() const noexcept {
+                    return length_;
+                }
+
+                $("/// Returns the bytes from the index to the end of the buffer.")
+                WriteBuf split_at(std::size_t index) const {
+                    check_bounds(index, 0);
+
+                    return WriteBuf(data_ + index, length_ - index);
+                }
+
+                template <typename T>
+                void put_at(std::size_t index, T value) {
+                    check_bounds(index, sizeof(T));
+
+                    const T ordered = detail::to_schema_order(value);
+                    std::memcpy(data_ + index, &ordered, sizeof(T));
+                }
+
+                void put_bytes_at(std::size_t index, std::string_view value) {
+                    check_bounds(index, value.size());
+
+                    std::memcpy(data_ + index, value.data(), value.size());
+                }
+
+            private:
+                void check_bounds(std::size_t index, std::size_t count) const {
+                    if (index > length_ || count > length_ - index) {
+                        throw SbeError::codec_out_of_bounds(index + count, length_);
+                    }
+                }
+
+                std::uint8_t *data_;
+                std::size_t length_;
+            };
+        };
+
+        self.write_header(
+            WRITE_BUF_HEADER_NAME,
+            &[
+                self.error_include(),
+                self.include(SCHEMA_HEADER_NAME),
+                "<cstddef>".to_owned(),
+                "<cstdint>".to_owned(),
+                "<cstring>".to_owned(),
+                "<string_view>".to_owned(),
+            ],
+            write_buf_content,
+        )
+    }
+}
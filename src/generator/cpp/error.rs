@@ -0,0 +1,118 @@
+use crate::generator::cpp::constants::ERROR_HEADER_NAME;
+use crate::generator::cpp::{write_header, CppGenerator};
+use anyhow::Result;
+use genco::prelude::*;
+
+impl CppGenerator {
+    pub fn write_error_header(&self) -> Result<()> {
+        let error_header_content: Tokens<C> = quote! {
+            $("/// Error of encoding or decoding a message.")
+            class SbeError : public std::runtime_error {
+            public:
+                enum class Kind {
+                    InvalidStringValue,
+                    InvalidEnumValue,
+                    ValueOutOfBounds,
+                    WrongSliceSize,
+                    MissingGroupSize,
+                    MissingVarDataSize,
+                    GroupOutOfBounds,
+                    VarDataOutOfBounds,
+                    WrongSchema,
+                    WrongMessageType,
+                    UnknownMessageType,
+                    CodecOutOfBounds,
+                };
+
+                SbeError(Kind kind, const std::string &message) : std::runtime_error(message), kind_(kind) {
+                }
+
+                Kind kind() const noexcept {
+                    return kind_;
+                }
+
+                static SbeError invalid_string_value(const std::string &value) {
+                    return SbeError(Kind::InvalidStringValue, "invalid ascii string: " + value);
+                }
+
+                static SbeError invalid_enum_value(const std::string &type_name, const std::string &value) {
+                    return SbeError(Kind::InvalidEnumValue, "invalid enum value for '" + type_name + "': " + value);
+                }
+
+                static SbeError value_out_of_bounds(const std::string &field_name, const std::string &message) {
+                    return SbeError(Kind::ValueOutOfBounds, "value out of bounds for field '" + field_name + "': " + message);
+                }
+
+                static SbeError wrong_slice_size(const std::string &message) {
+                    return SbeError(Kind::WrongSliceSize, "wrong slice size: " + message);
+                }
+
+                static SbeError missing_group_size(const std::string &group_name) {
+                    return SbeError(Kind::MissingGroupSize, "missing group size: " + group_name);
+                }
+
+                static SbeError missing_var_data_size(const std::string &var_data_name) {
+                    return SbeError(Kind::MissingVarDataSize, "missing var data size: " + var_data_name);
+                }
+
+                static SbeError group_out_of_bounds(const std::string &group_name) {
+                    return SbeError(Kind::GroupOutOfBounds, "group out of bounds: " + group_name);
+                }
+
+                static SbeError var_data_out_of_bounds(const std::string &var_data_name) {
+                    return SbeError(Kind::VarDataOutOfBounds, "var data out of bounds: " + var_data_name);
+                }
+
+                static SbeError wrong_schema(std::uint64_t schema_id, std::uint64_t expected_schema_id) {
+                    return SbeError(Kind::WrongSchema, "received message had wrong schema: " + std::to_string(schema_id) + ", expected " + std::to_string(expected_schema_id));
+                }
+
+                static SbeError wrong_message_type(std::uint16_t template_id, std::uint16_t expected_template_id) {
+                    return SbeError(Kind::WrongMessageType, "received message had wrong type: " + std::to_string(template_id) + ", expected " + std::to_string(expected_template_id));
+                }
+
+                static SbeError unknown_message_type(std::uint16_t template_id) {
+                    return SbeError(Kind::UnknownMessageType, "received message had unknown type: " + std::to_string(template_id));
+                }
+
+                static SbeError codec_out_of_bounds(std::size_t data_end, std::size_t length) {
+                    return SbeError(Kind::CodecOutOfBounds, "codec out of bounds: " + std::to_string(data_end) + " > " + std::to_string(length));
+                }
+
+                $("/// Returns the size of a group, which must be decoded or encoded first.")
+                static std::size_t require_group_size(const std::optional<std::size_t> &size, const char *group_name) {
+                    if (!size) {
+                        throw missing_group_size(group_name);
+                    }
+
+                    return *size;
+                }
+
+                $("/// Returns the size of a var data field, which must be decoded or encoded first.")
+                static std::size_t require_var_data_size(const std::optional<std::size_t> &size, const char *var_data_name) {
+                    if (!size) {
+                        throw missing_var_data_size(var_data_name);
+                    }
+
+                    return *size;
+                }
+
+            private:
+                Kind kind_;
+            };
+        };
+
+        write_header(
+            &self.root_path().join(ERROR_HEADER_NAME),
+            &self.root_namespace(),
+            &[
+                "<cstddef>".to_owned(),
+                "<cstdint>".to_owned(),
+                "<optional>".to_owned(),
+                "<stdexcept>".to_owned(),
+                "<string>".to_owned(),
+            ],
+            error_header_content,
+        )
+    }
+}
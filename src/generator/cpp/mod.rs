@@ -0,0 +1,186 @@
+mod codecs;
+mod constants;
+mod decoder;
+mod encoder;
+mod error;
+mod module;
+
+use crate::generator::cpp::constants::INCLUDE_DIR;
+use crate::generator::{write_file, CodeGenerator};
+use crate::models::schema::ValidatedMessageSchema;
+use anyhow::Result;
+use convert_case::{Case, Casing};
+use indoc::formatdoc;
+use std::collections::BTreeMap;
+use std::fs::{create_dir_all, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::models::types::encoded_data_type::EncodedDataType;
+use crate::models::types::primitive_type::{
+    LanguagePrimitive, NativeType, PrimitiveConvertible, ResolvableType,
+};
+use genco::prelude::*;
+
+pub struct CppGenerator {
+    path: PathBuf,
+    project_name: String,
+    project_version: String,
+    bounds_checks: bool,
+    schemas: Vec<ValidatedMessageSchema>,
+}
+
+impl CppGenerator {
+    pub fn new(
+        path: &Path,
+        project_name: &str,
+        project_version: &str,
+        schemas: Vec<ValidatedMessageSchema>,
+        bounds_checks: bool,
+    ) -> Self {
+        Self {
+            path: path.to_owned(),
+            project_name: project_name.to_owned(),
+            project_version: project_version.to_owned(),
+            bounds_checks,
+            schemas,
+        }
+    }
+
+    /// Root namespace of the project, which contains a namespace for every schema. It is also
+    /// the directory of the headers in the include path.
+    fn root_namespace(&self) -> String {
+        self.project_name.to_case(Case::Snake)
+    }
+
+    fn root_path(&self) -> PathBuf {
+        self.path.join(INCLUDE_DIR).join(self.root_namespace())
+    }
+
+    fn write_project_files(&self, with_test_dependencies: bool) -> Result<()> {
+        create_dir_all(self.root_path())?;
+
+        self.write_cmake_lists(with_test_dependencies)?;
+        self.write_error_header()
+    }
+
+    fn write_cmake_lists(&self, with_test_dependencies: bool) -> Result<()> {
+        let library = self.root_namespace();
+
+        let test_dependencies = formatdoc! {"
+
+            enable_testing()
+
+            include(FetchContent)
+            FetchContent_Declare(
+                googletest
+                URL https://github.com/google/googletest/archive/refs/tags/v1.14.0.zip
+            )
+            FetchContent_MakeAvailable(googletest)
+
+            file(GLOB TEST_SOURCES CONFIGURE_DEPENDS tests/*.cpp)
+
+            add_executable({library}_tests ${{TEST_SOURCES}})
+            target_link_libraries({library}_tests PRIVATE {library} GTest::gtest_main)
+
+            include(GoogleTest)
+            gtest_discover_tests({library}_tests WORKING_DIRECTORY ${{CMAKE_CURRENT_SOURCE_DIR}})
+            ",
+            library = library,
+        };
+
+        let cmake_lists_content = formatdoc! {"
+                cmake_minimum_required(VERSION 3.14)
+
+                project({name} VERSION {version} LANGUAGES CXX)
+
+                add_library({library} INTERFACE)
+                add_library({library}::{library} ALIAS {library})
+                target_include_directories({library} INTERFACE $<BUILD_INTERFACE:${{CMAKE_CURRENT_SOURCE_DIR}}/{include_dir}>)
+                target_compile_features({library} INTERFACE cxx_std_17)
+                {test_deps}",
+            name = self.project_name,
+            version = self.project_version,
+            library = library,
+            include_dir = INCLUDE_DIR,
+            test_deps = if with_test_dependencies {
+                test_dependencies
+            } else {
+                String::new()
+            },
+        };
+
+        let cmake_lists_path = self.path.join("CMakeLists.txt");
+        let mut cmake_lists_file = File::create(cmake_lists_path)?;
+        cmake_lists_file.write_all(cmake_lists_content.as_bytes())?;
+
+        Ok(())
+    }
+}
+
+impl CodeGenerator for CppGenerator {
+    fn generate_project(&self, with_test_dependencies: bool, _format_project: bool) -> Result<()> {
+        // Project files (CMakeLists.txt, ...)
+        self.write_project_files(with_test_dependencies)?;
+
+        // Generate namespaces. clang-format is not installed everywhere and its default style
+        // would reorder the includes, so the project is formatted only by the generator itself.
+        let root_namespace = self.root_namespace();
+
+        for schema in &self.schemas {
+            let module_generator = module::ModuleGenerator::new(
+                &self.root_path().join(&schema.package),
+                &root_namespace,
+                schema,
+                self.bounds_checks,
+            );
+
+            module_generator.generate_module()?;
+        }
+
+        Ok(())
+    }
+}
+
+impl PrimitiveConvertible<C> for NativeType {
+    fn lang_primitive(
+        &self,
+        encoded_types: &BTreeMap<String, EncodedDataType>,
+    ) -> Result<LanguagePrimitive<C>> {
+        let native_type = self.resolved(encoded_types)?;
+
+        Ok(LanguagePrimitive::new(codecs::cpp_type(&native_type)))
+    }
+}
+
+/// Writes a header with the `#pragma once` guard, the includes of the project headers followed
+/// by the ones of the standard library, and the content in the namespace.
+fn write_header(
+    path: &Path,
+    namespace: &str,
+    includes: &[String],
+    content: Tokens<C>,
+) -> Result<()> {
+    let (local_includes, system_includes): (Vec<_>, Vec<_>) = includes
+        .iter()
+        .partition(|include| include.starts_with('"'));
+
+    let header_content: Tokens<C> = quote! {
+        #pragma once
+        $(for group in [local_includes, system_includes].iter().filter(|group| !group.is_empty()) {
+            $['\n']
+            $(for include in group {
+                $['\r']
+                $(format!("#include {}", include))
+            })
+        })
+
+        namespace $namespace {
+
+        $content
+
+        }$(format!(" // namespace {}", namespace))
+    };
+
+    write_file(path, &c::Config::default(), header_content)
+}
@@ -0,0 +1,252 @@
+use crate::generator::cpp::constants::{
+    COMPOSITE_DIR_NAME, DISPATCHER_HEADER_NAME, ENUM_DIR_NAME, ERROR_HEADER_NAME, MESSAGE_DIR_NAME,
+    READ_BUF_HEADER_NAME, SCHEMA_HEADER_NAME, SET_DIR_NAME, WRITE_BUF_HEADER_NAME,
+};
+use crate::generator::cpp::write_header;
+use crate::generator::write_file;
+use crate::models::schema::ValidatedMessageSchema;
+use crate::models::types::Type;
+use crate::models::ByteOrder;
+use anyhow::{anyhow, Result};
+use convert_case::{Case, Casing};
+use genco::prelude::*;
+use std::collections::BTreeSet;
+use std::fs::create_dir_all;
+use std::path::{Path, PathBuf};
+
+pub struct ModuleGenerator<'a> {
+    pub(crate) path: PathBuf,
+    pub(crate) root_namespace: &'a str,
+    pub(crate) namespace: String,
+    pub(crate) schema: &'a ValidatedMessageSchema,
+    pub(crate) bounds_checks: bool,
+}
+
+impl<'a> ModuleGenerator<'a> {
+    pub fn new(
+        path: &Path,
+        root_namespace: &'a str,
+        schema: &'a ValidatedMessageSchema,
+        bounds_checks: bool,
+    ) -> Self {
+        Self {
+            path: path.to_owned(),
+            root_namespace,
+            namespace: format!("{}::{}", root_namespace, schema.package),
+            schema,
+            bounds_checks,
+        }
+    }
+
+    pub fn generate_module(&self) -> Result<()> {
+        create_dir_all(&self.path)?;
+
+        self.write_schema_header()?;
+
+        // Buffers the codecs read from and write to
+        self.write_read_buf_header()?;
+        self.write_write_buf_header()?;
+
+        // Type specific encoders and decoders, the ones of groups and var data
+        // are nested in the classes of their messages
+        self.write_enum_codecs()?;
+        self.write_set_codecs()?;
+        self.write_composite_codecs()?;
+        self.write_message_codecs()?;
+
+        self.write_package_header()
+    }
+
+    fn write_schema_header(&self) -> Result<()> {
+        let little_endian = match self.schema.byte_order {
+            ByteOrder::BigEndian => "false",
+            ByteOrder::LittleEndian => "true",
+        };
+
+        let schema_header_content: Tokens<C> = quote! {
+            constexpr std::uint16_t SCHEMA_ID = $(self.schema.id);
+            constexpr std::uint16_t SCHEMA_VERSION = $(self.schema.version);
+
+            namespace detail {
+
+            $("/// Reverses the bytes of a value, if the byte order of the schema differs from the one of the host.")
+            template <typename T>
+            T to_schema_order(T value) noexcept {
+                const std::uint16_t probe = 1;
+                std::uint8_t first_byte = 0;
+                std::memcpy(&first_byte, &probe, 1);
+
+                if ((first_byte == 1) != $little_endian) {
+                    std::uint8_t bytes[sizeof(T)];
+                    std::memcpy(bytes, &value, sizeof(T));
+                    std::reverse(bytes, bytes + sizeof(T));
+                    std::memcpy(&value, bytes, sizeof(T));
+                }
+
+                return value;
+            }
+
+            $("/// Calls the action with the codec, then the completion with the codec, and returns the")
+            $("/// result of the action.")
+            template <typename Codec, typename F, typename Then>
+            auto invoke_then(Codec &codec, F &&action, Then &&then) {
+                if constexpr (std::is_void_v<std::invoke_result_t<F, Codec &>>) {
+                    std::forward<F>(action)(codec);
+                    std::forward<Then>(then)(codec);
+                } else {
+                    auto result = std::forward<F>(action)(codec);
+                    std::forward<Then>(then)(codec);
+
+                    return result;
+                }
+            }
+
+            } // namespace detail
+        };
+
+        self.write_header(
+            SCHEMA_HEADER_NAME,
+            &[
+                self.error_include(),
+                "<algorithm>".to_owned(),
+                "<cstdint>".to_owned(),
+                "<cstring>".to_owned(),
+                "<type_traits>".to_owned(),
+                "<utility>".to_owned(),
+            ],
+            schema_header_content,
+        )
+    }
+
+    /// Header including all codecs of the schema.
+    fn write_package_header(&self) -> Result<()> {
+        let types = &self.schema.types;
+        let mut includes = vec![
+            self.include(SCHEMA_HEADER_NAME),
+            self.include(READ_BUF_HEADER_NAME),
+            self.include(WRITE_BUF_HEADER_NAME),
+        ];
+
+        includes.extend(types.enum_types.values().map(|enum_type| {
+            self.include(&format!(
+                "{}/{}.hpp",
+                ENUM_DIR_NAME,
+                enum_type.name.to_case(Case::Snake)
+            ))
+        }));
+        includes.extend(types.set_types.values().map(|set_type| {
+            self.include(&format!(
+                "{}/{}.hpp",
+                SET_DIR_NAME,
+                set_type.name.to_case(Case::Snake)
+            ))
+        }));
+        includes.extend(types.composite_types.values().map(|composite_type| {
+            self.include(&format!(
+                "{}/{}.hpp",
+                COMPOSITE_DIR_NAME,
+                composite_type.name.to_case(Case::Snake)
+            ))
+        }));
+        includes.extend(
+            self.schema
+                .message_types
+                .message_types
+                .values()
+                .map(|message_type| {
+                    self.include(&format!(
+                        "{}/{}.hpp",
+                        MESSAGE_DIR_NAME,
+                        message_type.name.to_case(Case::Snake)
+                    ))
+                }),
+        );
+        if !self.schema.message_types.message_types.is_empty() {
+            includes
+                .push(self.include(&format!("{}/{}", MESSAGE_DIR_NAME, DISPATCHER_HEADER_NAME)));
+        }
+
+        let package_header_content: Tokens<C> = quote! {
+            #pragma once
+            $['\n']
+            $(for include in includes {
+                $['\r']
+                $(format!("#include {}", include))
+            })
+        };
+
+        write_file(
+            &self
+                .path
+                .with_file_name(format!("{}.hpp", self.schema.package)),
+            &c::Config::default(),
+            package_header_content,
+        )
+    }
+
+    /// Writes a header of the schema at the path relative to the directory of the schema.
+    pub(crate) fn write_header(
+        &self,
+        relative_path: &str,
+        includes: &[String],
+        content: Tokens<C>,
+    ) -> Result<()> {
+        let path = self.path.join(relative_path);
+
+        if let Some(parent) = path.parent() {
+            create_dir_all(parent)?;
+        }
+
+        write_header(&path, &self.namespace, includes, content)
+    }
+
+    /// Include of a header of the schema, by its path relative to the directory of the schema.
+    pub(crate) fn include(&self, relative_path: &str) -> String {
+        format!(
+            "\"{}/{}/{}\"",
+            self.root_namespace, self.schema.package, relative_path
+        )
+    }
+
+    /// Include of the error raised by the codecs, shared by all schemas of the project.
+    pub(crate) fn error_include(&self) -> String {
+        format!("\"{}/{}\"", self.root_namespace, ERROR_HEADER_NAME)
+    }
+
+    /// Includes of the headers declaring the enums, sets and composites used by the types.
+    pub(crate) fn type_includes<'t>(
+        &self,
+        types: impl IntoIterator<Item = &'t Type>,
+    ) -> Result<BTreeSet<String>> {
+        let mut includes = BTreeSet::new();
+
+        for field_type in types {
+            let field_type = match field_type {
+                Type::Reference(reference_type) => self
+                    .schema
+                    .types
+                    .find_type(&reference_type.type_name)
+                    .ok_or(anyhow!(
+                        "Referenced type {} not found",
+                        reference_type.type_name
+                    ))?,
+                field_type => field_type.clone(),
+            };
+
+            let (dir_name, type_name) = match &field_type {
+                Type::EncodedData(_) | Type::Reference(_) => continue,
+                Type::Enum(enum_type) => (ENUM_DIR_NAME, &enum_type.name),
+                Type::Set(set_type) => (SET_DIR_NAME, &set_type.name),
+                Type::Composite(composite_type) => (COMPOSITE_DIR_NAME, &composite_type.name),
+            };
+
+            includes.insert(self.include(&format!(
+                "{}/{}.hpp",
+                dir_name,
+                type_name.to_case(Case::Snake)
+            )));
+        }
+
+        Ok(includes)
+    }
+}
@@ -0,0 +1,684 @@
+use crate::generator::common::FieldMetadata;
+use crate::generator::csharp::codecs::{
+    accessor, bounds_checks, field_metadata, literal, null_value, null_value_condition,
+    version_check,
+};
+use crate::generator::csharp::constants::{READ_BUF_CLASS_NAME, WRITE_BUF_CLASS_NAME};
+use crate::generator::csharp::module::ModuleGenerator;
+use crate::models::layout::BlockLayout;
+use crate::models::types::composite_type::CompositeType;
+use crate::models::types::encoded_data_type::EncodedDataType;
+use crate::models::types::enum_type::EnumType;
+use crate::models::types::primitive_type::{
+    LanguagePrimitive, NativeType, PrimitiveConvertible, ResolvableType,
+};
+use crate::models::types::set_type::SetType;
+use crate::models::types::{CharacterEncoding, Presence, SizedEncoded, Type};
+use anyhow::{anyhow, Result};
+use convert_case::{Case, Casing};
+use genco::prelude::*;
+
+impl ModuleGenerator<'_> {
+    fn write_composite_codec(&self, composite_type: &CompositeType) -> Result<()> {
+        let name = composite_type.name.to_case(Case::UpperCamel);
+        let decoder_name = format!("{}Decoder", name);
+        let encoder_name = format!("{}Encoder", name);
+
+        let layout = BlockLayout::from_composite(composite_type, &self.schema.types)?;
+        let encoded_length = composite_type.size(&self.schema.types)?;
+
+        let mut decoder_fields: Tokens<Csharp> = quote!();
+        let mut encoder_fields: Tokens<Csharp> = quote!();
+
+        for (field, offset) in composite_type.fields.iter().zip(layout.offsets) {
+            decoder_fields.line();
+            decoder_fields.append(self.generate_decoder_field(
+                field.name(),
+                field,
+                quote!($offset),
+                0,
+            )?);
+            encoder_fields.line();
+            encoder_fields.append(self.generate_encoder_field(
+                field.name(),
+                field,
+                quote!($offset),
+            )?);
+        }
+
+        let decoder_tokens: Tokens<Csharp> = quote! {
+            public readonly ref struct $(&decoder_name)
+            {
+                public const int EncodedLength = $encoded_length;
+
+                private readonly $READ_BUF_CLASS_NAME _buffer;
+
+                public $(&decoder_name)($READ_BUF_CLASS_NAME buffer)
+                {
+                    _buffer = buffer;
+                }
+                $decoder_fields
+            }
+        };
+
+        let encoder_tokens: Tokens<Csharp> = quote! {
+            public readonly ref struct $(&encoder_name)
+            {
+                public const int EncodedLength = $encoded_length;
+
+                private readonly $WRITE_BUF_CLASS_NAME _buffer;
+
+                public $(&encoder_name)($WRITE_BUF_CLASS_NAME buffer)
+                {
+                    _buffer = buffer;
+                }
+                $encoder_fields
+            }
+        };
+
+        self.write_class(&decoder_name, decoder_tokens)?;
+        self.write_class(&encoder_name, encoder_tokens)
+    }
+
+    pub fn write_composite_codecs(&self) -> Result<()> {
+        for composite_type in self.schema.types.composite_types.values() {
+            self.write_composite_codec(composite_type)?;
+        }
+
+        Ok(())
+    }
+
+    /// Generates the accessor for a field at the given offset.
+    ///
+    /// A non-zero `since_version` means the field was added in that version of the schema.
+    /// The accessor then checks the `_actingVersion` of the decoder (only message and group
+    /// decoders have one) and returns the null value of the field, if the field is not present
+    /// in the decoded data.
+    pub(crate) fn generate_decoder_field(
+        &self,
+        field_name: &str,
+        field: &Type,
+        offset: Tokens<Csharp>,
+        since_version: usize,
+    ) -> Result<Tokens<Csharp>> {
+        Ok(match field {
+            Type::EncodedData(encoded_type) => self.generate_encoded_field_decoder(
+                field_name,
+                encoded_type,
+                offset,
+                since_version,
+            )?,
+            Type::Enum(enum_type) => {
+                self.generate_enum_field_decoder(field_name, enum_type, offset, since_version)?
+            }
+            Type::Set(set_type) => {
+                self.generate_set_field_decoder(field_name, set_type, offset, since_version)?
+            }
+            Type::Composite(composite_type) => self.generate_composite_field_decoder(
+                field_name,
+                composite_type,
+                offset,
+                since_version,
+            ),
+            Type::Reference(reference_type) => {
+                let referenced_type = self
+                    .schema
+                    .types
+                    .find_type(&reference_type.type_name)
+                    .ok_or(anyhow!(
+                        "Referenced type {} not found",
+                        reference_type.type_name
+                    ))?;
+                self.generate_decoder_field(field_name, &referenced_type, offset, since_version)?
+            }
+        })
+    }
+
+    fn generate_enum_field_decoder(
+        &self,
+        field_name: &str,
+        enum_type: &EnumType,
+        offset: Tokens<Csharp>,
+        since_version: usize,
+    ) -> Result<Tokens<Csharp>> {
+        let enum_type_name = enum_type.name.to_case(Case::UpperCamel);
+        let field_name = field_name.to_case(Case::UpperCamel);
+        let encoding_type = enum_type
+            .encoding_type
+            .resolved(&self.schema.types.encoded_types)?;
+
+        Ok(quote! {
+            public $(&enum_type_name) $(field_name)()
+            {
+                $(version_check(since_version, format!("{}.NullVal", self.qualified(&enum_type_name))))
+                return $(self.qualified(&format!("{}Codec", enum_type_name))).FromValue(_buffer.Get$(accessor(&encoding_type))At($offset));
+            }
+        })
+    }
+
+    fn generate_set_field_decoder(
+        &self,
+        field_name: &str,
+        set_type: &SetType,
+        offset: Tokens<Csharp>,
+        since_version: usize,
+    ) -> Result<Tokens<Csharp>> {
+        let set_type_name = set_type.name.to_case(Case::UpperCamel);
+        let field_name = field_name.to_case(Case::UpperCamel);
+        let encoding_type = set_type
+            .encoding_type
+            .resolved(&self.schema.types.encoded_types)?;
+
+        Ok(quote! {
+            public $(&set_type_name) $(field_name)()
+            {
+                $(version_check(since_version, quote!(default)))
+                return ($(self.qualified(&set_type_name)))_buffer.Get$(accessor(&encoding_type))At($offset);
+            }
+        })
+    }
+
+    fn generate_composite_field_decoder(
+        &self,
+        field_name: &str,
+        composite_type: &CompositeType,
+        offset: Tokens<Csharp>,
+        since_version: usize,
+    ) -> Tokens<Csharp> {
+        let decoder_name = format!("{}Decoder", composite_type.name.to_case(Case::UpperCamel));
+        let field_name = field_name.to_case(Case::UpperCamel);
+
+        // Composites have no null value, so the decoder of the ones which might not be
+        // present in the decoded data has no buffer at all.
+        quote! {
+            public $(&decoder_name) $(field_name)()
+            {
+                $(version_check(since_version, quote!(default)))
+                return new $(&decoder_name)(_buffer.SplitAt($offset));
+            }
+        }
+    }
+
+    fn generate_encoded_field_decoder(
+        &self,
+        field_name: &str,
+        encoded_type: &EncodedDataType,
+        offset: Tokens<Csharp>,
+        since_version: usize,
+    ) -> Result<Tokens<Csharp>> {
+        Ok(match encoded_type.presence {
+            Presence::Constant => self.generate_encoded_constant_field(field_name, encoded_type)?,
+            Presence::Required => self.generate_encoded_required_field_decoder(
+                field_name,
+                encoded_type,
+                offset,
+                since_version,
+            )?,
+            Presence::Optional => self.generate_encoded_optional_field_decoder(
+                field_name,
+                encoded_type,
+                offset,
+                since_version,
+            )?,
+        })
+    }
+
+    fn generate_encoded_constant_field(
+        &self,
+        field_name: &str,
+        encoded_type: &EncodedDataType,
+    ) -> Result<Tokens<Csharp>> {
+        let metadata = field_metadata(field_name, encoded_type, &self.schema.types)?;
+
+        let value = encoded_type.default_value.as_ref().ok_or(anyhow!(
+            "Constant field {} has no default value",
+            metadata.field_name
+        ))?;
+
+        Ok(match metadata.field_primitive_type {
+            NativeType::Char => quote! {
+                public string $(&metadata.field_name)()
+                {
+                    return $(quoted(value));
+                }
+            },
+            _ => quote! {
+                public $(&metadata.lang_type) $(&metadata.field_name)()
+                {
+                    return $(literal(value, &metadata.field_primitive_type));
+                }
+            },
+        })
+    }
+
+    fn generate_encoded_required_field_decoder(
+        &self,
+        field_name: &str,
+        encoded_type: &EncodedDataType,
+        offset: Tokens<Csharp>,
+        since_version: usize,
+    ) -> Result<Tokens<Csharp>> {
+        let metadata = field_metadata(field_name, encoded_type, &self.schema.types)?;
+        let getter = format!("Get{}At", accessor(&metadata.field_primitive_type));
+        let null_value = null_value(encoded_type, &metadata);
+
+        Ok(
+            match (&metadata.field_primitive_type, metadata.field_length) {
+                (NativeType::Char, 2..) => quote! {
+                    public string $(&metadata.field_name)()
+                    {
+                        $(version_check(since_version, quote!("")))
+                        return _buffer.GetStringAt($offset, $(metadata.field_length));
+                    }
+                },
+                (_, 2..) => quote! {
+                    public $(&metadata.lang_type)[] $(&metadata.field_name)()
+                    {
+                        $(version_check(since_version, null_array(&metadata, &null_value)))
+                        int offset = $offset;
+                        $(&metadata.lang_type)[] value = new $(&metadata.lang_type)[$(metadata.field_length)];
+
+                        for (int idx = 0; idx < value.Length; idx++)
+                        {
+                            value[idx] = _buffer.$(&getter)(offset + idx * $(metadata.type_size));
+                        }
+
+                        return value;
+                    }
+                },
+                (_, _) => {
+                    let bounds_checks = bounds_checks(
+                        &metadata.field_name,
+                        "value",
+                        encoded_type,
+                        &metadata.field_primitive_type,
+                        &self.error_class(),
+                    );
+
+                    if bounds_checks.is_empty() {
+                        quote! {
+                            public $(&metadata.lang_type) $(&metadata.field_name)()
+                            {
+                                $(version_check(since_version, &null_value))
+                                return _buffer.$(&getter)($offset);
+                            }
+                        }
+                    } else {
+                        quote! {
+                            public $(&metadata.lang_type) $(&metadata.field_name)()
+                            {
+                                $(version_check(since_version, &null_value))
+                                $(&metadata.lang_type) value = _buffer.$(&getter)($offset);
+
+                                $bounds_checks
+                                return value;
+                            }
+                        }
+                    }
+                }
+            },
+        )
+    }
+
+    fn generate_encoded_optional_field_decoder(
+        &self,
+        field_name: &str,
+        encoded_type: &EncodedDataType,
+        offset: Tokens<Csharp>,
+        since_version: usize,
+    ) -> Result<Tokens<Csharp>> {
+        let metadata = field_metadata(field_name, encoded_type, &self.schema.types)?;
+        let getter = format!("Get{}At", accessor(&metadata.field_primitive_type));
+
+        Ok(
+            match (&metadata.field_primitive_type, metadata.field_length) {
+                (NativeType::Char, 2..) => quote! {
+                    public string? $(&metadata.field_name)()
+                    {
+                        $(version_check(since_version, quote!(null)))
+                        bool isNull = true;
+
+                        foreach (byte part in _buffer.GetSliceAt($(&offset), $(metadata.field_length)))
+                        {
+                            isNull &= $(null_value_condition("part", encoded_type, &metadata));
+                        }
+
+                        if (isNull)
+                        {
+                            return null;
+                        }
+
+                        return _buffer.GetStringAt($offset, $(metadata.field_length));
+                    }
+                },
+                (_, 2..) => quote! {
+                    public $(&metadata.lang_type)[]? $(&metadata.field_name)()
+                    {
+                        $(version_check(since_version, quote!(null)))
+                        int offset = $offset;
+                        $(&metadata.lang_type)[] value = new $(&metadata.lang_type)[$(metadata.field_length)];
+                        bool isNull = true;
+
+                        for (int idx = 0; idx < value.Length; idx++)
+                        {
+                            $(&metadata.lang_type) part = _buffer.$(&getter)(offset + idx * $(metadata.type_size));
+                            value[idx] = part;
+                            isNull &= $(null_value_condition("part", encoded_type, &metadata));
+                        }
+
+                        return isNull ? null : value;
+                    }
+                },
+                (_, _) => quote! {
+                    public $(&metadata.lang_type)? $(&metadata.field_name)()
+                    {
+                        $(version_check(since_version, quote!(null)))
+                        $(&metadata.lang_type) value = _buffer.$(&getter)($offset);
+
+                        if ($(null_value_condition("value", encoded_type, &metadata)))
+                        {
+                            return null;
+                        }
+
+                        $(bounds_checks(&metadata.field_name, "value", encoded_type, &metadata.field_primitive_type, &self.error_class()))
+                        return value;
+                    }
+                },
+            },
+        )
+    }
+
+    pub(crate) fn generate_encoder_field(
+        &self,
+        field_name: &str,
+        field: &Type,
+        offset: Tokens<Csharp>,
+    ) -> Result<Tokens<Csharp>> {
+        Ok(match field {
+            Type::EncodedData(encoded_type) => match encoded_type.presence {
+                Presence::Constant => quote!(),
+                Presence::Required => {
+                    self.generate_encoded_required_field_encoder(field_name, encoded_type, offset)?
+                }
+                Presence::Optional => {
+                    self.generate_encoded_optional_field_encoder(field_name, encoded_type, offset)?
+                }
+            },
+            Type::Enum(enum_type) => {
+                let encoding_type = enum_type
+                    .encoding_type
+                    .resolved(&self.schema.types.encoded_types)?;
+                let csharp_type: LanguagePrimitive<Csharp> =
+                    encoding_type.lang_primitive(&self.schema.types.encoded_types)?;
+
+                quote! {
+                    public void $(field_name.to_case(Case::UpperCamel))($(enum_type.name.to_case(Case::UpperCamel)) value)
+                    {
+                        _buffer.Put$(accessor(&encoding_type))At($offset, ($(&csharp_type))value);
+                    }
+                }
+            }
+            Type::Set(set_type) => {
+                let encoding_type = set_type
+                    .encoding_type
+                    .resolved(&self.schema.types.encoded_types)?;
+                let csharp_type: LanguagePrimitive<Csharp> =
+                    encoding_type.lang_primitive(&self.schema.types.encoded_types)?;
+
+                quote! {
+                    public void $(field_name.to_case(Case::UpperCamel))($(set_type.name.to_case(Case::UpperCamel)) value)
+                    {
+                        _buffer.Put$(accessor(&encoding_type))At($offset, ($(&csharp_type))value);
+                    }
+                }
+            }
+            Type::Composite(composite_type) => {
+                let encoder_name =
+                    format!("{}Encoder", composite_type.name.to_case(Case::UpperCamel));
+
+                quote! {
+                    public $(&encoder_name) $(field_name.to_case(Case::UpperCamel))()
+                    {
+                        return new $(&encoder_name)(_buffer.SplitAt($offset));
+                    }
+                }
+            }
+            Type::Reference(reference_type) => {
+                let referenced_type = self
+                    .schema
+                    .types
+                    .find_type(&reference_type.type_name)
+                    .ok_or(anyhow!(
+                        "Referenced type {} not found",
+                        reference_type.type_name
+                    ))?;
+                self.generate_encoder_field(field_name, &referenced_type, offset)?
+            }
+        })
+    }
+
+    fn generate_encoded_required_field_encoder(
+        &self,
+        field_name: &str,
+        encoded_type: &EncodedDataType,
+        offset: Tokens<Csharp>,
+    ) -> Result<Tokens<Csharp>> {
+        let metadata = field_metadata(field_name, encoded_type, &self.schema.types)?;
+        let setter = format!("Put{}At", accessor(&metadata.field_primitive_type));
+        let read_only_span = &csharp::import("System", "ReadOnlySpan");
+
+        Ok(
+            match (&metadata.field_primitive_type, metadata.field_length) {
+                (NativeType::Char, 2..) => quote! {
+                    public void $(&metadata.field_name)(string value)
+                    {
+                        int offset = $offset;
+
+                        $(self.string_encoder(&metadata))
+                    }
+                },
+                (_, 2..) => quote! {
+                    public void $(&metadata.field_name)($read_only_span<$(&metadata.lang_type)> value)
+                    {
+                        int offset = $offset;
+
+                        $(self.array_length_check(&metadata))
+                        for (int idx = 0; idx < value.Length; idx++)
+                        {
+                            $(self.bounds_checks(&metadata, "value[idx]", encoded_type))
+                            _buffer.$(&setter)(offset + idx * $(metadata.type_size), value[idx]);
+                        }
+                    }
+                },
+                (_, _) => {
+                    let bounds_checks = self.bounds_checks(&metadata, "value", encoded_type);
+
+                    if bounds_checks.is_empty() {
+                        quote! {
+                            public void $(&metadata.field_name)($(&metadata.lang_type) value)
+                            {
+                                _buffer.$(&setter)($offset, value);
+                            }
+                        }
+                    } else {
+                        quote! {
+                            public void $(&metadata.field_name)($(&metadata.lang_type) value)
+                            {
+                                int offset = $offset;
+
+                                $bounds_checks
+                                _buffer.$(&setter)(offset, value);
+                            }
+                        }
+                    }
+                }
+            },
+        )
+    }
+
+    fn generate_encoded_optional_field_encoder(
+        &self,
+        field_name: &str,
+        encoded_type: &EncodedDataType,
+        offset: Tokens<Csharp>,
+    ) -> Result<Tokens<Csharp>> {
+        let metadata = field_metadata(field_name, encoded_type, &self.schema.types)?;
+        let setter = format!("Put{}At", accessor(&metadata.field_primitive_type));
+        let null_value = null_value(encoded_type, &metadata);
+        let span = &csharp::import("System", "Span");
+
+        Ok(
+            match (&metadata.field_primitive_type, metadata.field_length) {
+                (NativeType::Char, 2..) => quote! {
+                    public void $(&metadata.field_name)(string? value)
+                    {
+                        int offset = $offset;
+
+                        if (value == null)
+                        {
+                            $span<byte> nulls = stackalloc byte[$(metadata.field_length)];
+                            nulls.Fill($(&null_value));
+                            _buffer.PutBytesAt(offset, nulls);
+                            return;
+                        }
+
+                        $(self.string_encoder(&metadata))
+                    }
+                },
+                (_, 2..) => {
+                    let bounds_checks = self.bounds_checks(&metadata, "part", encoded_type);
+
+                    quote! {
+                        public void $(&metadata.field_name)($(&metadata.lang_type)[]? value)
+                        {
+                            int offset = $offset;
+
+                            $(if bounds_checks.is_empty() {
+                                if (value == null)
+                                {
+                                    value = $(null_array(&metadata, &null_value));
+                                }
+                                else if (value.Length != $(metadata.field_length))
+                                {
+                                    $(self.wrong_slice_size(&metadata))
+                                }
+                            } else {
+                                if (value == null)
+                                {
+                                    value = $(null_array(&metadata, &null_value));
+                                }
+                                else
+                                {
+                                    $(self.array_length_check(&metadata))
+                                    foreach ($(&metadata.lang_type) part in value)
+                                    {
+                                        $bounds_checks
+                                    }
+                                }
+                            })
+
+                            for (int idx = 0; idx < value.Length; idx++)
+                            {
+                                _buffer.$(&setter)(offset + idx * $(metadata.type_size), value[idx]);
+                            }
+                        }
+                    }
+                }
+                (_, _) => {
+                    let bounds_checks = self.bounds_checks(&metadata, "value", encoded_type);
+
+                    if bounds_checks.is_empty() {
+                        quote! {
+                            public void $(&metadata.field_name)($(&metadata.lang_type)? value)
+                            {
+                                _buffer.$(&setter)($offset, value ?? $(&null_value));
+                            }
+                        }
+                    } else {
+                        quote! {
+                            public void $(&metadata.field_name)($(&metadata.lang_type)? value)
+                            {
+                                int offset = $offset;
+
+                                if (value != null)
+                                {
+                                    $bounds_checks
+                                }
+
+                                _buffer.$(&setter)(offset, value ?? $(&null_value));
+                            }
+                        }
+                    }
+                }
+            },
+        )
+    }
+
+    /// Checks of the `minValue` and `maxValue` of the field, unless they are disabled.
+    fn bounds_checks(
+        &self,
+        metadata: &FieldMetadata<Csharp>,
+        variable: &str,
+        encoded_type: &EncodedDataType,
+    ) -> Tokens<Csharp> {
+        if self.bounds_checks {
+            bounds_checks(
+                &metadata.field_name,
+                variable,
+                encoded_type,
+                &metadata.field_primitive_type,
+                &self.error_class(),
+            )
+        } else {
+            quote!()
+        }
+    }
+
+    fn array_length_check(&self, metadata: &FieldMetadata<Csharp>) -> Tokens<Csharp> {
+        quote! {
+            if (value.Length != $(metadata.field_length))
+            {
+                $(self.wrong_slice_size(metadata))
+            }
+            $['\n']
+        }
+    }
+
+    fn wrong_slice_size(&self, metadata: &FieldMetadata<Csharp>) -> Tokens<Csharp> {
+        quote! {
+            throw $(self.error_class()).WrongSliceSize($(quoted(format!("expected {} elements, got ", metadata.field_length))) + value.Length);
+        }
+    }
+
+    fn string_encoder(&self, metadata: &FieldMetadata<Csharp>) -> Tokens<Csharp> {
+        let ascii = &csharp::import("System.Text", "Ascii");
+        let encoding = &csharp::import("System.Text", "Encoding");
+        let error = &self.error_class();
+        let max_length = metadata.field_length;
+
+        quote! {
+            $(if let Some(CharacterEncoding::Ascii) = metadata.encoding {
+                if (!$ascii.IsValid(value))
+                {
+                    throw $error.InvalidStringValue(value);
+                }
+                $['\n']
+            })
+            byte[] encoded = $encoding.UTF8.GetBytes(value);
+
+            if (encoded.Length > $max_length)
+            {
+                throw $error.ValueOutOfBounds($(quoted(&metadata.field_name)), "string '" + value + "' length " + encoded.Length + $(quoted(format!(" > {} (max)", max_length))));
+            }
+
+            _buffer.PutBytesAt(offset, encoded);
+        }
+    }
+}
+
+/// Array of the field length filled with the null value.
+fn null_array(metadata: &FieldMetadata<Csharp>, null_value: &str) -> Tokens<Csharp> {
+    let values = vec![null_value; metadata.field_length].join(", ");
+
+    quote!(new $(&metadata.lang_type)[] { $values })
+}
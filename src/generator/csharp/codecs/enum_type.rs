@@ -0,0 +1,89 @@
+use anyhow::Result;
+use convert_case::{Case, Casing};
+
+use crate::generator::csharp::module::ModuleGenerator;
+use crate::models::types::enum_type::EnumType;
+use crate::models::types::primitive_type::{NativeType, PrimitiveConvertible, ResolvableType};
+use genco::prelude::*;
+
+impl ModuleGenerator<'_> {
+    fn write_enum_codec(&self, enum_type: &EnumType) -> Result<()> {
+        let name = enum_type.name.to_case(Case::UpperCamel);
+        let codec_name = format!("{}Codec", name);
+        let encoding_type = enum_type
+            .encoding_type
+            .resolved(&self.schema.types.encoded_types)?;
+        let csharp_type = encoding_type.lang_primitive(&self.schema.types.encoded_types)?;
+        let char_encoding = encoding_type == NativeType::Char;
+        let error = &self.error_class();
+
+        let values = enum_type
+            .values
+            .iter()
+            .map(|value| {
+                Ok((
+                    value.name.to_case(Case::UpperCamel),
+                    value.encoded_value(char_encoding)?.to_string(),
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let null_value = null_number(&encoding_type).to_owned();
+
+        // Patterns of a switch must be unique, so the null value is only matched once
+        let mut known_values = values
+            .iter()
+            .map(|(_, value)| value.as_str())
+            .collect::<Vec<_>>();
+        if !known_values.contains(&null_value.as_str()) {
+            known_values.push(&null_value);
+        }
+
+        let enum_tokens: Tokens<Csharp> = quote! {
+            public enum $(&name) : $(&csharp_type)
+            {
+                $(for (value_name, value) in &values {
+                    $['\r']
+                    $value_name = $value,
+                })
+                NullVal = $(&null_value),
+            }
+
+            public static class $(&codec_name)
+            {
+                public static $(&name) FromValue($(&csharp_type) value)
+                {
+                    return value switch
+                    {
+                        $(known_values.join(" or ")) => ($(&name))value,
+                        _ => throw $error.InvalidEnumValue($(quoted(&name)), value),
+                    };
+                }
+            }
+        };
+
+        self.write_class(&name, enum_tokens)
+    }
+
+    pub fn write_enum_codecs(&self) -> Result<()> {
+        for enum_type in self.schema.types.enum_types.values() {
+            self.write_enum_codec(enum_type)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Default null value of an integer primitive type as a number, see `NativeType::null`.
+fn null_number(native_type: &NativeType) -> &'static str {
+    match native_type {
+        NativeType::Char => "0",
+        NativeType::UInt8 => "255",
+        NativeType::UInt16 => "65535",
+        NativeType::UInt32 => "4294967295",
+        NativeType::UInt64 => "18446744073709551615",
+        NativeType::Int8 => "-128",
+        NativeType::Int16 => "-32768",
+        NativeType::Int32 => "-2147483648",
+        _ => "-9223372036854775808",
+    }
+}
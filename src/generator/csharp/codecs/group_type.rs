@@ -0,0 +1,465 @@
+use crate::generator::common::{dimension_type, field_groups};
+use crate::generator::csharp::codecs::{field_metadata, max_literal};
+use crate::generator::csharp::constants::{
+    READ_BUF_CLASS_NAME, SCHEMA_CLASS_NAME, WRITE_BUF_CLASS_NAME,
+};
+use crate::generator::csharp::module::ModuleGenerator;
+use crate::models::layout::BlockLayout;
+use crate::models::types::field_type::FieldType;
+use crate::models::types::group_type::GroupType;
+use crate::models::types::variable_data_type::VariableDataType;
+use crate::models::types::{Presence, SizedEncoded, Type};
+use anyhow::{anyhow, Result};
+use convert_case::{Case, Casing};
+use genco::prelude::*;
+
+impl ModuleGenerator<'_> {
+    /// Generates the decoder of a group as a struct nested in the decoder of its message or
+    /// enclosing group, which enumerates the entries of the group.
+    pub(crate) fn generate_group_decoder(&self, group: &GroupType) -> Result<Tokens<Csharp>> {
+        let name = group.name.as_str();
+        let decoder_name = format!("{}Decoder", name.to_case(Case::UpperCamel));
+        let (fields, groups, var_data) = field_groups(&group.fields);
+
+        let layout =
+            BlockLayout::from_fields(name, &fields, group.block_length, &self.schema.types)?;
+
+        let dimension_type = dimension_type(group, &self.schema.types.composite_types)?;
+        let dimension_type_size = dimension_type.size(&self.schema.types)?;
+        let dimension_type_decoder_name =
+            format!("{}Decoder", dimension_type.name.to_case(Case::UpperCamel));
+        let start = quote!(_offset + _blockLength);
+
+        Ok(quote! {
+            public ref struct $(&decoder_name)
+            {
+                private $READ_BUF_CLASS_NAME _buffer;
+                private int _actingVersion;
+                private int _blockLength;
+                private int _numInGroup;
+                private int _index;
+                private int _offset;
+
+                internal $(&decoder_name)($READ_BUF_CLASS_NAME buffer, int actingVersion)
+                {
+                    $(&dimension_type_decoder_name) dimension = new $(&dimension_type_decoder_name)(buffer);
+
+                    _buffer = buffer;
+                    _actingVersion = actingVersion;
+                    _blockLength = (int)dimension.BlockLength();
+                    _numInGroup = (int)dimension.NumInGroup();
+                    _index = -1;
+                    _offset = 0;
+                }
+
+                $(csharp::block_comment(["<summary>Creates a decoder of a group, which is not present in the decoded data,", "because it was added in a newer version of the schema.</summary>"]))
+                public static $(&decoder_name) Empty(int actingVersion)
+                {
+                    return new $(&decoder_name) { _actingVersion = actingVersion, _index = -1 };
+                }
+
+                public int ActingVersion()
+                {
+                    return _actingVersion;
+                }
+
+                public int BlockLength()
+                {
+                    return _blockLength;
+                }
+
+                public int NumInGroup()
+                {
+                    return _numInGroup;
+                }
+
+                public $(&decoder_name) GetEnumerator()
+                {
+                    return this;
+                }
+
+                public $(&decoder_name) Current => this;
+
+                $(csharp::block_comment(["<summary>Moves to the next entry of the group, skipping the groups and var data", "of the current one.</summary>"]))
+                public bool MoveNext()
+                {
+                    if (_index + 1 >= _numInGroup)
+                    {
+                        return false;
+                    }
+
+                    _offset = _index < 0 ? $dimension_type_size : EntryEnd();
+                    _index++;
+
+                    return true;
+                }
+
+                public void Reset()
+                {
+                    _index = -1;
+                    _offset = 0;
+                }
+
+                $(csharp::block_comment(["<summary>Size of the decoded group, including its dimension and all its entries,", "or 0 for a group which is not present in the decoded data.</summary>"]))
+                public int Size()
+                {
+                    if (_buffer.Length() == 0)
+                    {
+                        return 0;
+                    }
+
+                    $(&decoder_name) entries = this;
+                    entries.Reset();
+
+                    while (entries.MoveNext())
+                    {
+                    }
+
+                    return entries._numInGroup > 0 ? entries.EntryEnd() : $dimension_type_size;
+                }
+
+                private int EntryEnd()
+                {
+                    int offset = $(&start);
+                    $(self.skip_calls(&groups, &var_data, None))
+                    return offset;
+                }
+                $(self.generate_skip_helpers(&groups, &var_data)?)
+                $(self.generate_field_decoders(&fields, Some(&quote!(_offset)), layout.offsets)?)
+                $(self.generate_group_decoders(&groups, &start)?)
+                $(self.generate_var_data_decoders(&var_data, &groups, &start)?)
+                $(for group in &groups {
+                    $['\n']
+                    $(self.generate_group_decoder(group)?)
+                })
+            }
+        })
+    }
+
+    /// Generates the encoder of a group as a struct nested in the encoder of its message or
+    /// enclosing group. The `decoder_path` is the decoder of the same group, whose helpers
+    /// find where the encoded groups and var data of an entry end.
+    pub(crate) fn generate_group_encoder(
+        &self,
+        group: &GroupType,
+        decoder_path: &str,
+    ) -> Result<Tokens<Csharp>> {
+        let name = group.name.as_str();
+        let encoder_name = format!("{}Encoder", name.to_case(Case::UpperCamel));
+        let (fields, groups, var_data) = field_groups(&group.fields);
+        let error = &self.error_class();
+        let types = &self.schema.types;
+
+        let dimension_type = dimension_type(group, &types.composite_types)?;
+        let dimension_type_size = dimension_type.size(types)?;
+        let dimension_type_encoder_name =
+            format!("{}Encoder", dimension_type.name.to_case(Case::UpperCamel));
+
+        let block_length_type = match &dimension_type.fields.first() {
+            Some(Type::EncodedData(block_length_type)) => block_length_type,
+            _ => {
+                return Err(anyhow!(
+                    "Only encoded data type expected for the block length type in group '{}'",
+                    group.name
+                ))
+            }
+        };
+        let num_in_group_type = match &dimension_type.fields.last() {
+            Some(Type::EncodedData(num_in_group_type)) => num_in_group_type,
+            _ => {
+                return Err(anyhow!(
+                    "Only encoded data type expected for the num in group type in group '{}'",
+                    group.name
+                ))
+            }
+        };
+
+        let block_length_metadata = field_metadata("", block_length_type, types)?;
+        let num_in_group_metadata = field_metadata("", num_in_group_type, types)?;
+        let num_in_group_primitive = &num_in_group_metadata.lang_type;
+
+        let layout = BlockLayout::from_fields(name, &fields, group.block_length, types)?;
+        let start = quote!(_offset + BlockLength);
+
+        Ok(quote! {
+            public ref struct $(&encoder_name)
+            {
+                public const int BlockLength = $(layout.block_length);
+
+                private readonly $WRITE_BUF_CLASS_NAME _buffer;
+                private int _numInGroup;
+                private int _offset;
+
+                internal $(&encoder_name)($WRITE_BUF_CLASS_NAME buffer)
+                {
+                    $(&dimension_type_encoder_name) dimension = new $(&dimension_type_encoder_name)(buffer);
+                    dimension.BlockLength(($(&block_length_metadata.lang_type))BlockLength);
+                    dimension.NumInGroup(($num_in_group_primitive)0);
+
+                    _buffer = buffer;
+                    _numInGroup = 0;
+                    _offset = $dimension_type_size;
+                }
+
+                $(csharp::block_comment(["<summary>Size of the encoded group, including its dimension and all the entries", "it was advanced past.</summary>"]))
+                public int Size()
+                {
+                    return _offset;
+                }
+
+                $(csharp::block_comment(["<summary>Moves to the next entry of the group, after all the fields, groups", "and var data of the current one were encoded.</summary>"]))
+                public void Advance()
+                {
+                    if (_numInGroup >= $(max_literal(&num_in_group_metadata.field_primitive_type)))
+                    {
+                        throw $error.GroupOutOfBounds($(quoted(name.to_case(Case::UpperCamel))));
+                    }
+
+                    _offset = EntryEnd();
+                    _numInGroup++;
+                    new $(&dimension_type_encoder_name)(_buffer).NumInGroup(($num_in_group_primitive)_numInGroup);
+                }
+
+                private int EntryEnd()
+                {
+                    int offset = $(&start);
+                    $(self.skip_calls(&groups, &var_data, Some(decoder_path)))
+                    return offset;
+                }
+                $(self.generate_field_encoders(&fields, Some(&quote!(_offset)), layout.offsets)?)
+                $(self.generate_group_encoders(&groups, &start, decoder_path)?)
+                $(self.generate_var_data_encoders(&var_data, &groups, &start, decoder_path)?)
+                $(for group in &groups {
+                    $['\n']
+                    $(self.generate_group_encoder(group, &format!("{}.{}Decoder", decoder_path, group.name.to_case(Case::UpperCamel)))?)
+                })
+            }
+        })
+    }
+
+    /// Generates the helpers of a message or group decoder, which return the offset right
+    /// after each of its groups and var data, given the offset where it starts.
+    pub(crate) fn generate_skip_helpers(
+        &self,
+        groups: &[&GroupType],
+        var_data: &[&VariableDataType],
+    ) -> Result<Tokens<Csharp>> {
+        let mut helpers: Tokens<Csharp> = quote!();
+
+        for group in groups {
+            let decoder_name = format!("{}Decoder", group.name.to_case(Case::UpperCamel));
+
+            helpers.line();
+            helpers.append(quote! {
+                internal static int Skip$(group.name.to_case(Case::UpperCamel))($READ_BUF_CLASS_NAME buffer, int offset, int actingVersion)
+                {
+                    $(skip_version_check(group.since_version))
+                    return offset + new $(&decoder_name)(buffer.SplitAt(offset), actingVersion).Size();
+                }
+            });
+        }
+
+        for var_data_field in var_data {
+            helpers.line();
+            helpers.append(self.generate_var_data_skip_helper(var_data_field)?);
+        }
+
+        Ok(helpers)
+    }
+
+    /// Moves the `offset` variable past the given groups and var data, with the helpers of the
+    /// decoder itself, or the ones of the decoder at `decoder_path` for encoders.
+    pub(crate) fn skip_calls(
+        &self,
+        groups: &[&GroupType],
+        var_data: &[&VariableDataType],
+        decoder_path: Option<&str>,
+    ) -> Tokens<Csharp> {
+        let (prefix, arguments) = match decoder_path {
+            Some(decoder_path) => (
+                format!("{}.", decoder_path),
+                format!(
+                    "_buffer.AsReadBuf(), offset, {}.Version",
+                    self.qualified(SCHEMA_CLASS_NAME)
+                ),
+            ),
+            None => (String::new(), "_buffer, offset, _actingVersion".to_owned()),
+        };
+
+        let names = groups
+            .iter()
+            .map(|group| group.name.as_str())
+            .chain(var_data.iter().map(|var| var.name.as_str()));
+
+        quote! {
+            $(for name in names {
+                $['\r']
+                offset = $(&prefix)Skip$(name.to_case(Case::UpperCamel))($(&arguments));
+            })
+        }
+    }
+
+    pub(crate) fn generate_field_decoders(
+        &self,
+        fields: &[&FieldType],
+        offset_base: Option<&Tokens<Csharp>>,
+        offsets: impl IntoIterator<Item = usize>,
+    ) -> Result<Tokens<Csharp>> {
+        let mut decoder_fields: Tokens<Csharp> = quote!();
+
+        for (field, offset) in fields.iter().zip(offsets) {
+            let repr_type = field.to_type(&self.schema.types)?;
+
+            decoder_fields.line();
+            decoder_fields.append(if matches!(field.presence, Presence::Constant) {
+                self.generate_constant_enum(field, repr_type)?
+            } else {
+                let since_version = field.since_version.unwrap_or(0);
+                self.generate_decoder_field(
+                    &field.name,
+                    &repr_type,
+                    field_offset(offset_base, offset),
+                    since_version,
+                )?
+            });
+        }
+
+        Ok(decoder_fields)
+    }
+
+    fn generate_constant_enum(&self, field: &FieldType, repr_type: Type) -> Result<Tokens<Csharp>> {
+        let field_name = field.name.to_case(Case::UpperCamel);
+        let enum_type = match repr_type {
+            Type::Enum(enum_type) => enum_type,
+            _ => return Err(anyhow!("Constant field {} is not an enum", field.name)),
+        };
+        let enum_type_name = enum_type.name.to_case(Case::UpperCamel);
+
+        let default_value = field
+            .value_ref
+            .as_ref()
+            .ok_or(anyhow!("Constant field {} has no ref value", field.name))?;
+
+        let default_value = default_value.split('.').take(2).collect::<Vec<_>>()[1];
+        let default_value = enum_type
+            .values
+            .iter()
+            .find(|value| value.name == default_value)
+            .ok_or(anyhow!(
+                "Constant field {} has no value {}",
+                field.name,
+                default_value
+            ))?;
+
+        Ok(quote! {
+            public $(&enum_type_name) $(&field_name)()
+            {
+                return $(self.qualified(&enum_type_name)).$(default_value.name.to_case(Case::UpperCamel));
+            }
+        })
+    }
+
+    pub(crate) fn generate_field_encoders(
+        &self,
+        fields: &[&FieldType],
+        offset_base: Option<&Tokens<Csharp>>,
+        offsets: impl IntoIterator<Item = usize>,
+    ) -> Result<Tokens<Csharp>> {
+        let mut encoder_fields: Tokens<Csharp> = quote!();
+
+        for (field, offset) in fields.iter().zip(offsets) {
+            if matches!(field.presence, Presence::Constant) {
+                continue;
+            }
+
+            let repr_type = field.to_type(&self.schema.types)?;
+
+            encoder_fields.line();
+            encoder_fields.append(self.generate_encoder_field(
+                &field.name,
+                &repr_type,
+                field_offset(offset_base, offset),
+            )?);
+        }
+
+        Ok(encoder_fields)
+    }
+
+    pub(crate) fn generate_group_decoders(
+        &self,
+        groups: &[&GroupType],
+        start: &Tokens<Csharp>,
+    ) -> Result<Tokens<Csharp>> {
+        let mut decoder_fields: Tokens<Csharp> = quote!();
+
+        for (group_idx, group) in groups.iter().enumerate() {
+            let decoder_name = format!("{}Decoder", group.name.to_case(Case::UpperCamel));
+
+            decoder_fields.line();
+            decoder_fields.append(quote! {
+                public $(&decoder_name) $(group.name.to_case(Case::UpperCamel))()
+                {
+                    $(if let Some(since_version) = group.since_version.filter(|version| *version > 0) {
+                        if (_actingVersion < $since_version)
+                        {
+                            return $(&decoder_name).Empty(_actingVersion);
+                        }
+                        $['\n']
+                    })
+                    int offset = $start;
+                    $(self.skip_calls(&groups[..group_idx], &[], None))
+                    return new $(&decoder_name)(_buffer.SplitAt(offset), _actingVersion);
+                }
+            });
+        }
+
+        Ok(decoder_fields)
+    }
+
+    pub(crate) fn generate_group_encoders(
+        &self,
+        groups: &[&GroupType],
+        start: &Tokens<Csharp>,
+        decoder_path: &str,
+    ) -> Result<Tokens<Csharp>> {
+        let mut encoder_fields: Tokens<Csharp> = quote!();
+
+        for (group_idx, group) in groups.iter().enumerate() {
+            let encoder_name = format!("{}Encoder", group.name.to_case(Case::UpperCamel));
+
+            encoder_fields.line();
+            encoder_fields.append(quote! {
+                public $(&encoder_name) $(group.name.to_case(Case::UpperCamel))()
+                {
+                    int offset = $start;
+                    $(self.skip_calls(&groups[..group_idx], &[], Some(decoder_path)))
+                    return new $(&encoder_name)(_buffer.SplitAt(offset));
+                }
+            });
+        }
+
+        Ok(encoder_fields)
+    }
+}
+
+/// Offset of a field, relative to the current entry of a group, or absolute otherwise.
+fn field_offset(offset_base: Option<&Tokens<Csharp>>, offset: usize) -> Tokens<Csharp> {
+    match offset_base {
+        Some(offset_base) => quote!($offset_base + $offset),
+        None => quote!($offset),
+    }
+}
+
+/// Returns the offset unchanged from a skip helper, when the group or var data was added in
+/// a newer version of the schema than the one used to encode the data.
+pub(crate) fn skip_version_check(since_version: Option<usize>) -> Tokens<Csharp> {
+    quote! {
+        $(if let Some(since_version) = since_version.filter(|version| *version > 0) {
+            if (actingVersion < $since_version)
+            {
+                return offset;
+            }
+            $['\n']
+        })
+    }
+}
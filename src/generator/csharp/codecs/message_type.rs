@@ -0,0 +1,331 @@
+use crate::generator::common::{field_groups, header_counts};
+use crate::generator::csharp::codecs::field_metadata;
+use crate::generator::csharp::constants::{
+    DISPATCHER_CLASS_NAME, READ_BUF_CLASS_NAME, SCHEMA_CLASS_NAME, VISITOR_INTERFACE_NAME,
+    WRITE_BUF_CLASS_NAME,
+};
+use crate::generator::csharp::module::ModuleGenerator;
+use crate::models::layout::BlockLayout;
+use crate::models::message::MessageType;
+use crate::models::types::{SizedEncoded, Type};
+use anyhow::{anyhow, Result};
+use convert_case::{Case, Casing};
+use genco::prelude::*;
+
+impl ModuleGenerator<'_> {
+    fn write_message_decoder(&self, message: &MessageType) -> Result<()> {
+        let name = message.name.as_str();
+        let decoder_name = format!("{}Decoder", name.to_case(Case::UpperCamel));
+        let (fields, groups, var_data) = field_groups(&message.fields);
+        let read_only_span = &csharp::import("System", "ReadOnlySpan");
+        let error = &self.error_class();
+        let schema = self.qualified(SCHEMA_CLASS_NAME);
+
+        let layout =
+            BlockLayout::from_fields(name, &fields, message.block_length, &self.schema.types)?;
+
+        let header_type = Type::Composite(self.schema.types.header_type.clone());
+        let header_size = header_type.size(&self.schema.types)?;
+        let header_decoder_name = format!(
+            "{}Decoder",
+            self.schema.types.header_type.name.to_case(Case::UpperCamel)
+        );
+        let start = quote!($header_size + _blockLength);
+
+        let decoder_tokens: Tokens<Csharp> = quote! {
+            public readonly ref struct $(&decoder_name)
+            {
+                public const int TemplateId = $(message.id);
+
+                private readonly $READ_BUF_CLASS_NAME _buffer;
+                private readonly int _actingVersion;
+                private readonly int _blockLength;
+
+                public $(&decoder_name)($read_only_span<byte> buffer) : this(new $READ_BUF_CLASS_NAME(buffer))
+                {
+                }
+
+                public $(&decoder_name)($READ_BUF_CLASS_NAME buffer)
+                {
+                    $(&header_decoder_name) header = new $(&header_decoder_name)(buffer);
+
+                    long schemaId = (long)header.SchemaId();
+                    if (schemaId != $(&schema).Id)
+                    {
+                        throw $error.WrongSchema(schemaId, $(&schema).Id);
+                    }
+
+                    int templateId = (int)header.TemplateId();
+                    if (templateId != TemplateId)
+                    {
+                        throw $error.WrongMessageType(templateId, TemplateId);
+                    }
+
+                    _buffer = buffer;
+                    _actingVersion = (int)header.Version();
+                    _blockLength = (int)header.BlockLength();
+                }
+
+                $(csharp::block_comment(["<summary>Version of the schema the decoded message was encoded with.</summary>"]))
+                public int ActingVersion()
+                {
+                    return _actingVersion;
+                }
+
+                $(csharp::block_comment(["<summary>Length of the root block of the decoded message, which can differ from", "the one in the schema, when the message was encoded with another version.</summary>"]))
+                public int BlockLength()
+                {
+                    return _blockLength;
+                }
+
+                $(csharp::block_comment(["<summary>Size of the decoded message without its header.</summary>"]))
+                public int Size()
+                {
+                    int offset = $(&start);
+                    $(self.skip_calls(&groups, &var_data, None))
+                    return offset - $header_size;
+                }
+
+                $(self.generate_decoder_field("message_header", &header_type, quote!(0), 0)?)
+                $(self.generate_skip_helpers(&groups, &var_data)?)
+                $(self.generate_field_decoders(&fields, None, layout.offsets.iter().map(|field_offset| header_size + field_offset))?)
+                $(self.generate_group_decoders(&groups, &start)?)
+                $(self.generate_var_data_decoders(&var_data, &groups, &start)?)
+                $(for group in &groups {
+                    $['\n']
+                    $(self.generate_group_decoder(group)?)
+                })
+            }
+        };
+
+        self.write_class(&decoder_name, decoder_tokens)
+    }
+
+    fn write_message_encoder(&self, message: &MessageType) -> Result<()> {
+        let name = message.name.as_str();
+        let encoder_name = format!("{}Encoder", name.to_case(Case::UpperCamel));
+        let decoder_path = self.qualified(&format!("{}Decoder", name.to_case(Case::UpperCamel)));
+        let (fields, groups, var_data) = field_groups(&message.fields);
+        let span = &csharp::import("System", "Span");
+        let schema = self.qualified(SCHEMA_CLASS_NAME);
+
+        let layout =
+            BlockLayout::from_fields(name, &fields, message.block_length, &self.schema.types)?;
+
+        let header_type = Type::Composite(self.schema.types.header_type.clone());
+        let header_size = header_type.size(&self.schema.types)?;
+        let header_encoder_name = format!(
+            "{}Encoder",
+            self.schema.types.header_type.name.to_case(Case::UpperCamel)
+        );
+        let start = quote!($(header_size + layout.block_length));
+
+        let encoder_tokens: Tokens<Csharp> = quote! {
+            public readonly ref struct $(&encoder_name)
+            {
+                public const int TemplateId = $(message.id);
+                public const int BlockLength = $(layout.block_length);
+
+                private readonly $WRITE_BUF_CLASS_NAME _buffer;
+
+                public $(&encoder_name)($span<byte> buffer) : this(new $WRITE_BUF_CLASS_NAME(buffer))
+                {
+                }
+
+                public $(&encoder_name)($WRITE_BUF_CLASS_NAME buffer)
+                {
+                    _buffer = buffer;
+
+                    $(&header_encoder_name) header = new $(&header_encoder_name)(buffer);
+                    header.BlockLength(($(self.header_field_type("blockLength")?))BlockLength);
+                    header.TemplateId(($(self.header_field_type("templateId")?))TemplateId);
+                    header.SchemaId(($(self.header_field_type("schemaId")?))$(&schema).Id);
+                    header.Version(($(self.header_field_type("version")?))$(&schema).Version);
+                    $(for (field_name, count) in header_counts(&self.schema.types, groups.len(), var_data.len()) {
+                        $['\r']
+                        header.$(field_name.to_case(Case::UpperCamel))(($(self.header_field_type(field_name)?))$count);
+                    })
+                }
+
+                $(csharp::block_comment(["<summary>Size of the encoded message including its header, once all its groups", "and var data were encoded.</summary>"]))
+                public int Size()
+                {
+                    int offset = $(&start);
+                    $(self.skip_calls(&groups, &var_data, Some(&decoder_path)))
+                    return offset;
+                }
+
+                $(self.generate_encoder_field("message_header", &header_type, quote!(0))?)
+                $(self.generate_field_encoders(&fields, None, layout.offsets.iter().map(|field_offset| header_size + field_offset))?)
+                $(self.generate_group_encoders(&groups, &start, &decoder_path)?)
+                $(self.generate_var_data_encoders(&var_data, &groups, &start, &decoder_path)?)
+                $(for group in &groups {
+                    $['\n']
+                    $(self.generate_group_encoder(group, &format!("{}.{}Decoder", decoder_path, group.name.to_case(Case::UpperCamel)))?)
+                })
+            }
+        };
+
+        self.write_class(&encoder_name, encoder_tokens)
+    }
+
+    /// C# type of a field of the message header, which the values written by the message
+    /// encoders are cast to.
+    fn header_field_type(&self, field_name: &str) -> Result<String> {
+        let header_type = &self.schema.types.header_type;
+        let field = header_type
+            .fields
+            .iter()
+            .find(|field| field.name() == field_name)
+            .ok_or(anyhow!(
+                "Missing field '{}' in message header '{}'",
+                field_name,
+                header_type.name
+            ))?;
+
+        let encoded_type = match field {
+            Type::EncodedData(encoded_type) => encoded_type.clone(),
+            Type::Reference(reference_type) => {
+                match self.schema.types.find_type(&reference_type.type_name) {
+                    Some(Type::EncodedData(encoded_type)) => encoded_type,
+                    _ => {
+                        return Err(anyhow!(
+                            "Only encoded data type expected for the field '{}' in message header",
+                            field_name
+                        ))
+                    }
+                }
+            }
+            _ => {
+                return Err(anyhow!(
+                    "Only encoded data type expected for the field '{}' in message header",
+                    field_name
+                ))
+            }
+        };
+
+        let metadata = field_metadata(field_name, &encoded_type, &self.schema.types)?;
+
+        Ok(metadata.lang_type.name.to_owned())
+    }
+
+    /// Writes the decoder of any message of the schema, which checks the schema id and picks
+    /// the message decoder by the template id in the message header, and the visitor of the
+    /// decoded messages.
+    fn write_message_dispatcher(&self, message_types: &[&MessageType]) -> Result<()> {
+        let header_decoder_name = format!(
+            "{}Decoder",
+            self.schema.types.header_type.name.to_case(Case::UpperCamel)
+        );
+        let read_only_span = &csharp::import("System", "ReadOnlySpan");
+        let error = &self.error_class();
+        let schema = self.qualified(SCHEMA_CLASS_NAME);
+
+        let variants = message_types
+            .iter()
+            .map(|message_type| message_type.name.to_case(Case::UpperCamel))
+            .collect::<Vec<_>>();
+
+        let dispatcher_tokens: Tokens<Csharp> = quote! {
+            $(csharp::block_comment(["<summary>Decoder of any message of the schema.</summary>"]))
+            public readonly ref struct $DISPATCHER_CLASS_NAME
+            {
+                private readonly $READ_BUF_CLASS_NAME _buffer;
+                private readonly int _templateId;
+                private readonly int _actingVersion;
+
+                public $DISPATCHER_CLASS_NAME($read_only_span<byte> buffer) : this(new $READ_BUF_CLASS_NAME(buffer))
+                {
+                }
+
+                public $DISPATCHER_CLASS_NAME($READ_BUF_CLASS_NAME buffer)
+                {
+                    _buffer = buffer;
+                    $(&header_decoder_name) header = new $(&header_decoder_name)(buffer);
+
+                    long schemaId = (long)header.SchemaId();
+                    if (schemaId != $(&schema).Id)
+                    {
+                        throw $error.WrongSchema(schemaId, $(&schema).Id);
+                    }
+
+                    _templateId = (int)header.TemplateId();
+                    _actingVersion = (int)header.Version();
+
+                    switch (_templateId)
+                    {
+                        $(for name in &variants {
+                            $['\r']
+                            case $(name)Decoder.TemplateId:
+                                break;
+                        })
+                        default:
+                            throw $error.UnknownMessageType(_templateId);
+                    }
+                }
+
+                public int TemplateId()
+                {
+                    return _templateId;
+                }
+
+                $(csharp::block_comment(["<summary>Version of the schema the decoded message was encoded with.</summary>"]))
+                public int ActingVersion()
+                {
+                    return _actingVersion;
+                }
+
+                $(csharp::block_comment(["<summary>Calls the method of the visitor for the type of the decoded message.</summary>"]))
+                public void Accept($VISITOR_INTERFACE_NAME visitor)
+                {
+                    switch (_templateId)
+                    {
+                        $(for name in &variants {
+                            $['\r']
+                            case $(name)Decoder.TemplateId:
+                                visitor.On$(name)(new $(name)Decoder(_buffer));
+                                break;
+                        })
+                        default:
+                            throw $error.UnknownMessageType(_templateId);
+                    }
+                }
+            }
+        };
+
+        let visitor_tokens: Tokens<Csharp> = quote! {
+            $(csharp::block_comment(["<summary>Visitor of the decoded messages. Messages without an overridden method are ignored.</summary>"]))
+            public interface $VISITOR_INTERFACE_NAME
+            {
+                $(for name in &variants join ($['\n']) {
+                    void On$(name)($(name)Decoder decoder)
+                    {
+                    }
+                })
+            }
+        };
+
+        self.write_class(DISPATCHER_CLASS_NAME, dispatcher_tokens)?;
+        self.write_class(VISITOR_INTERFACE_NAME, visitor_tokens)
+    }
+
+    pub fn write_message_codecs(&self) -> Result<()> {
+        let message_types = self
+            .schema
+            .message_types
+            .message_types
+            .values()
+            .collect::<Vec<_>>();
+
+        for message_type in &message_types {
+            self.write_message_decoder(message_type)?;
+            self.write_message_encoder(message_type)?;
+        }
+
+        if !message_types.is_empty() {
+            self.write_message_dispatcher(&message_types)?;
+        }
+
+        Ok(())
+    }
+}
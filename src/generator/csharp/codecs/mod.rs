@@ -0,0 +1,203 @@
+mod composite_type;
+mod enum_type;
+mod group_type;
+mod message_type;
+mod set_type;
+mod var_data_type;
+
+use crate::generator::common::FieldMetadata;
+use crate::models::types::encoded_data_type::EncodedDataType;
+use crate::models::types::primitive_type::{NativeType, PrimitiveConvertible, ResolvableType};
+use crate::models::types::SizedEncoded;
+use crate::models::TypeMap;
+use anyhow::Result;
+use convert_case::{Case, Casing};
+use genco::prelude::*;
+
+/// Metadata of an encoded field, with the field name in upper camel case of C# methods.
+pub(crate) fn field_metadata(
+    field_name: &str,
+    encoded_type: &EncodedDataType,
+    types: &TypeMap,
+) -> Result<FieldMetadata<Csharp>> {
+    let field_primitive_type = encoded_type.primitive_type.resolved(&types.encoded_types)?;
+    let field_size = encoded_type.size(types)?;
+    let type_size = encoded_type.primitive_type.size(types)?;
+
+    Ok(FieldMetadata {
+        field_name: field_name.to_case(Case::UpperCamel),
+        field_primitive_type: field_primitive_type.clone(),
+        type_size,
+        field_length: field_size / type_size,
+        lang_type: field_primitive_type.lang_primitive(&types.encoded_types)?,
+        encoding: encoded_type.character_encoding,
+    })
+}
+
+/// Suffix of the `ReadBuf`/`WriteBuf` accessors of a resolved primitive type, e.g. `U16`
+/// for `GetU16At`.
+pub(crate) fn accessor(native_type: &NativeType) -> &'static str {
+    match native_type {
+        NativeType::Char | NativeType::UInt8 => "U8",
+        NativeType::Int8 => "I8",
+        NativeType::UInt16 => "U16",
+        NativeType::UInt32 => "U32",
+        NativeType::UInt64 => "U64",
+        NativeType::Int16 => "I16",
+        NativeType::Int32 => "I32",
+        NativeType::Int64 => "I64",
+        NativeType::Float => "F32",
+        NativeType::Double => "F64",
+        NativeType::Reference(type_name) => unreachable!("Resolved reference: {}", type_name),
+    }
+}
+
+/// C# literal of a numeric value from the schema, typed as the primitive type.
+pub(crate) fn literal(value: &str, native_type: &NativeType) -> String {
+    match native_type {
+        NativeType::Char | NativeType::UInt8 => format!("(byte){}", value),
+        NativeType::Int8 => format!("(sbyte){}", value),
+        NativeType::UInt16 => format!("(ushort){}", value),
+        NativeType::Int16 => format!("(short){}", value),
+        NativeType::UInt32 => format!("{}U", value),
+        NativeType::UInt64 => format!("{}UL", value),
+        NativeType::Int64 => format!("{}L", value),
+        NativeType::Float => format!("{}f", value),
+        NativeType::Int32 | NativeType::Double | NativeType::Reference(_) => value.to_owned(),
+    }
+}
+
+/// Default null value of a primitive type, see `NativeType::null`.
+pub(crate) fn null_literal(native_type: &NativeType) -> &'static str {
+    match native_type {
+        NativeType::Char => "(byte)0",
+        NativeType::UInt8 => "byte.MaxValue",
+        NativeType::UInt16 => "ushort.MaxValue",
+        NativeType::UInt32 => "uint.MaxValue",
+        NativeType::UInt64 => "ulong.MaxValue",
+        NativeType::Int8 => "sbyte.MinValue",
+        NativeType::Int16 => "short.MinValue",
+        NativeType::Int32 => "int.MinValue",
+        NativeType::Int64 => "long.MinValue",
+        NativeType::Float => "float.NaN",
+        NativeType::Double | NativeType::Reference(_) => "double.NaN",
+    }
+}
+
+/// Largest value of an integer primitive type, used for the counters of groups and var data.
+pub(crate) fn max_literal(native_type: &NativeType) -> &'static str {
+    match native_type {
+        NativeType::Char | NativeType::UInt8 => "byte.MaxValue",
+        NativeType::Int8 => "sbyte.MaxValue",
+        NativeType::UInt16 => "ushort.MaxValue",
+        NativeType::UInt32 => "uint.MaxValue",
+        NativeType::Int16 => "short.MaxValue",
+        NativeType::Int32 => "int.MaxValue",
+        NativeType::UInt64 => "ulong.MaxValue",
+        _ => "long.MaxValue",
+    }
+}
+
+/// Null value of an optional field, either declared by the `nullValue` of the encoded type,
+/// or the default one for its primitive type.
+pub(crate) fn null_value(
+    encoded_type: &EncodedDataType,
+    metadata: &FieldMetadata<Csharp>,
+) -> String {
+    match &encoded_type.null_value {
+        Some(null_value) => literal(null_value, &metadata.field_primitive_type),
+        None => null_literal(&metadata.field_primitive_type).to_owned(),
+    }
+}
+
+pub(crate) fn null_value_condition(
+    variable: &str,
+    encoded_type: &EncodedDataType,
+    metadata: &FieldMetadata<Csharp>,
+) -> Tokens<Csharp> {
+    match (&metadata.field_primitive_type, &encoded_type.null_value) {
+        (NativeType::Float, None) => quote!(float.IsNaN($variable)),
+        (NativeType::Double, None) => quote!(double.IsNaN($variable)),
+        _ => quote!($variable == $(null_value(encoded_type, metadata))),
+    }
+}
+
+/// Checks of a `variable` against the `minValue` and `maxValue` of the encoded type.
+pub(crate) fn bounds_checks(
+    field_name: &str,
+    variable: &str,
+    encoded_type: &EncodedDataType,
+    native_type: &NativeType,
+    error: &csharp::Import,
+) -> Tokens<Csharp> {
+    let mut checks = quote!();
+    let unsigned = matches!(
+        native_type,
+        NativeType::UInt8 | NativeType::UInt16 | NativeType::UInt32 | NativeType::UInt64
+    );
+
+    if let Some(min) = &encoded_type.min_value {
+        // Unsigned values can never be below zero
+        if !(unsigned && min.parse::<u64>() == Ok(0)) {
+            checks.append(bounds_check(
+                field_name,
+                variable,
+                "<",
+                min,
+                native_type,
+                "min",
+                error,
+            ));
+            checks.line();
+        }
+    }
+
+    if let Some(max) = &encoded_type.max_value {
+        checks.append(bounds_check(
+            field_name,
+            variable,
+            ">",
+            max,
+            native_type,
+            "max",
+            error,
+        ));
+        checks.line();
+    }
+
+    checks
+}
+
+fn bounds_check(
+    field_name: &str,
+    variable: &str,
+    operator: &str,
+    limit: &str,
+    native_type: &NativeType,
+    limit_name: &str,
+    error: &csharp::Import,
+) -> Tokens<Csharp> {
+    quote! {
+        if ($variable $operator $(literal(limit, native_type)))
+        {
+            throw $error.ValueOutOfBounds($(quoted(field_name)), $variable + $(quoted(format!(" {} {} ({})", operator, limit, limit_name))));
+        }
+    }
+}
+
+/// Returns the null value early from an accessor of a field, which was added in a newer
+/// version of the schema than the one used to encode the decoded data.
+pub(crate) fn version_check(
+    since_version: usize,
+    null_value: impl FormatInto<Csharp>,
+) -> Tokens<Csharp> {
+    quote! {
+        $(if since_version > 0 {
+            if (_actingVersion < $since_version)
+            {
+                return $null_value;
+            }
+            $['\n']
+        })
+    }
+}
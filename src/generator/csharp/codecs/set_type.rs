@@ -0,0 +1,45 @@
+use anyhow::Result;
+use convert_case::{Case, Casing};
+
+use crate::generator::csharp::module::ModuleGenerator;
+use crate::models::types::primitive_type::PrimitiveConvertible;
+use crate::models::types::set_type::SetType;
+use genco::prelude::*;
+
+impl ModuleGenerator<'_> {
+    fn write_set_codec(&self, set_type: &SetType) -> Result<()> {
+        let name = set_type.name.to_case(Case::UpperCamel);
+        let csharp_type = set_type
+            .encoding_type
+            .lang_primitive(&self.schema.types.encoded_types)?;
+
+        // Shifted bits have the type of the literal, which must fit in the encoding type
+        let bit = match csharp_type.name {
+            "uint" => "1U",
+            "ulong" => "1UL",
+            _ => "1",
+        };
+
+        let set_tokens: Tokens<Csharp> = quote! {
+            $(csharp::block_comment(["<summary>Choices of the set, which are encoded as bits of its value.</summary>"]))
+            [$(csharp::import("System", "Flags"))]
+            public enum $(&name) : $(&csharp_type)
+            {
+                $(for choice in &set_type.choices {
+                    $['\r']
+                    $(choice.name.to_case(Case::UpperCamel)) = $bit << $(choice.value),
+                })
+            }
+        };
+
+        self.write_class(&name, set_tokens)
+    }
+
+    pub fn write_set_codecs(&self) -> Result<()> {
+        for set_type in self.schema.types.set_types.values() {
+            self.write_set_codec(set_type)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,200 @@
+use crate::generator::common::FieldMetadata;
+use crate::generator::csharp::codecs::group_type::skip_version_check;
+use crate::generator::csharp::codecs::{accessor, field_metadata};
+use crate::generator::csharp::constants::READ_BUF_CLASS_NAME;
+use crate::generator::csharp::module::ModuleGenerator;
+use crate::models::types::composite_type::CompositeType;
+use crate::models::types::group_type::GroupType;
+use crate::models::types::primitive_type::NativeType;
+use crate::models::types::variable_data_type::VariableDataType;
+use crate::models::types::{CharacterEncoding, Type};
+use crate::models::TypeMap;
+use anyhow::{anyhow, Result};
+use convert_case::{Case, Casing};
+use genco::prelude::*;
+
+impl ModuleGenerator<'_> {
+    /// Generates the helper of a message or group decoder, which returns the offset right
+    /// after the var data, given the offset of its length.
+    pub(crate) fn generate_var_data_skip_helper(
+        &self,
+        var_data: &VariableDataType,
+    ) -> Result<Tokens<Csharp>> {
+        let name = var_data.name.as_str();
+        let repr_type = var_data.repr_type(&self.schema.types.composite_types)?;
+
+        let (length_type_metadata, value_type_metadata) =
+            repr_type_metadata(name, repr_type, &self.schema.types)?;
+
+        Ok(quote! {
+            internal static int Skip$(name.to_case(Case::UpperCamel))($READ_BUF_CLASS_NAME buffer, int offset, int actingVersion)
+            {
+                $(skip_version_check(var_data.since_version))
+                return offset + $(length_type_metadata.type_size) + $(encoded_length(&length_type_metadata, &value_type_metadata, "buffer", "offset"));
+            }
+        })
+    }
+
+    /// Generates the accessors of the var data of a message or group, which return the
+    /// values as a view of the decoded bytes.
+    pub(crate) fn generate_var_data_decoders(
+        &self,
+        var_data_fields: &[&VariableDataType],
+        groups: &[&GroupType],
+        start: &Tokens<Csharp>,
+    ) -> Result<Tokens<Csharp>> {
+        let mut decoder_fields: Tokens<Csharp> = quote!();
+        let read_only_span = &csharp::import("System", "ReadOnlySpan");
+
+        for (var_data_idx, var_data_field) in var_data_fields.iter().enumerate() {
+            let name = var_data_field.name.as_str();
+            let repr_type = var_data_field.repr_type(&self.schema.types.composite_types)?;
+
+            let (length_type_metadata, value_type_metadata) =
+                repr_type_metadata(name, repr_type, &self.schema.types)?;
+
+            decoder_fields.line();
+            decoder_fields.append(quote! {
+                public $read_only_span<byte> $(name.to_case(Case::UpperCamel))()
+                {
+                    $(if let Some(since_version) = var_data_field.since_version.filter(|version| *version > 0) {
+                        if (_actingVersion < $since_version)
+                        {
+                            return $read_only_span<byte>.Empty;
+                        }
+                        $['\n']
+                    })
+                    int offset = $start;
+                    $(self.skip_calls(groups, &var_data_fields[..var_data_idx], None))
+                    return _buffer.GetSliceAt(offset + $(length_type_metadata.type_size), $(encoded_length(&length_type_metadata, &value_type_metadata, "_buffer", "offset")));
+                }
+            });
+        }
+
+        Ok(decoder_fields)
+    }
+
+    /// Generates the setters of the var data of a message or group, which write the length
+    /// of the values, followed by their bytes.
+    pub(crate) fn generate_var_data_encoders(
+        &self,
+        var_data_fields: &[&VariableDataType],
+        groups: &[&GroupType],
+        start: &Tokens<Csharp>,
+        decoder_path: &str,
+    ) -> Result<Tokens<Csharp>> {
+        let mut encoder_fields: Tokens<Csharp> = quote!();
+        let read_only_span = &csharp::import("System", "ReadOnlySpan");
+        let encoding = &csharp::import("System.Text", "Encoding");
+        let error = &self.error_class();
+
+        for (var_data_idx, var_data_field) in var_data_fields.iter().enumerate() {
+            let name = var_data_field.name.as_str();
+            let field_name = name.to_case(Case::UpperCamel);
+            let repr_type = var_data_field.repr_type(&self.schema.types.composite_types)?;
+
+            let (length_type_metadata, value_type_metadata) =
+                repr_type_metadata(name, repr_type, &self.schema.types)?;
+
+            let length_type_native = &length_type_metadata.field_primitive_type;
+            let value_type_size = value_type_metadata.type_size;
+            let ascii = matches!(value_type_metadata.encoding, Some(CharacterEncoding::Ascii));
+
+            // Lengths of spans are ints, so only the smaller length types can overflow
+            let max_length = match length_type_native {
+                NativeType::UInt8 => Some("byte.MaxValue"),
+                NativeType::UInt16 => Some("ushort.MaxValue"),
+                _ => None,
+            };
+            let length = if value_type_size > 1 {
+                quote!((value.Length / $value_type_size))
+            } else {
+                quote!(value.Length)
+            };
+
+            encoder_fields.line();
+            encoder_fields.append(quote! {
+                public void $(&field_name)($read_only_span<byte> value)
+                {
+                    $(if ascii {
+                        foreach (byte part in value)
+                        {
+                            if (part > 127)
+                            {
+                                throw $error.InvalidStringValue($encoding.UTF8.GetString(value));
+                            }
+                        }
+                        $['\n']
+                    })
+                    $(if value_type_size > 1 {
+                        if (value.Length % $value_type_size != 0)
+                        {
+                            throw $error.WrongSliceSize($(quoted(format!("expected a multiple of {} bytes, got ", value_type_size))) + value.Length);
+                        }
+                        $['\n']
+                    })
+                    $(if let Some(max_length) = max_length {
+                        if ($(&length) > $max_length)
+                        {
+                            throw $error.VarDataOutOfBounds($(quoted(&field_name)));
+                        }
+                        $['\n']
+                    })
+                    int offset = $start;
+                    $(self.skip_calls(groups, &var_data_fields[..var_data_idx], Some(decoder_path)))
+                    _buffer.Put$(accessor(length_type_native))At(offset, ($(&length_type_metadata.lang_type))$(&length));
+                    _buffer.PutBytesAt(offset + $(length_type_metadata.type_size), value);
+                }
+            });
+        }
+
+        Ok(encoder_fields)
+    }
+}
+
+/// Number of bytes of the values of var data, read from its length at the offset.
+fn encoded_length(
+    length_type_metadata: &FieldMetadata<Csharp>,
+    value_type_metadata: &FieldMetadata<Csharp>,
+    buffer: &str,
+    offset: &str,
+) -> Tokens<Csharp> {
+    let length =
+        quote!((int)$buffer.Get$(accessor(&length_type_metadata.field_primitive_type))At($offset));
+
+    if value_type_metadata.type_size > 1 {
+        quote!($length * $(value_type_metadata.type_size))
+    } else {
+        length
+    }
+}
+
+fn repr_type_metadata(
+    var_data_name: &str,
+    repr_type: &CompositeType,
+    types: &TypeMap,
+) -> Result<(FieldMetadata<Csharp>, FieldMetadata<Csharp>)> {
+    let length_type = match &repr_type.fields[0] {
+        Type::EncodedData(length_type) => length_type,
+        _ => {
+            return Err(anyhow!(
+            "Only encoded data type expected for the length type in variable data encoding '{}'",
+            var_data_name
+        ))
+        }
+    };
+    let length_type_metadata = field_metadata("", length_type, types)?;
+
+    let value_type = match &repr_type.fields[1] {
+        Type::EncodedData(value_type) => value_type,
+        _ => {
+            return Err(anyhow!(
+                "Only encoded data type expected for the value type in variable data encoding '{}'",
+                var_data_name
+            ))
+        }
+    };
+    let value_type_metadata = field_metadata("", value_type, types)?;
+
+    Ok((length_type_metadata, value_type_metadata))
+}
@@ -0,0 +1,8 @@
+pub const SRC_DIR: &str = "src";
+pub const ERROR_CLASS_NAME: &str = "SbeException";
+pub const ERROR_KIND_NAME: &str = "SbeErrorKind";
+pub const SCHEMA_CLASS_NAME: &str = "Schema";
+pub const READ_BUF_CLASS_NAME: &str = "ReadBuf";
+pub const WRITE_BUF_CLASS_NAME: &str = "WriteBuf";
+pub const DISPATCHER_CLASS_NAME: &str = "AnyMessageDecoder";
+pub const VISITOR_INTERFACE_NAME: &str = "IMessageVisitor";
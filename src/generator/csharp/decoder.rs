@@ -0,0 +1,115 @@
+use anyhow::Result;
+
+use crate::generator::csharp::constants::READ_BUF_CLASS_NAME;
+use crate::generator::csharp::module::ModuleGenerator;
+use crate::models::ByteOrder;
+use genco::prelude::*;
+
+/// Suffixes of the accessors of the buffers, along with the types of their values, the
+/// names of the types in the methods of `BinaryPrimitives` and the sizes of the values.
+pub(crate) const ACCESSORS: &[(&str, &str, &str, usize)] = &[
+    ("U8", "byte", "Byte", 1),
+    ("U16", "ushort", "UInt16", 2),
+    ("U32", "uint", "UInt32", 4),
+    ("U64", "ulong", "UInt64", 8),
+    ("I8", "sbyte", "SByte", 1),
+    ("I16", "short", "Int16", 2),
+    ("I32", "int", "Int32", 4),
+    ("I64", "long", "Int64", 8),
+    ("F32", "float", "Single", 4),
+    ("F64", "double", "Double", 8),
+];
+
+/// Suffix of the methods of `BinaryPrimitives` for the byte order of the schema.
+pub(crate) fn byte_order_suffix(byte_order: &ByteOrder) -> &'static str {
+    match byte_order {
+        ByteOrder::BigEndian => "BigEndian",
+        ByteOrder::LittleEndian => "LittleEndian",
+    }
+}
+
+impl ModuleGenerator<'_> {
+    pub fn write_read_buf_class(&self) -> Result<()> {
+        let read_only_span = &csharp::import("System", "ReadOnlySpan");
+        let binary_primitives = &csharp::import("System.Buffers.Binary", "BinaryPrimitives");
+        let utf8_encoding = &csharp::import("System.Text", "UTF8Encoding");
+        let decoder_fallback_exception = &csharp::import("System.Text", "DecoderFallbackException");
+        let error = &self.error_class();
+        let byte_order = byte_order_suffix(&self.schema.byte_order);
+
+        let read_buf_content: Tokens<Csharp> = quote! {
+            $(csharp::block_comment(["<summary>Read-only view of the bytes of an encoded message, or a part of it.</summary>"]))
+            public readonly ref struct $READ_BUF_CLASS_NAME
+            {
+                private static readonly $utf8_encoding Utf8 = new $utf8_encoding(false, true);
+
+                private readonly $read_only_span<byte> _data;
+
+                public $READ_BUF_CLASS_NAME($read_only_span<byte> data)
+                {
+                    _data = data;
+                }
+
+                public int Length()
+                {
+                    return _data.Length;
+                }
+
+                $(csharp::block_comment(["<summary>Returns the bytes from the index to the end of the buffer.</summary>"]))
+                public $READ_BUF_CLASS_NAME SplitAt(int index)
+                {
+                    CheckBounds(index, 0);
+
+                    return new $READ_BUF_CLASS_NAME(_data.Slice(index));
+                }
+
+                private void CheckBounds(int index, int count)
+                {
+                    int dataEnd = index + count;
+
+                    if (index < 0 || count < 0 || dataEnd > _data.Length)
+                    {
+                        throw $error.CodecOutOfBounds(dataEnd, _data.Length);
+                    }
+                }
+
+                $(csharp::block_comment(["<summary>Returns the bytes as a view of the buffer, without copying them.</summary>"]))
+                public $read_only_span<byte> GetSliceAt(int index, int len)
+                {
+                    CheckBounds(index, len);
+
+                    return _data.Slice(index, len);
+                }
+
+                $(csharp::block_comment(["<summary>Decodes a string, which can be either UTF-8 or ASCII, as any valid ASCII string is also a valid UTF-8 string.</summary>"]))
+                public string GetStringAt(int index, int len)
+                {
+                    $read_only_span<byte> src = GetSliceAt(index, len);
+
+                    try
+                    {
+                        return Utf8.GetString(src);
+                    }
+                    catch ($decoder_fallback_exception e)
+                    {
+                        throw $error.InvalidStringValue(e.Message);
+                    }
+                }
+                $(for (suffix, value_type, primitive, size) in ACCESSORS {
+                    $['\n']
+                    public $(*value_type) Get$(*suffix)At(int index)
+                    {
+                        CheckBounds(index, $(*size));
+                        $(if *size == 1 {
+                            return ($(*value_type))_data[index];
+                        } else {
+                            return $binary_primitives.Read$(*primitive)$(byte_order)(_data.Slice(index));
+                        })
+                    }
+                })
+            }
+        };
+
+        self.write_class(READ_BUF_CLASS_NAME, read_buf_content)
+    }
+}
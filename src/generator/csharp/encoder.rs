@@ -0,0 +1,78 @@
+use anyhow::Result;
+
+use crate::generator::csharp::constants::{READ_BUF_CLASS_NAME, WRITE_BUF_CLASS_NAME};
+use crate::generator::csharp::decoder::{byte_order_suffix, ACCESSORS};
+use crate::generator::csharp::module::ModuleGenerator;
+use genco::prelude::*;
+
+impl ModuleGenerator<'_> {
+    pub fn write_write_buf_class(&self) -> Result<()> {
+        let span = &csharp::import("System", "Span");
+        let read_only_span = &csharp::import("System", "ReadOnlySpan");
+        let binary_primitives = &csharp::import("System.Buffers.Binary", "BinaryPrimitives");
+        let error = &self.error_class();
+        let byte_order = byte_order_suffix(&self.schema.byte_order);
+
+        let write_buf_content: Tokens<Csharp> = quote! {
+            $(csharp::block_comment(["<summary>Writable view of the bytes of an encoded message, or a part of it.</summary>"]))
+            public readonly ref struct $WRITE_BUF_CLASS_NAME
+            {
+                private readonly $span<byte> _data;
+
+                public $WRITE_BUF_CLASS_NAME($span<byte> data)
+                {
+                    _data = data;
+                }
+
+                public int Length()
+                {
+                    return _data.Length;
+                }
+
+                $(csharp::block_comment(["<summary>Returns the bytes from the index to the end of the buffer.</summary>"]))
+                public $WRITE_BUF_CLASS_NAME SplitAt(int index)
+                {
+                    CheckBounds(index, 0);
+
+                    return new $WRITE_BUF_CLASS_NAME(_data.Slice(index));
+                }
+
+                $(csharp::block_comment(["<summary>Returns a read-only view of the same bytes, to find where the encoded groups and var data end.</summary>"]))
+                public $READ_BUF_CLASS_NAME AsReadBuf()
+                {
+                    return new $READ_BUF_CLASS_NAME(_data);
+                }
+
+                private void CheckBounds(int index, int count)
+                {
+                    int dataEnd = index + count;
+
+                    if (index < 0 || count < 0 || dataEnd > _data.Length)
+                    {
+                        throw $error.CodecOutOfBounds(dataEnd, _data.Length);
+                    }
+                }
+
+                public void PutBytesAt(int index, $read_only_span<byte> value)
+                {
+                    CheckBounds(index, value.Length);
+                    value.CopyTo(_data.Slice(index));
+                }
+                $(for (suffix, value_type, primitive, size) in ACCESSORS {
+                    $['\n']
+                    public void Put$(*suffix)At(int index, $(*value_type) value)
+                    {
+                        CheckBounds(index, $(*size));
+                        $(if *size == 1 {
+                            _data[index] = (byte)value;
+                        } else {
+                            $binary_primitives.Write$(*primitive)$(byte_order)(_data.Slice(index), value);
+                        })
+                    }
+                })
+            }
+        };
+
+        self.write_class(WRITE_BUF_CLASS_NAME, write_buf_content)
+    }
+}
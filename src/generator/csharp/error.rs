@@ -0,0 +1,95 @@
+use crate::generator::csharp::constants::{ERROR_CLASS_NAME, ERROR_KIND_NAME};
+use crate::generator::csharp::CsharpGenerator;
+use crate::generator::write_file;
+use anyhow::Result;
+use genco::prelude::*;
+
+impl CsharpGenerator {
+    pub fn write_error_class(&self) -> Result<()> {
+        let exception = &csharp::import("System", "Exception");
+
+        let error_class_content: Tokens<Csharp> = quote! {
+            $(csharp::block_comment(["<summary>Kinds of the errors of encoding or decoding a message.</summary>"]))
+            public enum $ERROR_KIND_NAME
+            {
+                InvalidStringValue,
+                InvalidEnumValue,
+                ValueOutOfBounds,
+                WrongSliceSize,
+                GroupOutOfBounds,
+                VarDataOutOfBounds,
+                WrongSchema,
+                WrongMessageType,
+                UnknownMessageType,
+                CodecOutOfBounds,
+            }
+
+            $(csharp::block_comment(["<summary>Error of encoding or decoding a message.</summary>"]))
+            public class $ERROR_CLASS_NAME : $exception
+            {
+                public $ERROR_CLASS_NAME($ERROR_KIND_NAME kind, string message) : base(message)
+                {
+                    Kind = kind;
+                }
+
+                public $ERROR_KIND_NAME Kind { get; }
+
+                public static $ERROR_CLASS_NAME InvalidStringValue(string value)
+                {
+                    return new $ERROR_CLASS_NAME($ERROR_KIND_NAME.InvalidStringValue, "invalid ascii string: " + value);
+                }
+
+                public static $ERROR_CLASS_NAME InvalidEnumValue(string typeName, object value)
+                {
+                    return new $ERROR_CLASS_NAME($ERROR_KIND_NAME.InvalidEnumValue, "invalid enum value for '" + typeName + "': " + value);
+                }
+
+                public static $ERROR_CLASS_NAME ValueOutOfBounds(string fieldName, string message)
+                {
+                    return new $ERROR_CLASS_NAME($ERROR_KIND_NAME.ValueOutOfBounds, "value out of bounds for field '" + fieldName + "': " + message);
+                }
+
+                public static $ERROR_CLASS_NAME WrongSliceSize(string message)
+                {
+                    return new $ERROR_CLASS_NAME($ERROR_KIND_NAME.WrongSliceSize, "wrong slice size: " + message);
+                }
+
+                public static $ERROR_CLASS_NAME GroupOutOfBounds(string groupName)
+                {
+                    return new $ERROR_CLASS_NAME($ERROR_KIND_NAME.GroupOutOfBounds, "group out of bounds: " + groupName);
+                }
+
+                public static $ERROR_CLASS_NAME VarDataOutOfBounds(string varDataName)
+                {
+                    return new $ERROR_CLASS_NAME($ERROR_KIND_NAME.VarDataOutOfBounds, "var data out of bounds: " + varDataName);
+                }
+
+                public static $ERROR_CLASS_NAME WrongSchema(long schemaId, long expectedSchemaId)
+                {
+                    return new $ERROR_CLASS_NAME($ERROR_KIND_NAME.WrongSchema, "received message had wrong schema: " + schemaId + ", expected " + expectedSchemaId);
+                }
+
+                public static $ERROR_CLASS_NAME WrongMessageType(int templateId, int expectedTemplateId)
+                {
+                    return new $ERROR_CLASS_NAME($ERROR_KIND_NAME.WrongMessageType, "received message had wrong type: " + templateId + ", expected " + expectedTemplateId);
+                }
+
+                public static $ERROR_CLASS_NAME UnknownMessageType(int templateId)
+                {
+                    return new $ERROR_CLASS_NAME($ERROR_KIND_NAME.UnknownMessageType, "received message had unknown type: " + templateId);
+                }
+
+                public static $ERROR_CLASS_NAME CodecOutOfBounds(int dataEnd, int length)
+                {
+                    return new $ERROR_CLASS_NAME($ERROR_KIND_NAME.CodecOutOfBounds, "codec out of bounds: " + dataEnd + " > " + length);
+                }
+            }
+        };
+
+        write_file(
+            &self.src_path().join(format!("{}.cs", ERROR_CLASS_NAME)),
+            &csharp::Config::default().with_namespace(self.root_namespace()),
+            error_class_content,
+        )
+    }
+}
@@ -0,0 +1,168 @@
+mod codecs;
+mod constants;
+mod decoder;
+mod encoder;
+mod error;
+mod module;
+
+use crate::generator::csharp::constants::SRC_DIR;
+use crate::generator::CodeGenerator;
+use crate::models::schema::ValidatedMessageSchema;
+use anyhow::Result;
+use convert_case::{Case, Casing};
+use indoc::formatdoc;
+use std::collections::BTreeMap;
+use std::fs::{create_dir_all, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::models::types::encoded_data_type::EncodedDataType;
+use crate::models::types::primitive_type::{
+    LanguagePrimitive, NativeType, PrimitiveConvertible, ResolvableType,
+};
+use genco::prelude::*;
+
+/// Generates a .NET project of `ref struct` codecs over `Span<byte>`, with a namespace for
+/// every schema.
+pub struct CsharpGenerator {
+    path: PathBuf,
+    project_name: String,
+    project_version: String,
+    bounds_checks: bool,
+    schemas: Vec<ValidatedMessageSchema>,
+}
+
+impl CsharpGenerator {
+    pub fn new(
+        path: &Path,
+        project_name: &str,
+        project_version: &str,
+        schemas: Vec<ValidatedMessageSchema>,
+        bounds_checks: bool,
+    ) -> Self {
+        Self {
+            path: path.to_owned(),
+            project_name: project_name.to_owned(),
+            project_version: project_version.to_owned(),
+            bounds_checks,
+            schemas,
+        }
+    }
+
+    /// Root namespace of the project, which contains a namespace for every schema.
+    fn root_namespace(&self) -> String {
+        self.project_name.to_case(Case::UpperCamel)
+    }
+
+    fn src_path(&self) -> PathBuf {
+        self.path.join(SRC_DIR)
+    }
+
+    fn write_project_files(&self, with_test_dependencies: bool) -> Result<()> {
+        create_dir_all(self.src_path())?;
+
+        self.write_csproj(with_test_dependencies)?;
+        self.write_error_class()
+    }
+
+    fn write_csproj(&self, with_test_dependencies: bool) -> Result<()> {
+        // Tests live next to the codecs, in the `tests` directory, along with the data
+        // they read at runtime.
+        let test_dependencies = [
+            "",
+            "    <ItemGroup>",
+            "        <PackageReference Include=\"Microsoft.NET.Test.Sdk\" Version=\"17.9.0\" />",
+            "        <PackageReference Include=\"xunit\" Version=\"2.7.0\" />",
+            "        <PackageReference Include=\"xunit.runner.visualstudio\" Version=\"2.5.7\" />",
+            "    </ItemGroup>",
+            "",
+            "    <ItemGroup>",
+            "        <None Include=\"tests/**/*.sbe\" CopyToOutputDirectory=\"PreserveNewest\" />",
+            "    </ItemGroup>",
+            "",
+        ]
+        .join("\n");
+
+        let csproj_content = formatdoc! {"
+                <Project Sdk=\"Microsoft.NET.Sdk\">
+
+                    <PropertyGroup>
+                        <TargetFramework>net8.0</TargetFramework>
+                        <RootNamespace>{namespace}</RootNamespace>
+                        <AssemblyName>{name}</AssemblyName>
+                        <Version>{version}</Version>
+                        <Nullable>enable</Nullable>
+                    </PropertyGroup>
+                {test_deps}
+                </Project>
+            ",
+            namespace = self.root_namespace(),
+            name = self.project_name,
+            version = self.project_version,
+            test_deps = if with_test_dependencies {
+                test_dependencies
+            } else {
+                String::new()
+            },
+        };
+
+        let csproj_path = self.path.join(format!(
+            "{}.csproj",
+            self.project_name.to_case(Case::UpperCamel)
+        ));
+        let mut csproj_file = File::create(csproj_path)?;
+        csproj_file.write_all(csproj_content.as_bytes())?;
+
+        Ok(())
+    }
+}
+
+impl CodeGenerator for CsharpGenerator {
+    fn generate_project(&self, with_test_dependencies: bool, _format_project: bool) -> Result<()> {
+        // Project files (.csproj, ...)
+        self.write_project_files(with_test_dependencies)?;
+
+        // Generate namespaces. There is no formatter for C# everyone has installed,
+        // so the project is formatted only by the generator itself.
+        let root_namespace = self.root_namespace();
+
+        for schema in &self.schemas {
+            let module_generator = module::ModuleGenerator::new(
+                &self
+                    .src_path()
+                    .join(schema.package.to_case(Case::UpperCamel)),
+                &root_namespace,
+                schema,
+                self.bounds_checks,
+            );
+
+            module_generator.generate_module()?;
+        }
+
+        Ok(())
+    }
+}
+
+impl PrimitiveConvertible<Csharp> for NativeType {
+    fn lang_primitive(
+        &self,
+        encoded_types: &BTreeMap<String, EncodedDataType>,
+    ) -> Result<LanguagePrimitive<Csharp>> {
+        let native_type = self.resolved(encoded_types)?;
+
+        // Characters are single bytes, not UTF-16 code units as the C# char
+        Ok(LanguagePrimitive::new(match native_type {
+            NativeType::Char | NativeType::UInt8 => "byte",
+            NativeType::Int8 => "sbyte",
+            NativeType::UInt16 => "ushort",
+            NativeType::Int16 => "short",
+            NativeType::UInt32 => "uint",
+            NativeType::Int32 => "int",
+            NativeType::UInt64 => "ulong",
+            NativeType::Int64 => "long",
+            NativeType::Float => "float",
+            NativeType::Double => "double",
+            NativeType::Reference(type_name) => unreachable!("Resolved reference: {}", type_name),
+        }))
+    }
+}
@@ -0,0 +1,90 @@
+use crate::generator::csharp::constants::{ERROR_CLASS_NAME, SCHEMA_CLASS_NAME};
+use crate::generator::write_file;
+use crate::models::schema::ValidatedMessageSchema;
+use anyhow::Result;
+use convert_case::{Case, Casing};
+use genco::prelude::*;
+use std::fs::create_dir_all;
+use std::path::{Path, PathBuf};
+
+pub struct ModuleGenerator<'a> {
+    pub(crate) config: csharp::Config,
+    pub(crate) path: PathBuf,
+    pub(crate) namespace: String,
+    pub(crate) root_namespace: &'a str,
+    pub(crate) schema: &'a ValidatedMessageSchema,
+    pub(crate) bounds_checks: bool,
+}
+
+impl<'a> ModuleGenerator<'a> {
+    pub fn new(
+        path: &Path,
+        root_namespace: &'a str,
+        schema: &'a ValidatedMessageSchema,
+        bounds_checks: bool,
+    ) -> Self {
+        let namespace = format!(
+            "{}.{}",
+            root_namespace,
+            schema.package.to_case(Case::UpperCamel)
+        );
+
+        Self {
+            config: csharp::Config::default().with_namespace(namespace.clone()),
+            path: path.to_owned(),
+            namespace,
+            root_namespace,
+            schema,
+            bounds_checks,
+        }
+    }
+
+    pub fn generate_module(&self) -> Result<()> {
+        create_dir_all(&self.path)?;
+
+        self.write_schema_class()?;
+
+        // Buffers the codecs read from and write to
+        self.write_read_buf_class()?;
+        self.write_write_buf_class()?;
+
+        // Type specific encoders and decoders, the ones of groups are nested in the
+        // structs of their messages
+        self.write_enum_codecs()?;
+        self.write_set_codecs()?;
+        self.write_composite_codecs()?;
+        self.write_message_codecs()
+    }
+
+    fn write_schema_class(&self) -> Result<()> {
+        let schema_class_content: Tokens<Csharp> = quote! {
+            public static class $SCHEMA_CLASS_NAME
+            {
+                public const int Id = $(self.schema.id);
+                public const int Version = $(self.schema.version);
+            }
+        };
+
+        self.write_class(SCHEMA_CLASS_NAME, schema_class_content)
+    }
+
+    pub(crate) fn write_class(&self, class_name: &str, content: Tokens<Csharp>) -> Result<()> {
+        write_file(
+            &self.path.join(format!("{}.cs", class_name)),
+            &self.config,
+            content,
+        )
+    }
+
+    /// Error raised by the codecs, shared by all schemas of the project.
+    pub(crate) fn error_class(&self) -> csharp::Import {
+        csharp::import(self.root_namespace, ERROR_CLASS_NAME)
+    }
+
+    /// Fully qualified name of a type of the schema, for the expressions in codecs, where
+    /// the name alone could refer to a method of the codec with the same name instead, e.g.
+    /// the `Model()` var data of a message with a field of the `Model` enum.
+    pub(crate) fn qualified(&self, type_name: &str) -> String {
+        format!("global::{}.{}", self.namespace, type_name)
+    }
+}
@@ -0,0 +1,632 @@
+use crate::generator::common::FieldMetadata;
+use crate::generator::go::codecs::{
+    field_metadata, get_value, not_null_condition, null_value, put_value,
+};
+use crate::generator::go::constants::COMPOSITE_FILE_NAME;
+use crate::generator::go::module::ModuleGenerator;
+use crate::generator::go::string_literal;
+use crate::models::layout::BlockLayout;
+use crate::models::types::composite_type::CompositeType;
+use crate::models::types::encoded_data_type::EncodedDataType;
+use crate::models::types::primitive_type::{NativeType, ResolvableType};
+use crate::models::types::{CharacterEncoding, Presence, SizedEncoded, Type};
+use anyhow::{anyhow, Result};
+use convert_case::{Case, Casing};
+use genco::prelude::*;
+
+impl ModuleGenerator<'_> {
+    fn generate_composite_codec(&self, composite_type: &CompositeType) -> Result<Tokens<Go>> {
+        let name = composite_type.name.to_case(Case::UpperCamel);
+        let layout = BlockLayout::from_composite(composite_type, &self.schema.types)?;
+        let encoded_length = composite_type.size(&self.schema.types)?;
+
+        let mut struct_fields: Tokens<Go> = quote!();
+        let mut constants: Tokens<Go> = quote!();
+        let mut decode_fields: Tokens<Go> = quote!();
+        let mut encode_fields: Tokens<Go> = quote!();
+        let mut null_encoders: Tokens<Go> = quote!();
+        let mut null_checks: Tokens<Go> = quote!();
+
+        for (field, offset) in composite_type.fields.iter().zip(layout.offsets) {
+            // References are named by the field, not by the referenced type
+            let field_name = field.name();
+            let field = self.resolved(field)?;
+
+            if matches!(field.presence(&self.schema.types)?, Presence::Constant) {
+                constants.line();
+                constants.append(self.generate_constant_field(&name, field_name, &field)?);
+                continue;
+            }
+
+            // The values of var data follow their length and are not part of the composite
+            if field.size(&self.schema.types)? == 0 {
+                continue;
+            }
+
+            let target = format!("c.{}", field_name.to_case(Case::UpperCamel));
+
+            struct_fields.append(self.generate_struct_field(field_name, &field, 0)?);
+            struct_fields.push();
+            decode_fields.append(self.generate_field_decode(&target, &field, offset, 0)?);
+            decode_fields.push();
+            encode_fields.append(self.generate_field_encode(&target, &field, offset, 0)?);
+            encode_fields.push();
+
+            let (null_encoder, null_check) = self.generate_null_field_codec(&field, offset)?;
+            null_encoders.append(null_encoder);
+            null_encoders.push();
+            null_checks.append(null_check);
+            null_checks.push();
+        }
+
+        let io_writer = &go::import("io", "Writer");
+        let io_reader = &go::import("io", "Reader");
+
+        Ok(quote! {
+            type $(&name) struct {
+                $struct_fields
+            }
+
+            const $(&name)EncodedLength = $encoded_length
+            $constants
+
+            $(format!("// Encode writes the {} composite to w.", composite_type.name))
+            func (c *$(&name)) Encode(w $io_writer) error {
+                b := make([]byte, $(&name)EncodedLength)
+                if err := c.encodeTo(b); err != nil {
+                    return err
+                }
+
+                _, err := w.Write(b)
+                return err
+            }
+
+            $(format!("// Decode reads the {} composite from r.", composite_type.name))
+            func (c *$(&name)) Decode(r $io_reader) error {
+                b, err := readBytes(r, $(&name)EncodedLength)
+                if err != nil {
+                    return err
+                }
+
+                return c.decodeFrom(b)
+            }
+
+            func (c *$(&name)) encodeTo(b []byte) error {
+                $encode_fields
+                return nil
+            }
+
+            func (c *$(&name)) decodeFrom(b []byte) error {
+                $decode_fields
+                return nil
+            }
+
+            $(format!("// encodeNullTo writes the null values of all the fields of the {} composite, which stand for a nil composite.", composite_type.name))
+            func ($(&name)) encodeNullTo(b []byte) {
+                $null_encoders
+            }
+
+            $(format!("// isNull checks whether all the fields of the {} composite hold their null values.", composite_type.name))
+            func ($(&name)) isNull(b []byte) bool {
+                $null_checks
+                return true
+            }
+        })
+    }
+
+    /// Generates the statement writing the null value of a field of a composite into the
+    /// block `b`, and the one returning false when the field does not hold it.
+    fn generate_null_field_codec(
+        &self,
+        field: &Type,
+        offset: usize,
+    ) -> Result<(Tokens<Go>, Tokens<Go>)> {
+        Ok(match field {
+            Type::EncodedData(encoded_type) => {
+                let metadata = field_metadata("", encoded_type, &self.schema.types)?;
+                let native_type = &metadata.field_primitive_type;
+                // The null value is assigned to a variable first, since a negative constant
+                // cannot be converted to the unsigned integer written into the block
+                let null = null_value(encoded_type, &metadata);
+
+                if metadata.field_length > 1 {
+                    let element_offset = format!("{}+idx*{}", offset, metadata.type_size);
+
+                    (
+                        quote! {
+                            {
+                                value := $null
+                                for idx := 0; idx < $(metadata.field_length); idx++ {
+                                    $(put_value(native_type, "b", &element_offset, "value"))
+                                }
+                            }
+                        },
+                        quote! {
+                            for idx := 0; idx < $(metadata.field_length); idx++ {
+                                if value := $(get_value(native_type, "b", &element_offset)); $(not_null_condition("value", encoded_type, &metadata)) {
+                                    return false
+                                }
+                            }
+                        },
+                    )
+                } else {
+                    (
+                        quote! {
+                            {
+                                value := $null
+                                $(put_value(native_type, "b", &offset.to_string(), "value"))
+                            }
+                        },
+                        quote! {
+                            if value := $(get_value(native_type, "b", &offset.to_string())); $(not_null_condition("value", encoded_type, &metadata)) {
+                                return false
+                            }
+                        },
+                    )
+                }
+            }
+            Type::Enum(enum_type) => {
+                let encoding_type = enum_type
+                    .encoding_type
+                    .resolved(&self.schema.types.encoded_types)?;
+                let enum_name = enum_type.name.to_case(Case::UpperCamel);
+                let null = format!("{}({}NullVal)", super::go_type(&encoding_type), enum_name);
+
+                (
+                    put_value(&encoding_type, "b", &offset.to_string(), &null),
+                    quote! {
+                        if $(&enum_name)($(get_value(&encoding_type, "b", &offset.to_string()))) != $(&enum_name)NullVal {
+                            return false
+                        }
+                    },
+                )
+            }
+            Type::Set(set_type) => {
+                let encoding_type = set_type
+                    .encoding_type
+                    .resolved(&self.schema.types.encoded_types)?;
+
+                (
+                    put_value(&encoding_type, "b", &offset.to_string(), "0"),
+                    quote! {
+                        if $(get_value(&encoding_type, "b", &offset.to_string())) != 0 {
+                            return false
+                        }
+                    },
+                )
+            }
+            Type::Composite(composite_type) => {
+                let composite_name = composite_type.name.to_case(Case::UpperCamel);
+
+                (
+                    quote!($(&composite_name){}.encodeNullTo(b[$offset:])),
+                    quote! {
+                        if !($(&composite_name){}).isNull(b[$offset:]) {
+                            return false
+                        }
+                    },
+                )
+            }
+            Type::Reference(_) => unreachable!("Resolved reference"),
+        })
+    }
+
+    pub fn write_composite_codecs(&self) -> Result<()> {
+        let mut composite_tokens: Tokens<Go> = quote!();
+
+        for composite_type in self.schema.types.composite_types.values() {
+            composite_tokens.line();
+            composite_tokens.append(self.generate_composite_codec(composite_type)?);
+        }
+
+        self.write_file(COMPOSITE_FILE_NAME, composite_tokens)
+    }
+
+    /// Resolves a reference to the referenced type.
+    pub(crate) fn resolved(&self, field: &Type) -> Result<Type> {
+        match field {
+            Type::Reference(reference_type) => self
+                .schema
+                .types
+                .find_type(&reference_type.type_name)
+                .ok_or(anyhow!(
+                    "Referenced type {} not found",
+                    reference_type.type_name
+                )),
+            field => Ok(field.clone()),
+        }
+    }
+
+    /// Generates the declaration of a field of a struct. A non-zero `since_version` makes
+    /// composite fields pointers, since they are missing in the data encoded using an older
+    /// version of the schema.
+    pub(crate) fn generate_struct_field(
+        &self,
+        field_name: &str,
+        field: &Type,
+        since_version: usize,
+    ) -> Result<Tokens<Go>> {
+        let field_type = match self.resolved(field)? {
+            Type::EncodedData(encoded_type) => {
+                let metadata = field_metadata(field_name, &encoded_type, &self.schema.types)?;
+
+                match encoded_type.presence {
+                    Presence::Optional => format!("*{}", value_type(&metadata)),
+                    _ => value_type(&metadata),
+                }
+            }
+            Type::Enum(enum_type) => enum_type.name.to_case(Case::UpperCamel),
+            Type::Set(set_type) => set_type.name.to_case(Case::UpperCamel),
+            Type::Composite(composite_type) if since_version > 0 => {
+                format!("*{}", composite_type.name.to_case(Case::UpperCamel))
+            }
+            Type::Composite(composite_type) => composite_type.name.to_case(Case::UpperCamel),
+            Type::Reference(_) => unreachable!("Resolved reference"),
+        };
+
+        Ok(quote!($(field_name.to_case(Case::UpperCamel)) $field_type))
+    }
+
+    /// Generates the method returning the value of a constant field of a struct.
+    pub(crate) fn generate_constant_field(
+        &self,
+        struct_name: &str,
+        field_name: &str,
+        field: &Type,
+    ) -> Result<Tokens<Go>> {
+        let encoded_type = match self.resolved(field)? {
+            Type::EncodedData(encoded_type) => encoded_type,
+            _ => return Err(anyhow!("Constant field {} is not encoded data", field_name)),
+        };
+        let metadata = field_metadata(field_name, &encoded_type, &self.schema.types)?;
+
+        let value = encoded_type.default_value.as_ref().ok_or(anyhow!(
+            "Constant field {} has no default value",
+            metadata.field_name
+        ))?;
+
+        Ok(match metadata.field_primitive_type {
+            NativeType::Char => quote! {
+                func (*$struct_name) $(&metadata.field_name)() string {
+                    return $(string_literal(value))
+                }
+            },
+            _ => quote! {
+                func (*$struct_name) $(&metadata.field_name)() $(&metadata.lang_type) {
+                    return $value
+                }
+            },
+        })
+    }
+
+    /// Generates the statements decoding a field from the block `b` into the `target`.
+    ///
+    /// A non-zero `since_version` means the field was added in that version of the schema.
+    /// The field is then decoded only if the `actingVersion` of the decoded data (only
+    /// messages and groups have one) is at least that version, otherwise the `target` is set
+    /// to the null value of the field.
+    pub(crate) fn generate_field_decode(
+        &self,
+        target: &str,
+        field: &Type,
+        offset: usize,
+        since_version: usize,
+    ) -> Result<Tokens<Go>> {
+        let field = self.resolved(field)?;
+
+        let decode = match &field {
+            Type::EncodedData(encoded_type) => {
+                self.generate_encoded_field_decode(target, encoded_type, offset)?
+            }
+            Type::Enum(enum_type) => {
+                let encoding_type = enum_type
+                    .encoding_type
+                    .resolved(&self.schema.types.encoded_types)?;
+
+                quote! {
+                    if err := decodeEnum(&$target, $(get_value(&encoding_type, "b", &offset.to_string())), $(enum_type.name.to_case(Case::UpperCamel))FromValue); err != nil {
+                        return err
+                    }
+                }
+            }
+            Type::Set(set_type) => {
+                let encoding_type = set_type
+                    .encoding_type
+                    .resolved(&self.schema.types.encoded_types)?;
+
+                quote!($target = $(set_type.name.to_case(Case::UpperCamel))($(get_value(&encoding_type, "b", &offset.to_string()))))
+            }
+            Type::Composite(composite_type) if since_version > 0 => quote! {
+                $target = nil
+                if value := new($(composite_type.name.to_case(Case::UpperCamel))); !value.isNull(b[$offset:]) {
+                    if err := value.decodeFrom(b[$offset:]); err != nil {
+                        return err
+                    }
+                    $target = value
+                }
+            },
+            Type::Composite(_) => quote! {
+                if err := $target.decodeFrom(b[$offset:]); err != nil {
+                    return err
+                }
+            },
+            Type::Reference(_) => unreachable!("Resolved reference"),
+        };
+
+        if since_version == 0 {
+            return Ok(decode);
+        }
+
+        Ok(quote! {
+            if actingVersion >= $since_version {
+                $decode
+            } else {
+                $target = $(self.absent_value(&field)?)
+            }
+        })
+    }
+
+    /// Value of a field missing in the data encoded using an older version of the schema.
+    fn absent_value(&self, field: &Type) -> Result<Tokens<Go>> {
+        Ok(match field {
+            Type::EncodedData(encoded_type) => {
+                let metadata = field_metadata("", encoded_type, &self.schema.types)?;
+
+                match (
+                    encoded_type.presence,
+                    &metadata.field_primitive_type,
+                    metadata.field_length,
+                ) {
+                    (Presence::Optional, _, _) => quote!(nil),
+                    (_, NativeType::Char, 2..) => quote!(""),
+                    (_, _, 2..) => null_array(encoded_type, &metadata),
+                    (_, _, _) => null_value(encoded_type, &metadata),
+                }
+            }
+            Type::Enum(enum_type) => {
+                quote!($(enum_type.name.to_case(Case::UpperCamel))NullVal)
+            }
+            Type::Set(set_type) => quote!($(set_type.name.to_case(Case::UpperCamel))(0)),
+            Type::Composite(_) => quote!(nil),
+            Type::Reference(_) => unreachable!("Resolved reference"),
+        })
+    }
+
+    fn generate_encoded_field_decode(
+        &self,
+        target: &str,
+        encoded_type: &EncodedDataType,
+        offset: usize,
+    ) -> Result<Tokens<Go>> {
+        let metadata = field_metadata("", encoded_type, &self.schema.types)?;
+        let native_type = &metadata.field_primitive_type;
+        let field_name = target.rsplit('.').next().unwrap_or(target);
+        let end = offset + metadata.field_length * metadata.type_size;
+        let element_offset = format!("{}+idx*{}", offset, metadata.type_size);
+
+        Ok(
+            match (encoded_type.presence, native_type, metadata.field_length) {
+                (Presence::Optional, NativeType::Char, 2..) => quote! {
+                    $target = nil
+                    if !isNullString(b[$offset:$end], $(null_value(encoded_type, &metadata))) {
+                        var value string
+                        if err := decodeString(&value, b[$offset:$end]); err != nil {
+                            return err
+                        }
+                        $target = &value
+                    }
+                },
+                (Presence::Optional, _, 2..) => quote! {
+                    $target = nil
+                    {
+                        var value $(value_type(&metadata))
+                        isNull := true
+                        for idx := range value {
+                            value[idx] = $(get_value(native_type, "b", &element_offset))
+                            isNull = isNull && !($(not_null_condition("value[idx]", encoded_type, &metadata)))
+                        }
+                        if !isNull {
+                            $target = &value
+                        }
+                    }
+                },
+                (Presence::Optional, _, _) => quote! {
+                    $target = nil
+                    if value := $(get_value(native_type, "b", &offset.to_string())); $(not_null_condition("value", encoded_type, &metadata)) {
+                        $(self.value_bounds_checks(field_name, "value", encoded_type, native_type))
+                        $target = &value
+                    }
+                },
+                (_, NativeType::Char, 2..) => quote! {
+                    if err := decodeString(&$target, b[$offset:$end]); err != nil {
+                        return err
+                    }
+                },
+                (_, _, 2..) => quote! {
+                    for idx := range $target {
+                        $target[idx] = $(get_value(native_type, "b", &element_offset))
+                        $(self.value_bounds_checks(field_name, &format!("{}[idx]", target), encoded_type, native_type))
+                    }
+                },
+                (_, _, _) => quote! {
+                    $target = $(get_value(native_type, "b", &offset.to_string()))
+                    $(self.value_bounds_checks(field_name, target, encoded_type, native_type))
+                },
+            },
+        )
+    }
+
+    /// Generates the statements encoding a field from the `target` into the block `b`. A
+    /// composite field added in a non-zero `since_version` is a pointer, encoded as the null
+    /// values of its fields if nil.
+    pub(crate) fn generate_field_encode(
+        &self,
+        target: &str,
+        field: &Type,
+        offset: usize,
+        since_version: usize,
+    ) -> Result<Tokens<Go>> {
+        Ok(match self.resolved(field)? {
+            Type::EncodedData(encoded_type) => {
+                self.generate_encoded_field_encode(target, &encoded_type, offset)?
+            }
+            Type::Enum(enum_type) => {
+                let encoding_type = enum_type
+                    .encoding_type
+                    .resolved(&self.schema.types.encoded_types)?;
+                let value = format!("{}({})", super::go_type(&encoding_type), target);
+
+                put_value(&encoding_type, "b", &offset.to_string(), &value)
+            }
+            Type::Set(set_type) => {
+                let encoding_type = set_type
+                    .encoding_type
+                    .resolved(&self.schema.types.encoded_types)?;
+                let value = format!("{}({})", super::go_type(&encoding_type), target);
+
+                put_value(&encoding_type, "b", &offset.to_string(), &value)
+            }
+            Type::Composite(composite_type) if since_version > 0 => quote! {
+                if $target != nil {
+                    if err := $target.encodeTo(b[$offset:]); err != nil {
+                        return err
+                    }
+                } else {
+                    $(composite_type.name.to_case(Case::UpperCamel)){}.encodeNullTo(b[$offset:])
+                }
+            },
+            Type::Composite(_) => quote! {
+                if err := $target.encodeTo(b[$offset:]); err != nil {
+                    return err
+                }
+            },
+            Type::Reference(_) => unreachable!("Resolved reference"),
+        })
+    }
+
+    fn generate_encoded_field_encode(
+        &self,
+        target: &str,
+        encoded_type: &EncodedDataType,
+        offset: usize,
+    ) -> Result<Tokens<Go>> {
+        let metadata = field_metadata("", encoded_type, &self.schema.types)?;
+        let native_type = &metadata.field_primitive_type;
+        let field_name = target.rsplit('.').next().unwrap_or(target);
+        let end = offset + metadata.field_length * metadata.type_size;
+        let element_offset = format!("{}+idx*{}", offset, metadata.type_size);
+
+        Ok(
+            match (encoded_type.presence, native_type, metadata.field_length) {
+                (Presence::Optional, NativeType::Char, 2..) => quote! {
+                    if $target != nil {
+                        $(self.string_encode(&metadata, field_name, &format!("*{}", target), offset, end))
+                    }$(if encoded_type.null_value.is_some() {
+                        $[' ']else {
+                            for idx := $offset; idx < $end; idx++ {
+                                b[idx] = $(null_value(encoded_type, &metadata))
+                            }
+                        }
+                    })
+                },
+                (Presence::Optional, _, 2..) => {
+                    let bounds_checks =
+                        self.encode_bounds_checks(field_name, "part", encoded_type, native_type);
+
+                    quote! {
+                        {
+                            value := $(null_array(encoded_type, &metadata))
+                            if $target != nil {
+                                value = *$target
+                                $(if !bounds_checks.is_empty() {
+                                    for _, part := range value {
+                                        $bounds_checks
+                                    }
+                                })
+                            }
+                            for idx, part := range value {
+                                $(put_value(native_type, "b", &element_offset, "part"))
+                            }
+                        }
+                    }
+                }
+                (Presence::Optional, _, _) => quote! {
+                    {
+                        value := $(null_value(encoded_type, &metadata))
+                        if $target != nil {
+                            value = *$target
+                            $(self.encode_bounds_checks(field_name, "value", encoded_type, native_type))
+                        }
+                        $(put_value(native_type, "b", &offset.to_string(), "value"))
+                    }
+                },
+                (_, NativeType::Char, 2..) => {
+                    self.string_encode(&metadata, field_name, target, offset, end)
+                }
+                (_, _, 2..) => quote! {
+                    for idx, part := range $target {
+                        $(self.encode_bounds_checks(field_name, "part", encoded_type, native_type))
+                        $(put_value(native_type, "b", &element_offset, "part"))
+                    }
+                },
+                (_, _, _) => quote! {
+                    $(self.encode_bounds_checks(field_name, target, encoded_type, native_type))
+                    $(put_value(native_type, "b", &offset.to_string(), target))
+                },
+            },
+        )
+    }
+
+    /// Checks of the `minValue` and `maxValue` of the field, unless they are disabled.
+    fn encode_bounds_checks(
+        &self,
+        field_name: &str,
+        variable: &str,
+        encoded_type: &EncodedDataType,
+        native_type: &NativeType,
+    ) -> Tokens<Go> {
+        if self.bounds_checks {
+            self.value_bounds_checks(field_name, variable, encoded_type, native_type)
+        } else {
+            quote!()
+        }
+    }
+
+    /// Encodes a string, which has to fit into the field and to be ASCII for ASCII fields.
+    fn string_encode(
+        &self,
+        metadata: &FieldMetadata<Go>,
+        field_name: &str,
+        variable: &str,
+        offset: usize,
+        end: usize,
+    ) -> Tokens<Go> {
+        quote! {
+            $(if let Some(CharacterEncoding::Ascii) = metadata.encoding {
+                if err := checkASCII($variable); err != nil {
+                    return err
+                }
+                $['\r']
+            })
+            if err := encodeString(b[$offset:$end], $(quoted(field_name.to_case(Case::Snake))), $variable); err != nil {
+                return err
+            }
+        }
+    }
+}
+
+/// Go type of the value of an encoded field, a string for character arrays.
+fn value_type(metadata: &FieldMetadata<Go>) -> String {
+    match (&metadata.field_primitive_type, metadata.field_length) {
+        (NativeType::Char, 2..) => "string".to_owned(),
+        (_, 2..) => format!("[{}]{}", metadata.field_length, metadata.lang_type.name),
+        (_, _) => metadata.lang_type.name.to_owned(),
+    }
+}
+
+/// Array of the field length filled with the null value.
+fn null_array(encoded_type: &EncodedDataType, metadata: &FieldMetadata<Go>) -> Tokens<Go> {
+    let null_value = null_value(encoded_type, metadata);
+
+    quote!($(value_type(metadata)){$(for _ in 0..metadata.field_length join (, ) => $(&null_value))})
+}
@@ -0,0 +1,88 @@
+use anyhow::Result;
+use convert_case::{Case, Casing};
+
+use crate::generator::go::codecs::null_literal;
+use crate::generator::go::constants::ENUM_FILE_NAME;
+use crate::generator::go::module::ModuleGenerator;
+use crate::models::types::enum_type::EnumType;
+use crate::models::types::primitive_type::{NativeType, PrimitiveConvertible, ResolvableType};
+use genco::prelude::*;
+
+impl ModuleGenerator<'_> {
+    fn generate_enum_codec(&self, enum_type: &EnumType) -> Result<Tokens<Go>> {
+        let name = enum_type.name.to_case(Case::UpperCamel);
+        let encoding_type = enum_type
+            .encoding_type
+            .resolved(&self.schema.types.encoded_types)?;
+        let go_type = encoding_type.lang_primitive(&self.schema.types.encoded_types)?;
+        let char_encoding = encoding_type == NativeType::Char;
+
+        let values = enum_type
+            .values
+            .iter()
+            .map(|value| {
+                Ok((
+                    format!("{}{}", name, value.name.to_case(Case::UpperCamel)),
+                    value.encoded_value(char_encoding)?,
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let null_value_name = format!("{}NullVal", name);
+
+        // A valid value equal to the null value would be a duplicate case of the switch
+        let mut valid_names = values
+            .iter()
+            .map(|(value_name, _)| value_name)
+            .collect::<Vec<_>>();
+        let null_number = null_number(&encoding_type);
+        if !values.iter().any(|(_, value)| Some(*value) == null_number) {
+            valid_names.push(&null_value_name);
+        }
+
+        Ok(quote! {
+            type $(&name) $(&go_type)
+
+            const (
+                $(for (value_name, value) in &values {
+                    $['\r']
+                    $value_name $(&name) = $(*value)
+                })
+                $(&null_value_name) $(&name) = $(null_literal(&encoding_type))
+            )
+
+            $(format!("// {}FromValue converts an encoded value to the enum, failing for the values missing in", name))
+            $("// the schema.")
+            func $(&name)FromValue(value $(&go_type)) ($(&name), error) {
+                switch $(&name)(value) {
+                case $(for value_name in valid_names join (, ) => $value_name):
+                    return $(&name)(value), nil
+                }
+
+                return $(&null_value_name), $(self.error("NewInvalidEnumValue"))($(quoted(&name)), $(go::import("fmt", "Sprint"))(value))
+            }
+        })
+    }
+
+    pub fn write_enum_codecs(&self) -> Result<()> {
+        let mut enum_tokens: Tokens<Go> = quote!();
+
+        for enum_type in self.schema.types.enum_types.values() {
+            enum_tokens.line();
+            enum_tokens.append(self.generate_enum_codec(enum_type)?);
+        }
+
+        self.write_file(ENUM_FILE_NAME, enum_tokens)
+    }
+}
+
+/// Default null value of the unsigned encoding types of enums.
+fn null_number(native_type: &NativeType) -> Option<u64> {
+    match native_type {
+        NativeType::Char => Some(0),
+        NativeType::UInt8 => Some(u8::MAX.into()),
+        NativeType::UInt16 => Some(u16::MAX.into()),
+        NativeType::UInt32 => Some(u32::MAX.into()),
+        NativeType::UInt64 => Some(u64::MAX),
+        _ => None,
+    }
+}
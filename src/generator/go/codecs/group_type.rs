@@ -0,0 +1,381 @@
+use crate::generator::common::{dimension_type, field_groups};
+use crate::generator::go::codecs::{field_metadata, max_value};
+use crate::generator::go::module::ModuleGenerator;
+use crate::models::layout::BlockLayout;
+use crate::models::types::composite_type::CompositeType;
+use crate::models::types::encoded_data_type::EncodedDataType;
+use crate::models::types::field_type::FieldType;
+use crate::models::types::group_type::GroupType;
+use crate::models::types::primitive_type::NativeType;
+use crate::models::types::{MessageField, Presence, Type};
+use crate::models::FieldPath;
+use anyhow::{anyhow, Result};
+use convert_case::{Case, Casing};
+use genco::prelude::*;
+
+/// Statements encoding and decoding the block of a message or group entry along with its
+/// groups and var data, the bodies of the `encode` and `decode` functions of its struct.
+pub(crate) struct BlockCodec {
+    pub(crate) struct_fields: Tokens<Go>,
+    pub(crate) constants: Tokens<Go>,
+    pub(crate) encode: Tokens<Go>,
+    pub(crate) decode: Tokens<Go>,
+}
+
+impl ModuleGenerator<'_> {
+    /// Generates the struct of an entry of a group, scoped by the names of its message and
+    /// enclosing groups, followed by the structs of its own groups.
+    pub(crate) fn generate_group_codec(
+        &self,
+        group: &GroupType,
+        group_path: &FieldPath,
+    ) -> Result<Tokens<Go>> {
+        let name = scoped_name(group_path);
+        let block_length_name = format!("{}BlockLength", name);
+        let (_, groups, _) = field_groups(&group.fields);
+
+        let codec = self.generate_block_codec(
+            "g",
+            &group.name,
+            &group.fields,
+            group.block_length,
+            &block_length_name,
+            group_path,
+        )?;
+        let io_writer = &go::import("io", "Writer");
+        let io_reader = &go::import("io", "Reader");
+
+        let mut group_tokens = quote! {
+            $(format!("// {} is an entry of the {} group.", name, group.name))
+            type $(&name) struct {
+                $(codec.struct_fields)
+            }
+            $(codec.constants)
+
+            func (g *$(&name)) encode(w $io_writer) error {
+                $(codec.encode)
+            }
+
+            func (g *$(&name)) decode(r $io_reader, blockLength int, actingVersion int) error {
+                $(codec.decode)
+            }
+        };
+
+        for nested_group in groups {
+            group_tokens.line();
+            group_tokens.append(
+                self.generate_group_codec(nested_group, &group_path.join(&nested_group.name))?,
+            );
+        }
+
+        Ok(group_tokens)
+    }
+
+    /// Generates the codec of the fields, groups and var data of a message or group entry.
+    /// The fields are decoded from a block of the `blockLength` read from the wire, groups
+    /// and var data follow it and are missing in the data encoded using a version of the
+    /// schema older than their `sinceVersion`.
+    pub(crate) fn generate_block_codec(
+        &self,
+        receiver: &str,
+        block_name: &str,
+        block_fields: &[MessageField],
+        block_length: Option<usize>,
+        block_length_name: &str,
+        path: &FieldPath,
+    ) -> Result<BlockCodec> {
+        let (fields, groups, var_data) = field_groups(block_fields);
+        let layout =
+            BlockLayout::from_fields(block_name, &fields, block_length, &self.schema.types)?;
+
+        let struct_name = scoped_name(path);
+        let mut struct_fields: Tokens<Go> = quote!();
+        let mut constants: Tokens<Go> = quote! {
+            $['\n']
+            const $block_length_name = $(layout.block_length)
+        };
+        let mut encode_fields: Tokens<Go> = quote!();
+        let mut decode_fields: Tokens<Go> = quote!();
+
+        for (field, offset) in fields.iter().zip(layout.offsets) {
+            let repr_type = field.to_type(&self.schema.types)?;
+
+            if matches!(field.presence, Presence::Constant) {
+                constants.line();
+                constants.append(self.generate_constant_block_field(
+                    &struct_name,
+                    field,
+                    &repr_type,
+                )?);
+                continue;
+            }
+
+            let since_version = field.since_version.unwrap_or(0);
+            let target = format!("{}.{}", receiver, field.name.to_case(Case::UpperCamel));
+
+            struct_fields.append(self.generate_struct_field(
+                &field.name,
+                &repr_type,
+                since_version,
+            )?);
+            struct_fields.push();
+            encode_fields.append(self.generate_field_encode(
+                &target,
+                &repr_type,
+                offset,
+                since_version,
+            )?);
+            encode_fields.push();
+            decode_fields.append(self.generate_field_decode(
+                &target,
+                &repr_type,
+                offset,
+                since_version,
+            )?);
+            decode_fields.push();
+        }
+
+        let mut encode_nested: Tokens<Go> = quote!();
+        let mut decode_nested: Tokens<Go> = quote!();
+
+        for group in &groups {
+            let target = format!("{}.{}", receiver, group.name.to_case(Case::UpperCamel));
+            let group_path = path.join(&group.name);
+
+            struct_fields.append(
+                quote!($(group.name.to_case(Case::UpperCamel)) []$(scoped_name(&group_path))),
+            );
+            struct_fields.push();
+            encode_nested.line();
+            encode_nested.append(self.generate_group_encode(&target, group, &group_path)?);
+            decode_nested.line();
+            decode_nested.append(self.generate_group_decode(&target, group, &group_path)?);
+        }
+
+        for var_data_field in &var_data {
+            let target = format!(
+                "{}.{}",
+                receiver,
+                var_data_field.name.to_case(Case::UpperCamel)
+            );
+
+            struct_fields.append(self.generate_var_data_struct_field(var_data_field)?);
+            struct_fields.push();
+            encode_nested.line();
+            encode_nested.append(self.generate_var_data_encode(&target, var_data_field)?);
+            decode_nested.line();
+            decode_nested.append(self.generate_var_data_decode(&target, var_data_field)?);
+        }
+
+        // A block without fields has nothing to decode, but is still read past
+        let read_block = if decode_fields.is_empty() {
+            quote! {
+                if _, err := readBlock(r, blockLength, $block_length_name); err != nil {
+                    return err
+                }
+            }
+        } else {
+            quote! {
+                b, err := readBlock(r, blockLength, $block_length_name)
+                if err != nil {
+                    return err
+                }
+
+                $decode_fields
+            }
+        };
+
+        Ok(BlockCodec {
+            struct_fields,
+            constants,
+            encode: quote! {
+                b := make([]byte, $block_length_name)
+                $encode_fields
+                if _, err := w.Write(b); err != nil {
+                    return err
+                }
+                $encode_nested
+
+                return nil
+            },
+            decode: quote! {
+                $read_block
+                $decode_nested
+
+                return nil
+            },
+        })
+    }
+
+    /// Generates the method returning the value of a constant field of a message or group
+    /// entry, referencing a value of an enum or given by the encoded type.
+    fn generate_constant_block_field(
+        &self,
+        struct_name: &str,
+        field: &FieldType,
+        repr_type: &Type,
+    ) -> Result<Tokens<Go>> {
+        let enum_type = match self.resolved(repr_type)? {
+            Type::Enum(enum_type) => enum_type,
+            _ => return self.generate_constant_field(struct_name, &field.name, repr_type),
+        };
+        let enum_type_name = enum_type.name.to_case(Case::UpperCamel);
+
+        let value_ref = field
+            .value_ref
+            .as_ref()
+            .ok_or(anyhow!("Constant field {} has no ref value", field.name))?;
+        let value_name = value_ref.split('.').nth(1).unwrap_or(value_ref);
+
+        let value = enum_type
+            .values
+            .iter()
+            .find(|value| value.name == value_name)
+            .ok_or(anyhow!(
+                "Constant field {} has no value {}",
+                field.name,
+                value_name
+            ))?;
+
+        Ok(quote! {
+            func (*$struct_name) $(field.name.to_case(Case::UpperCamel))() $(&enum_type_name) {
+                return $(&enum_type_name)$(value.name.to_case(Case::UpperCamel))
+            }
+        })
+    }
+
+    /// Encodes the entries of a group preceded by its dimension, failing if there are more
+    /// entries than the counter of the dimension can hold.
+    fn generate_group_encode(
+        &self,
+        target: &str,
+        group: &GroupType,
+        group_path: &FieldPath,
+    ) -> Result<Tokens<Go>> {
+        let dimension_type = dimension_type(group, &self.schema.types.composite_types)?;
+        let dimension_type_name = dimension_type.name.to_case(Case::UpperCamel);
+        let (block_length_name, _) = self.counter_field(dimension_type, 0)?;
+        let (num_in_group_name, num_in_group_type) = self.counter_field(dimension_type, 1)?;
+        let num_in_group_metadata =
+            field_metadata(&num_in_group_name, &num_in_group_type, &self.schema.types)?;
+
+        Ok(quote! {
+            {
+                if uint64(len($target)) > $(counter_max(&num_in_group_type, &num_in_group_metadata.field_primitive_type)) {
+                    return $(self.error("NewGroupOutOfBounds"))($(quoted(group.name.to_case(Case::Snake))))
+                }
+
+                dimension := $(&dimension_type_name){$(block_length_name.to_case(Case::UpperCamel)): $(scoped_name(group_path))BlockLength, $(&num_in_group_metadata.field_name): $(&num_in_group_metadata.lang_type)(len($target))}
+                if err := dimension.Encode(w); err != nil {
+                    return err
+                }
+
+                for idx := range $target {
+                    if err := $target[idx].encode(w); err != nil {
+                        return err
+                    }
+                }
+            }
+        })
+    }
+
+    /// Decodes the entries of a group, which is left empty if the group is missing in the
+    /// decoded data.
+    fn generate_group_decode(
+        &self,
+        target: &str,
+        group: &GroupType,
+        group_path: &FieldPath,
+    ) -> Result<Tokens<Go>> {
+        let dimension_type = dimension_type(group, &self.schema.types.composite_types)?;
+        let (block_length_name, _) = self.counter_field(dimension_type, 0)?;
+        let (num_in_group_name, _) = self.counter_field(dimension_type, 1)?;
+
+        let decode = quote! {
+            var dimension $(dimension_type.name.to_case(Case::UpperCamel))
+            if err := dimension.Decode(r); err != nil {
+                return err
+            }
+
+            $target = make([]$(scoped_name(group_path)), dimension.$(num_in_group_name.to_case(Case::UpperCamel)))
+            for idx := range $target {
+                if err := $target[idx].decode(r, int(dimension.$(block_length_name.to_case(Case::UpperCamel))), actingVersion); err != nil {
+                    return err
+                }
+            }
+        };
+
+        Ok(since_version_guard(
+            target,
+            group.since_version.unwrap_or(0),
+            decode,
+            quote!(nil),
+        ))
+    }
+
+    /// Returns the name and the encoded type of the counter at the `index` of the dimension
+    /// of a group or the encoding of var data.
+    pub(crate) fn counter_field(
+        &self,
+        composite_type: &CompositeType,
+        index: usize,
+    ) -> Result<(String, EncodedDataType)> {
+        let field = composite_type.fields.get(index).ok_or(anyhow!(
+            "Missing field {} in composite '{}'",
+            index,
+            composite_type.name
+        ))?;
+
+        match self.resolved(field)? {
+            Type::EncodedData(encoded_type) => Ok((field.name().to_owned(), encoded_type)),
+            _ => Err(anyhow!(
+                "Only encoded data type expected for field '{}' in composite '{}'",
+                field.name(),
+                composite_type.name
+            )),
+        }
+    }
+}
+
+/// Name of the struct of a message or group entry, made of the names of the message and
+/// the groups enclosing it.
+pub(crate) fn scoped_name(path: &FieldPath) -> String {
+    path.segments()
+        .iter()
+        .map(|segment| segment.to_case(Case::UpperCamel))
+        .collect()
+}
+
+/// Largest count of a counter of groups or var data, the `maxValue` of its type if declared.
+pub(crate) fn counter_max(encoded_type: &EncodedDataType, native_type: &NativeType) -> Tokens<Go> {
+    match &encoded_type.max_value {
+        Some(max) => quote!($max),
+        None => max_value(native_type),
+    }
+}
+
+/// Wraps the decoding of groups and var data into a block, decoding them only from data
+/// encoded using a version of the schema at least the `since_version`. Otherwise the
+/// `target` is set to the `absent` value.
+pub(crate) fn since_version_guard(
+    target: &str,
+    since_version: usize,
+    decode: Tokens<Go>,
+    absent: Tokens<Go>,
+) -> Tokens<Go> {
+    if since_version == 0 {
+        return quote! {
+            {
+                $decode
+            }
+        };
+    }
+
+    quote! {
+        if actingVersion >= $since_version {
+            $decode
+        } else {
+            $target = $absent
+        }
+    }
+}
@@ -0,0 +1,163 @@
+use crate::generator::common::{field_groups, header_counts};
+use crate::generator::go::codecs::group_type::scoped_name;
+use crate::generator::go::constants::{DISPATCHER_FILE_NAME, MESSAGE_FILE_NAME};
+use crate::generator::go::module::ModuleGenerator;
+use crate::models::message::MessageType;
+use crate::models::FieldPath;
+use anyhow::Result;
+use convert_case::{Case, Casing};
+use genco::prelude::*;
+
+impl ModuleGenerator<'_> {
+    /// Generates the struct of a message, followed by the structs of the entries of its
+    /// groups.
+    fn generate_message_codec(&self, message: &MessageType) -> Result<Tokens<Go>> {
+        let message_path = FieldPath::new(vec![message.name.clone()]);
+        let name = scoped_name(&message_path);
+        let (_, groups, var_data) = field_groups(&message.fields);
+        let header_counts = header_counts(&self.schema.types, groups.len(), var_data.len());
+
+        let codec = self.generate_block_codec(
+            "m",
+            &message.name,
+            &message.fields,
+            message.block_length,
+            &format!("{}BlockLength", name),
+            &message_path,
+        )?;
+        let header_type_name = self.header_type_name();
+        let io_writer = &go::import("io", "Writer");
+        let io_reader = &go::import("io", "Reader");
+
+        let mut message_tokens = quote! {
+            $(format!("// {} is the {} message.", name, message.name))
+            type $(&name) struct {
+                $(codec.struct_fields)
+            }
+
+            const $(&name)TemplateID = $(message.id)
+            $(codec.constants)
+
+            func (*$(&name)) TemplateID() uint16 {
+                return $(&name)TemplateID
+            }
+
+            $(format!("// Encode writes the {} message preceded by its header to w.", message.name))
+            func (m *$(&name)) Encode(w $io_writer) error {
+                header := $(&header_type_name){BlockLength: $(&name)BlockLength, TemplateId: $(&name)TemplateID, SchemaId: SchemaID, Version: SchemaVersion$(for (field_name, count) in &header_counts => , $(field_name.to_case(Case::UpperCamel)): $(*count))}
+                if err := header.Encode(w); err != nil {
+                    return err
+                }
+
+                return m.encodeBody(w)
+            }
+
+            $(format!("// Decode reads the {} message preceded by its header from r.", message.name))
+            func (m *$(&name)) Decode(r $io_reader) error {
+                var header $(&header_type_name)
+                if err := header.Decode(r); err != nil {
+                    return err
+                }
+
+                if header.SchemaId != SchemaID {
+                    return $(self.error("NewWrongSchema"))(uint64(header.SchemaId), SchemaID)
+                }
+
+                if header.TemplateId != $(&name)TemplateID {
+                    return $(self.error("NewWrongMessageType"))(uint16(header.TemplateId), $(&name)TemplateID)
+                }
+
+                return m.decodeBody(r, int(header.BlockLength), int(header.Version))
+            }
+
+            func (m *$(&name)) encodeBody(w $io_writer) error {
+                $(codec.encode)
+            }
+
+            func (m *$(&name)) decodeBody(r $io_reader, blockLength int, actingVersion int) error {
+                $(codec.decode)
+            }
+        };
+
+        for group in groups {
+            message_tokens.line();
+            message_tokens
+                .append(self.generate_group_codec(group, &message_path.join(&group.name))?);
+        }
+
+        Ok(message_tokens)
+    }
+
+    pub fn write_message_codecs(&self) -> Result<()> {
+        let mut message_tokens: Tokens<Go> = quote!();
+
+        for message in self.schema.message_types.message_types.values() {
+            message_tokens.line();
+            message_tokens.append(self.generate_message_codec(message)?);
+        }
+
+        self.write_file(MESSAGE_FILE_NAME, message_tokens)?;
+        self.write_message_dispatcher()
+    }
+
+    /// Writes the decoder of any message of the schema, which checks the schema id and picks
+    /// the message by the template id in the message header.
+    fn write_message_dispatcher(&self) -> Result<()> {
+        let header_type_name = self.header_type_name();
+        let names = self
+            .schema
+            .message_types
+            .message_types
+            .values()
+            .map(|message| message.name.to_case(Case::UpperCamel))
+            .collect::<Vec<_>>();
+
+        let dispatcher_tokens: Tokens<Go> = quote! {
+            $("// Message is any message of the schema.")
+            type Message interface {
+                TemplateID() uint16
+                Encode(w $(go::import("io", "Writer"))) error
+            }
+
+            $("// DecodeMessage reads any message of the schema preceded by its header from r, picking")
+            $("// the message by the template id in the header, and fails for messages of other schemas.")
+            func DecodeMessage(r $(go::import("io", "Reader"))) (Message, error) {
+                var header $(&header_type_name)
+                if err := header.Decode(r); err != nil {
+                    return nil, err
+                }
+
+                var message interface {
+                    Message
+                    decodeBody(r $(go::import("io", "Reader")), blockLength int, actingVersion int) error
+                }
+
+                if header.SchemaId != SchemaID {
+                    return nil, $(self.error("NewWrongSchema"))(uint64(header.SchemaId), SchemaID)
+                }
+
+                switch header.TemplateId {
+                $(for name in &names {
+                    $['\r']
+                    case $(name)TemplateID:
+                        message = new($name)
+                })
+                default:
+                    return nil, $(self.error("NewUnknownMessageType"))(uint16(header.TemplateId))
+                }
+
+                if err := message.decodeBody(r, int(header.BlockLength), int(header.Version)); err != nil {
+                    return nil, err
+                }
+
+                return message, nil
+            }
+        };
+
+        self.write_file(DISPATCHER_FILE_NAME, dispatcher_tokens)
+    }
+
+    fn header_type_name(&self) -> String {
+        self.schema.types.header_type.name.to_case(Case::UpperCamel)
+    }
+}
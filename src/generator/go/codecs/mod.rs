@@ -0,0 +1,209 @@
+mod composite_type;
+mod enum_type;
+mod group_type;
+mod message_type;
+mod set_type;
+mod var_data_type;
+
+use crate::generator::common::FieldMetadata;
+use crate::generator::go::module::ModuleGenerator;
+use crate::models::types::encoded_data_type::EncodedDataType;
+use crate::models::types::primitive_type::{NativeType, PrimitiveConvertible, ResolvableType};
+use crate::models::types::SizedEncoded;
+use crate::models::TypeMap;
+use anyhow::Result;
+use convert_case::{Case, Casing};
+use genco::prelude::*;
+
+/// Metadata of an encoded field, with the field name in upper camel case of exported struct
+/// fields.
+pub(crate) fn field_metadata(
+    field_name: &str,
+    encoded_type: &EncodedDataType,
+    types: &TypeMap,
+) -> Result<FieldMetadata<Go>> {
+    let field_primitive_type = encoded_type.primitive_type.resolved(&types.encoded_types)?;
+    let field_size = encoded_type.size(types)?;
+    let type_size = encoded_type.primitive_type.size(types)?;
+
+    Ok(FieldMetadata {
+        field_name: field_name.to_case(Case::UpperCamel),
+        field_primitive_type: field_primitive_type.clone(),
+        type_size,
+        field_length: field_size / type_size,
+        lang_type: field_primitive_type.lang_primitive(&types.encoded_types)?,
+        encoding: encoded_type.character_encoding,
+    })
+}
+
+/// Go type of a resolved primitive type.
+pub(crate) fn go_type(native_type: &NativeType) -> &'static str {
+    match native_type {
+        NativeType::Char => "byte",
+        NativeType::UInt8 => "uint8",
+        NativeType::UInt16 => "uint16",
+        NativeType::UInt32 => "uint32",
+        NativeType::UInt64 => "uint64",
+        NativeType::Int8 => "int8",
+        NativeType::Int16 => "int16",
+        NativeType::Int32 => "int32",
+        NativeType::Int64 => "int64",
+        NativeType::Float => "float32",
+        NativeType::Double => "float64",
+        NativeType::Reference(type_name) => unreachable!("Resolved reference: {}", type_name),
+    }
+}
+
+/// Untyped default null value of a primitive type, see `NativeType::null`.
+pub(crate) fn null_literal(native_type: &NativeType) -> Tokens<Go> {
+    let math_constant = |name: &str| quote!($(go::import("math", name)));
+
+    match native_type {
+        NativeType::Char => quote!(0),
+        NativeType::UInt8 => math_constant("MaxUint8"),
+        NativeType::UInt16 => math_constant("MaxUint16"),
+        NativeType::UInt32 => math_constant("MaxUint32"),
+        NativeType::UInt64 => math_constant("MaxUint64"),
+        NativeType::Int8 => math_constant("MinInt8"),
+        NativeType::Int16 => math_constant("MinInt16"),
+        NativeType::Int32 => math_constant("MinInt32"),
+        NativeType::Int64 => math_constant("MinInt64"),
+        _ => quote!($(go::import("math", "NaN"))()),
+    }
+}
+
+/// Largest value of an integer primitive type, used for the counters of groups and var data.
+pub(crate) fn max_value(native_type: &NativeType) -> Tokens<Go> {
+    let name = match native_type {
+        NativeType::UInt8 => "MaxUint8",
+        NativeType::UInt16 => "MaxUint16",
+        NativeType::UInt32 => "MaxUint32",
+        NativeType::UInt64 => "MaxUint64",
+        NativeType::Int8 => "MaxInt8",
+        NativeType::Int16 => "MaxInt16",
+        NativeType::Int32 => "MaxInt32",
+        _ => "MaxInt64",
+    };
+
+    quote!($(go::import("math", name)))
+}
+
+/// Null value of an optional field typed as the field, either declared by the `nullValue` of
+/// the encoded type, or the default one for its primitive type.
+pub(crate) fn null_value(
+    encoded_type: &EncodedDataType,
+    metadata: &FieldMetadata<Go>,
+) -> Tokens<Go> {
+    match &encoded_type.null_value {
+        Some(null_value) => quote!($(&metadata.lang_type)($null_value)),
+        None => quote!($(&metadata.lang_type)($(null_literal(&metadata.field_primitive_type)))),
+    }
+}
+
+/// Condition of a `variable` holding a value, which is not the null value of the field.
+pub(crate) fn not_null_condition(
+    variable: &str,
+    encoded_type: &EncodedDataType,
+    metadata: &FieldMetadata<Go>,
+) -> Tokens<Go> {
+    match (&metadata.field_primitive_type, &encoded_type.null_value) {
+        (NativeType::Float | NativeType::Double, None) => {
+            quote!(!$(go::import("math", "IsNaN"))(float64($variable)))
+        }
+        _ => quote!($variable != $(null_value(encoded_type, metadata))),
+    }
+}
+
+/// Expression reading a value of a resolved primitive type from the `buffer` at the `offset`.
+pub(crate) fn get_value(native_type: &NativeType, buffer: &str, offset: &str) -> Tokens<Go> {
+    match native_type {
+        NativeType::Char | NativeType::UInt8 => quote!($buffer[$offset]),
+        NativeType::Int8 => quote!(int8($buffer[$offset])),
+        NativeType::UInt16 => quote!(byteOrder.Uint16($buffer[$offset:])),
+        NativeType::UInt32 => quote!(byteOrder.Uint32($buffer[$offset:])),
+        NativeType::UInt64 => quote!(byteOrder.Uint64($buffer[$offset:])),
+        NativeType::Int16 => quote!(int16(byteOrder.Uint16($buffer[$offset:]))),
+        NativeType::Int32 => quote!(int32(byteOrder.Uint32($buffer[$offset:]))),
+        NativeType::Int64 => quote!(int64(byteOrder.Uint64($buffer[$offset:]))),
+        NativeType::Float => {
+            quote!($(go::import("math", "Float32frombits"))(byteOrder.Uint32($buffer[$offset:])))
+        }
+        NativeType::Double => {
+            quote!($(go::import("math", "Float64frombits"))(byteOrder.Uint64($buffer[$offset:])))
+        }
+        NativeType::Reference(type_name) => unreachable!("Resolved reference: {}", type_name),
+    }
+}
+
+/// Statement writing a `value` of a resolved primitive type into the `buffer` at the `offset`.
+pub(crate) fn put_value(
+    native_type: &NativeType,
+    buffer: &str,
+    offset: &str,
+    value: impl FormatInto<Go>,
+) -> Tokens<Go> {
+    match native_type {
+        NativeType::Char | NativeType::UInt8 => quote!($buffer[$offset] = $value),
+        NativeType::Int8 => quote!($buffer[$offset] = byte($value)),
+        NativeType::UInt16 => quote!(byteOrder.PutUint16($buffer[$offset:], $value)),
+        NativeType::UInt32 => quote!(byteOrder.PutUint32($buffer[$offset:], $value)),
+        NativeType::UInt64 => quote!(byteOrder.PutUint64($buffer[$offset:], $value)),
+        NativeType::Int16 => quote!(byteOrder.PutUint16($buffer[$offset:], uint16($value))),
+        NativeType::Int32 => quote!(byteOrder.PutUint32($buffer[$offset:], uint32($value))),
+        NativeType::Int64 => quote!(byteOrder.PutUint64($buffer[$offset:], uint64($value))),
+        NativeType::Float => quote! {
+            byteOrder.PutUint32($buffer[$offset:], $(go::import("math", "Float32bits"))($value))
+        },
+        NativeType::Double => quote! {
+            byteOrder.PutUint64($buffer[$offset:], $(go::import("math", "Float64bits"))($value))
+        },
+        NativeType::Reference(type_name) => unreachable!("Resolved reference: {}", type_name),
+    }
+}
+
+impl ModuleGenerator<'_> {
+    /// Checks of a `variable` against the `minValue` and `maxValue` of the encoded type.
+    pub(crate) fn value_bounds_checks(
+        &self,
+        field_name: &str,
+        variable: &str,
+        encoded_type: &EncodedDataType,
+        native_type: &NativeType,
+    ) -> Tokens<Go> {
+        let mut checks = quote!();
+        let unsigned = matches!(
+            native_type,
+            NativeType::UInt8 | NativeType::UInt16 | NativeType::UInt32 | NativeType::UInt64
+        );
+
+        if let Some(min) = &encoded_type.min_value {
+            // Unsigned values can never be below zero
+            if !(unsigned && min.parse::<u64>() == Ok(0)) {
+                checks.append(self.bounds_check(field_name, variable, "<", min, "min"));
+                checks.push();
+            }
+        }
+
+        if let Some(max) = &encoded_type.max_value {
+            checks.append(self.bounds_check(field_name, variable, ">", max, "max"));
+            checks.push();
+        }
+
+        checks
+    }
+
+    fn bounds_check(
+        &self,
+        field_name: &str,
+        variable: &str,
+        operator: &str,
+        limit: &str,
+        limit_name: &str,
+    ) -> Tokens<Go> {
+        quote! {
+            if $variable $operator $limit {
+                return $(self.error("NewValueOutOfBounds"))($(quoted(field_name.to_case(Case::Snake))), $(go::import("fmt", "Sprintf"))($(quoted(format!("%v {} {} ({})", operator, limit, limit_name))), $variable))
+            }
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use anyhow::Result;
+use convert_case::{Case, Casing};
+
+use crate::generator::go::constants::SET_FILE_NAME;
+use crate::generator::go::module::ModuleGenerator;
+use crate::models::types::primitive_type::PrimitiveConvertible;
+use crate::models::types::set_type::{Choice, SetType};
+use genco::prelude::*;
+
+impl ModuleGenerator<'_> {
+    fn generate_choice_token(name: &str, choice: &Choice) -> Tokens<Go> {
+        let choice_name = choice.name.to_case(Case::UpperCamel);
+
+        quote! {
+            $['\n']
+            func (s $name) $(&choice_name)() bool {
+                return s&(1<<$(choice.value)) != 0
+            }
+
+            func (s $name) Set$(&choice_name)(value bool) $name {
+                if value {
+                    return s | 1<<$(choice.value)
+                }
+
+                return s &^ (1 << $(choice.value))
+            }
+        }
+    }
+
+    fn generate_set_codec(&self, set_type: &SetType) -> Result<Tokens<Go>> {
+        let name = set_type.name.to_case(Case::UpperCamel);
+        let go_type = set_type
+            .encoding_type
+            .lang_primitive(&self.schema.types.encoded_types)?;
+
+        Ok(quote! {
+            type $(&name) $(&go_type)
+            $(for choice in &set_type.choices => $(Self::generate_choice_token(&name, choice)))
+        })
+    }
+
+    pub fn write_set_codecs(&self) -> Result<()> {
+        let mut set_tokens: Tokens<Go> = quote!();
+
+        for set_type in self.schema.types.set_types.values() {
+            set_tokens.line();
+            set_tokens.append(self.generate_set_codec(set_type)?);
+        }
+
+        self.write_file(SET_FILE_NAME, set_tokens)
+    }
+}
@@ -0,0 +1,166 @@
+use crate::generator::common::FieldMetadata;
+use crate::generator::go::codecs::group_type::{counter_max, since_version_guard};
+use crate::generator::go::codecs::{field_metadata, get_value, put_value};
+use crate::generator::go::module::ModuleGenerator;
+use crate::models::types::primitive_type::NativeType;
+use crate::models::types::variable_data_type::VariableDataType;
+use crate::models::types::{CharacterEncoding, Type};
+use anyhow::{anyhow, Result};
+use convert_case::{Case, Casing};
+use genco::prelude::*;
+
+impl ModuleGenerator<'_> {
+    /// Generates the field holding var data, a string for character encoded var data, a
+    /// slice of its values otherwise.
+    pub(crate) fn generate_var_data_struct_field(
+        &self,
+        var_data: &VariableDataType,
+    ) -> Result<Tokens<Go>> {
+        let value_metadata = self.var_data_value_metadata(var_data)?;
+
+        Ok(quote!($(var_data.name.to_case(Case::UpperCamel)) $(value_type(&value_metadata))))
+    }
+
+    /// Encodes var data preceded by its length, failing if it is longer than the length can
+    /// hold.
+    pub(crate) fn generate_var_data_encode(
+        &self,
+        target: &str,
+        var_data: &VariableDataType,
+    ) -> Result<Tokens<Go>> {
+        let repr_type = var_data.repr_type(&self.schema.types.composite_types)?;
+        let (length_name, length_type) = self.counter_field(repr_type, 0)?;
+        let length_metadata = field_metadata(&length_name, &length_type, &self.schema.types)?;
+        let value_metadata = self.var_data_value_metadata(var_data)?;
+        let value_native = &value_metadata.field_primitive_type;
+        let value_size = value_metadata.type_size;
+
+        let write_values = match (value_metadata.encoding.is_some(), value_native) {
+            (true, _) => quote! {
+                $(if let Some(CharacterEncoding::Ascii) = value_metadata.encoding {
+                    if err := checkASCII($target); err != nil {
+                        return err
+                    }
+                    $['\r']
+                })
+                if _, err := $(go::import("io", "WriteString"))(w, $target); err != nil {
+                    return err
+                }
+            },
+            (false, NativeType::Char | NativeType::UInt8) => quote! {
+                if _, err := w.Write($target); err != nil {
+                    return err
+                }
+            },
+            (false, _) => quote! {
+                data := make([]byte, len($target)*$value_size)
+                for idx, part := range $target {
+                    $(put_value(value_native, "data", &format!("idx*{}", value_size), "part"))
+                }
+
+                if _, err := w.Write(data); err != nil {
+                    return err
+                }
+            },
+        };
+
+        Ok(quote! {
+            {
+                if uint64(len($target)) > $(counter_max(&length_type, &length_metadata.field_primitive_type)) {
+                    return $(self.error("NewVarDataOutOfBounds"))($(quoted(var_data.name.to_case(Case::Snake))))
+                }
+
+                length := $(repr_type.name.to_case(Case::UpperCamel)){$(&length_metadata.field_name): $(&length_metadata.lang_type)(len($target))}
+                if err := length.Encode(w); err != nil {
+                    return err
+                }
+
+                $write_values
+            }
+        })
+    }
+
+    /// Decodes var data following its length, which is left empty if the var data is missing
+    /// in the decoded data.
+    pub(crate) fn generate_var_data_decode(
+        &self,
+        target: &str,
+        var_data: &VariableDataType,
+    ) -> Result<Tokens<Go>> {
+        let repr_type = var_data.repr_type(&self.schema.types.composite_types)?;
+        let (length_name, _) = self.counter_field(repr_type, 0)?;
+        let value_metadata = self.var_data_value_metadata(var_data)?;
+        let value_native = &value_metadata.field_primitive_type;
+        let value_size = value_metadata.type_size;
+        let is_string = value_metadata.encoding.is_some();
+
+        let read_values = match (is_string, value_native) {
+            (true, _) => quote! {
+                if err := decodeString(&$target, data); err != nil {
+                    return err
+                }
+                $(if let Some(CharacterEncoding::Ascii) = value_metadata.encoding {
+                    $['\r']
+                    if err := checkASCII($target); err != nil {
+                        return err
+                    }
+                })
+            },
+            (false, NativeType::Char | NativeType::UInt8) => quote!($target = data),
+            (false, _) => quote! {
+                $target = make($(value_type(&value_metadata)), len(data)/$value_size)
+                for idx := range $target {
+                    $target[idx] = $(get_value(value_native, "data", &format!("idx*{}", value_size)))
+                }
+            },
+        };
+
+        let decode = quote! {
+            var length $(repr_type.name.to_case(Case::UpperCamel))
+            if err := length.Decode(r); err != nil {
+                return err
+            }
+
+            data, err := readBytes(r, int(length.$(length_name.to_case(Case::UpperCamel)))*$value_size)
+            if err != nil {
+                return err
+            }
+
+            $read_values
+        };
+        let absent = if is_string { quote!("") } else { quote!(nil) };
+
+        Ok(since_version_guard(
+            target,
+            var_data.since_version.unwrap_or(0),
+            decode,
+            absent,
+        ))
+    }
+
+    fn var_data_value_metadata(&self, var_data: &VariableDataType) -> Result<FieldMetadata<Go>> {
+        let repr_type = var_data.repr_type(&self.schema.types.composite_types)?;
+
+        match repr_type.fields.get(1).map(|field| self.resolved(field)) {
+            Some(Ok(Type::EncodedData(value_type))) => {
+                field_metadata(&var_data.name, &value_type, &self.schema.types)
+            }
+            _ => Err(anyhow!(
+                "Only encoded data type expected for the value type in variable data encoding '{}'",
+                var_data.name
+            )),
+        }
+    }
+}
+
+/// Go type of var data, a string for character encoded var data.
+fn value_type(value_metadata: &FieldMetadata<Go>) -> String {
+    match (
+        &value_metadata.encoding,
+        &value_metadata.field_primitive_type,
+    ) {
+        (Some(_), _) => "string".to_owned(),
+        (None, NativeType::Char | NativeType::UInt8) => "[]byte".to_owned(),
+        (None, _) => format!("[]{}", value_metadata.lang_type.name),
+    }
+}
@@ -0,0 +1,9 @@
+pub const GO_VERSION: &str = "1.21";
+pub const ERROR_FILE_NAME: &str = "errors.go";
+pub const SCHEMA_FILE_NAME: &str = "schema.go";
+pub const BUFFER_FILE_NAME: &str = "buffer.go";
+pub const ENUM_FILE_NAME: &str = "enums.go";
+pub const SET_FILE_NAME: &str = "sets.go";
+pub const COMPOSITE_FILE_NAME: &str = "composites.go";
+pub const MESSAGE_FILE_NAME: &str = "messages.go";
+pub const DISPATCHER_FILE_NAME: &str = "dispatcher.go";
@@ -0,0 +1,102 @@
+use crate::generator::go::constants::ERROR_FILE_NAME;
+use crate::generator::go::{string_literal, write_go_file, GoGenerator};
+use anyhow::Result;
+use genco::prelude::*;
+
+impl GoGenerator {
+    /// Writes the errors of the codecs into the root package. They mirror the `SbeError` of
+    /// the Rust codecs, without the kinds caused by decoding or encoding the groups and var
+    /// data of a flyweight out of order, as the Go codecs always handle whole messages.
+    pub fn write_error_file(&self) -> Result<()> {
+        let fmt_sprintf = &go::import("fmt", "Sprintf");
+
+        let error_file_content: Tokens<Go> = quote! {
+            $("// ErrorKind tells apart the errors of encoding or decoding a message.")
+            type ErrorKind int
+
+            const (
+                InvalidStringValue ErrorKind = iota
+                InvalidEnumValue
+                ValueOutOfBounds
+                GroupOutOfBounds
+                VarDataOutOfBounds
+                WrongSchema
+                WrongMessageType
+                UnknownMessageType
+                CodecOutOfBounds
+            )
+
+            $("// SbeError is the error of encoding or decoding a message.")
+            type SbeError struct {
+                Kind ErrorKind
+                Message string
+            }
+
+            func (e *SbeError) Error() string {
+                return e.Message
+            }
+
+            $("// Is matches the errors of the same kind, so errors.Is(err, ErrUnknownMessageType)")
+            $("// holds for any unknown message type.")
+            func (e *SbeError) Is(target error) bool {
+                other, ok := target.(*SbeError)
+                return ok && other.Kind == e.Kind
+            }
+
+            $("// Sentinel errors of every kind, to be matched with errors.Is.")
+            var (
+                ErrInvalidStringValue = &SbeError{Kind: InvalidStringValue, Message: "invalid string value"}
+                ErrInvalidEnumValue = &SbeError{Kind: InvalidEnumValue, Message: "invalid enum value"}
+                ErrValueOutOfBounds = &SbeError{Kind: ValueOutOfBounds, Message: "value out of bounds"}
+                ErrGroupOutOfBounds = &SbeError{Kind: GroupOutOfBounds, Message: "group out of bounds"}
+                ErrVarDataOutOfBounds = &SbeError{Kind: VarDataOutOfBounds, Message: "var data out of bounds"}
+                ErrWrongSchema = &SbeError{Kind: WrongSchema, Message: "wrong schema"}
+                ErrWrongMessageType = &SbeError{Kind: WrongMessageType, Message: "wrong message type"}
+                ErrUnknownMessageType = &SbeError{Kind: UnknownMessageType, Message: "unknown message type"}
+                ErrCodecOutOfBounds = &SbeError{Kind: CodecOutOfBounds, Message: "codec out of bounds"}
+            )
+
+            func NewInvalidStringValue(value string) error {
+                return &SbeError{Kind: InvalidStringValue, Message: "invalid ascii string: " + value}
+            }
+
+            func NewInvalidEnumValue(typeName string, value string) error {
+                return &SbeError{Kind: InvalidEnumValue, Message: $fmt_sprintf($(string_literal("invalid enum value for '%s': %s")), typeName, value)}
+            }
+
+            func NewValueOutOfBounds(fieldName string, message string) error {
+                return &SbeError{Kind: ValueOutOfBounds, Message: $fmt_sprintf($(string_literal("value out of bounds for field '%s': %s")), fieldName, message)}
+            }
+
+            func NewGroupOutOfBounds(groupName string) error {
+                return &SbeError{Kind: GroupOutOfBounds, Message: "group out of bounds: " + groupName}
+            }
+
+            func NewVarDataOutOfBounds(varDataName string) error {
+                return &SbeError{Kind: VarDataOutOfBounds, Message: "var data out of bounds: " + varDataName}
+            }
+
+            func NewWrongSchema(schemaID uint64, expectedSchemaID uint64) error {
+                return &SbeError{Kind: WrongSchema, Message: $fmt_sprintf("received message had wrong schema: %d, expected %d", schemaID, expectedSchemaID)}
+            }
+
+            func NewWrongMessageType(templateID uint16, expectedTemplateID uint16) error {
+                return &SbeError{Kind: WrongMessageType, Message: $fmt_sprintf("received message had wrong type: %d, expected %d", templateID, expectedTemplateID)}
+            }
+
+            func NewUnknownMessageType(templateID uint16) error {
+                return &SbeError{Kind: UnknownMessageType, Message: $fmt_sprintf("received message had unknown type: %d", templateID)}
+            }
+
+            func NewCodecOutOfBounds(dataEnd int, length int) error {
+                return &SbeError{Kind: CodecOutOfBounds, Message: $fmt_sprintf("codec out of bounds: %d > %d", dataEnd, length)}
+            }
+        };
+
+        write_go_file(
+            &self.path.join(ERROR_FILE_NAME),
+            &self.module_path(),
+            error_file_content,
+        )
+    }
+}
@@ -0,0 +1,148 @@
+mod codecs;
+mod constants;
+mod error;
+mod module;
+
+use crate::generator::CodeGenerator;
+use crate::models::schema::ValidatedMessageSchema;
+use anyhow::Result;
+use convert_case::{Case, Casing};
+use genco::fmt;
+use genco::fmt::IoWriter;
+use indoc::formatdoc;
+use std::collections::BTreeMap;
+use std::fs::{create_dir_all, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use crate::generator::go::constants::GO_VERSION;
+use crate::models::types::encoded_data_type::EncodedDataType;
+use crate::models::types::primitive_type::{
+    LanguagePrimitive, NativeType, PrimitiveConvertible, ResolvableType,
+};
+use genco::prelude::*;
+
+pub struct GoGenerator {
+    path: PathBuf,
+    project_name: String,
+    bounds_checks: bool,
+    schemas: Vec<ValidatedMessageSchema>,
+}
+
+impl GoGenerator {
+    pub fn new(
+        path: &Path,
+        project_name: &str,
+        schemas: Vec<ValidatedMessageSchema>,
+        bounds_checks: bool,
+    ) -> Self {
+        Self {
+            path: path.to_owned(),
+            project_name: project_name.to_owned(),
+            bounds_checks,
+            schemas,
+        }
+    }
+
+    /// Path of the Go module, which is also the name of its root package holding the errors
+    /// shared by the packages of all schemas.
+    fn module_path(&self) -> String {
+        self.project_name.to_case(Case::Snake)
+    }
+
+    fn write_project_files(&self) -> Result<()> {
+        create_dir_all(&self.path)?;
+
+        self.write_go_mod()?;
+        self.write_error_file()
+    }
+
+    fn write_go_mod(&self) -> Result<()> {
+        let go_mod_content = formatdoc! {"
+                module {module_path}
+
+                go {go_version}
+            ",
+            module_path = self.module_path(),
+            go_version = GO_VERSION,
+        };
+
+        let go_mod_path = self.path.join("go.mod");
+        let mut go_mod_file = File::create(go_mod_path)?;
+        go_mod_file.write_all(go_mod_content.as_bytes())?;
+
+        Ok(())
+    }
+
+    fn format_project(&self) {
+        // It was decided to ignore the possible failure of formatting.
+        // If user did not install Go, this will fail, but it is not critical.
+        let fmt_result = Command::new("gofmt")
+            .arg("-w")
+            .arg(".")
+            .current_dir(&self.path)
+            .status();
+
+        if !matches!(fmt_result, Ok(status) if status.success()) {
+            eprintln!("Failed to format project. Do you have gofmt installed?");
+        }
+    }
+}
+
+impl CodeGenerator for GoGenerator {
+    fn generate_project(&self, _with_test_dependencies: bool, format_project: bool) -> Result<()> {
+        // Project files (go.mod, ...). Tests only need the standard library, so there are
+        // no test dependencies.
+        self.write_project_files()?;
+
+        // Generate packages
+        let module_path = self.module_path();
+
+        for schema in &self.schemas {
+            let module_generator = module::ModuleGenerator::new(
+                &self.path.join(&schema.package),
+                &module_path,
+                schema,
+                self.bounds_checks,
+            );
+
+            module_generator.generate_module()?;
+        }
+
+        if format_project {
+            self.format_project();
+        }
+
+        Ok(())
+    }
+}
+
+impl PrimitiveConvertible<Go> for NativeType {
+    fn lang_primitive(
+        &self,
+        encoded_types: &BTreeMap<String, EncodedDataType>,
+    ) -> Result<LanguagePrimitive<Go>> {
+        let native_type = self.resolved(encoded_types)?;
+
+        Ok(LanguagePrimitive::new(codecs::go_type(&native_type)))
+    }
+}
+
+/// Writes a file of the package, indented with tabs like `gofmt` does.
+fn write_go_file(path: &Path, package: &str, content: Tokens<Go>) -> Result<()> {
+    let file = File::create(path)?;
+    let mut writer = IoWriter::new(file);
+    let fmt_config = fmt::Config::from_lang::<Go>().with_indentation(fmt::Indentation::Tab);
+    let config = go::Config::default().with_package(package);
+
+    content.format_file(&mut writer.as_formatter(&fmt_config), &config)?;
+
+    Ok(())
+}
+
+/// Go string literal of a value. The quoting of genco escapes single quotes, which is not a
+/// valid escape sequence in Go strings.
+pub(crate) fn string_literal(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
@@ -0,0 +1,170 @@
+use crate::generator::go::constants::{BUFFER_FILE_NAME, SCHEMA_FILE_NAME};
+use crate::generator::go::{string_literal, write_go_file};
+use crate::models::schema::ValidatedMessageSchema;
+use crate::models::ByteOrder;
+use anyhow::Result;
+use genco::prelude::*;
+use std::fs::create_dir_all;
+use std::path::{Path, PathBuf};
+
+pub struct ModuleGenerator<'a> {
+    pub(crate) path: PathBuf,
+    pub(crate) module_path: &'a str,
+    pub(crate) schema: &'a ValidatedMessageSchema,
+    pub(crate) bounds_checks: bool,
+}
+
+impl<'a> ModuleGenerator<'a> {
+    pub fn new(
+        path: &Path,
+        module_path: &'a str,
+        schema: &'a ValidatedMessageSchema,
+        bounds_checks: bool,
+    ) -> Self {
+        Self {
+            path: path.to_owned(),
+            module_path,
+            schema,
+            bounds_checks,
+        }
+    }
+
+    pub fn generate_module(&self) -> Result<()> {
+        create_dir_all(&self.path)?;
+
+        self.write_schema_file()?;
+
+        // Helpers the codecs read and write the blocks with
+        self.write_buffer_file()?;
+
+        // Types of the schema, the ones of groups are scoped by the names of their
+        // messages and enclosing groups
+        self.write_enum_codecs()?;
+        self.write_set_codecs()?;
+        self.write_composite_codecs()?;
+        self.write_message_codecs()
+    }
+
+    fn write_schema_file(&self) -> Result<()> {
+        let byte_order = match self.schema.byte_order {
+            ByteOrder::BigEndian => "BigEndian",
+            ByteOrder::LittleEndian => "LittleEndian",
+        };
+
+        let schema_file_content: Tokens<Go> = quote! {
+            const (
+                SchemaID = $(self.schema.id)
+                SchemaVersion = $(self.schema.version)
+            )
+
+            $("// byteOrder is the byte order of all values encoded with the schema.")
+            var byteOrder $(go::import("encoding/binary", "ByteOrder")) = $(go::import("encoding/binary", byte_order))
+        };
+
+        self.write_file(SCHEMA_FILE_NAME, schema_file_content)
+    }
+
+    fn write_buffer_file(&self) -> Result<()> {
+        let io_reader = &go::import("io", "Reader");
+        let fmt_sprintf = &go::import("fmt", "Sprintf");
+        let new_invalid_string_value = &self.error("NewInvalidStringValue");
+
+        let buffer_file_content: Tokens<Go> = quote! {
+            $("// readBytes reads exactly length bytes from r.")
+            func readBytes(r $io_reader, length int) ([]byte, error) {
+                data := make([]byte, length)
+
+                if read, err := $(go::import("io", "ReadFull"))(r, data); err != nil {
+                    if $(go::import("errors", "Is"))(err, $(go::import("io", "EOF"))) || $(go::import("errors", "Is"))(err, $(go::import("io", "ErrUnexpectedEOF"))) {
+                        return nil, $(self.error("NewCodecOutOfBounds"))(length, read)
+                    }
+
+                    return nil, err
+                }
+
+                return data, nil
+            }
+
+            $("// readBlock reads the block of a message or group entry of the given length. The block is")
+            $("// padded with zeros up to the length known to the schema, so all fields can be decoded")
+            $("// from blocks encoded using an older version of the schema.")
+            func readBlock(r $io_reader, length int, knownLength int) ([]byte, error) {
+                data, err := readBytes(r, length)
+                if err != nil {
+                    return nil, err
+                }
+
+                if length < knownLength {
+                    data = append(data, make([]byte, knownLength-length)...)
+                }
+
+                return data, nil
+            }
+
+            $("// decodeEnum converts an encoded value into the field, using the conversion of the enum.")
+            func decodeEnum[E any, T any](field *E, value T, fromValue func(T) (E, error)) error {
+                decoded, err := fromValue(value)
+                if err != nil {
+                    return err
+                }
+
+                *field = decoded
+                return nil
+            }
+
+            $("// decodeString decodes UTF-8 text into the field.")
+            func decodeString(field *string, data []byte) error {
+                if !$(go::import("unicode/utf8", "Valid"))(data) {
+                    return $new_invalid_string_value($fmt_sprintf("%q", data))
+                }
+
+                *field = string(data)
+                return nil
+            }
+
+            $("// isNullString tells whether all characters of a fixed length string are null.")
+            func isNullString(data []byte, null byte) bool {
+                for _, part := range data {
+                    if part != null {
+                        return false
+                    }
+                }
+
+                return true
+            }
+
+            $("// checkASCII fails for a string with characters outside of ASCII.")
+            func checkASCII(value string) error {
+                for idx := 0; idx < len(value); idx++ {
+                    if value[idx] > 127 {
+                        return $new_invalid_string_value(value)
+                    }
+                }
+
+                return nil
+            }
+
+            $("// encodeString encodes a string into a fixed length field, failing if it does not fit.")
+            func encodeString(data []byte, fieldName string, value string) error {
+                if len(value) > len(data) {
+                    return $(self.error("NewValueOutOfBounds"))(fieldName, $fmt_sprintf($(string_literal("string '%s' length %d > %d (max)")), value, len(value), len(data)))
+                }
+
+                copy(data, value)
+                return nil
+            }
+        };
+
+        self.write_file(BUFFER_FILE_NAME, buffer_file_content)
+    }
+
+    /// Writes a file of the package of the schema.
+    pub(crate) fn write_file(&self, file_name: &str, content: Tokens<Go>) -> Result<()> {
+        write_go_file(&self.path.join(file_name), &self.schema.package, content)
+    }
+
+    /// Constructor of an error of the root package, shared by all schemas of the project.
+    pub(crate) fn error(&self, name: &str) -> go::Import {
+        go::import(self.module_path, name)
+    }
+}
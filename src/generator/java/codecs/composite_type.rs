@@ -0,0 +1,639 @@
+use crate::generator::common::FieldMetadata;
+use crate::generator::java::codecs::{
+    accessor, bounds_checks, boxed, field_metadata, literal, null_value, null_value_condition,
+};
+use crate::generator::java::constants::{READ_BUF_CLASS_NAME, WRITE_BUF_CLASS_NAME};
+use crate::generator::java::module::ModuleGenerator;
+use crate::models::layout::BlockLayout;
+use crate::models::types::composite_type::CompositeType;
+use crate::models::types::encoded_data_type::EncodedDataType;
+use crate::models::types::enum_type::EnumType;
+use crate::models::types::primitive_type::{NativeType, ResolvableType};
+use crate::models::types::set_type::SetType;
+use crate::models::types::{CharacterEncoding, Presence, SizedEncoded, Type};
+use anyhow::{anyhow, Result};
+use convert_case::{Case, Casing};
+use genco::prelude::*;
+
+impl ModuleGenerator<'_> {
+    fn write_composite_codec(&self, composite_type: &CompositeType) -> Result<()> {
+        let name = composite_type.name.to_case(Case::UpperCamel);
+        let decoder_name = format!("{}Decoder", name);
+        let encoder_name = format!("{}Encoder", name);
+
+        let layout = BlockLayout::from_composite(composite_type, &self.schema.types)?;
+        let encoded_length = composite_type.size(&self.schema.types)?;
+
+        let mut decoder_fields: Tokens<Java> = quote!();
+        let mut encoder_fields: Tokens<Java> = quote!();
+
+        for (field, offset) in composite_type.fields.iter().zip(layout.offsets) {
+            decoder_fields.line();
+            decoder_fields.append(self.generate_decoder_field(field.name(), field, offset, 0)?);
+            encoder_fields.line();
+            encoder_fields.append(self.generate_encoder_field(
+                field.name(),
+                field,
+                quote!($offset),
+            )?);
+        }
+
+        let decoder_tokens: Tokens<Java> = quote! {
+            public final class $(&decoder_name) {
+                public static final int ENCODED_LENGTH = $encoded_length;
+
+                private final $READ_BUF_CLASS_NAME buffer;
+
+                public $(&decoder_name)($READ_BUF_CLASS_NAME buffer) {
+                    this.buffer = buffer;
+                }
+                $decoder_fields
+            }
+        };
+
+        let encoder_tokens: Tokens<Java> = quote! {
+            public final class $(&encoder_name) {
+                public static final int ENCODED_LENGTH = $encoded_length;
+
+                private final $WRITE_BUF_CLASS_NAME buffer;
+
+                public $(&encoder_name)($WRITE_BUF_CLASS_NAME buffer) {
+                    this.buffer = buffer;
+                }
+                $encoder_fields
+            }
+        };
+
+        self.write_class(&decoder_name, decoder_tokens)?;
+        self.write_class(&encoder_name, encoder_tokens)
+    }
+
+    pub fn write_composite_codecs(&self) -> Result<()> {
+        for composite_type in self.schema.types.composite_types.values() {
+            self.write_composite_codec(composite_type)?;
+        }
+
+        Ok(())
+    }
+
+    /// Generates the accessor for a field at the given offset.
+    ///
+    /// A non-zero `since_version` means the field was added in that version of the schema.
+    /// The accessor then checks the `actingVersion` of the decoder (only message and group
+    /// decoders have one) and returns the null value of the field, if the field is not present
+    /// in the decoded data.
+    pub(crate) fn generate_decoder_field(
+        &self,
+        field_name: &str,
+        field: &Type,
+        offset: usize,
+        since_version: usize,
+    ) -> Result<Tokens<Java>> {
+        Ok(match field {
+            Type::EncodedData(encoded_type) => self.generate_encoded_field_decoder(
+                field_name,
+                encoded_type,
+                offset,
+                since_version,
+            )?,
+            Type::Enum(enum_type) => {
+                self.generate_enum_field_decoder(field_name, enum_type, offset, since_version)?
+            }
+            Type::Set(set_type) => {
+                self.generate_set_field_decoder(field_name, set_type, offset, since_version)?
+            }
+            Type::Composite(composite_type) => self.generate_composite_field_decoder(
+                field_name,
+                composite_type,
+                offset,
+                since_version,
+            ),
+            Type::Reference(reference_type) => {
+                let referenced_type = self
+                    .schema
+                    .types
+                    .find_type(&reference_type.type_name)
+                    .ok_or(anyhow!(
+                        "Referenced type {} not found",
+                        reference_type.type_name
+                    ))?;
+                self.generate_decoder_field(field_name, &referenced_type, offset, since_version)?
+            }
+        })
+    }
+
+    fn generate_enum_field_decoder(
+        &self,
+        field_name: &str,
+        enum_type: &EnumType,
+        offset: usize,
+        since_version: usize,
+    ) -> Result<Tokens<Java>> {
+        let enum_type_name = enum_type.name.to_case(Case::UpperCamel);
+        let field_name = field_name.to_case(Case::Camel);
+        let encoding_type = enum_type
+            .encoding_type
+            .resolved(&self.schema.types.encoded_types)?;
+
+        Ok(quote! {
+            public $(&enum_type_name) $(field_name)() {
+                $(version_check(since_version, quote!($(&enum_type_name).NULL_VAL)))
+                return $(&enum_type_name).get(buffer.get$(accessor(&encoding_type))At($offset));
+            }
+        })
+    }
+
+    fn generate_set_field_decoder(
+        &self,
+        field_name: &str,
+        set_type: &SetType,
+        offset: usize,
+        since_version: usize,
+    ) -> Result<Tokens<Java>> {
+        let set_type_name = set_type.name.to_case(Case::UpperCamel);
+        let field_name = field_name.to_case(Case::Camel);
+        let encoding_type = set_type
+            .encoding_type
+            .resolved(&self.schema.types.encoded_types)?;
+
+        Ok(quote! {
+            public $(&set_type_name) $(field_name)() {
+                $(version_check(since_version, quote!(new $(&set_type_name)())))
+                return new $(&set_type_name)(buffer.get$(accessor(&encoding_type))At($offset));
+            }
+        })
+    }
+
+    fn generate_composite_field_decoder(
+        &self,
+        field_name: &str,
+        composite_type: &CompositeType,
+        offset: usize,
+        since_version: usize,
+    ) -> Tokens<Java> {
+        let decoder_name = format!("{}Decoder", composite_type.name.to_case(Case::UpperCamel));
+        let field_name = field_name.to_case(Case::Camel);
+        let function = &java::import("java.util.function", "Function");
+
+        // Composites have no null value, so the result is null for the ones
+        // which might not be present in the decoded data.
+        quote! {
+            public <T> T $(field_name)Decoder($function<$(&decoder_name), T> action) {
+                $(version_check(since_version, quote!(null)))
+                return action.apply(new $(&decoder_name)(buffer.splitAt($offset)));
+            }
+        }
+    }
+
+    fn generate_encoded_field_decoder(
+        &self,
+        field_name: &str,
+        encoded_type: &EncodedDataType,
+        offset: usize,
+        since_version: usize,
+    ) -> Result<Tokens<Java>> {
+        Ok(match encoded_type.presence {
+            Presence::Constant => self.generate_encoded_constant_field(field_name, encoded_type)?,
+            Presence::Required => self.generate_encoded_required_field_decoder(
+                field_name,
+                encoded_type,
+                offset,
+                since_version,
+            )?,
+            Presence::Optional => self.generate_encoded_optional_field_decoder(
+                field_name,
+                encoded_type,
+                offset,
+                since_version,
+            )?,
+        })
+    }
+
+    fn generate_encoded_constant_field(
+        &self,
+        field_name: &str,
+        encoded_type: &EncodedDataType,
+    ) -> Result<Tokens<Java>> {
+        let metadata = field_metadata(field_name, encoded_type, &self.schema.types)?;
+
+        let value = encoded_type.default_value.as_ref().ok_or(anyhow!(
+            "Constant field {} has no default value",
+            metadata.field_name
+        ))?;
+
+        Ok(match metadata.field_primitive_type {
+            NativeType::Char => quote! {
+                public String $(&metadata.field_name)() {
+                    return $(quoted(value));
+                }
+            },
+            _ => quote! {
+                public $(&metadata.lang_type) $(&metadata.field_name)() {
+                    return $(literal(value, &metadata.field_primitive_type));
+                }
+            },
+        })
+    }
+
+    fn generate_encoded_required_field_decoder(
+        &self,
+        field_name: &str,
+        encoded_type: &EncodedDataType,
+        offset: usize,
+        since_version: usize,
+    ) -> Result<Tokens<Java>> {
+        let metadata = field_metadata(field_name, encoded_type, &self.schema.types)?;
+        let getter = format!("get{}At", accessor(&metadata.field_primitive_type));
+        let null_value = null_value(encoded_type, &metadata);
+
+        Ok(
+            match (&metadata.field_primitive_type, metadata.field_length) {
+                (NativeType::Char, 2..) => quote! {
+                    public String $(&metadata.field_name)() {
+                        $(version_check(since_version, quote!("")))
+                        return buffer.getStringAt($offset, $(metadata.field_length));
+                    }
+                },
+                (_, 2..) => quote! {
+                    public $(&metadata.lang_type)[] $(&metadata.field_name)() {
+                        $(version_check(since_version, null_array(&metadata, &null_value)))
+                        int offset = $offset;
+                        $(&metadata.lang_type)[] value = new $(&metadata.lang_type)[$(metadata.field_length)];
+
+                        for (int idx = 0; idx < value.length; idx++) {
+                            value[idx] = buffer.$(&getter)(offset + idx * $(metadata.type_size));
+                        }
+
+                        return value;
+                    }
+                },
+                (_, _) => {
+                    let bounds_checks = bounds_checks(
+                        &metadata.field_name,
+                        "value",
+                        encoded_type,
+                        &metadata.field_primitive_type,
+                        &self.error_class(),
+                    );
+
+                    if bounds_checks.is_empty() {
+                        quote! {
+                            public $(&metadata.lang_type) $(&metadata.field_name)() {
+                                $(version_check(since_version, &null_value))
+                                return buffer.$(&getter)($offset);
+                            }
+                        }
+                    } else {
+                        quote! {
+                            public $(&metadata.lang_type) $(&metadata.field_name)() {
+                                $(version_check(since_version, &null_value))
+                                $(&metadata.lang_type) value = buffer.$(&getter)($offset);
+
+                                $bounds_checks
+                                return value;
+                            }
+                        }
+                    }
+                }
+            },
+        )
+    }
+
+    fn generate_encoded_optional_field_decoder(
+        &self,
+        field_name: &str,
+        encoded_type: &EncodedDataType,
+        offset: usize,
+        since_version: usize,
+    ) -> Result<Tokens<Java>> {
+        let metadata = field_metadata(field_name, encoded_type, &self.schema.types)?;
+        let getter = format!("get{}At", accessor(&metadata.field_primitive_type));
+
+        Ok(
+            match (&metadata.field_primitive_type, metadata.field_length) {
+                (NativeType::Char, 2..) => quote! {
+                    public String $(&metadata.field_name)() {
+                        $(version_check(since_version, quote!(null)))
+                        boolean isNull = true;
+
+                        for (byte part : buffer.getSliceAt($offset, $(metadata.field_length))) {
+                            isNull &= $(null_value_condition("part", encoded_type, &metadata));
+                        }
+
+                        if (isNull) {
+                            return null;
+                        }
+
+                        return buffer.getStringAt($offset, $(metadata.field_length));
+                    }
+                },
+                (_, 2..) => quote! {
+                    public $(&metadata.lang_type)[] $(&metadata.field_name)() {
+                        $(version_check(since_version, quote!(null)))
+                        int offset = $offset;
+                        $(&metadata.lang_type)[] value = new $(&metadata.lang_type)[$(metadata.field_length)];
+                        boolean isNull = true;
+
+                        for (int idx = 0; idx < value.length; idx++) {
+                            $(&metadata.lang_type) part = buffer.$(&getter)(offset + idx * $(metadata.type_size));
+                            value[idx] = part;
+                            isNull &= $(null_value_condition("part", encoded_type, &metadata));
+                        }
+
+                        return isNull ? null : value;
+                    }
+                },
+                (_, _) => quote! {
+                    public $(boxed(metadata.lang_type.name)) $(&metadata.field_name)() {
+                        $(version_check(since_version, quote!(null)))
+                        $(&metadata.lang_type) value = buffer.$(&getter)($offset);
+
+                        if ($(null_value_condition("value", encoded_type, &metadata))) {
+                            return null;
+                        }
+
+                        $(bounds_checks(&metadata.field_name, "value", encoded_type, &metadata.field_primitive_type, &self.error_class()))
+                        return value;
+                    }
+                },
+            },
+        )
+    }
+
+    pub(crate) fn generate_encoder_field(
+        &self,
+        field_name: &str,
+        field: &Type,
+        offset: Tokens<Java>,
+    ) -> Result<Tokens<Java>> {
+        Ok(match field {
+            Type::EncodedData(encoded_type) => match encoded_type.presence {
+                Presence::Constant => quote!(),
+                Presence::Required => {
+                    self.generate_encoded_required_field_encoder(field_name, encoded_type, offset)?
+                }
+                Presence::Optional => {
+                    self.generate_encoded_optional_field_encoder(field_name, encoded_type, offset)?
+                }
+            },
+            Type::Enum(enum_type) => {
+                let encoding_type = enum_type
+                    .encoding_type
+                    .resolved(&self.schema.types.encoded_types)?;
+
+                quote! {
+                    public void $(field_name.to_case(Case::Camel))($(enum_type.name.to_case(Case::UpperCamel)) value) {
+                        buffer.put$(accessor(&encoding_type))At($offset, value.value());
+                    }
+                }
+            }
+            Type::Set(set_type) => {
+                let encoding_type = set_type
+                    .encoding_type
+                    .resolved(&self.schema.types.encoded_types)?;
+
+                quote! {
+                    public void $(field_name.to_case(Case::Camel))($(set_type.name.to_case(Case::UpperCamel)) value) {
+                        buffer.put$(accessor(&encoding_type))At($offset, value.value());
+                    }
+                }
+            }
+            Type::Composite(composite_type) => {
+                let encoder_name =
+                    format!("{}Encoder", composite_type.name.to_case(Case::UpperCamel));
+                let function = &java::import("java.util.function", "Function");
+
+                quote! {
+                    public <T> T $(field_name.to_case(Case::Camel))Encoder($function<$(&encoder_name), T> action) {
+                        return action.apply(new $(&encoder_name)(buffer.splitAt($offset)));
+                    }
+                }
+            }
+            Type::Reference(reference_type) => {
+                let referenced_type = self
+                    .schema
+                    .types
+                    .find_type(&reference_type.type_name)
+                    .ok_or(anyhow!(
+                        "Referenced type {} not found",
+                        reference_type.type_name
+                    ))?;
+                self.generate_encoder_field(field_name, &referenced_type, offset)?
+            }
+        })
+    }
+
+    fn generate_encoded_required_field_encoder(
+        &self,
+        field_name: &str,
+        encoded_type: &EncodedDataType,
+        offset: Tokens<Java>,
+    ) -> Result<Tokens<Java>> {
+        let metadata = field_metadata(field_name, encoded_type, &self.schema.types)?;
+        let setter = format!("put{}At", accessor(&metadata.field_primitive_type));
+
+        Ok(
+            match (&metadata.field_primitive_type, metadata.field_length) {
+                (NativeType::Char, 2..) => quote! {
+                    public void $(&metadata.field_name)(String value) {
+                        int offset = $offset;
+
+                        $(self.string_encoder(&metadata))
+                    }
+                },
+                (_, 2..) => quote! {
+                    public void $(&metadata.field_name)($(&metadata.lang_type)[] value) {
+                        int offset = $offset;
+
+                        $(self.array_length_check(&metadata))
+                        for (int idx = 0; idx < value.length; idx++) {
+                            $(self.bounds_checks(&metadata, "value[idx]", encoded_type))
+                            buffer.$(&setter)(offset + idx * $(metadata.type_size), value[idx]);
+                        }
+                    }
+                },
+                (_, _) => {
+                    let bounds_checks = self.bounds_checks(&metadata, "value", encoded_type);
+
+                    if bounds_checks.is_empty() {
+                        quote! {
+                            public void $(&metadata.field_name)($(&metadata.lang_type) value) {
+                                buffer.$(&setter)($offset, value);
+                            }
+                        }
+                    } else {
+                        quote! {
+                            public void $(&metadata.field_name)($(&metadata.lang_type) value) {
+                                int offset = $offset;
+
+                                $bounds_checks
+                                buffer.$(&setter)(offset, value);
+                            }
+                        }
+                    }
+                }
+            },
+        )
+    }
+
+    fn generate_encoded_optional_field_encoder(
+        &self,
+        field_name: &str,
+        encoded_type: &EncodedDataType,
+        offset: Tokens<Java>,
+    ) -> Result<Tokens<Java>> {
+        let metadata = field_metadata(field_name, encoded_type, &self.schema.types)?;
+        let setter = format!("put{}At", accessor(&metadata.field_primitive_type));
+        let null_value = null_value(encoded_type, &metadata);
+        let arrays = &java::import("java.util", "Arrays");
+
+        Ok(
+            match (&metadata.field_primitive_type, metadata.field_length) {
+                (NativeType::Char, 2..) => quote! {
+                    public void $(&metadata.field_name)(String value) {
+                        int offset = $offset;
+
+                        if (value == null) {
+                            byte[] nulls = new byte[$(metadata.field_length)];
+                            $arrays.fill(nulls, $(&null_value));
+                            buffer.putBytesAt(offset, nulls);
+                            return;
+                        }
+
+                        $(self.string_encoder(&metadata))
+                    }
+                },
+                (_, 2..) => {
+                    let bounds_checks = self.bounds_checks(&metadata, "part", encoded_type);
+
+                    quote! {
+                        public void $(&metadata.field_name)($(&metadata.lang_type)[] value) {
+                            int offset = $offset;
+
+                            $(if bounds_checks.is_empty() {
+                                if (value == null) {
+                                    value = $(null_array(&metadata, &null_value));
+                                } else if (value.length != $(metadata.field_length)) {
+                                    $(self.wrong_slice_size(&metadata))
+                                }
+                            } else {
+                                if (value == null) {
+                                    value = $(null_array(&metadata, &null_value));
+                                } else {
+                                    $(self.array_length_check(&metadata))
+                                    for ($(&metadata.lang_type) part : value) {
+                                        $bounds_checks
+                                    }
+                                }
+                            })
+
+                            for (int idx = 0; idx < value.length; idx++) {
+                                buffer.$(&setter)(offset + idx * $(metadata.type_size), value[idx]);
+                            }
+                        }
+                    }
+                }
+                (_, _) => {
+                    let bounds_checks = self.bounds_checks(&metadata, "value", encoded_type);
+
+                    if bounds_checks.is_empty() {
+                        quote! {
+                            public void $(&metadata.field_name)($(boxed(metadata.lang_type.name)) value) {
+                                buffer.$(&setter)($offset, value != null ? value : $(&null_value));
+                            }
+                        }
+                    } else {
+                        quote! {
+                            public void $(&metadata.field_name)($(boxed(metadata.lang_type.name)) value) {
+                                int offset = $offset;
+
+                                if (value != null) {
+                                    $bounds_checks
+                                }
+
+                                buffer.$(&setter)(offset, value != null ? value : $(&null_value));
+                            }
+                        }
+                    }
+                }
+            },
+        )
+    }
+
+    /// Checks of the `minValue` and `maxValue` of the field, unless they are disabled.
+    fn bounds_checks(
+        &self,
+        metadata: &FieldMetadata<Java>,
+        variable: &str,
+        encoded_type: &EncodedDataType,
+    ) -> Tokens<Java> {
+        if self.bounds_checks {
+            bounds_checks(
+                &metadata.field_name,
+                variable,
+                encoded_type,
+                &metadata.field_primitive_type,
+                &self.error_class(),
+            )
+        } else {
+            quote!()
+        }
+    }
+
+    fn array_length_check(&self, metadata: &FieldMetadata<Java>) -> Tokens<Java> {
+        quote! {
+            if (value.length != $(metadata.field_length)) {
+                $(self.wrong_slice_size(metadata))
+            }
+            $['\n']
+        }
+    }
+
+    fn wrong_slice_size(&self, metadata: &FieldMetadata<Java>) -> Tokens<Java> {
+        quote! {
+            throw $(self.error_class()).wrongSliceSize($(quoted(format!("expected {} elements, got ", metadata.field_length))) + value.length);
+        }
+    }
+
+    fn string_encoder(&self, metadata: &FieldMetadata<Java>) -> Tokens<Java> {
+        let standard_charsets = &java::import("java.nio.charset", "StandardCharsets");
+        let error = &self.error_class();
+        let max_length = metadata.field_length;
+
+        quote! {
+            $(if let Some(CharacterEncoding::Ascii) = metadata.encoding {
+                if (!$standard_charsets.US_ASCII.newEncoder().canEncode(value)) {
+                    throw $error.invalidStringValue(value);
+                }
+                $['\n']
+            })
+            byte[] encoded = value.getBytes($standard_charsets.UTF_8);
+
+            if (encoded.length > $max_length) {
+                throw $error.valueOutOfBounds($(quoted(&metadata.field_name)), "string '" + value + "' length " + encoded.length + $(quoted(format!(" > {} (max)", max_length))));
+            }
+
+            buffer.putBytesAt(offset, encoded);
+        }
+    }
+}
+
+/// Returns the null value early from an accessor of a field, which was added in a newer
+/// version of the schema than the one used to encode the decoded data.
+fn version_check(since_version: usize, null_value: impl FormatInto<Java>) -> Tokens<Java> {
+    quote! {
+        $(if since_version > 0 {
+            if (actingVersion < $since_version) {
+                return $null_value;
+            }
+            $['\n']
+        })
+    }
+}
+
+/// Array of the field length filled with the null value.
+fn null_array(metadata: &FieldMetadata<Java>, null_value: &str) -> Tokens<Java> {
+    let values = vec![null_value; metadata.field_length].join(", ");
+
+    quote!(new $(&metadata.lang_type)[] {$values})
+}
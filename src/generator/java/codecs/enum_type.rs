@@ -0,0 +1,79 @@
+use anyhow::Result;
+use convert_case::{Case, Casing};
+
+use crate::generator::java::codecs::{literal, null_literal};
+use crate::generator::java::module::ModuleGenerator;
+use crate::models::types::enum_type::EnumType;
+use crate::models::types::primitive_type::{NativeType, PrimitiveConvertible, ResolvableType};
+use genco::prelude::*;
+
+impl ModuleGenerator<'_> {
+    fn write_enum_codec(&self, enum_type: &EnumType) -> Result<()> {
+        let name = enum_type.name.to_case(Case::UpperCamel);
+        let encoding_type = enum_type
+            .encoding_type
+            .resolved(&self.schema.types.encoded_types)?;
+        let java_type = encoding_type.lang_primitive(&self.schema.types.encoded_types)?;
+        let char_encoding = encoding_type == NativeType::Char;
+        let error = &self.error_class();
+
+        let values = enum_type
+            .values
+            .iter()
+            .map(|value| {
+                Ok((
+                    value.name.to_case(Case::UpperSnake),
+                    literal(
+                        &value.encoded_value(char_encoding)?.to_string(),
+                        &encoding_type,
+                    ),
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let null_value = null_literal(&encoding_type);
+
+        let enum_tokens: Tokens<Java> = quote! {
+            public enum $(&name) {
+                $(for (value_name, value) in &values {
+                    $['\r']
+                    $value_name($value),
+                })
+                NULL_VAL($null_value);
+
+                private final $(&java_type) value;
+
+                $(&name)($(&java_type) value) {
+                    this.value = value;
+                }
+
+                public $(&java_type) value() {
+                    return value;
+                }
+
+                public static $(&name) get($(&java_type) value) {
+                    $(for (value_name, value) in &values {
+                        $['\r']
+                        if (value == $value) {
+                            return $value_name;
+                        }
+                    })
+                    if (value == $null_value) {
+                        return NULL_VAL;
+                    }
+
+                    throw $error.invalidEnumValue($(quoted(&name)), value);
+                }
+            }
+        };
+
+        self.write_class(&name, enum_tokens)
+    }
+
+    pub fn write_enum_codecs(&self) -> Result<()> {
+        for enum_type in self.schema.types.enum_types.values() {
+            self.write_enum_codec(enum_type)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,462 @@
+use crate::generator::common::{dimension_type, field_groups};
+use crate::generator::java::codecs::{accessor, max_literal};
+use crate::generator::java::constants::{READ_BUF_CLASS_NAME, WRITE_BUF_CLASS_NAME};
+use crate::generator::java::module::ModuleGenerator;
+use crate::models::layout::BlockLayout;
+use crate::models::types::field_type::FieldType;
+use crate::models::types::group_type::GroupType;
+use crate::models::types::primitive_type::{PrimitiveConvertible, ResolvableType};
+use crate::models::types::variable_data_type::VariableDataType;
+use crate::models::types::{Presence, SizedEncoded, Type};
+use anyhow::{anyhow, Result};
+use convert_case::{Case, Casing};
+use genco::prelude::*;
+
+impl ModuleGenerator<'_> {
+    /// Generates the decoder of a group as a class nested in the decoder of its message or
+    /// enclosing group, along with the decoders of its own groups and var data.
+    pub(crate) fn generate_group_decoder(&self, group: &GroupType) -> Result<Tokens<Java>> {
+        let name = group.name.as_str();
+        let decoder_name = format!("{}Decoder", name.to_case(Case::UpperCamel));
+        let (fields, groups, var_data) = field_groups(&group.fields);
+        let error = &self.error_class();
+
+        let layout =
+            BlockLayout::from_fields(name, &fields, group.block_length, &self.schema.types)?;
+
+        let dimension_type = dimension_type(group, &self.schema.types.composite_types)?;
+        let dimension_type_size = dimension_type.size(&self.schema.types)?;
+        let dimension_type_decoder_name =
+            format!("{}Decoder", dimension_type.name.to_case(Case::UpperCamel));
+
+        Ok(quote! {
+            public static final class $(&decoder_name) {
+                private $READ_BUF_CLASS_NAME buffer;
+                private final int actingVersion;
+                private final int blockLength;
+                private final int numInGroup;
+                private int index;
+                private int size;
+                $(size_fields(&groups, &var_data))
+
+                public $(&decoder_name)($READ_BUF_CLASS_NAME buffer, int actingVersion) {
+                    this(buffer.splitAt($dimension_type_size), actingVersion, new $(&dimension_type_decoder_name)(buffer), $dimension_type_size);
+                }
+
+                private $(&decoder_name)($READ_BUF_CLASS_NAME buffer, int actingVersion, $(&dimension_type_decoder_name) dimension, int size) {
+                    this.buffer = buffer;
+                    this.actingVersion = actingVersion;
+                    this.blockLength = dimension != null ? (int) dimension.blockLength() : 0;
+                    this.numInGroup = dimension != null ? (int) dimension.numInGroup() : 0;
+                    this.size = size;
+                }
+
+                $(java::block_comment(["Creates a decoder of a group, which is not present in the decoded data,", "because it was added in a newer version of the schema."]))
+                public static $(&decoder_name) empty(int actingVersion) {
+                    return new $(&decoder_name)($READ_BUF_CLASS_NAME.empty(), actingVersion, null, 0);
+                }
+
+                public int actingVersion() {
+                    return actingVersion;
+                }
+
+                public int blockLength() {
+                    return blockLength;
+                }
+
+                public int numInGroup() {
+                    return numInGroup;
+                }
+
+                public Integer size() {
+                    return index == numInGroup ? Integer.valueOf(size) : null;
+                }
+
+                public void advance() {
+                    if (index >= numInGroup) {
+                        throw $error.groupOutOfBounds($(quoted(name.to_case(Case::Camel))));
+                    }
+
+                    int fieldsSize = blockLength;
+                    $(self.taken_sizes(&groups, &var_data))
+                    int advancement = fieldsSize + groupsSize + varDataSize;
+
+                    index++;
+                    size += advancement;
+                    buffer = buffer.splitAt(advancement);
+                }
+                $(self.generate_field_decoders(&fields, layout.offsets)?)
+                $(self.generate_group_decoders(&groups, &quote!(blockLength))?)
+                $(self.generate_var_data_decoders(&var_data, &groups, &quote!(blockLength))?)
+                $(for group in &groups {
+                    $['\n']
+                    $(self.generate_group_decoder(group)?)
+                })
+                $(for var_data_field in &var_data {
+                    $['\n']
+                    $(self.generate_var_data_decoder(var_data_field)?)
+                })
+            }
+        })
+    }
+
+    /// Generates the encoder of a group as a class nested in the encoder of its message or
+    /// enclosing group, along with the encoders of its own groups and var data.
+    pub(crate) fn generate_group_encoder(&self, group: &GroupType) -> Result<Tokens<Java>> {
+        let name = group.name.as_str();
+        let encoder_name = format!("{}Encoder", name.to_case(Case::UpperCamel));
+        let (fields, groups, var_data) = field_groups(&group.fields);
+        let error = &self.error_class();
+        let types = &self.schema.types;
+
+        let dimension_type = dimension_type(group, &types.composite_types)?;
+        let dimension_type_size = dimension_type.size(types)?;
+
+        let block_length_type = match &dimension_type.fields.first() {
+            Some(Type::EncodedData(block_length_type)) => block_length_type,
+            _ => {
+                return Err(anyhow!(
+                    "Only encoded data type expected for the block length type in group '{}'",
+                    group.name
+                ))
+            }
+        };
+        let num_in_group_type = match &dimension_type.fields.last() {
+            Some(Type::EncodedData(num_in_group_type)) => num_in_group_type,
+            _ => {
+                return Err(anyhow!(
+                    "Only encoded data type expected for the num in group type in group '{}'",
+                    group.name
+                ))
+            }
+        };
+
+        let block_length_native = block_length_type
+            .primitive_type
+            .resolved(&types.encoded_types)?;
+        let block_length_primitive = block_length_native.lang_primitive(&types.encoded_types)?;
+        let block_length_primitive_size = block_length_type.primitive_type.size(types)?;
+        let num_in_group_native = num_in_group_type
+            .primitive_type
+            .resolved(&types.encoded_types)?;
+        let num_in_group_primitive = num_in_group_native.lang_primitive(&types.encoded_types)?;
+
+        let layout = BlockLayout::from_fields(name, &fields, group.block_length, types)?;
+        let offset_tokens = quote!(size + BLOCK_LENGTH);
+
+        Ok(quote! {
+            public static final class $(&encoder_name) {
+                public static final int BLOCK_LENGTH = $(layout.block_length);
+
+                private final $WRITE_BUF_CLASS_NAME buffer;
+                private int numInGroup;
+                private int size;
+                $(size_fields(&groups, &var_data))
+
+                public $(&encoder_name)($WRITE_BUF_CLASS_NAME buffer) {
+                    this.buffer = buffer;
+                    this.size = $dimension_type_size;
+                }
+
+                public int size() {
+                    return size;
+                }
+
+                public void advance() {
+                    int fieldsSize = BLOCK_LENGTH;
+                    $(self.taken_sizes(&groups, &var_data))
+
+                    if (numInGroup >= $(max_literal(&num_in_group_native))) {
+                        throw $error.groupOutOfBounds("numInGroup");
+                    }
+
+                    numInGroup++;
+                    size += fieldsSize + groupsSize + varDataSize;
+                }
+
+                $(java::block_comment(["Writes the dimension of the group, after all its entries were encoded."]))
+                public void finish() {
+                    buffer.put$(accessor(&block_length_native))At(0, ($(&block_length_primitive)) BLOCK_LENGTH);
+                    buffer.put$(accessor(&num_in_group_native))At($block_length_primitive_size, ($(&num_in_group_primitive)) numInGroup);
+                }
+                $(self.generate_field_encoders(&fields, &quote!(size + ), layout.offsets)?)
+                $(self.generate_group_encoders(&groups, &offset_tokens)?)
+                $(self.generate_var_data_encoders(&var_data, &groups, &offset_tokens)?)
+                $(for group in &groups {
+                    $['\n']
+                    $(self.generate_group_encoder(group)?)
+                })
+                $(for var_data_field in &var_data {
+                    $['\n']
+                    $(self.generate_var_data_encoder(var_data_field)?)
+                })
+            }
+        })
+    }
+
+    /// Sums the recorded sizes of the groups and var data of the current entry, which are
+    /// reset for the next one.
+    fn taken_sizes(&self, groups: &[&GroupType], var_data: &[&VariableDataType]) -> Tokens<Java> {
+        let error = &self.error_class();
+
+        quote! {
+            int groupsSize = 0$(for group in groups => $[' ']+ $error.requireGroupSize($(group.name.to_case(Case::Camel))Size, $(quoted(group.name.to_case(Case::Camel)))));
+            int varDataSize = 0$(for var in var_data => $[' ']+ $error.requireVarDataSize($(var.name.to_case(Case::Camel))Size, $(quoted(var.name.to_case(Case::Camel)))));
+            $(for group in groups {
+                $['\r']
+                $(group.name.to_case(Case::Camel))Size = null;
+            })
+            $(for var in var_data {
+                $['\r']
+                $(var.name.to_case(Case::Camel))Size = null;
+            })
+        }
+    }
+
+    pub(crate) fn generate_field_decoders(
+        &self,
+        fields: &[&FieldType],
+        offsets: impl IntoIterator<Item = usize>,
+    ) -> Result<Tokens<Java>> {
+        let mut decoder_fields: Tokens<Java> = quote!();
+
+        for (field, offset) in fields.iter().zip(offsets) {
+            let repr_type = field.to_type(&self.schema.types)?;
+
+            decoder_fields.line();
+            decoder_fields.append(if matches!(field.presence, Presence::Constant) {
+                self.generate_constant_enum(field, repr_type)?
+            } else {
+                let since_version = field.since_version.unwrap_or(0);
+                self.generate_decoder_field(&field.name, &repr_type, offset, since_version)?
+            });
+        }
+
+        Ok(decoder_fields)
+    }
+
+    fn generate_constant_enum(&self, field: &FieldType, repr_type: Type) -> Result<Tokens<Java>> {
+        let field_name = field.name.to_case(Case::Camel);
+        let enum_type = match repr_type {
+            Type::Enum(enum_type) => enum_type,
+            _ => return Err(anyhow!("Constant field {} is not an enum", field.name)),
+        };
+        let enum_type_name = enum_type.name.to_case(Case::UpperCamel);
+
+        let default_value = field
+            .value_ref
+            .as_ref()
+            .ok_or(anyhow!("Constant field {} has no ref value", field.name))?;
+
+        let default_value = default_value.split('.').take(2).collect::<Vec<_>>()[1];
+        let default_value = enum_type
+            .values
+            .iter()
+            .find(|value| value.name == default_value)
+            .ok_or(anyhow!(
+                "Constant field {} has no value {}",
+                field.name,
+                default_value
+            ))?;
+
+        Ok(quote! {
+            public $(&enum_type_name) $(&field_name)() {
+                return $(&enum_type_name).$(default_value.name.to_case(Case::UpperSnake));
+            }
+        })
+    }
+
+    pub(crate) fn generate_field_encoders(
+        &self,
+        fields: &[&FieldType],
+        offset_prefix: &Tokens<Java>,
+        offsets: impl IntoIterator<Item = usize>,
+    ) -> Result<Tokens<Java>> {
+        let mut encoder_fields: Tokens<Java> = quote!();
+
+        for (field, offset) in fields.iter().zip(offsets) {
+            if matches!(field.presence, Presence::Constant) {
+                continue;
+            }
+
+            let repr_type = field.to_type(&self.schema.types)?;
+
+            encoder_fields.line();
+            encoder_fields.append(self.generate_encoder_field(
+                &field.name,
+                &repr_type,
+                quote!($offset_prefix$offset),
+            )?);
+        }
+
+        Ok(encoder_fields)
+    }
+
+    pub(crate) fn generate_group_decoders(
+        &self,
+        groups: &[&GroupType],
+        offset: &Tokens<Java>,
+    ) -> Result<Tokens<Java>> {
+        let mut decoder_fields: Tokens<Java> = quote!();
+        let function = &java::import("java.util.function", "Function");
+
+        for (group_idx, group) in groups.iter().enumerate() {
+            let field_name = group.name.to_case(Case::Camel);
+            let decoder_name = format!("{}Decoder", group.name.to_case(Case::UpperCamel));
+
+            decoder_fields.line();
+            decoder_fields.append(quote! {
+                public <T> T $(&field_name)Decoder($function<$(&decoder_name), T> action) {
+                    $(if let Some(since_version) = group.since_version.filter(|version| *version > 0) {
+                        if (actingVersion < $since_version) {
+                            T result = action.apply($(&decoder_name).empty(actingVersion));
+                            $(&field_name)Size = 0;
+
+                            return result;
+                        }
+                        $['\n']
+                    })
+                    int offset = $offset$(self.previous_group_sizes(&groups[..group_idx]));
+                    $(&decoder_name) decoder = new $(&decoder_name)(buffer.splitAt(offset), actingVersion);
+
+                    T result = action.apply(decoder);
+                    $(&field_name)Size = decoder.size();
+
+                    return result;
+                }
+            });
+        }
+
+        Ok(decoder_fields)
+    }
+
+    pub(crate) fn generate_var_data_decoders(
+        &self,
+        var_data_fields: &[&VariableDataType],
+        groups: &[&GroupType],
+        offset: &Tokens<Java>,
+    ) -> Result<Tokens<Java>> {
+        let mut decoder_fields: Tokens<Java> = quote!();
+        let function = &java::import("java.util.function", "Function");
+
+        for (var_data_idx, var_data_field) in var_data_fields.iter().enumerate() {
+            let field_name = var_data_field.name.to_case(Case::Camel);
+            let decoder_name = format!("{}Decoder", var_data_field.name.to_case(Case::UpperCamel));
+
+            decoder_fields.line();
+            decoder_fields.append(quote! {
+                public <T> T $(&field_name)Decoder($function<$(&decoder_name), T> action) {
+                    $(if let Some(since_version) = var_data_field.since_version.filter(|version| *version > 0) {
+                        if (actingVersion < $since_version) {
+                            T result = action.apply(new $(&decoder_name)($READ_BUF_CLASS_NAME.empty()));
+                            $(&field_name)Size = 0;
+
+                            return result;
+                        }
+                        $['\n']
+                    })
+                    int offset = $offset$(self.previous_group_sizes(groups))$(self.previous_var_data_sizes(&var_data_fields[..var_data_idx]));
+                    $(&decoder_name) decoder = new $(&decoder_name)(buffer.splitAt(offset));
+
+                    T result = action.apply(decoder);
+                    $(&field_name)Size = decoder.size();
+
+                    return result;
+                }
+            });
+        }
+
+        Ok(decoder_fields)
+    }
+
+    pub(crate) fn generate_group_encoders(
+        &self,
+        groups: &[&GroupType],
+        offset: &Tokens<Java>,
+    ) -> Result<Tokens<Java>> {
+        let mut encoder_fields: Tokens<Java> = quote!();
+        let function = &java::import("java.util.function", "Function");
+
+        for (group_idx, group) in groups.iter().enumerate() {
+            let field_name = group.name.to_case(Case::Camel);
+            let encoder_name = format!("{}Encoder", group.name.to_case(Case::UpperCamel));
+
+            encoder_fields.line();
+            encoder_fields.append(quote! {
+                public <T> T $(&field_name)Encoder($function<$(&encoder_name), T> action) {
+                    int offset = $offset$(self.previous_group_sizes(&groups[..group_idx]));
+                    $(&encoder_name) encoder = new $(&encoder_name)(buffer.splitAt(offset));
+
+                    T result = action.apply(encoder);
+                    $(&field_name)Size = encoder.size();
+                    encoder.finish();
+
+                    return result;
+                }
+            });
+        }
+
+        Ok(encoder_fields)
+    }
+
+    pub(crate) fn generate_var_data_encoders(
+        &self,
+        var_data_fields: &[&VariableDataType],
+        groups: &[&GroupType],
+        offset: &Tokens<Java>,
+    ) -> Result<Tokens<Java>> {
+        let mut encoder_fields: Tokens<Java> = quote!();
+        let function = &java::import("java.util.function", "Function");
+
+        for (var_data_idx, var_data_field) in var_data_fields.iter().enumerate() {
+            let field_name = var_data_field.name.to_case(Case::Camel);
+            let encoder_name = format!("{}Encoder", var_data_field.name.to_case(Case::UpperCamel));
+
+            encoder_fields.line();
+            encoder_fields.append(quote! {
+                public <T> T $(&field_name)Encoder($function<$(&encoder_name), T> action) {
+                    int offset = $offset$(self.previous_group_sizes(groups))$(self.previous_var_data_sizes(&var_data_fields[..var_data_idx]));
+                    $(&encoder_name) encoder = new $(&encoder_name)(buffer.splitAt(offset));
+
+                    T result = action.apply(encoder);
+                    $(&field_name)Size = encoder.size();
+                    encoder.finish();
+
+                    return result;
+                }
+            });
+        }
+
+        Ok(encoder_fields)
+    }
+
+    fn previous_group_sizes(&self, groups: &[&GroupType]) -> Tokens<Java> {
+        let error = &self.error_class();
+
+        quote! {
+            $(for group in groups => $[' ']+ $error.requireGroupSize($(group.name.to_case(Case::Camel))Size, $(quoted(group.name.to_case(Case::Camel)))))
+        }
+    }
+
+    fn previous_var_data_sizes(&self, var_data_fields: &[&VariableDataType]) -> Tokens<Java> {
+        let error = &self.error_class();
+
+        quote! {
+            $(for var in var_data_fields => $[' ']+ $error.requireVarDataSize($(var.name.to_case(Case::Camel))Size, $(quoted(var.name.to_case(Case::Camel)))))
+        }
+    }
+}
+
+/// Recorded sizes of the groups and var data, null until they were decoded or encoded.
+pub(crate) fn size_fields(groups: &[&GroupType], var_data: &[&VariableDataType]) -> Tokens<Java> {
+    quote! {
+        $(for group in groups {
+            $['\r']
+            private Integer $(group.name.to_case(Case::Camel))Size;
+        })
+        $(for var in var_data {
+            $['\r']
+            private Integer $(var.name.to_case(Case::Camel))Size;
+        })
+    }
+}
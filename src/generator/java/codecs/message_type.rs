@@ -0,0 +1,345 @@
+use crate::generator::common::{field_groups, header_counts};
+use crate::generator::java::codecs::field_metadata;
+use crate::generator::java::codecs::group_type::size_fields;
+use crate::generator::java::constants::{
+    READ_BUF_CLASS_NAME, SCHEMA_CLASS_NAME, WRITE_BUF_CLASS_NAME,
+};
+use crate::generator::java::module::ModuleGenerator;
+use crate::models::layout::BlockLayout;
+use crate::models::message::MessageType;
+use crate::models::types::group_type::GroupType;
+use crate::models::types::variable_data_type::VariableDataType;
+use crate::models::types::{SizedEncoded, Type};
+use anyhow::{anyhow, Result};
+use convert_case::{Case, Casing};
+use genco::prelude::*;
+
+impl ModuleGenerator<'_> {
+    fn write_message_decoder(&self, message: &MessageType) -> Result<()> {
+        let name = message.name.as_str();
+        let decoder_name = format!("{}Decoder", name.to_case(Case::UpperCamel));
+        let (fields, groups, var_data) = field_groups(&message.fields);
+        let byte_buffer = &java::import("java.nio", "ByteBuffer");
+        let error = &self.error_class();
+
+        let layout =
+            BlockLayout::from_fields(name, &fields, message.block_length, &self.schema.types)?;
+
+        let header_type = Type::Composite(self.schema.types.header_type.clone());
+        let header_size = header_type.size(&self.schema.types)?;
+        let header_offset = quote!($header_size + blockLength);
+
+        let decoder_tokens: Tokens<Java> = quote! {
+            public final class $(&decoder_name) {
+                public static final int TEMPLATE_ID = $(message.id);
+
+                private final $READ_BUF_CLASS_NAME buffer;
+                private int actingVersion;
+                private int blockLength;
+                $(size_fields(&groups, &var_data))
+
+                public $(&decoder_name)($byte_buffer buffer) {
+                    this(new $READ_BUF_CLASS_NAME(buffer));
+                }
+
+                public $(&decoder_name)($READ_BUF_CLASS_NAME buffer) {
+                    this.buffer = buffer;
+                    this.actingVersion = $SCHEMA_CLASS_NAME.VERSION;
+                    this.blockLength = $(layout.block_length);
+
+                    long schemaId = messageHeaderDecoder(header -> (long) header.schemaId());
+                    if (schemaId != $SCHEMA_CLASS_NAME.ID) {
+                        throw $error.wrongSchema(schemaId, $SCHEMA_CLASS_NAME.ID);
+                    }
+
+                    int templateId = messageHeaderDecoder(header -> (int) header.templateId());
+                    if (templateId != TEMPLATE_ID) {
+                        throw $error.wrongMessageType(templateId, TEMPLATE_ID);
+                    }
+
+                    actingVersion = messageHeaderDecoder(header -> (int) header.version());
+                    blockLength = messageHeaderDecoder(header -> (int) header.blockLength());
+                }
+
+                public static int id() {
+                    return TEMPLATE_ID;
+                }
+
+                $(java::block_comment(["Version of the schema the decoded message was encoded with."]))
+                public int actingVersion() {
+                    return actingVersion;
+                }
+
+                $(java::block_comment(["Length of the root block of the decoded message, which can differ from", "the one in the schema, when the message was encoded with another version."]))
+                public int blockLength() {
+                    return blockLength;
+                }
+
+                $(java::block_comment(["Size of the decoded message without its header, or null until all its", "groups and var data were decoded."]))
+                public Integer size() {
+                    $(null_sizes_check(&groups, &var_data))
+                    return blockLength$(for group in &groups => $[' ']+ $(group.name.to_case(Case::Camel))Size)$(for var in &var_data => $[' ']+ $(var.name.to_case(Case::Camel))Size);
+                }
+
+                $(self.generate_decoder_field("message_header", &header_type, 0, 0)?)
+                $(self.generate_field_decoders(&fields, layout.offsets.iter().map(|field_offset| header_size + field_offset))?)
+                $(self.generate_group_decoders(&groups, &header_offset)?)
+                $(self.generate_var_data_decoders(&var_data, &groups, &header_offset)?)
+                $(for group in &groups {
+                    $['\n']
+                    $(self.generate_group_decoder(group)?)
+                })
+                $(for var_data_field in &var_data {
+                    $['\n']
+                    $(self.generate_var_data_decoder(var_data_field)?)
+                })
+            }
+        };
+
+        self.write_class(&decoder_name, decoder_tokens)
+    }
+
+    fn write_message_encoder(&self, message: &MessageType) -> Result<()> {
+        let name = message.name.as_str();
+        let encoder_name = format!("{}Encoder", name.to_case(Case::UpperCamel));
+        let (fields, groups, var_data) = field_groups(&message.fields);
+        let byte_buffer = &java::import("java.nio", "ByteBuffer");
+
+        let layout =
+            BlockLayout::from_fields(name, &fields, message.block_length, &self.schema.types)?;
+
+        let header_type = Type::Composite(self.schema.types.header_type.clone());
+        let header_size = header_type.size(&self.schema.types)?;
+        let header_offset = quote!($(header_size + layout.block_length));
+
+        let encoder_tokens: Tokens<Java> = quote! {
+            public final class $(&encoder_name) {
+                public static final int TEMPLATE_ID = $(message.id);
+                public static final int BLOCK_LENGTH = $(layout.block_length);
+
+                private final $WRITE_BUF_CLASS_NAME buffer;
+                $(size_fields(&groups, &var_data))
+
+                public $(&encoder_name)($byte_buffer buffer) {
+                    this(new $WRITE_BUF_CLASS_NAME(buffer));
+                }
+
+                public $(&encoder_name)($WRITE_BUF_CLASS_NAME buffer) {
+                    this.buffer = buffer;
+
+                    messageHeaderEncoder(header -> {
+                        header.blockLength(($(self.header_field_type("blockLength")?)) BLOCK_LENGTH);
+                        header.templateId(($(self.header_field_type("templateId")?)) TEMPLATE_ID);
+                        header.schemaId(($(self.header_field_type("schemaId")?)) $SCHEMA_CLASS_NAME.ID);
+                        header.version(($(self.header_field_type("version")?)) $SCHEMA_CLASS_NAME.VERSION);
+                        $(for (field_name, count) in header_counts(&self.schema.types, groups.len(), var_data.len()) {
+                            $['\r']
+                            header.$(field_name)(($(self.header_field_type(field_name)?)) $count);
+                        })
+                        return null;
+                    });
+                }
+
+                public static int id() {
+                    return TEMPLATE_ID;
+                }
+
+                $(java::block_comment(["Size of the encoded message including its header, or null until all its", "groups and var data were encoded."]))
+                public Integer size() {
+                    $(null_sizes_check(&groups, &var_data))
+                    return $header_size + BLOCK_LENGTH$(for group in &groups => $[' ']+ $(group.name.to_case(Case::Camel))Size)$(for var in &var_data => $[' ']+ $(var.name.to_case(Case::Camel))Size);
+                }
+
+                $(self.generate_encoder_field("message_header", &header_type, quote!(0))?)
+                $(self.generate_field_encoders(&fields, &quote!(), layout.offsets.iter().map(|field_offset| header_size + field_offset))?)
+                $(self.generate_group_encoders(&groups, &header_offset)?)
+                $(self.generate_var_data_encoders(&var_data, &groups, &header_offset)?)
+                $(for group in &groups {
+                    $['\n']
+                    $(self.generate_group_encoder(group)?)
+                })
+                $(for var_data_field in &var_data {
+                    $['\n']
+                    $(self.generate_var_data_encoder(var_data_field)?)
+                })
+            }
+        };
+
+        self.write_class(&encoder_name, encoder_tokens)
+    }
+
+    /// Java type of a field of the message header, which the values written by the message
+    /// encoders are cast to.
+    fn header_field_type(&self, field_name: &str) -> Result<String> {
+        let header_type = &self.schema.types.header_type;
+        let field = header_type
+            .fields
+            .iter()
+            .find(|field| field.name() == field_name)
+            .ok_or(anyhow!(
+                "Missing field '{}' in message header '{}'",
+                field_name,
+                header_type.name
+            ))?;
+
+        let encoded_type = match field {
+            Type::EncodedData(encoded_type) => encoded_type.clone(),
+            Type::Reference(reference_type) => {
+                match self.schema.types.find_type(&reference_type.type_name) {
+                    Some(Type::EncodedData(encoded_type)) => encoded_type,
+                    _ => {
+                        return Err(anyhow!(
+                            "Only encoded data type expected for the field '{}' in message header",
+                            field_name
+                        ))
+                    }
+                }
+            }
+            _ => {
+                return Err(anyhow!(
+                    "Only encoded data type expected for the field '{}' in message header",
+                    field_name
+                ))
+            }
+        };
+
+        let metadata = field_metadata(field_name, &encoded_type, &self.schema.types)?;
+
+        Ok(metadata.lang_type.name.to_owned())
+    }
+
+    /// Writes the decoder of any message of the schema, which checks the schema id and picks
+    /// the message decoder by the template id in the message header, and the visitor of the
+    /// decoded messages.
+    fn write_message_dispatcher(&self, message_types: &[&MessageType]) -> Result<()> {
+        let header_decoder_name = format!(
+            "{}Decoder",
+            self.schema.types.header_type.name.to_case(Case::UpperCamel)
+        );
+        let byte_buffer = &java::import("java.nio", "ByteBuffer");
+        let error = &self.error_class();
+
+        let variants = message_types
+            .iter()
+            .map(|message_type| message_type.name.to_case(Case::UpperCamel))
+            .collect::<Vec<_>>();
+
+        let dispatcher_tokens: Tokens<Java> = quote! {
+            $(java::block_comment(["Decoder of any message of the schema."]))
+            public final class AnyMessageDecoder {
+                private final int templateId;
+                private final int actingVersion;
+                private final Object decoder;
+
+                public AnyMessageDecoder($byte_buffer buffer) {
+                    this(new $READ_BUF_CLASS_NAME(buffer));
+                }
+
+                public AnyMessageDecoder($READ_BUF_CLASS_NAME buffer) {
+                    $(&header_decoder_name) header = new $(&header_decoder_name)(buffer);
+                    long schemaId = header.schemaId();
+                    if (schemaId != $SCHEMA_CLASS_NAME.ID) {
+                        throw $error.wrongSchema(schemaId, $SCHEMA_CLASS_NAME.ID);
+                    }
+
+                    this.templateId = (int) header.templateId();
+                    this.actingVersion = (int) header.version();
+
+                    switch (templateId) {
+                        $(for name in &variants {
+                            $['\r']
+                            case $(name)Decoder.TEMPLATE_ID:
+                                this.decoder = new $(name)Decoder(buffer);
+                                break;
+                        })
+                        default:
+                            throw $error.unknownMessageType(templateId);
+                    }
+                }
+
+                public int templateId() {
+                    return templateId;
+                }
+
+                $(java::block_comment(["Version of the schema the decoded message was encoded with."]))
+                public int actingVersion() {
+                    return actingVersion;
+                }
+
+                $(java::block_comment(["Decoder of the message, of the class with the template id of the message."]))
+                public Object decoder() {
+                    return decoder;
+                }
+
+                $(java::block_comment(["Calls the method of the visitor for the type of the decoded message."]))
+                public void accept(MessageVisitor visitor) {
+                    switch (templateId) {
+                        $(for name in &variants {
+                            $['\r']
+                            case $(name)Decoder.TEMPLATE_ID:
+                                visitor.on$(name)(($(name)Decoder) decoder);
+                                break;
+                        })
+                        default:
+                            throw $error.unknownMessageType(templateId);
+                    }
+                }
+            }
+        };
+
+        let visitor_tokens: Tokens<Java> = quote! {
+            $(java::block_comment(["Visitor of the decoded messages. Messages without an overridden method are ignored."]))
+            public interface MessageVisitor {
+                $(for name in &variants join ($['\n']) {
+                    default void on$(name)($(name)Decoder decoder) {
+                    }
+                })
+            }
+        };
+
+        self.write_class("AnyMessageDecoder", dispatcher_tokens)?;
+        self.write_class("MessageVisitor", visitor_tokens)
+    }
+
+    pub fn write_message_codecs(&self) -> Result<()> {
+        let message_types = self
+            .schema
+            .message_types
+            .message_types
+            .values()
+            .collect::<Vec<_>>();
+
+        for message_type in &message_types {
+            self.write_message_decoder(message_type)?;
+            self.write_message_encoder(message_type)?;
+        }
+
+        if !message_types.is_empty() {
+            self.write_message_dispatcher(&message_types)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns null early from the size of a message, until the sizes of all its groups and
+/// var data were recorded.
+fn null_sizes_check(groups: &[&GroupType], var_data: &[&VariableDataType]) -> Tokens<Java> {
+    let names = groups
+        .iter()
+        .map(|group| group.name.as_str())
+        .chain(var_data.iter().map(|var| var.name.as_str()))
+        .map(|name| format!("{}Size == null", name.to_case(Case::Camel)))
+        .collect::<Vec<_>>();
+
+    if names.is_empty() {
+        return quote!();
+    }
+
+    quote! {
+        if ($(names.join(" || "))) {
+            return null;
+        }
+        $['\n']
+    }
+}
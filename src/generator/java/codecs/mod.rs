@@ -0,0 +1,207 @@
+mod composite_type;
+mod enum_type;
+mod group_type;
+mod message_type;
+mod set_type;
+mod var_data_type;
+
+use crate::generator::common::FieldMetadata;
+use crate::models::types::encoded_data_type::EncodedDataType;
+use crate::models::types::primitive_type::{NativeType, PrimitiveConvertible, ResolvableType};
+use crate::models::types::SizedEncoded;
+use crate::models::TypeMap;
+use anyhow::Result;
+use convert_case::{Case, Casing};
+use genco::prelude::*;
+
+/// Metadata of an encoded field, with the field name in lower camel case of Java.
+pub(crate) fn field_metadata(
+    field_name: &str,
+    encoded_type: &EncodedDataType,
+    types: &TypeMap,
+) -> Result<FieldMetadata<Java>> {
+    let field_primitive_type = encoded_type.primitive_type.resolved(&types.encoded_types)?;
+    let field_size = encoded_type.size(types)?;
+    let type_size = encoded_type.primitive_type.size(types)?;
+
+    Ok(FieldMetadata {
+        field_name: field_name.to_case(Case::Camel),
+        field_primitive_type: field_primitive_type.clone(),
+        type_size,
+        field_length: field_size / type_size,
+        lang_type: field_primitive_type.lang_primitive(&types.encoded_types)?,
+        encoding: encoded_type.character_encoding,
+    })
+}
+
+/// Suffix of the `ReadBuf`/`WriteBuf` accessors of a resolved primitive type, e.g. `U16`
+/// for `getU16At`.
+pub(crate) fn accessor(native_type: &NativeType) -> &'static str {
+    match native_type {
+        NativeType::Char | NativeType::Int8 => "I8",
+        NativeType::UInt8 => "U8",
+        NativeType::UInt16 => "U16",
+        NativeType::UInt32 => "U32",
+        NativeType::UInt64 => "U64",
+        NativeType::Int16 => "I16",
+        NativeType::Int32 => "I32",
+        NativeType::Int64 => "I64",
+        NativeType::Float => "F32",
+        NativeType::Double => "F64",
+        NativeType::Reference(type_name) => unreachable!("Resolved reference: {}", type_name),
+    }
+}
+
+/// Boxed counterpart of a Java primitive type, used for optional values.
+pub(crate) fn boxed(java_type: &str) -> &'static str {
+    match java_type {
+        "byte" => "Byte",
+        "short" => "Short",
+        "int" => "Integer",
+        "long" => "Long",
+        "float" => "Float",
+        _ => "Double",
+    }
+}
+
+/// Java literal of a numeric value from the schema, typed as the primitive type.
+pub(crate) fn literal(value: &str, native_type: &NativeType) -> String {
+    match native_type {
+        NativeType::Char | NativeType::Int8 => format!("(byte) {}", value),
+        NativeType::UInt8 | NativeType::Int16 => format!("(short) {}", value),
+        NativeType::UInt16 | NativeType::Int32 => value.to_owned(),
+        // Values above Long.MAX_VALUE keep their bits in the signed long
+        NativeType::UInt64 => match value.parse::<u64>() {
+            Ok(value) => format!("{}L", value as i64),
+            Err(_) => format!("{}L", value),
+        },
+        NativeType::UInt32 | NativeType::Int64 => format!("{}L", value),
+        NativeType::Float => format!("{}f", value),
+        NativeType::Double | NativeType::Reference(_) => value.to_owned(),
+    }
+}
+
+/// Default null value of a primitive type, see `NativeType::null`.
+pub(crate) fn null_literal(native_type: &NativeType) -> &'static str {
+    match native_type {
+        NativeType::Char => "(byte) 0",
+        NativeType::UInt8 => "(short) 255",
+        NativeType::UInt16 => "65535",
+        NativeType::UInt32 => "4294967295L",
+        NativeType::UInt64 => "-1L",
+        NativeType::Int8 => "Byte.MIN_VALUE",
+        NativeType::Int16 => "Short.MIN_VALUE",
+        NativeType::Int32 => "Integer.MIN_VALUE",
+        NativeType::Int64 => "Long.MIN_VALUE",
+        NativeType::Float => "Float.NaN",
+        NativeType::Double | NativeType::Reference(_) => "Double.NaN",
+    }
+}
+
+/// Largest value of an integer primitive type, used for the counters of groups and var data.
+pub(crate) fn max_literal(native_type: &NativeType) -> &'static str {
+    match native_type {
+        NativeType::Char | NativeType::Int8 => "Byte.MAX_VALUE",
+        NativeType::UInt8 => "255",
+        NativeType::UInt16 => "65535",
+        NativeType::UInt32 => "4294967295L",
+        NativeType::Int16 => "Short.MAX_VALUE",
+        NativeType::Int32 => "Integer.MAX_VALUE",
+        _ => "Long.MAX_VALUE",
+    }
+}
+
+/// Null value of an optional field, either declared by the `nullValue` of the encoded type,
+/// or the default one for its primitive type.
+pub(crate) fn null_value(encoded_type: &EncodedDataType, metadata: &FieldMetadata<Java>) -> String {
+    match &encoded_type.null_value {
+        Some(null_value) => literal(null_value, &metadata.field_primitive_type),
+        None => null_literal(&metadata.field_primitive_type).to_owned(),
+    }
+}
+
+pub(crate) fn null_value_condition(
+    variable: &str,
+    encoded_type: &EncodedDataType,
+    metadata: &FieldMetadata<Java>,
+) -> Tokens<Java> {
+    match (&metadata.field_primitive_type, &encoded_type.null_value) {
+        (NativeType::Float, None) => quote!(Float.isNaN($variable)),
+        (NativeType::Double, None) => quote!(Double.isNaN($variable)),
+        _ => quote!($variable == $(null_value(encoded_type, metadata))),
+    }
+}
+
+/// Checks of a `variable` against the `minValue` and `maxValue` of the encoded type.
+pub(crate) fn bounds_checks(
+    field_name: &str,
+    variable: &str,
+    encoded_type: &EncodedDataType,
+    native_type: &NativeType,
+    error: &java::Import,
+) -> Tokens<Java> {
+    let mut checks = quote!();
+    let unsigned = matches!(
+        native_type,
+        NativeType::UInt8 | NativeType::UInt16 | NativeType::UInt32 | NativeType::UInt64
+    );
+
+    if let Some(min) = &encoded_type.min_value {
+        // Unsigned values can never be below zero
+        if !(unsigned && min.parse::<u64>() == Ok(0)) {
+            checks.append(bounds_check(
+                field_name,
+                variable,
+                "<",
+                min,
+                native_type,
+                "min",
+                error,
+            ));
+            checks.line();
+        }
+    }
+
+    if let Some(max) = &encoded_type.max_value {
+        checks.append(bounds_check(
+            field_name,
+            variable,
+            ">",
+            max,
+            native_type,
+            "max",
+            error,
+        ));
+        checks.line();
+    }
+
+    checks
+}
+
+fn bounds_check(
+    field_name: &str,
+    variable: &str,
+    operator: &str,
+    limit: &str,
+    native_type: &NativeType,
+    limit_name: &str,
+    error: &java::Import,
+) -> Tokens<Java> {
+    // uint64 values are kept in a signed long, so they have to be compared as unsigned
+    let (condition, value) = match native_type {
+        NativeType::UInt64 => (
+            quote!(Long.compareUnsigned($variable, $(literal(limit, native_type))) $operator 0),
+            quote!(Long.toUnsignedString($variable)),
+        ),
+        _ => (
+            quote!($variable $operator $(literal(limit, native_type))),
+            quote!($variable),
+        ),
+    };
+
+    quote! {
+        if ($condition) {
+            throw $error.valueOutOfBounds($(quoted(field_name)), $value + $(quoted(format!(" {} {} ({})", operator, limit, limit_name))));
+        }
+    }
+}
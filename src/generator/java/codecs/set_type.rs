@@ -0,0 +1,80 @@
+use anyhow::Result;
+use convert_case::{Case, Casing};
+
+use crate::generator::java::module::ModuleGenerator;
+use crate::models::types::primitive_type::PrimitiveConvertible;
+use crate::models::types::set_type::{Choice, SetType};
+use genco::prelude::*;
+
+impl ModuleGenerator<'_> {
+    fn generate_choice_token(name: &str, java_type: &str, choice: &Choice) -> Tokens<Java> {
+        let choice_name = choice.name.to_case(Case::UpperCamel);
+
+        quote! {
+            $['\n']
+            public boolean get$(&choice_name)() {
+                return 0 != (value & (1L << $(choice.value)));
+            }
+
+            public $name set$(&choice_name)(boolean value) {
+                this.value = ($java_type) (value ? this.value | (1L << $(choice.value)) : this.value & ~(1L << $(choice.value)));
+                return this;
+            }
+        }
+    }
+
+    fn write_set_codec(&self, set_type: &SetType) -> Result<()> {
+        let name = set_type.name.to_case(Case::UpperCamel);
+        let java_type = set_type
+            .encoding_type
+            .lang_primitive(&self.schema.types.encoded_types)?;
+
+        let set_tokens: Tokens<Java> = quote! {
+            public final class $(&name) {
+                private $(&java_type) value;
+
+                public $(&name)() {
+                }
+
+                public $(&name)($(&java_type) value) {
+                    this.value = value;
+                }
+
+                public $(&java_type) value() {
+                    return value;
+                }
+
+                public $(&name) clear() {
+                    value = 0;
+                    return this;
+                }
+                $(for choice in &set_type.choices => $(Self::generate_choice_token(&name, java_type.name, choice)))
+
+                @Override
+                public boolean equals(Object other) {
+                    return other instanceof $(&name) && (($(&name)) other).value == value;
+                }
+
+                @Override
+                public int hashCode() {
+                    return Long.hashCode(value);
+                }
+
+                @Override
+                public String toString() {
+                    return $(quoted(format!("{}(", name))) + value + ")";
+                }
+            }
+        };
+
+        self.write_class(&name, set_tokens)
+    }
+
+    pub fn write_set_codecs(&self) -> Result<()> {
+        for set_type in self.schema.types.set_types.values() {
+            self.write_set_codec(set_type)?;
+        }
+
+        Ok(())
+    }
+}
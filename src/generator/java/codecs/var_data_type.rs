@@ -0,0 +1,201 @@
+use crate::generator::common::FieldMetadata;
+use crate::generator::java::codecs::{accessor, field_metadata, max_literal};
+use crate::generator::java::constants::{READ_BUF_CLASS_NAME, WRITE_BUF_CLASS_NAME};
+use crate::generator::java::module::ModuleGenerator;
+use crate::models::types::composite_type::CompositeType;
+use crate::models::types::primitive_type::NativeType;
+use crate::models::types::variable_data_type::VariableDataType;
+use crate::models::types::{CharacterEncoding, Type};
+use crate::models::TypeMap;
+use anyhow::{anyhow, Result};
+use convert_case::{Case, Casing};
+use genco::prelude::*;
+
+impl ModuleGenerator<'_> {
+    /// Generates the decoder of var data as a class nested in the decoder of its message
+    /// or group.
+    pub(crate) fn generate_var_data_decoder(
+        &self,
+        var_data: &VariableDataType,
+    ) -> Result<Tokens<Java>> {
+        let name = var_data.name.as_str();
+        let decoder_name = format!("{}Decoder", name.to_case(Case::UpperCamel));
+        let repr_type = var_data.repr_type(&self.schema.types.composite_types)?;
+
+        let (length_type_metadata, value_type_metadata) =
+            repr_type_metadata(name, repr_type, &self.schema.types)?;
+
+        let length_type_native = &length_type_metadata.field_primitive_type;
+        let length_type_size = length_type_metadata.type_size;
+
+        let value_type_native = &value_type_metadata.field_primitive_type;
+        let value_type_primitive = &value_type_metadata.lang_type;
+        let value_type_size = value_type_metadata.type_size;
+
+        Ok(quote! {
+            public static final class $(&decoder_name) {
+                private final $READ_BUF_CLASS_NAME buffer;
+
+                public $(&decoder_name)($READ_BUF_CLASS_NAME buffer) {
+                    this.buffer = buffer;
+                }
+
+                public int length() {
+                    // Var data missing in older versions of the schema has no buffer at all
+                    if (buffer.length() < $length_type_size) {
+                        return 0;
+                    }
+
+                    return (int) buffer.get$(accessor(length_type_native))At(0);
+                }
+
+                public int size() {
+                    return length() * $value_type_size + $length_type_size;
+                }
+
+                public $value_type_primitive getAt(int index) {
+                    int offset = index * $value_type_size + $length_type_size;
+
+                    return buffer.get$(accessor(value_type_native))At(offset);
+                }
+                $(if is_bytes(value_type_native) {
+                    $['\n']
+                    public byte[] getSliceAt(int index, int length) {
+                        if (length == 0) {
+                            return new byte[0];
+                        }
+
+                        int offset = index * $value_type_size + $length_type_size;
+
+                        return buffer.getSliceAt(offset, length);
+                    }
+                })
+            }
+        })
+    }
+
+    /// Generates the encoder of var data as a class nested in the encoder of its message
+    /// or group.
+    pub(crate) fn generate_var_data_encoder(
+        &self,
+        var_data: &VariableDataType,
+    ) -> Result<Tokens<Java>> {
+        let name = var_data.name.as_str();
+        let encoder_name = format!("{}Encoder", name.to_case(Case::UpperCamel));
+        let repr_type = var_data.repr_type(&self.schema.types.composite_types)?;
+        let error = &self.error_class();
+        let standard_charsets = &java::import("java.nio.charset", "StandardCharsets");
+
+        let (length_type_metadata, value_type_metadata) =
+            repr_type_metadata(name, repr_type, &self.schema.types)?;
+
+        let length_type_native = &length_type_metadata.field_primitive_type;
+        let length_type_primitive = &length_type_metadata.lang_type;
+        let length_type_size = length_type_metadata.type_size;
+        let max_length = max_literal(length_type_native);
+
+        let value_type_native = &value_type_metadata.field_primitive_type;
+        let value_type_primitive = &value_type_metadata.lang_type;
+        let value_type_size = value_type_metadata.type_size;
+        let ascii = matches!(value_type_metadata.encoding, Some(CharacterEncoding::Ascii));
+
+        Ok(quote! {
+            public static final class $(&encoder_name) {
+                private final $WRITE_BUF_CLASS_NAME buffer;
+                private int length;
+
+                public $(&encoder_name)($WRITE_BUF_CLASS_NAME buffer) {
+                    this.buffer = buffer;
+                }
+
+                public int size() {
+                    return length * $value_type_size + $length_type_size;
+                }
+
+                public void putAt(int index, $value_type_primitive value) {
+                    $(if ascii {
+                        if ((value & 0xFF) > 127) {
+                            throw $error.invalidStringValue(String.valueOf((char) (value & 0xFF)));
+                        }
+                        $['\n']
+                    })
+                    if (index >= length) {
+                        if ((long) index + 1 > $max_length) {
+                            throw $error.varDataOutOfBounds($(quoted(name.to_case(Case::Camel))));
+                        }
+
+                        length = index + 1;
+                    }
+
+                    int offset = index * $value_type_size + $length_type_size;
+                    buffer.put$(accessor(value_type_native))At(offset, value);
+                }
+                $(if is_bytes(value_type_native) {
+                    $['\n']
+                    public void putSliceAt(int index, byte[] value) {
+                        $(if ascii {
+                            for (byte part : value) {
+                                if (part < 0) {
+                                    throw $error.invalidStringValue(new String(value, $standard_charsets.UTF_8));
+                                }
+                            }
+                            $['\n']
+                        })
+                        long dataEnd = (long) index + value.length;
+
+                        if (dataEnd >= length) {
+                            if (dataEnd > $max_length) {
+                                throw $error.varDataOutOfBounds($(quoted(name.to_case(Case::Camel))));
+                            }
+
+                            length = (int) dataEnd;
+                        }
+
+                        int offset = index * $value_type_size + $length_type_size;
+                        buffer.putBytesAt(offset, value);
+                    }
+                })
+
+                $(java::block_comment(["Writes the length of the var data, after all its values were encoded."]))
+                public void finish() {
+                    buffer.put$(accessor(length_type_native))At(0, ($length_type_primitive) length);
+                }
+            }
+        })
+    }
+}
+
+/// Single byte values can be read and written as slices.
+fn is_bytes(native_type: &NativeType) -> bool {
+    matches!(native_type, NativeType::Char | NativeType::UInt8)
+}
+
+fn repr_type_metadata(
+    var_data_name: &str,
+    repr_type: &CompositeType,
+    types: &TypeMap,
+) -> Result<(FieldMetadata<Java>, FieldMetadata<Java>)> {
+    let length_type = match &repr_type.fields[0] {
+        Type::EncodedData(length_type) => length_type,
+        _ => {
+            return Err(anyhow!(
+            "Only encoded data type expected for the length type in variable data encoding '{}'",
+            var_data_name
+        ))
+        }
+    };
+    let length_type_metadata = field_metadata("", length_type, types)?;
+
+    let value_type = match &repr_type.fields[1] {
+        Type::EncodedData(value_type) => value_type,
+        _ => {
+            return Err(anyhow!(
+                "Only encoded data type expected for the value type in variable data encoding '{}'",
+                var_data_name
+            ))
+        }
+    };
+    let value_type_metadata = field_metadata("", value_type, types)?;
+
+    Ok((length_type_metadata, value_type_metadata))
+}
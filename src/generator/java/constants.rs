@@ -0,0 +1,5 @@
+pub const SRC_DIR: &str = "src/main/java";
+pub const ERROR_CLASS_NAME: &str = "SbeException";
+pub const SCHEMA_CLASS_NAME: &str = "Schema";
+pub const READ_BUF_CLASS_NAME: &str = "ReadBuf";
+pub const WRITE_BUF_CLASS_NAME: &str = "WriteBuf";
@@ -0,0 +1,137 @@
+use anyhow::Result;
+
+use crate::generator::java::constants::READ_BUF_CLASS_NAME;
+use crate::generator::java::module::ModuleGenerator;
+use crate::models::ByteOrder;
+use genco::prelude::*;
+
+impl ModuleGenerator<'_> {
+    pub fn write_read_buf_class(&self) -> Result<()> {
+        let byte_buffer = &java::import("java.nio", "ByteBuffer");
+        let byte_order = &java::import("java.nio", "ByteOrder");
+        let character_coding_exception =
+            &java::import("java.nio.charset", "CharacterCodingException");
+        let standard_charsets = &java::import("java.nio.charset", "StandardCharsets");
+        let error = &self.error_class();
+
+        let byte_order_name = match self.schema.byte_order {
+            ByteOrder::BigEndian => "BIG_ENDIAN",
+            ByteOrder::LittleEndian => "LITTLE_ENDIAN",
+        };
+
+        let read_buf_content: Tokens<Java> = quote! {
+            $(java::block_comment(["Read-only view of the bytes of an encoded message, or a part of it."]))
+            public final class $READ_BUF_CLASS_NAME {
+                private final $byte_buffer buffer;
+                private final int offset;
+                private final int length;
+
+                $(java::block_comment(["Wraps the remaining bytes of the buffer, without changing its position or byte order."]))
+                public $READ_BUF_CLASS_NAME($byte_buffer buffer) {
+                    this(buffer.duplicate().order($byte_order.$byte_order_name), buffer.position(), buffer.remaining());
+                }
+
+                private $READ_BUF_CLASS_NAME($byte_buffer buffer, int offset, int length) {
+                    this.buffer = buffer;
+                    this.offset = offset;
+                    this.length = length;
+                }
+
+                public static $READ_BUF_CLASS_NAME empty() {
+                    return new $READ_BUF_CLASS_NAME($byte_buffer.allocate(0));
+                }
+
+                public int length() {
+                    return length;
+                }
+
+                $(java::block_comment(["Returns the bytes from the index to the end of the buffer."]))
+                public $READ_BUF_CLASS_NAME splitAt(int index) {
+                    checkBounds(index, 0);
+
+                    return new $READ_BUF_CLASS_NAME(buffer, offset + index, length - index);
+                }
+
+                private void checkBounds(int index, int count) {
+                    int dataEnd = index + count;
+
+                    if (index < 0 || dataEnd > length) {
+                        throw $error.codecOutOfBounds(dataEnd, length);
+                    }
+                }
+
+                public byte[] getSliceAt(int index, int len) {
+                    checkBounds(index, len);
+
+                    byte[] value = new byte[len];
+                    buffer.get(offset + index, value);
+
+                    return value;
+                }
+
+                $(java::block_comment(["Decodes a string, which can be either UTF-8 or ASCII, as any valid ASCII string is also a valid UTF-8 string."]))
+                public String getStringAt(int index, int len) {
+                    byte[] src = getSliceAt(index, len);
+
+                    try {
+                        return $standard_charsets.UTF_8.newDecoder().decode($byte_buffer.wrap(src)).toString();
+                    } catch ($character_coding_exception e) {
+                        throw $error.invalidStringValue(e.toString());
+                    }
+                }
+
+                public short getU8At(int index) {
+                    checkBounds(index, 1);
+                    return (short) (buffer.get(offset + index) & 0xFF);
+                }
+
+                public int getU16At(int index) {
+                    checkBounds(index, 2);
+                    return buffer.getShort(offset + index) & 0xFFFF;
+                }
+
+                public long getU32At(int index) {
+                    checkBounds(index, 4);
+                    return buffer.getInt(offset + index) & 0xFFFFFFFFL;
+                }
+
+                public long getU64At(int index) {
+                    checkBounds(index, 8);
+                    return buffer.getLong(offset + index);
+                }
+
+                public byte getI8At(int index) {
+                    checkBounds(index, 1);
+                    return buffer.get(offset + index);
+                }
+
+                public short getI16At(int index) {
+                    checkBounds(index, 2);
+                    return buffer.getShort(offset + index);
+                }
+
+                public int getI32At(int index) {
+                    checkBounds(index, 4);
+                    return buffer.getInt(offset + index);
+                }
+
+                public long getI64At(int index) {
+                    checkBounds(index, 8);
+                    return buffer.getLong(offset + index);
+                }
+
+                public float getF32At(int index) {
+                    checkBounds(index, 4);
+                    return buffer.getFloat(offset + index);
+                }
+
+                public double getF64At(int index) {
+                    checkBounds(index, 8);
+                    return buffer.getDouble(offset + index);
+                }
+            }
+        };
+
+        self.write_class(READ_BUF_CLASS_NAME, read_buf_content)
+    }
+}
@@ -0,0 +1,115 @@
+use anyhow::Result;
+
+use crate::generator::java::constants::WRITE_BUF_CLASS_NAME;
+use crate::generator::java::module::ModuleGenerator;
+use crate::models::ByteOrder;
+use genco::prelude::*;
+
+impl ModuleGenerator<'_> {
+    pub fn write_write_buf_class(&self) -> Result<()> {
+        let byte_buffer = &java::import("java.nio", "ByteBuffer");
+        let byte_order = &java::import("java.nio", "ByteOrder");
+        let error = &self.error_class();
+
+        let byte_order_name = match self.schema.byte_order {
+            ByteOrder::BigEndian => "BIG_ENDIAN",
+            ByteOrder::LittleEndian => "LITTLE_ENDIAN",
+        };
+
+        let write_buf_content: Tokens<Java> = quote! {
+            $(java::block_comment(["Writable view of the bytes of an encoded message, or a part of it."]))
+            public final class $WRITE_BUF_CLASS_NAME {
+                private final $byte_buffer buffer;
+                private final int offset;
+                private final int length;
+
+                $(java::block_comment(["Wraps the remaining bytes of the buffer, without changing its position or byte order."]))
+                public $WRITE_BUF_CLASS_NAME($byte_buffer buffer) {
+                    this(buffer.duplicate().order($byte_order.$byte_order_name), buffer.position(), buffer.remaining());
+                }
+
+                private $WRITE_BUF_CLASS_NAME($byte_buffer buffer, int offset, int length) {
+                    this.buffer = buffer;
+                    this.offset = offset;
+                    this.length = length;
+                }
+
+                public int length() {
+                    return length;
+                }
+
+                $(java::block_comment(["Returns the bytes from the index to the end of the buffer."]))
+                public $WRITE_BUF_CLASS_NAME splitAt(int index) {
+                    checkBounds(index, 0);
+
+                    return new $WRITE_BUF_CLASS_NAME(buffer, offset + index, length - index);
+                }
+
+                private void checkBounds(int index, int count) {
+                    int dataEnd = index + count;
+
+                    if (index < 0 || dataEnd > length) {
+                        throw $error.codecOutOfBounds(dataEnd, length);
+                    }
+                }
+
+                public void putBytesAt(int index, byte[] value) {
+                    checkBounds(index, value.length);
+                    buffer.put(offset + index, value);
+                }
+
+                public void putU8At(int index, short value) {
+                    checkBounds(index, 1);
+                    buffer.put(offset + index, (byte) value);
+                }
+
+                public void putU16At(int index, int value) {
+                    checkBounds(index, 2);
+                    buffer.putShort(offset + index, (short) value);
+                }
+
+                public void putU32At(int index, long value) {
+                    checkBounds(index, 4);
+                    buffer.putInt(offset + index, (int) value);
+                }
+
+                public void putU64At(int index, long value) {
+                    checkBounds(index, 8);
+                    buffer.putLong(offset + index, value);
+                }
+
+                public void putI8At(int index, byte value) {
+                    checkBounds(index, 1);
+                    buffer.put(offset + index, value);
+                }
+
+                public void putI16At(int index, short value) {
+                    checkBounds(index, 2);
+                    buffer.putShort(offset + index, value);
+                }
+
+                public void putI32At(int index, int value) {
+                    checkBounds(index, 4);
+                    buffer.putInt(offset + index, value);
+                }
+
+                public void putI64At(int index, long value) {
+                    checkBounds(index, 8);
+                    buffer.putLong(offset + index, value);
+                }
+
+                public void putF32At(int index, float value) {
+                    checkBounds(index, 4);
+                    buffer.putFloat(offset + index, value);
+                }
+
+                public void putF64At(int index, double value) {
+                    checkBounds(index, 8);
+                    buffer.putDouble(offset + index, value);
+                }
+            }
+        };
+
+        self.write_class(WRITE_BUF_CLASS_NAME, write_buf_content)
+    }
+}
@@ -0,0 +1,114 @@
+use crate::generator::java::constants::ERROR_CLASS_NAME;
+use crate::generator::java::JavaGenerator;
+use crate::generator::write_file;
+use anyhow::Result;
+use genco::prelude::*;
+
+impl JavaGenerator {
+    pub fn write_error_class(&self) -> Result<()> {
+        let error_class_content: Tokens<Java> = quote! {
+            $(java::block_comment(["Error of encoding or decoding a message."]))
+            public class SbeException extends RuntimeException {
+                public enum Kind {
+                    INVALID_STRING_VALUE,
+                    INVALID_ENUM_VALUE,
+                    VALUE_OUT_OF_BOUNDS,
+                    WRONG_SLICE_SIZE,
+                    MISSING_GROUP_SIZE,
+                    MISSING_VAR_DATA_SIZE,
+                    GROUP_OUT_OF_BOUNDS,
+                    VAR_DATA_OUT_OF_BOUNDS,
+                    WRONG_SCHEMA,
+                    WRONG_MESSAGE_TYPE,
+                    UNKNOWN_MESSAGE_TYPE,
+                    CODEC_OUT_OF_BOUNDS,
+                }
+
+                private static final long serialVersionUID = 1L;
+
+                private final Kind kind;
+
+                public SbeException(Kind kind, String message) {
+                    super(message);
+                    this.kind = kind;
+                }
+
+                public Kind kind() {
+                    return kind;
+                }
+
+                public static SbeException invalidStringValue(String value) {
+                    return new SbeException(Kind.INVALID_STRING_VALUE, "invalid ascii string: " + value);
+                }
+
+                public static SbeException invalidEnumValue(String typeName, Object value) {
+                    return new SbeException(Kind.INVALID_ENUM_VALUE, "invalid enum value for '" + typeName + "': " + value);
+                }
+
+                public static SbeException valueOutOfBounds(String fieldName, String message) {
+                    return new SbeException(Kind.VALUE_OUT_OF_BOUNDS, "value out of bounds for field '" + fieldName + "': " + message);
+                }
+
+                public static SbeException wrongSliceSize(String message) {
+                    return new SbeException(Kind.WRONG_SLICE_SIZE, "wrong slice size: " + message);
+                }
+
+                public static SbeException missingGroupSize(String groupName) {
+                    return new SbeException(Kind.MISSING_GROUP_SIZE, "missing group size: " + groupName);
+                }
+
+                public static SbeException missingVarDataSize(String varDataName) {
+                    return new SbeException(Kind.MISSING_VAR_DATA_SIZE, "missing var data size: " + varDataName);
+                }
+
+                public static SbeException groupOutOfBounds(String groupName) {
+                    return new SbeException(Kind.GROUP_OUT_OF_BOUNDS, "group out of bounds: " + groupName);
+                }
+
+                public static SbeException varDataOutOfBounds(String varDataName) {
+                    return new SbeException(Kind.VAR_DATA_OUT_OF_BOUNDS, "var data out of bounds: " + varDataName);
+                }
+
+                public static SbeException wrongSchema(long schemaId, long expectedSchemaId) {
+                    return new SbeException(Kind.WRONG_SCHEMA, "received message had wrong schema: " + schemaId + ", expected " + expectedSchemaId);
+                }
+
+                public static SbeException wrongMessageType(int templateId, int expectedTemplateId) {
+                    return new SbeException(Kind.WRONG_MESSAGE_TYPE, "received message had wrong type: " + templateId + ", expected " + expectedTemplateId);
+                }
+
+                public static SbeException unknownMessageType(int templateId) {
+                    return new SbeException(Kind.UNKNOWN_MESSAGE_TYPE, "received message had unknown type: " + templateId);
+                }
+
+                public static SbeException codecOutOfBounds(int dataEnd, int length) {
+                    return new SbeException(Kind.CODEC_OUT_OF_BOUNDS, "codec out of bounds: " + dataEnd + " > " + length);
+                }
+
+                $(java::block_comment(["Returns the size of a group, which must be decoded or encoded first."]))
+                public static int requireGroupSize(Integer size, String groupName) {
+                    if (size == null) {
+                        throw missingGroupSize(groupName);
+                    }
+
+                    return size;
+                }
+
+                $(java::block_comment(["Returns the size of a var data field, which must be decoded or encoded first."]))
+                public static int requireVarDataSize(Integer size, String varDataName) {
+                    if (size == null) {
+                        throw missingVarDataSize(varDataName);
+                    }
+
+                    return size;
+                }
+            }
+        };
+
+        write_file(
+            &self.root_path().join(format!("{}.java", ERROR_CLASS_NAME)),
+            &java::Config::default().with_package(self.root_package()),
+            error_class_content,
+        )
+    }
+}
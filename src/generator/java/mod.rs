@@ -0,0 +1,169 @@
+mod codecs;
+mod constants;
+mod decoder;
+mod encoder;
+mod error;
+mod module;
+
+use crate::generator::java::constants::SRC_DIR;
+use crate::generator::CodeGenerator;
+use crate::models::schema::ValidatedMessageSchema;
+use anyhow::Result;
+use convert_case::{Case, Casing};
+use indoc::formatdoc;
+use std::collections::BTreeMap;
+use std::fs::{create_dir_all, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::models::types::encoded_data_type::EncodedDataType;
+use crate::models::types::primitive_type::{
+    LanguagePrimitive, NativeType, PrimitiveConvertible, ResolvableType,
+};
+use genco::prelude::*;
+
+pub struct JavaGenerator {
+    path: PathBuf,
+    project_name: String,
+    project_version: String,
+    bounds_checks: bool,
+    schemas: Vec<ValidatedMessageSchema>,
+}
+
+impl JavaGenerator {
+    pub fn new(
+        path: &Path,
+        project_name: &str,
+        project_version: &str,
+        schemas: Vec<ValidatedMessageSchema>,
+        bounds_checks: bool,
+    ) -> Self {
+        Self {
+            path: path.to_owned(),
+            project_name: project_name.to_owned(),
+            project_version: project_version.to_owned(),
+            bounds_checks,
+            schemas,
+        }
+    }
+
+    /// Root Java package of the project, which contains a package for every schema.
+    fn root_package(&self) -> String {
+        self.project_name.to_case(Case::Snake)
+    }
+
+    fn root_path(&self) -> PathBuf {
+        self.path.join(SRC_DIR).join(self.root_package())
+    }
+
+    fn write_project_files(&self, with_test_dependencies: bool) -> Result<()> {
+        create_dir_all(self.root_path())?;
+
+        self.write_pom_xml(with_test_dependencies)?;
+        self.write_error_class()
+    }
+
+    fn write_pom_xml(&self, with_test_dependencies: bool) -> Result<()> {
+        let test_dependencies = formatdoc! {"
+            <dependencies>
+                    <dependency>
+                        <groupId>org.junit.jupiter</groupId>
+                        <artifactId>junit-jupiter</artifactId>
+                        <version>5.10.2</version>
+                        <scope>test</scope>
+                    </dependency>
+                </dependencies>
+
+                <build>
+                    <plugins>
+                        <plugin>
+                            <groupId>org.apache.maven.plugins</groupId>
+                            <artifactId>maven-surefire-plugin</artifactId>
+                            <version>3.2.5</version>
+                        </plugin>
+                    </plugins>
+                </build>
+        "};
+
+        let pom_xml_content = formatdoc! {"
+                <?xml version=\"1.0\" encoding=\"UTF-8\"?>
+                <project xmlns=\"http://maven.apache.org/POM/4.0.0\"
+                         xmlns:xsi=\"http://www.w3.org/2001/XMLSchema-instance\"
+                         xsi:schemaLocation=\"http://maven.apache.org/POM/4.0.0 http://maven.apache.org/xsd/maven-4.0.0.xsd\">
+                    <modelVersion>4.0.0</modelVersion>
+
+                    <groupId>{package}</groupId>
+                    <artifactId>{name}</artifactId>
+                    <version>{version}</version>
+                    <packaging>jar</packaging>
+
+                    <properties>
+                        <maven.compiler.release>17</maven.compiler.release>
+                        <project.build.sourceEncoding>UTF-8</project.build.sourceEncoding>
+                    </properties>
+
+                    {test_deps}
+                </project>
+            ",
+            package = self.root_package(),
+            name = self.project_name,
+            version = self.project_version,
+            test_deps = if with_test_dependencies {
+                test_dependencies
+            } else {
+                String::new()
+            },
+        };
+
+        let pom_xml_path = self.path.join("pom.xml");
+        let mut pom_xml_file = File::create(pom_xml_path)?;
+        pom_xml_file.write_all(pom_xml_content.as_bytes())?;
+
+        Ok(())
+    }
+}
+
+impl CodeGenerator for JavaGenerator {
+    fn generate_project(&self, with_test_dependencies: bool, _format_project: bool) -> Result<()> {
+        // Project files (pom.xml, ...)
+        self.write_project_files(with_test_dependencies)?;
+
+        // Generate packages. There is no formatter for Java everyone has installed,
+        // so the project is formatted only by the generator itself.
+        let root_package = self.root_package();
+
+        for schema in &self.schemas {
+            let module_generator = module::ModuleGenerator::new(
+                &self.root_path().join(&schema.package),
+                &root_package,
+                schema,
+                self.bounds_checks,
+            );
+
+            module_generator.generate_module()?;
+        }
+
+        Ok(())
+    }
+}
+
+impl PrimitiveConvertible<Java> for NativeType {
+    fn lang_primitive(
+        &self,
+        encoded_types: &BTreeMap<String, EncodedDataType>,
+    ) -> Result<LanguagePrimitive<Java>> {
+        let native_type = self.resolved(encoded_types)?;
+
+        // Java has no unsigned types, so the unsigned ones are widened to the next signed type,
+        // except for uint64, which is kept in a long.
+        Ok(LanguagePrimitive::new(match native_type {
+            NativeType::Char | NativeType::Int8 => "byte",
+            NativeType::UInt8 | NativeType::Int16 => "short",
+            NativeType::UInt16 | NativeType::Int32 => "int",
+            NativeType::UInt32 | NativeType::UInt64 | NativeType::Int64 => "long",
+            NativeType::Float => "float",
+            NativeType::Double => "double",
+            NativeType::Reference(type_name) => unreachable!("Resolved reference: {}", type_name),
+        }))
+    }
+}
@@ -0,0 +1,77 @@
+use crate::generator::java::constants::{ERROR_CLASS_NAME, SCHEMA_CLASS_NAME};
+use crate::generator::write_file;
+use crate::models::schema::ValidatedMessageSchema;
+use anyhow::Result;
+use genco::prelude::*;
+use std::fs::create_dir_all;
+use std::path::{Path, PathBuf};
+
+pub struct ModuleGenerator<'a> {
+    pub(crate) config: java::Config,
+    pub(crate) path: PathBuf,
+    pub(crate) root_package: &'a str,
+    pub(crate) schema: &'a ValidatedMessageSchema,
+    pub(crate) bounds_checks: bool,
+}
+
+impl<'a> ModuleGenerator<'a> {
+    pub fn new(
+        path: &Path,
+        root_package: &'a str,
+        schema: &'a ValidatedMessageSchema,
+        bounds_checks: bool,
+    ) -> Self {
+        Self {
+            config: java::Config::default()
+                .with_package(format!("{}.{}", root_package, schema.package)),
+            path: path.to_owned(),
+            root_package,
+            schema,
+            bounds_checks,
+        }
+    }
+
+    pub fn generate_module(&self) -> Result<()> {
+        create_dir_all(&self.path)?;
+
+        self.write_schema_class()?;
+
+        // Buffers the codecs read from and write to
+        self.write_read_buf_class()?;
+        self.write_write_buf_class()?;
+
+        // Type specific encoders and decoders, the ones of groups and var data
+        // are nested in the classes of their messages
+        self.write_enum_codecs()?;
+        self.write_set_codecs()?;
+        self.write_composite_codecs()?;
+        self.write_message_codecs()
+    }
+
+    fn write_schema_class(&self) -> Result<()> {
+        let schema_class_content: Tokens<Java> = quote! {
+            public final class $SCHEMA_CLASS_NAME {
+                public static final int ID = $(self.schema.id);
+                public static final int VERSION = $(self.schema.version);
+
+                private $SCHEMA_CLASS_NAME() {
+                }
+            }
+        };
+
+        self.write_class(SCHEMA_CLASS_NAME, schema_class_content)
+    }
+
+    pub(crate) fn write_class(&self, class_name: &str, content: Tokens<Java>) -> Result<()> {
+        write_file(
+            &self.path.join(format!("{}.java", class_name)),
+            &self.config,
+            content,
+        )
+    }
+
+    /// Error raised by the codecs, shared by all schemas of the project.
+    pub(crate) fn error_class(&self) -> java::Import {
+        java::import(self.root_package, ERROR_CLASS_NAME)
+    }
+}
@@ -1,6 +1,12 @@
+pub mod c;
 mod common;
+pub mod cpp;
+pub mod csharp;
+pub mod go;
+pub mod java;
 pub mod python;
 pub mod rust;
+pub mod typescript;
 
 use anyhow::Result;
 use genco::fmt;
@@ -22,3 +28,257 @@ fn write_file<L: Lang>(path: &Path, config: &L::Config, content: Tokens<L>) -> R
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::c::CGenerator;
+    use super::cpp::CppGenerator;
+    use super::csharp::CsharpGenerator;
+    use super::go::GoGenerator;
+    use super::java::JavaGenerator;
+    use super::python::pure::PurePythonGenerator;
+    use super::python::PythonGenerator;
+    use super::rust::{RustGenerator, RustLayout};
+    use super::typescript::TypeScriptGenerator;
+    use super::CodeGenerator;
+    use crate::models::schema::{MessageSchema, ValidatedMessageSchema};
+    use std::collections::BTreeMap;
+    use std::fs;
+    use std::path::{Path, PathBuf};
+    use xml_include::resolve_xml_includes;
+
+    fn load_schema(path: &str) -> ValidatedMessageSchema {
+        let merged_content = resolve_xml_includes(Path::new(path)).unwrap();
+        MessageSchema::load_from_string(&merged_content)
+            .unwrap()
+            .validate()
+            .unwrap()
+    }
+
+    fn example_schema() -> ValidatedMessageSchema {
+        load_schema("./examples/example-schema.xml")
+    }
+
+    fn read_tree(root: &Path) -> BTreeMap<PathBuf, Vec<u8>> {
+        let mut files = BTreeMap::new();
+        let mut dirs = vec![root.to_path_buf()];
+
+        while let Some(dir) = dirs.pop() {
+            for entry in fs::read_dir(dir).unwrap() {
+                let path = entry.unwrap().path();
+                if path.is_dir() {
+                    dirs.push(path);
+                } else {
+                    let content = fs::read(&path).unwrap();
+                    files.insert(path.strip_prefix(root).unwrap().to_path_buf(), content);
+                }
+            }
+        }
+
+        files
+    }
+
+    /// Creates a generator of a project in the given path.
+    type GeneratorFn = fn(&Path) -> Box<dyn CodeGenerator>;
+
+    fn assert_reproducible(name: &str, generator: GeneratorFn) {
+        let root =
+            std::env::temp_dir().join(format!("sbe-codegen-{}-{}", name, std::process::id()));
+        let (first, second) = (root.join("first"), root.join("second"));
+
+        generator(&first).generate_project(true, false).unwrap();
+        generator(&second).generate_project(true, false).unwrap();
+
+        let (first_tree, second_tree) = (read_tree(&first), read_tree(&second));
+        fs::remove_dir_all(&root).unwrap();
+
+        assert!(!first_tree.is_empty(), "{} generated no files", name);
+        assert_eq!(
+            first_tree.keys().collect::<Vec<_>>(),
+            second_tree.keys().collect::<Vec<_>>(),
+            "{} generated different files between runs",
+            name
+        );
+        for (path, content) in &first_tree {
+            assert!(
+                content == &second_tree[path],
+                "{} of {} differs between runs",
+                path.display(),
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn test_generation_is_reproducible() {
+        let generators: [(&str, GeneratorFn); 10] = [
+            ("rust", |path| {
+                Box::new(RustGenerator::new(
+                    path,
+                    "example",
+                    "1.0.0",
+                    vec![example_schema()],
+                    RustLayout::Crate {
+                        workspace_root: false,
+                    },
+                    true,
+                    true,
+                ))
+            }),
+            ("rust-module", |path| {
+                Box::new(RustGenerator::new(
+                    path,
+                    "example",
+                    "1.0.0",
+                    vec![example_schema()],
+                    RustLayout::Module {
+                        path: "crate::codecs".to_owned(),
+                    },
+                    true,
+                    true,
+                ))
+            }),
+            ("python", |path| {
+                Box::new(PythonGenerator::new(
+                    path,
+                    "example",
+                    "1.0.0",
+                    vec![example_schema()],
+                    true,
+                ))
+            }),
+            ("python-pure", |path| {
+                Box::new(PurePythonGenerator::new(
+                    path,
+                    "example",
+                    "1.0.0",
+                    vec![example_schema()],
+                    true,
+                ))
+            }),
+            ("java", |path| {
+                Box::new(JavaGenerator::new(
+                    path,
+                    "example",
+                    "1.0.0",
+                    vec![example_schema()],
+                    true,
+                ))
+            }),
+            ("cpp", |path| {
+                Box::new(CppGenerator::new(
+                    path,
+                    "example",
+                    "1.0.0",
+                    vec![example_schema()],
+                    true,
+                ))
+            }),
+            ("c", |path| {
+                Box::new(CGenerator::new(
+                    path,
+                    "example",
+                    "1.0.0",
+                    vec![example_schema()],
+                    true,
+                ))
+            }),
+            ("go", |path| {
+                Box::new(GoGenerator::new(
+                    path,
+                    "example",
+                    vec![example_schema()],
+                    true,
+                ))
+            }),
+            ("typescript", |path| {
+                Box::new(TypeScriptGenerator::new(
+                    path,
+                    "example",
+                    "0.1.0",
+                    vec![example_schema()],
+                    true,
+                ))
+            }),
+            ("csharp", |path| {
+                Box::new(CsharpGenerator::new(
+                    path,
+                    "example",
+                    "0.1.0",
+                    vec![example_schema()],
+                    true,
+                ))
+            }),
+        ];
+
+        for (name, generator) in generators {
+            assert_reproducible(name, generator);
+        }
+    }
+
+    #[test]
+    fn test_rust_module_generation_removes_staged_modules() {
+        let path = std::env::temp_dir().join(format!(
+            "sbe-codegen-rust-module-staging-{}",
+            std::process::id()
+        ));
+        let generator = RustGenerator::new(
+            &path,
+            "example",
+            "1.0.0",
+            vec![example_schema()],
+            RustLayout::Module {
+                path: "crate::codecs".to_owned(),
+            },
+            true,
+            false,
+        );
+
+        generator.generate_project(false, false).unwrap();
+        let tree = read_tree(&path);
+        fs::remove_dir_all(&path).unwrap();
+
+        assert_eq!(
+            tree.keys().collect::<Vec<_>>(),
+            vec![Path::new("codecs.rs")]
+        );
+    }
+
+    /// Whether the encoders of the bounds schema check the bounds of their values.
+    fn rust_encoders_check_bounds(bounds_checks: bool) -> bool {
+        let root = std::env::temp_dir().join(format!(
+            "sbe-codegen-bounds-{}-{}",
+            bounds_checks,
+            std::process::id()
+        ));
+
+        RustGenerator::new(
+            &root,
+            "example",
+            "1.0.0",
+            vec![load_schema("./examples/bounds-schema.xml")],
+            RustLayout::Crate {
+                workspace_root: false,
+            },
+            bounds_checks,
+            false,
+        )
+        .generate_project(false, false)
+        .unwrap();
+
+        let tree = read_tree(&root);
+        fs::remove_dir_all(&root).unwrap();
+
+        tree.iter()
+            .filter(|(path, _)| path.ends_with("encoder.rs"))
+            .any(|(_, content)| {
+                String::from_utf8_lossy(content).contains("SbeError::ValueOutOfBounds")
+            })
+    }
+
+    #[test]
+    fn test_rust_generation_without_bounds_checks() {
+        assert!(rust_encoders_check_bounds(true));
+        assert!(!rust_encoders_check_bounds(false));
+    }
+}
@@ -43,8 +43,8 @@ impl ModuleGenerator<'_> {
         composite_type: &CompositeType,
     ) -> Result<()> {
         let name = composite_type.name.to_case(Case::UpperCamel);
-        let rust_decoder_name = format!("Rust{}Decoder", &name);
-        let rust_encoder_name = format!("Rust{}Encoder", &name);
+        let rust_decoder_name = format!("Rust{}Decoder", name);
+        let rust_encoder_name = format!("Rust{}Encoder", name);
 
         let fields = &composite_type.fields;
         let optional_fields = fields
@@ -73,7 +73,6 @@ impl ModuleGenerator<'_> {
             use crate::$(&self.schema.package)::composites::*;
             use crate::$(&self.schema.package)::enums::*;
             use crate::$(&self.schema.package)::sets::*;
-            use crate::$(&self.schema.package)::groups::*;
             use rust_codecs::error::*;
             use std::convert::TryFrom;
             use std::hash::{DefaultHasher, Hash, Hasher};
@@ -203,10 +202,11 @@ impl ModuleGenerator<'_> {
             let metadata = FieldMetadata::from(field_name, encoded_data_type, &self.schema.types)?;
 
             Ok(quote! {
-                $(if metadata.field_length > 1 {
-                    encoder.$(field_name)(&self.$(field_name))?
-                } else {
-                    encoder.$(field_name)(self.$(field_name).into())?
+                $(match (encoded_data_type.presence, metadata.field_primitive_type, metadata.field_length) {
+                    (Presence::Optional, NativeType::Char, 2..) => encoder.$(field_name)(self.$(field_name).as_deref())?,
+                    (Presence::Optional, _, 2..) => encoder.$(field_name)(self.$(field_name).as_ref())?,
+                    (_, _, 2..) => encoder.$(field_name)(&self.$(field_name))?,
+                    (_, _, _) => encoder.$(field_name)(self.$(field_name).into())?,
                 })
             })
         };
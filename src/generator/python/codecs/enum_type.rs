@@ -105,6 +105,18 @@ impl ModuleGenerator<'_> {
                 }
             }
 
+            impl $(&name) {
+                /// Converts the decoded enum, whose null value is `None`, which is also the
+                /// value of an enum field added in a newer version than the acting one.
+                #[inline]
+                pub fn try_from_nullable(rust_enum: $(&rust_name)) -> Result<Option<Self>, SbeError> {
+                    match rust_enum {
+                        $(&rust_name)::NullVal => Ok(None),
+                        rust_enum => rust_enum.try_into().map(Some),
+                    }
+                }
+            }
+
             impl From<$(&name)> for $(&rust_name) {
                 #[inline]
                 fn from(value: $(&name)) -> $(&rust_name) {
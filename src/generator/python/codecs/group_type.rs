@@ -6,8 +6,9 @@ use crate::models::types::group_type::GroupType;
 use crate::models::types::primitive_type::PrimitiveConvertible;
 use crate::models::types::variable_data_type::VariableDataType;
 use crate::models::types::{Presence, Type};
+use crate::models::FieldPath;
 
-use crate::generator::common::field_groups;
+use crate::generator::common::{field_groups, scoped_module_dir, scoped_module_path};
 use crate::generator::python::module::ModuleGenerator;
 use anyhow::{anyhow, Result};
 use convert_case::{Case, Casing};
@@ -17,29 +18,58 @@ use std::fs::create_dir_all;
 use std::path::Path;
 
 impl ModuleGenerator<'_> {
+    /// Writes the classes of the groups of every message, placed in modules named after
+    /// the message and the enclosing groups. Each class is exposed to Python as an attribute
+    /// of the class of its parent message or group.
     pub fn write_group_codecs(&self) -> Result<()> {
         let module_path = self.path.join(GROUP_MODULE_NAME);
         create_dir_all(&module_path)?;
 
         let mut module_tokens: Tokens<Rust> = quote!();
 
-        for (name, group_type) in &self.schema.message_types.group_types {
-            self.write_group_codec(&module_path, group_type)?;
+        for message_type in self.schema.message_types.message_types.values() {
+            let (_, groups, _) = field_groups(&message_type.fields);
+            if groups.is_empty() {
+                continue;
+            }
+
+            let message_path = FieldPath::new(vec![message_type.name.clone()]);
+            let message_module_path = scoped_module_dir(&module_path, &message_path);
+            create_dir_all(&message_module_path)?;
+
+            let message_module_tokens: Tokens<Rust> = quote!($(nested_group_modules(&groups)));
+            write_file(
+                &message_module_path.join("mod.rs"),
+                &self.config,
+                message_module_tokens,
+            )?;
 
             module_tokens.append(quote! {
-                pub mod $(name.to_case(Case::Snake));
-                pub use self::$(name.to_case(Case::Snake))::$(name.to_case(Case::UpperCamel));
+                pub mod $(message_type.name.to_case(Case::Snake));
             });
             module_tokens.push();
         }
 
+        for (group_path, group_type) in &self.schema.message_types.group_types {
+            self.write_group_codec(&module_path, group_path, group_type)?;
+        }
+
         write_file(&module_path.join("mod.rs"), &self.config, module_tokens)?;
 
         Ok(())
     }
 
-    fn write_group_codec(&self, module_path: &Path, group: &GroupType) -> Result<()> {
+    fn write_group_codec(
+        &self,
+        module_path: &Path,
+        group_path: &FieldPath,
+        group: &GroupType,
+    ) -> Result<()> {
+        let module_path = scoped_module_dir(module_path, group_path);
+        create_dir_all(&module_path)?;
+
         let name = group.name.to_case(Case::UpperCamel);
+        let rust_module = scoped_module_path(group_path);
         let rust_decoder = format!("Rust{}Decoder", name);
         let rust_encoder = format!("Rust{}Encoder", name);
         let (fields, groups, var_data_fields) = field_groups(&group.fields);
@@ -51,32 +81,37 @@ impl ModuleGenerator<'_> {
             .iter()
             .filter(|field| matches!(field.presence, Presence::Required));
         // Floats are not hashable
-        let hashable_fields = fields
+        let mut hashable_fields = vec![];
+        for field in fields
             .iter()
             .filter(|field| !matches!(field.presence, Presence::Constant))
-            .filter(|field| {
-                let encoded = field.to_type(&self.schema.types).unwrap();
-                match encoded {
-                    Type::EncodedData(encoded) => encoded
-                        .is_hashable(&self.schema.types.encoded_types)
-                        .unwrap_or(false),
-                    _ => true,
-                }
-            });
+        {
+            let hashable = match field.to_type(&self.schema.types)? {
+                Type::EncodedData(encoded) => encoded
+                    .is_hashable(&self.schema.types.encoded_types)
+                    .unwrap_or(false),
+                _ => true,
+            };
+
+            if hashable {
+                hashable_fields.push(field);
+            }
+        }
 
         let group_tokens: Tokens<Rust> = quote! {
-            use pyo3::{pyclass, pymethods};
-            use rust_codecs::$(&self.schema.package)::groups::$(&name)Decoder as $(&rust_decoder);
-            use rust_codecs::$(&self.schema.package)::groups::$(&name)Encoder as $(&rust_encoder);
+            use pyo3::{pyclass, pymethods, PyObject, Python};
+            use rust_codecs::$(&self.schema.package)::groups::$(&rust_module)::$(&name)Decoder as $(&rust_decoder);
+            use rust_codecs::$(&self.schema.package)::groups::$(&rust_module)::$(&name)Encoder as $(&rust_encoder);
             use rust_codecs::error::{Result as SbeResult, SbeError};
             use crate::$(&self.schema.package)::composites::*;
             use crate::$(&self.schema.package)::enums::*;
             use crate::$(&self.schema.package)::sets::*;
-            use crate::$(&self.schema.package)::groups::*;
             use std::convert::TryFrom;
             use std::borrow::Cow;
             use std::hash::{DefaultHasher, Hash, Hasher};
 
+            $(nested_group_modules(&groups))
+
             #[pyclass(subclass, eq)]
             #[derive(Debug, Clone, PartialEq)]
             pub struct $(&name) {
@@ -147,6 +182,8 @@ impl ModuleGenerator<'_> {
                 $(self.field_constant_enums(&fields)?)
 
                 $(self.var_data_fields_getter_bytes(&var_data_fields)?)
+
+                $(nested_group_classes(&groups))
             }
 
             impl $(&name) {
@@ -201,8 +238,7 @@ impl ModuleGenerator<'_> {
             }
         };
 
-        let file_path = module_path.join(format!("{}.rs", name.to_case(Case::Snake)));
-        write_file(&file_path, &self.config, group_tokens)?;
+        write_file(&module_path.join("mod.rs"), &self.config, group_tokens)?;
 
         Ok(())
     }
@@ -216,7 +252,14 @@ impl ModuleGenerator<'_> {
         let var_name = field_name.to_case(Case::Snake);
         let field_type = field.to_type(&self.schema.types)?;
 
-        let field_type = get_type_name(&field_type, &self.schema.types)?;
+        let field_type = if self.is_versioned_nullable(field)? {
+            format!(
+                "Option<{}>",
+                get_type_name(&field_type, &self.schema.types)?
+            )
+        } else {
+            get_type_name(&field_type, &self.schema.types)?
+        };
 
         Ok(quote! {
             $(&var_name): $field_type,
@@ -230,9 +273,34 @@ impl ModuleGenerator<'_> {
 
         let field_type = field.to_type(&self.schema.types)?;
 
+        if self.is_versioned_nullable(field)? {
+            let var_name = field.name.to_case(Case::Snake);
+
+            return Ok(match field_type {
+                Type::Enum(_) => quote! {
+                    encoder.$(&var_name)(self.$(&var_name).map(Into::into).unwrap_or_default())?;
+                },
+                _ => quote! {
+                    match &self.$(&var_name) {
+                        Some($(&var_name)) => encoder.$(&var_name)_encoder(|encoder| { $(&var_name).write(encoder) })?,
+                        None => encoder.$(&var_name)_encoder(|encoder| encoder.null())?,
+                    }
+                },
+            });
+        }
+
         Ok(quote!($(self.generate_composite_field_write(&field.name, &field_type)?)))
     }
 
+    /// Enum and composite fields added in later versions of the schema are optional,
+    /// since they are not present in data encoded using an older version.
+    pub fn is_versioned_nullable(&self, field: &FieldType) -> Result<bool> {
+        let field_type = field.to_type(&self.schema.types)?;
+
+        Ok(field.since_version.unwrap_or(0) > 0
+            && matches!(field_type, Type::Enum(_) | Type::Composite(_)))
+    }
+
     pub fn field_read(&self, field: &FieldType) -> Result<impl FormatInto<Rust>> {
         if matches!(field.presence, Presence::Constant) {
             return Ok(quote!());
@@ -240,6 +308,26 @@ impl ModuleGenerator<'_> {
 
         let field_type = field.to_type(&self.schema.types)?;
 
+        if self.is_versioned_nullable(field)? {
+            let var_name = field.name.to_case(Case::Snake);
+
+            // The null values encoded for a missing field are `None` too
+            return Ok(match &field_type {
+                Type::Enum(enum_type) => quote! {
+                    $(&var_name): $(enum_type.name.to_case(Case::UpperCamel))::try_from_nullable(value.$(&var_name)()?)?,
+                },
+                _ => quote! {
+                    $(&var_name): value.$(&var_name)_decoder(|decoder| {
+                        if decoder.is_null()? {
+                            return Ok(None);
+                        }
+
+                        decoder.try_into().map(Some)
+                    })?.flatten(),
+                },
+            });
+        }
+
         Ok(quote!($(self.generate_composite_field_from(&field.name, &field_type)?)))
     }
 
@@ -274,7 +362,7 @@ impl ModuleGenerator<'_> {
 
         let default_value = default_value
             .split('.')
-            .last()
+            .next_back()
             .ok_or(anyhow!("Constant field {} has no ref value", field.name))?;
         let default_value = enum_type
             .values
@@ -453,3 +541,42 @@ impl ModuleGenerator<'_> {
         Ok(tokens)
     }
 }
+
+/// Generates the declarations of the modules of the groups nested in a message or group,
+/// re-exporting their classes.
+fn nested_group_modules(groups: &[&GroupType]) -> Tokens<Rust> {
+    let mut tokens: Tokens<Rust> = quote!();
+
+    for group in groups {
+        let module_name = group.name.to_case(Case::Snake);
+
+        tokens.append(quote! {
+            pub mod $(&module_name);
+            pub use self::$(&module_name)::$(group.name.to_case(Case::UpperCamel));
+        });
+        tokens.push();
+    }
+
+    tokens
+}
+
+/// Generates the class attributes exposing the classes of the groups nested in a message
+/// or group, e.g. `Car.FuelFigures`.
+pub fn nested_group_classes(groups: &[&GroupType]) -> Tokens<Rust> {
+    let mut tokens: Tokens<Rust> = quote!();
+
+    for group in groups {
+        let class_name = group.name.to_case(Case::UpperCamel);
+
+        tokens.push();
+        tokens.append(quote! {
+            #[classattr]
+            #[allow(non_snake_case)]
+            fn $(&class_name)(py: Python<'_>) -> PyObject {
+                py.get_type_bound::<$(&class_name)>().into_any().unbind()
+            }
+        });
+    }
+
+    tokens
+}
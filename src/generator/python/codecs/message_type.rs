@@ -1,4 +1,5 @@
 use crate::generator::common::field_groups;
+use crate::generator::python::codecs::group_type::nested_group_classes;
 use crate::generator::python::constants::MESSAGE_MODULE_NAME;
 use crate::generator::python::module::ModuleGenerator;
 use crate::generator::write_file;
@@ -54,22 +55,26 @@ impl ModuleGenerator<'_> {
             .copied()
             .collect();
         // Floats are not hashable
-        let hashable_fields = fields
+        let mut hashable_fields = vec![];
+        for field in fields
             .iter()
             .filter(|field| !matches!(field.presence, Presence::Constant))
-            .filter(|field| {
-                let encoded = field.to_type(&self.schema.types).unwrap();
-                match encoded {
-                    Type::EncodedData(encoded) => encoded
-                        .is_hashable(&self.schema.types.encoded_types)
-                        .unwrap_or(false),
-                    _ => true,
-                }
-            });
+        {
+            let hashable = match field.to_type(&self.schema.types)? {
+                Type::EncodedData(encoded) => encoded
+                    .is_hashable(&self.schema.types.encoded_types)
+                    .unwrap_or(false),
+                _ => true,
+            };
+
+            if hashable {
+                hashable_fields.push(field);
+            }
+        }
 
         let message_tokens: Tokens<Rust> = quote! {
             use anyhow::{anyhow, Result};
-            use pyo3::{pyclass, pymethods, Bound};
+            use pyo3::{pyclass, pymethods, Bound, PyObject, Python};
             use pyo3::types::{PyType, PyByteArray, PyTuple, PyDict};
             use pyo3::prelude::PyByteArrayMethods;
             use rust_codecs::$(&self.schema.package)::messages::$(&name)Decoder as $(&rust_decoder);
@@ -81,7 +86,9 @@ impl ModuleGenerator<'_> {
             use crate::$(&self.schema.package)::composites::*;
             use crate::$(&self.schema.package)::enums::*;
             use crate::$(&self.schema.package)::sets::*;
-            use crate::$(&self.schema.package)::groups::*;
+            $(if !groups.is_empty() {
+                use crate::$(&self.schema.package)::groups::$(message.name.to_case(Case::Snake))::*;
+            })
             use std::convert::TryFrom;
             use std::borrow::Cow;
             use std::hash::{DefaultHasher, Hash, Hasher};
@@ -113,6 +120,8 @@ impl ModuleGenerator<'_> {
                 #[classattr]
                 const ID: u16 = $(name.to_case(Case::ScreamingSnake))_ID;
 
+                $(nested_group_classes(&groups))
+
                 $(generate_ctor_signature(&mandatory_fields, &groups, &var_data_fields, &optional_fields))
                 #[new]
                 pub fn py_new(
@@ -244,14 +253,13 @@ impl ModuleGenerator<'_> {
             }
 
             #[inline]
-            pub fn to_bytes(&self, buffer_size: usize) -> Result<Cow<[u8]>> {
-                let mut buffer = vec![0; buffer_size];
-                let write_buf = WriteBuf::new(&mut buffer);
+            pub fn to_bytes(&self) -> Result<Cow<[u8]>> {
+                let mut buffer = vec![];
+                let write_buf = WriteBuf::growable(&mut buffer);
                 let mut encoder = write_buf.try_into()?;
 
                 self.write(&mut encoder)?;
-                let size = encoder.size().ok_or(anyhow!("Missing encoder size"))?;
-                Ok(buffer[0..size].to_vec().into())
+                Ok(buffer.into())
             }
         }
     }
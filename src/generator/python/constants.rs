@@ -5,3 +5,6 @@ pub const ENUM_MODULE_NAME: &str = "enums";
 pub const COMPOSITE_MODULE_NAME: &str = "composites";
 pub const GROUP_MODULE_NAME: &str = "groups";
 pub const MESSAGE_MODULE_NAME: &str = "messages";
+pub const BUFFER_MODULE_NAME: &str = "buffer";
+pub const ERROR_MODULE_NAME: &str = "error";
+pub const ERROR_CLASS_NAME: &str = "SbeError";
@@ -1,6 +1,7 @@
 mod codecs;
 mod constants;
 mod module;
+pub mod pure;
 mod typing;
 
 use crate::generator::{write_file, CodeGenerator};
@@ -8,7 +9,7 @@ use crate::models::schema::ValidatedMessageSchema;
 use anyhow::Result;
 use convert_case::{Case, Casing};
 use indoc::formatdoc;
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 use std::fs::{create_dir_all, File};
 use std::io::Write;
 use std::path::{Path, PathBuf};
@@ -18,25 +19,57 @@ use crate::models::types::encoded_data_type::EncodedDataType;
 
 use crate::generator::python::constants::{PYSRC_DIR, SRC_DIR};
 use crate::generator::python::typing::TypingGenerator;
-use crate::generator::rust::RustGenerator;
+use crate::generator::rust::{RustGenerator, RustLayout};
 use crate::models::message::MessageType;
 use crate::models::types::primitive_type::{
     LanguagePrimitive, NativeType, PrimitiveConvertible, ResolvableType,
 };
-use crate::models::types::{MessageField, Type};
+use crate::models::types::Type;
 use genco::prelude::*;
 
 enum ExportedClass {
     Message(MessageType),
-    MessageField(MessageField),
     Type(Type),
 }
 
+/// Classes exported by the module of a schema, keyed by their names: the messages and all
+/// types except for the encoded ones, which are mapped to Python primitives.
+fn exported_classes(schema: &ValidatedMessageSchema) -> BTreeMap<String, ExportedClass> {
+    let mut exported_classes = BTreeMap::new();
+
+    exported_classes.extend(schema.types.iter_values().filter_map(|simple_type| {
+        if !matches!(simple_type, Type::EncodedData(_)) {
+            (
+                simple_type.name().to_case(Case::UpperCamel),
+                ExportedClass::Type(simple_type.clone()),
+            )
+                .into()
+        } else {
+            None
+        }
+    }));
+    exported_classes.extend(
+        schema
+            .message_types
+            .message_types
+            .values()
+            .map(|message_type| {
+                (
+                    message_type.name.to_case(Case::UpperCamel),
+                    ExportedClass::Message(message_type.clone()),
+                )
+            }),
+    );
+
+    exported_classes
+}
+
 pub struct PythonGenerator {
     config: rust::Config,
     path: PathBuf,
     project_name: String,
     project_version: String,
+    bounds_checks: bool,
     schemas: Vec<ValidatedMessageSchema>,
 }
 
@@ -46,12 +79,14 @@ impl PythonGenerator {
         project_name: &str,
         project_version: &str,
         schemas: Vec<ValidatedMessageSchema>,
+        bounds_checks: bool,
     ) -> Self {
         Self {
             config: rust::Config::default().with_default_import(rust::ImportMode::Direct),
             path: path.to_owned(),
             project_name: project_name.to_owned(),
             project_version: project_version.to_owned(),
+            bounds_checks,
             schemas,
         }
     }
@@ -73,45 +108,13 @@ impl PythonGenerator {
                     .join(&schema.package),
             )?;
 
-            let mut exported_classes = HashMap::new();
-
-            exported_classes.extend(schema.types.iter_values().filter_map(|simple_type| {
-                if !matches!(simple_type, Type::EncodedData(_)) {
-                    (
-                        simple_type.name().to_case(Case::UpperCamel),
-                        ExportedClass::Type(simple_type.clone()),
-                    )
-                        .into()
-                } else {
-                    None
-                }
-            }));
-            exported_classes.extend(schema.message_types.iter_values().filter_map(
-                |message_type| {
-                    if matches!(message_type, MessageField::Group(_)) {
-                        (
-                            message_type.name().to_case(Case::UpperCamel),
-                            ExportedClass::MessageField(message_type.clone()),
-                        )
-                            .into()
-                    } else {
-                        None
-                    }
-                },
-            ));
-            exported_classes.extend(schema.message_types.message_types.values().map(
-                |message_type| {
-                    (
-                        message_type.name.to_case(Case::UpperCamel),
-                        ExportedClass::Message(message_type.clone()),
-                    )
-                },
-            ));
+            let exported_classes = exported_classes(schema);
 
             modules.push(self.generate_pymodule(schema, &exported_classes));
 
             self.write_schema_init_py(schema, &exported_classes)?;
-            TypingGenerator::from_python(self, schema).write_typing_hints(&exported_classes)?;
+            TypingGenerator::new(&self.path, &self.project_name, schema)
+                .write_typing_hints(&exported_classes)?;
         }
 
         self.write_init_py()?;
@@ -123,7 +126,6 @@ impl PythonGenerator {
                 [package]
                 name = \"{name}\"
                 version = \"{version}\"
-                authors = [\"Second Foundation\"]
                 edition = \"2021\"
 
                 [lib]
@@ -179,7 +181,7 @@ impl PythonGenerator {
                 name = \"{name}\"
                 version = \"{version}\"
                 license = \"Proprietary\"
-                authors = [\"Second Foundation\"]
+                authors = []
                 description = \"\"
 
                 [tool.poetry.dependencies]
@@ -212,6 +214,10 @@ impl PythonGenerator {
             "rust_codecs",
             &self.project_version,
             self.schemas.clone(),
+            RustLayout::Crate {
+                workspace_root: false,
+            },
+            self.bounds_checks,
             false,
         );
         rust_generator.generate_project(false, format_project)?;
@@ -222,13 +228,12 @@ impl PythonGenerator {
     fn generate_pymodule(
         &self,
         schema: &ValidatedMessageSchema,
-        exported_classes: &HashMap<String, ExportedClass>,
+        exported_classes: &BTreeMap<String, ExportedClass>,
     ) -> Tokens<Rust> {
         quote! {
             fn $(&schema.package)_submodule(py: Python) -> PyResult<Bound<PyModule>> {
                 use $(&schema.package)::enums::*;
                 use $(&schema.package)::sets::*;
-                use $(&schema.package)::groups::*;
                 use $(&schema.package)::messages::*;
                 use $(&schema.package)::composites::*;
 
@@ -283,7 +288,7 @@ impl PythonGenerator {
     fn write_schema_init_py(
         &self,
         schema: &ValidatedMessageSchema,
-        exported_classes: &HashMap<String, ExportedClass>,
+        exported_classes: &BTreeMap<String, ExportedClass>,
     ) -> Result<()> {
         let init_py_content: Tokens<Python> = quote! {
             from . import *
@@ -433,7 +438,7 @@ impl CodeGenerator for PythonGenerator {
 impl PrimitiveConvertible<Python> for NativeType {
     fn lang_primitive(
         &self,
-        encoded_types: &HashMap<String, EncodedDataType>,
+        encoded_types: &BTreeMap<String, EncodedDataType>,
     ) -> Result<LanguagePrimitive<Python>> {
         let native_type = self.resolved(encoded_types)?;
 
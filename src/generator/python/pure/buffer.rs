@@ -0,0 +1,103 @@
+use crate::generator::python::constants::{
+    BUFFER_MODULE_NAME, ERROR_CLASS_NAME, ERROR_MODULE_NAME,
+};
+use crate::generator::python::pure::module::ModuleGenerator;
+use crate::models::ByteOrder;
+use anyhow::Result;
+use indoc::formatdoc;
+use std::fs::File;
+use std::io::Write;
+
+impl ModuleGenerator<'_> {
+    /// Writes the module of the precompiled structs of all primitive types in the byte order of
+    /// the schema, along with the helpers reading and writing arrays, strings and bytes, which
+    /// check the bounds of the buffer themselves.
+    pub(crate) fn write_buffer_module(&self) -> Result<()> {
+        let byte_order = match self.schema.byte_order {
+            ByteOrder::LittleEndian => "<",
+            ByteOrder::BigEndian => ">",
+        };
+
+        let buffer_py_content = formatdoc! {"
+                import struct
+                from contextlib import contextmanager
+
+                from ..{error_module} import {error_class}
+
+                U8 = struct.Struct(\"{byte_order}B\")
+                I8 = struct.Struct(\"{byte_order}b\")
+                U16 = struct.Struct(\"{byte_order}H\")
+                I16 = struct.Struct(\"{byte_order}h\")
+                U32 = struct.Struct(\"{byte_order}I\")
+                I32 = struct.Struct(\"{byte_order}i\")
+                U64 = struct.Struct(\"{byte_order}Q\")
+                I64 = struct.Struct(\"{byte_order}q\")
+                F32 = struct.Struct(\"{byte_order}f\")
+                F64 = struct.Struct(\"{byte_order}d\")
+
+
+                def get_array(buffer, offset, format, length):
+                    return list(struct.unpack_from(f\"{byte_order}{{length}}{{format}}\", buffer, offset))
+
+
+                def put_array(buffer, offset, format, length, values):
+                    if len(values) != length:
+                        raise {error_class}(f\"wrong slice size: {{len(values)}} != {{length}}\")
+
+                    struct.pack_into(f\"{byte_order}{{length}}{{format}}\", buffer, offset, *values)
+
+
+                def get_bytes(buffer, offset, length):
+                    end = offset + length
+                    if end > len(buffer):
+                        raise {error_class}(f\"codec out of bounds: {{end}} > {{len(buffer)}}\")
+
+                    return bytes(buffer[offset:end])
+
+
+                def put_bytes(buffer, offset, value):
+                    end = offset + len(value)
+                    if end > len(buffer):
+                        raise {error_class}(f\"codec out of bounds: {{end}} > {{len(buffer)}}\")
+
+                    buffer[offset:end] = value
+
+
+                def put_string(buffer, offset, length, value, field_name, ascii):
+                    if ascii and not value.isascii():
+                        raise {error_class}(f\"invalid ascii string: {{value}}\")
+
+                    encoded = value.encode()
+                    if len(encoded) > length:
+                        raise {error_class}(
+                            f\"value out of bounds for field '{{field_name}}': \"
+                            f\"string '{{value}}' length {{len(encoded)}} > {{length}} (max)\"
+                        )
+
+                    put_bytes(buffer, offset, encoded.ljust(length, b\"\\0\"))
+
+
+                def to_f32(value):
+                    \"\"\"Rounds a float to the single precision it is encoded with.\"\"\"
+                    return value if value is None else F32.unpack(F32.pack(value))[0]
+
+
+                @contextmanager
+                def sbe_errors():
+                    \"\"\"Raises the errors of packing and unpacking the values as {error_class}.\"\"\"
+                    try:
+                        yield
+                    except (struct.error, ValueError, OverflowError) as error:
+                        raise {error_class}(str(error)) from error
+            ",
+            error_module = ERROR_MODULE_NAME,
+            error_class = ERROR_CLASS_NAME,
+        };
+
+        let buffer_py_path = self.path.join(format!("{}.py", BUFFER_MODULE_NAME));
+        let mut buffer_py_file = File::create(buffer_py_path)?;
+        buffer_py_file.write_all(buffer_py_content.as_bytes())?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,159 @@
+use crate::generator::python::constants::COMPOSITE_MODULE_NAME;
+use crate::generator::python::pure::codecs::{
+    enum_null_value, field_metadata, format_character, null_value, null_value_condition,
+    offset_tokens, struct_name,
+};
+use crate::generator::python::pure::module::ModuleGenerator;
+use crate::models::layout::BlockLayout;
+use crate::models::types::composite_type::CompositeType;
+use crate::models::types::primitive_type::ResolvableType;
+use crate::models::types::{Presence, Type};
+use anyhow::Result;
+use convert_case::{Case, Casing};
+use genco::prelude::*;
+
+impl ModuleGenerator<'_> {
+    fn generate_composite_codec(&self, composite_type: &CompositeType) -> Result<Tokens<Python>> {
+        let name = composite_type.name.to_case(Case::UpperCamel);
+        let layout = BlockLayout::from_composite(composite_type, &self.schema.types)?;
+
+        let mut attributes = Vec::new();
+        let mut constants = Vec::new();
+        let mut decoders: Tokens<Python> = quote!();
+        let mut encoders: Tokens<Python> = quote!();
+        let mut null_encoders: Tokens<Python> = quote!();
+        let mut null_checks: Tokens<Python> = quote!();
+
+        for (field, offset) in composite_type.fields.iter().zip(&layout.offsets) {
+            attributes.extend(self.field_attribute(field.name(), field)?);
+            constants.extend(self.field_constant(field.name(), field, None)?);
+
+            decoders.push();
+            decoders.append(self.field_decoder(
+                COMPOSITE_MODULE_NAME,
+                field.name(),
+                field,
+                *offset,
+                0,
+            )?);
+            encoders.push();
+            encoders.append(self.field_encoder(field.name(), field, *offset, 0)?);
+
+            if let Some((null_encoder, null_check)) = self.null_field_codec(field, *offset)? {
+                null_encoders.push();
+                null_encoders.append(null_encoder);
+                null_checks.push();
+                null_checks.append(quote! {
+                    if not $null_check:
+                        return False
+                });
+            }
+        }
+
+        Ok(quote! {
+            class $(&name):
+                $(self.generate_class_methods(&name, &name, &attributes, &constants))
+
+                @staticmethod
+                def _decode(buffer, offset):
+                    decoded = $(&name).__new__($(&name))
+                    $decoders
+                    return decoded
+
+                def _encode(self, buffer, offset):
+                    $encoders
+                    $(if attributes.is_empty() { pass })
+
+                @staticmethod
+                def _encode_null(buffer, offset):
+                    $(if null_encoders.is_empty() { pass } else { $null_encoders })
+
+                @staticmethod
+                def _is_null(buffer, offset):
+                    $null_checks
+                    return True
+        })
+    }
+
+    /// Encoder of the null value of a field of a composite and the condition of the field
+    /// holding it, which together stand for a missing optional composite. Constant fields
+    /// have none.
+    fn null_field_codec(
+        &self,
+        field_type: &Type,
+        offset: usize,
+    ) -> Result<Option<(Tokens<Python>, Tokens<Python>)>> {
+        let types = &self.schema.types;
+        let offset = offset_tokens(offset);
+
+        let (native_type, null, condition) = match self.resolved(field_type)? {
+            Type::EncodedData(encoded_type) => {
+                if encoded_type.presence == Presence::Constant {
+                    return Ok(None);
+                }
+
+                let metadata = field_metadata(field_type.name(), &encoded_type, types)?;
+                let native_type = metadata.field_primitive_type;
+                let null = null_value(&encoded_type, &native_type);
+                let condition = null_value_condition("part", &encoded_type, &native_type);
+
+                if metadata.field_length > 1 {
+                    let format = format_character(&native_type);
+                    let length = metadata.field_length;
+
+                    return Ok(Some((
+                        quote!($(self.buffer_import("put_array"))(buffer, $(&offset), $(quoted(format)), $length, [$(&null)] * $length)),
+                        quote!(all($condition for part in $(self.buffer_import("get_array"))(buffer, $(&offset), $(quoted(format)), $length))),
+                    )));
+                }
+
+                (native_type, null, condition)
+            }
+            Type::Enum(enum_type) => {
+                let null = enum_null_value(&enum_type, types)?.to_owned();
+
+                (
+                    enum_type.encoding_type.resolved(&types.encoded_types)?,
+                    null.clone(),
+                    quote!(part == $null),
+                )
+            }
+            Type::Set(set_type) => (
+                set_type.encoding_type.resolved(&types.encoded_types)?,
+                "0".to_owned(),
+                quote!(part == 0),
+            ),
+            Type::Composite(composite_type) => {
+                let composite_class = self.import_into(
+                    COMPOSITE_MODULE_NAME,
+                    COMPOSITE_MODULE_NAME,
+                    &composite_type.name.to_case(Case::UpperCamel),
+                );
+
+                return Ok(Some((
+                    quote!($(&composite_class)._encode_null(buffer, $(&offset))),
+                    quote!($(&composite_class)._is_null(buffer, $(&offset))),
+                )));
+            }
+            Type::Reference(_) => unreachable!("Resolved reference: {}", field_type.name()),
+        };
+
+        let buffer_struct = self.buffer_import(struct_name(&native_type));
+
+        Ok(Some((
+            quote!($(&buffer_struct).pack_into(buffer, $(&offset), $null)),
+            quote!(all($condition for part in $(&buffer_struct).unpack_from(buffer, $(&offset)))),
+        )))
+    }
+
+    pub(crate) fn write_composite_module(&self) -> Result<()> {
+        let mut composite_tokens: Tokens<Python> = quote!();
+
+        for composite_type in self.schema.types.composite_types.values() {
+            composite_tokens.line();
+            composite_tokens.append(self.generate_composite_codec(composite_type)?);
+        }
+
+        self.write_module(COMPOSITE_MODULE_NAME, composite_tokens)
+    }
+}
@@ -0,0 +1,48 @@
+use crate::generator::python::constants::ENUM_MODULE_NAME;
+use crate::generator::python::pure::module::ModuleGenerator;
+use crate::models::types::enum_type::EnumType;
+use crate::models::types::primitive_type::{NativeType, ResolvableType};
+use anyhow::Result;
+use convert_case::{Case, Casing};
+use genco::prelude::*;
+
+impl ModuleGenerator<'_> {
+    /// Generates an `Enum` with the encoded values as its values, same as the one of the
+    /// pyo3 extension. Character values are encoded as their ordinal.
+    fn generate_enum_codec(&self, enum_type: &EnumType) -> Result<Tokens<Python>> {
+        let char_encoding = matches!(
+            enum_type
+                .encoding_type
+                .resolved(&self.schema.types.encoded_types)?,
+            NativeType::Char
+        );
+
+        let mut values_tokens: Tokens<Python> = quote!();
+        for value in &enum_type.values {
+            values_tokens.push();
+            values_tokens.append(quote! {
+                $(value.name.to_case(Case::UpperSnake)) = $(value.encoded_value(char_encoding)?)
+            });
+        }
+
+        if enum_type.values.is_empty() {
+            values_tokens.append(quote!(pass));
+        }
+
+        Ok(quote! {
+            class $(enum_type.name.to_case(Case::UpperCamel))($(python::import("enum", "Enum"))):
+                $values_tokens
+        })
+    }
+
+    pub(crate) fn write_enum_module(&self) -> Result<()> {
+        let mut enum_tokens: Tokens<Python> = quote!();
+
+        for enum_type in self.schema.types.enum_types.values() {
+            enum_tokens.line();
+            enum_tokens.append(self.generate_enum_codec(enum_type)?);
+        }
+
+        self.write_module(ENUM_MODULE_NAME, enum_tokens)
+    }
+}
@@ -0,0 +1,359 @@
+use crate::generator::common::{dimension_type, field_groups};
+use crate::generator::python::constants::MESSAGE_MODULE_NAME;
+use crate::generator::python::pure::codecs::{
+    field_metadata, format_character, offset_tokens, struct_name, Attribute,
+};
+use crate::generator::python::pure::module::ModuleGenerator;
+use crate::models::layout::BlockLayout;
+use crate::models::types::group_type::GroupType;
+use crate::models::types::primitive_type::NativeType;
+use crate::models::types::variable_data_type::VariableDataType;
+use crate::models::types::{CharacterEncoding, MessageField, Presence, Type};
+use anyhow::{anyhow, Result};
+use convert_case::{Case, Casing};
+use genco::prelude::*;
+
+/// Code decoding, encoding and sizing var data, along with the attribute holding it.
+struct VarDataCodec {
+    decoder: Tokens<Python>,
+    encoder: Tokens<Python>,
+    size: Tokens<Python>,
+    attribute: Attribute,
+}
+
+impl ModuleGenerator<'_> {
+    /// Generates the class of an entry of a group, nested in the class of its message or
+    /// enclosing group, e.g. `Car.FuelFigures`.
+    fn generate_group_codec(&self, group: &GroupType, scope: &str) -> Result<Tokens<Python>> {
+        let name = group.name.to_case(Case::UpperCamel);
+        let qualified_name = format!("{}.{}", scope, name);
+        let (fields, _, _) = field_groups(&group.fields);
+        let block_length =
+            BlockLayout::from_fields(&group.name, &fields, group.block_length, &self.schema.types)?
+                .block_length;
+
+        let dimension_type = dimension_type(group, &self.schema.types.composite_types)?;
+        let dimension_layout = BlockLayout::from_composite(dimension_type, &self.schema.types)?;
+        let (Some(block_length_field), Some(num_in_group_field)) =
+            (dimension_type.fields.first(), dimension_type.fields.get(1))
+        else {
+            return Err(anyhow!(
+                "Dimension type '{}' of group '{}' has no block length and number of entries",
+                dimension_type.name,
+                group.name
+            ));
+        };
+        let block_length_struct =
+            self.buffer_import(struct_name(&self.native_type(block_length_field)?));
+        let num_in_group_struct =
+            self.buffer_import(struct_name(&self.native_type(num_in_group_field)?));
+        let block_length_offset = offset_tokens(dimension_layout.offsets[0]);
+        let num_in_group_offset = offset_tokens(dimension_layout.offsets[1]);
+
+        Ok(quote! {
+            class $(&name):
+                $(self.generate_block_methods(&name, &qualified_name, &group.fields, group.block_length)?)
+
+                @staticmethod
+                def _decode_all(buffer, offset, acting_version):
+                    block_length = $(&block_length_struct).unpack_from(buffer, $(&block_length_offset))[0]
+                    num_in_group = $(&num_in_group_struct).unpack_from(buffer, $(&num_in_group_offset))[0]
+                    offset += $(dimension_layout.block_length)
+
+                    entries = []
+                    for _ in range(num_in_group):
+                        entry, offset = $(&qualified_name)._decode(buffer, offset, block_length, acting_version)
+                        entries.append(entry)
+
+                    return entries, offset
+
+                @staticmethod
+                def _encode_all(entries, buffer, offset):
+                    $(&block_length_struct).pack_into(buffer, $(&block_length_offset), $block_length)
+                    $(&num_in_group_struct).pack_into(buffer, $(&num_in_group_offset), len(entries))
+                    offset += $(dimension_layout.block_length)
+
+                    for entry in entries:
+                        offset = entry._encode(buffer, offset)
+
+                    return offset
+
+                @staticmethod
+                def _size_all(entries):
+                    return $(dimension_layout.block_length) + sum(entry._size() for entry in entries)
+        })
+    }
+
+    /// Generates the body of the class of a message or an entry of a group: the classes of its
+    /// groups, its attributes and the methods decoding and encoding its block, groups and
+    /// var data. Both methods take and return the offset, past the end of the whole entry,
+    /// whose size is computed by `_size` before encoding it.
+    pub(crate) fn generate_block_methods(
+        &self,
+        name: &str,
+        qualified_name: &str,
+        block_fields: &[MessageField],
+        block_length: Option<usize>,
+    ) -> Result<Tokens<Python>> {
+        let (fields, groups, var_data) = field_groups(block_fields);
+        let layout = BlockLayout::from_fields(name, &fields, block_length, &self.schema.types)?;
+
+        let mut attributes = Vec::new();
+        let mut constants = Vec::new();
+        let mut decoders: Tokens<Python> = quote!();
+        let mut encoders: Tokens<Python> = quote!();
+
+        for (field, offset) in fields.iter().zip(&layout.offsets) {
+            let field_type = self.message_field_type(field)?;
+            let since_version = field.since_version.unwrap_or(0);
+
+            if field.presence == Presence::Constant {
+                constants.extend(self.field_constant(
+                    &field.name,
+                    &field_type,
+                    field.value_ref.as_deref(),
+                )?);
+                continue;
+            }
+
+            if let Some(mut attribute) = self.field_attribute(&field.name, &field_type)? {
+                // Fields of optional types are still required arguments, same as in the stubs
+                attribute.optional = field.presence == Presence::Optional;
+                attributes.push(attribute);
+            }
+
+            decoders.push();
+            decoders.append(self.field_decoder(
+                MESSAGE_MODULE_NAME,
+                &field.name,
+                &field_type,
+                *offset,
+                since_version,
+            )?);
+            encoders.push();
+            encoders.append(self.field_encoder(
+                &field.name,
+                &field_type,
+                *offset,
+                since_version,
+            )?);
+        }
+
+        let mut nested_groups = Vec::new();
+        let mut groups_decoders: Tokens<Python> = quote!();
+        let mut groups_encoders: Tokens<Python> = quote!();
+        let mut sizes: Tokens<Python> = quote!();
+
+        for group in &groups {
+            let attribute = group.name.to_case(Case::Snake);
+            let group_class = format!(
+                "{}.{}",
+                qualified_name,
+                group.name.to_case(Case::UpperCamel)
+            );
+
+            nested_groups.push(self.generate_group_codec(group, qualified_name)?);
+
+            attributes.push(Attribute {
+                name: attribute.clone(),
+                optional: false,
+                float32: false,
+                list: true,
+            });
+
+            let decoder = quote!(decoded.$(&attribute), offset = $(&group_class)._decode_all(buffer, offset, acting_version));
+            groups_decoders.push();
+            groups_decoders.append(match group.since_version.unwrap_or(0) {
+                0 => decoder,
+                since_version => quote! {
+                    if acting_version < $since_version:
+                        decoded.$(&attribute) = []
+                    else:
+                        $decoder
+                },
+            });
+            groups_encoders.push();
+            groups_encoders.append(
+                quote!(offset = $(&group_class)._encode_all(self.$(&attribute), buffer, offset)),
+            );
+            sizes.push();
+            sizes.append(quote!(size += $(&group_class)._size_all(self.$(&attribute))));
+        }
+
+        let mut var_data_decoders: Tokens<Python> = quote!();
+        let mut var_data_encoders: Tokens<Python> = quote!();
+
+        for var_data_type in &var_data {
+            let codec = self.var_data_codec(var_data_type)?;
+
+            attributes.push(codec.attribute);
+            var_data_decoders.push();
+            var_data_decoders.append(codec.decoder);
+            var_data_encoders.push();
+            var_data_encoders.append(codec.encoder);
+            sizes.push();
+            sizes.append(quote!(size += $(codec.size)));
+        }
+
+        Ok(quote! {
+            $(for nested_group in nested_groups {
+                $nested_group
+                $['\n']
+            })
+            $(self.generate_class_methods(name, qualified_name, &attributes, &constants))
+
+            @staticmethod
+            def _decode(buffer, offset, block_length, acting_version):
+                decoded = $qualified_name.__new__($qualified_name)
+                $decoders
+                offset += block_length
+                $groups_decoders
+                $var_data_decoders
+                return decoded, offset
+
+            def _encode(self, buffer, offset):
+                $encoders
+                offset += $(layout.block_length)
+                $groups_encoders
+                $var_data_encoders
+                return offset
+
+            def _size(self):
+                size = $(layout.block_length)
+                $sizes
+                return size
+        })
+    }
+
+    /// Decoder, encoder and encoded size of var data, prefixed by its length, and the attribute
+    /// holding it: a `str` for strings, `bytes` for bytes and a list for any other type.
+    fn var_data_codec(&self, var_data_type: &VariableDataType) -> Result<VarDataCodec> {
+        let types = &self.schema.types;
+        let attribute = var_data_type.name.to_case(Case::Snake);
+        let repr_type = var_data_type.repr_type(&types.composite_types)?;
+
+        let (Some(Type::EncodedData(length_type)), Some(Type::EncodedData(value_type))) =
+            (repr_type.fields.first(), repr_type.fields.get(1))
+        else {
+            return Err(anyhow!(
+                "Only encoded data types expected for the length and value types in variable data encoding '{}'",
+                var_data_type.name
+            ));
+        };
+        let length_metadata = field_metadata("", length_type, types)?;
+        let value_metadata = field_metadata("", value_type, types)?;
+        let length_struct = self.buffer_import(struct_name(&length_metadata.field_primitive_type));
+        let length_size = length_metadata.type_size;
+        let value_size = value_metadata.type_size;
+        let max_length = match &length_type.max_value {
+            Some(max_value) => max_value.to_owned(),
+            None => ((1u128 << (8 * length_size)) - 1).to_string(),
+        };
+
+        let string = var_data_type.is_string(&types.composite_types)?;
+        let bytes = var_data_type.is_bytes(types)?;
+
+        let byte_length = |length: &str| match value_size {
+            1 => length.to_owned(),
+            _ => format!("{} * {}", length, value_size),
+        };
+
+        let length = if string {
+            format!("len(self.{}.encode())", attribute)
+        } else {
+            format!("len(self.{})", attribute)
+        };
+        let size = quote!($length_size + $(byte_length(&length)));
+
+        let (value, encoded, check) = match (string, bytes) {
+            (true, _) => (
+                quote!($(self.buffer_import("get_bytes"))(buffer, offset, length).decode()),
+                quote!(value = self.$(&attribute).encode()),
+                match value_metadata.encoding {
+                    Some(CharacterEncoding::Ascii) => quote! {
+                        if not self.$(&attribute).isascii():
+                            raise $(self.error_class())($(format!("f\"invalid ascii string: {{self.{}}}\"", attribute)))
+                    },
+                    _ => quote!(),
+                },
+            ),
+            (false, true) => (
+                quote!($(self.buffer_import("get_bytes"))(buffer, offset, length)),
+                quote!(value = self.$(&attribute)),
+                quote!(),
+            ),
+            (false, false) => (
+                quote!($(self.buffer_import("get_array"))(buffer, offset, $(quoted(format_character(&value_metadata.field_primitive_type))), length)),
+                quote!(value = self.$(&attribute)),
+                quote!(),
+            ),
+        };
+
+        let put_value = if string || bytes {
+            quote!($(self.buffer_import("put_bytes"))(buffer, offset, value))
+        } else {
+            quote!($(self.buffer_import("put_array"))(buffer, offset, $(quoted(format_character(&value_metadata.field_primitive_type))), len(value), value))
+        };
+
+        let empty = match (string, bytes) {
+            (true, _) => quote!(""),
+            (false, true) => quote!(b""),
+            (false, false) => quote!([]),
+        };
+
+        let decoder = quote! {
+            length = $(&length_struct).unpack_from(buffer, offset)[0]
+            offset += $length_size
+            decoded.$(&attribute) = $value
+            offset += $(byte_length("length"))
+        };
+        let decoder = match var_data_type.since_version.unwrap_or(0) {
+            0 => decoder,
+            since_version => quote! {
+                if acting_version < $since_version:
+                    decoded.$(&attribute) = $empty
+                else:
+                    $decoder
+            },
+        };
+
+        let encoder = quote! {
+            $check
+            $encoded
+            if len(value) > $(&max_length):
+                raise $(self.error_class())($(quoted(format!("var data out of bounds: {}", var_data_type.name))))
+            $(&length_struct).pack_into(buffer, offset, len(value))
+            offset += $length_size
+            $put_value
+            offset += $(byte_length("len(value)"))
+        };
+
+        Ok(VarDataCodec {
+            decoder,
+            encoder,
+            size,
+            attribute: Attribute {
+                name: attribute,
+                optional: false,
+                float32: false,
+                list: !string && !bytes,
+            },
+        })
+    }
+
+    /// Resolved primitive type of an encoded field of a composite.
+    pub(crate) fn native_type(&self, field_type: &Type) -> Result<NativeType> {
+        match self.resolved(field_type)? {
+            Type::EncodedData(encoded_type) => {
+                Ok(
+                    field_metadata(field_type.name(), &encoded_type, &self.schema.types)?
+                        .field_primitive_type,
+                )
+            }
+            _ => Err(anyhow!(
+                "Only encoded data type expected for the field '{}'",
+                field_type.name()
+            )),
+        }
+    }
+}
@@ -0,0 +1,117 @@
+use crate::generator::common::{field_groups, header_counts};
+use crate::generator::python::constants::MESSAGE_MODULE_NAME;
+use crate::generator::python::pure::codecs::struct_name;
+use crate::generator::python::pure::module::ModuleGenerator;
+use crate::models::layout::BlockLayout;
+use crate::models::message::MessageType;
+use anyhow::{anyhow, Result};
+use convert_case::{Case, Casing};
+use genco::prelude::*;
+
+impl ModuleGenerator<'_> {
+    /// Generates the class of a message, which encodes and decodes the message along with its
+    /// header, as the one of the pyo3 extension.
+    fn generate_message_codec(&self, message: &MessageType) -> Result<Tokens<Python>> {
+        let name = message.name.to_case(Case::UpperCamel);
+        let header_layout =
+            BlockLayout::from_composite(&self.schema.types.header_type, &self.schema.types)?;
+        let header_size = header_layout.block_length;
+        let block_length = BlockLayout::from_fields(
+            &message.name,
+            &field_groups(&message.fields).0,
+            message.block_length,
+            &self.schema.types,
+        )?
+        .block_length;
+
+        let header_field = |field_name: &str| -> Result<(Tokens<Python>, Tokens<Python>)> {
+            let header_type = &self.schema.types.header_type;
+            let (field, offset) = header_type
+                .fields
+                .iter()
+                .zip(&header_layout.offsets)
+                .find(|(field, _)| field.name() == field_name)
+                .ok_or(anyhow!(
+                    "Missing field '{}' in message header '{}'",
+                    field_name,
+                    header_type.name
+                ))?;
+
+            Ok((
+                self.buffer_import(struct_name(&self.native_type(field)?)),
+                quote!($(*offset)),
+            ))
+        };
+
+        let (block_length_struct, block_length_offset) = header_field("blockLength")?;
+        let (template_id_struct, template_id_offset) = header_field("templateId")?;
+        let (schema_id_struct, schema_id_offset) = header_field("schemaId")?;
+        let (version_struct, version_offset) = header_field("version")?;
+        let (_, groups, var_data) = field_groups(&message.fields);
+        let header_counts = header_counts(&self.schema.types, groups.len(), var_data.len())
+            .into_iter()
+            .map(|(field_name, count)| Ok((header_field(field_name)?, count)))
+            .collect::<Result<Vec<_>>>()?;
+
+        let wrong_schema = format!(
+            "f\"received message had wrong schema: {{schema_id}}, expected {}\"",
+            self.schema.id
+        );
+        let wrong_type = format!(
+            "f\"received message had wrong type: {{template_id}}, expected {{{}.ID}}\"",
+            name
+        );
+
+        Ok(quote! {
+            class $(&name):
+                ID = $(message.id)
+
+                $(self.generate_block_methods(&name, &name, &message.fields, message.block_length)?)
+
+                def to_bytes(self):
+                    buffer = bytearray($header_size + self._size())
+                    self.write_to_buffer(buffer)
+                    return bytes(buffer)
+
+                def write_to_buffer(self, buffer):
+                    with $(self.buffer_import("sbe_errors"))(), memoryview(buffer) as view:
+                        $(&block_length_struct).pack_into(view, $(&block_length_offset), $block_length)
+                        $(&template_id_struct).pack_into(view, $(&template_id_offset), $(&name).ID)
+                        $(&schema_id_struct).pack_into(view, $(&schema_id_offset), $(self.schema.id))
+                        $(&version_struct).pack_into(view, $(&version_offset), $(self.schema.version))
+                        $(for ((count_struct, count_offset), count) in &header_counts {
+                            $['\r']
+                            $count_struct.pack_into(view, $count_offset, $(*count))
+                        })
+
+                        return self._encode(view, $header_size)
+
+                @classmethod
+                def from_bytes(cls, buffer):
+                    with $(self.buffer_import("sbe_errors"))(), memoryview(buffer) as view:
+                        schema_id = $(&schema_id_struct).unpack_from(view, $(&schema_id_offset))[0]
+                        if schema_id != $(self.schema.id):
+                            raise $(self.error_class())($wrong_schema)
+
+                        template_id = $(&template_id_struct).unpack_from(view, $(&template_id_offset))[0]
+                        if template_id != $(&name).ID:
+                            raise $(self.error_class())($wrong_type)
+
+                        block_length = $(&block_length_struct).unpack_from(view, $(&block_length_offset))[0]
+                        acting_version = $(&version_struct).unpack_from(view, $(&version_offset))[0]
+
+                        return $(&name)._decode(view, $header_size, block_length, acting_version)[0]
+        })
+    }
+
+    pub(crate) fn write_message_module(&self) -> Result<()> {
+        let mut message_tokens: Tokens<Python> = quote!();
+
+        for message_type in self.schema.message_types.message_types.values() {
+            message_tokens.line();
+            message_tokens.append(self.generate_message_codec(message_type)?);
+        }
+
+        self.write_module(MESSAGE_MODULE_NAME, message_tokens)
+    }
+}
@@ -0,0 +1,733 @@
+mod composite_type;
+mod enum_type;
+mod group_type;
+mod message_type;
+mod set_type;
+
+use crate::generator::common::FieldMetadata;
+use crate::generator::python::constants::{
+    BUFFER_MODULE_NAME, COMPOSITE_MODULE_NAME, ENUM_MODULE_NAME, SET_MODULE_NAME,
+};
+use crate::generator::python::pure::module::ModuleGenerator;
+use crate::models::types::encoded_data_type::EncodedDataType;
+use crate::models::types::enum_type::EnumType;
+use crate::models::types::field_type::FieldType;
+use crate::models::types::primitive_type::{NativeType, PrimitiveConvertible, ResolvableType};
+use crate::models::types::{CharacterEncoding, Presence, SizedEncoded, Type};
+use crate::models::TypeMap;
+use anyhow::{anyhow, Result};
+use convert_case::{Case, Casing};
+use genco::prelude::*;
+
+/// Attribute of a class holding a value of a field, a group or var data, which is an argument
+/// of the initializer of the class.
+pub(crate) struct Attribute {
+    pub name: String,
+    /// Optional values are the last arguments of the initializer, with `None` as the default.
+    pub optional: bool,
+    /// Floats are rounded to single precision, so the attribute holds the value as encoded.
+    pub float32: bool,
+    /// Lists are compared and hashed as tuples.
+    pub list: bool,
+}
+
+/// Read-only attribute of a constant field, which is not encoded.
+pub(crate) struct Constant {
+    pub name: String,
+    pub value: Tokens<Python>,
+}
+
+/// Metadata of an encoded field, with the field name in snake case of Python.
+pub(crate) fn field_metadata(
+    field_name: &str,
+    encoded_type: &EncodedDataType,
+    types: &TypeMap,
+) -> Result<FieldMetadata<Python>> {
+    let field_primitive_type = encoded_type.primitive_type.resolved(&types.encoded_types)?;
+    let field_size = encoded_type.size(types)?;
+    let type_size = encoded_type.primitive_type.size(types)?;
+
+    Ok(FieldMetadata {
+        field_name: field_name.to_case(Case::Snake),
+        field_primitive_type: field_primitive_type.clone(),
+        type_size,
+        field_length: field_size / type_size,
+        lang_type: field_primitive_type.lang_primitive(&types.encoded_types)?,
+        encoding: encoded_type.character_encoding,
+    })
+}
+
+/// Name of the precompiled `struct.Struct` of a resolved primitive type in the buffer module,
+/// e.g. `U16`.
+pub(crate) fn struct_name(native_type: &NativeType) -> &'static str {
+    match native_type {
+        NativeType::Char | NativeType::UInt8 => "U8",
+        NativeType::UInt16 => "U16",
+        NativeType::UInt32 => "U32",
+        NativeType::UInt64 => "U64",
+        NativeType::Int8 => "I8",
+        NativeType::Int16 => "I16",
+        NativeType::Int32 => "I32",
+        NativeType::Int64 => "I64",
+        NativeType::Float => "F32",
+        NativeType::Double => "F64",
+        NativeType::Reference(type_name) => unreachable!("Resolved reference: {}", type_name),
+    }
+}
+
+/// Format character of the `struct` module of a resolved primitive type, used for arrays.
+pub(crate) fn format_character(native_type: &NativeType) -> &'static str {
+    match native_type {
+        NativeType::Char | NativeType::UInt8 => "B",
+        NativeType::UInt16 => "H",
+        NativeType::UInt32 => "I",
+        NativeType::UInt64 => "Q",
+        NativeType::Int8 => "b",
+        NativeType::Int16 => "h",
+        NativeType::Int32 => "i",
+        NativeType::Int64 => "q",
+        NativeType::Float => "f",
+        NativeType::Double => "d",
+        NativeType::Reference(type_name) => unreachable!("Resolved reference: {}", type_name),
+    }
+}
+
+/// Default null value of a primitive type, see `NativeType::null`.
+pub(crate) fn null_literal(native_type: &NativeType) -> &'static str {
+    match native_type {
+        NativeType::Char => "0",
+        NativeType::UInt8 => "255",
+        NativeType::UInt16 => "65535",
+        NativeType::UInt32 => "4294967295",
+        NativeType::UInt64 => "18446744073709551615",
+        NativeType::Int8 => "-128",
+        NativeType::Int16 => "-32768",
+        NativeType::Int32 => "-2147483648",
+        NativeType::Int64 => "-9223372036854775808",
+        NativeType::Float | NativeType::Double | NativeType::Reference(_) => "float(\"nan\")",
+    }
+}
+
+/// Null value of an optional field, either declared by the `nullValue` of the encoded type,
+/// or the default one for its primitive type.
+pub(crate) fn null_value(encoded_type: &EncodedDataType, native_type: &NativeType) -> String {
+    match &encoded_type.null_value {
+        Some(null_value) => null_value.to_owned(),
+        None => null_literal(native_type).to_owned(),
+    }
+}
+
+/// Null value of an enum, which is encoded for enums missing in older versions of the schema.
+pub(crate) fn enum_null_value(enum_type: &EnumType, types: &TypeMap) -> Result<&'static str> {
+    Ok(null_literal(
+        &enum_type.encoding_type.resolved(&types.encoded_types)?,
+    ))
+}
+
+fn null_value_condition(
+    variable: &str,
+    encoded_type: &EncodedDataType,
+    native_type: &NativeType,
+) -> Tokens<Python> {
+    match (native_type, &encoded_type.null_value) {
+        (NativeType::Float | NativeType::Double, None) => {
+            quote!($(python::import("math", "isnan"))($variable))
+        }
+        _ => quote!($variable == $(null_value(encoded_type, native_type))),
+    }
+}
+
+/// Python literal of a constant value from the schema.
+fn constant_literal(value: &str, native_type: &NativeType) -> String {
+    match native_type {
+        NativeType::Char => format!("\"{}\"", value),
+        _ => value.to_owned(),
+    }
+}
+
+/// Offset of a field relative to the `offset` of its block.
+pub(crate) fn offset_tokens(offset: usize) -> Tokens<Python> {
+    if offset == 0 {
+        quote!(offset)
+    } else {
+        quote!(offset + $offset)
+    }
+}
+
+impl ModuleGenerator<'_> {
+    /// The referenced type of references, any other type as it is.
+    pub(crate) fn resolved(&self, field_type: &Type) -> Result<Type> {
+        match field_type {
+            Type::Reference(reference_type) => {
+                let referenced_type = self
+                    .schema
+                    .types
+                    .find_type(&reference_type.type_name)
+                    .ok_or(anyhow!(
+                        "Referenced type {} not found",
+                        reference_type.type_name
+                    ))?;
+
+                self.resolved(&referenced_type)
+            }
+            _ => Ok(field_type.clone()),
+        }
+    }
+
+    /// Type of a field of a message or a group. Encoded fields declared optional or constant
+    /// by the field itself are such, even if their type is not.
+    pub(crate) fn message_field_type(&self, field: &FieldType) -> Result<Type> {
+        Ok(match self.resolved(&field.to_type(&self.schema.types)?)? {
+            Type::EncodedData(mut encoded_type) if field.presence != Presence::Required => {
+                encoded_type.presence = field.presence;
+                if encoded_type.default_value.is_none() {
+                    encoded_type.default_value = field.value_ref.clone();
+                }
+
+                Type::EncodedData(encoded_type)
+            }
+            field_type => field_type,
+        })
+    }
+
+    /// Attribute holding the value of a field, unless the field is constant.
+    pub(crate) fn field_attribute(
+        &self,
+        field_name: &str,
+        field_type: &Type,
+    ) -> Result<Option<Attribute>> {
+        let types = &self.schema.types;
+        let name = field_name.to_case(Case::Snake);
+
+        Ok(match self.resolved(field_type)? {
+            Type::EncodedData(encoded_type) => {
+                let metadata = field_metadata(field_name, &encoded_type, types)?;
+
+                match encoded_type.presence {
+                    Presence::Constant => None,
+                    presence => Some(Attribute {
+                        name,
+                        optional: presence == Presence::Optional,
+                        float32: metadata.field_primitive_type == NativeType::Float
+                            && metadata.field_length == 1,
+                        list: !matches!(
+                            (&metadata.field_primitive_type, metadata.field_length),
+                            (_, 0 | 1) | (NativeType::Char | NativeType::UInt8, _)
+                        ),
+                    }),
+                }
+            }
+            // Enums and composites added in later versions of the schema are still required
+            // arguments, but might be `None`
+            Type::Enum(_) | Type::Set(_) | Type::Composite(_) => Some(Attribute {
+                name,
+                optional: false,
+                float32: false,
+                list: false,
+            }),
+            Type::Reference(_) => unreachable!("Resolved reference: {}", field_name),
+        })
+    }
+
+    /// Read-only attribute of a constant field of a composite, a message or a group.
+    pub(crate) fn field_constant(
+        &self,
+        field_name: &str,
+        field_type: &Type,
+        value_ref: Option<&str>,
+    ) -> Result<Option<Constant>> {
+        let name = field_name.to_case(Case::Snake);
+
+        Ok(match self.resolved(field_type)? {
+            Type::EncodedData(encoded_type) if encoded_type.presence == Presence::Constant => {
+                let native_type = encoded_type
+                    .primitive_type
+                    .resolved(&self.schema.types.encoded_types)?;
+                let value = encoded_type.default_value.as_ref().ok_or(anyhow!(
+                    "Constant field {} has no default value",
+                    field_name
+                ))?;
+
+                Some(Constant {
+                    name,
+                    value: quote!($(constant_literal(value, &native_type))),
+                })
+            }
+            Type::Enum(enum_type) => match value_ref {
+                Some(value_ref) => {
+                    let value_name = value_ref
+                        .split('.')
+                        .next_back()
+                        .ok_or(anyhow!("Constant field {} has no ref value", field_name))?;
+                    let value = enum_type
+                        .values
+                        .iter()
+                        .find(|value| value.name == value_name)
+                        .ok_or(anyhow!(
+                            "Constant field {} has no value {}",
+                            field_name,
+                            value_name
+                        ))?;
+
+                    Some(Constant {
+                        name,
+                        value: quote!($(self.enum_class(&enum_type)).$(value.name.to_case(Case::UpperSnake))),
+                    })
+                }
+                None => None,
+            },
+            _ => None,
+        })
+    }
+
+    /// Class of an enum, imported into the module being written.
+    fn enum_class(&self, enum_type: &EnumType) -> Tokens<Python> {
+        self.import(ENUM_MODULE_NAME, &enum_type.name.to_case(Case::UpperCamel))
+    }
+
+    /// Decodes a field of a block at `offset` of the `buffer` into an attribute of `decoded`.
+    /// Fields added in a newer version of the schema than the `acting_version` of the decoded
+    /// data are null.
+    pub(crate) fn field_decoder(
+        &self,
+        module: &str,
+        field_name: &str,
+        field_type: &Type,
+        offset: usize,
+        since_version: usize,
+    ) -> Result<Tokens<Python>> {
+        let attribute = field_name.to_case(Case::Snake);
+        let offset = offset_tokens(offset);
+
+        let (decoder, missing): (Tokens<Python>, Tokens<Python>) = match self
+            .resolved(field_type)?
+        {
+            Type::EncodedData(encoded_type) => {
+                if encoded_type.presence == Presence::Constant {
+                    return Ok(quote!());
+                }
+
+                self.encoded_field_decoder(field_name, &encoded_type, offset)?
+            }
+            Type::Enum(enum_type) => {
+                let native_type = enum_type
+                    .encoding_type
+                    .resolved(&self.schema.types.encoded_types)?;
+                let buffer_struct = self.buffer_import(struct_name(&native_type));
+                let enum_class = self.enum_class(&enum_type);
+
+                // The null value encoded for a missing enum of a newer version is `None` too,
+                // so that the field can be encoded back with the version it was decoded with.
+                let decoder = if since_version > 0 {
+                    quote! {
+                        value = $buffer_struct.unpack_from(buffer, $offset)[0]
+                        decoded.$(&attribute) = None if value == $(enum_null_value(&enum_type, &self.schema.types)?) else $enum_class(value)
+                    }
+                } else {
+                    quote!(decoded.$(&attribute) = $enum_class($buffer_struct.unpack_from(buffer, $offset)[0]))
+                };
+
+                (decoder, quote!(decoded.$(&attribute) = None))
+            }
+            Type::Set(set_type) => {
+                let set_class =
+                    self.import(SET_MODULE_NAME, &set_type.name.to_case(Case::UpperCamel));
+
+                (
+                    quote!(decoded.$(&attribute) = $(&set_class)._decode(buffer, $offset)),
+                    quote!(decoded.$(&attribute) = $(&set_class)()),
+                )
+            }
+            Type::Composite(composite_type) => {
+                let composite_class = self.import_into(
+                    module,
+                    COMPOSITE_MODULE_NAME,
+                    &composite_type.name.to_case(Case::UpperCamel),
+                );
+
+                // The null values encoded for a missing composite are `None` too
+                let decoder = if since_version > 0 {
+                    quote!(decoded.$(&attribute) = None if $(&composite_class)._is_null(buffer, $(&offset)) else $(&composite_class)._decode(buffer, $(&offset)))
+                } else {
+                    quote!(decoded.$(&attribute) = $composite_class._decode(buffer, $offset))
+                };
+
+                (decoder, quote!(decoded.$(&attribute) = None))
+            }
+            Type::Reference(_) => unreachable!("Resolved reference: {}", field_name),
+        };
+
+        if since_version == 0 {
+            return Ok(decoder);
+        }
+
+        Ok(quote! {
+            if acting_version < $since_version:
+                $missing
+            else:
+                $decoder
+        })
+    }
+
+    /// Decoder of an encoded field and the null value of the field missing in older versions
+    /// of the schema.
+    fn encoded_field_decoder(
+        &self,
+        field_name: &str,
+        encoded_type: &EncodedDataType,
+        offset: Tokens<Python>,
+    ) -> Result<(Tokens<Python>, Tokens<Python>)> {
+        let metadata = field_metadata(field_name, encoded_type, &self.schema.types)?;
+        let native_type = &metadata.field_primitive_type;
+        let attribute = &metadata.field_name;
+        let length = metadata.field_length;
+        let optional = encoded_type.presence == Presence::Optional;
+        let null_value = null_value(encoded_type, native_type);
+
+        let missing = match (optional, native_type, length) {
+            (true, _, _) => quote!(decoded.$attribute = None),
+            (false, NativeType::Char, 2..) => quote!(decoded.$attribute = ""),
+            (false, NativeType::UInt8, 2..) => {
+                quote!(decoded.$attribute = bytes([$(&null_value)]) * $length)
+            }
+            (false, _, 2..) => quote!(decoded.$attribute = [$(&null_value)] * $length),
+            (false, _, _) => quote!(decoded.$attribute = $(&null_value)),
+        };
+
+        let decoder = match (native_type, length) {
+            (NativeType::Char, 2..) => {
+                let get_bytes = self.buffer_import("get_bytes");
+
+                if optional {
+                    quote! {
+                        value = $get_bytes(buffer, $offset, $length)
+                        decoded.$attribute = None if value == bytes([$(&null_value)]) * $length else value.decode()
+                    }
+                } else {
+                    quote!(decoded.$attribute = $get_bytes(buffer, $offset, $length).decode())
+                }
+            }
+            (NativeType::UInt8, 2..) => {
+                let get_bytes = self.buffer_import("get_bytes");
+
+                if optional {
+                    quote! {
+                        value = $get_bytes(buffer, $offset, $length)
+                        decoded.$attribute = None if value == bytes([$(&null_value)]) * $length else value
+                    }
+                } else {
+                    quote!(decoded.$attribute = $get_bytes(buffer, $offset, $length))
+                }
+            }
+            (_, 2..) => {
+                let get_array = self.buffer_import("get_array");
+                let format = quoted(format_character(native_type));
+
+                if optional {
+                    quote! {
+                        value = $get_array(buffer, $offset, $format, $length)
+                        decoded.$attribute = None if all($(null_value_condition("part", encoded_type, native_type)) for part in value) else value
+                    }
+                } else {
+                    quote!(decoded.$attribute = $get_array(buffer, $offset, $format, $length))
+                }
+            }
+            (_, _) => {
+                let buffer_struct = self.buffer_import(struct_name(native_type));
+                let checks = self.bounds_checks(field_name, "value", encoded_type, optional);
+
+                match (optional, checks.is_empty()) {
+                    (false, true) => {
+                        quote!(decoded.$attribute = $buffer_struct.unpack_from(buffer, $offset)[0])
+                    }
+                    (false, false) => quote! {
+                        value = $buffer_struct.unpack_from(buffer, $offset)[0]
+                        $checks
+                        decoded.$attribute = value
+                    },
+                    (true, _) => quote! {
+                        value = $buffer_struct.unpack_from(buffer, $offset)[0]
+                        if $(null_value_condition("value", encoded_type, native_type)):
+                            value = None
+                        $checks
+                        decoded.$attribute = value
+                    },
+                }
+            }
+        };
+
+        Ok((decoder, missing))
+    }
+
+    /// Encodes an attribute of `self` as a field of a block at `offset` of the `buffer`.
+    pub(crate) fn field_encoder(
+        &self,
+        field_name: &str,
+        field_type: &Type,
+        offset: usize,
+        since_version: usize,
+    ) -> Result<Tokens<Python>> {
+        let attribute = field_name.to_case(Case::Snake);
+        let offset = offset_tokens(offset);
+
+        Ok(match self.resolved(field_type)? {
+            Type::EncodedData(encoded_type) => match encoded_type.presence {
+                Presence::Constant => quote!(),
+                _ => self.encoded_field_encoder(field_name, &encoded_type, offset)?,
+            },
+            Type::Enum(enum_type) => {
+                let native_type = enum_type
+                    .encoding_type
+                    .resolved(&self.schema.types.encoded_types)?;
+                let buffer_struct = self.buffer_import(struct_name(&native_type));
+
+                if since_version > 0 {
+                    quote!($buffer_struct.pack_into(buffer, $offset, $(enum_null_value(&enum_type, &self.schema.types)?) if self.$(&attribute) is None else self.$(&attribute).value))
+                } else {
+                    quote!($buffer_struct.pack_into(buffer, $offset, self.$(&attribute).value))
+                }
+            }
+            Type::Set(_) => quote!(self.$(&attribute)._encode(buffer, $offset)),
+            Type::Composite(composite_type) if since_version > 0 => {
+                let composite_class = self.import(
+                    COMPOSITE_MODULE_NAME,
+                    &composite_type.name.to_case(Case::UpperCamel),
+                );
+
+                quote! {
+                    if self.$(&attribute) is None:
+                        $composite_class._encode_null(buffer, $(&offset))
+                    else:
+                        self.$(&attribute)._encode(buffer, $(&offset))
+                }
+            }
+            Type::Composite(_) => quote!(self.$(&attribute)._encode(buffer, $offset)),
+            Type::Reference(_) => unreachable!("Resolved reference: {}", field_name),
+        })
+    }
+
+    fn encoded_field_encoder(
+        &self,
+        field_name: &str,
+        encoded_type: &EncodedDataType,
+        offset: Tokens<Python>,
+    ) -> Result<Tokens<Python>> {
+        let metadata = field_metadata(field_name, encoded_type, &self.schema.types)?;
+        let native_type = &metadata.field_primitive_type;
+        let attribute = &metadata.field_name;
+        let length = metadata.field_length;
+        let optional = encoded_type.presence == Presence::Optional;
+        let null_value = null_value(encoded_type, native_type);
+
+        Ok(match (native_type, length) {
+            (NativeType::Char, 2..) => {
+                let put_string = self.buffer_import("put_string");
+                let ascii = match metadata.encoding {
+                    Some(CharacterEncoding::Ascii) => "True",
+                    _ => "False",
+                };
+                let encode = quote!($put_string(buffer, $(&offset), $length, self.$attribute, $(quoted(field_name)), $ascii));
+
+                if optional {
+                    quote! {
+                        if self.$attribute is None:
+                            $(self.buffer_import("put_bytes"))(buffer, $(&offset), bytes([$(&null_value)]) * $length)
+                        else:
+                            $encode
+                    }
+                } else {
+                    encode
+                }
+            }
+            (_, 2..) => {
+                let put_array = self.buffer_import("put_array");
+                let format = quoted(format_character(native_type));
+                let checks = self.bounds_checks(field_name, "value", encoded_type, false);
+
+                let checks = if checks.is_empty() {
+                    checks
+                } else if optional {
+                    quote! {
+                        for value in self.$attribute or []:
+                            $checks
+                    }
+                } else {
+                    quote! {
+                        for value in self.$attribute:
+                            $checks
+                    }
+                };
+
+                let value = if optional {
+                    quote!([$(&null_value)] * $length if self.$attribute is None else self.$attribute)
+                } else {
+                    quote!(self.$attribute)
+                };
+
+                quote! {
+                    $checks
+                    $put_array(buffer, $offset, $format, $length, $value)
+                }
+            }
+            (_, _) => {
+                let buffer_struct = self.buffer_import(struct_name(native_type));
+                let checks = self.bounds_checks(field_name, "value", encoded_type, optional);
+
+                match (optional, checks.is_empty()) {
+                    (false, true) => {
+                        quote!($buffer_struct.pack_into(buffer, $offset, self.$attribute))
+                    }
+                    (false, false) => quote! {
+                        value = self.$attribute
+                        $checks
+                        $buffer_struct.pack_into(buffer, $offset, value)
+                    },
+                    (true, _) => quote! {
+                        value = self.$attribute
+                        $checks
+                        $buffer_struct.pack_into(buffer, $offset, $(&null_value) if value is None else value)
+                    },
+                }
+            }
+        })
+    }
+
+    /// Checks of a `variable` against the `minValue` and `maxValue` of the encoded type,
+    /// unless they are disabled. Null values of optional fields are not checked.
+    fn bounds_checks(
+        &self,
+        field_name: &str,
+        variable: &str,
+        encoded_type: &EncodedDataType,
+        optional: bool,
+    ) -> Tokens<Python> {
+        let mut checks = quote!();
+
+        if !self.bounds_checks {
+            return checks;
+        }
+
+        let limits = [
+            (&encoded_type.min_value, "<", "min"),
+            (&encoded_type.max_value, ">", "max"),
+        ];
+
+        for (limit, operator, limit_name) in limits {
+            let Some(limit) = limit else {
+                continue;
+            };
+
+            let condition = if optional {
+                quote!($variable is not None and $variable $operator $limit)
+            } else {
+                quote!($variable $operator $limit)
+            };
+            let message = format!(
+                "f\"value out of bounds for field '{}': {{{}}} {} {} ({})\"",
+                field_name, variable, operator, limit, limit_name
+            );
+
+            checks.append(quote! {
+                if $condition:
+                    raise $(self.error_class())($message)
+            });
+            checks.push();
+        }
+
+        checks
+    }
+
+    /// Helper of the buffer module of the schema, imported into the module being written.
+    pub(crate) fn buffer_import(&self, name: &str) -> Tokens<Python> {
+        self.import(BUFFER_MODULE_NAME, name)
+    }
+
+    /// Generates the initializer of a class of a composite, a message or a group with its
+    /// attributes as arguments, along with its constants, equality, hashing and representation.
+    /// Classes of groups are referred to by their `qualified_name`, e.g. `Car.FuelFigures`.
+    pub(crate) fn generate_class_methods(
+        &self,
+        name: &str,
+        qualified_name: &str,
+        attributes: &[Attribute],
+        constants: &[Constant],
+    ) -> Tokens<Python> {
+        // Optional attributes are the last arguments, same as in the stubs
+        let mut arguments = attributes.iter().collect::<Vec<_>>();
+        arguments.sort_by_key(|attribute| attribute.optional);
+
+        let mut init_tokens: Tokens<Python> = quote!();
+        for attribute in attributes {
+            let value = if attribute.float32 {
+                quote!($(self.buffer_import("to_f32"))($(&attribute.name)))
+            } else {
+                quote!($(&attribute.name))
+            };
+
+            init_tokens.push();
+            init_tokens.append(quote!(self.$(&attribute.name) = $value));
+        }
+
+        if attributes.is_empty() {
+            init_tokens.append(quote!(pass));
+        }
+
+        let key_values = attributes
+            .iter()
+            .map(|attribute| match (attribute.list, attribute.optional) {
+                (true, true) => format!(
+                    "None if self.{0} is None else tuple(self.{0})",
+                    attribute.name
+                ),
+                (true, false) => format!("tuple(self.{})", attribute.name),
+                _ => format!("self.{}", attribute.name),
+            })
+            .collect::<Vec<_>>();
+        let key = match key_values.len() {
+            1 => format!("({},)", key_values[0]),
+            _ => format!("({})", key_values.join(", ")),
+        };
+
+        let repr = format!(
+            "f\"{}({})\"",
+            name,
+            attributes
+                .iter()
+                .map(|attribute| format!("{0}={{self.{0}!r}}", attribute.name))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+
+        quote! {
+            def __init__(
+                self,
+                $(for argument in arguments {
+                    $['\r']
+                    $(&argument.name)$(if argument.optional { =None }),
+                })
+            ) -> None:
+                $init_tokens
+            $(for constant in constants {
+                $['\n']
+                @property
+                def $(&constant.name)(self):
+                    return $(&constant.value)
+            })
+
+            def _key(self):
+                return $key
+
+            def __eq__(self, other):
+                if not isinstance(other, $qualified_name):
+                    return NotImplemented
+
+                return self._key() == other._key()
+
+            def __hash__(self):
+                return hash(self._key())
+
+            def __repr__(self):
+                return $repr
+        }
+    }
+}
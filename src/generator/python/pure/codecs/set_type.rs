@@ -0,0 +1,92 @@
+use crate::generator::python::constants::SET_MODULE_NAME;
+use crate::generator::python::pure::codecs::struct_name;
+use crate::generator::python::pure::module::ModuleGenerator;
+use crate::models::types::primitive_type::ResolvableType;
+use crate::models::types::set_type::{Choice, SetType};
+use anyhow::Result;
+use convert_case::{Case, Casing};
+use genco::prelude::*;
+
+impl ModuleGenerator<'_> {
+    /// Generates the boolean property of a choice, backed by its bit in the encoded value.
+    fn generate_choice_property(choice: &Choice) -> Tokens<Python> {
+        let choice_name = choice.name.to_case(Case::Snake);
+        let mask = format!("(1 << {})", choice.value);
+
+        quote! {
+            $['\n']
+            @property
+            def $(&choice_name)(self) -> bool:
+                return self._bits & $(&mask) != 0
+
+            @$(&choice_name).setter
+            def $(&choice_name)(self, flag: bool) -> None:
+                self._bits = self._bits | $(&mask) if flag else self._bits & ~$(&mask)
+        }
+    }
+
+    fn generate_set_codec(&self, set_type: &SetType) -> Result<Tokens<Python>> {
+        let name = set_type.name.to_case(Case::UpperCamel);
+        let native_type = set_type
+            .encoding_type
+            .resolved(&self.schema.types.encoded_types)?;
+        let buffer_struct = self.buffer_import(struct_name(&native_type));
+
+        let choices = set_type
+            .choices
+            .iter()
+            .map(|choice| {
+                let choice_name = choice.name.to_case(Case::Snake);
+                format!("{}={{self.{}}}", choice_name, choice_name)
+            })
+            .collect::<Vec<_>>()
+            .join(", ");
+        let repr = format!("f\"{}({})\"", name, choices);
+
+        Ok(quote! {
+            @$(python::import("functools", "total_ordering"))
+            class $(&name):
+                def __init__(self) -> None:
+                    self._bits = 0
+                $(for choice in &set_type.choices => $(Self::generate_choice_property(choice)))
+
+                def __eq__(self, other):
+                    if not isinstance(other, $(&name)):
+                        return NotImplemented
+
+                    return self._bits == other._bits
+
+                def __lt__(self, other):
+                    if not isinstance(other, $(&name)):
+                        return NotImplemented
+
+                    return self._bits < other._bits
+
+                def __hash__(self):
+                    return hash(self._bits)
+
+                def __repr__(self):
+                    return $repr
+
+                @staticmethod
+                def _decode(buffer, offset):
+                    decoded = $(&name).__new__($(&name))
+                    decoded._bits = $(&buffer_struct).unpack_from(buffer, offset)[0]
+                    return decoded
+
+                def _encode(self, buffer, offset):
+                    $(&buffer_struct).pack_into(buffer, offset, self._bits)
+        })
+    }
+
+    pub(crate) fn write_set_module(&self) -> Result<()> {
+        let mut set_tokens: Tokens<Python> = quote!();
+
+        for set_type in self.schema.types.set_types.values() {
+            set_tokens.line();
+            set_tokens.append(self.generate_set_codec(set_type)?);
+        }
+
+        self.write_module(SET_MODULE_NAME, set_tokens)
+    }
+}
@@ -0,0 +1,172 @@
+mod buffer;
+mod codecs;
+mod module;
+
+use crate::generator::python::constants::{ERROR_CLASS_NAME, ERROR_MODULE_NAME, PYSRC_DIR};
+use crate::generator::python::exported_classes;
+use crate::generator::python::pure::module::ModuleGenerator;
+use crate::generator::python::typing::TypingGenerator;
+use crate::generator::{write_file, CodeGenerator};
+use crate::models::schema::ValidatedMessageSchema;
+use anyhow::Result;
+use genco::prelude::*;
+use indoc::formatdoc;
+use std::fs::{create_dir_all, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+/// Generates a package of plain Python classes built on `struct` and `memoryview`, with the
+/// same classes and stubs as the pyo3 extension, for interpreters which cannot load it.
+pub struct PurePythonGenerator {
+    path: PathBuf,
+    project_name: String,
+    project_version: String,
+    bounds_checks: bool,
+    schemas: Vec<ValidatedMessageSchema>,
+}
+
+impl PurePythonGenerator {
+    pub fn new(
+        path: &Path,
+        project_name: &str,
+        project_version: &str,
+        schemas: Vec<ValidatedMessageSchema>,
+        bounds_checks: bool,
+    ) -> Self {
+        Self {
+            path: path.to_owned(),
+            project_name: project_name.to_owned(),
+            project_version: project_version.to_owned(),
+            bounds_checks,
+            schemas,
+        }
+    }
+
+    fn package_path(&self) -> PathBuf {
+        self.path.join(PYSRC_DIR).join(&self.project_name)
+    }
+
+    fn write_project_files(&self, with_test_dependencies: bool) -> Result<()> {
+        create_dir_all(self.package_path())?;
+
+        self.write_pyproject_toml(with_test_dependencies)?;
+        self.write_error_py()?;
+        self.write_init_py()
+    }
+
+    fn write_pyproject_toml(&self, with_test_dependencies: bool) -> Result<()> {
+        let test_dependencies = formatdoc! {"
+            [tool.poetry.group.dev.dependencies]
+            pytest = \"^7\"
+            pytest-benchmark = \"^4\"
+        "};
+
+        let pyproject_toml_content = formatdoc! {"
+                [build-system]
+                requires = [\"poetry-core>=1.0.0\"]
+                build-backend = \"poetry.core.masonry.api\"
+
+                [tool.poetry]
+                name = \"{name}\"
+                version = \"{version}\"
+                license = \"Proprietary\"
+                authors = []
+                description = \"\"
+                packages = [{{ include = \"{name}\", from = \"{pysrc}\" }}]
+                classifiers = [
+                    \"Programming Language :: Python :: Implementation :: CPython\",
+                    \"Programming Language :: Python :: Implementation :: PyPy\",
+                ]
+
+                [tool.poetry.dependencies]
+                python = \"^3.10\"
+
+                {test_deps}
+            ",
+            name = self.project_name,
+            version = self.project_version,
+            pysrc = PYSRC_DIR,
+            test_deps = if with_test_dependencies {
+                test_dependencies
+            } else {
+                String::new()
+            },
+        };
+
+        let pyproject_toml_path = self.path.join("pyproject.toml");
+        let mut pyproject_toml_file = File::create(pyproject_toml_path)?;
+        pyproject_toml_file.write_all(pyproject_toml_content.as_bytes())?;
+
+        Ok(())
+    }
+
+    fn write_error_py(&self) -> Result<()> {
+        let error_py_content: Tokens<Python> = quote! {
+            class $ERROR_CLASS_NAME(RuntimeError):
+                pass
+        };
+
+        write_file(
+            &self
+                .package_path()
+                .join(format!("{}.py", ERROR_MODULE_NAME)),
+            &python::Config::default(),
+            error_py_content,
+        )
+    }
+
+    fn write_init_py(&self) -> Result<()> {
+        write_file::<Python>(
+            &self.package_path().join("py.typed"),
+            &python::Config::default(),
+            quote!(),
+        )?;
+
+        let init_py_content: Tokens<Python> = quote! {
+            $(for schema in &self.schemas {
+                from . import $(&schema.package)
+                $['\r']
+            })
+            from .$ERROR_MODULE_NAME import $ERROR_CLASS_NAME
+
+            __all__ = [
+                $(for schema in &self.schemas {
+                    $['\r']
+                    $(quoted(&schema.package)),
+                })
+                $(quoted(ERROR_CLASS_NAME)),
+            ]
+        };
+
+        write_file(
+            &self.package_path().join("__init__.py"),
+            &python::Config::default(),
+            init_py_content,
+        )
+    }
+}
+
+impl CodeGenerator for PurePythonGenerator {
+    fn generate_project(&self, with_test_dependencies: bool, _format_project: bool) -> Result<()> {
+        // Project files (pyproject.toml, ...)
+        self.write_project_files(with_test_dependencies)?;
+
+        // Generate a package for every schema along with its stubs, which are the same as
+        // the ones of the pyo3 extension. The generated modules are formatted already.
+        for schema in &self.schemas {
+            let exported_classes = exported_classes(schema);
+
+            ModuleGenerator::new(
+                &self.package_path().join(&schema.package),
+                schema,
+                self.bounds_checks,
+            )
+            .generate_module(&exported_classes)?;
+
+            TypingGenerator::new(&self.path, &self.project_name, schema)
+                .write_typing_hints(&exported_classes)?;
+        }
+
+        Ok(())
+    }
+}
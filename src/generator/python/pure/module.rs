@@ -0,0 +1,117 @@
+use crate::generator::python::constants::{
+    COMPOSITE_MODULE_NAME, ENUM_MODULE_NAME, ERROR_CLASS_NAME, ERROR_MODULE_NAME,
+    MESSAGE_MODULE_NAME, SET_MODULE_NAME,
+};
+use crate::generator::python::ExportedClass;
+use crate::generator::write_file;
+use crate::models::schema::ValidatedMessageSchema;
+use crate::models::types::Type;
+use anyhow::Result;
+use genco::prelude::*;
+use std::collections::BTreeMap;
+use std::fs::create_dir_all;
+use std::path::{Path, PathBuf};
+
+pub struct ModuleGenerator<'a> {
+    pub(crate) path: PathBuf,
+    pub(crate) schema: &'a ValidatedMessageSchema,
+    pub(crate) bounds_checks: bool,
+}
+
+impl<'a> ModuleGenerator<'a> {
+    pub fn new(path: &Path, schema: &'a ValidatedMessageSchema, bounds_checks: bool) -> Self {
+        Self {
+            path: path.to_owned(),
+            schema,
+            bounds_checks,
+        }
+    }
+
+    pub fn generate_module(
+        &self,
+        exported_classes: &BTreeMap<String, ExportedClass>,
+    ) -> Result<()> {
+        create_dir_all(&self.path)?;
+
+        // Primitive types in the byte order of the schema
+        self.write_buffer_module()?;
+
+        // Type specific classes, the ones of groups are nested in the classes of their
+        // messages and enclosing groups
+        self.write_enum_module()?;
+        self.write_set_module()?;
+        self.write_composite_module()?;
+        self.write_message_module()?;
+
+        self.write_init_module(exported_classes)
+    }
+
+    /// Writes the package of the schema, which exports the same classes as the module of the
+    /// pyo3 extension.
+    fn write_init_module(&self, exported_classes: &BTreeMap<String, ExportedClass>) -> Result<()> {
+        let mut modules: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+
+        for (class_name, class) in exported_classes {
+            let module = match class {
+                ExportedClass::Message(_) => MESSAGE_MODULE_NAME,
+                ExportedClass::Type(Type::Enum(_)) => ENUM_MODULE_NAME,
+                ExportedClass::Type(Type::Set(_)) => SET_MODULE_NAME,
+                ExportedClass::Type(Type::Composite(_)) => COMPOSITE_MODULE_NAME,
+                _ => unreachable!("Only messages, composites, sets and enums are exported"),
+            };
+
+            modules.entry(module).or_default().push(class_name);
+        }
+
+        let init_py_content: Tokens<Python> = quote! {
+            $(for (module, class_names) in &modules {
+                $['\r']
+                from .$(*module) import $(for class_name in class_names join (, ) => $(*class_name))
+            })
+
+            SCHEMA_ID: int = $(self.schema.id)
+            SCHEMA_VERSION: int = $(self.schema.version)
+
+            __all__ = [
+                $(for class_name in exported_classes.keys() {
+                    $['\r']
+                    $(quoted(class_name)),
+                })
+            ]
+        };
+
+        self.write_module("__init__", init_py_content)
+    }
+
+    pub(crate) fn write_module(&self, name: &str, content: Tokens<Python>) -> Result<()> {
+        write_file(
+            &self.path.join(format!("{}.py", name)),
+            &python::Config::default(),
+            content,
+        )
+    }
+
+    /// Error raised by the codecs, shared by all schemas of the project.
+    pub(crate) fn error_class(&self) -> python::Import {
+        python::import(format!("..{}", ERROR_MODULE_NAME), ERROR_CLASS_NAME)
+    }
+
+    /// Class or function of a module of the schema, imported into the module being written.
+    pub(crate) fn import(&self, module_name: &str, name: &str) -> Tokens<Python> {
+        quote!($(python::import(format!(".{}", module_name), name.to_owned())))
+    }
+
+    /// Same as `import`, unless the class is defined in the module being written itself.
+    pub(crate) fn import_into(
+        &self,
+        current_module: &str,
+        module_name: &str,
+        name: &str,
+    ) -> Tokens<Python> {
+        if current_module == module_name {
+            quote!($name)
+        } else {
+            self.import(module_name, name)
+        }
+    }
+}
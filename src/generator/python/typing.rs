@@ -1,10 +1,11 @@
 use crate::generator::python::constants::PYSRC_DIR;
-use crate::generator::python::{ExportedClass, PythonGenerator};
+use crate::generator::python::ExportedClass;
 use crate::generator::write_file;
 use crate::models::message::MessageType;
 use crate::models::schema::ValidatedMessageSchema;
 use crate::models::types::composite_type::CompositeType;
 use crate::models::types::enum_type::EnumType;
+use crate::models::types::field_type::FieldType;
 use crate::models::types::primitive_type::{
     LanguagePrimitive, NativeType, PrimitiveConvertible, ResolvableType,
 };
@@ -15,8 +16,7 @@ use convert_case::{Case, Casing};
 use genco::lang::{python, Python};
 use genco::prelude::FormatInto;
 use genco::{quote, Tokens};
-use std::cmp::Ordering;
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 use std::path::Path;
 
 pub struct TypingGenerator<'a> {
@@ -28,22 +28,21 @@ pub struct TypingGenerator<'a> {
 }
 
 impl<'a> TypingGenerator<'a> {
-    pub fn from_python(
-        python_generator: &'a PythonGenerator,
-        schema: &'a ValidatedMessageSchema,
-    ) -> Self {
+    /// Creates the generator of the stubs of a schema, shared by the pyo3 extension and
+    /// the pure Python codecs, which expose the same classes.
+    pub fn new(path: &'a Path, project_name: &'a str, schema: &'a ValidatedMessageSchema) -> Self {
         Self {
-            path: &python_generator.path,
-            project_name: &python_generator.project_name,
+            path,
+            project_name,
             types: &schema.types,
-            python_config: python_generator.python_config(),
+            python_config: python::Config::default(),
             schema,
         }
     }
 
     pub fn write_typing_hints(
         &self,
-        exported_classes: &HashMap<String, ExportedClass>,
+        exported_classes: &BTreeMap<String, ExportedClass>,
     ) -> anyhow::Result<()> {
         let mut pyi_tokens: Tokens<Python> = quote! {
             from enum import Enum
@@ -58,26 +57,18 @@ impl<'a> TypingGenerator<'a> {
                     class $class_name:
                         ID: ClassVar[int]
 
-                        $(self.write_fields(&message.fields, false)?)
+                        $(self.write_nested_groups(class_name, &message.fields)?)
+
+                        $(self.write_fields(class_name, &message.fields, false)?)
 
                         def __init__(
                             self,
-                            $(self.write_fields(&message.fields, true)?)
+                            $(self.write_fields(class_name, &message.fields, true)?)
                         ) -> None:
                             ...
 
                         $(self.write_message_functions(message)?)
                 },
-                ExportedClass::MessageField(MessageField::Group(group_type)) => quote! {
-                    class $class_name:
-                        $(self.write_fields(&group_type.fields, false)?)
-
-                        def __init__(
-                            self,
-                            $(self.write_fields(&group_type.fields, true)?)
-                        ) -> None:
-                            ...
-                },
                 ExportedClass::Type(Type::Composite(composite)) => quote! {
                     class $class_name:
                         $(self.write_composite_fields(composite, false)?)
@@ -96,7 +87,7 @@ impl<'a> TypingGenerator<'a> {
                     class $class_name(Enum):
                         $(self.write_enum_fields(enum_type)?)
                 },
-                _ => unreachable!("Only messages, groups, composites, sets and enums are exported"),
+                _ => unreachable!("Only messages, composites, sets and enums are exported"),
             };
 
             pyi_tokens.append(class_tokens);
@@ -114,8 +105,44 @@ impl<'a> TypingGenerator<'a> {
         )
     }
 
+    /// Writes the classes of the groups of a message or group as nested classes,
+    /// e.g. `Car.FuelFigures`.
+    fn write_nested_groups(
+        &self,
+        scope: &str,
+        fields: &[MessageField],
+    ) -> anyhow::Result<impl FormatInto<Python>> {
+        let mut groups_tokens: Tokens<Python> = quote!();
+
+        for field in fields {
+            let MessageField::Group(group_type) = field else {
+                continue;
+            };
+
+            let class_name = group_type.name.to_case(Case::UpperCamel);
+            let qualified_name = format!("{}.{}", scope, class_name);
+
+            groups_tokens.line();
+            groups_tokens.append(quote! {
+                class $(&class_name):
+                    $(self.write_nested_groups(&qualified_name, &group_type.fields)?)
+
+                    $(self.write_fields(&qualified_name, &group_type.fields, false)?)
+
+                    def __init__(
+                        self,
+                        $(self.write_fields(&qualified_name, &group_type.fields, true)?)
+                    ) -> None:
+                        ...
+            });
+        }
+
+        Ok(groups_tokens)
+    }
+
     fn write_fields(
         &self,
+        scope: &str,
         fields: &[MessageField],
         init: bool,
     ) -> anyhow::Result<impl FormatInto<Python>> {
@@ -133,16 +160,8 @@ impl<'a> TypingGenerator<'a> {
         // fields to the end; everything else will be kept in its current (correct) position,
         // since the sort is stable.
         let mut fields = fields.to_vec();
-        fields.sort_by(|a, b| match (a, b) {
-            (MessageField::Field(a), _) => match a.presence {
-                Presence::Optional => Ordering::Greater,
-                _ => Ordering::Equal,
-            },
-            (_, MessageField::Field(b)) => match b.presence {
-                Presence::Optional => Ordering::Less,
-                _ => Ordering::Equal,
-            },
-            _ => Ordering::Equal,
+        fields.sort_by_key(|field| {
+            matches!(field, MessageField::Field(field) if field.presence == Presence::Optional)
         });
 
         for field in &fields {
@@ -154,11 +173,15 @@ impl<'a> TypingGenerator<'a> {
 
             // Optional fields will have | None annotation. In the init, there will
             // be a comma at the end.
-            let field_type = self.resolved_field_type_name(field)?;
+            let field_type = self.resolved_field_type_name(scope, field)?;
             let field_type = match field {
                 MessageField::Field(field) => match (field.presence, init) {
                     (Presence::Optional, true) => format!("{} | None = None,", field_type),
                     (Presence::Optional, false) => format!("{} | None", field_type),
+                    // Fields of optional types are still required arguments of the init
+                    _ if self.is_optional_type(field)? => {
+                        format!("{} | None{}", field_type, if init { "," } else { "" })
+                    }
                     (_, true) => format!("{},", field_type),
                     _ => field_type,
                 },
@@ -181,7 +204,7 @@ impl<'a> TypingGenerator<'a> {
         message_type: &MessageType,
     ) -> anyhow::Result<impl FormatInto<Python>> {
         Ok(quote! {
-            def to_bytes(self, buffer_size: int) -> bytes:
+            def to_bytes(self) -> bytes:
                 ...
 
             def write_to_buffer(self, buffer: bytearray) -> int:
@@ -206,17 +229,28 @@ impl<'a> TypingGenerator<'a> {
 
         let mut fields_tokens: Tokens<Python> = quote!();
 
-        for simple_type in &composite_type.fields {
-            if init && matches!(simple_type.presence(self.types)?, Presence::Constant) {
+        // Optional fields are the last arguments of the init, same as for messages
+        let mut fields = composite_type.fields.iter().collect::<Vec<_>>();
+        fields.sort_by_key(|field| matches!(field.presence(self.types), Ok(Presence::Optional)));
+
+        for simple_type in fields {
+            let presence = simple_type.presence(self.types)?;
+            if init && matches!(presence, Presence::Constant) {
                 continue;
             }
 
             let field_name = simple_type.name().to_case(Case::Snake);
             let field_type = self.resolved_type_name(simple_type)?;
+            let field_type = match (presence, init) {
+                (Presence::Optional, true) => format!("{} | None = None,", field_type),
+                (Presence::Optional, false) => format!("{} | None", field_type),
+                (_, true) => format!("{},", field_type),
+                _ => field_type,
+            };
 
             fields_tokens.push();
             fields_tokens.append(quote! {
-                $(field_name): $(field_type)$(if init {,})
+                $(field_name): $(field_type)
             });
         }
 
@@ -248,17 +282,38 @@ impl<'a> TypingGenerator<'a> {
         })
     }
 
-    fn resolved_field_type_name(&self, field: &MessageField) -> anyhow::Result<String> {
+    fn is_optional_type(&self, field_type: &FieldType) -> anyhow::Result<bool> {
+        let resolved_type = field_type.to_type(self.types)?;
+
+        Ok(matches!(
+            resolved_type.presence(self.types)?,
+            Presence::Optional
+        ))
+    }
+
+    fn resolved_field_type_name(
+        &self,
+        scope: &str,
+        field: &MessageField,
+    ) -> anyhow::Result<String> {
         match field {
             MessageField::Field(field_type) => {
+                let since_version = field_type.since_version.unwrap_or(0);
                 let field_type = self
                     .types
                     .find_type(&field_type.type_name)
                     .unwrap_or(field_type.try_into()?);
-                self.resolved_type_name(&field_type)
+
+                // Enums and composites added in later schema versions might be missing
+                if since_version > 0 && matches!(field_type, Type::Enum(_) | Type::Composite(_)) {
+                    Ok(format!("{} | None", self.resolved_type_name(&field_type)?))
+                } else {
+                    self.resolved_type_name(&field_type)
+                }
             }
             MessageField::Group(group_type) => Ok(format!(
-                "list[{}]",
+                "list[{}.{}]",
+                scope,
                 group_type.name.to_case(Case::UpperCamel)
             )),
             MessageField::VariableData(var_data_type) => {
@@ -1,13 +1,14 @@
 use crate::generator::common::{variable_value_type, FieldMetadata};
-use crate::generator::rust::codecs::composite_type::default_value;
+use crate::generator::rust::codecs::composite_type::{bounds_checks, default_value, null_value};
 use crate::generator::rust::constants::DECODER_FILE_NAME;
 use crate::generator::write_file;
+use crate::models::layout::BlockLayout;
 use crate::models::types::composite_type::CompositeType;
 use crate::models::types::encoded_data_type::EncodedDataType;
 use crate::models::types::enum_type::EnumType;
 use crate::models::types::primitive_type::{LanguagePrimitive, NativeType, PrimitiveConvertible};
 use crate::models::types::set_type::SetType;
-use crate::models::types::{Presence, SizedEncoded, Type};
+use crate::models::types::{Presence, Type};
 use crate::models::TypeMap;
 use anyhow::{anyhow, Result};
 use convert_case::{Case, Casing};
@@ -18,6 +19,7 @@ pub struct RustCompositeDecoderGenerator<'a> {
     pub(crate) config: &'a rust::Config,
     pub(crate) path: &'a Path,
     pub(crate) types: &'a TypeMap,
+    pub(crate) root: &'a str,
     pub(crate) package: &'a str,
 }
 
@@ -41,14 +43,13 @@ impl RustCompositeDecoderGenerator<'_> {
     ) -> Result<impl FormatInto<Rust>> {
         let name = composite_type.name.as_str();
         let decoder_name = format!("{}Decoder", name.to_case(Case::UpperCamel));
-        let fields = composite_type.fields.as_slice();
 
         Ok(quote! {
-            use crate::error::*;
-            use crate::$(self.package)::composites::*;
-            use crate::$(self.package)::decoder::*;
-            use crate::$(self.package)::enums::*;
-            use crate::$(self.package)::sets::*;
+            use $(self.root)::error::*;
+            use $(self.root)::$(self.package)::composites::*;
+            use $(self.root)::$(self.package)::decoder::*;
+            use $(self.root)::$(self.package)::enums::*;
+            use $(self.root)::$(self.package)::sets::*;
             use std::convert::TryFrom;
 
             #[derive(Debug, Default)]
@@ -57,7 +58,9 @@ impl RustCompositeDecoderGenerator<'_> {
             }
 
             impl<'a> $(&decoder_name)<'a> {
-                $(self.generate_decoder_fields(fields)?)
+                $(self.generate_decoder_fields(composite_type)?)
+
+                $(self.generate_null_decoder(composite_type)?)
             }
 
             impl<'a> From<ReadBuf<'a>> for $(&decoder_name)<'a> {
@@ -68,47 +71,145 @@ impl RustCompositeDecoderGenerator<'_> {
         })
     }
 
-    fn generate_decoder_fields(&self, fields: &[Type]) -> Result<impl FormatInto<Rust>> {
-        let mut offset: usize = 0;
+    fn generate_decoder_fields(
+        &self,
+        composite_type: &CompositeType,
+    ) -> Result<impl FormatInto<Rust>> {
+        let layout = BlockLayout::from_composite(composite_type, self.types)?;
 
         let mut decoder_fields: Tokens<Rust> = quote!();
 
-        for field in fields {
-            decoder_fields.append(self.generate_decoder_field(field.name(), field, offset)?);
+        for (field, offset) in composite_type.fields.iter().zip(layout.offsets) {
+            decoder_fields.append(self.generate_decoder_field(field.name(), field, offset, 0)?);
             decoder_fields.line();
-
-            offset += field.size(self.types)?;
         }
 
         Ok(decoder_fields)
     }
 
+    /// Generates the check whether all the fields hold their null values, which stand for
+    /// a missing optional composite.
+    fn generate_null_decoder(
+        &self,
+        composite_type: &CompositeType,
+    ) -> Result<impl FormatInto<Rust>> {
+        let layout = BlockLayout::from_composite(composite_type, self.types)?;
+
+        let mut null_checks: Tokens<Rust> = quote!();
+
+        for (field, offset) in composite_type.fields.iter().zip(layout.offsets) {
+            null_checks.append(self.generate_null_check(field.name(), field, offset)?);
+            null_checks.line();
+        }
+
+        Ok(quote! {
+            #[inline]
+            pub fn is_null(&self) -> Result<bool> {
+                $null_checks
+
+                Ok(true)
+            }
+        })
+    }
+
+    fn generate_null_check(
+        &self,
+        field_name: &str,
+        field: &Type,
+        offset: usize,
+    ) -> Result<Tokens<Rust>> {
+        let field_name = field_name.to_case(Case::Snake);
+
+        let is_not_null: Tokens<Rust> = match field {
+            Type::EncodedData(encoded_type) => match encoded_type.presence {
+                Presence::Constant => return Ok(quote!()),
+                Presence::Optional => quote!(self.$(&field_name)()?.is_some()),
+                // Required fields are read directly, since their decoders check the bounds
+                // and the encoding of the values
+                Presence::Required => {
+                    let metadata = FieldMetadata::from(&field_name, encoded_type, self.types)?;
+
+                    if metadata.field_length > 1 {
+                        return Ok(quote! {
+                            for idx in 0..$(metadata.field_length) {
+                                let part = self.buffer.get_$(&metadata.lang_type)_at($offset + idx * $(metadata.type_size))?;
+
+                                if $(non_null_value_condition("part", encoded_type, &metadata)?) {
+                                    return Ok(false);
+                                }
+                            }
+                        });
+                    }
+
+                    return Ok(quote! {
+                        let value = self.buffer.get_$(&metadata.lang_type)_at($offset)?;
+
+                        if $(non_null_value_condition("value", encoded_type, &metadata)?) {
+                            return Ok(false);
+                        }
+                    });
+                }
+            },
+            Type::Enum(enum_type) => {
+                quote!(self.$(&field_name)()? != $(enum_type.name.to_case(Case::UpperCamel))::NullVal)
+            }
+            Type::Set(_) => quote!(self.$(&field_name)()?.0 != 0),
+            Type::Composite(_) => {
+                quote!(!self.$(&field_name)_decoder(|decoder| decoder.is_null())?)
+            }
+            Type::Reference(reference_type) => {
+                let referenced_type =
+                    self.types
+                        .find_type(&reference_type.type_name)
+                        .ok_or(anyhow!(
+                            "Referenced type {} not found",
+                            reference_type.type_name
+                        ))?;
+
+                return self.generate_null_check(&field_name, &referenced_type, offset);
+            }
+        };
+
+        Ok(quote! {
+            if $is_not_null {
+                return Ok(false);
+            }
+        })
+    }
+
+    /// Generates the accessor for a field at the given offset.
+    ///
+    /// A non-zero `since_version` means the field was added in that version of the schema.
+    /// The accessor then checks the `acting_version` of the decoder (only message and group
+    /// decoders have one) and returns the null value of the field, if the field is not present
+    /// in the decoded data.
     pub(crate) fn generate_decoder_field(
         &self,
         field_name: &str,
         field: &Type,
         offset: usize,
+        since_version: usize,
     ) -> Result<impl FormatInto<Rust>> {
         Ok(quote! {
             $(match field {
                 Type::EncodedData(encoded_type) => $(
-                    self.generate_encoded_field_decoder(field_name, encoded_type, offset)?
+                    self.generate_encoded_field_decoder(field_name, encoded_type, offset, since_version)?
                 ),
                 Type::Enum(enum_type) => $(
-                    self.generate_enum_field_decoder(field_name, enum_type, offset)?
+                    self.generate_enum_field_decoder(field_name, enum_type, offset, since_version)?
                 ),
                 Type::Set(set_type) => $(
-                    self.generate_set_field_decoder(field_name, set_type, offset)?
+                    self.generate_set_field_decoder(field_name, set_type, offset, since_version)?
                 ),
                 Type::Composite(composite_type) => $(
-                    self.generate_composite_field_decoder(field_name, composite_type, offset)?
+                    self.generate_composite_field_decoder(field_name, composite_type, offset, since_version)?
                 ),
                 Type::Reference(reference_type) => {$({
                     let referenced_type = self
                         .types
                         .find_type(&reference_type.type_name)
                         .ok_or(anyhow!("Referenced type {} not found", reference_type.type_name))?;
-                    self.generate_decoder_field(field_name, &referenced_type, offset)?
+                    self.generate_decoder_field(field_name, &referenced_type, offset, since_version)?
                 })}
             })
         })
@@ -119,6 +220,7 @@ impl RustCompositeDecoderGenerator<'_> {
         field_name: &str,
         enum_type: &EnumType,
         offset: usize,
+        since_version: usize,
     ) -> Result<impl FormatInto<Rust>> {
         let enum_type_name = enum_type.name.to_case(Case::UpperCamel);
         let field_name = field_name.to_case(Case::Snake);
@@ -129,6 +231,7 @@ impl RustCompositeDecoderGenerator<'_> {
         Ok(quote! {
             #[inline]
             pub fn $(field_name)(&self) -> Result<$(&enum_type_name)> {
+                $(version_check(since_version, quote!($(&enum_type_name)::NullVal)))
                 $(&enum_type_name)::try_from(self.buffer.get_$(field_type)_at($offset)?)
             }
         })
@@ -139,6 +242,7 @@ impl RustCompositeDecoderGenerator<'_> {
         field_name: &str,
         set_type: &SetType,
         offset: usize,
+        since_version: usize,
     ) -> Result<impl FormatInto<Rust>> {
         let set_type_name = set_type.name.to_case(Case::UpperCamel);
         let field_name = field_name.to_case(Case::Snake);
@@ -149,6 +253,7 @@ impl RustCompositeDecoderGenerator<'_> {
         Ok(quote! {
             #[inline]
             pub fn $(field_name)(&self) -> Result<$(&set_type_name)> {
+                $(version_check(since_version, quote!($(&set_type_name)::default())))
                 Ok($(&set_type_name)(self.buffer.get_$(field_type)_at($offset)?))
             }
         })
@@ -159,20 +264,35 @@ impl RustCompositeDecoderGenerator<'_> {
         field_name: &str,
         composite_type: &CompositeType,
         offset: usize,
+        since_version: usize,
     ) -> Result<impl FormatInto<Rust>> {
         let composite_type_name = composite_type.name.to_case(Case::UpperCamel);
         let field_name = field_name.to_case(Case::Snake);
         let decoder_name = format!("{}Decoder", composite_type_name);
 
+        // Composites have no null value, so the result is optional for the ones
+        // which might not be present in the decoded data.
         Ok(quote! {
-            #[inline]
-            pub fn $(field_name)_decoder<T>(&self, action: impl FnOnce(&mut $(&decoder_name)) -> Result<T>) -> Result<T> {
-                let buffer = self.buffer.split_at($offset)?.1;
+            $(if since_version > 0 {
+                #[inline]
+                pub fn $(field_name)_decoder<T>(&self, action: impl FnOnce(&mut $(&decoder_name)) -> Result<T>) -> Result<Option<T>> {
+                    $(version_check(since_version, quote!(None)))
+                    let buffer = self.buffer.split_at($offset)?.1;
 
-                let mut decoder = buffer.into();
+                    let mut decoder = buffer.into();
 
-                action(&mut decoder)
-            }
+                    action(&mut decoder).map(Some)
+                }
+            } else {
+                #[inline]
+                pub fn $(field_name)_decoder<T>(&self, action: impl FnOnce(&mut $(&decoder_name)) -> Result<T>) -> Result<T> {
+                    let buffer = self.buffer.split_at($offset)?.1;
+
+                    let mut decoder = buffer.into();
+
+                    action(&mut decoder)
+                }
+            })
         })
     }
 
@@ -181,12 +301,13 @@ impl RustCompositeDecoderGenerator<'_> {
         field_name: &str,
         encoded_type: &EncodedDataType,
         offset: usize,
+        since_version: usize,
     ) -> Result<impl FormatInto<Rust>> {
         Ok(quote! {
             $(match encoded_type.presence {
                 Presence::Constant => $(self.generate_encoded_constant_field(field_name, encoded_type)?),
-                Presence::Required => $(self.generate_encoded_variable_field(field_name, encoded_type, offset)?),
-                Presence::Optional => $(self.generate_encoded_optional_field(field_name, encoded_type, offset)?),
+                Presence::Required => $(self.generate_encoded_variable_field(field_name, encoded_type, offset, since_version)?),
+                Presence::Optional => $(self.generate_encoded_optional_field(field_name, encoded_type, offset, since_version)?),
             })
         })
     }
@@ -224,6 +345,7 @@ impl RustCompositeDecoderGenerator<'_> {
         field_name: &str,
         encoded_type: &EncodedDataType,
         offset: usize,
+        since_version: usize,
     ) -> Result<impl FormatInto<Rust>> {
         let metadata = FieldMetadata::from(field_name, encoded_type, self.types)?;
 
@@ -233,6 +355,14 @@ impl RustCompositeDecoderGenerator<'_> {
             metadata.field_length,
         );
 
+        let null_value = null_value(encoded_type, &metadata)?;
+        let null_tokens: Tokens<Rust> =
+            match (&metadata.field_primitive_type, metadata.field_length) {
+                (NativeType::Char, 2..) => quote!(String::new()),
+                (_, 2..) => quote!([$(&null_value); $(metadata.field_length)]),
+                (_, _) => quote!($(&null_value)),
+            };
+
         let string_field = quote! {
             $(field_to_string(metadata.field_length))
 
@@ -252,7 +382,7 @@ impl RustCompositeDecoderGenerator<'_> {
         let simple_field: Tokens<Rust> = quote! {
             let value = self.buffer.get_$(&metadata.lang_type)_at(offset)?;
 
-            $(bounds_check(&metadata.field_name, encoded_type.min_value.as_ref(), encoded_type.max_value.as_ref()))
+            $(bounds_checks(&metadata.field_name, encoded_type, metadata.lang_type.name))
 
             Ok(value)
         };
@@ -260,6 +390,7 @@ impl RustCompositeDecoderGenerator<'_> {
         Ok(quote! {
             #[inline]
             pub fn $(metadata.field_name)(&self) -> Result<$value_type> {
+                $(version_check(since_version, null_tokens))
                 let offset = $offset;
 
                 $(match (metadata.field_primitive_type, metadata.field_length) {
@@ -276,6 +407,7 @@ impl RustCompositeDecoderGenerator<'_> {
         field_name: &str,
         encoded_type: &EncodedDataType,
         offset: usize,
+        since_version: usize,
     ) -> Result<impl FormatInto<Rust>> {
         let metadata = FieldMetadata::from(field_name, encoded_type, self.types)?;
 
@@ -286,13 +418,15 @@ impl RustCompositeDecoderGenerator<'_> {
         );
 
         let string_field: Tokens<Rust> = quote! {
+            let src = self.buffer.get_slice_at(offset, $(metadata.field_length))?;
+
+            if src.iter().all(|part| *part == $(null_value(encoded_type, &metadata)?)) {
+                return Ok(None);
+            }
+
             $(field_to_string(metadata.field_length))
 
-            Ok(if !($(null_value_condition("value", &metadata.field_primitive_type, encoded_type)?)) {
-                None
-            } else {
-                Some(value.to_string())
-            })
+            Ok(Some(value))
         };
 
         let array_field: Tokens<Rust> = quote! {
@@ -302,7 +436,7 @@ impl RustCompositeDecoderGenerator<'_> {
             for (idx, value) in value.iter_mut().enumerate() {
                 let part = self.buffer.get_$(&metadata.lang_type)_at(offset + idx * $(metadata.type_size))?;
                 *value = part;
-                is_null &= $(null_value_condition("part", &metadata.field_primitive_type, encoded_type)?);
+                is_null &= $(null_value_condition("part", encoded_type, &metadata)?);
             }
 
             Ok(if !is_null {
@@ -315,11 +449,11 @@ impl RustCompositeDecoderGenerator<'_> {
         let simple_field: Tokens<Rust> = quote! {
             let value = self.buffer.get_$(&metadata.lang_type)_at(offset)?;
 
-            if $(null_value_condition("value", &metadata.field_primitive_type, encoded_type)?) {
+            if $(null_value_condition("value", encoded_type, &metadata)?) {
                 return Ok(None);
             }
 
-            $(bounds_check(&metadata.field_name, encoded_type.min_value.as_ref(), encoded_type.max_value.as_ref()))
+            $(bounds_checks(&metadata.field_name, encoded_type, metadata.lang_type.name))
 
             Ok(Some(value))
         };
@@ -327,6 +461,7 @@ impl RustCompositeDecoderGenerator<'_> {
         Ok(quote! {
             #[inline]
             pub fn $(metadata.field_name)(&self) -> Result<Option<$value_type>> {
+                $(version_check(since_version, quote!(None)))
                 let offset = $offset;
 
                 $(match (metadata.field_primitive_type, metadata.field_length) {
@@ -351,47 +486,25 @@ fn field_to_string(field_length: usize) -> impl FormatInto<Rust> {
     }
 }
 
-fn bounds_check(
-    field_name: &str,
-    min: Option<&String>,
-    max: Option<&String>,
-) -> impl FormatInto<Rust> {
+/// Returns the null value early from an accessor of a field, which was added in a newer
+/// version of the schema than the one used to encode the decoded data.
+fn version_check(since_version: usize, null_value: impl FormatInto<Rust>) -> impl FormatInto<Rust> {
     quote! {
-        $(if let Some(min) = min {
-            if value < $min {
-                return Err(SbeError::ValueOutOfBounds {
-                    field_name: $(quoted(field_name)),
-                    message: format!(
-                        "{} < {} (min)",
-                        value, $min
-                    )
-                });
-            }
-        })
-
-        $(if let Some(max) = max {
-            if value > $max {
-                return Err(SbeError::ValueOutOfBounds {
-                    field_name: $(quoted(field_name)),
-                    message: format!(
-                        "{} > {} (max)",
-                        value, $max
-                    )
-                });
+        $(if since_version > 0 {
+            if self.acting_version < $since_version {
+                return Ok($null_value);
             }
+            $['\n']
         })
     }
 }
 
 fn null_value_condition(
     variable: &str,
-    field_type: &NativeType,
     encoded_type: &EncodedDataType,
+    metadata: &FieldMetadata<Rust>,
 ) -> Result<impl FormatInto<Rust>> {
-    let null_value = encoded_type
-        .null_value
-        .clone()
-        .unwrap_or(field_type.null()?);
+    let null_value = null_value(encoded_type, metadata)?;
 
     if null_value.contains("NAN") {
         Ok(quote! {
@@ -403,3 +516,21 @@ fn null_value_condition(
         })
     }
 }
+
+fn non_null_value_condition(
+    variable: &str,
+    encoded_type: &EncodedDataType,
+    metadata: &FieldMetadata<Rust>,
+) -> Result<impl FormatInto<Rust>> {
+    let null_value = null_value(encoded_type, metadata)?;
+
+    if null_value.contains("NAN") {
+        Ok(quote! {
+            !$(variable).is_nan()
+        })
+    } else {
+        Ok(quote! {
+            $(variable) != $(&null_value)
+        })
+    }
+}
@@ -5,21 +5,25 @@ use convert_case::{Case, Casing};
 use genco::prelude::*;
 
 use crate::generator::common::FieldMetadata;
+use crate::generator::rust::codecs::composite_type::{bounds_checks, null_value};
 use crate::generator::rust::constants::ENCODER_FILE_NAME;
 use crate::generator::write_file;
+use crate::models::layout::BlockLayout;
 use crate::models::types::composite_type::CompositeType;
 use crate::models::types::encoded_data_type::EncodedDataType;
 use crate::models::types::enum_type::EnumType;
 use crate::models::types::primitive_type::{NativeType, PrimitiveConvertible};
 use crate::models::types::set_type::SetType;
-use crate::models::types::{CharacterEncoding, Presence, SizedEncoded, Type};
+use crate::models::types::{CharacterEncoding, Presence, Type};
 use crate::models::TypeMap;
 
 pub struct RustCompositeEncoderGenerator<'a> {
     pub(crate) config: &'a rust::Config,
     pub(crate) path: &'a Path,
     pub(crate) types: &'a TypeMap,
+    pub(crate) root: &'a str,
     pub(crate) package: &'a str,
+    pub(crate) bounds_checks: bool,
 }
 
 impl RustCompositeEncoderGenerator<'_> {
@@ -42,14 +46,13 @@ impl RustCompositeEncoderGenerator<'_> {
     ) -> Result<impl FormatInto<Rust>> {
         let name = format!("{}Encoder", composite_type.name.to_case(Case::UpperCamel));
         let encoder_name = name.as_str();
-        let fields = composite_type.fields.as_slice();
 
         Ok(quote! {
-            use crate::error::*;
-            use crate::$(self.package)::composites::*;
-            use crate::$(self.package)::encoder::*;
-            use crate::$(self.package)::enums::*;
-            use crate::$(self.package)::sets::*;
+            use $(self.root)::error::*;
+            use $(self.root)::$(self.package)::composites::*;
+            use $(self.root)::$(self.package)::encoder::*;
+            use $(self.root)::$(self.package)::enums::*;
+            use $(self.root)::$(self.package)::sets::*;
             use std::convert::TryFrom;
             use std::str::FromStr;
 
@@ -65,30 +68,109 @@ impl RustCompositeEncoderGenerator<'_> {
             }
 
             impl<'a> $encoder_name<'a> {
-                $(self.generate_encoder_fields(fields)?)
+                $(self.generate_encoder_fields(composite_type)?)
+
+                $(self.generate_null_encoder(composite_type)?)
             }
         })
     }
 
-    fn generate_encoder_fields(&self, fields: &[Type]) -> Result<impl FormatInto<Rust>> {
-        let mut offset: usize = 0;
+    fn generate_encoder_fields(
+        &self,
+        composite_type: &CompositeType,
+    ) -> Result<impl FormatInto<Rust>> {
+        let layout = BlockLayout::from_composite(composite_type, self.types)?;
 
         let mut encoder_fields: Tokens<Rust> = quote!();
 
-        for field in fields {
+        for (field, offset) in composite_type.fields.iter().zip(layout.offsets) {
             encoder_fields.append(self.generate_encoder_field(
                 field.name(),
                 field,
                 quote!($offset),
             )?);
             encoder_fields.line();
-
-            offset += field.size(self.types)?;
         }
 
         Ok(encoder_fields)
     }
 
+    /// Generates the encoder of the null values of all the fields, which stand for a missing
+    /// optional composite.
+    fn generate_null_encoder(
+        &self,
+        composite_type: &CompositeType,
+    ) -> Result<impl FormatInto<Rust>> {
+        let layout = BlockLayout::from_composite(composite_type, self.types)?;
+
+        let mut null_fields: Tokens<Rust> = quote!();
+
+        for (field, offset) in composite_type.fields.iter().zip(layout.offsets) {
+            null_fields.append(self.generate_null_field(field.name(), field, offset)?);
+            null_fields.push();
+        }
+
+        Ok(quote! {
+            #[inline]
+            pub fn null(&mut self) -> Result<()> {
+                $null_fields
+
+                Ok(())
+            }
+        })
+    }
+
+    fn generate_null_field(
+        &self,
+        field_name: &str,
+        field: &Type,
+        offset: usize,
+    ) -> Result<Tokens<Rust>> {
+        let field_name = field_name.to_case(Case::Snake);
+
+        Ok(match field {
+            Type::EncodedData(encoded_type) => match encoded_type.presence {
+                Presence::Constant => quote!(),
+                Presence::Optional => quote!(self.$(&field_name)(None)?;),
+                // Required fields are written directly, since their null values
+                // might be out of the bounds checked by their encoders
+                Presence::Required => {
+                    let metadata = FieldMetadata::from(&field_name, encoded_type, self.types)?;
+                    let null_value = null_value(encoded_type, &metadata)?;
+
+                    match metadata.field_length {
+                        2.. => quote! {
+                            for idx in 0..$(metadata.field_length) {
+                                self.buffer.put_$(&metadata.lang_type)_at($offset + idx * $(metadata.type_size), $(&null_value))?;
+                            }
+                        },
+                        _ => {
+                            quote!(self.buffer.put_$(&metadata.lang_type)_at($offset, $(&null_value))?;)
+                        }
+                    }
+                }
+            },
+            Type::Enum(enum_type) => {
+                quote!(self.$(&field_name)($(enum_type.name.to_case(Case::UpperCamel))::NullVal)?;)
+            }
+            Type::Set(set_type) => {
+                quote!(self.$(&field_name)($(set_type.name.to_case(Case::UpperCamel))::default())?;)
+            }
+            Type::Composite(_) => quote!(self.$(&field_name)_encoder(|encoder| encoder.null())?;),
+            Type::Reference(reference_type) => {
+                let referenced_type =
+                    self.types
+                        .find_type(&reference_type.type_name)
+                        .ok_or(anyhow!(
+                            "Referenced type {} not found",
+                            reference_type.type_name
+                        ))?;
+
+                self.generate_null_field(&field_name, &referenced_type, offset)?
+            }
+        })
+    }
+
     pub(crate) fn generate_encoder_field(
         &self,
         field_name: &str,
@@ -150,24 +232,19 @@ impl RustCompositeEncoderGenerator<'_> {
         );
 
         let string_field = string_encoder(field_name, metadata.encoding, metadata.field_length);
+        let bounds_checks = self.bounds_checks(field_name, encoded_type, &metadata);
 
         let array_field: Tokens<Rust> = quote! {
-            for (idx, part) in value.iter().enumerate() {
-                self.buffer.put_$(&metadata.lang_type)_at(offset + idx * $(metadata.type_size), *part)?;
+            for (idx, value) in value.iter().copied().enumerate() {
+                $(&bounds_checks)
+                self.buffer.put_$(&metadata.lang_type)_at(offset + idx * $(metadata.type_size), value)?;
             }
 
             Ok(())
         };
 
         let simple_field: Tokens<Rust> = quote! {
-            $(if let Some(min) = &encoded_type.min_value {
-                $(min_check(field_name, min))
-            })
-
-            $(if let Some(max) = &encoded_type.max_value {
-                $(max_check(field_name, max))
-            })
-
+            $(&bounds_checks)
             self.buffer.put_$(metadata.lang_type)_at(offset, value)
         };
 
@@ -193,19 +270,31 @@ impl RustCompositeEncoderGenerator<'_> {
     ) -> Result<impl FormatInto<Rust>> {
         let metadata = FieldMetadata::from(field_name, encoded_type, self.types)?;
 
-        let null_value = encoded_type
-            .null_value
-            .clone()
-            .unwrap_or(metadata.field_primitive_type.null()?);
+        let null_value = null_value(encoded_type, &metadata)?;
         let value_type = value_type(
             &metadata.field_primitive_type,
             metadata.lang_type.name,
             metadata.field_length,
         );
 
-        let string_field = string_encoder(field_name, metadata.encoding, metadata.field_length);
+        let string_field: Tokens<Rust> = quote! {
+            let Some(value) = value else {
+                return self.buffer.put_bytes_at(offset, &[$(&null_value); $(metadata.field_length)]);
+            };
+
+            $(string_encoder(field_name, metadata.encoding, metadata.field_length))
+        };
+        let bounds_checks = self.bounds_checks(field_name, encoded_type, &metadata);
 
         let array_field: Tokens<Rust> = quote! {
+            $(if !bounds_checks.is_empty() {
+                if let Some(value) = value {
+                    for value in value.iter().copied() {
+                        $(&bounds_checks)
+                    }
+                }
+                $['\n']
+            })
             let value = value.unwrap_or(&[$(&null_value); $(metadata.field_length)]);
 
             for (idx, part) in value.iter().enumerate() {
@@ -216,18 +305,12 @@ impl RustCompositeEncoderGenerator<'_> {
         };
 
         let simple_field: Tokens<Rust> = quote! {
-            $(if let Some(min) = &encoded_type.min_value {
+            $(if !bounds_checks.is_empty() {
                 if let Some(value) = value {
-                    $(min_check(field_name, min))
+                    $(&bounds_checks)
                 }
+                $['\n']
             })
-
-            $(if let Some(max) = &encoded_type.max_value {
-                if let Some(value) = value {
-                    $(max_check(field_name, max))
-                }
-            })
-
             let value = value.unwrap_or($(&null_value));
             self.buffer.put_$(metadata.lang_type)_at(offset, value)
         };
@@ -246,6 +329,20 @@ impl RustCompositeEncoderGenerator<'_> {
         })
     }
 
+    /// Checks of the `minValue` and `maxValue` of the field, unless they are disabled.
+    fn bounds_checks(
+        &self,
+        field_name: &str,
+        encoded_type: &EncodedDataType,
+        metadata: &FieldMetadata<Rust>,
+    ) -> Tokens<Rust> {
+        if self.bounds_checks {
+            bounds_checks(field_name, encoded_type, metadata.lang_type.name)
+        } else {
+            quote!()
+        }
+    }
+
     fn generate_enum_field_encoder(
         &self,
         field_name: &str,
@@ -299,7 +396,7 @@ impl RustCompositeEncoderGenerator<'_> {
         Ok(quote! {
             #[inline]
             pub fn $(field_name)_encoder<T>(&mut self, action: impl FnOnce(&mut $(&encoder_name)) -> Result<T>) -> Result<T> {
-                let buffer = self.buffer.split_at_mut($offset)?.1;
+                let buffer = self.buffer.offset_mut($offset);
 
                 let mut encoder = buffer.into();
 
@@ -344,31 +441,3 @@ fn string_encoder(
         self.buffer.put_bytes_at(offset, encoded)
     }
 }
-
-fn min_check(field_name: &str, min: &str) -> impl FormatInto<Rust> {
-    quote! {
-        if value < $min {
-            return Err(SbeError::ValueOutOfBounds {
-                field_name: $(quoted(field_name)),
-                message: format!(
-                    "{} < {} (min)",
-                    value, $min
-                )
-            });
-        }
-    }
-}
-
-fn max_check(field_name: &str, max: &str) -> impl FormatInto<Rust> {
-    quote! {
-        if value > $max {
-            return Err(SbeError::ValueOutOfBounds {
-                field_name: $(quoted(field_name)),
-                message: format!(
-                    "{} > {} (max)",
-                    value, $max
-                )
-            });
-        }
-    }
-}
@@ -1,6 +1,8 @@
 pub mod decoder;
 pub mod encoder;
+pub mod owned;
 
+use crate::generator::common::FieldMetadata;
 use crate::generator::rust::constants::COMPOSITE_MODULE_NAME;
 use anyhow::Result;
 use convert_case::{Case, Casing};
@@ -9,8 +11,10 @@ use std::path::Path;
 
 use crate::generator::rust::codecs::composite_type::decoder::RustCompositeDecoderGenerator;
 use crate::generator::rust::codecs::composite_type::encoder::RustCompositeEncoderGenerator;
+use crate::generator::rust::codecs::composite_type::owned::RustCompositeOwnedGenerator;
 use crate::generator::write_file;
 use crate::models::types::composite_type::CompositeType;
+use crate::models::types::encoded_data_type::EncodedDataType;
 use crate::models::types::primitive_type::NativeType;
 use crate::models::types::SizedEncoded;
 
@@ -26,7 +30,9 @@ impl ModuleGenerator<'_> {
             config: &self.config,
             path: module_path,
             types: &self.schema.types,
+            root: &self.root,
             package: &self.schema.package,
+            bounds_checks: self.bounds_checks,
         }
     }
 
@@ -38,10 +44,25 @@ impl ModuleGenerator<'_> {
             config: &self.config,
             path: module_path,
             types: &self.schema.types,
+            root: &self.root,
             package: &self.schema.package,
         }
     }
 
+    fn composite_owned_generator<'a>(
+        &'a self,
+        module_path: &'a Path,
+    ) -> RustCompositeOwnedGenerator<'a> {
+        RustCompositeOwnedGenerator {
+            config: &self.config,
+            path: module_path,
+            types: &self.schema.types,
+            root: &self.root,
+            package: &self.schema.package,
+            serde: self.serde,
+        }
+    }
+
     fn write_composite_codec(
         &self,
         module_path: &Path,
@@ -53,19 +74,23 @@ impl ModuleGenerator<'_> {
 
         let encoder_generator = self.composite_encoder_generator(&module_path);
         let decoder_generator = self.composite_decoder_generator(&module_path);
+        let owned_generator = self.composite_owned_generator(&module_path);
 
         let composite_tokens: Tokens<Rust> = quote! {
             mod decoder;
             mod encoder;
+            mod owned;
 
             pub use self::decoder::$(name.to_case(Case::UpperCamel))Decoder;
             pub use self::encoder::$(name.to_case(Case::UpperCamel))Encoder;
+            pub use self::owned::$(name.to_case(Case::UpperCamel));
 
             pub const $(name.to_case(Case::ScreamingSnake))_ENCODED_LENGTH: usize = $(composite_type.size(&self.schema.types)?);
         };
 
         encoder_generator.write_encoder(composite_type)?;
         decoder_generator.write_decoder(composite_type)?;
+        owned_generator.write_owned(composite_type)?;
 
         write_file(&module_path.join("mod.rs"), &self.config, composite_tokens)?;
 
@@ -94,6 +119,63 @@ impl ModuleGenerator<'_> {
     }
 }
 
+/// Null value of an optional field, either declared by the `nullValue` of the encoded type,
+/// or the default one for its primitive type.
+fn null_value(encoded_type: &EncodedDataType, metadata: &FieldMetadata<Rust>) -> Result<String> {
+    Ok(match &encoded_type.null_value {
+        Some(null_value) => format!("{}_{}", null_value, metadata.lang_type.name),
+        None => metadata.field_primitive_type.null()?,
+    })
+}
+
+/// Checks of a `value` against the `minValue` and `maxValue` of the encoded type.
+fn bounds_checks(
+    field_name: &str,
+    encoded_type: &EncodedDataType,
+    rust_type: &str,
+) -> Tokens<Rust> {
+    let mut checks = quote!();
+
+    if let Some(min) = &encoded_type.min_value {
+        // Unsigned values can never be below zero, which the compiler would warn about
+        if !(rust_type.starts_with('u') && min.parse::<u64>() == Ok(0)) {
+            checks.append(bounds_check(
+                field_name,
+                "<",
+                &format!("{}_{}", min, rust_type),
+                "min",
+            ));
+            checks.line();
+        }
+    }
+
+    if let Some(max) = &encoded_type.max_value {
+        checks.append(bounds_check(
+            field_name,
+            ">",
+            &format!("{}_{}", max, rust_type),
+            "max",
+        ));
+        checks.line();
+    }
+
+    checks
+}
+
+fn bounds_check(field_name: &str, operator: &str, limit: &str, limit_name: &str) -> Tokens<Rust> {
+    quote! {
+        if value $operator $limit {
+            return Err(SbeError::ValueOutOfBounds {
+                field_name: $(quoted(field_name)),
+                message: format!(
+                    $(quoted(format!("{{}} {} {{}} ({})", operator, limit_name))),
+                    value, $limit
+                )
+            });
+        }
+    }
+}
+
 fn default_value(default_value: &str, field_type: &NativeType) -> String {
     match field_type {
         NativeType::Char => format!("\"{}\"", default_value),
@@ -0,0 +1,256 @@
+use std::path::Path;
+
+use anyhow::{anyhow, Result};
+use convert_case::{Case, Casing};
+use genco::prelude::*;
+
+use crate::generator::common::{variable_value_type, FieldMetadata};
+use crate::generator::rust::constants::OWNED_FILE_NAME;
+use crate::generator::rust::module::serde_derive;
+use crate::generator::write_file;
+use crate::models::types::composite_type::CompositeType;
+use crate::models::types::encoded_data_type::EncodedDataType;
+use crate::models::types::primitive_type::NativeType;
+use crate::models::types::{Presence, Type};
+use crate::models::TypeMap;
+
+pub struct RustCompositeOwnedGenerator<'a> {
+    pub(crate) config: &'a rust::Config,
+    pub(crate) path: &'a Path,
+    pub(crate) types: &'a TypeMap,
+    pub(crate) root: &'a str,
+    pub(crate) package: &'a str,
+    pub(crate) serde: bool,
+}
+
+impl RustCompositeOwnedGenerator<'_> {
+    pub fn write_owned(&self, composite_type: &CompositeType) -> Result<()> {
+        let owned_tokens: Tokens<Rust> = quote!($(self.generate_composite_owned(composite_type)?));
+
+        write_file(&self.path.join(OWNED_FILE_NAME), self.config, owned_tokens)?;
+
+        Ok(())
+    }
+
+    fn generate_composite_owned(
+        &self,
+        composite_type: &CompositeType,
+    ) -> Result<impl FormatInto<Rust>> {
+        let name = composite_type.name.to_case(Case::UpperCamel);
+        let decoder_name = format!("{}Decoder", name);
+        let encoder_name = format!("{}Encoder", name);
+
+        let mut struct_fields: Tokens<Rust> = quote!();
+        let mut decode_fields: Tokens<Rust> = quote!();
+        let mut encode_fields: Tokens<Rust> = quote!();
+
+        for field in &composite_type.fields {
+            struct_fields.append(self.generate_struct_field(field.name(), field, 0)?);
+            decode_fields.append(self.generate_field_decode(field.name(), field, 0)?);
+            encode_fields.append(self.generate_field_encode(field.name(), field, 0)?);
+        }
+
+        Ok(quote! {
+            use $(self.root)::error::*;
+            use $(self.root)::$(self.package)::composites::*;
+            use $(self.root)::$(self.package)::enums::*;
+            use $(self.root)::$(self.package)::sets::*;
+
+            #[derive(Debug, Clone, PartialEq)]
+            $(serde_derive(self.serde))
+            pub struct $(&name) {
+                $struct_fields
+            }
+
+            impl $(&name) {
+                #[inline]
+                pub fn decode_from(decoder: &mut $(&decoder_name)<'_>) -> Result<Self> {
+                    Ok(Self {
+                        $decode_fields
+                    })
+                }
+
+                #[inline]
+                pub fn encode_into(&self, encoder: &mut $(&encoder_name)<'_>) -> Result<()> {
+                    $encode_fields
+
+                    Ok(())
+                }
+            }
+        })
+    }
+
+    /// Generates the declaration of a field of an owned struct, typed as the value returned
+    /// by the decoder of the field. Constant fields are not stored.
+    ///
+    /// A non-zero `since_version` makes composite fields optional, since their decoders
+    /// return `None` for data encoded using an older version of the schema.
+    pub(crate) fn generate_struct_field(
+        &self,
+        field_name: &str,
+        field: &Type,
+        since_version: usize,
+    ) -> Result<Tokens<Rust>> {
+        if matches!(field.presence(self.types)?, Presence::Constant) {
+            return Ok(quote!());
+        }
+
+        Ok(quote! {
+            pub $(field_name.to_case(Case::Snake)): $(self.owned_type_name(field, since_version)?),
+            $['\r']
+        })
+    }
+
+    fn owned_type_name(&self, field: &Type, since_version: usize) -> Result<String> {
+        Ok(match field {
+            Type::EncodedData(encoded_type) => {
+                let metadata = FieldMetadata::from(field.name(), encoded_type, self.types)?;
+                let value_type = variable_value_type(
+                    &metadata.field_primitive_type,
+                    metadata.lang_type.name,
+                    metadata.field_length,
+                );
+
+                match encoded_type.presence {
+                    Presence::Optional => format!("Option<{}>", value_type),
+                    _ => value_type,
+                }
+            }
+            Type::Enum(enum_type) => enum_type.name.to_case(Case::UpperCamel),
+            Type::Set(set_type) => set_type.name.to_case(Case::UpperCamel),
+            Type::Composite(composite_type) if since_version > 0 => {
+                format!("Option<{}>", composite_type.name.to_case(Case::UpperCamel))
+            }
+            Type::Composite(composite_type) => composite_type.name.to_case(Case::UpperCamel),
+            Type::Reference(reference_type) => {
+                let referenced_type =
+                    self.types
+                        .find_type(&reference_type.type_name)
+                        .ok_or(anyhow!(
+                            "Referenced type {} not found",
+                            reference_type.type_name
+                        ))?;
+
+                self.owned_type_name(&referenced_type, since_version)?
+            }
+        })
+    }
+
+    /// Generates the initialization of a field of an owned struct from a `decoder`.
+    /// Optional composite fields are `None` when all their fields hold null values.
+    pub(crate) fn generate_field_decode(
+        &self,
+        field_name: &str,
+        field: &Type,
+        since_version: usize,
+    ) -> Result<Tokens<Rust>> {
+        let field_name = field_name.to_case(Case::Snake);
+        if matches!(field.presence(self.types)?, Presence::Constant) {
+            return Ok(quote!());
+        }
+
+        Ok(match field {
+            Type::EncodedData(_) | Type::Enum(_) | Type::Set(_) => quote! {
+                $(&field_name): decoder.$(&field_name)()?,
+                $['\r']
+            },
+            Type::Composite(composite_type) if since_version > 0 => quote! {
+                $(&field_name): decoder.$(&field_name)_decoder(|decoder| {
+                    if decoder.is_null()? {
+                        return Ok(None);
+                    }
+
+                    $(composite_type.name.to_case(Case::UpperCamel))::decode_from(decoder).map(Some)
+                })?.flatten(),
+                $['\r']
+            },
+            Type::Composite(composite_type) => quote! {
+                $(&field_name): decoder.$(&field_name)_decoder(|decoder| {
+                    $(composite_type.name.to_case(Case::UpperCamel))::decode_from(decoder)
+                })?,
+                $['\r']
+            },
+            Type::Reference(reference_type) => {
+                let referenced_type =
+                    self.types
+                        .find_type(&reference_type.type_name)
+                        .ok_or(anyhow!(
+                            "Referenced type {} not found",
+                            reference_type.type_name
+                        ))?;
+
+                self.generate_field_decode(&field_name, &referenced_type, since_version)?
+            }
+        })
+    }
+
+    /// Generates the statement writing a field of an owned struct into an `encoder`.
+    /// Missing optional composite fields are written as the null values of their fields.
+    pub(crate) fn generate_field_encode(
+        &self,
+        field_name: &str,
+        field: &Type,
+        since_version: usize,
+    ) -> Result<Tokens<Rust>> {
+        let field_name = field_name.to_case(Case::Snake);
+        if matches!(field.presence(self.types)?, Presence::Constant) {
+            return Ok(quote!());
+        }
+
+        Ok(match field {
+            Type::EncodedData(encoded_type) => {
+                self.generate_encoded_field_encode(&field_name, encoded_type)?
+            }
+            Type::Enum(_) | Type::Set(_) => quote! {
+                encoder.$(&field_name)(self.$(&field_name))?;
+                $['\r']
+            },
+            Type::Composite(_) if since_version > 0 => quote! {
+                match &self.$(&field_name) {
+                    Some($(&field_name)) => encoder.$(&field_name)_encoder(|encoder| $(&field_name).encode_into(encoder))?,
+                    None => encoder.$(&field_name)_encoder(|encoder| encoder.null())?,
+                }
+                $['\r']
+            },
+            Type::Composite(_) => quote! {
+                encoder.$(&field_name)_encoder(|encoder| self.$(&field_name).encode_into(encoder))?;
+                $['\r']
+            },
+            Type::Reference(reference_type) => {
+                let referenced_type =
+                    self.types
+                        .find_type(&reference_type.type_name)
+                        .ok_or(anyhow!(
+                            "Referenced type {} not found",
+                            reference_type.type_name
+                        ))?;
+
+                self.generate_field_encode(&field_name, &referenced_type, since_version)?
+            }
+        })
+    }
+
+    fn generate_encoded_field_encode(
+        &self,
+        field_name: &str,
+        encoded_type: &EncodedDataType,
+    ) -> Result<Tokens<Rust>> {
+        let metadata = FieldMetadata::from(field_name, encoded_type, self.types)?;
+
+        let value: Tokens<Rust> = match (
+            encoded_type.presence,
+            metadata.field_primitive_type,
+            metadata.field_length,
+        ) {
+            (Presence::Optional, NativeType::Char, 2..) => quote!(self.$field_name.as_deref()),
+            (Presence::Optional, _, 2..) => quote!(self.$field_name.as_ref()),
+            (_, _, 2..) => quote!(&self.$field_name),
+            (_, _, _) => quote!(self.$field_name),
+        };
+
+        Ok(quote! {
+            encoder.$field_name($value)?;
+            $['\r']
+        })
+    }
+}
@@ -6,7 +6,7 @@ use std::path::Path;
 use crate::models::types::enum_type::EnumType;
 
 use crate::generator::rust::constants::ENUM_MODULE_NAME;
-use crate::generator::rust::module::ModuleGenerator;
+use crate::generator::rust::module::{serde_derive, ModuleGenerator};
 use crate::generator::write_file;
 use crate::models::types::primitive_type::{NativeType, PrimitiveConvertible, ResolvableType};
 use genco::prelude::*;
@@ -25,11 +25,12 @@ impl ModuleGenerator<'_> {
         let null_value = encoding_type.null()?;
 
         let enum_tokens: Tokens<Rust> = quote! {
-            use crate::error::*;
+            use $(&self.root)::error::*;
             use std::any::type_name;
             use std::convert::TryFrom;
 
             #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+            $(serde_derive(self.serde))
             #[repr($language_primitive_type)]
             pub enum $(&name)  {
                 $(for value in values {
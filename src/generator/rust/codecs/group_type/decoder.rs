@@ -1,13 +1,14 @@
-use crate::generator::common::field_groups;
+use crate::generator::common::{dimension_type, field_groups};
 use crate::generator::rust::codecs::composite_type::decoder::RustCompositeDecoderGenerator;
-use crate::generator::rust::codecs::group_type::dimension_type;
+use crate::generator::rust::codecs::nested_codec_imports;
 use crate::generator::rust::constants::DECODER_FILE_NAME;
 use crate::generator::write_file;
+use crate::models::layout::BlockLayout;
 use crate::models::types::field_type::FieldType;
 use crate::models::types::group_type::GroupType;
 use crate::models::types::variable_data_type::VariableDataType;
 use crate::models::types::{Presence, SizedEncoded, Type};
-use crate::models::TypeMap;
+use crate::models::{FieldPath, TypeMap};
 use anyhow::{anyhow, Result};
 use convert_case::{Case, Casing};
 use genco::prelude::*;
@@ -17,12 +18,14 @@ pub struct RustGroupDecoderGenerator<'a> {
     pub(crate) config: &'a rust::Config,
     pub(crate) path: &'a Path,
     pub(crate) types: &'a TypeMap,
+    pub(crate) root: &'a str,
     pub(crate) package: &'a str,
 }
 
 impl RustGroupDecoderGenerator<'_> {
-    pub fn write_decoder(&self, group: &GroupType) -> Result<()> {
-        let decoder_tokens: Tokens<Rust> = quote!($(self.generate_group_decoder(group)?));
+    pub fn write_decoder(&self, group: &GroupType, group_path: &FieldPath) -> Result<()> {
+        let decoder_tokens: Tokens<Rust> =
+            quote!($(self.generate_group_decoder(group, group_path)?));
 
         write_file(
             &self.path.join(DECODER_FILE_NAME),
@@ -33,26 +36,26 @@ impl RustGroupDecoderGenerator<'_> {
         Ok(())
     }
 
-    pub fn generate_group_decoder(&self, group: &GroupType) -> Result<impl FormatInto<Rust>> {
+    pub fn generate_group_decoder(
+        &self,
+        group: &GroupType,
+        group_path: &FieldPath,
+    ) -> Result<impl FormatInto<Rust>> {
         let name = group.name.as_str();
         let decoder_name = format!("{}Decoder", name.to_case(Case::UpperCamel));
         let (fields, groups, var_data) = field_groups(&group.fields);
 
-        let mut offset = 0;
+        let layout = BlockLayout::from_fields(name, &fields, group.block_length, self.types)?;
 
         let group_sizes = groups
             .iter()
-            .map(|prev_group| {
-                let prev_group_name = prev_group.name.to_case(Case::Snake);
-                quote!($[' ']+ self.$(&prev_group_name)_size.take().ok_or(SbeError::MissingGroupSize($(quoted(prev_group_name))))?)
-            });
+            .map(|group| quote!($[' ']+ self.$(group.name.to_case(Case::Snake))_size()?));
 
         let var_data_field_sizes = var_data
             .iter()
-            .map(|var_data_field| {
-                let var_data_field_name = var_data_field.name.to_case(Case::Snake);
-                quote!($[' ']+ self.$(&var_data_field_name)_size.take().ok_or(SbeError::MissingVarDataSize($(quoted(var_data_field_name))))?)
-            });
+            .map(|var_data_field| quote!($[' ']+ self.$(var_data_field.name.to_case(Case::Snake))_size()?));
+
+        let iter_name = format!("{}Iter", name.to_case(Case::UpperCamel));
 
         let dimension_type = dimension_type(group, &self.types.composite_types)?;
         let dimension_type_size = dimension_type.size(self.types)?;
@@ -60,18 +63,19 @@ impl RustGroupDecoderGenerator<'_> {
             format!("{}Decoder", dimension_type.name.to_case(Case::UpperCamel));
 
         Ok(quote! {
-            use crate::error::*;
-            use crate::$(self.package)::composites::*;
-            use crate::$(self.package)::decoder::*;
-            use crate::$(self.package)::enums::*;
-            use crate::$(self.package)::groups::*;
-            use crate::$(self.package)::sets::*;
-            use crate::$(self.package)::var_data::*;
+            use $(self.root)::error::*;
+            use $(self.root)::$(self.package)::composites::*;
+            use $(self.root)::$(self.package)::decoder::*;
+            use $(self.root)::$(self.package)::enums::*;
+            use $(self.root)::$(self.package)::sets::*;
+            $(nested_codec_imports(self.root, self.package, group_path, !groups.is_empty(), !var_data.is_empty()))
+            use $(self.root)::$(self.package)::SCHEMA_VERSION;
             use std::convert::TryFrom;
 
-            #[derive(Debug)]
+            #[derive(Debug, Clone)]
             pub struct $(&decoder_name)<'a> {
                 buffer: ReadBuf<'a>,
+                acting_version: u16,
                 block_length: usize,
                 num_in_group: usize,
                 index: usize,
@@ -86,7 +90,39 @@ impl RustGroupDecoderGenerator<'_> {
                 })
             }
 
-            impl $(&decoder_name)<'_> {
+            impl<'a> $(&decoder_name)<'a> {
+                #[inline]
+                pub fn new(buffer: ReadBuf<'a>, acting_version: u16) -> Result<Self> {
+                    let (metadata_buffer, buffer) = buffer.split_at($dimension_type_size)?;
+                    let metadata = $(&dimension_type_decoder_name)::from(metadata_buffer);
+                    Ok(Self {
+                        buffer,
+                        acting_version,
+                        block_length: metadata.block_length()? as usize,
+                        num_in_group: metadata.num_in_group()? as usize,
+                        index: 0,
+                        size: $dimension_type_size,
+                        $(for group in &groups => $['\r']$(group.name.to_case(Case::Snake))_size: None,)
+                        $(for var in &var_data => $['\r']$(var.name.to_case(Case::Snake))_size: None,)
+                    })
+                }
+
+                /// Creates a decoder of a group, which is not present in the decoded data,
+                /// because it was added in a newer version of the schema.
+                #[inline]
+                pub fn empty(acting_version: u16) -> Self {
+                    Self {
+                        buffer: ReadBuf::default(),
+                        acting_version,
+                        block_length: 0,
+                        num_in_group: 0,
+                        index: 0,
+                        size: 0,
+                        $(for group in &groups => $['\r']$(group.name.to_case(Case::Snake))_size: None,)
+                        $(for var in &var_data => $['\r']$(var.name.to_case(Case::Snake))_size: None,)
+                    }
+                }
+
                 #[inline]
                 pub fn size(&self) -> Option<usize> {
                     if self.index == self.num_in_group {
@@ -106,6 +142,8 @@ impl RustGroupDecoderGenerator<'_> {
                     let groups_size = 0$(for group_size in group_sizes => $group_size);
                     let var_data_size = 0$(for var_data_field_size in var_data_field_sizes => $var_data_field_size);
                     let advancement = fields_size + groups_size + var_data_size;
+                    $(for group in &groups => $['\r']self.$(group.name.to_case(Case::Snake))_size = None;)
+                    $(for var in &var_data => $['\r']self.$(var.name.to_case(Case::Snake))_size = None;)
 
                     self.index += 1;
                     self.size += advancement;
@@ -114,30 +152,71 @@ impl RustGroupDecoderGenerator<'_> {
                     Ok(())
                 }
 
+                /// Skips the remaining entries of the group along with their nested groups and
+                /// var data, after which the size of the group is known.
+                #[inline]
+                pub fn skip(&mut self) -> Result<()> {
+                    while self.index < self.num_in_group {
+                        self.advance()?;
+                    }
+
+                    Ok(())
+                }
+
                 $(self.generate_metadata()?)
 
-                $(self.generate_fields(&fields, &mut offset)?)
+                $(self.generate_fields(&fields, layout.offsets)?)
 
-                $(self.generate_groups(&groups, offset)?)
+                $(self.generate_groups(&groups, &quote!(self.block_length))?)
 
-                $(self.generate_var_data_fields(&var_data, &groups, offset)?)
+                $(self.generate_var_data_fields(&var_data, &groups, &quote!(self.block_length))?)
             }
 
             impl<'a> TryFrom<ReadBuf<'a>> for $(&decoder_name)<'a> {
                 type Error = SbeError;
 
                 fn try_from(buffer: ReadBuf<'a>) -> Result<Self> {
-                    let (metadata_buffer, buffer) = buffer.split_at($dimension_type_size)?;
-                    let metadata = $(&dimension_type_decoder_name)::from(metadata_buffer);
-                    Ok(Self {
-                        buffer,
-                        block_length: metadata.block_length()? as usize,
-                        num_in_group: metadata.num_in_group()? as usize,
-                        index: 0,
-                        size: $dimension_type_size,
-                        $(for group in &groups => $['\r']$(group.name.to_case(Case::Snake))_size: None,)
-                        $(for var in &var_data => $['\r']$(var.name.to_case(Case::Snake))_size: None,)
-                    })
+                    Self::new(buffer, SCHEMA_VERSION)
+                }
+            }
+
+            impl<'a> IntoIterator for $(&decoder_name)<'a> {
+                type Item = Result<$(&decoder_name)<'a>>;
+                type IntoIter = $(&iter_name)<'a>;
+
+                fn into_iter(self) -> Self::IntoIter {
+                    $(&iter_name) { decoder: self }
+                }
+            }
+
+            /// Iterator over the remaining entries of the group, each read by a copy of the
+            /// group decoder. The nested groups and var data of every entry are skipped, so
+            /// they only have to be decoded when needed.
+            #[derive(Debug, Clone)]
+            pub struct $(&iter_name)<'a> {
+                decoder: $(&decoder_name)<'a>,
+            }
+
+            impl<'a> Iterator for $(&iter_name)<'a> {
+                type Item = Result<$(&decoder_name)<'a>>;
+
+                fn next(&mut self) -> Option<Self::Item> {
+                    if self.decoder.index >= self.decoder.num_in_group {
+                        return None;
+                    }
+
+                    let entry = self.decoder.clone();
+
+                    if let Err(error) = self.decoder.advance() {
+                        self.decoder.index = self.decoder.num_in_group;
+                        return Some(Err(error));
+                    }
+
+                    Some(Ok(entry))
+                }
+
+                fn size_hint(&self) -> (usize, Option<usize>) {
+                    (0, Some(self.decoder.num_in_group - self.decoder.index))
                 }
             }
         })
@@ -145,6 +224,11 @@ impl RustGroupDecoderGenerator<'_> {
 
     fn generate_metadata(&self) -> Result<impl FormatInto<Rust>> {
         let metadata_decoder_tokens = quote! {
+            #[inline]
+            pub fn acting_version(&self) -> u16 {
+                self.acting_version
+            }
+
             #[inline]
             pub fn block_length(&self) -> usize {
                 self.block_length
@@ -162,17 +246,18 @@ impl RustGroupDecoderGenerator<'_> {
     pub fn generate_fields(
         &self,
         fields: &[&FieldType],
-        offset: &mut usize,
+        offsets: impl IntoIterator<Item = usize>,
     ) -> Result<impl FormatInto<Rust>> {
         let mut decoder_fields: Tokens<Rust> = quote!();
         let composite_decoder_gen = RustCompositeDecoderGenerator {
             config: self.config,
             path: self.path,
             types: self.types,
+            root: self.root,
             package: self.package,
         };
 
-        for field in fields {
+        for (field, offset) in fields.iter().zip(offsets) {
             decoder_fields.line();
             decoder_fields.append(self.generate_field(offset, &composite_decoder_gen, field)?);
         }
@@ -182,7 +267,7 @@ impl RustGroupDecoderGenerator<'_> {
 
     fn generate_field(
         &self,
-        offset: &mut usize,
+        offset: usize,
         composite_decoder_gen: &RustCompositeDecoderGenerator,
         field_type: &FieldType,
     ) -> Result<impl FormatInto<Rust>> {
@@ -191,10 +276,8 @@ impl RustGroupDecoderGenerator<'_> {
         let field_tokens = if matches!(field_type.presence, Presence::Constant) {
             quote!($(self.generate_constant_enum(field_type, repr_type)?))
         } else {
-            let tokens = quote!($(composite_decoder_gen.generate_decoder_field(&field_type.name, &repr_type, *offset)?));
-            *offset += repr_type.size(self.types)?;
-
-            tokens
+            let since_version = field_type.since_version.unwrap_or(0);
+            quote!($(composite_decoder_gen.generate_decoder_field(&field_type.name, &repr_type, offset, since_version)?))
         };
 
         Ok(field_tokens)
@@ -239,7 +322,7 @@ impl RustGroupDecoderGenerator<'_> {
     pub fn generate_groups(
         &self,
         groups: &[&GroupType],
-        offset: usize,
+        offset: &Tokens<Rust>,
     ) -> Result<impl FormatInto<Rust>> {
         let mut decoder_fields: Tokens<Rust> = quote!();
 
@@ -259,32 +342,59 @@ impl RustGroupDecoderGenerator<'_> {
         &self,
         group: &GroupType,
         previous_groups: &[&GroupType],
-        offset: usize,
+        offset: &Tokens<Rust>,
     ) -> Result<impl FormatInto<Rust>> {
         let group_name = group.name.as_str();
         let func_name = group_name.to_case(Case::Snake);
         let decoder_name = format!("{}Decoder", group_name.to_case(Case::UpperCamel));
+        let iter_name = format!("{}Iter", group_name.to_case(Case::UpperCamel));
 
         let prev_group_sizes = previous_groups
             .iter()
-            .map(|prev_group| {
-                let prev_group_name = prev_group.name.to_case(Case::Snake);
-                quote!($[' ']+ self.$(&prev_group_name)_size.ok_or(SbeError::MissingGroupSize($(quoted(prev_group_name))))?)
-            });
+            .map(|prev_group| quote!($[' ']+ self.$(prev_group.name.to_case(Case::Snake))_size()?));
 
         Ok(quote! {
             #[inline]
             pub fn $(&func_name)_decoder<T>(&mut self, action: impl FnOnce(&mut $(&decoder_name)) -> Result<T>) -> Result<T> {
-                let offset = $offset$(for prev_group_size in prev_group_sizes => $prev_group_size);
-                let buffer = self.buffer.split_at(offset)?.1;
-
-                let mut decoder = buffer.try_into()?;
+                let mut decoder = self.$(&func_name)_group()?;
 
                 let result = action(&mut decoder)?;
-                self.$(func_name)_size = decoder.size();
+                self.$(&func_name)_size = decoder.size();
 
                 Ok(result)
             }
+
+            #[inline]
+            pub fn $(&func_name)(&mut self) -> Result<$(&iter_name)<'a>> {
+                Ok(self.$(&func_name)_group()?.into_iter())
+            }
+
+            /// Size of the group, which is found by skipping its entries, when they were
+            /// not decoded before.
+            #[inline]
+            fn $(&func_name)_size(&mut self) -> Result<usize> {
+                match self.$(&func_name)_size {
+                    Some(size) => Ok(size),
+                    None => self.$(&func_name)_decoder(|decoder| {
+                        decoder.skip()?;
+                        decoder.size().ok_or(SbeError::MissingGroupSize($(quoted(&func_name))))
+                    }),
+                }
+            }
+
+            #[inline]
+            fn $(&func_name)_group(&mut self) -> Result<$(&decoder_name)<'a>> {
+                $(if let Some(since_version) = group.since_version.filter(|version| *version > 0) {
+                    if self.acting_version < $since_version {
+                        return Ok($(&decoder_name)::empty(self.acting_version));
+                    }
+                    $['\n']
+                })
+                let offset = $offset$(for prev_group_size in prev_group_sizes => $prev_group_size);
+                let buffer = self.buffer.split_at(offset)?.1;
+
+                $(&decoder_name)::new(buffer, self.acting_version)
+            }
         })
     }
 
@@ -292,7 +402,7 @@ impl RustGroupDecoderGenerator<'_> {
         &self,
         var_data_fields: &[&VariableDataType],
         groups: &[&GroupType],
-        offset: usize,
+        offset: &Tokens<Rust>,
     ) -> Result<impl FormatInto<Rust>> {
         let mut decoder_fields: Tokens<Rust> = quote!();
 
@@ -314,7 +424,7 @@ impl RustGroupDecoderGenerator<'_> {
         var_data_field: &VariableDataType,
         previous_var_data_fields: &[&VariableDataType],
         groups: &[&GroupType],
-        offset: usize,
+        offset: &Tokens<Rust>,
     ) -> Result<impl FormatInto<Rust>> {
         let name = var_data_field.name.as_str();
         let func_name = name.to_case(Case::Snake);
@@ -322,33 +432,54 @@ impl RustGroupDecoderGenerator<'_> {
 
         let group_sizes = groups
             .iter()
-            .map(|prev_group| {
-                let prev_group_name = prev_group.name.to_case(Case::Snake);
-                quote!($[' ']+ self.$(&prev_group_name)_size.ok_or(SbeError::MissingGroupSize($(quoted(prev_group_name))))?)
-            });
+            .map(|group| quote!($[' ']+ self.$(group.name.to_case(Case::Snake))_size()?));
 
         let group_size = quote!($offset$(for group_size in group_sizes => $group_size));
 
         let var_data_field_sizes = previous_var_data_fields
             .iter()
-            .map(|prev_var_data_field| {
-                let prev_var_data_field_name = prev_var_data_field.name.to_case(Case::Snake);
-                quote!($[' ']+ self.$(&prev_var_data_field_name)_size.ok_or(SbeError::MissingVarDataSize($(quoted(prev_var_data_field_name))))?)
-            });
+            .map(|prev_var_data_field| quote!($[' ']+ self.$(prev_var_data_field.name.to_case(Case::Snake))_size()?));
 
         Ok(quote! {
             #[inline]
             pub fn $(&func_name)_decoder<T>(&mut self, action: impl FnOnce(&mut $(&decoder_name)) -> Result<T>) -> Result<T> {
+                $(if let Some(since_version) = var_data_field.since_version.filter(|version| *version > 0) {
+                    if self.acting_version < $since_version {
+                        let mut decoder = $(&decoder_name)::empty();
+
+                        let result = action(&mut decoder)?;
+                        self.$(&func_name)_size = Some(0);
+
+                        return Ok(result);
+                    }
+                    $['\n']
+                })
                 let offset = $group_size$(for var_data_field_size in var_data_field_sizes => $var_data_field_size);
                 let buffer = self.buffer.split_at(offset)?.1;
 
                 let mut decoder = buffer.into();
 
                 let result = action(&mut decoder)?;
-                self.$(func_name)_size = Some(decoder.size());
+                self.$(&func_name)_size = Some(decoder.size());
 
                 Ok(result)
             }
+
+            /// Size of the var data, which is none when it was added in a newer version of
+            /// the schema than the acting one.
+            #[inline]
+            fn $(&func_name)_size(&mut self) -> Result<usize> {
+                $(if let Some(since_version) = var_data_field.since_version.filter(|version| *version > 0) {
+                    if self.acting_version < $since_version {
+                        return Ok(0);
+                    }
+                    $['\n']
+                })
+                match self.$(&func_name)_size {
+                    Some(size) => Ok(size),
+                    None => self.$(&func_name)_decoder(|decoder| Ok(decoder.size())),
+                }
+            }
         })
     }
 }
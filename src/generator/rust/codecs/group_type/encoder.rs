@@ -1,14 +1,15 @@
-use crate::generator::common::field_groups;
+use crate::generator::common::{dimension_type, field_groups};
 use crate::generator::rust::codecs::composite_type::encoder::RustCompositeEncoderGenerator;
-use crate::generator::rust::codecs::group_type::dimension_type;
+use crate::generator::rust::codecs::nested_codec_imports;
 use crate::generator::rust::constants::ENCODER_FILE_NAME;
 use crate::generator::write_file;
+use crate::models::layout::BlockLayout;
 use crate::models::types::field_type::FieldType;
 use crate::models::types::group_type::GroupType;
 use crate::models::types::primitive_type::PrimitiveConvertible;
 use crate::models::types::variable_data_type::VariableDataType;
 use crate::models::types::{Presence, SizedEncoded, Type};
-use crate::models::TypeMap;
+use crate::models::{FieldPath, TypeMap};
 use anyhow::{anyhow, Result};
 use convert_case::{Case, Casing};
 use genco::prelude::*;
@@ -18,12 +19,15 @@ pub struct RustGroupEncoderGenerator<'a> {
     pub(crate) config: &'a rust::Config,
     pub(crate) path: &'a Path,
     pub(crate) types: &'a TypeMap,
+    pub(crate) root: &'a str,
     pub(crate) package: &'a str,
+    pub(crate) bounds_checks: bool,
 }
 
 impl RustGroupEncoderGenerator<'_> {
-    pub fn write_encoder(&self, group: &GroupType) -> Result<()> {
-        let encoder_tokens: Tokens<Rust> = quote!($(self.generate_group_encoder(group)?));
+    pub fn write_encoder(&self, group: &GroupType, group_path: &FieldPath) -> Result<()> {
+        let encoder_tokens: Tokens<Rust> =
+            quote!($(self.generate_group_encoder(group, group_path)?));
 
         write_file(
             &self.path.join(ENCODER_FILE_NAME),
@@ -34,7 +38,11 @@ impl RustGroupEncoderGenerator<'_> {
         Ok(())
     }
 
-    pub fn generate_group_encoder(&self, group: &GroupType) -> Result<impl FormatInto<Rust>> {
+    pub fn generate_group_encoder(
+        &self,
+        group: &GroupType,
+        group_path: &FieldPath,
+    ) -> Result<impl FormatInto<Rust>> {
         let name = group.name.as_str();
         let encoder_name = format!("{}Encoder", name.to_case(Case::UpperCamel));
         let (fields, groups, var_data) = field_groups(&group.fields);
@@ -83,24 +91,25 @@ impl RustGroupEncoderGenerator<'_> {
             .primitive_type
             .lang_primitive(&self.types.encoded_types)?;
 
+        let layout = BlockLayout::from_fields(name, &fields, group.block_length, self.types)?;
+        let block_length = layout.block_length;
+
         let offset_prefix = quote!(self.size +);
-        let mut offset = 0;
 
-        let field_tokens = self.generate_fields(&fields, &offset_prefix, &mut offset)?;
+        let field_tokens = self.generate_fields(&fields, &offset_prefix, layout.offsets)?;
 
-        let offset_tokens = quote!($offset_prefix$offset);
+        let offset_tokens = quote!($offset_prefix$block_length);
 
         let group_tokens = self.generate_groups(&groups, &offset_tokens)?;
         let var_data_tokens = self.generate_var_data_fields(&var_data, &groups, &offset_tokens)?;
 
         Ok(quote! {
-            use crate::error::*;
-            use crate::$(self.package)::composites::*;
-            use crate::$(self.package)::encoder::*;
-            use crate::$(self.package)::enums::*;
-            use crate::$(self.package)::groups::*;
-            use crate::$(self.package)::sets::*;
-            use crate::$(self.package)::var_data::*;
+            use $(self.root)::error::*;
+            use $(self.root)::$(self.package)::composites::*;
+            use $(self.root)::$(self.package)::encoder::*;
+            use $(self.root)::$(self.package)::enums::*;
+            use $(self.root)::$(self.package)::sets::*;
+            $(nested_codec_imports(self.root, self.package, group_path, !groups.is_empty(), !var_data.is_empty()))
             use std::convert::TryFrom;
 
             #[derive(Debug)]
@@ -134,7 +143,7 @@ impl RustGroupEncoderGenerator<'_> {
                     self.num_in_group = self.num_in_group.checked_add(1).ok_or(SbeError::GroupOutOfBounds("num_in_group"))?;
                     self.size += fields_size + groups_size + var_data_size;
 
-                    Ok(())
+                    self.buffer.extend_to(self.size)
                 }
 
                 #[inline]
@@ -156,7 +165,7 @@ impl RustGroupEncoderGenerator<'_> {
                 fn try_from(buffer: WriteBuf<'a>) -> Result<Self> {
                     Ok(Self {
                         buffer,
-                        block_length: $(offset),
+                        block_length: $(block_length),
                         num_in_group: 0,
                         size: $(dimension_type_size),
                         $(for group in &groups => $['\r']$(group.name.to_case(Case::Snake))_size: None,)
@@ -171,17 +180,19 @@ impl RustGroupEncoderGenerator<'_> {
         &self,
         fields: &[&FieldType],
         offset_prefix: &Tokens<Rust>,
-        offset: &mut usize,
+        offsets: impl IntoIterator<Item = usize>,
     ) -> Result<impl FormatInto<Rust>> {
         let mut encoder_fields: Tokens<Rust> = quote!();
         let composite_encoder_gen = RustCompositeEncoderGenerator {
             config: self.config,
             path: self.path,
             types: self.types,
+            root: self.root,
             package: self.package,
+            bounds_checks: self.bounds_checks,
         };
 
-        for field in fields {
+        for (field, offset) in fields.iter().zip(offsets) {
             encoder_fields.line();
             encoder_fields.append(self.generate_field(
                 field,
@@ -199,10 +210,10 @@ impl RustGroupEncoderGenerator<'_> {
         field_type: &FieldType,
         composite_encoder_gen: &RustCompositeEncoderGenerator,
         offset_prefix: &Tokens<Rust>,
-        offset: &mut usize,
+        offset: usize,
     ) -> Result<impl FormatInto<Rust>> {
         let repr_type = field_type.to_type(self.types)?;
-        let field_offset = quote!($offset_prefix$(*offset));
+        let field_offset = quote!($offset_prefix$offset);
 
         Ok(match field_type.presence {
             Presence::Constant => quote!(),
@@ -212,7 +223,6 @@ impl RustGroupEncoderGenerator<'_> {
                     &repr_type,
                     field_offset,
                 )?;
-                *offset += repr_type.size(self.types)?;
                 quote!($tokens)
             }
         })
@@ -258,7 +268,7 @@ impl RustGroupEncoderGenerator<'_> {
             #[inline]
             pub fn $(&func_name)_encoder<T>(&mut self, action: impl FnOnce(&mut $(&encoder_name)) -> Result<T>) -> Result<T> {
                 let offset = $offset$(for prev_group_size in prev_group_sizes => $prev_group_size);
-                let buffer = self.buffer.split_at_mut(offset)?.1;
+                let buffer = self.buffer.offset_mut(offset);
 
                 let mut encoder = buffer.try_into()?;
 
@@ -324,7 +334,7 @@ impl RustGroupEncoderGenerator<'_> {
             #[inline]
             pub fn $(&func_name)_encoder<T>(&mut self, action: impl FnOnce(&mut $(&encoder_name)) -> Result<T>) -> Result<T> {
                 let offset = $group_size$(for var_data_field_size in var_data_field_sizes => $var_data_field_size);
-                let buffer = self.buffer.split_at_mut(offset)?.1;
+                let buffer = self.buffer.offset_mut(offset);
 
                 let mut encoder = buffer.try_into()?;
 
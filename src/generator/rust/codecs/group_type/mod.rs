@@ -1,20 +1,22 @@
 use crate::models::types::group_type::GroupType;
-use anyhow::{anyhow, Result};
+use anyhow::Result;
 use convert_case::{Case, Casing};
-use std::collections::HashMap;
 use std::fs::create_dir_all;
 use std::path::Path;
 
+use crate::generator::common::{field_groups, scoped_module_dir};
 use crate::generator::rust::codecs::group_type::decoder::RustGroupDecoderGenerator;
 use crate::generator::rust::codecs::group_type::encoder::RustGroupEncoderGenerator;
+use crate::generator::rust::codecs::group_type::owned::RustGroupOwnedGenerator;
 use crate::generator::rust::constants::GROUP_MODULE_NAME;
 use crate::generator::rust::module::ModuleGenerator;
 use crate::generator::write_file;
-use crate::models::types::composite_type::CompositeType;
+use crate::models::FieldPath;
 use genco::prelude::*;
 
 pub mod decoder;
 pub mod encoder;
+pub mod owned;
 
 impl ModuleGenerator<'_> {
     fn group_encoder_generator<'a>(
@@ -25,7 +27,9 @@ impl ModuleGenerator<'_> {
             config: &self.config,
             path: module_path,
             types: &self.schema.types,
+            root: &self.root,
             package: &self.schema.package,
+            bounds_checks: self.bounds_checks,
         }
     }
 
@@ -37,68 +41,111 @@ impl ModuleGenerator<'_> {
             config: &self.config,
             path: module_path,
             types: &self.schema.types,
+            root: &self.root,
             package: &self.schema.package,
         }
     }
 
-    fn write_group_codece(&self, module_path: &Path, group_type: &GroupType) -> Result<()> {
+    fn group_owned_generator<'a>(&'a self, module_path: &'a Path) -> RustGroupOwnedGenerator<'a> {
+        RustGroupOwnedGenerator {
+            config: &self.config,
+            path: module_path,
+            types: &self.schema.types,
+            root: &self.root,
+            package: &self.schema.package,
+            serde: self.serde,
+        }
+    }
+
+    fn write_group_codec(&self, root_path: &Path, group_path: &FieldPath) -> Result<()> {
+        let group_type = &self.schema.message_types.group_types[group_path];
         let name = group_type.name.as_str();
-        let module_path = module_path.join(name.to_case(Case::Snake));
+        let module_path = scoped_module_dir(root_path, group_path);
         create_dir_all(&module_path)?;
 
         let encoder_generator = self.group_encoder_generator(&module_path);
         let decoder_generator = self.group_decoder_generator(&module_path);
+        let owned_generator = self.group_owned_generator(&module_path);
+        let (_, groups, _) = field_groups(&group_type.fields);
 
         let composite_tokens: Tokens<Rust> = quote! {
             mod decoder;
             mod encoder;
+            mod owned;
 
-            pub use self::decoder::$(name.to_case(Case::UpperCamel))Decoder;
+            pub use self::decoder::{$(name.to_case(Case::UpperCamel))Decoder, $(name.to_case(Case::UpperCamel))Iter};
             pub use self::encoder::$(name.to_case(Case::UpperCamel))Encoder;
+            pub use self::owned::$(name.to_case(Case::UpperCamel));
+
+            $(nested_group_modules(&groups))
         };
 
-        encoder_generator.write_encoder(group_type)?;
-        decoder_generator.write_decoder(group_type)?;
+        encoder_generator.write_encoder(group_type, group_path)?;
+        decoder_generator.write_decoder(group_type, group_path)?;
+        owned_generator.write_owned(group_type, group_path)?;
 
         write_file(&module_path.join("mod.rs"), &self.config, composite_tokens)?;
 
         Ok(())
     }
 
+    /// Writes the codecs of the groups of every message. The codecs are placed in modules
+    /// named after the message and the enclosing groups, so that groups with the same name
+    /// in different messages do not clash.
     pub fn write_group_codecs(&self) -> Result<()> {
         let module_path = self.path.join(GROUP_MODULE_NAME);
         create_dir_all(&module_path)?;
 
         let mut module_tokens: Tokens<Rust> = quote!();
 
-        for (name, group_type) in &self.schema.message_types.group_types {
-            self.write_group_codece(&module_path, group_type)?;
+        for message_type in self.schema.message_types.message_types.values() {
+            let (_, groups, _) = field_groups(&message_type.fields);
+            if groups.is_empty() {
+                continue;
+            }
+
+            let message_path = FieldPath::new(vec![message_type.name.clone()]);
+            let message_module_path = scoped_module_dir(&module_path, &message_path);
+            create_dir_all(&message_module_path)?;
+
+            let message_module_tokens: Tokens<Rust> = quote!($(nested_group_modules(&groups)));
+            write_file(
+                &message_module_path.join("mod.rs"),
+                &self.config,
+                message_module_tokens,
+            )?;
 
             module_tokens.append(quote! {
-                pub mod $(name.to_case(Case::Snake));
-                pub use self::$(name.to_case(Case::Snake))::*;
+                pub mod $(message_type.name.to_case(Case::Snake));
             });
             module_tokens.push();
         }
 
+        for group_path in self.schema.message_types.group_types.keys() {
+            self.write_group_codec(&module_path, group_path)?;
+        }
+
         write_file(&module_path.join("mod.rs"), &self.config, module_tokens)?;
 
         Ok(())
     }
 }
 
-fn dimension_type<'a>(
-    group: &GroupType,
-    composite_types: &'a HashMap<String, CompositeType>,
-) -> Result<&'a CompositeType> {
-    let dimension_type_name = group
-        .dimension_type
-        .clone()
-        .unwrap_or("groupSizeEncoding".to_owned());
-
-    composite_types.get(&dimension_type_name).ok_or(anyhow!(
-        "Missing dimension type '{}' for group '{}'",
-        dimension_type_name,
-        group.name
-    ))
+/// Generates the declarations of the modules of the groups nested in a message or group,
+/// re-exporting their codecs.
+fn nested_group_modules(groups: &[&GroupType]) -> Tokens<Rust> {
+    let mut tokens: Tokens<Rust> = quote!();
+
+    for group in groups {
+        let module_name = group.name.to_case(Case::Snake);
+        let type_name = group.name.to_case(Case::UpperCamel);
+
+        tokens.append(quote! {
+            pub mod $(&module_name);
+            pub use self::$(&module_name)::{$(&type_name), $(&type_name)Decoder, $(&type_name)Encoder, $(&type_name)Iter};
+        });
+        tokens.push();
+    }
+
+    tokens
 }
@@ -0,0 +1,274 @@
+use std::path::Path;
+
+use anyhow::Result;
+use convert_case::{Case, Casing};
+use genco::prelude::*;
+
+use crate::generator::common::field_groups;
+use crate::generator::rust::codecs::composite_type::owned::RustCompositeOwnedGenerator;
+use crate::generator::rust::codecs::nested_codec_imports;
+use crate::generator::rust::codecs::var_data_type::repr_type_metadata;
+use crate::generator::rust::constants::OWNED_FILE_NAME;
+use crate::generator::rust::module::serde_derive;
+use crate::generator::write_file;
+use crate::models::types::field_type::FieldType;
+use crate::models::types::group_type::GroupType;
+use crate::models::types::variable_data_type::VariableDataType;
+use crate::models::types::Presence;
+use crate::models::{FieldPath, TypeMap};
+
+pub struct RustGroupOwnedGenerator<'a> {
+    pub(crate) config: &'a rust::Config,
+    pub(crate) path: &'a Path,
+    pub(crate) types: &'a TypeMap,
+    pub(crate) root: &'a str,
+    pub(crate) package: &'a str,
+    pub(crate) serde: bool,
+}
+
+impl RustGroupOwnedGenerator<'_> {
+    pub fn write_owned(&self, group: &GroupType, group_path: &FieldPath) -> Result<()> {
+        let owned_tokens: Tokens<Rust> = quote!($(self.generate_group_owned(group, group_path)?));
+
+        write_file(&self.path.join(OWNED_FILE_NAME), self.config, owned_tokens)?;
+
+        Ok(())
+    }
+
+    fn generate_group_owned(
+        &self,
+        group: &GroupType,
+        group_path: &FieldPath,
+    ) -> Result<impl FormatInto<Rust>> {
+        let name = group.name.to_case(Case::UpperCamel);
+        let decoder_name = format!("{}Decoder", name);
+        let encoder_name = format!("{}Encoder", name);
+        let (fields, groups, var_data) = field_groups(&group.fields);
+
+        Ok(quote! {
+            use $(self.root)::error::*;
+            use $(self.root)::$(self.package)::composites::*;
+            use $(self.root)::$(self.package)::enums::*;
+            use $(self.root)::$(self.package)::sets::*;
+            $(nested_codec_imports(self.root, self.package, group_path, !groups.is_empty(), false))
+            use super::{$(&decoder_name), $(&encoder_name)};
+
+            #[derive(Debug, Clone, PartialEq)]
+            $(serde_derive(self.serde))
+            pub struct $(&name) {
+                $(self.generate_struct_fields(&fields, &groups, &var_data)?)
+            }
+
+            impl $(&name) {
+                #[inline]
+                pub fn decode_from(decoder: &mut $(&decoder_name)<'_>) -> Result<Self> {
+                    Ok(Self {
+                        $(self.generate_decode_fields(&fields, &groups, &var_data)?)
+                    })
+                }
+
+                #[inline]
+                pub fn encode_into(&self, encoder: &mut $(&encoder_name)<'_>) -> Result<()> {
+                    $(self.generate_encode_fields(&fields, &groups, &var_data)?)
+
+                    Ok(())
+                }
+            }
+        })
+    }
+
+    fn composite_owned_generator(&self) -> RustCompositeOwnedGenerator<'_> {
+        RustCompositeOwnedGenerator {
+            config: self.config,
+            path: self.path,
+            types: self.types,
+            root: self.root,
+            package: self.package,
+            serde: self.serde,
+        }
+    }
+
+    /// Generates the fields of an owned message or group entry. Groups are stored as vectors
+    /// of their entries, var data as strings or vectors of their values.
+    pub fn generate_struct_fields(
+        &self,
+        fields: &[&FieldType],
+        groups: &[&GroupType],
+        var_data: &[&VariableDataType],
+    ) -> Result<impl FormatInto<Rust>> {
+        let composite_owned_gen = self.composite_owned_generator();
+        let mut struct_fields: Tokens<Rust> = quote!();
+
+        for field in fields {
+            if matches!(field.presence, Presence::Constant) {
+                continue;
+            }
+
+            struct_fields.append(composite_owned_gen.generate_struct_field(
+                &field.name,
+                &field.to_type(self.types)?,
+                field.since_version.unwrap_or(0),
+            )?);
+        }
+
+        for group in groups {
+            struct_fields.append(quote! {
+                pub $(group.name.to_case(Case::Snake)): Vec<$(group.name.to_case(Case::UpperCamel))>,
+                $['\r']
+            });
+        }
+
+        for var_data_field in var_data {
+            struct_fields.append(quote! {
+                pub $(var_data_field.name.to_case(Case::Snake)): $(self.var_data_type_name(var_data_field)?),
+                $['\r']
+            });
+        }
+
+        Ok(struct_fields)
+    }
+
+    fn var_data_type_name(&self, var_data_field: &VariableDataType) -> Result<String> {
+        if var_data_field.is_string(&self.types.composite_types)? {
+            return Ok("String".to_owned());
+        }
+
+        let repr_type = var_data_field.repr_type(&self.types.composite_types)?;
+        let (_, value_type_metadata) =
+            repr_type_metadata(&var_data_field.name, repr_type, self.types)?;
+
+        Ok(format!("Vec<{}>", value_type_metadata.lang_type.name))
+    }
+
+    pub fn generate_decode_fields(
+        &self,
+        fields: &[&FieldType],
+        groups: &[&GroupType],
+        var_data: &[&VariableDataType],
+    ) -> Result<impl FormatInto<Rust>> {
+        let composite_owned_gen = self.composite_owned_generator();
+        let mut decode_fields: Tokens<Rust> = quote!();
+
+        for field in fields {
+            if matches!(field.presence, Presence::Constant) {
+                continue;
+            }
+
+            decode_fields.append(composite_owned_gen.generate_field_decode(
+                &field.name,
+                &field.to_type(self.types)?,
+                field.since_version.unwrap_or(0),
+            )?);
+        }
+
+        for group in groups {
+            let var_name = group.name.to_case(Case::Snake);
+
+            decode_fields.append(quote! {
+                $(&var_name): decoder.$(&var_name)_decoder(|decoder| {
+                    let mut $(&var_name) = Vec::with_capacity(decoder.num_in_group());
+                    for _ in 0..decoder.num_in_group() {
+                        $(&var_name).push($(group.name.to_case(Case::UpperCamel))::decode_from(decoder)?);
+                        decoder.advance()?;
+                    }
+                    Ok($(&var_name))
+                })?,
+                $['\r']
+            });
+        }
+
+        for var_data_field in var_data {
+            let var_name = var_data_field.name.to_case(Case::Snake);
+
+            let decode = if var_data_field.is_string(&self.types.composite_types)? {
+                quote!(Ok(String::from_utf8(
+                    decoder.get_slice_at(0, decoder.length())?.to_vec()
+                )?))
+            } else if var_data_field.is_bytes(self.types)? {
+                quote!(Ok(decoder.get_slice_at(0, decoder.length())?.to_vec()))
+            } else {
+                quote! {
+                    let mut $(&var_name) = Vec::with_capacity(decoder.length());
+                    for idx in 0..decoder.length() {
+                        $(&var_name).push(decoder.get_at(idx)?);
+                    }
+                    Ok($(&var_name))
+                }
+            };
+
+            decode_fields.append(quote! {
+                $(&var_name): decoder.$(&var_name)_decoder(|decoder| {
+                    $decode
+                })?,
+                $['\r']
+            });
+        }
+
+        Ok(decode_fields)
+    }
+
+    pub fn generate_encode_fields(
+        &self,
+        fields: &[&FieldType],
+        groups: &[&GroupType],
+        var_data: &[&VariableDataType],
+    ) -> Result<impl FormatInto<Rust>> {
+        let composite_owned_gen = self.composite_owned_generator();
+        let mut encode_fields: Tokens<Rust> = quote!();
+
+        for field in fields {
+            if matches!(field.presence, Presence::Constant) {
+                continue;
+            }
+
+            encode_fields.append(composite_owned_gen.generate_field_encode(
+                &field.name,
+                &field.to_type(self.types)?,
+                field.since_version.unwrap_or(0),
+            )?);
+        }
+
+        for group in groups {
+            let var_name = group.name.to_case(Case::Snake);
+
+            encode_fields.append(quote! {
+                encoder.$(&var_name)_encoder(|encoder| {
+                    for $(&var_name) in &self.$(&var_name) {
+                        $(&var_name).encode_into(encoder)?;
+                        encoder.advance()?;
+                    }
+                    Ok(())
+                })?;
+                $['\r']
+            });
+        }
+
+        for var_data_field in var_data {
+            let name = var_data_field.name.as_str();
+            let var_name = name.to_case(Case::Snake);
+
+            let encode = if var_data_field.is_string(&self.types.composite_types)? {
+                quote!(encoder.put_slice_at(0, self.$(&var_name).as_bytes()))
+            } else if var_data_field.is_bytes(self.types)? {
+                quote!(encoder.put_slice_at(0, &self.$(&var_name)))
+            } else {
+                quote! {
+                    for (idx, value) in self.$(&var_name).iter().enumerate() {
+                        let idx = idx.try_into().map_err(|_| SbeError::VarDataOutOfBounds($(quoted(name))))?;
+                        encoder.put_at(idx, *value)?;
+                    }
+                    Ok(())
+                }
+            };
+
+            encode_fields.append(quote! {
+                encoder.$(&var_name)_encoder(|encoder| {
+                    $encode
+                })?;
+                $['\r']
+            });
+        }
+
+        Ok(encode_fields)
+    }
+}
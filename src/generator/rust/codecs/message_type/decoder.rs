@@ -1,11 +1,13 @@
 use crate::generator::common::field_groups;
 use crate::generator::rust::codecs::composite_type::decoder::RustCompositeDecoderGenerator;
 use crate::generator::rust::codecs::group_type::decoder::RustGroupDecoderGenerator;
+use crate::generator::rust::codecs::nested_codec_imports;
 use crate::generator::rust::constants::DECODER_FILE_NAME;
 use crate::generator::write_file;
+use crate::models::layout::BlockLayout;
 use crate::models::message::MessageType;
 use crate::models::types::{SizedEncoded, Type};
-use crate::models::TypeMap;
+use crate::models::{FieldPath, TypeMap};
 use anyhow::Result;
 use convert_case::{Case, Casing};
 use genco::prelude::*;
@@ -16,6 +18,7 @@ pub struct RustMessageDecoderGenerator<'a> {
     pub(crate) config: &'a rust::Config,
     pub(crate) path: &'a Path,
     pub(crate) types: &'a TypeMap,
+    pub(crate) root: &'a str,
     pub(crate) package: &'a str,
 }
 
@@ -36,38 +39,39 @@ impl RustMessageDecoderGenerator<'_> {
         let name = message.name.as_str();
         let decoder_name = format!("{}Decoder", name.to_case(Case::UpperCamel));
         let (fields, groups, var_data) = field_groups(&message.fields);
+        let message_path = FieldPath::new(vec![message.name.clone()]);
 
         let group_decoder_gen = RustGroupDecoderGenerator {
             config: self.config,
             path: self.path,
             types: self.types,
+            root: self.root,
             package: self.package,
         };
 
-        let fields_size: usize = fields
-            .iter()
-            .map(|field| {
-                let repr_type = field.to_type(self.types)?;
-                repr_type.size(self.types)
-            })
-            .sum::<Result<usize>>()?;
+        let layout = BlockLayout::from_fields(name, &fields, message.block_length, self.types)?;
+        let block_length = layout.block_length;
 
         let mut offset = 0;
+        let header = self.generate_header(&mut offset)?;
+        let header_size = offset;
 
         Ok(quote! {
-            use crate::error::*;
-            use crate::$(self.package)::composites::*;
-            use crate::$(self.package)::decoder::*;
-            use crate::$(self.package)::enums::*;
-            use crate::$(self.package)::groups::*;
-            use crate::$(self.package)::sets::*;
-            use crate::$(self.package)::var_data::*;
+            use $(self.root)::error::*;
+            use $(self.root)::$(self.package)::composites::*;
+            use $(self.root)::$(self.package)::decoder::*;
+            use $(self.root)::$(self.package)::enums::*;
+            use $(self.root)::$(self.package)::sets::*;
+            $(nested_codec_imports(self.root, self.package, &message_path, !groups.is_empty(), !var_data.is_empty()))
             use std::convert::TryFrom;
             use super::$(name.to_case(Case::ScreamingSnake))_ID;
+            use $(self.root)::$(self.package)::{SCHEMA_ID, SCHEMA_VERSION};
 
             #[derive(Debug)]
             pub struct $(&decoder_name)<'a> {
                 buffer: ReadBuf<'a>,
+                acting_version: u16,
+                block_length: usize,
                 $(for group in &groups {
                     $(group.name.to_case(Case::Snake))_size: Option<usize>,
                     $['\r']
@@ -78,47 +82,71 @@ impl RustMessageDecoderGenerator<'_> {
                 })
             }
 
-            impl $(&decoder_name)<'_> {
+            impl<'a> $(&decoder_name)<'a> {
                 #[inline]
                 pub const fn id() -> u16 {
                     $(name.to_case(Case::ScreamingSnake))_ID
                 }
 
+                /// Version of the schema the decoded message was encoded with.
                 #[inline]
-                pub fn size(&self) -> Option<usize> {
-                    Some($fields_size
-                    $(for group in &groups {
-                        $[' ']+ self.$(group.name.to_case(Case::Snake))_size?
-                    })
-                    $(for var in &var_data {
-                        $[' ']+ self.$(var.name.to_case(Case::Snake))_size?
-                    }))
+                pub fn acting_version(&self) -> u16 {
+                    self.acting_version
                 }
 
-                $(self.generate_header(&mut offset)?)
+                /// Length of the root block of the decoded message, which can differ from
+                /// the one in the schema, when the message was encoded with another version.
+                #[inline]
+                pub fn block_length(&self) -> usize {
+                    self.block_length
+                }
+
+                /// Size of the decoded message including its header, for which the groups and
+                /// var data, that were not decoded before, are skipped.
+                #[inline]
+                pub fn size(&mut self) -> Result<usize> {
+                    let header_size = $header_size;
+                    let fields_size = self.block_length;
+                    let groups_size = 0$(for group in &groups => $[' ']+ self.$(group.name.to_case(Case::Snake))_size()?);
+                    let var_data_size = 0$(for var in &var_data => $[' ']+ self.$(var.name.to_case(Case::Snake))_size()?);
+
+                    Ok(header_size + fields_size + groups_size + var_data_size)
+                }
+
+                $header
 
-                $(group_decoder_gen.generate_fields(&fields, &mut offset)?)
+                $(group_decoder_gen.generate_fields(&fields, layout.offsets.iter().map(|field_offset| header_size + field_offset))?)
 
-                $(group_decoder_gen.generate_groups(&groups, offset)?)
+                $(group_decoder_gen.generate_groups(&groups, &quote!($header_size + self.block_length))?)
 
-                $(group_decoder_gen.generate_var_data_fields(&var_data, &groups, offset)?)
+                $(group_decoder_gen.generate_var_data_fields(&var_data, &groups, &quote!($header_size + self.block_length))?)
             }
 
             impl<'a> TryFrom<ReadBuf<'a>> for $(&decoder_name)<'a> {
                 type Error = SbeError;
 
                 fn try_from(buffer: ReadBuf<'a>) -> Result<Self> {
-                    let msg = Self {
+                    let mut msg = Self {
                         buffer,
+                        acting_version: SCHEMA_VERSION,
+                        block_length: $block_length,
                         $(for group in &groups => $['\r']$(group.name.to_case(Case::Snake))_size: None,)
                         $(for var in &var_data => $['\r']$(var.name.to_case(Case::Snake))_size: None,)
                     };
 
+                    let current_schema_id = msg.message_header_decoder(|decoder| decoder.schema_id())?;
+                    if current_schema_id != SCHEMA_ID as _ {
+                        return Err(SbeError::WrongSchema(current_schema_id as _, SCHEMA_ID.into()));
+                    }
+
                     let current_message_id = msg.message_header_decoder(|decoder| decoder.template_id())?;
-                    if current_message_id != Self::id() {
-                        return Err(SbeError::WrongMessageType(current_message_id, Self::id()));
+                    if current_message_id != Self::id() as _ {
+                        return Err(SbeError::WrongMessageType(current_message_id as _, Self::id()));
                     }
 
+                    msg.acting_version = msg.message_header_decoder(|decoder| decoder.version())? as u16;
+                    msg.block_length = msg.message_header_decoder(|decoder| decoder.block_length())? as usize;
+
                     Ok(msg)
                 }
             }
@@ -130,6 +158,7 @@ impl RustMessageDecoderGenerator<'_> {
             config: self.config,
             path: self.path,
             types: self.types,
+            root: self.root,
             package: self.package,
         };
 
@@ -139,6 +168,7 @@ impl RustMessageDecoderGenerator<'_> {
             "message_header",
             &message_header_type,
             *offset,
+            0,
         )?;
         *offset += message_header_type.size(self.types)?;
 
@@ -0,0 +1,125 @@
+use crate::generator::rust::constants::DISPATCHER_FILE_NAME;
+use crate::generator::rust::module::ModuleGenerator;
+use crate::generator::write_file;
+use crate::models::message::MessageType;
+use anyhow::Result;
+use convert_case::{Case, Casing};
+use genco::prelude::*;
+use std::path::Path;
+
+impl ModuleGenerator<'_> {
+    /// Writes the decoder of any message of the schema, which checks the schema id and picks
+    /// the message decoder by the template id in the message header, and the visitor of the
+    /// decoded messages.
+    pub(crate) fn write_message_dispatcher(
+        &self,
+        module_path: &Path,
+        message_types: &[&MessageType],
+    ) -> Result<()> {
+        let header_decoder_name = format!(
+            "{}Decoder",
+            self.schema.types.header_type.name.to_case(Case::UpperCamel)
+        );
+
+        let variants = message_types
+            .iter()
+            .map(|message_type| {
+                (
+                    message_type.name.to_case(Case::UpperCamel),
+                    message_type.name.to_case(Case::Snake),
+                    message_type.name.to_case(Case::ScreamingSnake),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let dispatcher_tokens: Tokens<Rust> = quote! {
+            use $(&self.root)::error::*;
+            use $(&self.root)::$(&self.schema.package)::composites::$(&header_decoder_name);
+            use $(&self.root)::$(&self.schema.package)::decoder::ReadBuf;
+            use $(&self.root)::$(&self.schema.package)::messages::*;
+            use $(&self.root)::$(&self.schema.package)::SCHEMA_ID;
+            use std::convert::TryFrom;
+
+            /// Decoder of any message of the schema.
+            #[derive(Debug)]
+            pub enum AnyMessageDecoder<'a> {
+                $(for (name, _, _) in &variants {
+                    $['\r']
+                    $name($(name)Decoder<'a>),
+                })
+            }
+
+            impl AnyMessageDecoder<'_> {
+                #[inline]
+                pub fn template_id(&self) -> u16 {
+                    match self {
+                        $(for (name, _, _) in &variants {
+                            $['\r']
+                            Self::$name(_) => $(name)Decoder::id(),
+                        })
+                    }
+                }
+
+                /// Version of the schema the decoded message was encoded with.
+                #[inline]
+                pub fn acting_version(&self) -> u16 {
+                    match self {
+                        $(for (name, _, _) in &variants {
+                            $['\r']
+                            Self::$name(decoder) => decoder.acting_version(),
+                        })
+                    }
+                }
+
+                /// Calls the method of the `visitor` for the type of the decoded message.
+                #[inline]
+                pub fn accept(&mut self, visitor: &mut impl MessageVisitor) -> Result<()> {
+                    match self {
+                        $(for (name, var_name, _) in &variants {
+                            $['\r']
+                            Self::$name(decoder) => visitor.on_$var_name(decoder),
+                        })
+                    }
+                }
+            }
+
+            impl<'a> TryFrom<ReadBuf<'a>> for AnyMessageDecoder<'a> {
+                type Error = SbeError;
+
+                fn try_from(buffer: ReadBuf<'a>) -> Result<Self> {
+                    let header = $(&header_decoder_name)::from(buffer.split_at(0)?.1);
+
+                    let schema_id = header.schema_id()?;
+                    if schema_id != SCHEMA_ID as _ {
+                        return Err(SbeError::WrongSchema(schema_id as _, SCHEMA_ID.into()));
+                    }
+
+                    Ok(match header.template_id()? as u16 {
+                        $(for (name, _, id_name) in &variants {
+                            $['\r']
+                            $(id_name)_ID => Self::$name($(name)Decoder::try_from(buffer)?),
+                        })
+                        template_id => return Err(SbeError::UnknownMessageType(template_id)),
+                    })
+                }
+            }
+
+            /// Visitor of the decoded messages. Messages without an overridden method are ignored.
+            pub trait MessageVisitor {
+                $(for (name, var_name, _) in &variants {
+                    $['\r']
+                    fn on_$var_name(&mut self, _decoder: &mut $(name)Decoder<'_>) -> Result<()> {
+                        Ok(())
+                    }
+                    $['\n']
+                })
+            }
+        };
+
+        write_file(
+            &module_path.join(DISPATCHER_FILE_NAME),
+            &self.config,
+            dispatcher_tokens,
+        )
+    }
+}
@@ -1,11 +1,13 @@
-use crate::generator::common::field_groups;
+use crate::generator::common::{field_groups, header_counts};
 use crate::generator::rust::codecs::composite_type::encoder::RustCompositeEncoderGenerator;
 use crate::generator::rust::codecs::group_type::encoder::RustGroupEncoderGenerator;
+use crate::generator::rust::codecs::nested_codec_imports;
 use crate::generator::rust::constants::ENCODER_FILE_NAME;
 use crate::generator::write_file;
+use crate::models::layout::BlockLayout;
 use crate::models::message::MessageType;
 use crate::models::types::{SizedEncoded, Type};
-use crate::models::TypeMap;
+use crate::models::{FieldPath, TypeMap};
 use anyhow::Result;
 use convert_case::{Case, Casing};
 use genco::lang::{rust, Rust};
@@ -17,7 +19,9 @@ pub struct RustMessageEncoderGenerator<'a> {
     pub(crate) config: &'a rust::Config,
     pub(crate) path: &'a Path,
     pub(crate) types: &'a TypeMap,
+    pub(crate) root: &'a str,
     pub(crate) package: &'a str,
+    pub(crate) bounds_checks: bool,
 }
 
 impl RustMessageEncoderGenerator<'_> {
@@ -37,41 +41,51 @@ impl RustMessageEncoderGenerator<'_> {
         let name = message.name.as_str();
         let encoder_name = format!("{}Encoder", name.to_case(Case::UpperCamel));
         let (fields, groups, var_data) = field_groups(&message.fields);
+        let message_path = FieldPath::new(vec![message.name.clone()]);
 
         let group_encoder_gen = RustGroupEncoderGenerator {
             config: self.config,
             path: self.path,
             types: self.types,
+            root: self.root,
             package: self.package,
+            bounds_checks: self.bounds_checks,
         };
 
         let offset_prefix = quote!();
         let mut header_offset = 0;
 
         let header_tokens = self.generate_header(&mut header_offset)?;
+        let header_counts = header_counts(self.types, groups.len(), var_data.len());
+
+        let layout = BlockLayout::from_fields(name, &fields, message.block_length, self.types)?;
+        let block_length = layout.block_length;
+
+        let field_tokens = group_encoder_gen.generate_fields(
+            &fields,
+            &offset_prefix,
+            layout
+                .offsets
+                .iter()
+                .map(|field_offset| header_offset + field_offset),
+        )?;
 
-        let mut field_offset = header_offset;
-
-        let field_tokens =
-            group_encoder_gen.generate_fields(&fields, &offset_prefix, &mut field_offset)?;
-
-        let offset_tokens = quote!($field_offset);
+        let offset_tokens = quote!($(header_offset + block_length));
 
         let group_tokens = group_encoder_gen.generate_groups(&groups, &offset_tokens)?;
         let var_data_tokens =
             group_encoder_gen.generate_var_data_fields(&var_data, &groups, &offset_tokens)?;
 
         Ok(quote! {
-            use crate::error::*;
-            use crate::$(self.package)::composites::*;
-            use crate::$(self.package)::encoder::*;
-            use crate::$(self.package)::enums::*;
-            use crate::$(self.package)::groups::*;
-            use crate::$(self.package)::sets::*;
-            use crate::$(self.package)::var_data::*;
+            use $(self.root)::error::*;
+            use $(self.root)::$(self.package)::composites::*;
+            use $(self.root)::$(self.package)::encoder::*;
+            use $(self.root)::$(self.package)::enums::*;
+            use $(self.root)::$(self.package)::sets::*;
+            $(nested_codec_imports(self.root, self.package, &message_path, !groups.is_empty(), !var_data.is_empty()))
             use std::convert::TryFrom;
             use super::$(name.to_case(Case::ScreamingSnake))_ID;
-            use crate::$(self.package)::{SCHEMA_ID, SCHEMA_VERSION};
+            use $(self.root)::$(self.package)::{SCHEMA_ID, SCHEMA_VERSION};
 
             #[derive(Debug)]
             pub struct $(&encoder_name)<'a> {
@@ -94,7 +108,7 @@ impl RustMessageEncoderGenerator<'_> {
 
                 #[inline]
                 pub const fn block_length() -> usize {
-                    $(field_offset - header_offset)
+                    $block_length
                 }
 
                 #[inline]
@@ -128,10 +142,11 @@ impl RustMessageEncoderGenerator<'_> {
 
                     msg.message_header_encoder(|encoder| {
                         encoder.block_length(Self::block_length() as _)?;
-                        encoder.template_id(Self::id())?;
-                        encoder.schema_id(SCHEMA_ID)?;
-                        encoder.version(SCHEMA_VERSION)
+                        encoder.template_id(Self::id() as _)?;
+                        encoder.schema_id(SCHEMA_ID as _)?;
+                        encoder.version(SCHEMA_VERSION as _)$(for (field, count) in &header_counts => ?;$['\r']encoder.$(field.to_case(Case::Snake))($(*count)))
                     })?;
+                    msg.buffer.extend_to($(header_offset) + Self::block_length())?;
 
                     Ok(msg)
                 }
@@ -144,7 +159,9 @@ impl RustMessageEncoderGenerator<'_> {
             config: self.config,
             path: self.path,
             types: self.types,
+            root: self.root,
             package: self.package,
+            bounds_checks: self.bounds_checks,
         };
 
         let message_header_type = Type::Composite(self.types.header_type.clone());
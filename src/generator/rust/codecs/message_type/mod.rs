@@ -1,5 +1,6 @@
 use crate::generator::rust::codecs::message_type::decoder::RustMessageDecoderGenerator;
 use crate::generator::rust::codecs::message_type::encoder::RustMessageEncoderGenerator;
+use crate::generator::rust::codecs::message_type::owned::RustMessageOwnedGenerator;
 use crate::generator::rust::constants::MESSAGE_MODULE_NAME;
 use crate::generator::rust::module::ModuleGenerator;
 use crate::generator::write_file;
@@ -12,7 +13,9 @@ use std::fs::create_dir_all;
 use std::path::Path;
 
 pub mod decoder;
+pub mod dispatcher;
 pub mod encoder;
+pub mod owned;
 
 impl ModuleGenerator<'_> {
     fn message_encoder_generator<'a>(
@@ -23,7 +26,9 @@ impl ModuleGenerator<'_> {
             config: &self.config,
             path: module_path,
             types: &self.schema.types,
+            root: &self.root,
             package: &self.schema.package,
+            bounds_checks: self.bounds_checks,
         }
     }
 
@@ -35,10 +40,25 @@ impl ModuleGenerator<'_> {
             config: &self.config,
             path: module_path,
             types: &self.schema.types,
+            root: &self.root,
             package: &self.schema.package,
         }
     }
 
+    fn message_owned_generator<'a>(
+        &'a self,
+        module_path: &'a Path,
+    ) -> RustMessageOwnedGenerator<'a> {
+        RustMessageOwnedGenerator {
+            config: &self.config,
+            path: module_path,
+            types: &self.schema.types,
+            root: &self.root,
+            package: &self.schema.package,
+            serde: self.serde,
+        }
+    }
+
     fn write_message_codec(&self, module_path: &Path, message_type: &MessageType) -> Result<()> {
         let name = message_type.name.as_str();
         let module_path = module_path.join(name.to_case(Case::Snake));
@@ -46,19 +66,23 @@ impl ModuleGenerator<'_> {
 
         let encoder_generator = self.message_encoder_generator(&module_path);
         let decoder_generator = self.message_decoder_generator(&module_path);
+        let owned_generator = self.message_owned_generator(&module_path);
 
         let composite_tokens: Tokens<Rust> = quote! {
             mod decoder;
             mod encoder;
+            mod owned;
 
             pub use self::decoder::$(name.to_case(Case::UpperCamel))Decoder;
             pub use self::encoder::$(name.to_case(Case::UpperCamel))Encoder;
+            pub use self::owned::$(name.to_case(Case::UpperCamel));
 
             pub const $(name.to_case(Case::ScreamingSnake))_ID: u16 = $(message_type.id);
         };
 
         encoder_generator.write_encoder(message_type)?;
         decoder_generator.write_decoder(message_type)?;
+        owned_generator.write_owned(message_type)?;
 
         write_file(&module_path.join("mod.rs"), &self.config, composite_tokens)?;
 
@@ -81,6 +105,23 @@ impl ModuleGenerator<'_> {
             module_tokens.push();
         }
 
+        let message_types = self
+            .schema
+            .message_types
+            .message_types
+            .values()
+            .collect::<Vec<_>>();
+
+        if !message_types.is_empty() {
+            self.write_message_dispatcher(&module_path, &message_types)?;
+
+            module_tokens.append(quote! {
+                mod dispatcher;
+                pub use self::dispatcher::{AnyMessageDecoder, MessageVisitor};
+            });
+            module_tokens.push();
+        }
+
         write_file(&module_path.join("mod.rs"), &self.config, module_tokens)?;
 
         Ok(())
@@ -0,0 +1,80 @@
+use crate::generator::common::field_groups;
+use crate::generator::rust::codecs::group_type::owned::RustGroupOwnedGenerator;
+use crate::generator::rust::codecs::nested_codec_imports;
+use crate::generator::rust::constants::OWNED_FILE_NAME;
+use crate::generator::rust::module::serde_derive;
+use crate::generator::write_file;
+use crate::models::message::MessageType;
+use crate::models::{FieldPath, TypeMap};
+use anyhow::Result;
+use convert_case::{Case, Casing};
+use genco::prelude::*;
+use genco::tokens::FormatInto;
+use std::path::Path;
+
+pub struct RustMessageOwnedGenerator<'a> {
+    pub(crate) config: &'a rust::Config,
+    pub(crate) path: &'a Path,
+    pub(crate) types: &'a TypeMap,
+    pub(crate) root: &'a str,
+    pub(crate) package: &'a str,
+    pub(crate) serde: bool,
+}
+
+impl RustMessageOwnedGenerator<'_> {
+    pub fn write_owned(&self, message: &MessageType) -> Result<()> {
+        let owned_tokens: Tokens<Rust> = quote!($(self.generate_message_owned(message)?));
+
+        write_file(&self.path.join(OWNED_FILE_NAME), self.config, owned_tokens)?;
+
+        Ok(())
+    }
+
+    fn generate_message_owned(&self, message: &MessageType) -> Result<impl FormatInto<Rust>> {
+        let name = message.name.to_case(Case::UpperCamel);
+        let decoder_name = format!("{}Decoder", name);
+        let encoder_name = format!("{}Encoder", name);
+        let (fields, groups, var_data) = field_groups(&message.fields);
+        let message_path = FieldPath::new(vec![message.name.clone()]);
+
+        let group_owned_gen = RustGroupOwnedGenerator {
+            config: self.config,
+            path: self.path,
+            types: self.types,
+            root: self.root,
+            package: self.package,
+            serde: self.serde,
+        };
+
+        Ok(quote! {
+            use $(self.root)::error::*;
+            use $(self.root)::$(self.package)::composites::*;
+            use $(self.root)::$(self.package)::enums::*;
+            use $(self.root)::$(self.package)::sets::*;
+            $(nested_codec_imports(self.root, self.package, &message_path, !groups.is_empty(), false))
+            use super::{$(&decoder_name), $(&encoder_name)};
+
+            #[derive(Debug, Clone, PartialEq)]
+            $(serde_derive(self.serde))
+            pub struct $(&name) {
+                $(group_owned_gen.generate_struct_fields(&fields, &groups, &var_data)?)
+            }
+
+            impl $(&name) {
+                #[inline]
+                pub fn decode_from(decoder: &mut $(&decoder_name)<'_>) -> Result<Self> {
+                    Ok(Self {
+                        $(group_owned_gen.generate_decode_fields(&fields, &groups, &var_data)?)
+                    })
+                }
+
+                #[inline]
+                pub fn encode_into(&self, encoder: &mut $(&encoder_name)<'_>) -> Result<()> {
+                    $(group_owned_gen.generate_encode_fields(&fields, &groups, &var_data)?)
+
+                    Ok(())
+                }
+            }
+        })
+    }
+}
@@ -4,3 +4,28 @@ mod group_type;
 mod message_type;
 mod set_type;
 mod var_data_type;
+
+use crate::generator::common::scoped_module_path;
+use crate::models::FieldPath;
+use genco::prelude::*;
+
+/// Generates the imports of the codecs of the groups and var data declared directly
+/// in the message or group at `path`.
+pub(crate) fn nested_codec_imports(
+    root: &str,
+    package: &str,
+    path: &FieldPath,
+    has_groups: bool,
+    has_var_data: bool,
+) -> Tokens<Rust> {
+    let module_path = scoped_module_path(path);
+
+    quote! {
+        $(if has_groups {
+            use $root::$package::groups::$(&module_path)::*;
+        })
+        $(if has_var_data {
+            use $root::$package::var_data::$(&module_path)::*;
+        })
+    }
+}
@@ -32,15 +32,64 @@ impl ModuleGenerator<'_> {
         }
     }
 
+    fn generate_serde_flags(name: &str, flags_name: &str, choices: &[Choice]) -> Tokens<Rust> {
+        quote! {
+            #[cfg(feature = "serde")]
+            #[derive(Debug, Default, Clone, Copy, serde::Serialize, serde::Deserialize)]
+            #[serde(default)]
+            struct $flags_name {
+                $(for choice in choices {
+                    $['\r']
+                    $(choice.name.to_case(Case::Snake)): bool,
+                })
+            }
+
+            #[cfg(feature = "serde")]
+            impl From<$flags_name> for $name {
+                #[inline]
+                fn from(flags: $flags_name) -> Self {
+                    let mut value = Self::default();
+                    $(for choice in choices {
+                        $['\r']
+                        value.set_$(choice.name.to_case(Case::Snake))(flags.$(choice.name.to_case(Case::Snake)));
+                    })
+                    value
+                }
+            }
+
+            #[cfg(feature = "serde")]
+            impl From<$name> for $flags_name {
+                #[inline]
+                fn from(value: $name) -> Self {
+                    Self {
+                        $(for choice in choices {
+                            $['\r']
+                            $(choice.name.to_case(Case::Snake)): value.get_$(choice.name.to_case(Case::Snake))(),
+                        })
+                    }
+                }
+            }
+        }
+    }
+
     fn write_set_codec(&self, module_path: &Path, set_type: &SetType) -> Result<()> {
         let name = set_type.name.to_case(Case::UpperCamel);
         let choices = set_type.choices.as_slice();
         let rust_type = set_type
             .encoding_type
             .lang_primitive(&self.schema.types.encoded_types)?;
+        // Sets are (de)serialized through a struct with a named flag per choice
+        let flags_name = format!("{}Flags", name);
 
         let set_tokens: Tokens<Rust> = quote! {
             #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+            $(if self.serde {
+                #[cfg_attr(
+                    feature = "serde",
+                    derive(serde::Serialize, serde::Deserialize),
+                    serde(from = $(quoted(&flags_name)), into = $(quoted(&flags_name)))
+                )]
+            })
             pub struct $(&name)(pub $(rust_type));
 
             impl $(&name) {
@@ -52,6 +101,10 @@ impl ModuleGenerator<'_> {
 
                 $(for choice in choices => $(Self::generate_choice_token(choice)))
             }
+
+            $(if self.serde {
+                $(Self::generate_serde_flags(&name, &flags_name, choices))
+            })
         };
 
         let file_path = module_path.join(format!("{}.rs", name.to_case(Case::Snake)));
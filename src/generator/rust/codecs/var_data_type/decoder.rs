@@ -12,6 +12,7 @@ pub struct RustVariableDataDecoderGenerator<'a> {
     pub(crate) config: &'a rust::Config,
     pub(crate) path: &'a Path,
     pub(crate) types: &'a TypeMap,
+    pub(crate) root: &'a str,
     pub(crate) package: &'a str,
 }
 
@@ -46,9 +47,9 @@ impl RustVariableDataDecoderGenerator<'_> {
         let value_type_size = value_type_metadata.type_size;
 
         Ok(quote! {
-            use crate::error::*;
-            use crate::$(self.package)::composites::*;
-            use crate::$(self.package)::decoder::*;
+            use $(self.root)::error::*;
+            use $(self.root)::$(self.package)::composites::*;
+            use $(self.root)::$(self.package)::decoder::*;
             use std::convert::TryFrom;
 
             #[derive(Debug, Default)]
@@ -76,6 +77,16 @@ impl RustVariableDataDecoderGenerator<'_> {
             }
 
             impl $(&decoder_name)<'_> {
+                /// Creates a decoder of var data, which is not present in the decoded data,
+                /// because it was added in a newer version of the schema, so it reads as empty.
+                #[inline]
+                pub fn empty() -> Self {
+                    Self {
+                        buffer: ReadBuf::new(&[0u8; $length_type_size]),
+                        index: 0,
+                    }
+                }
+
                 #[inline]
                 pub fn length(&self) -> usize {
                     // TODO: Max length check
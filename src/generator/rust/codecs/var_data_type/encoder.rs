@@ -13,6 +13,7 @@ pub struct RustVariableDataEncoderGenerator<'a> {
     pub(crate) config: &'a rust::Config,
     pub(crate) path: &'a Path,
     pub(crate) types: &'a TypeMap,
+    pub(crate) root: &'a str,
     pub(crate) package: &'a str,
 }
 
@@ -48,9 +49,9 @@ impl RustVariableDataEncoderGenerator<'_> {
         let value_type_size = value_type_metadata.type_size;
 
         Ok(quote!(
-            use crate::error::*;
-            use crate::$(self.package)::composites::*;
-            use crate::$(self.package)::encoder::*;
+            use $(self.root)::error::*;
+            use $(self.root)::$(self.package)::composites::*;
+            use $(self.root)::$(self.package)::encoder::*;
             use std::convert::TryFrom;
             use std::ops::Index;
 
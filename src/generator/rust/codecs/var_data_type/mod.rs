@@ -1,13 +1,12 @@
-use crate::generator::common::FieldMetadata;
+use crate::generator::common::{field_groups, scoped_module_dir, FieldMetadata};
 use crate::generator::rust::codecs::var_data_type::decoder::RustVariableDataDecoderGenerator;
 use crate::generator::rust::codecs::var_data_type::encoder::RustVariableDataEncoderGenerator;
 use crate::generator::rust::constants::VAR_DATA_MODULE_NAME;
 use crate::generator::rust::module::ModuleGenerator;
 use crate::generator::write_file;
 use crate::models::types::composite_type::CompositeType;
-use crate::models::types::variable_data_type::VariableDataType;
-use crate::models::types::Type;
-use crate::models::TypeMap;
+use crate::models::types::{MessageField, Type};
+use crate::models::{FieldPath, TypeMap};
 use anyhow::{anyhow, Result};
 use convert_case::{Case, Casing};
 use genco::prelude::*;
@@ -26,6 +25,7 @@ impl ModuleGenerator<'_> {
             config: &self.config,
             path: module_path,
             types: &self.schema.types,
+            root: &self.root,
             package: &self.schema.package,
         }
     }
@@ -38,17 +38,15 @@ impl ModuleGenerator<'_> {
             config: &self.config,
             path: module_path,
             types: &self.schema.types,
+            root: &self.root,
             package: &self.schema.package,
         }
     }
 
-    fn write_var_data_codec(
-        &self,
-        module_path: &Path,
-        var_data_type: &VariableDataType,
-    ) -> Result<()> {
+    fn write_var_data_codec(&self, root_path: &Path, var_data_path: &FieldPath) -> Result<()> {
+        let var_data_type = &self.schema.message_types.variable_data_types[var_data_path];
         let name = var_data_type.name.as_str();
-        let module_path = module_path.join(name.to_case(Case::Snake));
+        let module_path = scoped_module_dir(root_path, var_data_path);
         create_dir_all(&module_path)?;
 
         let encoder_generator = self.var_data_encoder_generator(&module_path);
@@ -70,22 +68,74 @@ impl ModuleGenerator<'_> {
         Ok(())
     }
 
+    /// Writes the module scoping the var data codecs of a message or group, which declares
+    /// its var data and the scopes of its nested groups containing var data.
+    fn write_var_data_scope(
+        &self,
+        root_path: &Path,
+        path: &FieldPath,
+        fields: &[MessageField],
+    ) -> Result<()> {
+        let module_path = scoped_module_dir(root_path, path);
+        create_dir_all(&module_path)?;
+
+        let (_, groups, var_data) = field_groups(fields);
+        let mut module_tokens: Tokens<Rust> = quote!();
+
+        for var_data_type in var_data {
+            let module_name = var_data_type.name.to_case(Case::Snake);
+            let type_name = var_data_type.name.to_case(Case::UpperCamel);
+
+            module_tokens.append(quote! {
+                pub mod $(&module_name);
+                pub use self::$(&module_name)::{$(&type_name)Decoder, $(&type_name)Encoder};
+            });
+            module_tokens.push();
+        }
+
+        for group_type in groups {
+            let group_path = path.join(&group_type.name);
+            if !self.schema.message_types.has_variable_data(&group_path) {
+                continue;
+            }
+
+            self.write_var_data_scope(root_path, &group_path, &group_type.fields)?;
+
+            module_tokens.append(quote! {
+                pub mod $(group_type.name.to_case(Case::Snake));
+            });
+            module_tokens.push();
+        }
+
+        write_file(&module_path.join("mod.rs"), &self.config, module_tokens)
+    }
+
+    /// Writes the codecs of the var data of every message, scoped by the message and the
+    /// enclosing groups like the group codecs.
     pub fn write_var_data_codecs(&self) -> Result<()> {
         let module_path = self.path.join(VAR_DATA_MODULE_NAME);
         create_dir_all(&module_path)?;
 
         let mut module_tokens: Tokens<Rust> = quote!();
 
-        for (name, var_data_type) in &self.schema.message_types.variable_data_types {
-            self.write_var_data_codec(&module_path, var_data_type)?;
+        for message_type in self.schema.message_types.message_types.values() {
+            let message_path = FieldPath::new(vec![message_type.name.clone()]);
+            if !self.schema.message_types.has_variable_data(&message_path) {
+                continue;
+            }
+
+            self.write_var_data_scope(&module_path, &message_path, &message_type.fields)?;
 
             module_tokens.append(quote! {
-                pub mod $(name.to_case(Case::Snake));
-                pub use self::$(name.to_case(Case::Snake))::*;
+                pub mod $(message_type.name.to_case(Case::Snake));
             });
             module_tokens.push();
         }
 
+        for var_data_path in self.schema.message_types.variable_data_types.keys() {
+            self.write_var_data_codec(&module_path, var_data_path)?;
+        }
+
         write_file(&module_path.join("mod.rs"), &self.config, module_tokens)?;
 
         Ok(())
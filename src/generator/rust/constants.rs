@@ -6,3 +6,5 @@ pub const GROUP_MODULE_NAME: &str = "groups";
 pub const VAR_DATA_MODULE_NAME: &str = "var_data";
 pub const DECODER_FILE_NAME: &str = "decoder.rs";
 pub const ENCODER_FILE_NAME: &str = "encoder.rs";
+pub const OWNED_FILE_NAME: &str = "owned.rs";
+pub const DISPATCHER_FILE_NAME: &str = "dispatcher.rs";
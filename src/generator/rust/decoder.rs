@@ -14,17 +14,21 @@ impl ModuleGenerator<'_> {
 
         let decoder_tokens: Tokens<Rust> = quote! {
             use std::convert::TryInto;
-            use crate::error::*;
+            use $(&self.root)::error::*;
 
-            #[derive(Debug, Default)]
+            #[derive(Debug, Default, Clone, Copy)]
             pub struct ReadBuf<'a> {
                 data: &'a [u8],
             }
 
             impl<'a> ReadBuf<'a> {
+                /// Creates a buffer decoding the memory in place, without copying it, e.g. a
+                /// slice of a network ring buffer, a memory mapped file or `bytes::Bytes`.
                 #[inline]
-                pub fn new(data: &'a [u8]) -> Self {
-                    Self { data }
+                pub fn new<B: AsRef<[u8]> + ?Sized>(data: &'a B) -> Self {
+                    Self {
+                        data: data.as_ref(),
+                    }
                 }
 
                 #[inline]
@@ -112,7 +116,7 @@ impl ModuleGenerator<'_> {
 
                 #[inline]
                 pub fn split_at(&self, index: usize) -> Result<(Self, Self)> {
-                    if index >= self.data.len() {
+                    if index > self.data.len() {
                         return Err(SbeError::CodecOutOfBounds(index, self.data.len()));
                     }
 
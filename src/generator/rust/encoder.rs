@@ -13,27 +13,101 @@ impl ModuleGenerator<'_> {
         };
 
         let encoder_tokens: Tokens<Rust> = quote! {
-            use crate::error::*;
+            use std::fmt::{Debug, Formatter};
+            use $(&self.root)::error::*;
 
-            #[derive(Debug, Default)]
+            /// Memory which grows as the data is written, e.g. a vector or `bytes::BytesMut`.
+            trait Growable {
+                fn grow_to(&mut self, len: usize) -> &mut [u8];
+            }
+
+            impl<B: AsMut<[u8]> + Extend<u8>> Growable for B {
+                #[inline]
+                fn grow_to(&mut self, len: usize) -> &mut [u8] {
+                    let data_len = self.as_mut().len();
+                    if len > data_len {
+                        self.extend(std::iter::repeat(0).take(len - data_len));
+                    }
+
+                    self.as_mut()
+                }
+            }
+
+            enum WriteData<'a> {
+                Slice(&'a mut [u8]),
+                Growable(&'a mut dyn Growable),
+            }
+
+            impl Debug for WriteData<'_> {
+                fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+                    match self {
+                        WriteData::Slice(data) => f.debug_tuple("Slice").field(data).finish(),
+                        WriteData::Growable(_) => f.debug_tuple("Growable").finish_non_exhaustive(),
+                    }
+                }
+            }
+
+            #[derive(Debug)]
             pub struct WriteBuf<'a> {
-                data: &'a mut [u8],
+                data: WriteData<'a>,
+                offset: usize,
+            }
+
+            impl Default for WriteBuf<'_> {
+                fn default() -> Self {
+                    WriteBuf::new(&mut [])
+                }
             }
 
             impl<'a> WriteBuf<'a> {
-                pub fn new(data: &mut [u8]) -> WriteBuf {
-                    WriteBuf { data }
+                pub fn new(data: &mut [u8]) -> WriteBuf<'_> {
+                    WriteBuf {
+                        data: WriteData::Slice(data),
+                        offset: 0,
+                    }
                 }
 
-                #[inline]
-                pub fn put_bytes_at(&mut self, index: usize, bytes: &[u8]) -> Result<()> {
-                    let data_end = index + bytes.len();
+                /// Creates a buffer appending to the memory, e.g. a vector or `bytes::BytesMut`,
+                /// which grows as the data is written, so the encoded message is never out of
+                /// bounds.
+                pub fn growable<B: AsMut<[u8]> + Extend<u8>>(data: &'a mut B) -> Self {
+                    let offset = data.as_mut().len();
+                    WriteBuf {
+                        data: WriteData::Growable(data),
+                        offset,
+                    }
+                }
 
-                    if data_end > self.data.len() {
-                        return Err(SbeError::CodecOutOfBounds(data_end, self.data.len()));
+                #[inline]
+                fn data(&mut self, data_end: usize) -> Result<&mut [u8]> {
+                    let data: &mut [u8] = match &mut self.data {
+                        WriteData::Slice(data) => data,
+                        WriteData::Growable(data) => data.grow_to(data_end),
+                    };
+
+                    if data_end > data.len() {
+                        return Err(SbeError::CodecOutOfBounds(data_end, data.len()));
                     }
 
-                    self.data[index..data_end].copy_from_slice(bytes);
+                    Ok(data)
+                }
+
+                /// Makes sure the buffer holds the bytes up to the index, so the padding after
+                /// the last written field is part of the encoded message.
+                #[inline]
+                pub fn extend_to(&mut self, index: usize) -> Result<()> {
+                    let data_end = self.offset + index;
+                    self.data(data_end)?;
+
+                    Ok(())
+                }
+
+                #[inline]
+                pub fn put_bytes_at(&mut self, index: usize, bytes: &[u8]) -> Result<()> {
+                    let data_start = self.offset + index;
+                    let data_end = data_start + bytes.len();
+
+                    self.data(data_end)?[data_start..data_end].copy_from_slice(bytes);
 
                     Ok(())
                 }
@@ -88,29 +162,19 @@ impl ModuleGenerator<'_> {
                     self.put_bytes_at(index, &f64::$byte_order_conversion(value))
                 }
 
+                /// Borrows the part of the buffer starting at the index, in which a nested
+                /// composite, group or var data is encoded.
                 #[inline]
-                pub fn split_at_mut(&mut self, index: usize) -> Result<(WriteBuf, WriteBuf)> {
-                    // Self cannot be used in this method, as it inherits generics
-                    // (and most importantly, lifetimes).
-                    // Let self have lifetime 'a. Then the WriteBuf being returned has to have
-                    // lifetime 'b, where 'b <= 'a. If we used self, WriteBuf would be
-                    // implicitly WriteBuf<'a>, which would not work.
-                    if index >= self.data.len() {
-                        return Err(SbeError::CodecOutOfBounds(index, self.data.len()));
+                pub fn offset_mut(&mut self, index: usize) -> WriteBuf<'_> {
+                    let data = match &mut self.data {
+                        WriteData::Slice(data) => WriteData::Slice(data),
+                        WriteData::Growable(data) => WriteData::Growable(&mut **data),
+                    };
+
+                    WriteBuf {
+                        data,
+                        offset: self.offset + index,
                     }
-
-                    let (before, after) = self.data.split_at_mut(index);
-                    Ok((Self::new(before), Self::new(after)))
-                }
-
-                #[inline]
-                pub fn split_at_mut_owned(self, index: usize) -> Result<(Self, Self)> {
-                    if index >= self.data.len() {
-                        return Err(SbeError::CodecOutOfBounds(index, self.data.len()));
-                    }
-
-                    let (before, after) = self.data.split_at_mut(index);
-                    Ok((Self::new(before), Self::new(after)))
                 }
             }
         };
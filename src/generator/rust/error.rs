@@ -35,8 +35,12 @@ impl RustGenerator {
                 GroupOutOfBounds(&'static str),
                 #[error("var data out of bounds: {0}")]
                 VarDataOutOfBounds(&'static str),
+                #[error("received message had wrong schema: {0}, expected {1}")]
+                WrongSchema(u64, u64),
                 #[error("received message had wrong type: {0}, expected {1}")]
                 WrongMessageType(u16, u16),
+                #[error("received message had unknown type: {0}")]
+                UnknownMessageType(u16),
                 #[error("codec out of bounds: {0} > {1}")]
                 CodecOutOfBounds(usize, usize),
             }
@@ -57,7 +61,7 @@ impl RustGenerator {
         };
 
         write_file(
-            &self.path.join("src/error.rs"),
+            &self.source_path().join("error.rs"),
             &self.config,
             error_module_content,
         )
@@ -8,9 +8,10 @@ mod module;
 use crate::generator::{write_file, CodeGenerator};
 use crate::models::schema::ValidatedMessageSchema;
 use anyhow::Result;
+use convert_case::{Case, Casing};
 use indoc::formatdoc;
-use std::collections::HashMap;
-use std::fs::{create_dir_all, File};
+use std::collections::BTreeMap;
+use std::fs::{create_dir_all, read_to_string, remove_dir_all, File};
 use std::io::Write;
 use std::path::{Path, PathBuf};
 use std::process::{Command, ExitStatus};
@@ -22,12 +23,26 @@ use crate::models::types::primitive_type::{
 };
 use genco::prelude::*;
 
+/// Layout of the generated codecs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RustLayout {
+    /// A Cargo project of its own, which is optionally the root of a workspace.
+    Crate { workspace_root: bool },
+    /// A single file with the module tree of the codecs and their errors, which an existing
+    /// crate mounts at `path`, e.g. `crate::example` for `example.rs`. The crate has to
+    /// depend on `thiserror`, and on `serde` for the serde derives, which are gated behind a
+    /// `serde` feature the crate declares itself.
+    Module { path: String },
+}
+
 pub struct RustGenerator {
     config: rust::Config,
     path: PathBuf,
     project_name: String,
     project_version: String,
-    workspace_root: bool,
+    layout: RustLayout,
+    bounds_checks: bool,
+    serde: bool,
     schemas: Vec<ValidatedMessageSchema>,
 }
 
@@ -37,51 +52,95 @@ impl RustGenerator {
         project_name: &str,
         project_version: &str,
         schemas: Vec<ValidatedMessageSchema>,
-        workspace_root: bool,
+        layout: RustLayout,
+        bounds_checks: bool,
+        serde: bool,
     ) -> Self {
         Self {
             config: rust::Config::default().with_default_import(rust::ImportMode::Direct),
             path: path.to_owned(),
             project_name: project_name.to_owned(),
             project_version: project_version.to_owned(),
-            workspace_root,
+            layout,
+            bounds_checks,
+            serde,
             schemas,
         }
     }
 
+    /// Path of the module containing the error module and the module of every schema.
+    fn root(&self) -> &str {
+        match &self.layout {
+            RustLayout::Crate { .. } => "crate",
+            RustLayout::Module { path } => path,
+        }
+    }
+
+    /// Name of the single file of the module layout, which is the last segment of its path.
+    fn module_name(&self) -> String {
+        let name = self.root().rsplit("::").next().unwrap_or_default();
+
+        match name {
+            "crate" | "self" | "super" => self.project_name.to_case(Case::Snake),
+            _ => name.to_owned(),
+        }
+    }
+
+    /// Directory of the root module, which is only staged next to the single file in the
+    /// module layout before being inlined into it.
+    fn source_path(&self) -> PathBuf {
+        match &self.layout {
+            RustLayout::Crate { .. } => self.path.join("src"),
+            RustLayout::Module { .. } => self.path.join(format!(".{}.staging", self.module_name())),
+        }
+    }
+
     fn write_project_files(&self, with_test_dependencies: bool) -> Result<()> {
-        create_dir_all(self.path.join("src"))?;
+        create_dir_all(self.source_path())?;
 
         let test_dependencies = formatdoc! {"
             [dev-dependencies]
             serde_json = \"^1.0\"
+            bytes = \"^1.0\"
             rstest = \"^0.23.0\"
             proptest = \"^1.4.0\"
             criterion = \"^0.5.1\"
             time = {{ version = \"^0.3\", features = [\"parsing\"] }}
         "};
 
+        let serde_dependency = formatdoc! {"
+            serde = {{ version = \"^1.0\", features = [\"derive\"], optional = true }}
+
+            [features]
+            serde = [\"dep:serde\"]
+        "};
+
         let cargo_toml_content = formatdoc! {"
                 [package]
                 name = \"{name}\"
                 version = \"{version}\"
-                authors = [\"Second Foundation\"]
                 edition = \"2021\"
 
                 {workspace}
 
                 [dependencies]
                 thiserror = \"^1.0\"
+                {serde_deps}
 
                 {test_deps}
             ",
             name = self.project_name,
             version = self.project_version,
-            workspace = if self.workspace_root {
+            workspace = if self.layout == (RustLayout::Crate { workspace_root: true }) {
                 "[workspace]".to_owned()
             } else {
                 String::new()
             },
+            serde_deps = if self.serde {
+                serde_dependency
+            } else {
+                String::new()
+            },
             test_deps = if with_test_dependencies {
                 test_dependencies
             } else {
@@ -93,26 +152,88 @@ impl RustGenerator {
         let mut cargo_toml_file = File::create(cargo_toml_path)?;
         cargo_toml_file.write_all(cargo_toml_content.as_bytes())?;
 
-        self.write_lib_rs()?;
+        self.write_root_module("lib.rs")?;
         self.write_error_module()
     }
 
-    fn write_lib_rs(&self) -> Result<()> {
-        let lib_rs_content: Tokens<Rust> = quote! {
+    fn write_module_files(&self) -> Result<()> {
+        let source_path = self.source_path();
+
+        if source_path.exists() {
+            remove_dir_all(&source_path)?;
+        }
+        create_dir_all(&source_path)?;
+
+        self.write_root_module("mod.rs")?;
+        self.write_error_module()
+    }
+
+    /// Inlines the staged module tree into a single file.
+    fn write_module_file(&self) -> Result<()> {
+        let source_path = self.source_path();
+        let module_content = inline_modules(&source_path.join("mod.rs"), &source_path)?;
+
+        let module_path = self.path.join(format!("{}.rs", self.module_name()));
+        let mut module_file = File::create(module_path)?;
+        module_file.write_all(module_content.as_bytes())?;
+
+        Ok(())
+    }
+
+    fn write_schema_modules(&self) -> Result<()> {
+        for schema in &self.schemas {
+            let module_generator = module::ModuleGenerator::new(
+                &self.source_path().join(&schema.package),
+                self.root(),
+                schema,
+                self.bounds_checks,
+                self.serde,
+            );
+
+            module_generator.generate_module()?;
+        }
+
+        Ok(())
+    }
+
+    fn write_root_module(&self, file_name: &str) -> Result<()> {
+        // The imports shared by every file of a schema are not all used in each of them, which
+        // the crate layout fixes with clippy, and the crate mounting the module privately does
+        // not use all the codecs, so neither warns in the crate
+        let allow: Tokens<Rust> = match self.layout {
+            RustLayout::Crate { .. } => quote!(),
+            RustLayout::Module { .. } => quote!(#[allow(dead_code, unused_imports)]),
+        };
+
+        let root_module_content: Tokens<Rust> = quote! {
+            $(&allow)
             pub mod error;
 
             $(for schema in &self.schemas {
                 $['\r']
+                $(&allow)
                 pub mod $(&schema.package);
             })
         };
 
-        write_file(&self.path.join("src/lib.rs"), &self.config, lib_rs_content)
+        write_file(
+            &self.source_path().join(file_name),
+            &self.config,
+            root_module_content,
+        )
     }
 
     fn format_project(&self) {
         // It was decided to ignore the possible failure of formatting.
         // If user did not install Rust, this will fail, but it is not critical.
+        if let RustLayout::Module { .. } = self.layout {
+            if self.run_rustfmt().is_err() {
+                eprintln!("Failed to format module. Do you have rustfmt installed?");
+            }
+
+            return;
+        }
+
         let clippy_result = self.run_cargo_clippy();
         let fmt_result = self.run_cargo_fmt();
 
@@ -134,6 +255,21 @@ impl RustGenerator {
         Ok(())
     }
 
+    fn run_rustfmt(&self) -> Result<()> {
+        let fmt_status = Command::new("rustfmt")
+            .arg("--edition")
+            .arg("2021")
+            .arg(format!("{}.rs", self.module_name()))
+            .current_dir(&self.path)
+            .status()?;
+
+        if !fmt_status.success() {
+            return Err(anyhow::anyhow!("rustfmt failed"));
+        }
+
+        Ok(())
+    }
+
     fn run_cargo_clippy(&self) -> Result<()> {
         // multiple passes of clippy are needed, first fix uncovers more errors to fix.
         let mut status = ExitStatus::default();
@@ -166,15 +302,23 @@ impl RustGenerator {
 
 impl CodeGenerator for RustGenerator {
     fn generate_project(&self, with_test_dependencies: bool, format_project: bool) -> Result<()> {
-        // Project files (Cargo.toml, ...)
-        self.write_project_files(with_test_dependencies)?;
+        match self.layout {
+            RustLayout::Crate { .. } => {
+                self.write_project_files(with_test_dependencies)?;
+                self.write_schema_modules()?;
+            }
+            RustLayout::Module { .. } => {
+                let result = self
+                    .write_module_files()
+                    .and_then(|_| self.write_schema_modules())
+                    .and_then(|_| self.write_module_file());
 
-        // Generate modules
-        for schema in &self.schemas {
-            let module_generator =
-                module::ModuleGenerator::new(&self.path.join("src").join(&schema.package), schema);
+                // The staged module tree is removed even if it could not be generated
+                let cleanup = remove_dir_all(self.source_path());
 
-            module_generator.generate_module()?;
+                result?;
+                cleanup?;
+            }
         }
 
         if format_project {
@@ -185,10 +329,52 @@ impl CodeGenerator for RustGenerator {
     }
 }
 
+/// Reads a module file and replaces every `mod` declaration in it by the inlined content of
+/// the declared module, which is read from `dir` like rustc does, i.e. from `dir/name.rs` or
+/// `dir/name/mod.rs`.
+fn inline_modules(file: &Path, dir: &Path) -> Result<String> {
+    let mut content = String::new();
+
+    for line in read_to_string(file)?.lines() {
+        let declaration = line.trim_start();
+        let indentation = &line[..line.len() - declaration.len()];
+        let module = declaration.strip_suffix(';').and_then(|declaration| {
+            ["pub mod ", "mod "].into_iter().find_map(|keyword| {
+                declaration
+                    .strip_prefix(keyword)
+                    .map(|name| (keyword, name))
+            })
+        });
+
+        let Some((keyword, name)) = module else {
+            content.push_str(line);
+            content.push('\n');
+            continue;
+        };
+
+        let module_dir = dir.join(name);
+        let module_file = match dir.join(format!("{}.rs", name)) {
+            module_file if module_file.is_file() => module_file,
+            _ => module_dir.join("mod.rs"),
+        };
+
+        content.push_str(&format!("{}{}{} {{\n", indentation, keyword, name));
+        for module_line in inline_modules(&module_file, &module_dir)?.lines() {
+            if !module_line.is_empty() {
+                content.push_str(&format!("{}    {}", indentation, module_line));
+            }
+            content.push('\n');
+        }
+        content.push_str(&format!("{}}}\n", indentation));
+    }
+
+    Ok(content)
+}
+
 impl PrimitiveConvertible<Rust> for NativeType {
     fn lang_primitive(
         &self,
-        encoded_types: &HashMap<String, EncodedDataType>,
+        encoded_types: &BTreeMap<String, EncodedDataType>,
     ) -> Result<LanguagePrimitive<Rust>> {
         let native_type = self.resolved(encoded_types)?;
 
@@ -10,15 +10,29 @@ use std::path::{Path, PathBuf};
 pub struct ModuleGenerator<'a> {
     pub(crate) config: rust::Config,
     pub(crate) path: PathBuf,
+    /// Path of the module containing the error module and the module of every schema, which
+    /// is `crate` unless the codecs are generated as a module of an existing crate.
+    pub(crate) root: String,
     pub(crate) schema: &'a ValidatedMessageSchema,
+    pub(crate) bounds_checks: bool,
+    pub(crate) serde: bool,
 }
 
 impl<'a> ModuleGenerator<'a> {
-    pub fn new(path: &Path, schema: &'a ValidatedMessageSchema) -> Self {
+    pub fn new(
+        path: &Path,
+        root: &str,
+        schema: &'a ValidatedMessageSchema,
+        bounds_checks: bool,
+        serde: bool,
+    ) -> Self {
         Self {
             config: rust::Config::default().with_default_import(rust::ImportMode::Direct),
             path: path.to_owned(),
+            root: root.to_owned(),
             schema,
+            bounds_checks,
+            serde,
         }
     }
 
@@ -59,3 +73,12 @@ impl<'a> ModuleGenerator<'a> {
         write_file(&self.path.join("mod.rs"), &self.config, mod_rs_content)
     }
 }
+
+/// Derives the serde traits of a generated type behind the `serde` feature of the generated crate.
+pub(crate) fn serde_derive(serde: bool) -> Tokens<Rust> {
+    quote! {
+        $(if serde {
+            #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+        })
+    }
+}
@@ -0,0 +1,155 @@
+use anyhow::Result;
+
+use crate::generator::typescript::constants::{
+    BUFFER_MODULE_NAME, READ_BUF_CLASS_NAME, WRITE_BUF_CLASS_NAME,
+};
+use crate::generator::typescript::module::ModuleGenerator;
+use crate::models::ByteOrder;
+use genco::prelude::*;
+
+/// Suffixes of the accessors of the buffers, along with the methods of `DataView` they use
+/// and the sizes of their values.
+pub(crate) const ACCESSORS: &[(&str, &str, usize)] = &[
+    ("U8", "Uint8", 1),
+    ("U16", "Uint16", 2),
+    ("U32", "Uint32", 4),
+    ("U64", "BigUint64", 8),
+    ("I8", "Int8", 1),
+    ("I16", "Int16", 2),
+    ("I32", "Int32", 4),
+    ("I64", "BigInt64", 8),
+    ("F32", "Float32", 4),
+    ("F64", "Float64", 8),
+];
+
+impl ModuleGenerator<'_> {
+    /// Writes the buffers the codecs read from and write to, views of the bytes of an
+    /// encoded message over a `DataView`, which read and write all values in the byte
+    /// order of the schema.
+    pub fn write_buffer_module(&self) -> Result<()> {
+        let error = &self.error_class();
+
+        let little_endian = match self.schema.byte_order {
+            ByteOrder::BigEndian => "false",
+            ByteOrder::LittleEndian => "true",
+        };
+
+        // Single byte values have no byte order
+        let byte_order = |size: usize| if size > 1 { ", LITTLE_ENDIAN" } else { "" };
+
+        let buffer_tokens: Tokens<JavaScript> = quote! {
+            $("/** Byte order of all values encoded with the schema, as the `littleEndian` flag of `DataView`. */")
+            const LITTLE_ENDIAN = $little_endian;
+
+            const UTF_8 = new TextDecoder("utf-8", { fatal: true });
+
+            $("/** Wraps the bytes of a buffer, or of a view of a buffer, without copying them. */")
+            function dataView(data) {
+                if (ArrayBuffer.isView(data)) {
+                    return new DataView(data.buffer, data.byteOffset, data.byteLength);
+                }
+
+                return new DataView(data);
+            }
+
+            $("/** Read-only view of the bytes of an encoded message, or a part of it. */")
+            export class $READ_BUF_CLASS_NAME {
+                #view;
+
+                constructor(data) {
+                    this.#view = dataView(data);
+                }
+
+                static empty() {
+                    return new $READ_BUF_CLASS_NAME(new ArrayBuffer(0));
+                }
+
+                length() {
+                    return this.#view.byteLength;
+                }
+
+                $("/** Returns the bytes from the index to the end of the buffer. */")
+                splitAt(index) {
+                    this.#checkBounds(index, 0);
+
+                    return new $READ_BUF_CLASS_NAME(new DataView(this.#view.buffer, this.#view.byteOffset + index, this.#view.byteLength - index));
+                }
+
+                #checkBounds(index, count) {
+                    const dataEnd = index + count;
+
+                    if (index < 0 || dataEnd > this.#view.byteLength) {
+                        throw $error.codecOutOfBounds(dataEnd, this.#view.byteLength);
+                    }
+                }
+
+                $("/** Returns the bytes as a view of the buffer, without copying them. */")
+                getSliceAt(index, len) {
+                    this.#checkBounds(index, len);
+
+                    return new Uint8Array(this.#view.buffer, this.#view.byteOffset + index, len);
+                }
+
+                $("/** Decodes a string, which can be either UTF-8 or ASCII, as any valid ASCII string is also a valid UTF-8 string. */")
+                getStringAt(index, len) {
+                    const src = this.getSliceAt(index, len);
+
+                    try {
+                        return UTF_8.decode(src);
+                    } catch (e) {
+                        throw $error.invalidStringValue(String(e));
+                    }
+                }
+                $(for (suffix, method, size) in ACCESSORS {
+                    $['\n']
+                    get$(*suffix)At(index) {
+                        this.#checkBounds(index, $(*size));
+                        return this.#view.get$(*method)(index$(byte_order(*size)));
+                    }
+                })
+            }
+
+            $("/** Writable view of the bytes of an encoded message, or a part of it. */")
+            export class $WRITE_BUF_CLASS_NAME {
+                #view;
+
+                constructor(data) {
+                    this.#view = dataView(data);
+                }
+
+                length() {
+                    return this.#view.byteLength;
+                }
+
+                $("/** Returns the bytes from the index to the end of the buffer. */")
+                splitAt(index) {
+                    this.#checkBounds(index, 0);
+
+                    return new $WRITE_BUF_CLASS_NAME(new DataView(this.#view.buffer, this.#view.byteOffset + index, this.#view.byteLength - index));
+                }
+
+                #checkBounds(index, count) {
+                    const dataEnd = index + count;
+
+                    if (index < 0 || dataEnd > this.#view.byteLength) {
+                        throw $error.codecOutOfBounds(dataEnd, this.#view.byteLength);
+                    }
+                }
+
+                putBytesAt(index, value) {
+                    this.#checkBounds(index, value.length);
+                    new Uint8Array(this.#view.buffer, this.#view.byteOffset + index, value.length).set(value);
+                }
+                $(for (suffix, method, size) in ACCESSORS {
+                    $['\n']
+                    put$(*suffix)At(index, value) {
+                        this.#checkBounds(index, $(*size));
+                        this.#view.set$(*method)(index, value$(byte_order(*size)));
+                    }
+                })
+            }
+        };
+
+        self.write_module(BUFFER_MODULE_NAME, buffer_tokens)
+    }
+}
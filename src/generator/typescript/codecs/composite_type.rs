@@ -0,0 +1,675 @@
+use crate::generator::common::FieldMetadata;
+use crate::generator::typescript::codecs::enum_type::enum_from_value;
+use crate::generator::typescript::codecs::{
+    accessor, bounds_checks, field_metadata, from_number, literal, null_value,
+    null_value_condition, to_number,
+};
+use crate::generator::typescript::constants::{
+    COMPOSITE_MODULE_NAME, ENUM_MODULE_NAME, SET_MODULE_NAME,
+};
+use crate::generator::typescript::module::ModuleGenerator;
+use crate::models::layout::BlockLayout;
+use crate::models::types::composite_type::CompositeType;
+use crate::models::types::encoded_data_type::EncodedDataType;
+use crate::models::types::enum_type::EnumType;
+use crate::models::types::primitive_type::{NativeType, ResolvableType};
+use crate::models::types::set_type::SetType;
+use crate::models::types::{CharacterEncoding, Presence, SizedEncoded, Type};
+use anyhow::{anyhow, Result};
+use convert_case::{Case, Casing};
+use genco::prelude::*;
+
+impl ModuleGenerator<'_> {
+    fn generate_composite_codec(
+        &self,
+        composite_type: &CompositeType,
+    ) -> Result<Tokens<JavaScript>> {
+        let name = composite_type.name.to_case(Case::UpperCamel);
+        let decoder_name = format!("{}Decoder", name);
+        let encoder_name = format!("{}Encoder", name);
+
+        let layout = BlockLayout::from_composite(composite_type, &self.schema.types)?;
+        let encoded_length = composite_type.size(&self.schema.types)?;
+
+        let mut decoder_fields: Tokens<JavaScript> = quote!();
+        let mut encoder_fields: Tokens<JavaScript> = quote!();
+
+        for (field, offset) in composite_type.fields.iter().zip(layout.offsets) {
+            decoder_fields.line();
+            decoder_fields.append(self.generate_decoder_field(
+                COMPOSITE_MODULE_NAME,
+                field.name(),
+                field,
+                offset,
+                0,
+            )?);
+            encoder_fields.line();
+            encoder_fields.append(self.generate_encoder_field(
+                COMPOSITE_MODULE_NAME,
+                field.name(),
+                field,
+                quote!($offset),
+            )?);
+        }
+
+        Ok(quote! {
+            export class $(&decoder_name) {
+                static ENCODED_LENGTH = $encoded_length;
+
+                #buffer;
+
+                constructor(buffer) {
+                    this.#buffer = buffer;
+                }
+                $decoder_fields
+            }
+
+            export class $(&encoder_name) {
+                static ENCODED_LENGTH = $encoded_length;
+
+                #buffer;
+
+                constructor(buffer) {
+                    this.#buffer = buffer;
+                }
+                $encoder_fields
+            }
+        })
+    }
+
+    pub fn write_composite_codecs(&self) -> Result<()> {
+        let mut composite_tokens: Tokens<JavaScript> = quote!();
+
+        for composite_type in self.schema.types.composite_types.values() {
+            composite_tokens.line();
+            composite_tokens.append(self.generate_composite_codec(composite_type)?);
+        }
+
+        self.write_module(COMPOSITE_MODULE_NAME, composite_tokens)
+    }
+
+    /// Resolves a reference to the referenced type.
+    pub(crate) fn resolved(&self, field: &Type) -> Result<Type> {
+        match field {
+            Type::Reference(reference_type) => self
+                .schema
+                .types
+                .find_type(&reference_type.type_name)
+                .ok_or(anyhow!(
+                    "Referenced type {} not found",
+                    reference_type.type_name
+                )),
+            field => Ok(field.clone()),
+        }
+    }
+
+    /// Generates the accessor for a field at the given offset, for a decoder in the `module`.
+    ///
+    /// A non-zero `since_version` means the field was added in that version of the schema.
+    /// The accessor then checks the `actingVersion` of the decoder (only message and group
+    /// decoders have one) and returns the null value of the field, if the field is not present
+    /// in the decoded data.
+    pub(crate) fn generate_decoder_field(
+        &self,
+        module: &str,
+        field_name: &str,
+        field: &Type,
+        offset: usize,
+        since_version: usize,
+    ) -> Result<Tokens<JavaScript>> {
+        Ok(match self.resolved(field)? {
+            Type::EncodedData(encoded_type) => self.generate_encoded_field_decoder(
+                field_name,
+                &encoded_type,
+                offset,
+                since_version,
+            )?,
+            Type::Enum(enum_type) => self.generate_enum_field_decoder(
+                module,
+                field_name,
+                &enum_type,
+                offset,
+                since_version,
+            )?,
+            Type::Set(set_type) => self.generate_set_field_decoder(
+                module,
+                field_name,
+                &set_type,
+                offset,
+                since_version,
+            )?,
+            Type::Composite(composite_type) => self.generate_composite_field_decoder(
+                module,
+                field_name,
+                &composite_type,
+                offset,
+                since_version,
+            ),
+            Type::Reference(reference_type) => {
+                unreachable!("Resolved reference: {}", reference_type.type_name)
+            }
+        })
+    }
+
+    fn generate_enum_field_decoder(
+        &self,
+        module: &str,
+        field_name: &str,
+        enum_type: &EnumType,
+        offset: usize,
+        since_version: usize,
+    ) -> Result<Tokens<JavaScript>> {
+        let enum_type_name = enum_type.name.to_case(Case::UpperCamel);
+        let enum_class = &self.import(module, ENUM_MODULE_NAME, &enum_type_name);
+        let encoding_type = enum_type
+            .encoding_type
+            .resolved(&self.schema.types.encoded_types)?;
+        let value = quote!(this.#buffer.get$(accessor(&encoding_type))At($offset));
+
+        Ok(quote! {
+            $(field_name.to_case(Case::Camel))() {
+                $(version_check(since_version, quote!($enum_class.NULL_VAL)))
+                return $(self.import(module, ENUM_MODULE_NAME, &enum_from_value(enum_type)))($(to_number(&encoding_type, value)));
+            }
+        })
+    }
+
+    fn generate_set_field_decoder(
+        &self,
+        module: &str,
+        field_name: &str,
+        set_type: &SetType,
+        offset: usize,
+        since_version: usize,
+    ) -> Result<Tokens<JavaScript>> {
+        let set_class = &self.import(
+            module,
+            SET_MODULE_NAME,
+            &set_type.name.to_case(Case::UpperCamel),
+        );
+        let encoding_type = set_type
+            .encoding_type
+            .resolved(&self.schema.types.encoded_types)?;
+
+        Ok(quote! {
+            $(field_name.to_case(Case::Camel))() {
+                $(version_check(since_version, quote!(new $set_class())))
+                return new $set_class(this.#buffer.get$(accessor(&encoding_type))At($offset));
+            }
+        })
+    }
+
+    fn generate_composite_field_decoder(
+        &self,
+        module: &str,
+        field_name: &str,
+        composite_type: &CompositeType,
+        offset: usize,
+        since_version: usize,
+    ) -> Tokens<JavaScript> {
+        let decoder_class = self.import(
+            module,
+            COMPOSITE_MODULE_NAME,
+            &format!("{}Decoder", composite_type.name.to_case(Case::UpperCamel)),
+        );
+
+        // Composites have no null value, so the result is null for the ones
+        // which might not be present in the decoded data.
+        quote! {
+            $(field_name.to_case(Case::Camel))Decoder(action) {
+                $(version_check(since_version, quote!(null)))
+                return action(new $decoder_class(this.#buffer.splitAt($offset)));
+            }
+        }
+    }
+
+    fn generate_encoded_field_decoder(
+        &self,
+        field_name: &str,
+        encoded_type: &EncodedDataType,
+        offset: usize,
+        since_version: usize,
+    ) -> Result<Tokens<JavaScript>> {
+        Ok(match encoded_type.presence {
+            Presence::Constant => self.generate_encoded_constant_field(field_name, encoded_type)?,
+            Presence::Required => self.generate_encoded_required_field_decoder(
+                field_name,
+                encoded_type,
+                offset,
+                since_version,
+            )?,
+            Presence::Optional => self.generate_encoded_optional_field_decoder(
+                field_name,
+                encoded_type,
+                offset,
+                since_version,
+            )?,
+        })
+    }
+
+    fn generate_encoded_constant_field(
+        &self,
+        field_name: &str,
+        encoded_type: &EncodedDataType,
+    ) -> Result<Tokens<JavaScript>> {
+        let metadata = field_metadata(field_name, encoded_type, &self.schema.types)?;
+
+        let value = encoded_type.default_value.as_ref().ok_or(anyhow!(
+            "Constant field {} has no default value",
+            metadata.field_name
+        ))?;
+
+        Ok(match metadata.field_primitive_type {
+            NativeType::Char => quote! {
+                $(&metadata.field_name)() {
+                    return $(quoted(value));
+                }
+            },
+            _ => quote! {
+                $(&metadata.field_name)() {
+                    return $(literal(value, &metadata.field_primitive_type));
+                }
+            },
+        })
+    }
+
+    fn generate_encoded_required_field_decoder(
+        &self,
+        field_name: &str,
+        encoded_type: &EncodedDataType,
+        offset: usize,
+        since_version: usize,
+    ) -> Result<Tokens<JavaScript>> {
+        let metadata = field_metadata(field_name, encoded_type, &self.schema.types)?;
+        let getter = format!("get{}At", accessor(&metadata.field_primitive_type));
+        let null_value = null_value(encoded_type, &metadata);
+
+        Ok(
+            match (&metadata.field_primitive_type, metadata.field_length) {
+                (NativeType::Char, 2..) => quote! {
+                    $(&metadata.field_name)() {
+                        $(version_check(since_version, quote!("")))
+                        return this.#buffer.getStringAt($offset, $(metadata.field_length));
+                    }
+                },
+                (_, 2..) => quote! {
+                    $(&metadata.field_name)() {
+                        $(version_check(since_version, null_array(&metadata, &null_value)))
+                        const offset = $offset;
+                        const value = new Array($(metadata.field_length));
+
+                        for (let idx = 0; idx < value.length; idx++) {
+                            value[idx] = this.#buffer.$(&getter)(offset + idx * $(metadata.type_size));
+                        }
+
+                        return value;
+                    }
+                },
+                (_, _) => {
+                    let bounds_checks = bounds_checks(
+                        &metadata.field_name,
+                        "value",
+                        encoded_type,
+                        &metadata.field_primitive_type,
+                        &self.error_class(),
+                    );
+
+                    if bounds_checks.is_empty() {
+                        quote! {
+                            $(&metadata.field_name)() {
+                                $(version_check(since_version, &null_value))
+                                return this.#buffer.$(&getter)($offset);
+                            }
+                        }
+                    } else {
+                        quote! {
+                            $(&metadata.field_name)() {
+                                $(version_check(since_version, &null_value))
+                                const value = this.#buffer.$(&getter)($offset);
+
+                                $bounds_checks
+                                return value;
+                            }
+                        }
+                    }
+                }
+            },
+        )
+    }
+
+    fn generate_encoded_optional_field_decoder(
+        &self,
+        field_name: &str,
+        encoded_type: &EncodedDataType,
+        offset: usize,
+        since_version: usize,
+    ) -> Result<Tokens<JavaScript>> {
+        let metadata = field_metadata(field_name, encoded_type, &self.schema.types)?;
+        let getter = format!("get{}At", accessor(&metadata.field_primitive_type));
+
+        Ok(
+            match (&metadata.field_primitive_type, metadata.field_length) {
+                (NativeType::Char, 2..) => quote! {
+                    $(&metadata.field_name)() {
+                        $(version_check(since_version, quote!(null)))
+                        let isNull = true;
+
+                        for (const part of this.#buffer.getSliceAt($offset, $(metadata.field_length))) {
+                            isNull &&= $(null_value_condition("part", encoded_type, &metadata));
+                        }
+
+                        if (isNull) {
+                            return null;
+                        }
+
+                        return this.#buffer.getStringAt($offset, $(metadata.field_length));
+                    }
+                },
+                (_, 2..) => quote! {
+                    $(&metadata.field_name)() {
+                        $(version_check(since_version, quote!(null)))
+                        const offset = $offset;
+                        const value = new Array($(metadata.field_length));
+                        let isNull = true;
+
+                        for (let idx = 0; idx < value.length; idx++) {
+                            const part = this.#buffer.$(&getter)(offset + idx * $(metadata.type_size));
+                            value[idx] = part;
+                            isNull &&= $(null_value_condition("part", encoded_type, &metadata));
+                        }
+
+                        return isNull ? null : value;
+                    }
+                },
+                (_, _) => quote! {
+                    $(&metadata.field_name)() {
+                        $(version_check(since_version, quote!(null)))
+                        const value = this.#buffer.$(&getter)($offset);
+
+                        if ($(null_value_condition("value", encoded_type, &metadata))) {
+                            return null;
+                        }
+
+                        $(bounds_checks(&metadata.field_name, "value", encoded_type, &metadata.field_primitive_type, &self.error_class()))
+                        return value;
+                    }
+                },
+            },
+        )
+    }
+
+    /// Generates the setter for a field at the given offset, for an encoder in the `module`.
+    pub(crate) fn generate_encoder_field(
+        &self,
+        module: &str,
+        field_name: &str,
+        field: &Type,
+        offset: Tokens<JavaScript>,
+    ) -> Result<Tokens<JavaScript>> {
+        Ok(match self.resolved(field)? {
+            Type::EncodedData(encoded_type) => match encoded_type.presence {
+                Presence::Constant => quote!(),
+                Presence::Required => {
+                    self.generate_encoded_required_field_encoder(field_name, &encoded_type, offset)?
+                }
+                Presence::Optional => {
+                    self.generate_encoded_optional_field_encoder(field_name, &encoded_type, offset)?
+                }
+            },
+            Type::Enum(enum_type) => {
+                let encoding_type = enum_type
+                    .encoding_type
+                    .resolved(&self.schema.types.encoded_types)?;
+
+                quote! {
+                    $(field_name.to_case(Case::Camel))(value) {
+                        this.#buffer.put$(accessor(&encoding_type))At($offset, $(from_number(&encoding_type, "value")));
+                    }
+                }
+            }
+            Type::Set(set_type) => {
+                let encoding_type = set_type
+                    .encoding_type
+                    .resolved(&self.schema.types.encoded_types)?;
+
+                quote! {
+                    $(field_name.to_case(Case::Camel))(value) {
+                        this.#buffer.put$(accessor(&encoding_type))At($offset, value.value);
+                    }
+                }
+            }
+            Type::Composite(composite_type) => {
+                let encoder_class = self.import(
+                    module,
+                    COMPOSITE_MODULE_NAME,
+                    &format!("{}Encoder", composite_type.name.to_case(Case::UpperCamel)),
+                );
+
+                quote! {
+                    $(field_name.to_case(Case::Camel))Encoder(action) {
+                        return action(new $encoder_class(this.#buffer.splitAt($offset)));
+                    }
+                }
+            }
+            Type::Reference(reference_type) => {
+                unreachable!("Resolved reference: {}", reference_type.type_name)
+            }
+        })
+    }
+
+    fn generate_encoded_required_field_encoder(
+        &self,
+        field_name: &str,
+        encoded_type: &EncodedDataType,
+        offset: Tokens<JavaScript>,
+    ) -> Result<Tokens<JavaScript>> {
+        let metadata = field_metadata(field_name, encoded_type, &self.schema.types)?;
+        let setter = format!("put{}At", accessor(&metadata.field_primitive_type));
+
+        Ok(
+            match (&metadata.field_primitive_type, metadata.field_length) {
+                (NativeType::Char, 2..) => quote! {
+                    $(&metadata.field_name)(value) {
+                        const offset = $offset;
+
+                        $(self.string_encoder(&metadata))
+                    }
+                },
+                (_, 2..) => quote! {
+                    $(&metadata.field_name)(value) {
+                        const offset = $offset;
+
+                        $(self.array_length_check(&metadata))
+                        for (let idx = 0; idx < value.length; idx++) {
+                            $(self.bounds_checks(&metadata, "value[idx]", encoded_type))
+                            this.#buffer.$(&setter)(offset + idx * $(metadata.type_size), value[idx]);
+                        }
+                    }
+                },
+                (_, _) => {
+                    let bounds_checks = self.bounds_checks(&metadata, "value", encoded_type);
+
+                    if bounds_checks.is_empty() {
+                        quote! {
+                            $(&metadata.field_name)(value) {
+                                this.#buffer.$(&setter)($offset, value);
+                            }
+                        }
+                    } else {
+                        quote! {
+                            $(&metadata.field_name)(value) {
+                                const offset = $offset;
+
+                                $bounds_checks
+                                this.#buffer.$(&setter)(offset, value);
+                            }
+                        }
+                    }
+                }
+            },
+        )
+    }
+
+    fn generate_encoded_optional_field_encoder(
+        &self,
+        field_name: &str,
+        encoded_type: &EncodedDataType,
+        offset: Tokens<JavaScript>,
+    ) -> Result<Tokens<JavaScript>> {
+        let metadata = field_metadata(field_name, encoded_type, &self.schema.types)?;
+        let setter = format!("put{}At", accessor(&metadata.field_primitive_type));
+        let null_value = null_value(encoded_type, &metadata);
+
+        Ok(
+            match (&metadata.field_primitive_type, metadata.field_length) {
+                (NativeType::Char, 2..) => quote! {
+                    $(&metadata.field_name)(value) {
+                        const offset = $offset;
+
+                        if (value === null) {
+                            this.#buffer.putBytesAt(offset, new Uint8Array($(metadata.field_length)).fill($(&null_value)));
+                            return;
+                        }
+
+                        $(self.string_encoder(&metadata))
+                    }
+                },
+                (_, 2..) => {
+                    let bounds_checks = self.bounds_checks(&metadata, "part", encoded_type);
+
+                    quote! {
+                        $(&metadata.field_name)(value) {
+                            const offset = $offset;
+
+                            $(if bounds_checks.is_empty() {
+                                if (value === null) {
+                                    value = $(null_array(&metadata, &null_value));
+                                } else if (value.length !== $(metadata.field_length)) {
+                                    $(self.wrong_slice_size(&metadata))
+                                }
+                            } else {
+                                if (value === null) {
+                                    value = $(null_array(&metadata, &null_value));
+                                } else {
+                                    $(self.array_length_check(&metadata))
+                                    for (const part of value) {
+                                        $bounds_checks
+                                    }
+                                }
+                            })
+
+                            for (let idx = 0; idx < value.length; idx++) {
+                                this.#buffer.$(&setter)(offset + idx * $(metadata.type_size), value[idx]);
+                            }
+                        }
+                    }
+                }
+                (_, _) => {
+                    let bounds_checks = self.bounds_checks(&metadata, "value", encoded_type);
+
+                    if bounds_checks.is_empty() {
+                        quote! {
+                            $(&metadata.field_name)(value) {
+                                this.#buffer.$(&setter)($offset, value !== null ? value : $(&null_value));
+                            }
+                        }
+                    } else {
+                        quote! {
+                            $(&metadata.field_name)(value) {
+                                const offset = $offset;
+
+                                if (value !== null) {
+                                    $bounds_checks
+                                }
+
+                                this.#buffer.$(&setter)(offset, value !== null ? value : $(&null_value));
+                            }
+                        }
+                    }
+                }
+            },
+        )
+    }
+
+    /// Checks of the `minValue` and `maxValue` of the field, unless they are disabled.
+    fn bounds_checks(
+        &self,
+        metadata: &FieldMetadata<JavaScript>,
+        variable: &str,
+        encoded_type: &EncodedDataType,
+    ) -> Tokens<JavaScript> {
+        if self.bounds_checks {
+            bounds_checks(
+                &metadata.field_name,
+                variable,
+                encoded_type,
+                &metadata.field_primitive_type,
+                &self.error_class(),
+            )
+        } else {
+            quote!()
+        }
+    }
+
+    fn array_length_check(&self, metadata: &FieldMetadata<JavaScript>) -> Tokens<JavaScript> {
+        quote! {
+            if (value.length !== $(metadata.field_length)) {
+                $(self.wrong_slice_size(metadata))
+            }
+            $['\n']
+        }
+    }
+
+    fn wrong_slice_size(&self, metadata: &FieldMetadata<JavaScript>) -> Tokens<JavaScript> {
+        quote! {
+            throw $(self.error_class()).wrongSliceSize($(quoted(format!("expected {} elements, got ", metadata.field_length))) + value.length);
+        }
+    }
+
+    fn string_encoder(&self, metadata: &FieldMetadata<JavaScript>) -> Tokens<JavaScript> {
+        let error = &self.error_class();
+        let max_length = metadata.field_length;
+
+        quote! {
+            $(if let Some(CharacterEncoding::Ascii) = metadata.encoding {
+                if ($(NON_ASCII).test(value)) {
+                    throw $error.invalidStringValue(value);
+                }
+                $['\n']
+            })
+            const encoded = new TextEncoder().encode(value);
+
+            if (encoded.length > $max_length) {
+                throw $error.valueOutOfBounds($(quoted(&metadata.field_name)), "string '" + value + "' length " + encoded.length + $(quoted(format!(" > {} (max)", max_length))));
+            }
+
+            this.#buffer.putBytesAt(offset, encoded);
+        }
+    }
+}
+
+/// Regular expression matching any character, which is not ASCII.
+pub(crate) const NON_ASCII: &str = "/[^\\x00-\\x7f]/";
+
+/// Returns the null value early from an accessor of a field, which was added in a newer
+/// version of the schema than the one used to encode the decoded data.
+fn version_check(
+    since_version: usize,
+    null_value: impl FormatInto<JavaScript>,
+) -> Tokens<JavaScript> {
+    quote! {
+        $(if since_version > 0 {
+            if (this.#actingVersion < $since_version) {
+                return $null_value;
+            }
+            $['\n']
+        })
+    }
+}
+
+/// Array of the field length filled with the null value.
+fn null_array(metadata: &FieldMetadata<JavaScript>, null_value: &str) -> Tokens<JavaScript> {
+    let values = vec![null_value; metadata.field_length].join(", ");
+
+    quote!([$values])
+}
@@ -0,0 +1,84 @@
+use anyhow::Result;
+use convert_case::{Case, Casing};
+
+use crate::generator::typescript::codecs::null_literal;
+use crate::generator::typescript::constants::ENUM_MODULE_NAME;
+use crate::generator::typescript::module::ModuleGenerator;
+use crate::models::types::enum_type::EnumType;
+use crate::models::types::primitive_type::{NativeType, ResolvableType};
+use genco::prelude::*;
+
+impl ModuleGenerator<'_> {
+    fn generate_enum_codec(&self, enum_type: &EnumType) -> Result<Tokens<JavaScript>> {
+        let name = enum_type.name.to_case(Case::UpperCamel);
+        let error = &self.error_class();
+        let values = self.enum_values(enum_type)?;
+
+        Ok(quote! {
+            export const $(&name) = Object.freeze({
+                $(for (value_name, value) in &values {
+                    $['\r']
+                    $value_name: $value,
+                })
+            });
+
+            $(format!("/** Returns the value of {}, failing for the values not in the schema. */", name))
+            export function $(enum_from_value(enum_type))(value) {
+                switch (value) {
+                    $(for (value_name, _) in &values {
+                        $['\r']
+                        case $(&name).$value_name:
+                    })
+                        return value;
+                    default:
+                        throw $error.invalidEnumValue($(quoted(&name)), value);
+                }
+            }
+        })
+    }
+
+    pub fn write_enum_codecs(&self) -> Result<()> {
+        let mut enum_tokens: Tokens<JavaScript> = quote!();
+
+        for enum_type in self.schema.types.enum_types.values() {
+            enum_tokens.line();
+            enum_tokens.append(self.generate_enum_codec(enum_type)?);
+        }
+
+        self.write_module(ENUM_MODULE_NAME, enum_tokens)
+    }
+
+    /// Names and values of an enum, followed by its null value. The values are numbers
+    /// even for 64-bit encodings, as TypeScript enums cannot hold bigints.
+    pub(crate) fn enum_values(&self, enum_type: &EnumType) -> Result<Vec<(String, String)>> {
+        let encoding_type = enum_type
+            .encoding_type
+            .resolved(&self.schema.types.encoded_types)?;
+        let char_encoding = encoding_type == NativeType::Char;
+
+        let mut values = enum_type
+            .values
+            .iter()
+            .map(|value| {
+                Ok((
+                    value.name.to_case(Case::UpperSnake),
+                    value.encoded_value(char_encoding)?.to_string(),
+                ))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        values.push((
+            "NULL_VAL".to_owned(),
+            null_literal(&encoding_type)
+                .trim_end_matches('n')
+                .to_owned(),
+        ));
+
+        Ok(values)
+    }
+}
+
+/// Name of the function validating a value of an enum, e.g. `modelFromValue`.
+pub(crate) fn enum_from_value(enum_type: &EnumType) -> String {
+    format!("{}FromValue", enum_type.name.to_case(Case::Camel))
+}
@@ -0,0 +1,579 @@
+use crate::generator::common::{dimension_type, field_groups};
+use crate::generator::typescript::codecs::{
+    accessor, field_metadata, from_number, max_literal, to_number,
+};
+use crate::generator::typescript::constants::{
+    BUFFER_MODULE_NAME, COMPOSITE_MODULE_NAME, ENUM_MODULE_NAME, MESSAGE_MODULE_NAME,
+    READ_BUF_CLASS_NAME,
+};
+use crate::generator::typescript::module::ModuleGenerator;
+use crate::models::layout::BlockLayout;
+use crate::models::types::composite_type::CompositeType;
+use crate::models::types::encoded_data_type::EncodedDataType;
+use crate::models::types::field_type::FieldType;
+use crate::models::types::group_type::GroupType;
+use crate::models::types::variable_data_type::VariableDataType;
+use crate::models::types::{Presence, SizedEncoded, Type};
+use crate::models::FieldPath;
+use anyhow::{anyhow, Result};
+use convert_case::{Case, Casing};
+use genco::prelude::*;
+
+impl ModuleGenerator<'_> {
+    /// Generates the decoder of a group, scoped by the names of its message and enclosing
+    /// groups, followed by the decoders of its own groups and var data.
+    pub(crate) fn generate_group_decoder(
+        &self,
+        group: &GroupType,
+        group_path: &FieldPath,
+    ) -> Result<Tokens<JavaScript>> {
+        let name = group.name.as_str();
+        let decoder_name = format!("{}Decoder", scoped_name(group_path));
+        let (fields, groups, var_data) = field_groups(&group.fields);
+        let error = &self.error_class();
+
+        let layout =
+            BlockLayout::from_fields(name, &fields, group.block_length, &self.schema.types)?;
+
+        let dimension_type = dimension_type(group, &self.schema.types.composite_types)?;
+        let dimension_type_size = dimension_type.size(&self.schema.types)?;
+        let dimension_decoder_class = self.import(
+            MESSAGE_MODULE_NAME,
+            COMPOSITE_MODULE_NAME,
+            &format!("{}Decoder", dimension_type.name.to_case(Case::UpperCamel)),
+        );
+        let (block_length_name, block_length_type) = self.counter_field(dimension_type, 0)?;
+        let (num_in_group_name, num_in_group_type) = self.counter_field(dimension_type, 1)?;
+        let read_buf = self.import(MESSAGE_MODULE_NAME, BUFFER_MODULE_NAME, READ_BUF_CLASS_NAME);
+
+        let mut group_tokens = quote! {
+            export class $(&decoder_name) {
+                #buffer;
+                #actingVersion;
+                #blockLength;
+                #numInGroup;
+                #index = 0;
+                #size;
+                $(size_fields(&groups, &var_data))
+
+                constructor(buffer, actingVersion) {
+                    const dimension = new $dimension_decoder_class(buffer);
+
+                    this.#buffer = buffer.splitAt($dimension_type_size);
+                    this.#actingVersion = actingVersion;
+                    this.#blockLength = $(self.counter_value(&block_length_type, quote!(dimension.$(block_length_name.to_case(Case::Camel))()))?);
+                    this.#numInGroup = $(self.counter_value(&num_in_group_type, quote!(dimension.$(num_in_group_name.to_case(Case::Camel))()))?);
+                    this.#size = $dimension_type_size;
+                }
+
+                $("/**")
+                $(" * Creates a decoder of a group, which is not present in the decoded data,")
+                $(" * because it was added in a newer version of the schema.")
+                $(" */")
+                static empty(actingVersion) {
+                    const decoder = new $(&decoder_name)(new $read_buf(new ArrayBuffer($dimension_type_size)), actingVersion);
+                    decoder.#size = 0;
+
+                    return decoder;
+                }
+
+                actingVersion() {
+                    return this.#actingVersion;
+                }
+
+                blockLength() {
+                    return this.#blockLength;
+                }
+
+                numInGroup() {
+                    return this.#numInGroup;
+                }
+
+                size() {
+                    return this.#index === this.#numInGroup ? this.#size : null;
+                }
+
+                $("/** Advances past the remaining entries, skipping their groups and var data. */")
+                skip() {
+                    while (this.#index < this.#numInGroup) {
+                        $(skipped_sizes(&groups, &var_data))
+                        this.advance();
+                    }
+                }
+
+                advance() {
+                    if (this.#index >= this.#numInGroup) {
+                        throw $error.groupOutOfBounds($(quoted(name.to_case(Case::Camel))));
+                    }
+
+                    const fieldsSize = this.#blockLength;
+                    $(self.taken_sizes(&groups, &var_data))
+                    const advancement = fieldsSize + groupsSize + varDataSize;
+
+                    this.#index++;
+                    this.#size += advancement;
+                    this.#buffer = this.#buffer.splitAt(advancement);
+                }
+                $(self.generate_field_decoders(&fields, layout.offsets)?)
+                $(self.generate_group_decoders(&groups, group_path, &quote!(this.#blockLength))?)
+                $(self.generate_var_data_decoders(&var_data, &groups, group_path, &quote!(this.#blockLength))?)
+            }
+        };
+
+        for nested_group in &groups {
+            group_tokens.line();
+            group_tokens.append(
+                self.generate_group_decoder(nested_group, &group_path.join(&nested_group.name))?,
+            );
+        }
+
+        for var_data_field in &var_data {
+            group_tokens.line();
+            group_tokens.append(self.generate_var_data_decoder(
+                var_data_field,
+                &group_path.join(&var_data_field.name),
+            )?);
+        }
+
+        Ok(group_tokens)
+    }
+
+    /// Generates the encoder of a group, scoped by the names of its message and enclosing
+    /// groups, followed by the encoders of its own groups and var data.
+    pub(crate) fn generate_group_encoder(
+        &self,
+        group: &GroupType,
+        group_path: &FieldPath,
+    ) -> Result<Tokens<JavaScript>> {
+        let name = group.name.as_str();
+        let encoder_name = format!("{}Encoder", scoped_name(group_path));
+        let (fields, groups, var_data) = field_groups(&group.fields);
+        let error = &self.error_class();
+        let types = &self.schema.types;
+
+        let dimension_type = dimension_type(group, &types.composite_types)?;
+        let dimension_type_size = dimension_type.size(types)?;
+
+        let (_, block_length_type) = self.counter_field(dimension_type, 0)?;
+        let (_, num_in_group_type) = self.counter_field(dimension_type, 1)?;
+        let block_length_metadata = field_metadata("", &block_length_type, types)?;
+        let block_length_native = &block_length_metadata.field_primitive_type;
+        let num_in_group_native =
+            &field_metadata("", &num_in_group_type, types)?.field_primitive_type;
+
+        let layout = BlockLayout::from_fields(name, &fields, group.block_length, types)?;
+        let offset_tokens = quote!(this.#size + $(&encoder_name).BLOCK_LENGTH);
+
+        let mut group_tokens = quote! {
+            export class $(&encoder_name) {
+                static BLOCK_LENGTH = $(layout.block_length);
+
+                #buffer;
+                #numInGroup = 0;
+                #size;
+                $(size_fields(&groups, &var_data))
+
+                constructor(buffer) {
+                    this.#buffer = buffer;
+                    this.#size = $dimension_type_size;
+                }
+
+                size() {
+                    return this.#size;
+                }
+
+                advance() {
+                    const fieldsSize = $(&encoder_name).BLOCK_LENGTH;
+                    $(self.taken_sizes(&groups, &var_data))
+
+                    if (this.#numInGroup >= $(max_literal(num_in_group_native))) {
+                        throw $error.groupOutOfBounds($(quoted(name.to_case(Case::Camel))));
+                    }
+
+                    this.#numInGroup++;
+                    this.#size += fieldsSize + groupsSize + varDataSize;
+                }
+
+                $("/** Writes the dimension of the group, after all its entries were encoded. */")
+                finish() {
+                    this.#buffer.put$(accessor(block_length_native))At(0, $(from_number(block_length_native, quote!($(&encoder_name).BLOCK_LENGTH))));
+                    this.#buffer.put$(accessor(num_in_group_native))At($(block_length_metadata.type_size), $(from_number(num_in_group_native, quote!(this.#numInGroup))));
+                }
+                $(self.generate_field_encoders(&fields, Some(quote!(this.#size)), layout.offsets)?)
+                $(self.generate_group_encoders(&groups, group_path, &offset_tokens)?)
+                $(self.generate_var_data_encoders(&var_data, &groups, group_path, &offset_tokens)?)
+            }
+        };
+
+        for nested_group in &groups {
+            group_tokens.line();
+            group_tokens.append(
+                self.generate_group_encoder(nested_group, &group_path.join(&nested_group.name))?,
+            );
+        }
+
+        for var_data_field in &var_data {
+            group_tokens.line();
+            group_tokens.append(self.generate_var_data_encoder(
+                var_data_field,
+                &group_path.join(&var_data_field.name),
+            )?);
+        }
+
+        Ok(group_tokens)
+    }
+
+    /// Sums the recorded sizes of the groups and var data of the current entry, which are
+    /// reset for the next one.
+    fn taken_sizes(
+        &self,
+        groups: &[&GroupType],
+        var_data: &[&VariableDataType],
+    ) -> Tokens<JavaScript> {
+        quote! {
+            const groupsSize = 0$(self.previous_group_sizes(groups));
+            const varDataSize = 0$(self.previous_var_data_sizes(var_data));
+            $(for group in groups {
+                $['\r']
+                this.#$(group.name.to_case(Case::Camel))Size = null;
+            })
+            $(for var in var_data {
+                $['\r']
+                this.#$(var.name.to_case(Case::Camel))Size = null;
+            })
+        }
+    }
+
+    pub(crate) fn generate_field_decoders(
+        &self,
+        fields: &[&FieldType],
+        offsets: impl IntoIterator<Item = usize>,
+    ) -> Result<Tokens<JavaScript>> {
+        let mut decoder_fields: Tokens<JavaScript> = quote!();
+
+        for (field, offset) in fields.iter().zip(offsets) {
+            let repr_type = field.to_type(&self.schema.types)?;
+
+            decoder_fields.line();
+            decoder_fields.append(if matches!(field.presence, Presence::Constant) {
+                self.generate_constant_enum(field, repr_type)?
+            } else {
+                let since_version = field.since_version.unwrap_or(0);
+                self.generate_decoder_field(
+                    MESSAGE_MODULE_NAME,
+                    &field.name,
+                    &repr_type,
+                    offset,
+                    since_version,
+                )?
+            });
+        }
+
+        Ok(decoder_fields)
+    }
+
+    fn generate_constant_enum(
+        &self,
+        field: &FieldType,
+        repr_type: Type,
+    ) -> Result<Tokens<JavaScript>> {
+        let field_name = field.name.to_case(Case::Camel);
+        let enum_type = match self.resolved(&repr_type)? {
+            Type::Enum(enum_type) => enum_type,
+            _ => return Err(anyhow!("Constant field {} is not an enum", field.name)),
+        };
+
+        let default_value = field
+            .value_ref
+            .as_ref()
+            .ok_or(anyhow!("Constant field {} has no ref value", field.name))?;
+
+        let default_value = default_value.split('.').nth(1).unwrap_or(default_value);
+        let default_value = enum_type
+            .values
+            .iter()
+            .find(|value| value.name == default_value)
+            .ok_or(anyhow!(
+                "Constant field {} has no value {}",
+                field.name,
+                default_value
+            ))?;
+        let enum_class = self.import(
+            MESSAGE_MODULE_NAME,
+            ENUM_MODULE_NAME,
+            &enum_type.name.to_case(Case::UpperCamel),
+        );
+
+        Ok(quote! {
+            $(&field_name)() {
+                return $enum_class.$(default_value.name.to_case(Case::UpperSnake));
+            }
+        })
+    }
+
+    pub(crate) fn generate_field_encoders(
+        &self,
+        fields: &[&FieldType],
+        offset_base: Option<Tokens<JavaScript>>,
+        offsets: impl IntoIterator<Item = usize>,
+    ) -> Result<Tokens<JavaScript>> {
+        let mut encoder_fields: Tokens<JavaScript> = quote!();
+
+        for (field, offset) in fields.iter().zip(offsets) {
+            if matches!(field.presence, Presence::Constant) {
+                continue;
+            }
+
+            let repr_type = field.to_type(&self.schema.types)?;
+
+            encoder_fields.line();
+            encoder_fields.append(self.generate_encoder_field(
+                MESSAGE_MODULE_NAME,
+                &field.name,
+                &repr_type,
+                match &offset_base {
+                    Some(offset_base) => quote!($offset_base + $offset),
+                    None => quote!($offset),
+                },
+            )?);
+        }
+
+        Ok(encoder_fields)
+    }
+
+    pub(crate) fn generate_group_decoders(
+        &self,
+        groups: &[&GroupType],
+        path: &FieldPath,
+        offset: &Tokens<JavaScript>,
+    ) -> Result<Tokens<JavaScript>> {
+        let mut decoder_fields: Tokens<JavaScript> = quote!();
+
+        for (group_idx, group) in groups.iter().enumerate() {
+            let field_name = group.name.to_case(Case::Camel);
+            let decoder_name = format!("{}Decoder", scoped_name(&path.join(&group.name)));
+
+            decoder_fields.line();
+            decoder_fields.append(quote! {
+                $(&field_name)Decoder(action) {
+                    $(if let Some(since_version) = group.since_version.filter(|version| *version > 0) {
+                        if (this.#actingVersion < $since_version) {
+                            const result = action($(&decoder_name).empty(this.#actingVersion));
+                            this.#$(&field_name)Size = 0;
+
+                            return result;
+                        }
+                        $['\n']
+                    })
+                    const offset = $offset$(self.previous_group_sizes(&groups[..group_idx]));
+                    const decoder = new $(&decoder_name)(this.#buffer.splitAt(offset), this.#actingVersion);
+
+                    const result = action(decoder);
+                    this.#$(&field_name)Size = decoder.size();
+
+                    return result;
+                }
+            });
+        }
+
+        Ok(decoder_fields)
+    }
+
+    pub(crate) fn generate_var_data_decoders(
+        &self,
+        var_data_fields: &[&VariableDataType],
+        groups: &[&GroupType],
+        path: &FieldPath,
+        offset: &Tokens<JavaScript>,
+    ) -> Result<Tokens<JavaScript>> {
+        let mut decoder_fields: Tokens<JavaScript> = quote!();
+        let read_buf = &self.import(MESSAGE_MODULE_NAME, BUFFER_MODULE_NAME, READ_BUF_CLASS_NAME);
+
+        for (var_data_idx, var_data_field) in var_data_fields.iter().enumerate() {
+            let field_name = var_data_field.name.to_case(Case::Camel);
+            let decoder_name = format!("{}Decoder", scoped_name(&path.join(&var_data_field.name)));
+
+            decoder_fields.line();
+            decoder_fields.append(quote! {
+                $(&field_name)Decoder(action) {
+                    $(if let Some(since_version) = var_data_field.since_version.filter(|version| *version > 0) {
+                        if (this.#actingVersion < $since_version) {
+                            const result = action(new $(&decoder_name)($read_buf.empty()));
+                            this.#$(&field_name)Size = 0;
+
+                            return result;
+                        }
+                        $['\n']
+                    })
+                    const offset = $offset$(self.previous_group_sizes(groups))$(self.previous_var_data_sizes(&var_data_fields[..var_data_idx]));
+                    const decoder = new $(&decoder_name)(this.#buffer.splitAt(offset));
+
+                    const result = action(decoder);
+                    this.#$(&field_name)Size = decoder.size();
+
+                    return result;
+                }
+            });
+        }
+
+        Ok(decoder_fields)
+    }
+
+    pub(crate) fn generate_group_encoders(
+        &self,
+        groups: &[&GroupType],
+        path: &FieldPath,
+        offset: &Tokens<JavaScript>,
+    ) -> Result<Tokens<JavaScript>> {
+        let mut encoder_fields: Tokens<JavaScript> = quote!();
+
+        for (group_idx, group) in groups.iter().enumerate() {
+            let field_name = group.name.to_case(Case::Camel);
+            let encoder_name = format!("{}Encoder", scoped_name(&path.join(&group.name)));
+
+            encoder_fields.line();
+            encoder_fields.append(quote! {
+                $(&field_name)Encoder(action) {
+                    const offset = $offset$(self.previous_group_sizes(&groups[..group_idx]));
+                    const encoder = new $(&encoder_name)(this.#buffer.splitAt(offset));
+
+                    const result = action(encoder);
+                    this.#$(&field_name)Size = encoder.size();
+                    encoder.finish();
+
+                    return result;
+                }
+            });
+        }
+
+        Ok(encoder_fields)
+    }
+
+    pub(crate) fn generate_var_data_encoders(
+        &self,
+        var_data_fields: &[&VariableDataType],
+        groups: &[&GroupType],
+        path: &FieldPath,
+        offset: &Tokens<JavaScript>,
+    ) -> Result<Tokens<JavaScript>> {
+        let mut encoder_fields: Tokens<JavaScript> = quote!();
+
+        for (var_data_idx, var_data_field) in var_data_fields.iter().enumerate() {
+            let field_name = var_data_field.name.to_case(Case::Camel);
+            let encoder_name = format!("{}Encoder", scoped_name(&path.join(&var_data_field.name)));
+
+            encoder_fields.line();
+            encoder_fields.append(quote! {
+                $(&field_name)Encoder(action) {
+                    const offset = $offset$(self.previous_group_sizes(groups))$(self.previous_var_data_sizes(&var_data_fields[..var_data_idx]));
+                    const encoder = new $(&encoder_name)(this.#buffer.splitAt(offset));
+
+                    const result = action(encoder);
+                    this.#$(&field_name)Size = encoder.size();
+                    encoder.finish();
+
+                    return result;
+                }
+            });
+        }
+
+        Ok(encoder_fields)
+    }
+
+    fn previous_group_sizes(&self, groups: &[&GroupType]) -> Tokens<JavaScript> {
+        let error = &self.error_class();
+
+        quote! {
+            $(for group in groups => $[' ']+ $error.requireGroupSize(this.#$(group.name.to_case(Case::Camel))Size, $(quoted(group.name.to_case(Case::Camel)))))
+        }
+    }
+
+    fn previous_var_data_sizes(&self, var_data_fields: &[&VariableDataType]) -> Tokens<JavaScript> {
+        let error = &self.error_class();
+
+        quote! {
+            $(for var in var_data_fields => $[' ']+ $error.requireVarDataSize(this.#$(var.name.to_case(Case::Camel))Size, $(quoted(var.name.to_case(Case::Camel)))))
+        }
+    }
+
+    /// Returns the name and the encoded type of the counter at the `index` of the dimension
+    /// of a group or the encoding of var data.
+    pub(crate) fn counter_field(
+        &self,
+        composite_type: &CompositeType,
+        index: usize,
+    ) -> Result<(String, EncodedDataType)> {
+        let field = composite_type.fields.get(index).ok_or(anyhow!(
+            "Missing field {} in composite '{}'",
+            index,
+            composite_type.name
+        ))?;
+
+        match self.resolved(field)? {
+            Type::EncodedData(encoded_type) => Ok((field.name().to_owned(), encoded_type)),
+            _ => Err(anyhow!(
+                "Only encoded data type expected for field '{}' in composite '{}'",
+                field.name(),
+                composite_type.name
+            )),
+        }
+    }
+
+    /// Value of a counter read from the data as a number.
+    fn counter_value(
+        &self,
+        encoded_type: &EncodedDataType,
+        value: Tokens<JavaScript>,
+    ) -> Result<Tokens<JavaScript>> {
+        let metadata = field_metadata("", encoded_type, &self.schema.types)?;
+
+        Ok(to_number(&metadata.field_primitive_type, value))
+    }
+}
+
+/// Recorded sizes of the groups and var data, null until they were decoded or encoded.
+pub(crate) fn size_fields(
+    groups: &[&GroupType],
+    var_data: &[&VariableDataType],
+) -> Tokens<JavaScript> {
+    quote! {
+        $(for group in groups {
+            $['\r']
+            #$(group.name.to_case(Case::Camel))Size = null;
+        })
+        $(for var in var_data {
+            $['\r']
+            #$(var.name.to_case(Case::Camel))Size = null;
+        })
+    }
+}
+
+/// Records the sizes of all the groups and var data of a message or of an entry of a group,
+/// skipping the entries of the groups.
+pub(crate) fn skipped_sizes(
+    groups: &[&GroupType],
+    var_data: &[&VariableDataType],
+) -> Tokens<JavaScript> {
+    quote! {
+        $(for group in groups {
+            $['\r']
+            this.$(group.name.to_case(Case::Camel))Decoder((decoder) => decoder.skip());
+        })
+        $(for var in var_data {
+            $['\r']
+            this.$(var.name.to_case(Case::Camel))Decoder(() => {});
+        })
+        $(if !groups.is_empty() || !var_data.is_empty() {
+            $['\n']
+        })
+    }
+}
+
+/// Name of the codecs of a group or var data, made of the names of the message and the
+/// groups enclosing it, e.g. `CarFuelFigures` for `CarFuelFiguresDecoder`.
+pub(crate) fn scoped_name(path: &FieldPath) -> String {
+    path.segments()
+        .iter()
+        .map(|segment| segment.to_case(Case::UpperCamel))
+        .collect()
+}
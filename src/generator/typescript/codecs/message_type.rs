@@ -0,0 +1,376 @@
+use crate::generator::common::{field_groups, header_counts};
+use crate::generator::typescript::codecs::group_type::{size_fields, skipped_sizes};
+use crate::generator::typescript::codecs::{field_metadata, from_number, scoped_name, to_number};
+use crate::generator::typescript::constants::{
+    BUFFER_MODULE_NAME, COMPOSITE_MODULE_NAME, DISPATCHER_MODULE_NAME, MESSAGE_MODULE_NAME,
+    READ_BUF_CLASS_NAME, SCHEMA_MODULE_NAME, WRITE_BUF_CLASS_NAME,
+};
+use crate::generator::typescript::module::ModuleGenerator;
+use crate::models::layout::BlockLayout;
+use crate::models::message::MessageType;
+use crate::models::types::group_type::GroupType;
+use crate::models::types::primitive_type::NativeType;
+use crate::models::types::variable_data_type::VariableDataType;
+use crate::models::types::{SizedEncoded, Type};
+use crate::models::FieldPath;
+use anyhow::{anyhow, Result};
+use convert_case::{Case, Casing};
+use genco::prelude::*;
+
+impl ModuleGenerator<'_> {
+    /// Generates the decoder of a message, followed by the decoders of its groups and
+    /// var data.
+    fn generate_message_decoder(&self, message: &MessageType) -> Result<Tokens<JavaScript>> {
+        let name = message.name.as_str();
+        let message_path = FieldPath::new(vec![message.name.clone()]);
+        let decoder_name = format!("{}Decoder", scoped_name(&message_path));
+        let (fields, groups, var_data) = field_groups(&message.fields);
+        let read_buf = &self.import(MESSAGE_MODULE_NAME, BUFFER_MODULE_NAME, READ_BUF_CLASS_NAME);
+        let error = &self.error_class();
+
+        let layout =
+            BlockLayout::from_fields(name, &fields, message.block_length, &self.schema.types)?;
+
+        let header_type = Type::Composite(self.schema.types.header_type.clone());
+        let header_size = header_type.size(&self.schema.types)?;
+        let header_offset = quote!($header_size + this.#blockLength);
+
+        let mut decoder_tokens = quote! {
+            export class $(&decoder_name) {
+                static TEMPLATE_ID = $(message.id);
+
+                #buffer;
+                #actingVersion;
+                #blockLength;
+                $(size_fields(&groups, &var_data))
+
+                constructor(buffer) {
+                    this.#buffer = buffer instanceof $read_buf ? buffer : new $read_buf(buffer);
+                    this.#actingVersion = $(self.import(MESSAGE_MODULE_NAME, SCHEMA_MODULE_NAME, "SCHEMA_VERSION"));
+                    this.#blockLength = $(layout.block_length);
+
+                    const schemaId = this.messageHeaderDecoder((header) => $(self.header_field_value("schemaId")?));
+                    if (schemaId !== $(self.import(MESSAGE_MODULE_NAME, SCHEMA_MODULE_NAME, "SCHEMA_ID"))) {
+                        throw $error.wrongSchema(schemaId, $(self.import(MESSAGE_MODULE_NAME, SCHEMA_MODULE_NAME, "SCHEMA_ID")));
+                    }
+
+                    const templateId = this.messageHeaderDecoder((header) => $(self.header_field_value("templateId")?));
+                    if (templateId !== $(&decoder_name).TEMPLATE_ID) {
+                        throw $error.wrongMessageType(templateId, $(&decoder_name).TEMPLATE_ID);
+                    }
+
+                    this.#actingVersion = this.messageHeaderDecoder((header) => $(self.header_field_value("version")?));
+                    this.#blockLength = this.messageHeaderDecoder((header) => $(self.header_field_value("blockLength")?));
+                }
+
+                static id() {
+                    return $(&decoder_name).TEMPLATE_ID;
+                }
+
+                $("/** Version of the schema the decoded message was encoded with. */")
+                actingVersion() {
+                    return this.#actingVersion;
+                }
+
+                $("/**")
+                $(" * Length of the root block of the decoded message, which can differ from")
+                $(" * the one in the schema, when the message was encoded with another version.")
+                $(" */")
+                blockLength() {
+                    return this.#blockLength;
+                }
+
+                $("/**")
+                $(" * Size of the decoded message including its header, skipping all its groups")
+                $(" * and var data.")
+                $(" */")
+                size() {
+                    $(skipped_sizes(&groups, &var_data))
+                    return $header_size + this.#blockLength$(for group in &groups => $[' ']+ this.#$(group.name.to_case(Case::Camel))Size)$(for var in &var_data => $[' ']+ this.#$(var.name.to_case(Case::Camel))Size);
+                }
+
+                $(self.generate_decoder_field(MESSAGE_MODULE_NAME, "message_header", &header_type, 0, 0)?)
+                $(self.generate_field_decoders(&fields, layout.offsets.iter().map(|field_offset| header_size + field_offset))?)
+                $(self.generate_group_decoders(&groups, &message_path, &header_offset)?)
+                $(self.generate_var_data_decoders(&var_data, &groups, &message_path, &header_offset)?)
+            }
+        };
+
+        for group in &groups {
+            decoder_tokens.line();
+            decoder_tokens
+                .append(self.generate_group_decoder(group, &message_path.join(&group.name))?);
+        }
+
+        for var_data_field in &var_data {
+            decoder_tokens.line();
+            decoder_tokens.append(self.generate_var_data_decoder(
+                var_data_field,
+                &message_path.join(&var_data_field.name),
+            )?);
+        }
+
+        Ok(decoder_tokens)
+    }
+
+    /// Generates the encoder of a message, followed by the encoders of its groups and
+    /// var data.
+    fn generate_message_encoder(&self, message: &MessageType) -> Result<Tokens<JavaScript>> {
+        let name = message.name.as_str();
+        let message_path = FieldPath::new(vec![message.name.clone()]);
+        let encoder_name = format!("{}Encoder", scoped_name(&message_path));
+        let (fields, groups, var_data) = field_groups(&message.fields);
+        let write_buf = &self.import(
+            MESSAGE_MODULE_NAME,
+            BUFFER_MODULE_NAME,
+            WRITE_BUF_CLASS_NAME,
+        );
+
+        let layout =
+            BlockLayout::from_fields(name, &fields, message.block_length, &self.schema.types)?;
+
+        let header_type = Type::Composite(self.schema.types.header_type.clone());
+        let header_size = header_type.size(&self.schema.types)?;
+        let header_offset = quote!($(header_size + layout.block_length));
+
+        let header_value = |field_name: &str,
+                            value: Tokens<JavaScript>|
+         -> Result<Tokens<JavaScript>> {
+            Ok(
+                quote!(header.$field_name($(from_number(&self.header_field_native(field_name)?, value)));),
+            )
+        };
+
+        let mut encoder_tokens = quote! {
+            export class $(&encoder_name) {
+                static TEMPLATE_ID = $(message.id);
+                static BLOCK_LENGTH = $(layout.block_length);
+
+                #buffer;
+                $(size_fields(&groups, &var_data))
+
+                constructor(buffer) {
+                    this.#buffer = buffer instanceof $write_buf ? buffer : new $write_buf(buffer);
+
+                    this.messageHeaderEncoder((header) => {
+                        $(header_value("blockLength", quote!($(&encoder_name).BLOCK_LENGTH))?)
+                        $(header_value("templateId", quote!($(&encoder_name).TEMPLATE_ID))?)
+                        $(header_value("schemaId", quote!($(self.import(MESSAGE_MODULE_NAME, SCHEMA_MODULE_NAME, "SCHEMA_ID"))))?)
+                        $(header_value("version", quote!($(self.import(MESSAGE_MODULE_NAME, SCHEMA_MODULE_NAME, "SCHEMA_VERSION"))))?)
+                        $(for (field_name, count) in header_counts(&self.schema.types, groups.len(), var_data.len()) {
+                            $['\r']
+                            $(header_value(field_name, quote!($count))?)
+                        })
+                    });
+                }
+
+                static id() {
+                    return $(&encoder_name).TEMPLATE_ID;
+                }
+
+                $("/**")
+                $(" * Size of the encoded message including its header, or null until all its")
+                $(" * groups and var data were encoded.")
+                $(" */")
+                size() {
+                    $(null_sizes_check(&groups, &var_data))
+                    return $header_size + $(&encoder_name).BLOCK_LENGTH$(for group in &groups => $[' ']+ this.#$(group.name.to_case(Case::Camel))Size)$(for var in &var_data => $[' ']+ this.#$(var.name.to_case(Case::Camel))Size);
+                }
+
+                $(self.generate_encoder_field(MESSAGE_MODULE_NAME, "message_header", &header_type, quote!(0))?)
+                $(self.generate_field_encoders(&fields, None, layout.offsets.iter().map(|field_offset| header_size + field_offset))?)
+                $(self.generate_group_encoders(&groups, &message_path, &header_offset)?)
+                $(self.generate_var_data_encoders(&var_data, &groups, &message_path, &header_offset)?)
+            }
+        };
+
+        for group in &groups {
+            encoder_tokens.line();
+            encoder_tokens
+                .append(self.generate_group_encoder(group, &message_path.join(&group.name))?);
+        }
+
+        for var_data_field in &var_data {
+            encoder_tokens.line();
+            encoder_tokens.append(self.generate_var_data_encoder(
+                var_data_field,
+                &message_path.join(&var_data_field.name),
+            )?);
+        }
+
+        Ok(encoder_tokens)
+    }
+
+    /// Resolved primitive type of a field of the message header.
+    pub(crate) fn header_field_native(&self, field_name: &str) -> Result<NativeType> {
+        let header_type = &self.schema.types.header_type;
+        let field = header_type
+            .fields
+            .iter()
+            .find(|field| field.name() == field_name)
+            .ok_or(anyhow!(
+                "Missing field '{}' in message header '{}'",
+                field_name,
+                header_type.name
+            ))?;
+
+        match self.resolved(field)? {
+            Type::EncodedData(encoded_type) => {
+                Ok(
+                    field_metadata(field_name, &encoded_type, &self.schema.types)?
+                        .field_primitive_type,
+                )
+            }
+            _ => Err(anyhow!(
+                "Only encoded data type expected for the field '{}' in message header",
+                field_name
+            )),
+        }
+    }
+
+    /// Value of a field of the message header decoded by `header`, as a number.
+    pub(crate) fn header_field_value(&self, field_name: &str) -> Result<Tokens<JavaScript>> {
+        Ok(to_number(
+            &self.header_field_native(field_name)?,
+            quote!(header.$field_name()),
+        ))
+    }
+
+    /// Writes the decoder of any message of the schema, which checks the schema id and picks
+    /// the message decoder by the template id in the message header.
+    fn write_message_dispatcher(&self, message_types: &[&MessageType]) -> Result<()> {
+        let header_decoder_class = self.import(
+            DISPATCHER_MODULE_NAME,
+            COMPOSITE_MODULE_NAME,
+            &format!(
+                "{}Decoder",
+                self.schema.types.header_type.name.to_case(Case::UpperCamel)
+            ),
+        );
+        let read_buf = &self.import(
+            DISPATCHER_MODULE_NAME,
+            BUFFER_MODULE_NAME,
+            READ_BUF_CLASS_NAME,
+        );
+        let error = &self.error_class();
+
+        let variants = message_types
+            .iter()
+            .map(|message_type| {
+                let name = message_type.name.to_case(Case::UpperCamel);
+                let decoder_class = self.import(
+                    DISPATCHER_MODULE_NAME,
+                    MESSAGE_MODULE_NAME,
+                    &format!("{}Decoder", name),
+                );
+
+                (name, decoder_class)
+            })
+            .collect::<Vec<_>>();
+
+        let dispatcher_tokens: Tokens<JavaScript> = quote! {
+            $("/** Decoder of any message of the schema. */")
+            export class AnyMessageDecoder {
+                #templateId;
+                #decoder;
+
+                constructor(buffer) {
+                    const messageBuffer = buffer instanceof $read_buf ? buffer : new $read_buf(buffer);
+                    const header = new $header_decoder_class(messageBuffer);
+                    const schemaId = $(self.header_field_value("schemaId")?);
+                    if (schemaId !== $(self.import(DISPATCHER_MODULE_NAME, SCHEMA_MODULE_NAME, "SCHEMA_ID"))) {
+                        throw $error.wrongSchema(schemaId, $(self.import(DISPATCHER_MODULE_NAME, SCHEMA_MODULE_NAME, "SCHEMA_ID")));
+                    }
+
+                    this.#templateId = $(self.header_field_value("templateId")?);
+
+                    switch (this.#templateId) {
+                        $(for (_, decoder_class) in &variants {
+                            $['\r']
+                            case $decoder_class.TEMPLATE_ID:
+                                this.#decoder = new $decoder_class(messageBuffer);
+                                break;
+                        })
+                        default:
+                            throw $error.unknownMessageType(this.#templateId);
+                    }
+                }
+
+                templateId() {
+                    return this.#templateId;
+                }
+
+                $("/** Version of the schema the decoded message was encoded with. */")
+                actingVersion() {
+                    return this.#decoder.actingVersion();
+                }
+
+                $("/** Decoder of the message, of the class with the template id of the message. */")
+                decoder() {
+                    return this.#decoder;
+                }
+
+                $("/** Calls the method of the visitor for the type of the decoded message, if it has one. */")
+                accept(visitor) {
+                    switch (this.#templateId) {
+                        $(for (name, decoder_class) in &variants {
+                            $['\r']
+                            case $decoder_class.TEMPLATE_ID:
+                                visitor.on$(name)?.(this.#decoder);
+                                break;
+                        })
+                    }
+                }
+            }
+        };
+
+        self.write_module(DISPATCHER_MODULE_NAME, dispatcher_tokens)
+    }
+
+    pub fn write_message_codecs(&self) -> Result<()> {
+        let message_types = self
+            .schema
+            .message_types
+            .message_types
+            .values()
+            .collect::<Vec<_>>();
+        let mut message_tokens: Tokens<JavaScript> = quote!();
+
+        for message_type in &message_types {
+            message_tokens.line();
+            message_tokens.append(self.generate_message_decoder(message_type)?);
+            message_tokens.line();
+            message_tokens.append(self.generate_message_encoder(message_type)?);
+        }
+
+        self.write_module(MESSAGE_MODULE_NAME, message_tokens)?;
+
+        if !message_types.is_empty() {
+            self.write_message_dispatcher(&message_types)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns null early from the size of a message, until the sizes of all its groups and
+/// var data were recorded.
+fn null_sizes_check(groups: &[&GroupType], var_data: &[&VariableDataType]) -> Tokens<JavaScript> {
+    let names = groups
+        .iter()
+        .map(|group| group.name.as_str())
+        .chain(var_data.iter().map(|var| var.name.as_str()))
+        .map(|name| format!("this.#{}Size === null", name.to_case(Case::Camel)))
+        .collect::<Vec<_>>();
+
+    if names.is_empty() {
+        return quote!();
+    }
+
+    quote! {
+        if ($(names.join(" || "))) {
+            return null;
+        }
+        $['\n']
+    }
+}
@@ -0,0 +1,211 @@
+mod composite_type;
+mod enum_type;
+mod group_type;
+mod message_type;
+mod set_type;
+mod var_data_type;
+
+pub(crate) use group_type::scoped_name;
+pub(crate) use var_data_type::is_bytes;
+
+use crate::generator::common::FieldMetadata;
+use crate::models::types::encoded_data_type::EncodedDataType;
+use crate::models::types::primitive_type::{NativeType, PrimitiveConvertible, ResolvableType};
+use crate::models::types::SizedEncoded;
+use crate::models::TypeMap;
+use anyhow::Result;
+use convert_case::{Case, Casing};
+use genco::prelude::*;
+
+/// Metadata of an encoded field, with the field name in lower camel case of JavaScript.
+pub(crate) fn field_metadata(
+    field_name: &str,
+    encoded_type: &EncodedDataType,
+    types: &TypeMap,
+) -> Result<FieldMetadata<JavaScript>> {
+    let field_primitive_type = encoded_type.primitive_type.resolved(&types.encoded_types)?;
+    let field_size = encoded_type.size(types)?;
+    let type_size = encoded_type.primitive_type.size(types)?;
+
+    Ok(FieldMetadata {
+        field_name: field_name.to_case(Case::Camel),
+        field_primitive_type: field_primitive_type.clone(),
+        type_size,
+        field_length: field_size / type_size,
+        lang_type: field_primitive_type.lang_primitive(&types.encoded_types)?,
+        encoding: encoded_type.character_encoding,
+    })
+}
+
+/// Suffix of the `ReadBuf`/`WriteBuf` accessors of a resolved primitive type, e.g. `U16`
+/// for `getU16At`.
+pub(crate) fn accessor(native_type: &NativeType) -> &'static str {
+    match native_type {
+        NativeType::Char | NativeType::UInt8 => "U8",
+        NativeType::UInt16 => "U16",
+        NativeType::UInt32 => "U32",
+        NativeType::UInt64 => "U64",
+        NativeType::Int8 => "I8",
+        NativeType::Int16 => "I16",
+        NativeType::Int32 => "I32",
+        NativeType::Int64 => "I64",
+        NativeType::Float => "F32",
+        NativeType::Double => "F64",
+        NativeType::Reference(type_name) => unreachable!("Resolved reference: {}", type_name),
+    }
+}
+
+/// 64-bit integers are read and written as bigints, all other values as numbers.
+pub(crate) fn is_bigint(native_type: &NativeType) -> bool {
+    matches!(native_type, NativeType::UInt64 | NativeType::Int64)
+}
+
+/// Converts a value read from a buffer to a number, used for enums, counters and other
+/// values which always fit into one.
+pub(crate) fn to_number(
+    native_type: &NativeType,
+    value: impl FormatInto<JavaScript>,
+) -> Tokens<JavaScript> {
+    if is_bigint(native_type) {
+        quote!(Number($value))
+    } else {
+        quote!($value)
+    }
+}
+
+/// Converts a number to a value to be written to a buffer, the counterpart of `to_number`.
+pub(crate) fn from_number(
+    native_type: &NativeType,
+    value: impl FormatInto<JavaScript>,
+) -> Tokens<JavaScript> {
+    if is_bigint(native_type) {
+        quote!(BigInt($value))
+    } else {
+        quote!($value)
+    }
+}
+
+/// JavaScript literal of a numeric value from the schema, a bigint literal for 64-bit
+/// integers.
+pub(crate) fn literal(value: &str, native_type: &NativeType) -> String {
+    if is_bigint(native_type) {
+        format!("{}n", value)
+    } else {
+        value.to_owned()
+    }
+}
+
+/// Default null value of a primitive type, see `NativeType::null`.
+pub(crate) fn null_literal(native_type: &NativeType) -> &'static str {
+    match native_type {
+        NativeType::Char => "0",
+        NativeType::UInt8 => "255",
+        NativeType::UInt16 => "65535",
+        NativeType::UInt32 => "4294967295",
+        NativeType::UInt64 => "18446744073709551615n",
+        NativeType::Int8 => "-128",
+        NativeType::Int16 => "-32768",
+        NativeType::Int32 => "-2147483648",
+        NativeType::Int64 => "-9223372036854775808n",
+        NativeType::Float | NativeType::Double | NativeType::Reference(_) => "NaN",
+    }
+}
+
+/// Largest value of an integer primitive type, used for the counters of groups and var data.
+/// Counters are numbers, so the ones of 64-bit integers are limited to the largest safe one.
+pub(crate) fn max_literal(native_type: &NativeType) -> &'static str {
+    match native_type {
+        NativeType::Char | NativeType::Int8 => "127",
+        NativeType::UInt8 => "255",
+        NativeType::UInt16 => "65535",
+        NativeType::UInt32 => "4294967295",
+        NativeType::Int16 => "32767",
+        NativeType::Int32 => "2147483647",
+        _ => "Number.MAX_SAFE_INTEGER",
+    }
+}
+
+/// Null value of an optional field, either declared by the `nullValue` of the encoded type,
+/// or the default one for its primitive type.
+pub(crate) fn null_value(
+    encoded_type: &EncodedDataType,
+    metadata: &FieldMetadata<JavaScript>,
+) -> String {
+    match &encoded_type.null_value {
+        Some(null_value) => literal(null_value, &metadata.field_primitive_type),
+        None => null_literal(&metadata.field_primitive_type).to_owned(),
+    }
+}
+
+pub(crate) fn null_value_condition(
+    variable: &str,
+    encoded_type: &EncodedDataType,
+    metadata: &FieldMetadata<JavaScript>,
+) -> Tokens<JavaScript> {
+    match (&metadata.field_primitive_type, &encoded_type.null_value) {
+        (NativeType::Float | NativeType::Double, None) => quote!(Number.isNaN($variable)),
+        _ => quote!($variable === $(null_value(encoded_type, metadata))),
+    }
+}
+
+/// Checks of a `variable` against the `minValue` and `maxValue` of the encoded type.
+pub(crate) fn bounds_checks(
+    field_name: &str,
+    variable: &str,
+    encoded_type: &EncodedDataType,
+    native_type: &NativeType,
+    error: &js::Import,
+) -> Tokens<JavaScript> {
+    let mut checks = quote!();
+    let unsigned = matches!(
+        native_type,
+        NativeType::UInt8 | NativeType::UInt16 | NativeType::UInt32 | NativeType::UInt64
+    );
+
+    if let Some(min) = &encoded_type.min_value {
+        // Unsigned values can never be below zero
+        if !(unsigned && min.parse::<u64>() == Ok(0)) {
+            checks.append(bounds_check(
+                field_name,
+                variable,
+                "<",
+                min,
+                native_type,
+                "min",
+                error,
+            ));
+            checks.line();
+        }
+    }
+
+    if let Some(max) = &encoded_type.max_value {
+        checks.append(bounds_check(
+            field_name,
+            variable,
+            ">",
+            max,
+            native_type,
+            "max",
+            error,
+        ));
+        checks.line();
+    }
+
+    checks
+}
+
+fn bounds_check(
+    field_name: &str,
+    variable: &str,
+    operator: &str,
+    limit: &str,
+    native_type: &NativeType,
+    limit_name: &str,
+    error: &js::Import,
+) -> Tokens<JavaScript> {
+    quote! {
+        if ($variable $operator $(literal(limit, native_type))) {
+            throw $error.valueOutOfBounds($(quoted(field_name)), $variable + $(quoted(format!(" {} {} ({})", operator, limit, limit_name))));
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use anyhow::Result;
+use convert_case::{Case, Casing};
+
+use crate::generator::typescript::codecs::is_bigint;
+use crate::generator::typescript::constants::SET_MODULE_NAME;
+use crate::generator::typescript::module::ModuleGenerator;
+use crate::models::types::primitive_type::ResolvableType;
+use crate::models::types::set_type::{Choice, SetType};
+use genco::prelude::*;
+
+impl ModuleGenerator<'_> {
+    /// Generates the boolean property of a choice. Bitwise operators on numbers work on
+    /// signed 32-bit integers, so their results are converted back to unsigned ones.
+    fn generate_choice_token(choice: &Choice, bigint: bool) -> Tokens<JavaScript> {
+        let choice_name = choice.name.to_case(Case::Camel);
+        let (mask, zero, unsigned) = if bigint {
+            (format!("(1n << {}n)", choice.value), "0n", "")
+        } else {
+            (format!("(1 << {})", choice.value), "0", " >>> 0")
+        };
+
+        quote! {
+            $['\n']
+            get $(&choice_name)() {
+                return (this.value & $(&mask)) !== $zero;
+            }
+
+            set $(&choice_name)(flag) {
+                this.value = (flag ? this.value | $(&mask) : this.value & ~$(&mask))$unsigned;
+            }
+        }
+    }
+
+    fn generate_set_codec(&self, set_type: &SetType) -> Result<Tokens<JavaScript>> {
+        let name = set_type.name.to_case(Case::UpperCamel);
+        let bigint = is_bigint(
+            &set_type
+                .encoding_type
+                .resolved(&self.schema.types.encoded_types)?,
+        );
+        let zero = if bigint { "0n" } else { "0" };
+
+        Ok(quote! {
+            export class $(&name) {
+                constructor(value = $zero) {
+                    this.value = value;
+                }
+
+                clear() {
+                    this.value = $zero;
+                    return this;
+                }
+                $(for choice in &set_type.choices => $(Self::generate_choice_token(choice, bigint)))
+
+                toString() {
+                    return $(quoted(format!("{}(", name))) + this.value + ")";
+                }
+            }
+        })
+    }
+
+    pub fn write_set_codecs(&self) -> Result<()> {
+        let mut set_tokens: Tokens<JavaScript> = quote!();
+
+        for set_type in self.schema.types.set_types.values() {
+            set_tokens.line();
+            set_tokens.append(self.generate_set_codec(set_type)?);
+        }
+
+        self.write_module(SET_MODULE_NAME, set_tokens)
+    }
+}
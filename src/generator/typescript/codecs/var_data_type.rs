@@ -0,0 +1,185 @@
+use crate::generator::common::FieldMetadata;
+use crate::generator::typescript::codecs::group_type::scoped_name;
+use crate::generator::typescript::codecs::{
+    accessor, field_metadata, from_number, max_literal, to_number,
+};
+use crate::generator::typescript::module::ModuleGenerator;
+use crate::models::types::primitive_type::NativeType;
+use crate::models::types::variable_data_type::VariableDataType;
+use crate::models::types::{CharacterEncoding, Type};
+use crate::models::FieldPath;
+use anyhow::{anyhow, Result};
+use convert_case::{Case, Casing};
+use genco::prelude::*;
+
+impl ModuleGenerator<'_> {
+    /// Generates the decoder of var data, scoped by the names of its message and enclosing
+    /// groups.
+    pub(crate) fn generate_var_data_decoder(
+        &self,
+        var_data: &VariableDataType,
+        var_data_path: &FieldPath,
+    ) -> Result<Tokens<JavaScript>> {
+        let decoder_name = format!("{}Decoder", scoped_name(var_data_path));
+        let (length_type_metadata, value_type_metadata) = self.repr_type_metadata(var_data)?;
+
+        let length_type_native = &length_type_metadata.field_primitive_type;
+        let length_type_size = length_type_metadata.type_size;
+
+        let value_type_native = &value_type_metadata.field_primitive_type;
+        let value_type_size = value_type_metadata.type_size;
+
+        Ok(quote! {
+            export class $(&decoder_name) {
+                #buffer;
+
+                constructor(buffer) {
+                    this.#buffer = buffer;
+                }
+
+                length() {
+                    // Var data missing in older versions of the schema has no buffer at all
+                    if (this.#buffer.length() < $length_type_size) {
+                        return 0;
+                    }
+
+                    return $(to_number(length_type_native, quote!(this.#buffer.get$(accessor(length_type_native))At(0))));
+                }
+
+                size() {
+                    return this.length() * $value_type_size + $length_type_size;
+                }
+
+                getAt(index) {
+                    const offset = index * $value_type_size + $length_type_size;
+
+                    return this.#buffer.get$(accessor(value_type_native))At(offset);
+                }
+                $(if is_bytes(value_type_native) {
+                    $['\n']
+                    $("/** Returns the values as a view of the decoded data, without copying them. */")
+                    getSliceAt(index = 0, length = this.length() - index) {
+                        if (length === 0) {
+                            return new Uint8Array(0);
+                        }
+
+                        const offset = index * $value_type_size + $length_type_size;
+
+                        return this.#buffer.getSliceAt(offset, length);
+                    }
+                })
+            }
+        })
+    }
+
+    /// Generates the encoder of var data, scoped by the names of its message and enclosing
+    /// groups.
+    pub(crate) fn generate_var_data_encoder(
+        &self,
+        var_data: &VariableDataType,
+        var_data_path: &FieldPath,
+    ) -> Result<Tokens<JavaScript>> {
+        let name = var_data.name.to_case(Case::Camel);
+        let encoder_name = format!("{}Encoder", scoped_name(var_data_path));
+        let error = &self.error_class();
+        let (length_type_metadata, value_type_metadata) = self.repr_type_metadata(var_data)?;
+
+        let length_type_native = &length_type_metadata.field_primitive_type;
+        let length_type_size = length_type_metadata.type_size;
+        let max_length = max_literal(length_type_native);
+
+        let value_type_native = &value_type_metadata.field_primitive_type;
+        let value_type_size = value_type_metadata.type_size;
+        let ascii = matches!(value_type_metadata.encoding, Some(CharacterEncoding::Ascii));
+
+        Ok(quote! {
+            export class $(&encoder_name) {
+                #buffer;
+                #length = 0;
+
+                constructor(buffer) {
+                    this.#buffer = buffer;
+                }
+
+                size() {
+                    return this.#length * $value_type_size + $length_type_size;
+                }
+
+                putAt(index, value) {
+                    $(if ascii {
+                        if (value > 127) {
+                            throw $error.invalidStringValue(String.fromCharCode(value));
+                        }
+                        $['\n']
+                    })
+                    if (index >= this.#length) {
+                        if (index + 1 > $max_length) {
+                            throw $error.varDataOutOfBounds($(quoted(&name)));
+                        }
+
+                        this.#length = index + 1;
+                    }
+
+                    const offset = index * $value_type_size + $length_type_size;
+                    this.#buffer.put$(accessor(value_type_native))At(offset, value);
+                }
+                $(if is_bytes(value_type_native) {
+                    $['\n']
+                    putSliceAt(index, value) {
+                        $(if ascii {
+                            if (value.some((part) => part > 127)) {
+                                throw $error.invalidStringValue(new TextDecoder().decode(value));
+                            }
+                            $['\n']
+                        })
+                        const dataEnd = index + value.length;
+
+                        if (dataEnd >= this.#length) {
+                            if (dataEnd > $max_length) {
+                                throw $error.varDataOutOfBounds($(quoted(&name)));
+                            }
+
+                            this.#length = dataEnd;
+                        }
+
+                        const offset = index * $value_type_size + $length_type_size;
+                        this.#buffer.putBytesAt(offset, value);
+                    }
+                })
+
+                $("/** Writes the length of the var data, after all its values were encoded. */")
+                finish() {
+                    this.#buffer.put$(accessor(length_type_native))At(0, $(from_number(length_type_native, quote!(this.#length))));
+                }
+            }
+        })
+    }
+
+    /// Metadata of the length and the values of the encoding of var data.
+    pub(crate) fn repr_type_metadata(
+        &self,
+        var_data: &VariableDataType,
+    ) -> Result<(FieldMetadata<JavaScript>, FieldMetadata<JavaScript>)> {
+        let repr_type = var_data.repr_type(&self.schema.types.composite_types)?;
+        let (_, length_type) = self.counter_field(repr_type, 0)?;
+        let length_type_metadata = field_metadata("", &length_type, &self.schema.types)?;
+
+        let value_type = match repr_type.fields.get(1).map(|field| self.resolved(field)) {
+            Some(Ok(Type::EncodedData(value_type))) => value_type,
+            _ => {
+                return Err(anyhow!(
+                "Only encoded data type expected for the value type in variable data encoding '{}'",
+                var_data.name
+            ))
+            }
+        };
+        let value_type_metadata = field_metadata("", &value_type, &self.schema.types)?;
+
+        Ok((length_type_metadata, value_type_metadata))
+    }
+}
+
+/// Single byte values can be read and written as slices.
+pub(crate) fn is_bytes(native_type: &NativeType) -> bool {
+    matches!(native_type, NativeType::Char | NativeType::UInt8)
+}
@@ -0,0 +1,13 @@
+pub const SRC_DIR: &str = "src";
+pub const ERROR_MODULE_NAME: &str = "error";
+pub const SCHEMA_MODULE_NAME: &str = "schema";
+pub const BUFFER_MODULE_NAME: &str = "buffer";
+pub const ENUM_MODULE_NAME: &str = "enums";
+pub const SET_MODULE_NAME: &str = "sets";
+pub const COMPOSITE_MODULE_NAME: &str = "composites";
+pub const MESSAGE_MODULE_NAME: &str = "messages";
+pub const DISPATCHER_MODULE_NAME: &str = "dispatcher";
+pub const INDEX_MODULE_NAME: &str = "index";
+pub const ERROR_CLASS_NAME: &str = "SbeError";
+pub const READ_BUF_CLASS_NAME: &str = "ReadBuf";
+pub const WRITE_BUF_CLASS_NAME: &str = "WriteBuf";
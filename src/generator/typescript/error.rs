@@ -0,0 +1,186 @@
+use crate::generator::typescript::constants::ERROR_MODULE_NAME;
+use crate::generator::typescript::{write_declarations, write_module, TypeScriptGenerator};
+use anyhow::Result;
+use genco::prelude::*;
+
+/// Kinds of the errors of the codecs, along with the names and the parameters of their
+/// factory methods, which are declared with their TypeScript types.
+const ERROR_KINDS: &[(&str, &str, &str)] = &[
+    (
+        "INVALID_STRING_VALUE",
+        "invalidStringValue",
+        "value: string",
+    ),
+    (
+        "INVALID_ENUM_VALUE",
+        "invalidEnumValue",
+        "typeName: string, value: number | bigint",
+    ),
+    (
+        "VALUE_OUT_OF_BOUNDS",
+        "valueOutOfBounds",
+        "fieldName: string, message: string",
+    ),
+    ("WRONG_SLICE_SIZE", "wrongSliceSize", "message: string"),
+    (
+        "MISSING_GROUP_SIZE",
+        "missingGroupSize",
+        "groupName: string",
+    ),
+    (
+        "MISSING_VAR_DATA_SIZE",
+        "missingVarDataSize",
+        "varDataName: string",
+    ),
+    (
+        "GROUP_OUT_OF_BOUNDS",
+        "groupOutOfBounds",
+        "groupName: string",
+    ),
+    (
+        "VAR_DATA_OUT_OF_BOUNDS",
+        "varDataOutOfBounds",
+        "varDataName: string",
+    ),
+    (
+        "WRONG_SCHEMA",
+        "wrongSchema",
+        "schemaId: number, expectedSchemaId: number",
+    ),
+    (
+        "WRONG_MESSAGE_TYPE",
+        "wrongMessageType",
+        "templateId: number, expectedTemplateId: number",
+    ),
+    (
+        "UNKNOWN_MESSAGE_TYPE",
+        "unknownMessageType",
+        "templateId: number",
+    ),
+    (
+        "CODEC_OUT_OF_BOUNDS",
+        "codecOutOfBounds",
+        "dataEnd: number, length: number",
+    ),
+];
+
+impl TypeScriptGenerator {
+    /// Writes the error of the codecs, shared by all schemas of the project, along with its
+    /// declarations.
+    pub fn write_error_module(&self) -> Result<()> {
+        let error_module_content: Tokens<JavaScript> = quote! {
+            $("/** Kinds of the errors of encoding or decoding a message. */")
+            export const SbeErrorKind = Object.freeze({
+                $(for (kind, _, _) in ERROR_KINDS {
+                    $['\r']
+                    $(*kind): $(quoted(*kind)),
+                })
+            });
+
+            $("/** Error of encoding or decoding a message. */")
+            export class SbeError extends Error {
+                constructor(kind, message) {
+                    super(message);
+                    this.name = "SbeError";
+                    this.kind = kind;
+                }
+
+                static invalidStringValue(value) {
+                    return new SbeError(SbeErrorKind.INVALID_STRING_VALUE, "invalid ascii string: " + value);
+                }
+
+                static invalidEnumValue(typeName, value) {
+                    return new SbeError(SbeErrorKind.INVALID_ENUM_VALUE, "invalid enum value for '" + typeName + "': " + value);
+                }
+
+                static valueOutOfBounds(fieldName, message) {
+                    return new SbeError(SbeErrorKind.VALUE_OUT_OF_BOUNDS, "value out of bounds for field '" + fieldName + "': " + message);
+                }
+
+                static wrongSliceSize(message) {
+                    return new SbeError(SbeErrorKind.WRONG_SLICE_SIZE, "wrong slice size: " + message);
+                }
+
+                static missingGroupSize(groupName) {
+                    return new SbeError(SbeErrorKind.MISSING_GROUP_SIZE, "missing group size: " + groupName);
+                }
+
+                static missingVarDataSize(varDataName) {
+                    return new SbeError(SbeErrorKind.MISSING_VAR_DATA_SIZE, "missing var data size: " + varDataName);
+                }
+
+                static groupOutOfBounds(groupName) {
+                    return new SbeError(SbeErrorKind.GROUP_OUT_OF_BOUNDS, "group out of bounds: " + groupName);
+                }
+
+                static varDataOutOfBounds(varDataName) {
+                    return new SbeError(SbeErrorKind.VAR_DATA_OUT_OF_BOUNDS, "var data out of bounds: " + varDataName);
+                }
+
+                static wrongSchema(schemaId, expectedSchemaId) {
+                    return new SbeError(SbeErrorKind.WRONG_SCHEMA, "received message had wrong schema: " + schemaId + ", expected " + expectedSchemaId);
+                }
+
+                static wrongMessageType(templateId, expectedTemplateId) {
+                    return new SbeError(SbeErrorKind.WRONG_MESSAGE_TYPE, "received message had wrong type: " + templateId + ", expected " + expectedTemplateId);
+                }
+
+                static unknownMessageType(templateId) {
+                    return new SbeError(SbeErrorKind.UNKNOWN_MESSAGE_TYPE, "received message had unknown type: " + templateId);
+                }
+
+                static codecOutOfBounds(dataEnd, length) {
+                    return new SbeError(SbeErrorKind.CODEC_OUT_OF_BOUNDS, "codec out of bounds: " + dataEnd + " > " + length);
+                }
+
+                $("/** Returns the size of a group, which must be decoded or encoded first. */")
+                static requireGroupSize(size, groupName) {
+                    if (size === null) {
+                        throw SbeError.missingGroupSize(groupName);
+                    }
+
+                    return size;
+                }
+
+                $("/** Returns the size of a var data field, which must be decoded or encoded first. */")
+                static requireVarDataSize(size, varDataName) {
+                    if (size === null) {
+                        throw SbeError.missingVarDataSize(varDataName);
+                    }
+
+                    return size;
+                }
+            }
+        };
+
+        let error_declarations: Tokens<JavaScript> = quote! {
+            $("/** Kinds of the errors of encoding or decoding a message. */")
+            export declare enum SbeErrorKind {
+                $(for (kind, _, _) in ERROR_KINDS {
+                    $['\r']
+                    $(*kind) = $(quoted(*kind)),
+                })
+            }
+
+            $("/** Error of encoding or decoding a message. */")
+            export declare class SbeError extends Error {
+                readonly kind: SbeErrorKind;
+
+                constructor(kind: SbeErrorKind, message: string);
+                $(for (_, factory, parameters) in ERROR_KINDS {
+                    $['\r']
+                    static $(*factory)($(*parameters)): SbeError;
+                })
+
+                $("/** Returns the size of a group, which must be decoded or encoded first. */")
+                static requireGroupSize(size: number | null, groupName: string): number;
+
+                $("/** Returns the size of a var data field, which must be decoded or encoded first. */")
+                static requireVarDataSize(size: number | null, varDataName: string): number;
+            }
+        };
+
+        write_module(&self.src_path(), ERROR_MODULE_NAME, error_module_content)?;
+        write_declarations(&self.src_path(), ERROR_MODULE_NAME, error_declarations)
+    }
+}
@@ -0,0 +1,207 @@
+mod buffer;
+mod codecs;
+mod constants;
+mod error;
+mod module;
+mod typing;
+
+use crate::generator::typescript::constants::{ERROR_MODULE_NAME, INDEX_MODULE_NAME, SRC_DIR};
+use crate::generator::{write_file, CodeGenerator};
+use crate::models::schema::ValidatedMessageSchema;
+use anyhow::Result;
+use convert_case::{Case, Casing};
+use indoc::formatdoc;
+use std::collections::BTreeMap;
+use std::fs::{create_dir_all, File};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::models::types::encoded_data_type::EncodedDataType;
+use crate::models::types::primitive_type::{
+    LanguagePrimitive, NativeType, PrimitiveConvertible, ResolvableType,
+};
+use genco::prelude::*;
+
+/// Generates an npm package of JavaScript modules with TypeScript declarations (`.d.ts`)
+/// next to each of them, so the codecs can be used from both languages without a build
+/// step.
+pub struct TypeScriptGenerator {
+    path: PathBuf,
+    project_name: String,
+    project_version: String,
+    bounds_checks: bool,
+    schemas: Vec<ValidatedMessageSchema>,
+}
+
+impl TypeScriptGenerator {
+    pub fn new(
+        path: &Path,
+        project_name: &str,
+        project_version: &str,
+        schemas: Vec<ValidatedMessageSchema>,
+        bounds_checks: bool,
+    ) -> Self {
+        Self {
+            path: path.to_owned(),
+            project_name: project_name.to_owned(),
+            project_version: project_version.to_owned(),
+            bounds_checks,
+            schemas,
+        }
+    }
+
+    fn src_path(&self) -> PathBuf {
+        self.path.join(SRC_DIR)
+    }
+
+    fn write_project_files(&self, with_test_dependencies: bool) -> Result<()> {
+        create_dir_all(self.src_path())?;
+
+        self.write_package_json(with_test_dependencies)?;
+        self.write_error_module()?;
+        self.write_index_module()
+    }
+
+    fn write_package_json(&self, with_test_dependencies: bool) -> Result<()> {
+        // Every schema is also exported as a subpath of the package, e.g. `name/baseline`
+        let exports = std::iter::once(package_export(".", INDEX_MODULE_NAME))
+            .chain(self.schemas.iter().map(|schema| {
+                package_export(
+                    &format!("./{}", schema.package),
+                    &format!("{}/{}", schema.package, INDEX_MODULE_NAME),
+                )
+            }))
+            .collect::<Vec<_>>()
+            .join(",\n");
+
+        // Tests only need the test runner of Node.js, so there are no test dependencies
+        let scripts = if with_test_dependencies {
+            ",\n    \"scripts\": {\n        \"test\": \"node --test\"\n    }"
+        } else {
+            ""
+        };
+
+        let package_json_content = formatdoc! {"
+                {{
+                    \"name\": \"{name}\",
+                    \"version\": \"{version}\",
+                    \"type\": \"module\",
+                    \"main\": \"./{src}/{index}.js\",
+                    \"types\": \"./{src}/{index}.d.ts\",
+                    \"exports\": {{
+                {exports}
+                    }},
+                    \"files\": [
+                        \"{src}\"
+                    ]{scripts}
+                }}
+            ",
+            name = self.project_name.to_case(Case::Kebab),
+            version = self.project_version,
+            src = SRC_DIR,
+            index = INDEX_MODULE_NAME,
+        };
+
+        let package_json_path = self.path.join("package.json");
+        let mut package_json_file = File::create(package_json_path)?;
+        package_json_file.write_all(package_json_content.as_bytes())?;
+
+        Ok(())
+    }
+
+    /// Writes the entry point of the package, which exports the codecs of every schema as a
+    /// namespace named after the package of the schema. The declarations are the same as
+    /// the module itself.
+    fn write_index_module(&self) -> Result<()> {
+        let error_module = format!("./{}.js", ERROR_MODULE_NAME);
+
+        let index_tokens = || -> Tokens<JavaScript> {
+            quote! {
+                export { SbeError, SbeErrorKind } from $(quoted(&error_module));
+                $(for schema in &self.schemas {
+                    $['\r']
+                    export * as $(schema.package.to_case(Case::Camel)) from $(quoted(format!("./{}/{}.js", schema.package, INDEX_MODULE_NAME)));
+                })
+            }
+        };
+
+        write_module(&self.src_path(), INDEX_MODULE_NAME, index_tokens())?;
+        write_declarations(&self.src_path(), INDEX_MODULE_NAME, index_tokens())
+    }
+}
+
+impl CodeGenerator for TypeScriptGenerator {
+    fn generate_project(&self, with_test_dependencies: bool, _format_project: bool) -> Result<()> {
+        // Project files (package.json, ...)
+        self.write_project_files(with_test_dependencies)?;
+
+        // Generate modules. There is no formatter for JavaScript everyone has installed,
+        // so the project is formatted only by the generator itself.
+        for schema in &self.schemas {
+            let module_generator = module::ModuleGenerator::new(
+                &self.src_path().join(&schema.package),
+                schema,
+                self.bounds_checks,
+            );
+
+            module_generator.generate_module()?;
+            typing::TypingGenerator::new(&module_generator).write_declarations()?;
+        }
+
+        Ok(())
+    }
+}
+
+impl PrimitiveConvertible<JavaScript> for NativeType {
+    fn lang_primitive(
+        &self,
+        encoded_types: &BTreeMap<String, EncodedDataType>,
+    ) -> Result<LanguagePrimitive<JavaScript>> {
+        let native_type = self.resolved(encoded_types)?;
+
+        // Numbers hold integers of up to 53 bits exactly, so the 64-bit ones are bigints
+        Ok(LanguagePrimitive::new(match native_type {
+            NativeType::UInt64 | NativeType::Int64 => "bigint",
+            NativeType::Reference(type_name) => unreachable!("Resolved reference: {}", type_name),
+            _ => "number",
+        }))
+    }
+}
+
+/// Entry of the `exports` of package.json, pointing to a module and its declarations.
+fn package_export(subpath: &str, module: &str) -> String {
+    formatdoc! {"
+            \"{subpath}\": {{
+                \"types\": \"./{src}/{module}.d.ts\",
+                \"default\": \"./{src}/{module}.js\"
+            }}",
+        src = SRC_DIR,
+    }
+    .lines()
+    .map(|line| format!("        {}", line))
+    .collect::<Vec<_>>()
+    .join("\n")
+}
+
+/// Writes the JavaScript module `<name>.js` into the directory.
+fn write_module(dir: &Path, name: &str, content: Tokens<JavaScript>) -> Result<()> {
+    write_file(
+        &dir.join(format!("{}.js", name)),
+        &js::Config::default(),
+        content,
+    )
+}
+
+/// Writes the TypeScript declarations `<name>.d.ts` of the module `<name>.js`. Declarations
+/// without any export are still marked as a module, so they can be re-exported.
+fn write_declarations(dir: &Path, name: &str, mut content: Tokens<JavaScript>) -> Result<()> {
+    if content.is_empty() {
+        content = quote!(export {};);
+    }
+
+    write_file(
+        &dir.join(format!("{}.d.ts", name)),
+        &js::Config::default(),
+        content,
+    )
+}
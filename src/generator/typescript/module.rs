@@ -0,0 +1,113 @@
+use crate::generator::typescript::constants::{
+    BUFFER_MODULE_NAME, COMPOSITE_MODULE_NAME, DISPATCHER_MODULE_NAME, ENUM_MODULE_NAME,
+    ERROR_CLASS_NAME, ERROR_MODULE_NAME, INDEX_MODULE_NAME, MESSAGE_MODULE_NAME,
+    SCHEMA_MODULE_NAME, SET_MODULE_NAME,
+};
+use crate::generator::typescript::{write_declarations, write_module};
+use crate::models::schema::ValidatedMessageSchema;
+use anyhow::Result;
+use genco::prelude::*;
+use std::fs::create_dir_all;
+use std::path::{Path, PathBuf};
+
+pub struct ModuleGenerator<'a> {
+    pub(crate) path: PathBuf,
+    pub(crate) schema: &'a ValidatedMessageSchema,
+    pub(crate) bounds_checks: bool,
+}
+
+impl<'a> ModuleGenerator<'a> {
+    pub fn new(path: &Path, schema: &'a ValidatedMessageSchema, bounds_checks: bool) -> Self {
+        Self {
+            path: path.to_owned(),
+            schema,
+            bounds_checks,
+        }
+    }
+
+    pub fn generate_module(&self) -> Result<()> {
+        create_dir_all(&self.path)?;
+
+        self.write_schema_module()?;
+
+        // Buffers the codecs read from and write to
+        self.write_buffer_module()?;
+
+        // Type specific encoders and decoders, the ones of groups and var data are scoped
+        // by the names of their messages and enclosing groups
+        self.write_enum_codecs()?;
+        self.write_set_codecs()?;
+        self.write_composite_codecs()?;
+        self.write_message_codecs()?;
+
+        self.write_index_module()
+    }
+
+    fn write_schema_module(&self) -> Result<()> {
+        let schema_tokens = || -> Tokens<JavaScript> {
+            quote! {
+                export const SCHEMA_ID = $(self.schema.id);
+                export const SCHEMA_VERSION = $(self.schema.version);
+            }
+        };
+
+        write_module(&self.path, SCHEMA_MODULE_NAME, schema_tokens())?;
+        write_declarations(&self.path, SCHEMA_MODULE_NAME, schema_tokens())
+    }
+
+    /// Writes the entry point of the codecs of the schema, which re-exports all of its
+    /// modules. The declarations are the same as the module itself.
+    fn write_index_module(&self) -> Result<()> {
+        let mut modules = vec![
+            SCHEMA_MODULE_NAME,
+            BUFFER_MODULE_NAME,
+            ENUM_MODULE_NAME,
+            SET_MODULE_NAME,
+            COMPOSITE_MODULE_NAME,
+            MESSAGE_MODULE_NAME,
+        ];
+
+        if !self.schema.message_types.message_types.is_empty() {
+            modules.push(DISPATCHER_MODULE_NAME);
+        }
+
+        let index_tokens = || -> Tokens<JavaScript> {
+            quote! {
+                $(for module in &modules {
+                    $['\r']
+                    export * from $(quoted(format!("./{}.js", module)));
+                })
+            }
+        };
+
+        write_module(&self.path, INDEX_MODULE_NAME, index_tokens())?;
+        write_declarations(&self.path, INDEX_MODULE_NAME, index_tokens())
+    }
+
+    pub(crate) fn write_module(&self, name: &str, content: Tokens<JavaScript>) -> Result<()> {
+        write_module(&self.path, name, content)
+    }
+
+    /// Error raised by the codecs, shared by all schemas of the project.
+    pub(crate) fn error_class(&self) -> js::Import {
+        js::import(
+            format!("../{}.js", ERROR_MODULE_NAME).as_str(),
+            ERROR_CLASS_NAME,
+        )
+    }
+
+    /// Class or function exported by a module of the schema, imported into the module
+    /// being written, unless it is the same module.
+    pub(crate) fn import(
+        &self,
+        current_module: &str,
+        module_name: &str,
+        name: &str,
+    ) -> Tokens<JavaScript> {
+        if current_module == module_name {
+            quote!($name)
+        } else {
+            quote!($(js::import(format!("./{}.js", module_name).as_str(), name.to_owned())))
+        }
+    }
+}
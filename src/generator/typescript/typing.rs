@@ -0,0 +1,727 @@
+use crate::generator::common::{field_groups, FieldMetadata};
+use crate::generator::typescript::buffer::ACCESSORS;
+use crate::generator::typescript::codecs::{field_metadata, is_bigint, is_bytes, scoped_name};
+use crate::generator::typescript::constants::{
+    BUFFER_MODULE_NAME, COMPOSITE_MODULE_NAME, DISPATCHER_MODULE_NAME, ENUM_MODULE_NAME,
+    MESSAGE_MODULE_NAME, READ_BUF_CLASS_NAME, SET_MODULE_NAME, WRITE_BUF_CLASS_NAME,
+};
+use crate::generator::typescript::module::ModuleGenerator;
+use crate::generator::typescript::write_declarations;
+use crate::models::message::MessageType;
+use crate::models::types::composite_type::CompositeType;
+use crate::models::types::field_type::FieldType;
+use crate::models::types::group_type::GroupType;
+use crate::models::types::primitive_type::{NativeType, ResolvableType};
+use crate::models::types::set_type::SetType;
+use crate::models::types::variable_data_type::VariableDataType;
+use crate::models::types::{MessageField, Presence, Type};
+use crate::models::FieldPath;
+use anyhow::Result;
+use convert_case::{Case, Casing};
+use genco::prelude::*;
+
+/// Bytes the buffers and the message codecs can wrap.
+const BUFFER_SOURCE: &str = "ArrayBuffer | ArrayBufferView";
+
+/// Writes the TypeScript declarations (`.d.ts`) of the modules of a schema, which type the
+/// values of the fields, the codecs of composites, groups and var data and the callbacks
+/// they are passed to.
+pub struct TypingGenerator<'a> {
+    generator: &'a ModuleGenerator<'a>,
+}
+
+impl<'a> TypingGenerator<'a> {
+    pub fn new(generator: &'a ModuleGenerator<'a>) -> Self {
+        Self { generator }
+    }
+
+    pub fn write_declarations(&self) -> Result<()> {
+        let message_types = self
+            .generator
+            .schema
+            .message_types
+            .message_types
+            .values()
+            .collect::<Vec<_>>();
+
+        self.write(BUFFER_MODULE_NAME, self.buffer_declarations())?;
+        self.write(ENUM_MODULE_NAME, self.enum_declarations()?)?;
+        self.write(SET_MODULE_NAME, self.set_declarations()?)?;
+        self.write(COMPOSITE_MODULE_NAME, self.composite_declarations()?)?;
+        self.write(
+            MESSAGE_MODULE_NAME,
+            self.message_declarations(&message_types)?,
+        )?;
+
+        if !message_types.is_empty() {
+            self.write(
+                DISPATCHER_MODULE_NAME,
+                self.dispatcher_declarations(&message_types),
+            )?;
+        }
+
+        Ok(())
+    }
+
+    fn write(&self, module_name: &str, content: Tokens<JavaScript>) -> Result<()> {
+        write_declarations(&self.generator.path, module_name, content)
+    }
+
+    fn buffer_declarations(&self) -> Tokens<JavaScript> {
+        let value_type = |method: &str| {
+            if method.starts_with("Big") {
+                "bigint"
+            } else {
+                "number"
+            }
+        };
+
+        quote! {
+            $("/** Read-only view of the bytes of an encoded message, or a part of it. */")
+            export declare class $READ_BUF_CLASS_NAME {
+                #private;
+
+                constructor(data: $BUFFER_SOURCE);
+
+                static empty(): $READ_BUF_CLASS_NAME;
+
+                length(): number;
+
+                $("/** Returns the bytes from the index to the end of the buffer. */")
+                splitAt(index: number): $READ_BUF_CLASS_NAME;
+
+                $("/** Returns the bytes as a view of the buffer, without copying them. */")
+                getSliceAt(index: number, len: number): Uint8Array;
+
+                $("/** Decodes a string, which can be either UTF-8 or ASCII, as any valid ASCII string is also a valid UTF-8 string. */")
+                getStringAt(index: number, len: number): string;
+                $(for (suffix, method, _) in ACCESSORS {
+                    $['\r']
+                    get$(*suffix)At(index: number): $(value_type(method));
+                })
+            }
+
+            $("/** Writable view of the bytes of an encoded message, or a part of it. */")
+            export declare class $WRITE_BUF_CLASS_NAME {
+                #private;
+
+                constructor(data: $BUFFER_SOURCE);
+
+                length(): number;
+
+                $("/** Returns the bytes from the index to the end of the buffer. */")
+                splitAt(index: number): $WRITE_BUF_CLASS_NAME;
+
+                putBytesAt(index: number, value: Uint8Array): void;
+                $(for (suffix, method, _) in ACCESSORS {
+                    $['\r']
+                    put$(*suffix)At(index: number, value: $(value_type(method))): void;
+                })
+            }
+        }
+    }
+
+    fn enum_declarations(&self) -> Result<Tokens<JavaScript>> {
+        let mut enum_tokens: Tokens<JavaScript> = quote!();
+
+        for enum_type in self.generator.schema.types.enum_types.values() {
+            let name = enum_type.name.to_case(Case::UpperCamel);
+            let values = self.generator.enum_values(enum_type)?;
+
+            enum_tokens.line();
+            enum_tokens.append(quote! {
+                $(doc_comment(&enum_type.description))
+                export declare enum $(&name) {
+                    $(for (value_name, value) in &values {
+                        $['\r']
+                        $value_name = $value,
+                    })
+                }
+
+                $(format!("/** Returns the value of {}, failing for the values not in the schema. */", name))
+                export declare function $(format!("{}FromValue", enum_type.name.to_case(Case::Camel)))(value: number): $(&name);
+            });
+        }
+
+        Ok(enum_tokens)
+    }
+
+    fn set_declarations(&self) -> Result<Tokens<JavaScript>> {
+        let mut set_tokens: Tokens<JavaScript> = quote!();
+
+        for set_type in self.generator.schema.types.set_types.values() {
+            let name = set_type.name.to_case(Case::UpperCamel);
+            let value_type = self.set_value_type(set_type)?;
+
+            set_tokens.line();
+            set_tokens.append(quote! {
+                $(doc_comment(&set_type.description))
+                export declare class $(&name) {
+                    value: $value_type;
+
+                    constructor(value?: $value_type);
+
+                    clear(): this;
+                    $(for choice in &set_type.choices {
+                        $['\n']
+                        $(doc_comment(&choice.description))
+                        $(choice.name.to_case(Case::Camel)): boolean;
+                    })
+
+                    toString(): string;
+                }
+            });
+        }
+
+        Ok(set_tokens)
+    }
+
+    fn composite_declarations(&self) -> Result<Tokens<JavaScript>> {
+        let mut composite_tokens: Tokens<JavaScript> = quote!();
+        let module = COMPOSITE_MODULE_NAME;
+
+        for composite_type in self.generator.schema.types.composite_types.values() {
+            let name = composite_type.name.to_case(Case::UpperCamel);
+            let mut decoder_fields: Tokens<JavaScript> = quote!();
+            let mut encoder_fields: Tokens<JavaScript> = quote!();
+
+            for field in &composite_type.fields {
+                decoder_fields.line();
+                decoder_fields.append(self.decoder_field(module, field.name(), field, 0)?);
+                encoder_fields.append(self.encoder_field(module, field.name(), field)?);
+            }
+
+            composite_tokens.line();
+            composite_tokens.append(quote! {
+                $(doc_comment(&composite_type.description))
+                export declare class $(&name)Decoder {
+                    #private;
+
+                    static readonly ENCODED_LENGTH: number;
+
+                    constructor(buffer: $(self.read_buf(module)));
+                    $decoder_fields
+                }
+
+                $(doc_comment(&composite_type.description))
+                export declare class $(&name)Encoder {
+                    #private;
+
+                    static readonly ENCODED_LENGTH: number;
+
+                    constructor(buffer: $(self.write_buf(module)));
+                    $encoder_fields
+                }
+            });
+        }
+
+        Ok(composite_tokens)
+    }
+
+    fn message_declarations(&self, message_types: &[&MessageType]) -> Result<Tokens<JavaScript>> {
+        let mut message_tokens: Tokens<JavaScript> = quote!();
+        let module = MESSAGE_MODULE_NAME;
+        let header_type = Type::Composite(self.generator.schema.types.header_type.clone());
+
+        for message in message_types {
+            let message_path = FieldPath::new(vec![message.name.clone()]);
+            let name = scoped_name(&message_path);
+
+            message_tokens.line();
+            message_tokens.append(quote! {
+                $(doc_comment(&message.description))
+                export declare class $(&name)Decoder {
+                    #private;
+
+                    static readonly TEMPLATE_ID: number;
+
+                    constructor(buffer: $(self.read_buf(module)) | $BUFFER_SOURCE);
+
+                    static id(): number;
+
+                    $("/** Version of the schema the decoded message was encoded with. */")
+                    actingVersion(): number;
+
+                    $("/**")
+                    $(" * Length of the root block of the decoded message, which can differ from")
+                    $(" * the one in the schema, when the message was encoded with another version.")
+                    $(" */")
+                    blockLength(): number;
+
+                    $("/**")
+                    $(" * Size of the decoded message including its header, skipping all its groups")
+                    $(" * and var data.")
+                    $(" */")
+                    size(): number;
+
+                    $(self.decoder_field(module, "message_header", &header_type, 0)?)
+                    $(self.block_decoder_fields(&message.fields, &message_path)?)
+                }
+
+                $(doc_comment(&message.description))
+                export declare class $(&name)Encoder {
+                    #private;
+
+                    static readonly TEMPLATE_ID: number;
+                    static readonly BLOCK_LENGTH: number;
+
+                    constructor(buffer: $(self.write_buf(module)) | $BUFFER_SOURCE);
+
+                    static id(): number;
+
+                    $("/**")
+                    $(" * Size of the encoded message including its header, or null until all its")
+                    $(" * groups and var data were encoded.")
+                    $(" */")
+                    size(): number | null;
+
+                    $(self.encoder_field(module, "message_header", &header_type)?)
+                    $(self.block_encoder_fields(&message.fields, &message_path)?)
+                }
+                $(self.nested_declarations(&message.fields, &message_path)?)
+            });
+        }
+
+        Ok(message_tokens)
+    }
+
+    /// Declarations of the codecs of the groups and var data of a message or group entry,
+    /// followed by the ones of their own groups.
+    fn nested_declarations(
+        &self,
+        fields: &[MessageField],
+        path: &FieldPath,
+    ) -> Result<Tokens<JavaScript>> {
+        let mut nested_tokens: Tokens<JavaScript> = quote!();
+        let (_, groups, var_data) = field_groups(fields);
+
+        for group in groups {
+            nested_tokens.line();
+            nested_tokens.append(self.group_declarations(group, &path.join(&group.name))?);
+        }
+
+        for var_data_field in var_data {
+            nested_tokens.line();
+            nested_tokens.append(
+                self.var_data_declarations(var_data_field, &path.join(&var_data_field.name))?,
+            );
+        }
+
+        Ok(nested_tokens)
+    }
+
+    fn group_declarations(
+        &self,
+        group: &GroupType,
+        group_path: &FieldPath,
+    ) -> Result<Tokens<JavaScript>> {
+        let name = scoped_name(group_path);
+        let module = MESSAGE_MODULE_NAME;
+
+        Ok(quote! {
+            $(doc_comment(&group.description))
+            export declare class $(&name)Decoder {
+                #private;
+
+                constructor(buffer: $(self.read_buf(module)), actingVersion: number);
+
+                $("/**")
+                $(" * Creates a decoder of a group, which is not present in the decoded data,")
+                $(" * because it was added in a newer version of the schema.")
+                $(" */")
+                static empty(actingVersion: number): $(&name)Decoder;
+
+                actingVersion(): number;
+
+                blockLength(): number;
+
+                numInGroup(): number;
+
+                size(): number | null;
+
+                $("/** Advances past the remaining entries, skipping their groups and var data. */")
+                skip(): void;
+
+                advance(): void;
+                $(self.block_decoder_fields(&group.fields, group_path)?)
+            }
+
+            $(doc_comment(&group.description))
+            export declare class $(&name)Encoder {
+                #private;
+
+                static readonly BLOCK_LENGTH: number;
+
+                constructor(buffer: $(self.write_buf(module)));
+
+                size(): number;
+
+                advance(): void;
+
+                $("/** Writes the dimension of the group, after all its entries were encoded. */")
+                finish(): void;
+                $(self.block_encoder_fields(&group.fields, group_path)?)
+            }
+            $(self.nested_declarations(&group.fields, group_path)?)
+        })
+    }
+
+    fn var_data_declarations(
+        &self,
+        var_data: &VariableDataType,
+        var_data_path: &FieldPath,
+    ) -> Result<Tokens<JavaScript>> {
+        let name = scoped_name(var_data_path);
+        let module = MESSAGE_MODULE_NAME;
+        let (_, value_type_metadata) = self.generator.repr_type_metadata(var_data)?;
+        let value_type = &value_type_metadata.lang_type;
+        let bytes = is_bytes(&value_type_metadata.field_primitive_type);
+
+        Ok(quote! {
+            $(doc_comment(&var_data.description))
+            export declare class $(&name)Decoder {
+                #private;
+
+                constructor(buffer: $(self.read_buf(module)));
+
+                length(): number;
+
+                size(): number;
+
+                getAt(index: number): $value_type;
+                $(if bytes {
+                    $['\n']
+                    $("/** Returns the values as a view of the decoded data, without copying them. */")
+                    getSliceAt(index?: number, length?: number): Uint8Array;
+                })
+            }
+
+            $(doc_comment(&var_data.description))
+            export declare class $(&name)Encoder {
+                #private;
+
+                constructor(buffer: $(self.write_buf(module)));
+
+                size(): number;
+
+                putAt(index: number, value: $value_type): void;
+                $(if bytes {
+                    $['\n']
+                    putSliceAt(index: number, value: Uint8Array): void;
+                })
+
+                $("/** Writes the length of the var data, after all its values were encoded. */")
+                finish(): void;
+            }
+        })
+    }
+
+    /// Declarations of the accessors of the fields, groups and var data of a message or
+    /// group entry decoder.
+    fn block_decoder_fields(
+        &self,
+        fields: &[MessageField],
+        path: &FieldPath,
+    ) -> Result<Tokens<JavaScript>> {
+        let module = MESSAGE_MODULE_NAME;
+        let (fields, groups, var_data) = field_groups(fields);
+        let mut decoder_fields: Tokens<JavaScript> = quote!();
+
+        for field in fields {
+            let repr_type = field.to_type(&self.generator.schema.types)?;
+
+            decoder_fields.line();
+            decoder_fields.append(if matches!(field.presence, Presence::Constant) {
+                self.constant_enum(field, &repr_type)?
+            } else {
+                self.decoder_field(
+                    module,
+                    &field.name,
+                    &repr_type,
+                    field.since_version.unwrap_or(0),
+                )?
+            });
+        }
+
+        for group in groups {
+            decoder_fields.line();
+            decoder_fields.append(callback(
+                &group.name,
+                "Decoder",
+                format!("{}Decoder", scoped_name(&path.join(&group.name))),
+                false,
+            ));
+        }
+
+        for var_data_field in var_data {
+            decoder_fields.line();
+            decoder_fields.append(callback(
+                &var_data_field.name,
+                "Decoder",
+                format!("{}Decoder", scoped_name(&path.join(&var_data_field.name))),
+                false,
+            ));
+        }
+
+        Ok(decoder_fields)
+    }
+
+    /// Declarations of the setters of the fields and the accessors of the groups and var
+    /// data of a message or group entry encoder.
+    fn block_encoder_fields(
+        &self,
+        fields: &[MessageField],
+        path: &FieldPath,
+    ) -> Result<Tokens<JavaScript>> {
+        let module = MESSAGE_MODULE_NAME;
+        let (fields, groups, var_data) = field_groups(fields);
+        let mut encoder_fields: Tokens<JavaScript> = quote!();
+
+        for field in fields {
+            if matches!(field.presence, Presence::Constant) {
+                continue;
+            }
+
+            let repr_type = field.to_type(&self.generator.schema.types)?;
+            encoder_fields.append(self.encoder_field(module, &field.name, &repr_type)?);
+        }
+
+        for group in groups {
+            encoder_fields.line();
+            encoder_fields.append(callback(
+                &group.name,
+                "Encoder",
+                format!("{}Encoder", scoped_name(&path.join(&group.name))),
+                false,
+            ));
+        }
+
+        for var_data_field in var_data {
+            encoder_fields.line();
+            encoder_fields.append(callback(
+                &var_data_field.name,
+                "Encoder",
+                format!("{}Encoder", scoped_name(&path.join(&var_data_field.name))),
+                false,
+            ));
+        }
+
+        Ok(encoder_fields)
+    }
+
+    fn constant_enum(&self, field: &FieldType, repr_type: &Type) -> Result<Tokens<JavaScript>> {
+        Ok(quote! {
+            $(field.name.to_case(Case::Camel))(): $(self.generator.import(MESSAGE_MODULE_NAME, ENUM_MODULE_NAME, &self.generator.resolved(repr_type)?.name().to_case(Case::UpperCamel)));
+        })
+    }
+
+    /// Declaration of the accessor of a field of a decoder in the `module`, which returns
+    /// null for the fields added after the `since_version` of the decoded data.
+    fn decoder_field(
+        &self,
+        module: &str,
+        field_name: &str,
+        field: &Type,
+        since_version: usize,
+    ) -> Result<Tokens<JavaScript>> {
+        let field_name = field_name.to_case(Case::Camel);
+
+        Ok(match self.generator.resolved(field)? {
+            Type::EncodedData(encoded_type) => {
+                let metadata =
+                    field_metadata(&field_name, &encoded_type, &self.generator.schema.types)?;
+
+                match encoded_type.presence {
+                    Presence::Constant => quote!($(&field_name)(): $(constant_type(&metadata));),
+                    Presence::Required => quote!($(&field_name)(): $(value_type(&metadata));),
+                    Presence::Optional => {
+                        quote!($(&field_name)(): $(value_type(&metadata)) | null;)
+                    }
+                }
+            }
+            Type::Enum(enum_type) => quote! {
+                $(&field_name)(): $(self.generator.import(module, ENUM_MODULE_NAME, &enum_type.name.to_case(Case::UpperCamel)));
+            },
+            Type::Set(set_type) => quote! {
+                $(&field_name)(): $(self.generator.import(module, SET_MODULE_NAME, &set_type.name.to_case(Case::UpperCamel)));
+            },
+            Type::Composite(composite_type) => {
+                let decoder_class = self.composite_class(module, &composite_type, "Decoder");
+
+                callback(&field_name, "Decoder", &decoder_class, since_version > 0)
+            }
+            Type::Reference(reference_type) => {
+                unreachable!("Resolved reference: {}", reference_type.type_name)
+            }
+        })
+    }
+
+    /// Declaration of the setter of a field of an encoder in the `module`, nothing for
+    /// constant fields.
+    fn encoder_field(
+        &self,
+        module: &str,
+        field_name: &str,
+        field: &Type,
+    ) -> Result<Tokens<JavaScript>> {
+        let field_name = field_name.to_case(Case::Camel);
+
+        let declaration = match self.generator.resolved(field)? {
+            Type::EncodedData(encoded_type) => {
+                let metadata =
+                    field_metadata(&field_name, &encoded_type, &self.generator.schema.types)?;
+
+                match encoded_type.presence {
+                    Presence::Constant => return Ok(quote!()),
+                    Presence::Required => {
+                        quote!($(&field_name)(value: $(value_type(&metadata))): void;)
+                    }
+                    Presence::Optional => {
+                        quote!($(&field_name)(value: $(value_type(&metadata)) | null): void;)
+                    }
+                }
+            }
+            Type::Enum(enum_type) => quote! {
+                $(&field_name)(value: $(self.generator.import(module, ENUM_MODULE_NAME, &enum_type.name.to_case(Case::UpperCamel)))): void;
+            },
+            Type::Set(set_type) => quote! {
+                $(&field_name)(value: $(self.generator.import(module, SET_MODULE_NAME, &set_type.name.to_case(Case::UpperCamel)))): void;
+            },
+            Type::Composite(composite_type) => {
+                let encoder_class = self.composite_class(module, &composite_type, "Encoder");
+
+                callback(&field_name, "Encoder", &encoder_class, false)
+            }
+            Type::Reference(reference_type) => {
+                unreachable!("Resolved reference: {}", reference_type.type_name)
+            }
+        };
+
+        Ok(quote! {
+            $['\n']
+            $declaration
+        })
+    }
+
+    fn dispatcher_declarations(&self, message_types: &[&MessageType]) -> Tokens<JavaScript> {
+        let module = DISPATCHER_MODULE_NAME;
+        let variants = message_types
+            .iter()
+            .map(|message_type| {
+                let name = message_type.name.to_case(Case::UpperCamel);
+                let decoder_class =
+                    self.generator
+                        .import(module, MESSAGE_MODULE_NAME, &format!("{}Decoder", name));
+
+                (name, decoder_class)
+            })
+            .collect::<Vec<_>>();
+
+        quote! {
+            $("/** Decoder of any message of the schema. */")
+            export declare class AnyMessageDecoder {
+                #private;
+
+                constructor(buffer: $(self.read_buf(module)) | $BUFFER_SOURCE);
+
+                templateId(): number;
+
+                $("/** Version of the schema the decoded message was encoded with. */")
+                actingVersion(): number;
+
+                $("/** Decoder of the message, of the class with the template id of the message. */")
+                decoder(): $(for (_, decoder_class) in &variants join ( | ) => $decoder_class);
+
+                $("/** Calls the method of the visitor for the type of the decoded message, if it has one. */")
+                accept(visitor: MessageVisitor): void;
+            }
+
+            $("/** Visitor of the decoded messages. Messages without a method are ignored. */")
+            export interface MessageVisitor {
+                $(for (name, decoder_class) in &variants {
+                    $['\r']
+                    on$(name)?(decoder: $decoder_class): void;
+                })
+            }
+        }
+    }
+
+    fn composite_class(
+        &self,
+        module: &str,
+        composite_type: &CompositeType,
+        suffix: &str,
+    ) -> Tokens<JavaScript> {
+        let class_name = format!(
+            "{}{}",
+            composite_type.name.to_case(Case::UpperCamel),
+            suffix
+        );
+
+        self.generator
+            .import(module, COMPOSITE_MODULE_NAME, &class_name)
+    }
+
+    fn read_buf(&self, module: &str) -> Tokens<JavaScript> {
+        self.generator
+            .import(module, BUFFER_MODULE_NAME, READ_BUF_CLASS_NAME)
+    }
+
+    fn write_buf(&self, module: &str) -> Tokens<JavaScript> {
+        self.generator
+            .import(module, BUFFER_MODULE_NAME, WRITE_BUF_CLASS_NAME)
+    }
+
+    fn set_value_type(&self, set_type: &SetType) -> Result<&'static str> {
+        let encoding_type = set_type
+            .encoding_type
+            .resolved(&self.generator.schema.types.encoded_types)?;
+
+        Ok(if is_bigint(&encoding_type) {
+            "bigint"
+        } else {
+            "number"
+        })
+    }
+}
+
+/// TypeScript type of the value of an encoded field, a string for character arrays.
+fn value_type(metadata: &FieldMetadata<JavaScript>) -> String {
+    match (&metadata.field_primitive_type, metadata.field_length) {
+        (NativeType::Char, 2..) => "string".to_owned(),
+        (_, 2..) => format!("{}[]", metadata.lang_type.name),
+        (_, _) => metadata.lang_type.name.to_owned(),
+    }
+}
+
+/// TypeScript type of the value of a constant field, a string for characters.
+fn constant_type(metadata: &FieldMetadata<JavaScript>) -> &'static str {
+    match metadata.field_primitive_type {
+        NativeType::Char => "string",
+        _ => metadata.lang_type.name,
+    }
+}
+
+/// Declaration of a method passing a codec to a callback and returning its result, or null
+/// when the codec is `nullable`.
+fn callback(
+    field_name: &str,
+    suffix: &str,
+    codec_class: impl FormatInto<JavaScript>,
+    nullable: bool,
+) -> Tokens<JavaScript> {
+    let argument = suffix.to_case(Case::Lower);
+
+    quote! {
+        $(field_name.to_case(Case::Camel))$suffix<T>(action: ($(&argument): $codec_class) => T): T$(if nullable { $[' ']| null });
+    }
+}
+
+fn doc_comment(description: &Option<String>) -> Tokens<JavaScript> {
+    match description {
+        Some(description) if !description.trim().is_empty() => {
+            quote!($(format!("/** {} */", description.trim())))
+        }
+        _ => quote!(),
+    }
+}
@@ -0,0 +1,12 @@
+//! Performance-oriented multi-language SBE codec generator.
+//!
+//! Besides the `sbe-codegen` binary, the codecs can be generated from the `build.rs` of a
+//! downstream crate with the [`Builder`], and the validated schemas inspected through
+//! [`models::schema::ValidatedMessageSchema`].
+
+mod builder;
+mod generator;
+#[allow(clippy::needless_late_init)]
+pub mod models;
+
+pub use builder::{Builder, Language};
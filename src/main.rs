@@ -1,47 +1,7 @@
-use crate::generator::python::PythonGenerator;
-use crate::generator::rust::RustGenerator;
-use crate::generator::CodeGenerator;
-use crate::models::schema::{MessageSchema, ValidatedMessageSchema};
-use anyhow::{Context, Result};
-use clap::{Parser, ValueEnum};
-use std::path::{Path, PathBuf};
-use xml_include::resolve_xml_includes;
-
-mod generator;
-#[allow(clippy::needless_late_init)]
-mod models;
-
-#[derive(ValueEnum, Copy, Clone)]
-enum Language {
-    Rust,
-    Python,
-}
-
-impl Language {
-    fn generator(
-        &self,
-        schemas: Vec<ValidatedMessageSchema>,
-        project_name: &str,
-        project_path: &Path,
-        version: &str,
-    ) -> Box<dyn CodeGenerator> {
-        match self {
-            Language::Rust => Box::new(RustGenerator::new(
-                project_path,
-                project_name,
-                version,
-                schemas,
-                true,
-            )),
-            Language::Python => Box::new(PythonGenerator::new(
-                project_path,
-                project_name,
-                version,
-                schemas,
-            )),
-        }
-    }
-}
+use anyhow::Result;
+use clap::Parser;
+use sbe_codegen::{Builder, Language};
+use std::path::PathBuf;
 
 #[derive(Parser)]
 #[command(name = "sbe-codegen", about = "SBE multi-language codec generator")]
@@ -76,47 +36,55 @@ struct Opt {
     /// Format project
     #[arg(long = "format", help = "Format project")]
     format: bool,
-}
-
-fn validate_schemas(schema_paths: &Vec<PathBuf>) -> Vec<ValidatedMessageSchema> {
-    let mut schemas = Vec::new();
 
-    for schema_path in schema_paths {
-        let merged_content = resolve_xml_includes(schema_path)
-            .context("failed to resolve XML includes")
-            .unwrap();
-
-        let schema = MessageSchema::load_from_string(&merged_content)
-            .context("failed to load XML schema")
-            .unwrap();
+    /// Bounds checks
+    #[arg(
+        long = "without-bounds-checks",
+        help = "Do not check minValue/maxValue ranges in encoders"
+    )]
+    without_bounds_checks: bool,
 
-        let validated_schema = schema
-            .validate()
-            .context("failed to validate schema")
-            .unwrap();
-        schemas.push(validated_schema);
-    }
+    /// Serde
+    #[arg(
+        long = "with-serde",
+        help = "Derive serde traits behind a `serde` feature of the generated Rust crate, or of the crate mounting the module"
+    )]
+    serde: bool,
 
-    schemas
+    /// Module
+    #[arg(
+        long = "module-path",
+        help = "Generate the Rust codecs as a single module file mounted at this path (e.g. crate::example) instead of a crate"
+    )]
+    module_path: Option<String>,
 }
 
 fn main() -> Result<()> {
     let opt: Opt = Opt::parse();
 
-    let schemas = validate_schemas(&opt.schema_paths);
+    let mut builder = Builder::new()
+        .language(opt.language)
+        .project_name(&opt.project_name)
+        .out_dir(&opt.project_path)
+        .with_test_dependencies(opt.test_dependencies)
+        .format(opt.format)
+        .bounds_checks(!opt.without_bounds_checks)
+        .serde(opt.serde)
+        .rerun_if_changed(false);
+
+    for schema_path in &opt.schema_paths {
+        builder = builder.schema(schema_path);
+    }
 
-    if schemas.is_empty() {
-        return Err(anyhow::anyhow!("No schemas found"));
+    if let Some(version) = &opt.version {
+        builder = builder.project_version(version);
     }
 
-    // TODO: A better way to determine project version
-    // It does not matter now, because the release pipeline will set the version
-    // regardless, but this is not pretty.
-    let version = opt.version.unwrap_or(schemas[0].semantic_version.clone());
+    if let Some(module_path) = &opt.module_path {
+        builder = builder.module(module_path);
+    }
 
-    let generator = opt
-        .language
-        .generator(schemas, &opt.project_name, &opt.project_path, &version);
+    builder.generate()?;
 
-    generator.generate_project(opt.test_dependencies, opt.format)
+    Ok(())
 }
@@ -0,0 +1,118 @@
+use crate::models::types::composite_type::CompositeType;
+use crate::models::types::field_type::FieldType;
+use crate::models::types::{Presence, SizedEncoded};
+use crate::models::TypeMap;
+use anyhow::{anyhow, Result};
+
+/// Offsets of the fields of a block (the root block of a message, a block of a group entry
+/// or a composite) and the length of the whole block.
+#[derive(PartialEq, Debug, Clone)]
+pub struct BlockLayout {
+    pub offsets: Vec<usize>,
+    pub block_length: usize,
+}
+
+impl BlockLayout {
+    /// Lays out the fields of a message or a group. Fields without an explicit offset are placed
+    /// right after the previous one, while the declared offsets and block length can leave
+    /// padding in between, but must not make the fields overlap.
+    pub fn from_fields(
+        block_name: &str,
+        fields: &[&FieldType],
+        block_length: Option<usize>,
+        types: &TypeMap,
+    ) -> Result<Self> {
+        let elements = fields
+            .iter()
+            .map(|field| {
+                Ok(match field.presence {
+                    Presence::Constant => (field.name.as_str(), None, 0),
+                    _ => (
+                        field.name.as_str(),
+                        field.offset,
+                        field.to_type(types)?.size(types)?,
+                    ),
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Self::from_elements(block_name, &elements, block_length)
+    }
+
+    /// Lays out the fields of a composite, honouring their explicit offsets.
+    pub fn from_composite(composite_type: &CompositeType, types: &TypeMap) -> Result<Self> {
+        let elements = composite_type
+            .fields
+            .iter()
+            .map(|field| Ok((field.name(), field.offset(), field.size(types)?)))
+            .collect::<Result<Vec<_>>>()?;
+
+        Self::from_elements(&composite_type.name, &elements, None)
+    }
+
+    fn from_elements(
+        block_name: &str,
+        elements: &[(&str, Option<usize>, usize)],
+        block_length: Option<usize>,
+    ) -> Result<Self> {
+        let mut offsets = Vec::with_capacity(elements.len());
+        let mut offset = 0;
+
+        for (name, declared_offset, size) in elements {
+            if let Some(declared_offset) = *declared_offset {
+                if declared_offset < offset {
+                    return Err(anyhow!(
+                        "Field '{}' in '{}' has offset {}, but the previous field ends at {}",
+                        name,
+                        block_name,
+                        declared_offset,
+                        offset
+                    ));
+                }
+
+                offset = declared_offset;
+            }
+
+            offsets.push(offset);
+            offset += size;
+        }
+
+        let block_length = match block_length {
+            Some(block_length) if block_length < offset => {
+                return Err(anyhow!(
+                    "Block length {} of '{}' is smaller than the size of its fields {}",
+                    block_length,
+                    block_name,
+                    offset
+                ))
+            }
+            Some(block_length) => block_length,
+            None => offset,
+        };
+
+        Ok(Self {
+            offsets,
+            block_length,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::BlockLayout;
+
+    #[test]
+    fn test_explicit_offsets_and_block_length() {
+        let elements = [("a", None, 2), ("b", Some(4), 4), ("c", None, 1)];
+        let layout = BlockLayout::from_elements("block", &elements, Some(16)).unwrap();
+
+        assert_eq!(layout.offsets, vec![0, 4, 8]);
+        assert_eq!(layout.block_length, 16);
+
+        assert!(BlockLayout::from_elements("block", &elements, Some(8)).is_err());
+        assert!(
+            BlockLayout::from_elements("block", &[("a", None, 4), ("b", Some(2), 1)], None)
+                .is_err()
+        );
+    }
+}
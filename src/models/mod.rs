@@ -9,10 +9,11 @@ use crate::models::types::set_type::SetType;
 use crate::models::types::variable_data_type::VariableDataType;
 use crate::models::types::{MessageField, Type};
 use anyhow::anyhow;
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 use std::str::FromStr;
 
 pub mod constants;
+pub mod layout;
 pub mod message;
 pub mod schema;
 pub mod types;
@@ -38,18 +39,18 @@ impl FromStr for ByteOrder {
 #[derive(PartialEq, Debug, Clone)]
 pub struct TypeMap {
     pub header_type: CompositeType,
-    pub encoded_types: HashMap<String, EncodedDataType>,
-    pub set_types: HashMap<String, SetType>,
-    pub enum_types: HashMap<String, EnumType>,
-    pub composite_types: HashMap<String, CompositeType>,
+    pub encoded_types: BTreeMap<String, EncodedDataType>,
+    pub set_types: BTreeMap<String, SetType>,
+    pub enum_types: BTreeMap<String, EnumType>,
+    pub composite_types: BTreeMap<String, CompositeType>,
 }
 
 impl TypeMap {
-    pub fn new(types: HashMap<String, Type>, header_type: CompositeType) -> Self {
-        let mut encoded_types = HashMap::new();
-        let mut set_types = HashMap::new();
-        let mut enum_types = HashMap::new();
-        let mut composite_types = HashMap::new();
+    pub fn new(types: BTreeMap<String, Type>, header_type: CompositeType) -> Self {
+        let mut encoded_types = BTreeMap::new();
+        let mut set_types = BTreeMap::new();
+        let mut enum_types = BTreeMap::new();
+        let mut composite_types = BTreeMap::new();
 
         for (name, type_) in types {
             match type_ {
@@ -113,29 +114,52 @@ impl TypeMap {
     }
 }
 
+/// Location of a group or var data field, made of the name of its message, the names of the
+/// enclosing groups and its own name. Fields with the same name in different messages or groups
+/// are told apart by their path.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Debug, Clone)]
+pub struct FieldPath(Vec<String>);
+
+impl FieldPath {
+    pub fn new(segments: Vec<String>) -> Self {
+        Self(segments)
+    }
+
+    pub fn join(&self, name: &str) -> Self {
+        let mut segments = self.0.clone();
+        segments.push(name.to_owned());
+
+        Self(segments)
+    }
+
+    pub fn segments(&self) -> &[String] {
+        &self.0
+    }
+}
+
 #[derive(PartialEq, Debug, Clone)]
 pub struct MessageTypeMap {
-    pub message_types: HashMap<String, MessageType>,
-    pub group_types: HashMap<String, GroupType>,
-    pub variable_data_types: HashMap<String, VariableDataType>,
+    pub message_types: BTreeMap<String, MessageType>,
+    pub group_types: BTreeMap<FieldPath, GroupType>,
+    pub variable_data_types: BTreeMap<FieldPath, VariableDataType>,
 }
 
 impl MessageTypeMap {
     pub fn new(
-        message_types: HashMap<String, MessageType>,
-        message_field_types: HashMap<String, MessageField>,
+        message_types: BTreeMap<String, MessageType>,
+        message_field_types: BTreeMap<FieldPath, MessageField>,
     ) -> Self {
-        let mut group_types = HashMap::new();
-        let mut variable_data_types = HashMap::new();
+        let mut group_types = BTreeMap::new();
+        let mut variable_data_types = BTreeMap::new();
 
-        for (name, field_type) in message_field_types {
+        for (path, field_type) in message_field_types {
             match field_type {
                 MessageField::Field(_) => (),
                 MessageField::Group(group_type) => {
-                    group_types.insert(name, group_type.clone());
+                    group_types.insert(path, group_type.clone());
                 }
                 MessageField::VariableData(variable_data_type) => {
-                    variable_data_types.insert(name, variable_data_type.clone());
+                    variable_data_types.insert(path, variable_data_type.clone());
                 }
             }
         }
@@ -147,14 +171,11 @@ impl MessageTypeMap {
         }
     }
 
-    pub fn iter_values(&self) -> impl Iterator<Item = MessageField> + '_ {
-        self.group_types
-            .values()
-            .map(|group_type| MessageField::Group(group_type.clone()))
-            .chain(
-                self.variable_data_types.values().map(|variable_data_type| {
-                    MessageField::VariableData(variable_data_type.clone())
-                }),
-            )
+    /// Returns true if the message or group at `path` contains var data, directly or in any
+    /// of its nested groups.
+    pub fn has_variable_data(&self, path: &FieldPath) -> bool {
+        self.variable_data_types
+            .keys()
+            .any(|var_data_path| var_data_path.segments().starts_with(path.segments()))
     }
 }
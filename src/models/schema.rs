@@ -1,10 +1,15 @@
+use crate::models::layout::BlockLayout;
 use crate::models::message::MessageType;
+use crate::models::types::composite_type::CompositeType;
+use crate::models::types::field_type::FieldType;
+use crate::models::types::primitive_type::{NativeType, ResolvableType};
 use crate::models::types::{MessageField, Type};
 use anyhow::{anyhow, Result};
 use hard_xml::{XmlRead, XmlReader};
-use std::collections::HashMap;
+use std::collections::BTreeMap;
+use std::str::FromStr;
 
-use crate::models::{ByteOrder, MessageTypeMap, TypeMap};
+use crate::models::{ByteOrder, FieldPath, MessageTypeMap, TypeMap};
 
 #[derive(XmlRead, PartialEq, Debug, Clone)]
 #[xml(tag = "sbe:messageSchema")]
@@ -58,8 +63,8 @@ impl MessageSchema {
         Ok(message_schema)
     }
 
-    fn find_nested_types(type_: &Type) -> HashMap<String, Type> {
-        let mut result = HashMap::new();
+    fn find_nested_types(type_: &Type) -> BTreeMap<String, Type> {
+        let mut result = BTreeMap::new();
         match type_ {
             Type::Composite(c) => {
                 result.insert(c.name.clone(), type_.clone());
@@ -74,23 +79,27 @@ impl MessageSchema {
         result
     }
 
-    fn find_nested_message_types(message_field: &MessageField) -> HashMap<String, MessageField> {
-        let mut result = HashMap::new();
-        result.insert(message_field.name().to_owned(), message_field.clone());
+    fn find_nested_message_types(
+        scope: &FieldPath,
+        message_field: &MessageField,
+    ) -> BTreeMap<FieldPath, MessageField> {
+        let mut result = BTreeMap::new();
+        let path = scope.join(message_field.name());
 
         if let MessageField::Group(group_type) = message_field {
             result.extend(
                 group_type
                     .fields
                     .iter()
-                    .flat_map(Self::find_nested_message_types),
+                    .flat_map(|field| Self::find_nested_message_types(&path, field)),
             );
         }
 
+        result.insert(path, message_field.clone());
         result
     }
 
-    pub fn types(&self) -> HashMap<String, Type> {
+    pub fn types(&self) -> BTreeMap<String, Type> {
         self.types_section
             .iter()
             .flat_map(|section| section.types.iter())
@@ -98,21 +107,23 @@ impl MessageSchema {
             .collect()
     }
 
-    pub fn message_types(&self) -> HashMap<String, MessageType> {
+    pub fn message_types(&self) -> BTreeMap<String, MessageType> {
         self.message_types
             .iter()
             .map(|message_type| (message_type.name.clone(), message_type.clone()))
             .collect()
     }
 
-    pub fn message_field_types(&self) -> HashMap<String, MessageField> {
+    pub fn message_field_types(&self) -> BTreeMap<FieldPath, MessageField> {
         self.message_types
             .iter()
             .flat_map(|message_type| {
+                let scope = FieldPath::new(vec![message_type.name.clone()]);
+
                 message_type
                     .fields
                     .iter()
-                    .flat_map(Self::find_nested_message_types)
+                    .flat_map(move |field| Self::find_nested_message_types(&scope, field))
             })
             .collect()
     }
@@ -145,7 +156,7 @@ impl MessageSchema {
         let package = self
             .package
             .split('.')
-            .last()
+            .next_back()
             .ok_or(anyhow!("Package name was missing."))?
             .to_owned();
 
@@ -168,6 +179,11 @@ impl MessageSchema {
             }
         };
 
+        let types = TypeMap::new(types, header_type);
+        let message_types = MessageTypeMap::new(message_types, message_field_types);
+
+        Self::validate_semantics(&types, &self.message_types)?;
+
         Ok(ValidatedMessageSchema {
             package,
             id: self.id,
@@ -175,16 +191,317 @@ impl MessageSchema {
             semantic_version,
             description: self.description.clone(),
             byte_order,
-            types: TypeMap::new(types, header_type),
-            message_types: MessageTypeMap::new(message_types, message_field_types),
+            types,
+            message_types,
         })
     }
+
+    /// Checks the schema for errors which would otherwise only surface while generating the
+    /// codecs. All found problems are reported together, each naming the offending element.
+    fn validate_semantics(types: &TypeMap, message_types: &[MessageType]) -> Result<()> {
+        let mut diagnostics = Vec::new();
+
+        Self::validate_types(types, &mut diagnostics);
+        Self::validate_header_type(types, &mut diagnostics);
+
+        let mut message_ids = BTreeMap::new();
+        for message_type in message_types {
+            if let Some(other) = message_ids.insert(message_type.id, &message_type.name) {
+                diagnostics.push(format!(
+                    "Message '{}' has the same id {} as message '{}'",
+                    message_type.name, message_type.id, other
+                ));
+            }
+
+            Self::validate_block(
+                &message_type.name,
+                &message_type.fields,
+                message_type.block_length,
+                types,
+                &mut diagnostics,
+            );
+        }
+
+        if diagnostics.is_empty() {
+            Ok(())
+        } else {
+            Err(anyhow!(
+                "Invalid schema:\n  - {}",
+                diagnostics.join("\n  - ")
+            ))
+        }
+    }
+
+    fn validate_types(types: &TypeMap, diagnostics: &mut Vec<String>) {
+        for encoded_type in types.encoded_types.values() {
+            if let NativeType::Reference(type_name) = &encoded_type.primitive_type {
+                if !types.encoded_types.contains_key(type_name) {
+                    diagnostics.push(format!(
+                        "Type '{}' has unknown primitive type '{}'",
+                        encoded_type.name, type_name
+                    ));
+                }
+            }
+        }
+
+        for enum_type in types.enum_types.values() {
+            let encoding_type = match enum_type.encoding_type.resolved(&types.encoded_types) {
+                Ok(encoding_type) => encoding_type,
+                Err(_) => {
+                    diagnostics.push(format!(
+                        "Enum '{}' has unknown encoding type '{}'",
+                        enum_type.name,
+                        type_name(&enum_type.encoding_type)
+                    ));
+                    continue;
+                }
+            };
+
+            for valid_value in &enum_type.values {
+                if !fits_encoding(&valid_value.value, &encoding_type) {
+                    diagnostics.push(format!(
+                        "Value '{}' of enum '{}.{}' does not fit its encoding type '{}'",
+                        valid_value.value,
+                        enum_type.name,
+                        valid_value.name,
+                        type_name(&encoding_type)
+                    ));
+                }
+            }
+        }
+
+        for set_type in types.set_types.values() {
+            let encoding_type = match set_type.encoding_type.resolved(&types.encoded_types) {
+                Ok(encoding_type) => encoding_type,
+                Err(_) => {
+                    diagnostics.push(format!(
+                        "Set '{}' has unknown encoding type '{}'",
+                        set_type.name,
+                        type_name(&set_type.encoding_type)
+                    ));
+                    continue;
+                }
+            };
+
+            let bits = match encoding_type {
+                NativeType::UInt8 => 8,
+                NativeType::UInt16 => 16,
+                NativeType::UInt32 => 32,
+                NativeType::UInt64 => 64,
+                _ => {
+                    diagnostics.push(format!(
+                        "Set '{}' must be encoded as an unsigned integer, not '{}'",
+                        set_type.name,
+                        type_name(&encoding_type)
+                    ));
+                    continue;
+                }
+            };
+
+            for choice in &set_type.choices {
+                if choice.value >= bits {
+                    diagnostics.push(format!(
+                        "Choice '{}.{}' uses bit {} past the {} bits of its encoding type",
+                        set_type.name, choice.name, choice.value, bits
+                    ));
+                }
+            }
+        }
+
+        for composite_type in types.composite_types.values() {
+            for field in &composite_type.fields {
+                if let Type::Reference(reference_type) = field {
+                    if types.find_type(&reference_type.type_name).is_none() {
+                        diagnostics.push(format!(
+                            "Reference '{}.{}' has unknown type '{}'",
+                            composite_type.name, reference_type.name, reference_type.type_name
+                        ));
+                    }
+                }
+            }
+
+            if let Err(err) = BlockLayout::from_composite(composite_type, types) {
+                diagnostics.push(err.to_string());
+            }
+        }
+    }
+
+    fn validate_header_type(types: &TypeMap, diagnostics: &mut Vec<String>) {
+        Self::validate_encoded_fields(
+            "Header type",
+            &types.header_type,
+            &["blockLength", "templateId", "schemaId", "version"],
+            diagnostics,
+        );
+    }
+
+    /// Checks the fields, groups and var data of a message or group, recursing into groups.
+    fn validate_block(
+        name: &str,
+        fields: &[MessageField],
+        block_length: Option<usize>,
+        types: &TypeMap,
+        diagnostics: &mut Vec<String>,
+    ) {
+        let mut field_ids = BTreeMap::new();
+        for field in fields {
+            let id = match field {
+                MessageField::Field(field_type) => field_type.id,
+                MessageField::Group(group_type) => group_type.id,
+                MessageField::VariableData(variable_data_type) => variable_data_type.id,
+            };
+
+            if let Some(other) = field_ids.insert(id, field.name()) {
+                diagnostics.push(format!(
+                    "Field '{}.{}' has the same id {} as field '{}.{}'",
+                    name,
+                    field.name(),
+                    id,
+                    name,
+                    other
+                ));
+            }
+
+            match field {
+                MessageField::Field(field_type) => {
+                    if types.find_type(&field_type.type_name).is_none()
+                        && matches!(
+                            NativeType::from_str(&field_type.type_name),
+                            Ok(NativeType::Reference(_))
+                        )
+                    {
+                        diagnostics.push(format!(
+                            "Field '{}.{}' has unknown type '{}'",
+                            name, field_type.name, field_type.type_name
+                        ));
+                    }
+                }
+                MessageField::Group(group_type) => {
+                    let dimension_type_name = group_type
+                        .dimension_type
+                        .as_deref()
+                        .unwrap_or("groupSizeEncoding");
+
+                    match types.composite_types.get(dimension_type_name) {
+                        Some(dimension_type) => Self::validate_encoded_fields(
+                            &format!(
+                                "Dimension type '{}' of group '{}.{}'",
+                                dimension_type_name, name, group_type.name
+                            ),
+                            dimension_type,
+                            &["blockLength", "numInGroup"],
+                            diagnostics,
+                        ),
+                        None => diagnostics.push(format!(
+                            "Group '{}.{}' has unknown dimension type '{}'",
+                            name, group_type.name, dimension_type_name
+                        )),
+                    }
+
+                    Self::validate_block(
+                        &format!("{}.{}", name, group_type.name),
+                        &group_type.fields,
+                        group_type.block_length,
+                        types,
+                        diagnostics,
+                    );
+                }
+                MessageField::VariableData(variable_data_type) => {
+                    match types.composite_types.get(&variable_data_type.type_name) {
+                        Some(repr_type) => Self::validate_encoded_fields(
+                            &format!(
+                                "Type '{}' of variable data '{}.{}'",
+                                variable_data_type.type_name, name, variable_data_type.name
+                            ),
+                            repr_type,
+                            &["length", "varData"],
+                            diagnostics,
+                        ),
+                        None => diagnostics.push(format!(
+                            "Variable data '{}.{}' has unknown composite type '{}'",
+                            name, variable_data_type.name, variable_data_type.type_name
+                        )),
+                    }
+                }
+            }
+        }
+
+        if let Err(err) = BlockLayout::from_fields(name, &block_fields(fields), block_length, types)
+        {
+            diagnostics.push(err.to_string());
+        }
+    }
+
+    /// Checks that a composite contains encoded data fields with the given names.
+    fn validate_encoded_fields(
+        description: &str,
+        composite_type: &CompositeType,
+        field_names: &[&str],
+        diagnostics: &mut Vec<String>,
+    ) {
+        for field_name in field_names {
+            let found = composite_type.fields.iter().any(|field| {
+                matches!(field, Type::EncodedData(encoded_type) if encoded_type.name == *field_name)
+            });
+
+            if !found {
+                diagnostics.push(format!(
+                    "{} is missing the encoded field '{}'",
+                    description, field_name
+                ));
+            }
+        }
+    }
+}
+
+fn block_fields(fields: &[MessageField]) -> Vec<&FieldType> {
+    fields
+        .iter()
+        .filter_map(|field| match field {
+            MessageField::Field(field_type) => Some(field_type),
+            _ => None,
+        })
+        .collect()
+}
+
+fn type_name(native_type: &NativeType) -> &str {
+    match native_type {
+        NativeType::Char => "char",
+        NativeType::UInt8 => "uint8",
+        NativeType::UInt16 => "uint16",
+        NativeType::UInt32 => "uint32",
+        NativeType::UInt64 => "uint64",
+        NativeType::Int8 => "int8",
+        NativeType::Int16 => "int16",
+        NativeType::Int32 => "int32",
+        NativeType::Int64 => "int64",
+        NativeType::Float => "float",
+        NativeType::Double => "double",
+        NativeType::Reference(name) => name,
+    }
+}
+
+/// Checks that an enum value can be represented by its resolved encoding type.
+fn fits_encoding(value: &str, encoding_type: &NativeType) -> bool {
+    match encoding_type {
+        NativeType::Char => value.len() == 1 && value.is_ascii(),
+        NativeType::UInt8 => value.parse::<u8>().is_ok(),
+        NativeType::UInt16 => value.parse::<u16>().is_ok(),
+        NativeType::UInt32 => value.parse::<u32>().is_ok(),
+        NativeType::UInt64 => value.parse::<u64>().is_ok(),
+        NativeType::Int8 => value.parse::<i8>().is_ok(),
+        NativeType::Int16 => value.parse::<i16>().is_ok(),
+        NativeType::Int32 => value.parse::<i32>().is_ok(),
+        NativeType::Int64 => value.parse::<i64>().is_ok(),
+        NativeType::Float | NativeType::Double | NativeType::Reference(_) => false,
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::MessageSchema;
     use crate::models::types::SizedEncoded;
+    use crate::models::FieldPath;
     use std::path::Path;
     use xml_include::resolve_xml_includes;
 
@@ -197,6 +514,15 @@ mod tests {
         println!("{:#?}", validated_schema);
     }
 
+    #[test]
+    fn test_parse_versioned_message_schema() {
+        let path = Path::new("./examples/versioned-schema.xml");
+        let merged_content = resolve_xml_includes(path).unwrap();
+        let schema = MessageSchema::load_from_string(&merged_content).unwrap();
+        let validated_schema = schema.validate().unwrap();
+        println!("{:#?}", validated_schema);
+    }
+
     #[test]
     fn test_valid_composite_sizes() {
         let path = Path::new("./examples/example-schema.xml");
@@ -222,4 +548,196 @@ mod tests {
             2
         );
     }
+
+    fn validation_error(types: &str, messages: &str) -> String {
+        let content = format!(
+            r#"<sbe:messageSchema xmlns:sbe="http://fixprotocol.io/2016/sbe" package="test" id="1" version="0">
+                <types>
+                    <composite name="messageHeader">
+                        <type name="blockLength" primitiveType="uint16"/>
+                        <type name="templateId" primitiveType="uint16"/>
+                        <type name="schemaId" primitiveType="uint16"/>
+                        <type name="version" primitiveType="uint16"/>
+                    </composite>
+                    {types}
+                </types>
+                {messages}
+            </sbe:messageSchema>"#
+        );
+
+        MessageSchema::load_from_string(&content)
+            .unwrap()
+            .validate()
+            .unwrap_err()
+            .to_string()
+    }
+
+    #[test]
+    fn test_duplicate_ids() {
+        let error = validation_error(
+            "",
+            r#"<sbe:message name="Car" id="1">
+                <field name="serialNumber" id="1" type="uint64"/>
+                <field name="modelYear" id="1" type="uint16"/>
+            </sbe:message>
+            <sbe:message name="Bike" id="1"/>"#,
+        );
+
+        assert!(error.contains("Message 'Bike' has the same id 1 as message 'Car'"));
+        assert!(
+            error.contains("Field 'Car.modelYear' has the same id 1 as field 'Car.serialNumber'")
+        );
+    }
+
+    #[test]
+    fn test_nested_group_paths() {
+        let error = validation_error(
+            r#"<composite name="groupSizeEncoding">
+                <type name="blockLength" primitiveType="uint16"/>
+                <type name="numInGroup" primitiveType="uint16"/>
+            </composite>"#,
+            r#"<sbe:message name="Car" id="1">
+                <group name="fuelFigures" id="1">
+                    <field name="speed" id="2" type="uint16"/>
+                    <group name="launchControl" id="3" dimensionType="launchSizeEncoding">
+                        <field name="mph" id="4" type="Mph"/>
+                        <field name="seconds" id="4" type="float"/>
+                    </group>
+                </group>
+            </sbe:message>"#,
+        );
+
+        assert!(error.contains(
+            "Group 'Car.fuelFigures.launchControl' has unknown dimension type 'launchSizeEncoding'"
+        ));
+        assert!(error.contains("Field 'Car.fuelFigures.launchControl.mph' has unknown type 'Mph'"));
+        assert!(error.contains(
+            "Field 'Car.fuelFigures.launchControl.seconds' has the same id 4 as field 'Car.fuelFigures.launchControl.mph'"
+        ));
+    }
+
+    #[test]
+    fn test_unknown_types() {
+        let error = validation_error(
+            r#"<composite name="Engine">
+                <ref name="booster" type="Booster"/>
+            </composite>"#,
+            r#"<sbe:message name="Car" id="1">
+                <field name="model" id="1" type="Model"/>
+                <data name="manufacturer" id="2" type="varStringEncoding"/>
+            </sbe:message>"#,
+        );
+
+        assert!(error.contains("Reference 'Engine.booster' has unknown type 'Booster'"));
+        assert!(error.contains("Field 'Car.model' has unknown type 'Model'"));
+        assert!(error.contains(
+            "Variable data 'Car.manufacturer' has unknown composite type 'varStringEncoding'"
+        ));
+    }
+
+    #[test]
+    fn test_enum_and_set_encodings() {
+        let error = validation_error(
+            r#"<enum name="Model" encodingType="char">
+                <validValue name="A">AB</validValue>
+            </enum>
+            <enum name="Gear" encodingType="uint8">
+                <validValue name="Reverse">-1</validValue>
+                <validValue name="Neutral">0</validValue>
+            </enum>
+            <set name="OptionalExtras" encodingType="uint8">
+                <choice name="sunRoof">0</choice>
+                <choice name="sportsPack">8</choice>
+            </set>"#,
+            "",
+        );
+
+        assert!(
+            error.contains("Value 'AB' of enum 'Model.A' does not fit its encoding type 'char'")
+        );
+        assert!(error
+            .contains("Value '-1' of enum 'Gear.Reverse' does not fit its encoding type 'uint8'"));
+        assert!(error.contains(
+            "Choice 'OptionalExtras.sportsPack' uses bit 8 past the 8 bits of its encoding type"
+        ));
+        assert!(!error.contains("Neutral"));
+        assert!(!error.contains("sunRoof"));
+    }
+
+    #[test]
+    fn test_incomplete_dimension_type() {
+        let error = validation_error(
+            r#"<composite name="groupSizeEncoding">
+                <type name="blockLength" primitiveType="uint16"/>
+            </composite>"#,
+            r#"<sbe:message name="Car" id="1">
+                <group name="fuelFigures" id="1">
+                    <field name="speed" id="2" type="uint16"/>
+                </group>
+            </sbe:message>"#,
+        );
+
+        assert!(error.contains(
+            "Dimension type 'groupSizeEncoding' of group 'Car.fuelFigures' is missing the encoded field 'numInGroup'"
+        ));
+        assert!(!error.contains("'blockLength'"));
+    }
+
+    #[test]
+    fn test_scoped_group_and_var_data_types() {
+        let content = r#"<sbe:messageSchema xmlns:sbe="http://fixprotocol.io/2016/sbe" package="test" id="1" version="0">
+            <types>
+                <composite name="messageHeader">
+                    <type name="blockLength" primitiveType="uint16"/>
+                    <type name="templateId" primitiveType="uint16"/>
+                    <type name="schemaId" primitiveType="uint16"/>
+                    <type name="version" primitiveType="uint16"/>
+                </composite>
+                <composite name="groupSizeEncoding">
+                    <type name="blockLength" primitiveType="uint16"/>
+                    <type name="numInGroup" primitiveType="uint16"/>
+                </composite>
+                <composite name="varDataEncoding">
+                    <type name="length" primitiveType="uint32"/>
+                    <type name="varData" primitiveType="uint8" length="0"/>
+                </composite>
+            </types>
+            <sbe:message name="Quote" id="1">
+                <group name="entries" id="1">
+                    <field name="price" id="2" type="int64"/>
+                    <data name="text" id="3" type="varDataEncoding"/>
+                </group>
+                <data name="text" id="4" type="varDataEncoding"/>
+            </sbe:message>
+            <sbe:message name="Trade" id="2">
+                <group name="entries" id="1">
+                    <field name="side" id="2" type="uint8"/>
+                </group>
+            </sbe:message>
+        </sbe:messageSchema>"#;
+
+        let message_types = MessageSchema::load_from_string(content)
+            .unwrap()
+            .validate()
+            .unwrap()
+            .message_types;
+
+        let path = |segments: &[&str]| {
+            FieldPath::new(segments.iter().map(|segment| segment.to_string()).collect())
+        };
+
+        assert_eq!(
+            message_types.group_types.keys().collect::<Vec<_>>(),
+            [&path(&["Quote", "entries"]), &path(&["Trade", "entries"])]
+        );
+        assert_eq!(
+            message_types.variable_data_types.keys().collect::<Vec<_>>(),
+            [
+                &path(&["Quote", "entries", "text"]),
+                &path(&["Quote", "text"])
+            ]
+        );
+        assert!(message_types.has_variable_data(&path(&["Quote", "entries"])));
+        assert!(!message_types.has_variable_data(&path(&["Trade"])));
+    }
 }
@@ -1,3 +1,4 @@
+use crate::models::layout::BlockLayout;
 use crate::models::types::{SizedEncoded, Type};
 use crate::models::TypeMap;
 use anyhow::Result;
@@ -37,6 +38,6 @@ pub struct CompositeType {
 
 impl SizedEncoded for CompositeType {
     fn size(&self, types: &TypeMap) -> Result<usize> {
-        self.fields.iter().map(|f| f.size(types)).sum()
+        Ok(BlockLayout::from_composite(self, types)?.block_length)
     }
 }
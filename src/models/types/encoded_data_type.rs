@@ -3,7 +3,7 @@ use crate::models::types::{CharacterEncoding, Presence, SizedEncoded};
 use crate::models::TypeMap;
 use anyhow::Result;
 use hard_xml::XmlRead;
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 #[derive(XmlRead, PartialEq, Debug, Clone)]
 #[xml(tag = "type")]
@@ -53,7 +53,7 @@ impl EncodedDataType {
         self.character_encoding.is_some()
     }
 
-    pub fn is_hashable(&self, types: &HashMap<String, EncodedDataType>) -> Result<bool> {
+    pub fn is_hashable(&self, types: &BTreeMap<String, EncodedDataType>) -> Result<bool> {
         self.primitive_type.resolved(types).map(|t| t.is_hashable())
     }
 }
@@ -47,6 +47,16 @@ impl Type {
         }
     }
 
+    pub fn offset(&self) -> Option<usize> {
+        match self {
+            Type::EncodedData(t) => t.offset,
+            Type::Set(t) => t.offset,
+            Type::Enum(t) => t.offset,
+            Type::Composite(t) => t.offset,
+            Type::Reference(t) => t.offset,
+        }
+    }
+
     pub fn presence(&self, types: &TypeMap) -> Result<Presence> {
         Ok(match self {
             Type::EncodedData(encoded_data_type) => encoded_data_type.presence,
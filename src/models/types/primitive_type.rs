@@ -6,7 +6,7 @@ use anyhow::{anyhow, Result};
 use genco::lang::Lang;
 use genco::tokens::FormatInto;
 use genco::Tokens;
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 use std::marker::PhantomData;
 use std::str::FromStr;
 
@@ -131,7 +131,7 @@ impl<L: Lang> FormatInto<L> for &LanguagePrimitive<L> {
 }
 
 pub trait ResolvableType {
-    fn resolved(&self, encoded_types: &HashMap<String, EncodedDataType>) -> Result<Self>
+    fn resolved(&self, encoded_types: &BTreeMap<String, EncodedDataType>) -> Result<Self>
     where
         Self: Sized;
 }
@@ -139,12 +139,12 @@ pub trait ResolvableType {
 pub trait PrimitiveConvertible<L: Lang>: ResolvableType {
     fn lang_primitive(
         &self,
-        encoded_types: &HashMap<String, EncodedDataType>,
+        encoded_types: &BTreeMap<String, EncodedDataType>,
     ) -> Result<LanguagePrimitive<L>>;
 }
 
 impl ResolvableType for NativeType {
-    fn resolved(&self, encoded_types: &HashMap<String, EncodedDataType>) -> Result<Self> {
+    fn resolved(&self, encoded_types: &BTreeMap<String, EncodedDataType>) -> Result<Self> {
         match self {
             NativeType::Reference(type_name) => encoded_types
                 .get(type_name)
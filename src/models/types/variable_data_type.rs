@@ -4,7 +4,7 @@ use crate::models::types::Type;
 use crate::models::TypeMap;
 use anyhow::{anyhow, Result};
 use hard_xml::XmlRead;
-use std::collections::HashMap;
+use std::collections::BTreeMap;
 
 #[derive(XmlRead, PartialEq, Debug, Clone)]
 #[xml(tag = "data")]
@@ -32,7 +32,7 @@ pub struct VariableDataType {
 impl VariableDataType {
     pub fn repr_type<'a>(
         &self,
-        composite_types: &'a HashMap<String, CompositeType>,
+        composite_types: &'a BTreeMap<String, CompositeType>,
     ) -> Result<&'a CompositeType> {
         composite_types.get(&self.type_name).ok_or(anyhow!(
             "Missing type '{}' for variable data '{}'",
@@ -41,7 +41,7 @@ impl VariableDataType {
         ))
     }
 
-    pub fn is_string(&self, composite_types: &HashMap<String, CompositeType>) -> Result<bool> {
+    pub fn is_string(&self, composite_types: &BTreeMap<String, CompositeType>) -> Result<bool> {
         let repr_type = self.repr_type(composite_types)?;
         let value_type = match repr_type.fields[1] {
             Type::EncodedData(ref var_data_type) => var_data_type,